@@ -6,5 +6,6 @@
 // Re-export all service interfaces from ports for backward compatibility
 pub use crate::ports::services::{
     ChunkingOrchestratorInterface, ContextServiceInterface, IndexingResult,
-    IndexingServiceInterface, IndexingStatus, SearchServiceInterface,
+    IndexingServiceInterface, IndexingStatus, SearchFilters, SearchResponse,
+    SearchServiceInterface,
 };
@@ -0,0 +1,2 @@
+//! Audit log port - re-exports from mcb-domain
+pub use mcb_domain::ports::infrastructure::audit::*;
@@ -0,0 +1,2 @@
+//! Event store port - re-exports from mcb-domain
+pub use mcb_domain::ports::infrastructure::event_store::*;
@@ -17,9 +17,15 @@
 //! | [`LockProvider`] | Distributed lock coordination |
 //! | [`StateStoreProvider`] | Key-value state persistence |
 //! | [`ProviderRouter`] | Provider routing and selection services |
+//! | [`AuditLogInterface`] | Append-only audit trail |
+//! | [`EventStoreInterface`] | Append-only domain event log |
 
+/// Audit log port - re-exports from mcb-domain
+pub mod audit;
 /// Authentication service port - re-exports from mcb-domain
 pub mod auth;
+/// Event store port - re-exports from mcb-domain
+pub mod event_store;
 /// Event bus provider port - re-exports from mcb-domain
 pub mod events;
 /// Distributed lock provider port - re-exports from mcb-domain
@@ -37,8 +43,9 @@ pub mod sync;
 
 // Re-export infrastructure ports at module level for convenience
 pub use mcb_domain::ports::infrastructure::{
-    AuthServiceInterface, DomainEventStream, EventBusProvider, LockGuard, LockProvider,
+    AuditEvent, AuditLogInterface, AuditOutcome, AuditQuery, AuditSource, AuthServiceInterface,
+    DomainEventStream, EventBusProvider, EventQuery, EventStoreInterface, LockGuard, LockProvider,
     ProviderContext, ProviderHealthStatus, ProviderRouter, SharedSyncCoordinator, SnapshotProvider,
-    StateStoreProvider, SyncCoordinator, SyncOptions, SyncProvider, SyncResult, SystemMetrics,
-    SystemMetricsCollectorInterface,
+    StateStoreProvider, StoredEvent, StoredEventStream, SyncCoordinator, SyncOptions, SyncProvider,
+    SyncResult, SystemMetrics, SystemMetricsCollectorInterface,
 };
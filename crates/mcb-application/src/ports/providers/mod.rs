@@ -36,6 +36,8 @@ pub use mcb_domain::ports::providers::{
     CacheProvider,
     CacheProviderFactoryInterface,
     CacheStats,
+    CacheTierStats,
+    NamespaceStats,
     // Crypto
     CryptoProvider,
     // Embedding
@@ -9,6 +9,7 @@ use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::Result;
 use mcb_domain::value_objects::config::SyncBatch;
 use mcb_domain::value_objects::{Embedding, SearchResult};
+use serde::Serialize;
 use std::path::Path;
 
 // ============================================================================
@@ -26,6 +27,31 @@ pub trait ContextServiceInterface: Send + Sync {
     /// Store code chunks in the repository
     async fn store_chunks(&self, collection: &str, chunks: &[CodeChunk]) -> Result<()>;
 
+    /// Delete every stored chunk of a file, returning how many were removed
+    async fn delete_file_chunks(&self, collection: &str, file_path: &str) -> Result<u64>;
+
+    /// Replace the stored chunks of a file with `chunks`
+    ///
+    /// The default deletes the file's chunks, then stores the new ones.
+    async fn replace_file_chunks(
+        &self,
+        collection: &str,
+        file_path: &str,
+        chunks: &[CodeChunk],
+    ) -> Result<()> {
+        self.delete_file_chunks(collection, file_path).await?;
+        self.store_chunks(collection, chunks).await
+    }
+
+    /// Mark the end of an indexing run of a collection
+    ///
+    /// Chunks stored or deleted since the previous call are published to
+    /// searches at once, so cached search results go stale once per run.
+    /// The default does nothing.
+    async fn finish_indexing(&self, _collection: &str) -> Result<()> {
+        Ok(())
+    }
+
     /// Search for code similar to the query
     async fn search_similar(
         &self,
@@ -63,6 +89,80 @@ pub trait SearchServiceInterface: Send + Sync {
         query: &str,
         limit: usize,
     ) -> Result<Vec<SearchResult>>;
+
+    /// Search with result filters, reporting whether results came from cache
+    ///
+    /// The default runs [`search`](Self::search) and filters its results.
+    async fn search_filtered(
+        &self,
+        collection: &str,
+        query: &str,
+        filters: &SearchFilters,
+        limit: usize,
+    ) -> Result<SearchResponse> {
+        let results = self.search(collection, query, limit).await?;
+        Ok(SearchResponse {
+            results: filters.apply(results, limit),
+            cache_hit: false,
+        })
+    }
+}
+
+/// Filters applied to search results
+///
+/// Empty lists and `None` match everything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SearchFilters {
+    /// File extensions without the leading dot, e.g. `rs`
+    pub file_extensions: Vec<String>,
+    /// Languages as reported by the chunker, e.g. `rust`
+    pub languages: Vec<String>,
+    /// Minimum similarity score
+    pub min_score: Option<f64>,
+}
+
+impl SearchFilters {
+    /// Whether the filters match every result
+    pub fn is_empty(&self) -> bool {
+        self.file_extensions.is_empty() && self.languages.is_empty() && self.min_score.is_none()
+    }
+
+    /// Whether `result` passes every filter
+    pub fn matches(&self, result: &SearchResult) -> bool {
+        let extension = Path::new(&result.file_path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        (self.file_extensions.is_empty()
+            || self
+                .file_extensions
+                .iter()
+                .any(|ext| ext.trim_start_matches('.').eq_ignore_ascii_case(extension)))
+            && (self.languages.is_empty()
+                || self
+                    .languages
+                    .iter()
+                    .any(|language| language.eq_ignore_ascii_case(&result.language)))
+            && self.min_score.is_none_or(|min| result.score >= min)
+    }
+
+    /// Keep the first `limit` results that pass every filter
+    pub fn apply(&self, results: Vec<SearchResult>, limit: usize) -> Vec<SearchResult> {
+        results
+            .into_iter()
+            .filter(|result| self.matches(result))
+            .take(limit)
+            .collect()
+    }
+}
+
+/// Results of a filtered search
+#[derive(Debug, Clone, Default)]
+pub struct SearchResponse {
+    /// Ranked results
+    pub results: Vec<SearchResult>,
+    /// Whether the results were served from the search result cache
+    pub cache_hit: bool,
 }
 
 // ============================================================================
@@ -4,13 +4,15 @@
 //! Orchestrates embeddings, vector storage, and caching for semantic code understanding.
 
 use crate::domain_services::search::ContextServiceInterface;
+use crate::use_cases::search_service::{IndexGenerations, search_results_namespace};
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::Result;
 use mcb_domain::ports::providers::{CacheEntryConfig, EmbeddingProvider, VectorStoreProvider};
 use mcb_domain::value_objects::{Embedding, SearchResult};
 use serde_json::json;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::debug;
 
 /// Cache key helpers for collection management
 mod cache_keys {
@@ -42,6 +44,9 @@ pub struct ContextServiceImpl {
     cache: Arc<dyn crate::ports::providers::cache::CacheProvider>,
     embedding_provider: Arc<dyn EmbeddingProvider>,
     vector_store_provider: Arc<dyn VectorStoreProvider>,
+    index_generations: Option<Arc<IndexGenerations>>,
+    /// Collections written since their last finished indexing run
+    changed: Mutex<HashSet<String>>,
 }
 
 impl ContextServiceImpl {
@@ -55,9 +60,94 @@ impl ContextServiceImpl {
             cache,
             embedding_provider,
             vector_store_provider,
+            index_generations: None,
+            changed: Mutex::new(HashSet::new()),
         }
     }
 
+    /// Bump the generation of collections whose index changes
+    ///
+    /// Share `generations` with the search service caching their results.
+    pub fn with_index_generations(mut self, generations: Arc<IndexGenerations>) -> Self {
+        self.index_generations = Some(generations);
+        self
+    }
+
+    /// Remember that the index of `collection` changed in the current run
+    fn mark_changed(&self, collection: &str) {
+        if self.index_generations.is_some() {
+            self.changed().insert(collection.to_string());
+        }
+    }
+
+    fn changed(&self) -> MutexGuard<'_, HashSet<String>> {
+        self.changed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Retire cached search results after the index of `collection` changed
+    async fn index_changed(&self, collection: &str) {
+        let Some(generations) = &self.index_generations else {
+            return;
+        };
+        self.changed().remove(collection);
+        generations.bump(collection).await;
+        // Stale entries are unreachable now; dropping them just frees space
+        if let Err(e) = self
+            .cache
+            .clear_namespace(&search_results_namespace(collection))
+            .await
+        {
+            debug!(error = %e, collection, "Failed to drop stale search results");
+        }
+    }
+
+    /// Embed and upsert the chunks whose ids are not in `stored`
+    ///
+    /// Chunk ids are content-addressed, so a stored id already holds the
+    /// same chunk and needs no new embedding.
+    async fn write_chunks(
+        &self,
+        collection: &str,
+        chunks: &[CodeChunk],
+        stored: &HashSet<String>,
+    ) -> Result<()> {
+        // Repeated chunks are stored once
+        let mut seen = HashSet::new();
+        let chunks: Vec<&CodeChunk> = chunks
+            .iter()
+            .filter(|chunk| !stored.contains(&chunk.id) && seen.insert(chunk.id.as_str()))
+            .collect();
+        if chunks.is_empty() {
+            return Ok(());
+        }
+
+        // Generate embeddings for each chunk
+        let texts: Vec<String> = chunks.iter().map(|c| c.content.clone()).collect();
+        let embeddings = self
+            .embedding_provider
+            .embed_batch_for(collection, &texts)
+            .await?;
+
+        // Build metadata for each chunk
+        let ids: Vec<String> = chunks.iter().map(|c| c.id.clone()).collect();
+        let metadata: Vec<_> = chunks.iter().map(|c| build_chunk_metadata(c)).collect();
+
+        // Upsert so re-indexed chunks replace their previous version
+        self.vector_store_provider
+            .upsert_vectors(collection, &ids, &embeddings, metadata)
+            .await?;
+        self.mark_changed(collection);
+
+        // Update collection metadata in cache
+        self.cache_set(
+            &cache_keys::collection_meta(collection),
+            &chunks.len().to_string(),
+        )
+        .await
+    }
+
     /// Check if collection exists in vector store
     async fn collection_exists(&self, collection: &str) -> Result<bool> {
         self.vector_store_provider
@@ -78,7 +168,7 @@ impl ContextServiceInterface for ContextServiceImpl {
     async fn initialize(&self, collection: &str) -> Result<()> {
         // Create collection if it doesn't exist
         if !self.collection_exists(collection).await? {
-            let dimensions = self.embedding_provider.dimensions();
+            let dimensions = self.embedding_provider.dimensions_for(collection);
             self.vector_store_provider
                 .create_collection(collection, dimensions)
                 .await?;
@@ -90,24 +180,55 @@ impl ContextServiceInterface for ContextServiceImpl {
     }
 
     async fn store_chunks(&self, collection: &str, chunks: &[CodeChunk]) -> Result<()> {
-        // Generate embeddings for each chunk
-        let texts: Vec<String> = chunks.iter().map(|c| c.content.clone()).collect();
-        let embeddings = self.embedding_provider.embed_batch(&texts).await?;
+        self.write_chunks(collection, chunks, &HashSet::new()).await
+    }
 
-        // Build metadata for each chunk
-        let metadata: Vec<_> = chunks.iter().map(build_chunk_metadata).collect();
+    async fn replace_file_chunks(
+        &self,
+        collection: &str,
+        file_path: &str,
+        chunks: &[CodeChunk],
+    ) -> Result<()> {
+        let stored: HashSet<String> = self
+            .vector_store_provider
+            .file_vector_ids(collection, file_path)
+            .await?
+            .into_iter()
+            .collect();
 
-        // Insert into vector store
-        self.vector_store_provider
-            .insert_vectors(collection, &embeddings, metadata)
+        // Write the new version first so a failed write leaves the old one
+        // searchable, then drop only the chunks that no longer exist
+        self.write_chunks(collection, chunks, &stored).await?;
+        let current: HashSet<&str> = chunks.iter().map(|chunk| chunk.id.as_str()).collect();
+        let stale: Vec<String> = stored
+            .into_iter()
+            .filter(|id| !current.contains(id.as_str()))
+            .collect();
+        if !stale.is_empty() {
+            self.vector_store_provider
+                .delete_vectors(collection, &stale)
+                .await?;
+            self.mark_changed(collection);
+        }
+        Ok(())
+    }
+
+    async fn delete_file_chunks(&self, collection: &str, file_path: &str) -> Result<u64> {
+        let deleted = self
+            .vector_store_provider
+            .delete_by_file(collection, file_path)
             .await?;
+        if deleted > 0 {
+            self.mark_changed(collection);
+        }
+        Ok(deleted)
+    }
 
-        // Update collection metadata in cache
-        self.cache_set(
-            &cache_keys::collection_meta(collection),
-            &chunks.len().to_string(),
-        )
-        .await
+    async fn finish_indexing(&self, collection: &str) -> Result<()> {
+        if self.changed().contains(collection) {
+            self.index_changed(collection).await;
+        }
+        Ok(())
     }
 
     async fn search_similar(
@@ -116,7 +237,7 @@ impl ContextServiceInterface for ContextServiceImpl {
         query: &str,
         limit: usize,
     ) -> Result<Vec<SearchResult>> {
-        let query_embedding = self.embedding_provider.embed(query).await?;
+        let query_embedding = self.embedding_provider.embed_for(collection, query).await?;
         self.vector_store_provider
             .search_similar(collection, &query_embedding.vector, limit, None)
             .await
@@ -133,6 +254,7 @@ impl ContextServiceInterface for ContextServiceImpl {
                 .delete_collection(collection)
                 .await?;
         }
+        self.index_changed(collection).await;
 
         // Clear cache metadata
         self.cache
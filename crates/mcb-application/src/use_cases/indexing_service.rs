@@ -147,8 +147,14 @@ impl IndexingServiceInterface for IndexingServiceImpl {
                 }
             };
 
+            // Replace the file's previous chunks so edited or removed code
+            // does not linger next to the new version
             let chunks = self.chunk_file_content(&content, &file_path);
-            if let Err(e) = self.context_service.store_chunks(collection, &chunks).await {
+            if let Err(e) = self
+                .context_service
+                .replace_file_chunks(collection, &file_path.to_string_lossy(), &chunks)
+                .await
+            {
                 progress.record_error("Failed to store chunks for", &file_path, e);
                 continue;
             }
@@ -156,6 +162,7 @@ impl IndexingServiceInterface for IndexingServiceImpl {
             progress.chunks_created += chunks.len();
         }
 
+        self.context_service.finish_indexing(collection).await?;
         Ok(progress.into_result())
     }
 
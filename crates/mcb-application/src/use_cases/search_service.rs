@@ -2,21 +2,309 @@
 //!
 //! Application service for semantic search operations.
 //! Orchestrates search functionality using context service for semantic understanding.
+//!
+//! ## Result Cache
+//!
+//! With [`SearchServiceImpl::with_result_cache`], results are cached under
+//! the collection, normalized query, filters and limit together with the
+//! collection's index generation from [`IndexGenerations`]. Each indexing
+//! run and clearing a collection bump its generation, so results cached
+//! before the change are never served again, by this or any other instance
+//! sharing the cache.
 
-use crate::domain_services::search::{ContextServiceInterface, SearchServiceInterface};
+use crate::domain_services::search::{
+    ContextServiceInterface, SearchFilters, SearchResponse, SearchServiceInterface,
+};
+use crate::ports::admin::PerformanceMetricsInterface;
+use crate::ports::infrastructure::EventBusProvider;
+use crate::ports::providers::cache::{CacheEntryConfig, CacheProvider};
+use futures::StreamExt;
 use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
 use mcb_domain::value_objects::SearchResult;
-use std::sync::Arc;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Results fetched per requested result when filters are applied
+const FILTERED_SEARCH_OVERFETCH: usize = 4;
+
+/// Cache namespace holding the search results of `collection`
+pub fn search_results_namespace(collection: &str) -> String {
+    format!("search-{collection}")
+}
+
+/// Cache namespace holding the index generations
+const INDEX_GENERATION_NAMESPACE: &str = "index-generation";
+
+/// Per-collection index generations
+///
+/// A collection's generation changes whenever its index changes; cached
+/// search results are keyed by it and go stale as soon as it moves on.
+/// Generations live in the cache next to the results, so every instance
+/// sharing that cache sees a bump. Each bump writes a fresh random token
+/// rather than incrementing a counter, so concurrent bumps never collide.
+#[derive(Debug)]
+pub struct IndexGenerations {
+    cache: Arc<dyn CacheProvider>,
+}
+
+impl IndexGenerations {
+    /// Keep the generations in `cache`
+    pub fn new(cache: Arc<dyn CacheProvider>) -> Self {
+        Self { cache }
+    }
+
+    /// Current generation of `collection`, or `None` if the cache failed
+    pub async fn current(&self, collection: &str) -> Option<String> {
+        let collection = self.token(&collection_generation_key(collection)).await?;
+        // Bumped when an unknown set of collections changed, e.g. after a sync
+        let epoch = self.token(&epoch_generation_key()).await?;
+        Some(format!("{collection}.{epoch}"))
+    }
+
+    /// Mark the index of `collection` as changed
+    pub async fn bump(&self, collection: &str) {
+        self.write_token(&collection_generation_key(collection))
+            .await;
+    }
+
+    /// Mark the index of every collection as changed
+    pub async fn bump_all(&self) {
+        self.write_token(&epoch_generation_key()).await;
+    }
+
+    /// Token stored under `key`, starting a new one if there is none
+    ///
+    /// A token evicted from the cache is replaced by a fresh one rather than
+    /// a default, so results cached before the eviction stay unreachable.
+    async fn token(&self, key: &str) -> Option<String> {
+        match self.cache.get_json(key).await {
+            Ok(Some(json)) => match serde_json::from_str(&json) {
+                Ok(token) => Some(token),
+                Err(_) => self.write_token(key).await,
+            },
+            Ok(None) => self.write_token(key).await,
+            Err(e) => {
+                debug!(error = %e, "Index generation read failed");
+                None
+            }
+        }
+    }
+
+    async fn write_token(&self, key: &str) -> Option<String> {
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        // Generations never expire; a zero TTL means no expiry
+        let config = CacheEntryConfig::default()
+            .with_ttl(Duration::ZERO)
+            .with_namespace(INDEX_GENERATION_NAMESPACE);
+        match self
+            .cache
+            .set_json(key, &json!(token).to_string(), config)
+            .await
+        {
+            Ok(()) => Some(token),
+            Err(e) => {
+                warn!(error = %e, key, "Failed to bump index generation");
+                None
+            }
+        }
+    }
+
+    /// Bump generations on index events published by other components
+    ///
+    /// `IndexingCompleted` and `IndexRebuild` bump the named collection;
+    /// `SyncCompleted` and collection-less rebuilds bump every collection.
+    /// Returns `None` when no Tokio runtime is running.
+    pub fn follow_events(
+        self: &Arc<Self>,
+        event_bus: Arc<dyn EventBusProvider>,
+    ) -> Option<JoinHandle<()>> {
+        let runtime = tokio::runtime::Handle::try_current().ok()?;
+        let generations: Weak<Self> = Arc::downgrade(self);
+
+        Some(runtime.spawn(async move {
+            let mut events = match event_bus.subscribe_events().await {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!(error = %e, "Index generation listener could not subscribe");
+                    return;
+                }
+            };
+            while let Some(event) = events.next().await {
+                let Some(generations) = generations.upgrade() else {
+                    break;
+                };
+                match event {
+                    DomainEvent::IndexingCompleted { collection, .. }
+                    | DomainEvent::IndexRebuild {
+                        collection: Some(collection),
+                    } => generations.bump(&collection).await,
+                    DomainEvent::IndexRebuild { collection: None }
+                    | DomainEvent::SyncCompleted { .. } => generations.bump_all().await,
+                    _ => {}
+                }
+            }
+        }))
+    }
+}
+
+/// Cache key of the generation of `collection`
+fn collection_generation_key(collection: &str) -> String {
+    format!("{INDEX_GENERATION_NAMESPACE}:collection:{collection}")
+}
+
+/// Cache key of the generation shared by every collection
+fn epoch_generation_key() -> String {
+    format!("{INDEX_GENERATION_NAMESPACE}:epoch")
+}
+
+/// Cache of search results, invalidated by index generation
+struct ResultCache {
+    cache: Arc<dyn CacheProvider>,
+    generations: Arc<IndexGenerations>,
+    ttl: Duration,
+}
+
+impl ResultCache {
+    /// Cache key for a search at the current generation of `collection`
+    ///
+    /// `None` when the generation is unknown and the search must not be
+    /// cached.
+    async fn key(
+        &self,
+        collection: &str,
+        query: &str,
+        filters: &SearchFilters,
+        limit: usize,
+    ) -> Option<String> {
+        let generation = self.generations.current(collection).await?;
+        let mut hasher = Sha256::new();
+        hasher.update(query.as_bytes());
+        hasher.update([0]);
+        hasher.update(serde_json::to_vec(filters).unwrap_or_default());
+        hasher.update(limit.to_le_bytes());
+        Some(format!(
+            "{}:g{}:{:x}",
+            search_results_namespace(collection),
+            generation,
+            hasher.finalize()
+        ))
+    }
+
+    async fn get(&self, key: &str) -> Option<Vec<SearchResult>> {
+        match self.cache.get_json(key).await {
+            Ok(Some(json)) => serde_json::from_str(&json).ok(),
+            Ok(None) => None,
+            Err(e) => {
+                debug!(error = %e, "Search result cache read failed");
+                None
+            }
+        }
+    }
+
+    async fn set(&self, key: &str, collection: &str, results: &[SearchResult]) {
+        let Ok(json) = serde_json::to_string(results) else {
+            return;
+        };
+        let config = CacheEntryConfig::default()
+            .with_ttl(self.ttl)
+            .with_namespace(search_results_namespace(collection));
+        if let Err(e) = self.cache.set_json(key, &json, config).await {
+            debug!(error = %e, "Search result cache write failed");
+        }
+    }
+}
+
+/// Trim the query and collapse runs of whitespace
+fn normalize_query(query: &str) -> String {
+    query.split_whitespace().collect::<Vec<_>>().join(" ")
+}
 
 /// Search service implementation - delegates to context service
 pub struct SearchServiceImpl {
     context_service: Arc<dyn ContextServiceInterface>,
+    result_cache: Option<ResultCache>,
+    metrics: Option<Arc<dyn PerformanceMetricsInterface>>,
 }
 
 impl SearchServiceImpl {
     /// Create new search service with injected dependencies
     pub fn new(context_service: Arc<dyn ContextServiceInterface>) -> Self {
-        Self { context_service }
+        Self {
+            context_service,
+            result_cache: None,
+            metrics: None,
+        }
+    }
+
+    /// Cache results in `cache` for `ttl`, keyed by index generation
+    ///
+    /// `generations` must be shared with the context service that writes
+    /// the searched collections.
+    pub fn with_result_cache(
+        mut self,
+        cache: Arc<dyn CacheProvider>,
+        generations: Arc<IndexGenerations>,
+        ttl: Duration,
+    ) -> Self {
+        self.result_cache = Some(ResultCache {
+            cache,
+            generations,
+            ttl,
+        });
+        self
+    }
+
+    /// Record every query in `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<dyn PerformanceMetricsInterface>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    async fn run_search(
+        &self,
+        collection: &str,
+        query: &str,
+        filters: &SearchFilters,
+        limit: usize,
+    ) -> Result<SearchResponse> {
+        let key = match &self.result_cache {
+            Some(cache) => cache.key(collection, query, filters, limit).await,
+            None => None,
+        };
+        if let (Some(cache), Some(key)) = (&self.result_cache, &key)
+            && let Some(results) = cache.get(key).await
+        {
+            return Ok(SearchResponse {
+                results,
+                cache_hit: true,
+            });
+        }
+
+        // Fetch extra candidates so filtering still fills the limit
+        let fetch = if filters.is_empty() {
+            limit
+        } else {
+            limit.saturating_mul(FILTERED_SEARCH_OVERFETCH)
+        };
+        // Future: add BM25 scoring and hybrid ranking
+        let results = self
+            .context_service
+            .search_similar(collection, query, fetch)
+            .await?;
+        let results = filters.apply(results, limit);
+
+        if let (Some(cache), Some(key)) = (&self.result_cache, &key) {
+            cache.set(key, collection, &results).await;
+        }
+        Ok(SearchResponse {
+            results,
+            cache_hit: false,
+        })
     }
 }
 
@@ -28,10 +316,30 @@ impl SearchServiceInterface for SearchServiceImpl {
         query: &str,
         limit: usize,
     ) -> Result<Vec<SearchResult>> {
-        // Delegate to context service for semantic search
-        // Future: add BM25 scoring and hybrid ranking
-        self.context_service
-            .search_similar(collection, query, limit)
+        self.search_filtered(collection, query, &SearchFilters::default(), limit)
             .await
+            .map(|response| response.results)
+    }
+
+    async fn search_filtered(
+        &self,
+        collection: &str,
+        query: &str,
+        filters: &SearchFilters,
+        limit: usize,
+    ) -> Result<SearchResponse> {
+        let started = Instant::now();
+        let query = normalize_query(query);
+        let response = self.run_search(collection, &query, filters, limit).await;
+
+        if let Some(metrics) = &self.metrics {
+            let cache_hit = response.as_ref().is_ok_and(|r| r.cache_hit);
+            metrics.record_query(
+                started.elapsed().as_millis() as u64,
+                response.is_ok(),
+                cache_hit,
+            );
+        }
+        response
     }
 }
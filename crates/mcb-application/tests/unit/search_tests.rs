@@ -13,17 +13,23 @@
 // Force linkme registration of all providers
 extern crate mcb_providers;
 
-use mcb_application::domain_services::search::SearchServiceInterface;
+use mcb_application::domain_services::search::{SearchFilters, SearchServiceInterface};
+use mcb_application::ports::admin::{PerformanceMetricsData, PerformanceMetricsInterface};
+use mcb_application::ports::infrastructure::EventBusProvider;
 use mcb_application::ports::providers::CacheProvider;
 use mcb_application::ports::services::ContextServiceInterface;
-use mcb_application::use_cases::{ContextServiceImpl, SearchServiceImpl};
+use mcb_application::use_cases::{ContextServiceImpl, IndexGenerations, SearchServiceImpl};
 use mcb_domain::entities::CodeChunk;
+use mcb_domain::events::DomainEvent;
 use mcb_domain::ports::providers::{EmbeddingProvider, VectorStoreProvider};
-use mcb_providers::cache::NullCacheProvider;
+use mcb_domain::value_objects::SearchResult;
+use mcb_providers::cache::{MokaCacheProvider, NullCacheProvider};
 use mcb_providers::embedding::NullEmbeddingProvider;
+use mcb_providers::events::TokioEventBusProvider;
 use mcb_providers::vector_store::InMemoryVectorStoreProvider;
 use serde_json::json;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 /// Create a real ContextServiceImpl with actual test providers
 fn create_real_context_service() -> Arc<dyn ContextServiceInterface> {
@@ -332,3 +338,247 @@ async fn test_full_search_flow_validates_architecture() {
         "Results should be relevant to query (or at least non-empty with deterministic embeddings)"
     );
 }
+
+// ============================================================================
+// Search Result Cache
+// ============================================================================
+
+/// Records the `(success, cache_hit)` flags of every query
+#[derive(Default)]
+struct RecordingMetrics {
+    queries: Mutex<Vec<(bool, bool)>>,
+}
+
+impl PerformanceMetricsInterface for RecordingMetrics {
+    fn uptime_secs(&self) -> u64 {
+        0
+    }
+
+    fn record_query(&self, _response_time_ms: u64, success: bool, cache_hit: bool) {
+        self.queries.lock().unwrap().push((success, cache_hit));
+    }
+
+    fn update_active_connections(&self, _delta: i64) {}
+
+    fn get_performance_metrics(&self) -> PerformanceMetricsData {
+        PerformanceMetricsData {
+            total_queries: 0,
+            successful_queries: 0,
+            failed_queries: 0,
+            average_response_time_ms: 0.0,
+            cache_hit_rate: 0.0,
+            active_connections: 0,
+            uptime_seconds: 0,
+        }
+    }
+}
+
+/// Context and search services sharing a Moka cache and index generations
+fn create_cached_services() -> (
+    Arc<dyn ContextServiceInterface>,
+    SearchServiceImpl,
+    Arc<RecordingMetrics>,
+) {
+    let cache: Arc<dyn CacheProvider> = Arc::new(MokaCacheProvider::new());
+    let generations = Arc::new(IndexGenerations::new(Arc::clone(&cache)));
+    let context_service: Arc<dyn ContextServiceInterface> = Arc::new(
+        ContextServiceImpl::new(
+            Arc::clone(&cache),
+            Arc::new(NullEmbeddingProvider::new()),
+            Arc::new(InMemoryVectorStoreProvider::new()),
+        )
+        .with_index_generations(Arc::clone(&generations)),
+    );
+    let metrics = Arc::new(RecordingMetrics::default());
+    let search_service = SearchServiceImpl::new(Arc::clone(&context_service))
+        .with_result_cache(cache, generations, Duration::from_secs(60))
+        .with_metrics(metrics.clone());
+    (context_service, search_service, metrics)
+}
+
+#[tokio::test]
+async fn test_repeated_search_is_served_from_cache() {
+    let (context_service, search_service, metrics) = create_cached_services();
+    context_service.initialize("cached").await.expect("init");
+    context_service
+        .store_chunks("cached", &create_test_chunks())
+        .await
+        .expect("store");
+    let filters = SearchFilters::default();
+
+    let first = search_service
+        .search_filtered("cached", "request handler", &filters, 5)
+        .await
+        .expect("search");
+    let second = search_service
+        .search_filtered("cached", "  request   handler ", &filters, 5)
+        .await
+        .expect("search");
+
+    assert!(!first.cache_hit);
+    assert!(second.cache_hit, "normalized query should hit the cache");
+    let ids = |results: &[SearchResult]| results.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids(&first.results), ids(&second.results));
+    assert_eq!(
+        *metrics.queries.lock().unwrap(),
+        vec![(true, false), (true, true)]
+    );
+
+    // A different limit is a different search
+    let other = search_service
+        .search_filtered("cached", "request handler", &filters, 2)
+        .await
+        .expect("search");
+    assert!(!other.cache_hit);
+}
+
+#[tokio::test]
+async fn test_indexing_and_clearing_invalidate_cached_results() {
+    let (context_service, search_service, _) = create_cached_services();
+    let filters = SearchFilters::default();
+    let chunks = create_test_chunks();
+    context_service.initialize("stale").await.expect("init");
+    context_service
+        .store_chunks("stale", &chunks[..1])
+        .await
+        .expect("store");
+
+    let before = search_service
+        .search_filtered("stale", "config", &filters, 10)
+        .await
+        .expect("search");
+    assert_eq!(before.results.len(), 1);
+
+    context_service
+        .store_chunks("stale", &chunks[1..])
+        .await
+        .expect("store");
+    // Cached results are kept until the indexing run finishes
+    let mid_run = search_service
+        .search_filtered("stale", "config", &filters, 10)
+        .await
+        .expect("search");
+    assert!(mid_run.cache_hit);
+    context_service
+        .finish_indexing("stale")
+        .await
+        .expect("finish");
+    let after_index = search_service
+        .search_filtered("stale", "config", &filters, 10)
+        .await
+        .expect("search");
+    assert!(!after_index.cache_hit);
+    assert_eq!(after_index.results.len(), 3);
+
+    context_service
+        .clear_collection("stale")
+        .await
+        .expect("clear");
+    context_service.initialize("stale").await.expect("init");
+    let after_clear = search_service
+        .search_filtered("stale", "config", &filters, 10)
+        .await
+        .expect("search");
+    assert!(!after_clear.cache_hit);
+    assert!(after_clear.results.is_empty());
+}
+
+#[tokio::test]
+async fn test_filters_narrow_results_and_key_the_cache() {
+    let (context_service, search_service, _) = create_cached_services();
+    context_service.initialize("filtered").await.expect("init");
+    context_service
+        .store_chunks("filtered", &create_test_chunks())
+        .await
+        .expect("store");
+
+    let rust_only = SearchFilters {
+        file_extensions: vec![".rs".to_string()],
+        languages: vec!["Rust".to_string()],
+        min_score: None,
+    };
+    let all = search_service
+        .search_filtered("filtered", "config", &SearchFilters::default(), 1)
+        .await
+        .expect("search");
+    let filtered = search_service
+        .search_filtered("filtered", "config", &rust_only, 1)
+        .await
+        .expect("search");
+    assert!(!filtered.cache_hit, "filters are part of the cache key");
+    assert_eq!(filtered.results.len(), 1);
+    assert_eq!(all.results.len(), 1);
+
+    let python_only = SearchFilters {
+        languages: vec!["python".to_string()],
+        ..SearchFilters::default()
+    };
+    let none = search_service
+        .search_filtered("filtered", "config", &python_only, 5)
+        .await
+        .expect("search");
+    assert!(none.results.is_empty());
+}
+
+#[tokio::test]
+async fn test_index_generations_follow_index_events() {
+    let bus = Arc::new(TokioEventBusProvider::new());
+    let generations = Arc::new(IndexGenerations::new(Arc::new(MokaCacheProvider::new())));
+    generations.follow_events(bus.clone()).expect("listener");
+    for _ in 0..100 {
+        if bus.has_subscribers() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    let (a, b) = (
+        generations.current("a").await.expect("generation"),
+        generations.current("b").await.expect("generation"),
+    );
+
+    bus.publish_event(DomainEvent::IndexingCompleted {
+        collection: "a".to_string(),
+        chunks: 1,
+        duration_ms: 1,
+    })
+    .await
+    .expect("publish");
+    for _ in 0..100 {
+        if generations.current("a").await.as_ref() != Some(&a) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    let bumped_a = generations.current("a").await.expect("generation");
+    assert_ne!(bumped_a, a);
+    assert_eq!(generations.current("b").await, Some(b.clone()));
+
+    bus.publish_event(DomainEvent::SyncCompleted {
+        path: "/repo".to_string(),
+        files_changed: 1,
+    })
+    .await
+    .expect("publish");
+    for _ in 0..100 {
+        if generations.current("b").await.as_ref() != Some(&b) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_ne!(generations.current("b").await, Some(b));
+    assert_ne!(generations.current("a").await, Some(bumped_a));
+}
+
+#[tokio::test]
+async fn test_index_generations_are_shared_through_the_cache() {
+    let cache: Arc<dyn CacheProvider> = Arc::new(MokaCacheProvider::new());
+    let local = IndexGenerations::new(Arc::clone(&cache));
+    let remote = IndexGenerations::new(cache);
+
+    let before = local.current("code").await.expect("generation");
+    assert_eq!(remote.current("code").await, Some(before.clone()));
+
+    remote.bump("code").await;
+    assert_ne!(local.current("code").await, Some(before));
+    assert_eq!(local.current("code").await, remote.current("code").await);
+}
@@ -6,6 +6,7 @@
 
 use crate::value_objects::Language;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Core Entity: Semantically Meaningful Code Segment
 ///
@@ -15,7 +16,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## Business Rules
 ///
-/// - Each chunk must have a unique identifier
+/// - Each chunk must have a unique identifier, derived with
+///   [`CodeChunk::content_id`] so re-indexing unchanged code yields the same id
 /// - Content must be non-empty and meaningful
 /// - Language identification enables proper parsing
 /// - Metadata provides additional context for search
@@ -53,3 +55,20 @@ pub struct CodeChunk {
     /// Additional metadata as JSON (context, AST info, etc.)
     pub metadata: serde_json::Value,
 }
+
+impl CodeChunk {
+    /// Content-addressed identifier for a chunk
+    ///
+    /// Hex SHA-256 of the file path, line range and content. The same code at
+    /// the same place always maps to the same id, so stores can upsert
+    /// re-indexed chunks instead of duplicating them.
+    pub fn content_id(file_path: &str, start_line: u32, end_line: u32, content: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(file_path.as_bytes());
+        hasher.update([0]);
+        hasher.update(start_line.to_le_bytes());
+        hasher.update(end_line.to_le_bytes());
+        hasher.update(content.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
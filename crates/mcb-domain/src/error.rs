@@ -73,6 +73,13 @@ pub enum Error {
         message: String,
     },
 
+    /// Operation refused because it conflicts with the current state
+    #[error("Conflict: {message}")]
+    Conflict {
+        /// Description of the conflict
+        message: String,
+    },
+
     /// Vector database operation error
     #[error("Vector database error: {message}")]
     VectorDb {
@@ -114,6 +121,16 @@ pub enum Error {
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
 
+    /// Provider temporarily unable to serve the request (rate limited,
+    /// overloaded, server error or circuit open); retrying later may help
+    #[error("Provider unavailable: {message}")]
+    Unavailable {
+        /// Description of the failure
+        message: String,
+        /// Wait requested by the provider before retrying
+        retry_after: Option<std::time::Duration>,
+    },
+
     /// Network-related error
     #[error("Network error: {message}")]
     Network {
@@ -180,6 +197,13 @@ impl Error {
         }
     }
 
+    /// Create a conflict error
+    pub fn conflict<S: Into<String>>(message: S) -> Self {
+        Self::Conflict {
+            message: message.into(),
+        }
+    }
+
     /// Create a vector database error
     pub fn vector_db<S: Into<String>>(message: S) -> Self {
         Self::VectorDb {
@@ -193,6 +217,18 @@ impl Error {
             message: message.into(),
         }
     }
+
+    /// Create a provider unavailable error, with the provider's requested
+    /// wait if it sent one
+    pub fn unavailable<S: Into<String>>(
+        message: S,
+        retry_after: Option<std::time::Duration>,
+    ) -> Self {
+        Self::Unavailable {
+            message: message.into(),
+            retry_after,
+        }
+    }
 }
 
 // I/O error creation methods
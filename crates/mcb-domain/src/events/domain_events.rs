@@ -83,6 +83,12 @@ pub enum DomainEvent {
     CacheInvalidate {
         /// Namespace to invalidate (None = all)
         namespace: Option<String>,
+        /// Single key to invalidate (None = the whole namespace)
+        #[serde(default)]
+        key: Option<String>,
+        /// Cache instance that published the invalidation
+        #[serde(default)]
+        origin: Option<String>,
     },
 
     // === Snapshot Events ===
@@ -159,6 +165,47 @@ pub enum DomainEvent {
     },
 }
 
+impl DomainEvent {
+    /// Variant name, used as the event type on the SSE stream and in queries
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::IndexRebuild { .. } => "IndexRebuild",
+            DomainEvent::IndexingStarted { .. } => "IndexingStarted",
+            DomainEvent::IndexingProgress { .. } => "IndexingProgress",
+            DomainEvent::IndexingCompleted { .. } => "IndexingCompleted",
+            DomainEvent::SyncCompleted { .. } => "SyncCompleted",
+            DomainEvent::CacheInvalidate { .. } => "CacheInvalidate",
+            DomainEvent::SnapshotCreated { .. } => "SnapshotCreated",
+            DomainEvent::FileChangesDetected { .. } => "FileChangesDetected",
+            DomainEvent::ServiceStateChanged { .. } => "ServiceStateChanged",
+            DomainEvent::ConfigReloaded { .. } => "ConfigReloaded",
+            DomainEvent::HealthCheckCompleted { .. } => "HealthCheckCompleted",
+            DomainEvent::MetricsSnapshot { .. } => "MetricsSnapshot",
+            DomainEvent::SearchExecuted { .. } => "SearchExecuted",
+        }
+    }
+
+    /// Whether the event is worth recording in the event store
+    ///
+    /// Cache invalidations only coordinate the instances running now, so
+    /// they are published but never recorded or replayed.
+    pub fn is_durable(&self) -> bool {
+        !matches!(self, DomainEvent::CacheInvalidate { .. })
+    }
+
+    /// Collection the event concerns, if any
+    pub fn collection(&self) -> Option<&str> {
+        match self {
+            DomainEvent::IndexRebuild { collection } => collection.as_deref(),
+            DomainEvent::IndexingStarted { collection, .. }
+            | DomainEvent::IndexingProgress { collection, .. }
+            | DomainEvent::IndexingCompleted { collection, .. }
+            | DomainEvent::SearchExecuted { collection, .. } => Some(collection),
+            _ => None,
+        }
+    }
+}
+
 /// Domain Port for Publishing System Events
 ///
 /// This trait defines the contract for event publishing without coupling to
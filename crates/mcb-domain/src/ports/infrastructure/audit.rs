@@ -0,0 +1,100 @@
+//! Audit Log Port
+//!
+//! Defines the contract for the append-only audit trail of MCP tool calls
+//! and admin mutations. Records are written once and never modified;
+//! implementations decide how they are stored and rotated.
+
+use crate::error::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Where an audited action originated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSource {
+    /// MCP tool call (stdio or HTTP transport)
+    Tool,
+    /// Admin API request
+    Admin,
+}
+
+/// Result of an audited action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The action completed
+    Success,
+    /// The caller was not authenticated or lacked permission
+    Denied,
+    /// The action was attempted and failed
+    Failure,
+}
+
+/// A single audit record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// When the action completed
+    pub timestamp: DateTime<Utc>,
+    /// Authenticated principal, if any
+    pub principal: Option<String>,
+    /// Client session identifier, if any
+    pub session: Option<String>,
+    /// Tool call or admin request
+    pub source: AuditSource,
+    /// Tool name or `METHOD /path` of the admin endpoint
+    pub action: String,
+    /// Call arguments with secrets redacted
+    pub arguments: serde_json::Value,
+    /// Result of the action
+    pub outcome: AuditOutcome,
+    /// Error message for denied or failed actions
+    pub error: Option<String>,
+    /// Wall-clock duration in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Filters for querying the audit log
+///
+/// All filters are optional and combined with AND.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditQuery {
+    /// Exact principal name
+    pub principal: Option<String>,
+    /// Exact tool name or admin action
+    pub action: Option<String>,
+    /// Tool calls or admin requests only
+    pub source: Option<AuditSource>,
+    /// Outcome to match
+    pub outcome: Option<AuditOutcome>,
+    /// Only events at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only events before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Maximum number of events to return (newest first)
+    pub limit: Option<usize>,
+}
+
+impl AuditQuery {
+    /// Whether `event` satisfies every filter in this query
+    pub fn matches(&self, event: &AuditEvent) -> bool {
+        self.principal
+            .as_deref()
+            .is_none_or(|p| event.principal.as_deref() == Some(p))
+            && self.action.as_deref().is_none_or(|a| event.action == a)
+            && self.source.is_none_or(|s| event.source == s)
+            && self.outcome.is_none_or(|o| event.outcome == o)
+            && self.since.is_none_or(|t| event.timestamp >= t)
+            && self.until.is_none_or(|t| event.timestamp < t)
+    }
+}
+
+/// Append-only audit log
+#[async_trait]
+pub trait AuditLogInterface: Send + Sync {
+    /// Append an event to the log
+    async fn record(&self, event: AuditEvent) -> Result<()>;
+
+    /// Return matching events, newest first
+    async fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEvent>>;
+}
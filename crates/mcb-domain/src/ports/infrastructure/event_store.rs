@@ -0,0 +1,83 @@
+//! Event Store Port
+//!
+//! Defines the contract for the append-only log of published domain events.
+//! Every event gets a sequence number that increases by one per event, so
+//! consumers can resume after the last sequence they saw. Implementations
+//! decide how long events are retained.
+
+use crate::error::Result;
+use crate::events::DomainEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+/// A domain event with its position in the log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredEvent {
+    /// Position in the log, starting at 1
+    pub sequence: u64,
+    /// When the event was appended
+    pub timestamp: DateTime<Utc>,
+    /// The event itself
+    pub event: DomainEvent,
+}
+
+/// Filters for querying the event log
+///
+/// All filters are optional and combined with AND.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventQuery {
+    /// Event type, e.g. `IndexingProgress`
+    pub event_type: Option<String>,
+    /// Collection the event concerns
+    pub collection: Option<String>,
+    /// Only events at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only events before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Only events after this sequence number
+    pub after_sequence: Option<u64>,
+    /// Maximum number of events to return (newest first)
+    pub limit: Option<usize>,
+}
+
+impl EventQuery {
+    /// Whether `stored` satisfies every filter in this query
+    pub fn matches(&self, stored: &StoredEvent) -> bool {
+        self.event_type
+            .as_deref()
+            .is_none_or(|t| stored.event.name() == t)
+            && self
+                .collection
+                .as_deref()
+                .is_none_or(|c| stored.event.collection() == Some(c))
+            && self.since.is_none_or(|t| stored.timestamp >= t)
+            && self.until.is_none_or(|t| stored.timestamp < t)
+            && self.after_sequence.is_none_or(|s| stored.sequence > s)
+    }
+}
+
+/// Stream of stored events in sequence order
+pub type StoredEventStream = Pin<Box<dyn Stream<Item = StoredEvent> + Send + Sync + 'static>>;
+
+/// Append-only event log
+#[async_trait]
+pub trait EventStoreInterface: Send + Sync {
+    /// Append an event, assigning it the next sequence number
+    async fn append(&self, event: DomainEvent) -> Result<StoredEvent>;
+
+    /// Return matching events, newest first
+    async fn query(&self, query: &EventQuery) -> Result<Vec<StoredEvent>>;
+
+    /// Stream retained events after `sequence`, then every new event
+    ///
+    /// With `None`, only events appended from now on are streamed. A
+    /// `sequence` beyond the end of the log (e.g. from before a restart)
+    /// replays every retained event.
+    async fn subscribe_after(&self, sequence: Option<u64>) -> Result<StoredEventStream>;
+
+    /// Sequence number of the newest event, 0 when none was appended
+    fn last_sequence(&self) -> u64;
+}
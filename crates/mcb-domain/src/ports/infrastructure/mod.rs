@@ -16,9 +16,15 @@
 //! | [`LockProvider`] | Distributed lock coordination |
 //! | [`StateStoreProvider`] | Key-value state persistence |
 //! | [`ProviderRouter`] | Provider routing and selection services |
+//! | [`AuditLogInterface`] | Append-only audit trail |
+//! | [`EventStoreInterface`] | Append-only domain event log |
 
+/// Audit log port
+pub mod audit;
 /// Authentication service port
 pub mod auth;
+/// Append-only domain event log port
+pub mod event_store;
 /// Event bus provider port
 pub mod events;
 /// Distributed lock provider port
@@ -35,7 +41,9 @@ pub mod state_store;
 pub mod sync;
 
 // Re-export infrastructure ports
+pub use audit::{AuditEvent, AuditLogInterface, AuditOutcome, AuditQuery, AuditSource};
 pub use auth::AuthServiceInterface;
+pub use event_store::{EventQuery, EventStoreInterface, StoredEvent, StoredEventStream};
 pub use events::{DomainEventStream, EventBusProvider};
 pub use lock::{LockGuard, LockProvider};
 pub use metrics::{SystemMetrics, SystemMetricsCollectorInterface};
@@ -17,6 +17,27 @@ use std::time::Duration;
 /// Default TTL for cache entries (5 minutes)
 pub const DEFAULT_CACHE_TTL_SECS: u64 = 300;
 
+/// Namespace of entries stored without one
+pub const DEFAULT_CACHE_ENTRY_NAMESPACE: &str = "default";
+
+/// Separator between the namespace and the rest of a cache key
+pub const CACHE_KEY_NAMESPACE_SEPARATOR: char = ':';
+
+/// Namespace implied by a cache key: the part before the first separator
+///
+/// # Example
+///
+/// ```
+/// use mcb_domain::ports::providers::cache::key_namespace;
+///
+/// assert_eq!(key_namespace("embeddings:abc"), "embeddings");
+/// assert_eq!(key_namespace("abc"), "default");
+/// ```
+pub fn key_namespace(key: &str) -> &str {
+    key.split_once(CACHE_KEY_NAMESPACE_SEPARATOR)
+        .map_or(DEFAULT_CACHE_ENTRY_NAMESPACE, |(namespace, _)| namespace)
+}
+
 /// Cache Entry Configuration
 ///
 /// Configures how a cache entry should be stored, including TTL
@@ -77,7 +98,17 @@ impl CacheEntryConfig {
     pub fn effective_namespace(&self) -> String {
         self.namespace
             .clone()
-            .unwrap_or_else(|| "default".to_string())
+            .unwrap_or_else(|| DEFAULT_CACHE_ENTRY_NAMESPACE.to_string())
+    }
+
+    /// Namespace an entry stored under `key` belongs to
+    ///
+    /// The configured namespace if set, otherwise the one implied by the key
+    /// (see [`key_namespace`]).
+    pub fn entry_namespace(&self, key: &str) -> String {
+        self.namespace
+            .clone()
+            .unwrap_or_else(|| key_namespace(key).to_string())
     }
 }
 
@@ -111,6 +142,9 @@ pub struct CacheStats {
     pub hit_rate: f64,
     /// Total bytes used by cache
     pub bytes_used: u64,
+    /// Per-tier statistics, outermost tier first (tiered providers only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<CacheTierStats>,
 }
 
 impl CacheStats {
@@ -130,11 +164,47 @@ impl CacheStats {
     }
 }
 
+/// Statistics of one tier of a tiered cache
+///
+/// A lookup counts as a miss in every tier it passes through and as a hit
+/// in the tier that answers it.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CacheTierStats {
+    /// Tier name (e.g., "l1", "l2")
+    pub tier: String,
+    /// Provider backing the tier
+    pub provider: String,
+    /// Lookups answered by this tier
+    pub hits: u64,
+    /// Lookups passed on to the next tier
+    pub misses: u64,
+    /// Hit rate of this tier (0.0 to 1.0)
+    pub hit_rate: f64,
+    /// Number of entries in this tier
+    pub entries: u64,
+}
+
+/// Entry counts of one cache namespace
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct NamespaceStats {
+    /// Namespace name
+    pub namespace: String,
+    /// Number of live entries in the namespace
+    pub entries: u64,
+    /// Total bytes of the cached values
+    pub bytes_used: u64,
+}
+
 /// Cache Provider Port
 ///
 /// Defines the contract for cache backend providers. Implementations
 /// must provide JSON-based storage with TTL support.
 ///
+/// Every entry belongs to a namespace: the one set in its
+/// [`CacheEntryConfig`], or else the one implied by its key (see
+/// [`key_namespace`]). Namespaces can be listed, counted and cleared
+/// independently.
+///
 /// # Implementations
 ///
 /// - **Moka**: In-memory cache with configurable TTL
@@ -170,6 +240,16 @@ pub trait CacheProvider: Send + Sync + std::fmt::Debug {
     /// The cached JSON string if present, None if not found or expired
     async fn get_json(&self, key: &str) -> Result<Option<String>>;
 
+    /// Get a value from the cache with the time it has left to live
+    ///
+    /// # Arguments
+    /// * `key` - The cache key
+    ///
+    /// # Returns
+    /// The cached JSON string and its remaining TTL if present (a `None` TTL
+    /// means the entry does not expire), None if not found or expired
+    async fn get_json_with_ttl(&self, key: &str) -> Result<Option<(String, Option<Duration>)>>;
+
     /// Set a value in the cache from JSON string
     ///
     /// # Arguments
@@ -199,6 +279,18 @@ pub trait CacheProvider: Send + Sync + std::fmt::Debug {
     /// Clear all values from the cache
     async fn clear(&self) -> Result<()>;
 
+    /// Remove every entry in a namespace
+    ///
+    /// # Returns
+    /// The number of entries removed
+    async fn clear_namespace(&self, namespace: &str) -> Result<u64>;
+
+    /// List the keys of the live entries in a namespace, sorted
+    async fn list_keys(&self, namespace: &str) -> Result<Vec<String>>;
+
+    /// Get entry counts per namespace, sorted by namespace
+    async fn stats_by_namespace(&self) -> Result<Vec<NamespaceStats>>;
+
     /// Get cache statistics
     async fn stats(&self) -> Result<CacheStats>;
 
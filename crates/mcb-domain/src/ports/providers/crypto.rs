@@ -56,11 +56,19 @@ pub trait CryptoProvider: Send + Sync {
 
     /// Get the name/identifier of this provider implementation
     fn provider_name(&self) -> &str;
+
+    /// Id of the key new data is encrypted with
+    ///
+    /// Providers with a single unnamed key return `None`.
+    fn active_key_id(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Encrypted data container
 ///
-/// Holds the ciphertext and nonce produced by encryption.
+/// Holds the ciphertext and nonce produced by encryption, and the id of the
+/// key it was encrypted with when the provider names its keys.
 /// Can be serialized for storage in vector store metadata.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EncryptedData {
@@ -68,12 +76,25 @@ pub struct EncryptedData {
     pub ciphertext: Vec<u8>,
     /// The nonce used for encryption
     pub nonce: Vec<u8>,
+    /// Id of the encryption key (absent for data written before key ids)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
 }
 
 impl EncryptedData {
     /// Create a new encrypted data container
     pub fn new(ciphertext: Vec<u8>, nonce: Vec<u8>) -> Self {
-        Self { ciphertext, nonce }
+        Self {
+            ciphertext,
+            nonce,
+            key_id: None,
+        }
+    }
+
+    /// Tag the data with the id of the key it was encrypted with
+    pub fn with_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
     }
 }
 
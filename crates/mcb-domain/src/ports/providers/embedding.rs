@@ -54,12 +54,50 @@ pub trait EmbeddingProvider: Send + Sync {
     /// The number of dimensions in each embedding vector
     fn dimensions(&self) -> usize;
 
+    /// Get embeddings for texts stored in or searched against `collection`
+    ///
+    /// Providers that route between several backends use the collection to
+    /// keep every vector of a collection in the same embedding space. The
+    /// default ignores the collection.
+    async fn embed_batch_for(&self, collection: &str, texts: &[String]) -> Result<Vec<Embedding>> {
+        let _ = collection;
+        self.embed_batch(texts).await
+    }
+
+    /// Get embedding for a single text used with `collection`
+    async fn embed_for(&self, collection: &str, text: &str) -> Result<Embedding> {
+        let embeddings = self
+            .embed_batch_for(collection, &[text.to_string()])
+            .await?;
+        embeddings
+            .into_iter()
+            .next()
+            .ok_or_else(|| crate::error::Error::embedding("No embedding returned"))
+    }
+
+    /// Dimensionality of the embeddings used for `collection`
+    fn dimensions_for(&self, collection: &str) -> usize {
+        let _ = collection;
+        self.dimensions()
+    }
+
     /// Get the name/identifier of this provider implementation
     ///
     /// # Returns
     /// A string identifier for the provider (e.g., "openai", "ollama", "anthropic")
     fn provider_name(&self) -> &str;
 
+    /// Name of the provider whose embeddings `collection` is built from
+    ///
+    /// Providers that route between several backends report the backend the
+    /// collection is pinned to rather than their own name, so the provider
+    /// recorded for a collection is the one that produced its vectors. The
+    /// default is [`Self::provider_name`].
+    fn provider_name_for(&self, collection: &str) -> &str {
+        let _ = collection;
+        self.provider_name()
+    }
+
     /// Health check for the provider (default implementation provided)
     async fn health_check(&self) -> Result<()> {
         // Default implementation - try a simple embed operation
@@ -64,4 +64,13 @@ pub trait HybridSearchProvider: Send + Sync {
 
     /// Get hybrid search statistics
     async fn get_stats(&self) -> HashMap<String, serde_json::Value>;
+
+    /// Chunks indexed for a collection
+    ///
+    /// Re-indexing them with [`index_chunks`](Self::index_chunks) restores
+    /// the lexical index, so exports carry this instead of scorer internals.
+    /// Providers without an index return nothing.
+    async fn indexed_chunks(&self, _collection: &str) -> Result<Vec<CodeChunk>> {
+        Ok(Vec::new())
+    }
 }
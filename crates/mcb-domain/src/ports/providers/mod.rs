@@ -32,7 +32,10 @@ pub mod language_chunking;
 pub mod vector_store;
 
 // Re-export provider ports for convenience
-pub use cache::{CacheEntryConfig, CacheProvider, CacheProviderFactoryInterface, CacheStats};
+pub use cache::{
+    CacheEntryConfig, CacheProvider, CacheProviderFactoryInterface, CacheStats, CacheTierStats,
+    NamespaceStats,
+};
 pub use config::ProviderConfigManagerInterface;
 pub use crypto::{CryptoProvider, EncryptedData};
 pub use embedding::EmbeddingProvider;
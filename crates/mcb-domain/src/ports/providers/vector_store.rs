@@ -1,5 +1,7 @@
-use crate::error::Result;
-use crate::value_objects::{CollectionInfo, Embedding, FileInfo, SearchResult};
+use crate::error::{Error, Result};
+use crate::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -56,6 +58,20 @@ pub trait VectorStoreAdmin: Send + Sync {
     /// Ok(()) if flush completed successfully, Error if flush failed
     async fn flush(&self, collection: &str) -> Result<()>;
 
+    /// List the names of every collection in the store
+    ///
+    /// The default fails; stores that can enumerate their collections
+    /// override it.
+    ///
+    /// # Returns
+    /// Ok(names) of all collections, Error if the store cannot list them
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        Err(Error::vector_db(format!(
+            "The {} vector store cannot list its collections",
+            self.provider_name()
+        )))
+    }
+
     /// Get the name/identifier of this vector store provider
     ///
     /// # Returns
@@ -133,6 +149,28 @@ pub trait VectorStoreProvider: VectorStoreAdmin + Send + Sync {
         metadata: Vec<HashMap<String, Value>>,
     ) -> Result<Vec<String>>;
 
+    /// Insert vectors under caller-supplied IDs, replacing existing ones
+    ///
+    /// Unlike [`insert_vectors`](Self::insert_vectors), the store keeps the
+    /// given IDs, so writing the same chunk twice leaves a single vector.
+    /// Search results and lookups report these IDs.
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to write to
+    /// * `ids` - One ID per vector, typically [`CodeChunk::content_id`](crate::entities::CodeChunk::content_id)
+    /// * `vectors` - Slice of embedding vectors to write
+    /// * `metadata` - Vector of metadata maps, one per vector
+    ///
+    /// # Returns
+    /// Ok(()) if every vector was written, Error if the write failed
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()>;
+
     /// Search for vectors similar to a query vector
     ///
     /// # Arguments
@@ -161,6 +199,53 @@ pub trait VectorStoreProvider: VectorStoreAdmin + Send + Sync {
     /// Ok(()) if all vectors were deleted successfully, Error if deletion failed
     async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()>;
 
+    /// Delete every vector whose metadata matches a filter
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to delete from
+    /// * `filter` - Conditions the metadata must meet; an empty filter is rejected
+    ///
+    /// # Returns
+    /// Ok(count) with the number of vectors deleted, Error if deletion failed
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64>;
+
+    /// Delete every chunk of a file
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to delete from
+    /// * `file_path` - Path stored in the `file_path` metadata of the chunks
+    ///
+    /// # Returns
+    /// Ok(count) with the number of vectors deleted, Error if deletion failed
+    async fn delete_by_file(&self, collection: &str, file_path: &str) -> Result<u64> {
+        self.delete_by_filter(collection, &MetadataFilter::file_path(file_path))
+            .await
+    }
+
+    /// List the ids of every chunk of a file
+    ///
+    /// Lets re-indexing keep a file's unchanged chunks and delete only the
+    /// ones that disappeared. The default scans
+    /// [`export_vectors`](Self::export_vectors); stores that can look chunks
+    /// up by file override it.
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to search in
+    /// * `file_path` - Path stored in the `file_path` metadata of the chunks
+    ///
+    /// # Returns
+    /// Ok(ids) of the file's chunks, in no particular order
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        let filter = MetadataFilter::file_path(file_path);
+        Ok(self
+            .export_vectors(collection)
+            .await?
+            .into_iter()
+            .filter(|vector| filter.matches(&vector.metadata))
+            .map(|vector| vector.id)
+            .collect())
+    }
+
     /// Retrieve vectors by their IDs
     ///
     /// # Arguments
@@ -184,6 +269,57 @@ pub trait VectorStoreProvider: VectorStoreAdmin + Send + Sync {
     /// # Returns
     /// Ok(vector_of_results) containing the vectors in the collection
     async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>>;
+
+    /// Read every vector of a collection with its id and full metadata
+    ///
+    /// Used to export collections. Stores that cannot read their vectors
+    /// back keep the default, which reports the operation as unsupported.
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to read
+    ///
+    /// # Returns
+    /// Ok(vectors) with one entry per stored vector, in no particular order
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        Err(Error::vector_db(format!(
+            "The {} vector store cannot export collection '{}'",
+            self.provider_name(),
+            collection
+        )))
+    }
+
+    /// Read one page of a collection's vectors in a stable store order
+    ///
+    /// Pages are keyed by the id of the last vector returned rather than an
+    /// offset, so vectors written or deleted between calls do not shift later
+    /// pages. The default reads the whole collection through
+    /// [`export_vectors`](Self::export_vectors) and orders it by id; stores
+    /// that can seek override it and may use their own order.
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to read
+    /// * `after` - Id of the last vector of the previous page, `None` to start
+    /// * `limit` - Maximum number of vectors to return
+    ///
+    /// # Returns
+    /// Ok(vectors) following `after` in store order; fewer than `limit`
+    /// vectors means the collection is exhausted
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        let mut vectors: Vec<StoredVector> = self
+            .export_vectors(collection)
+            .await?
+            .into_iter()
+            .filter(|vector| after.is_none_or(|after| vector.id.as_str() > after))
+            .collect();
+        vectors.sort_by(|a, b| a.id.cmp(&b.id));
+        vectors.truncate(limit);
+        Ok(vectors)
+    }
 }
 
 /// Vector Store Browse Operations for Admin UI
@@ -4,6 +4,8 @@
 //! concepts for similarity search and text understanding.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
 /// Value Object: Semantic Text Embedding
 ///
@@ -37,3 +39,31 @@ pub struct Embedding {
     /// Dimensionality of the embedding vector
     pub dimensions: usize,
 }
+
+/// Value Object: Stored Vector
+///
+/// A vector as held by a vector store, with the id and the full metadata
+/// it was written with. Used to move collections between stores.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::StoredVector;
+/// use std::collections::HashMap;
+///
+/// let stored = StoredVector {
+///     id: "chunk_abc123".to_string(),
+///     vector: vec![0.1, 0.2, 0.3],
+///     metadata: HashMap::from([("file_path".to_string(), "src/lib.rs".into())]),
+/// };
+/// assert_eq!(stored.vector.len(), 3);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoredVector {
+    /// Id the vector was written under
+    pub id: String,
+    /// The vector values
+    pub vector: Vec<f32>,
+    /// Metadata written with the vector
+    pub metadata: HashMap<String, Value>,
+}
@@ -9,7 +9,9 @@
 //! | Value Object | Description |
 //! |--------------|-------------|
 //! | [`Embedding`] | Vector representation of text for semantic search |
+//! | [`StoredVector`] | Vector with the id and metadata a store holds for it |
 //! | [`SearchResult`] | Ranked result from semantic search operation |
+//! | [`MetadataFilter`] | Equality conditions on stored vector metadata |
 //! | [`Language`] | Programming language identifier |
 //! | [`OperationType`] | Operation type for metrics and rate limiting |
 //! | [`CollectionInfo`] | Metadata about an indexed collection |
@@ -29,8 +31,8 @@ pub mod types;
 // Re-export commonly used value objects
 pub use browse::{CollectionInfo, FileInfo};
 pub use config::{CacheConfig, EmbeddingConfig, VectorStoreConfig};
-pub use embedding::Embedding;
-pub use search::SearchResult;
+pub use embedding::{Embedding, StoredVector};
+pub use search::{MetadataFilter, SearchResult};
 pub use types::{
     CacheProviderKind, EmbeddingProviderKind, Language, OperationType, VectorStoreProviderKind,
 };
@@ -3,8 +3,11 @@
 //! Value objects representing search results and related concepts
 //! for semantic search operations.
 
+use crate::error::{Error, Result};
 use crate::value_objects::Language;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
 
 /// Value Object: Ranked Search Result
 ///
@@ -47,3 +50,76 @@ pub struct SearchResult {
     /// Programming language of the matched code
     pub language: Language,
 }
+
+/// Value Object: Metadata Filter
+///
+/// Equality conditions on the metadata stored with each vector. A vector
+/// matches when every condition holds, so an empty filter matches
+/// everything and is refused by destructive operations.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::MetadataFilter;
+///
+/// let filter = MetadataFilter::file_path("src/auth/login.rs").with("language", "rust");
+/// assert_eq!(filter.conditions().len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MetadataFilter {
+    conditions: BTreeMap<String, Value>,
+}
+
+impl MetadataFilter {
+    /// Filter matching the chunks of one file
+    pub fn file_path(path: impl Into<String>) -> Self {
+        Self::default().with("file_path", path.into())
+    }
+
+    /// Add a condition requiring `key` to equal `value`
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.conditions.insert(key.into(), value.into());
+        self
+    }
+
+    /// Filter from a search filter string
+    ///
+    /// The string is a JSON object of equality conditions, e.g.
+    /// `{"file_path": "src/lib.rs", "kind": "fn"}`.
+    pub fn parse(filter: &str) -> Result<Self> {
+        let conditions: serde_json::Map<String, Value> = serde_json::from_str(filter)
+            .map_err(|e| Error::invalid_argument(format!("Invalid search filter: {}", e)))?;
+        Ok(Self {
+            conditions: conditions.into_iter().collect(),
+        })
+    }
+
+    /// Conditions by metadata key
+    pub fn conditions(&self) -> &BTreeMap<String, Value> {
+        &self.conditions
+    }
+
+    /// Whether `metadata` satisfies every condition
+    pub fn matches(&self, metadata: &HashMap<String, Value>) -> bool {
+        self.conditions
+            .iter()
+            .all(|(key, value)| metadata.get(key) == Some(value))
+    }
+
+    /// Whether a JSON object satisfies every condition
+    pub fn matches_value(&self, metadata: &Value) -> bool {
+        self.conditions
+            .iter()
+            .all(|(key, value)| metadata.get(key) == Some(value))
+    }
+
+    /// Refuse a filter without conditions, which would match every vector
+    pub fn ensure_not_empty(&self) -> Result<()> {
+        if self.conditions.is_empty() {
+            return Err(Error::invalid_argument(
+                "Metadata filter needs at least one condition",
+            ));
+        }
+        Ok(())
+    }
+}
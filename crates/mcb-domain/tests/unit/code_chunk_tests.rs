@@ -76,4 +76,36 @@ mod tests {
         assert_eq!(chunk.metadata["complexity"], 2);
         assert!(chunk.metadata["methods"].is_array());
     }
+
+    #[test]
+    fn test_content_id_is_stable_for_the_same_chunk() {
+        let content = "fn hello() {}";
+        let id = CodeChunk::content_id("src/main.rs", 1, 3, content);
+
+        assert_eq!(id, CodeChunk::content_id("src/main.rs", 1, 3, content));
+        assert_eq!(id.len(), 64);
+        assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_content_id_changes_with_path_range_or_content() {
+        let id = CodeChunk::content_id("src/main.rs", 1, 3, "fn hello() {}");
+
+        assert_ne!(
+            id,
+            CodeChunk::content_id("src/lib.rs", 1, 3, "fn hello() {}")
+        );
+        assert_ne!(
+            id,
+            CodeChunk::content_id("src/main.rs", 2, 3, "fn hello() {}")
+        );
+        assert_ne!(
+            id,
+            CodeChunk::content_id("src/main.rs", 1, 4, "fn hello() {}")
+        );
+        assert_ne!(
+            id,
+            CodeChunk::content_id("src/main.rs", 1, 3, "fn hello() { }")
+        );
+    }
 }
@@ -68,6 +68,8 @@ fn test_domain_event_variants() {
 
     let cache_invalidate = DomainEvent::CacheInvalidate {
         namespace: Some("embeddings".to_string()),
+        key: None,
+        origin: None,
     };
 
     let snapshot_created = DomainEvent::SnapshotCreated {
@@ -159,7 +161,11 @@ async fn test_publish_multiple_events() {
             path: "/path".to_string(),
             files_changed: 5,
         },
-        DomainEvent::CacheInvalidate { namespace: None },
+        DomainEvent::CacheInvalidate {
+            namespace: None,
+            key: None,
+            origin: None,
+        },
     ];
 
     for event in events {
@@ -194,3 +200,76 @@ async fn test_event_serialization() {
     let deserialized: DomainEvent = serde_json::from_str(&json).unwrap();
     assert_eq!(event, deserialized);
 }
+
+#[test]
+fn test_event_name_and_collection() {
+    let event = DomainEvent::IndexingCompleted {
+        collection: "alpha".to_string(),
+        chunks: 10,
+        duration_ms: 5,
+    };
+    assert_eq!(event.name(), "IndexingCompleted");
+    assert_eq!(event.collection(), Some("alpha"));
+
+    let event = DomainEvent::IndexRebuild { collection: None };
+    assert_eq!(event.name(), "IndexRebuild");
+    assert_eq!(event.collection(), None);
+
+    let event = DomainEvent::ConfigReloaded {
+        section: "cache".to_string(),
+        timestamp: chrono::Utc::now(),
+    };
+    assert_eq!(event.collection(), None);
+}
+
+#[test]
+fn test_cache_invalidations_are_not_durable() {
+    let event = DomainEvent::CacheInvalidate {
+        namespace: Some("search-alpha".to_string()),
+        key: None,
+        origin: Some("instance-a".to_string()),
+    };
+    assert!(!event.is_durable());
+
+    let event = DomainEvent::IndexRebuild { collection: None };
+    assert!(event.is_durable());
+}
+
+#[test]
+fn test_event_query_matches() {
+    use mcb_domain::ports::infrastructure::{EventQuery, StoredEvent};
+
+    let stored = StoredEvent {
+        sequence: 7,
+        timestamp: chrono::Utc::now(),
+        event: DomainEvent::IndexingStarted {
+            collection: "alpha".to_string(),
+            total_files: 3,
+        },
+    };
+
+    assert!(EventQuery::default().matches(&stored));
+    assert!(
+        EventQuery {
+            event_type: Some("IndexingStarted".to_string()),
+            collection: Some("alpha".to_string()),
+            after_sequence: Some(6),
+            ..EventQuery::default()
+        }
+        .matches(&stored)
+    );
+    assert!(
+        !EventQuery {
+            collection: Some("beta".to_string()),
+            ..EventQuery::default()
+        }
+        .matches(&stored)
+    );
+    assert!(
+        !EventQuery {
+            after_sequence: Some(7),
+            ..EventQuery::default()
+        }
+        .matches(&stored)
+    );
+}
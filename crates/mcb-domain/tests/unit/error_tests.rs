@@ -1,6 +1,7 @@
 //! Unit tests for domain error types
 
 use mcb_domain::Error;
+use std::time::Duration;
 
 #[test]
 fn test_error_creation() {
@@ -28,6 +29,15 @@ fn test_invalid_argument_error() {
     }
 }
 
+#[test]
+fn test_conflict_error() {
+    let error = Error::conflict("Collection exists");
+    match error {
+        Error::Conflict { message } => assert_eq!(message, "Collection exists"),
+        _ => panic!("Expected Conflict error"),
+    }
+}
+
 #[test]
 fn test_embedding_error() {
     let error = Error::embedding("Model not available");
@@ -134,6 +144,25 @@ fn test_network_error() {
     }
 }
 
+#[test]
+fn test_unavailable_error() {
+    let error = Error::unavailable("Rate limit exceeded", Some(Duration::from_secs(5)));
+    assert_eq!(
+        error.to_string(),
+        "Provider unavailable: Rate limit exceeded"
+    );
+    match error {
+        Error::Unavailable {
+            message,
+            retry_after,
+        } => {
+            assert_eq!(message, "Rate limit exceeded");
+            assert_eq!(retry_after, Some(Duration::from_secs(5)));
+        }
+        _ => panic!("Expected Unavailable error"),
+    }
+}
+
 #[test]
 fn test_database_error() {
     let error = Error::database("Query failed");
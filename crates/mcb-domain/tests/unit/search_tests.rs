@@ -1,8 +1,10 @@
-//! Unit tests for SearchResult value object
+//! Unit tests for SearchResult and MetadataFilter value objects
 
 #[cfg(test)]
 mod tests {
-    use mcb_domain::SearchResult;
+    use mcb_domain::{MetadataFilter, SearchResult};
+    use serde_json::json;
+    use std::collections::HashMap;
 
     #[test]
     fn test_search_result_creation() {
@@ -108,4 +110,58 @@ mod tests {
 
         assert_eq!(result.score, 1.0);
     }
+
+    #[test]
+    fn test_metadata_filter_matches_all_conditions() {
+        let metadata = HashMap::from([
+            ("file_path".to_string(), json!("src/lib.rs")),
+            ("language".to_string(), json!("rust")),
+            ("start_line".to_string(), json!(10)),
+        ]);
+
+        assert!(MetadataFilter::file_path("src/lib.rs").matches(&metadata));
+        assert!(
+            MetadataFilter::file_path("src/lib.rs")
+                .with("start_line", 10)
+                .matches(&metadata)
+        );
+        assert!(
+            !MetadataFilter::file_path("src/lib.rs")
+                .with("language", "python")
+                .matches(&metadata)
+        );
+        assert!(
+            !MetadataFilter::default()
+                .with("missing", "x")
+                .matches(&metadata)
+        );
+        assert!(
+            MetadataFilter::file_path("src/lib.rs")
+                .matches_value(&json!({"file_path": "src/lib.rs", "language": "rust"}))
+        );
+    }
+
+    #[test]
+    fn test_empty_metadata_filter_is_refused() {
+        assert!(MetadataFilter::default().ensure_not_empty().is_err());
+        assert!(MetadataFilter::file_path("a.rs").ensure_not_empty().is_ok());
+    }
+
+    #[test]
+    fn test_metadata_filter_parses_json_object() {
+        let filter = MetadataFilter::parse(r#"{"file_path": "src/lib.rs", "start_line": 10}"#)
+            .expect("parse");
+        assert_eq!(
+            filter,
+            MetadataFilter::file_path("src/lib.rs").with("start_line", 10)
+        );
+        assert!(
+            MetadataFilter::parse("{}")
+                .expect("empty")
+                .conditions()
+                .is_empty()
+        );
+        assert!(MetadataFilter::parse("file_path").is_err());
+        assert!(MetadataFilter::parse(r#"["src/lib.rs"]"#).is_err());
+    }
 }
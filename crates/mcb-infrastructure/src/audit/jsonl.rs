@@ -0,0 +1,220 @@
+//! JSONL audit log with size-based rotation
+//!
+//! The active file is `audit.jsonl` in the configured directory. When a
+//! write would push it past `max_file_size`, it is renamed to
+//! `audit.1.jsonl`, older files shift up by one, and files beyond
+//! `max_files` are deleted.
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::infrastructure::{AuditEvent, AuditLogInterface, AuditQuery};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::config::AuditConfig;
+use crate::constants::{AUDIT_LOG_FILE_NAME, AUDIT_QUERY_DEFAULT_LIMIT, AUDIT_QUERY_MAX_LIMIT};
+
+/// Currently open audit file and its size
+struct ActiveFile {
+    file: tokio::fs::File,
+    size: u64,
+}
+
+/// Append-only audit log stored as rotated JSONL files
+pub struct JsonlAuditLog {
+    directory: PathBuf,
+    max_file_size: u64,
+    max_files: usize,
+    active: Mutex<Option<ActiveFile>>,
+}
+
+impl JsonlAuditLog {
+    /// Create an audit log writing into `directory`
+    ///
+    /// The directory and file are created on the first write.
+    pub fn new(directory: impl Into<PathBuf>, max_file_size: u64, max_files: usize) -> Self {
+        Self {
+            directory: directory.into(),
+            max_file_size,
+            max_files,
+            active: Mutex::new(None),
+        }
+    }
+
+    /// Build from configuration; `None` when auditing is disabled
+    pub fn from_config(config: &AuditConfig) -> Option<Self> {
+        config.enabled.then(|| {
+            Self::new(
+                config.directory.clone(),
+                config.max_file_size,
+                config.max_files,
+            )
+        })
+    }
+
+    /// Path of the active file
+    pub fn current_path(&self) -> PathBuf {
+        self.directory.join(AUDIT_LOG_FILE_NAME)
+    }
+
+    /// Path of the `n`-th rotated file (1 is the most recent)
+    fn rotated_path(&self, n: usize) -> PathBuf {
+        let (stem, ext) = AUDIT_LOG_FILE_NAME
+            .rsplit_once('.')
+            .unwrap_or((AUDIT_LOG_FILE_NAME, "jsonl"));
+        self.directory.join(format!("{}.{}.{}", stem, n, ext))
+    }
+
+    async fn open_active(&self) -> Result<ActiveFile> {
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to create audit directory", e))?;
+
+        let path = self.current_path();
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .map_err(|e| {
+                Error::io_with_source(format!("Failed to open audit log {}", path.display()), e)
+            })?;
+        let size = file
+            .metadata()
+            .await
+            .map_err(|e| Error::io_with_source("Failed to stat audit log", e))?
+            .len();
+
+        Ok(ActiveFile { file, size })
+    }
+
+    /// Shift rotated files up by one and move the active file to slot 1
+    async fn rotate(&self) -> Result<()> {
+        if self.max_files == 0 {
+            return remove_if_exists(&self.current_path()).await;
+        }
+
+        remove_if_exists(&self.rotated_path(self.max_files)).await?;
+        for n in (1..self.max_files).rev() {
+            rename_if_exists(&self.rotated_path(n), &self.rotated_path(n + 1)).await?;
+        }
+        rename_if_exists(&self.current_path(), &self.rotated_path(1)).await
+    }
+
+    /// Files to scan for queries, newest first
+    fn files_newest_first(&self) -> Vec<PathBuf> {
+        std::iter::once(self.current_path())
+            .chain((1..=self.max_files).map(|n| self.rotated_path(n)))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AuditLogInterface for JsonlAuditLog {
+    async fn record(&self, event: AuditEvent) -> Result<()> {
+        let mut line = serde_json::to_vec(&event)?;
+        line.push(b'\n');
+
+        let mut active = self.active.lock().await;
+
+        let needs_rotation = active
+            .as_ref()
+            .is_some_and(|a| a.size > 0 && a.size + line.len() as u64 > self.max_file_size);
+        if needs_rotation {
+            *active = None;
+            self.rotate().await?;
+        }
+
+        if active.is_none() {
+            let opened = self.open_active().await?;
+            // A file left over from a previous run may already be full
+            if opened.size > 0 && opened.size + line.len() as u64 > self.max_file_size {
+                drop(opened);
+                self.rotate().await?;
+                *active = Some(self.open_active().await?);
+            } else {
+                *active = Some(opened);
+            }
+        }
+
+        let Some(current) = active.as_mut() else {
+            return Err(Error::internal("Audit log file not open"));
+        };
+        current
+            .file
+            .write_all(&line)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to write audit event", e))?;
+        current
+            .file
+            .flush()
+            .await
+            .map_err(|e| Error::io_with_source("Failed to flush audit log", e))?;
+        current.size += line.len() as u64;
+        Ok(())
+    }
+
+    async fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEvent>> {
+        let limit = query
+            .limit
+            .unwrap_or(AUDIT_QUERY_DEFAULT_LIMIT)
+            .min(AUDIT_QUERY_MAX_LIMIT);
+
+        // Hold the writer lock so a rotation cannot move files mid-scan
+        let _guard = self.active.lock().await;
+
+        let mut events = Vec::new();
+        for path in self.files_newest_first() {
+            let content = match tokio::fs::read_to_string(&path).await {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    return Err(Error::io_with_source(
+                        format!("Failed to read audit log {}", path.display()),
+                        e,
+                    ));
+                }
+            };
+
+            for line in content.lines().rev().filter(|l| !l.trim().is_empty()) {
+                match serde_json::from_str::<AuditEvent>(line) {
+                    Ok(event) if query.matches(&event) => {
+                        events.push(event);
+                        if events.len() >= limit {
+                            return Ok(events);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        warn!(path = %path.display(), error = %e, "Skipping malformed audit line")
+                    }
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+async fn remove_if_exists(path: &Path) -> Result<()> {
+    match tokio::fs::remove_file(path).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(Error::io_with_source(
+            format!("Failed to remove {}", path.display()),
+            e,
+        )),
+    }
+}
+
+async fn rename_if_exists(from: &Path, to: &Path) -> Result<()> {
+    match tokio::fs::rename(from, to).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(Error::io_with_source(
+            format!("Failed to rotate {}", from.display()),
+            e,
+        )),
+    }
+}
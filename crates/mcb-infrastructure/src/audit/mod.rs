@@ -0,0 +1,16 @@
+//! Audit logging
+//!
+//! Append-only record of MCP tool calls and admin mutations, enabled with
+//! `system.infrastructure.audit.enabled`. Events are written as one JSON
+//! object per line and rotated by size.
+//!
+//! | Type | Description |
+//! |------|-------------|
+//! | [`JsonlAuditLog`] | Size-rotated JSONL implementation of `AuditLogInterface` |
+//! | [`redact_arguments`] | Masks secret-looking argument values before logging |
+
+mod jsonl;
+mod redact;
+
+pub use jsonl::JsonlAuditLog;
+pub use redact::redact_arguments;
@@ -0,0 +1,35 @@
+//! Argument redaction for audit records
+
+use serde_json::Value;
+
+use crate::constants::{AUDIT_REDACTED_KEYS, AUDIT_REDACTED_VALUE};
+
+/// Copy `value`, replacing the values of secret-looking keys
+///
+/// A key is secret-looking when its lowercase form contains one of
+/// [`AUDIT_REDACTED_KEYS`]. Nested objects and arrays are walked.
+pub fn redact_arguments(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let redacted = if is_secret_key(key) && !value.is_null() {
+                        Value::String(AUDIT_REDACTED_VALUE.to_string())
+                    } else {
+                        redact_arguments(value)
+                    };
+                    (key.clone(), redacted)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_arguments).collect()),
+        other => other.clone(),
+    }
+}
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    AUDIT_REDACTED_KEYS
+        .iter()
+        .any(|pattern| key.contains(pattern))
+}
@@ -0,0 +1,19 @@
+//! Local user authentication
+//!
+//! Per-user credentials stored in the file configured by `auth.user_db_path`.
+//! Users log in with a password and receive HS256 access/refresh tokens
+//! signed with `auth.jwt.secret`.
+//!
+//! | Type | Description |
+//! |------|-------------|
+//! | [`UserStore`] | JSON-file user database with atomic writes |
+//! | [`UserAuthService`] | Login, token refresh and user management |
+//! | [`Principal`] | Authenticated caller with `collection:<glob>:<level>` scopes |
+
+mod scopes;
+mod service;
+mod users;
+
+pub use scopes::{AccessLevel, CollectionScope, Principal, default_scopes_for_role, parse_scopes};
+pub use service::{MIN_PASSWORD_LENGTH, TokenPair, UserAuthService, UserUpdate};
+pub use users::{User, UserInfo, UserRole, UserStore};
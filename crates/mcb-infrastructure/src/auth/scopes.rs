@@ -0,0 +1,223 @@
+//! Collection authorization scopes
+//!
+//! Scopes have the form `collection:<glob>:<level>`, where `<glob>` is a
+//! shell-style pattern over user-facing collection names and `<level>` is
+//! one of `read`, `write` or `admin`. Higher levels imply lower ones.
+//!
+//! | Level | Grants |
+//! |-------|--------|
+//! | `read` | `search_code`, browse API |
+//! | `write` | `index_codebase` plus everything in `read` |
+//! | `admin` | `clear_index` plus everything in `write` |
+
+use glob::Pattern;
+use mcb_domain::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::users::UserRole;
+
+/// Access level required by an operation on a collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessLevel {
+    /// Search and browse
+    Read,
+    /// Index into the collection
+    Write,
+    /// Clear or otherwise destroy the collection
+    Admin,
+}
+
+impl AccessLevel {
+    /// Level name as used in scope strings
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl fmt::Display for AccessLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AccessLevel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "read" => Ok(Self::Read),
+            "write" => Ok(Self::Write),
+            "admin" => Ok(Self::Admin),
+            other => Err(Error::invalid_argument(format!(
+                "Unknown access level '{}': expected read, write or admin",
+                other
+            ))),
+        }
+    }
+}
+
+/// A parsed `collection:<glob>:<level>` scope
+#[derive(Debug, Clone)]
+pub struct CollectionScope {
+    pattern: Pattern,
+    level: AccessLevel,
+}
+
+impl CollectionScope {
+    /// Glob pattern over collection names
+    pub fn pattern(&self) -> &str {
+        self.pattern.as_str()
+    }
+
+    /// Granted access level
+    pub fn level(&self) -> AccessLevel {
+        self.level
+    }
+
+    /// Whether this scope grants `level` on `collection`
+    pub fn allows(&self, collection: &str, level: AccessLevel) -> bool {
+        self.level >= level && self.pattern.matches(collection)
+    }
+
+    /// Whether this scope covers every collection at admin level
+    pub fn is_global_admin(&self) -> bool {
+        self.level == AccessLevel::Admin && self.pattern.as_str() == "*"
+    }
+}
+
+impl FromStr for CollectionScope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            Error::invalid_argument(format!(
+                "Invalid scope '{}': expected collection:<glob>:read|write|admin",
+                s
+            ))
+        };
+
+        let rest = s.strip_prefix("collection:").ok_or_else(invalid)?;
+        let (glob, level) = rest.rsplit_once(':').ok_or_else(invalid)?;
+        if glob.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            pattern: Pattern::new(glob).map_err(|e| {
+                Error::invalid_argument(format!("Invalid scope pattern '{}': {}", glob, e))
+            })?,
+            level: level.parse()?,
+        })
+    }
+}
+
+impl fmt::Display for CollectionScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "collection:{}:{}", self.pattern.as_str(), self.level)
+    }
+}
+
+/// Parse a list of scope strings, failing on the first invalid entry
+pub fn parse_scopes<S: AsRef<str>>(scopes: &[S]) -> Result<Vec<CollectionScope>> {
+    scopes.iter().map(|s| s.as_ref().parse()).collect()
+}
+
+/// Default scopes for a role when a user has no explicit scopes
+pub fn default_scopes_for_role(role: UserRole) -> Vec<String> {
+    let level = match role {
+        UserRole::Admin => AccessLevel::Admin,
+        UserRole::Editor => AccessLevel::Write,
+        UserRole::Viewer => AccessLevel::Read,
+    };
+    vec![format!("collection:*:{}", level)]
+}
+
+/// An authenticated caller and the scopes it holds
+#[derive(Debug, Clone)]
+pub struct Principal {
+    subject: String,
+    scopes: Vec<CollectionScope>,
+    role: Option<UserRole>,
+    unrestricted: bool,
+}
+
+impl Principal {
+    /// A principal limited to the given scopes
+    pub fn new(subject: impl Into<String>, scopes: Vec<CollectionScope>) -> Self {
+        Self {
+            subject: subject.into(),
+            scopes,
+            role: None,
+            unrestricted: false,
+        }
+    }
+
+    /// Attach the role of the user this principal was issued for
+    pub fn with_role(mut self, role: UserRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// A principal with access to everything
+    ///
+    /// Used when authorization is disabled and for the shared admin key.
+    pub fn unrestricted(subject: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            scopes: Vec::new(),
+            role: None,
+            unrestricted: true,
+        }
+    }
+
+    /// Principal name (username, API key name or `anonymous`)
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Scopes held by this principal
+    pub fn scopes(&self) -> &[CollectionScope] {
+        &self.scopes
+    }
+
+    /// Role of the user behind this principal, if it is a user
+    pub fn role(&self) -> Option<UserRole> {
+        self.role
+    }
+
+    /// Whether this principal may perform `level` operations on `collection`
+    pub fn can(&self, collection: &str, level: AccessLevel) -> bool {
+        self.unrestricted || self.scopes.iter().any(|s| s.allows(collection, level))
+    }
+
+    /// Whether this principal may use instance-wide admin endpoints
+    pub fn is_admin(&self) -> bool {
+        self.unrestricted || self.scopes.iter().any(CollectionScope::is_global_admin)
+    }
+
+    /// Whether this principal may manage user accounts
+    ///
+    /// Scopes alone are not enough: only users with the `admin` role (and
+    /// unrestricted principals) qualify.
+    pub fn is_user_admin(&self) -> bool {
+        self.unrestricted || self.role == Some(UserRole::Admin)
+    }
+
+    /// Keep only the collections this principal may read
+    pub fn filter_readable<T, F>(&self, items: Vec<T>, name: F) -> Vec<T>
+    where
+        F: Fn(&T) -> &str,
+    {
+        items
+            .into_iter()
+            .filter(|item| self.can(name(item), AccessLevel::Read))
+            .collect()
+    }
+}
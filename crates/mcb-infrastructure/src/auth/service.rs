@@ -0,0 +1,377 @@
+//! User authentication service
+//!
+//! Combines the [`UserStore`], [`PasswordService`] and [`JwtService`] into
+//! login, token refresh and user management operations.
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::infrastructure::AuthServiceInterface;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::scopes::{Principal, parse_scopes};
+use super::users::{User, UserInfo, UserRole, UserStore, now_secs};
+use crate::config::AuthConfig;
+use crate::crypto::{JwtClaims, JwtService, JwtTokenKind, PasswordService};
+
+/// Minimum accepted password length
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Password hashed once to give unknown users something to verify against
+const DUMMY_PASSWORD: &str = "mcb-login-timing-guard";
+
+/// Access/refresh token pair returned by login and refresh
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenPair {
+    /// Token to present as `Authorization: Bearer <token>`
+    pub access_token: String,
+    /// Token to exchange for a new pair once the access token expires
+    pub refresh_token: String,
+    /// Always `Bearer`
+    pub token_type: String,
+    /// Access token lifetime in seconds
+    pub expires_in: u64,
+}
+
+/// Partial update of a user record
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserUpdate {
+    /// New password
+    #[serde(default)]
+    pub password: Option<String>,
+    /// New role
+    #[serde(default)]
+    pub role: Option<UserRole>,
+    /// Replace explicit scopes (an empty list restores role defaults)
+    #[serde(default)]
+    pub scopes: Option<Vec<String>>,
+    /// Enable or disable the user
+    #[serde(default)]
+    pub disabled: Option<bool>,
+}
+
+/// Login and user management backed by the local user database
+pub struct UserAuthService {
+    store: Arc<UserStore>,
+    passwords: PasswordService,
+    jwt: JwtService,
+    /// Verified against for unknown users, so they cost as much as known ones
+    dummy_hash: String,
+}
+
+impl UserAuthService {
+    /// Create a service from its parts
+    pub fn new(store: Arc<UserStore>, passwords: PasswordService, jwt: JwtService) -> Self {
+        let dummy_hash = passwords.hash_password(DUMMY_PASSWORD).unwrap_or_default();
+        Self {
+            store,
+            passwords,
+            jwt,
+            dummy_hash,
+        }
+    }
+
+    /// Build the service from configuration
+    ///
+    /// Returns `Ok(None)` when `auth.user_db_path` is not set.
+    pub async fn from_config(config: &AuthConfig) -> Result<Option<Self>> {
+        let Some(path) = &config.user_db_path else {
+            return Ok(None);
+        };
+
+        let store = UserStore::open(path.clone()).await?;
+        let jwt = JwtService::new(
+            &config.jwt.secret,
+            config.jwt.expiration_secs,
+            config.jwt.refresh_expiration_secs,
+        )?;
+
+        Ok(Some(Self::new(
+            Arc::new(store),
+            PasswordService::with_algorithm(config.password_algorithm),
+            jwt,
+        )))
+    }
+
+    /// Underlying user store
+    pub fn store(&self) -> &Arc<UserStore> {
+        &self.store
+    }
+
+    /// Verify credentials and issue a token pair
+    ///
+    /// Unknown users, wrong passwords and disabled users all produce the same
+    /// error, and a password is verified in every case, so neither the
+    /// response nor its timing reveals which usernames exist.
+    pub async fn login(&self, username: &str, password: &str) -> Result<TokenPair> {
+        let invalid = || Error::authentication("Invalid username or password");
+
+        let user = self.store.get(username).await;
+        let hash = user
+            .as_ref()
+            .map_or(self.dummy_hash.as_str(), |u| u.password_hash.as_str());
+        let verified = self
+            .passwords
+            .verify_password(password, hash)
+            .unwrap_or(false);
+        let Some(user) = user.filter(|u| verified && !u.disabled) else {
+            return Err(invalid());
+        };
+
+        // Transparently migrate hashes after a password_algorithm change
+        if self.passwords.needs_rehash(&user.password_hash) {
+            let new_hash = self.passwords.hash_password(password)?;
+            self.store
+                .update(username, |u, _| {
+                    u.password_hash = new_hash;
+                    Ok(())
+                })
+                .await?;
+        }
+
+        self.issue_pair(&user)
+    }
+
+    /// Exchange a refresh token for a new token pair
+    ///
+    /// The role in the new tokens reflects the user's current record.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<TokenPair> {
+        let claims = self.jwt.validate(refresh_token, JwtTokenKind::Refresh)?;
+        let user = self.active_user(&claims.sub).await?;
+        self.issue_pair(&user)
+    }
+
+    /// Validate an access token and return its claims
+    ///
+    /// Tokens of deleted or disabled users are rejected even before expiry.
+    pub async fn authenticate(&self, access_token: &str) -> Result<JwtClaims> {
+        let mut claims = self.jwt.validate(access_token, JwtTokenKind::Access)?;
+        let user = self.active_user(&claims.sub).await?;
+        claims.role = user.role.as_str().to_string();
+        Ok(claims)
+    }
+
+    /// Resolve an access token to a principal carrying the user's scopes
+    pub async fn principal_for_token(&self, access_token: &str) -> Result<Principal> {
+        let claims = self.jwt.validate(access_token, JwtTokenKind::Access)?;
+        let user = self.active_user(&claims.sub).await?;
+        Ok(Principal::new(
+            user.username.clone(),
+            parse_scopes(&user.effective_scopes())?,
+        )
+        .with_role(user.role))
+    }
+
+    /// List all users
+    pub async fn list_users(&self) -> Vec<UserInfo> {
+        self.store.list().await.iter().map(UserInfo::from).collect()
+    }
+
+    /// Get a single user
+    pub async fn get_user(&self, username: &str) -> Result<UserInfo> {
+        self.store
+            .get(username)
+            .await
+            .map(|u| UserInfo::from(&u))
+            .ok_or_else(|| Error::not_found(format!("User '{}'", username)))
+    }
+
+    /// Create a new user with the role's default scopes
+    pub async fn create_user(
+        &self,
+        username: &str,
+        password: &str,
+        role: UserRole,
+    ) -> Result<UserInfo> {
+        self.create_user_with_scopes(username, password, role, Vec::new())
+            .await
+    }
+
+    /// Create a new user with explicit collection scopes
+    pub async fn create_user_with_scopes(
+        &self,
+        username: &str,
+        password: &str,
+        role: UserRole,
+        scopes: Vec<String>,
+    ) -> Result<UserInfo> {
+        let user = self.new_user(username, password, role, scopes)?;
+        let info = UserInfo::from(&user);
+        self.store.insert(user).await?;
+        Ok(info)
+    }
+
+    /// Update password, role, scopes or disabled flag of a user
+    ///
+    /// Refuses changes that would leave no enabled admin.
+    pub async fn update_user(&self, username: &str, update: UserUpdate) -> Result<UserInfo> {
+        if let Some(scopes) = &update.scopes {
+            parse_scopes(scopes)?;
+        }
+
+        let new_hash = match &update.password {
+            Some(password) => {
+                validate_password(password)?;
+                Some(self.passwords.hash_password(password)?)
+            }
+            None => None,
+        };
+
+        let user = self
+            .store
+            .update(username, |user, all| {
+                let loses_admin = user.role == UserRole::Admin
+                    && !user.disabled
+                    && (update.role.is_some_and(|r| r != UserRole::Admin)
+                        || update.disabled == Some(true));
+                if loses_admin && count_active_admins(all) <= 1 {
+                    return Err(Error::invalid_argument(
+                        "Cannot demote or disable the last active admin",
+                    ));
+                }
+
+                if let Some(hash) = new_hash {
+                    user.password_hash = hash;
+                }
+                if let Some(role) = update.role {
+                    user.role = role;
+                }
+                if let Some(scopes) = update.scopes {
+                    user.scopes = scopes;
+                }
+                if let Some(disabled) = update.disabled {
+                    user.disabled = disabled;
+                }
+                Ok(())
+            })
+            .await?;
+
+        Ok(UserInfo::from(&user))
+    }
+
+    /// Delete a user
+    ///
+    /// Refuses to delete the last enabled admin.
+    pub async fn delete_user(&self, username: &str) -> Result<()> {
+        self.store
+            .remove_if(username, |user, all| {
+                if user.role == UserRole::Admin && !user.disabled && count_active_admins(all) <= 1 {
+                    return Err(Error::invalid_argument(
+                        "Cannot delete the last active admin",
+                    ));
+                }
+                Ok(())
+            })
+            .await
+            .map(|_| ())
+    }
+
+    /// Create the first admin user
+    ///
+    /// Fails if any enabled admin already exists, so it is safe to run
+    /// repeatedly from provisioning scripts.
+    pub async fn bootstrap_admin(&self, username: &str, password: &str) -> Result<UserInfo> {
+        let user = self.new_user(username, password, UserRole::Admin, Vec::new())?;
+        let info = UserInfo::from(&user);
+        self.store
+            .insert_if(user, |all| {
+                if count_active_admins(all) > 0 {
+                    return Err(Error::invalid_argument(format!(
+                        "An admin user already exists in {}",
+                        self.store.path().display()
+                    )));
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(info)
+    }
+
+    /// Validate and hash a new user record
+    fn new_user(
+        &self,
+        username: &str,
+        password: &str,
+        role: UserRole,
+        scopes: Vec<String>,
+    ) -> Result<User> {
+        validate_username(username)?;
+        validate_password(password)?;
+        parse_scopes(&scopes)?;
+
+        let now = now_secs();
+        Ok(User {
+            username: username.to_string(),
+            password_hash: self.passwords.hash_password(password)?,
+            role,
+            scopes,
+            disabled: false,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    async fn active_user(&self, username: &str) -> Result<User> {
+        match self.store.get(username).await {
+            Some(user) if !user.disabled => Ok(user),
+            _ => Err(Error::authentication("User no longer active")),
+        }
+    }
+
+    fn issue_pair(&self, user: &User) -> Result<TokenPair> {
+        let role = user.role.as_str();
+        Ok(TokenPair {
+            access_token: self.jwt.issue(&user.username, role, JwtTokenKind::Access)?,
+            refresh_token: self
+                .jwt
+                .issue(&user.username, role, JwtTokenKind::Refresh)?,
+            token_type: "Bearer".to_string(),
+            expires_in: self.jwt.expiration_secs(),
+        })
+    }
+}
+
+#[async_trait]
+impl AuthServiceInterface for UserAuthService {
+    async fn validate_token(&self, token: &str) -> Result<bool> {
+        Ok(self.authenticate(token).await.is_ok())
+    }
+
+    async fn generate_token(&self, subject: &str) -> Result<String> {
+        let user = self.active_user(subject).await?;
+        self.jwt
+            .issue(&user.username, user.role.as_str(), JwtTokenKind::Access)
+    }
+}
+
+fn count_active_admins(users: &std::collections::BTreeMap<String, User>) -> usize {
+    users
+        .values()
+        .filter(|u| u.role == UserRole::Admin && !u.disabled)
+        .count()
+}
+
+fn validate_username(username: &str) -> Result<()> {
+    let valid = !username.is_empty()
+        && username.len() <= 64
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '@'));
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::invalid_argument(
+            "Username must be 1-64 characters of letters, digits, '_', '-', '.' or '@'",
+        ))
+    }
+}
+
+fn validate_password(password: &str) -> Result<()> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(Error::invalid_argument(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        )));
+    }
+    Ok(())
+}
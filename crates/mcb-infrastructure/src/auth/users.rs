@@ -0,0 +1,324 @@
+//! Local user database
+//!
+//! File-backed store of users, roles and password hashes. The whole database
+//! is a single JSON document that is rewritten on every mutation using
+//! write-to-temp-then-rename, so a crash never leaves a half-written file.
+
+use mcb_domain::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+use super::scopes::default_scopes_for_role;
+
+/// Current on-disk format version
+const USER_DB_VERSION: u32 = 1;
+
+/// Role assigned to a user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    /// Full access including user management and admin endpoints
+    Admin,
+    /// Can index and clear collections
+    Editor,
+    /// Read-only search access
+    Viewer,
+}
+
+impl UserRole {
+    /// Role name as stored in tokens
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Admin => "admin",
+            Self::Editor => "editor",
+            Self::Viewer => "viewer",
+        }
+    }
+}
+
+impl std::str::FromStr for UserRole {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "admin" => Ok(Self::Admin),
+            "editor" => Ok(Self::Editor),
+            "viewer" => Ok(Self::Viewer),
+            other => Err(Error::invalid_argument(format!("Unknown role: {}", other))),
+        }
+    }
+}
+
+/// Stored user record (includes the password hash)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    /// Unique login name
+    pub username: String,
+    /// Password hash in a self-describing format
+    pub password_hash: String,
+    /// Assigned role
+    pub role: UserRole,
+    /// Explicit collection scopes; empty means the role's defaults
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Disabled users cannot log in and their tokens are rejected
+    #[serde(default)]
+    pub disabled: bool,
+    /// Creation time (seconds since UNIX epoch)
+    pub created_at: u64,
+    /// Last modification time (seconds since UNIX epoch)
+    pub updated_at: u64,
+}
+
+impl User {
+    /// Scopes in effect for this user
+    pub fn effective_scopes(&self) -> Vec<String> {
+        if self.scopes.is_empty() {
+            default_scopes_for_role(self.role)
+        } else {
+            self.scopes.clone()
+        }
+    }
+}
+
+/// Public view of a user, safe to return from APIs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserInfo {
+    /// Unique login name
+    pub username: String,
+    /// Assigned role
+    pub role: UserRole,
+    /// Scopes in effect (explicit or role defaults)
+    pub scopes: Vec<String>,
+    /// Whether the user is disabled
+    pub disabled: bool,
+    /// Creation time (seconds since UNIX epoch)
+    pub created_at: u64,
+    /// Last modification time (seconds since UNIX epoch)
+    pub updated_at: u64,
+}
+
+impl From<&User> for UserInfo {
+    fn from(user: &User) -> Self {
+        Self {
+            username: user.username.clone(),
+            role: user.role,
+            scopes: user.effective_scopes(),
+            disabled: user.disabled,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+        }
+    }
+}
+
+/// On-disk document
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct UserDatabase {
+    version: u32,
+    users: BTreeMap<String, User>,
+}
+
+/// File-backed user store
+pub struct UserStore {
+    path: PathBuf,
+    users: RwLock<BTreeMap<String, User>>,
+}
+
+impl UserStore {
+    /// Open the user database at `path`, starting empty if the file does not exist
+    pub async fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let users = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => {
+                let db: UserDatabase =
+                    serde_json::from_str(&content).map_err(|e| Error::Infrastructure {
+                        message: format!("Failed to parse user database {}: {}", path.display(), e),
+                        source: Some(Box::new(e)),
+                    })?;
+                if db.version > USER_DB_VERSION {
+                    return Err(Error::configuration(format!(
+                        "User database {} has unsupported version {}",
+                        path.display(),
+                        db.version
+                    )));
+                }
+                db.users
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => {
+                return Err(Error::io_with_source(
+                    format!("Failed to read user database {}", path.display()),
+                    e,
+                ));
+            }
+        };
+
+        Ok(Self {
+            path,
+            users: RwLock::new(users),
+        })
+    }
+
+    /// Path of the backing file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Look up a user by name
+    pub async fn get(&self, username: &str) -> Option<User> {
+        self.users.read().await.get(username).cloned()
+    }
+
+    /// List all users ordered by name
+    pub async fn list(&self) -> Vec<User> {
+        self.users.read().await.values().cloned().collect()
+    }
+
+    /// Number of enabled admin users
+    pub async fn active_admin_count(&self) -> usize {
+        self.users
+            .read()
+            .await
+            .values()
+            .filter(|u| u.role == UserRole::Admin && !u.disabled)
+            .count()
+    }
+
+    /// Insert a new user; fails if the name is taken
+    pub async fn insert(&self, user: User) -> Result<()> {
+        self.insert_if(user, |_| Ok(())).await
+    }
+
+    /// Insert a new user if `check` accepts the current users
+    ///
+    /// `check` runs under the same write lock as the insert, so no
+    /// concurrent change slips in between.
+    pub async fn insert_if<F>(&self, user: User, check: F) -> Result<()>
+    where
+        F: FnOnce(&BTreeMap<String, User>) -> Result<()>,
+    {
+        let mut users = self.users.write().await;
+        if users.contains_key(&user.username) {
+            return Err(Error::invalid_argument(format!(
+                "User '{}' already exists",
+                user.username
+            )));
+        }
+        check(&users)?;
+        let mut next = users.clone();
+        next.insert(user.username.clone(), user);
+        self.commit(&mut users, next).await
+    }
+
+    /// Apply `update` to an existing user and persist the result
+    ///
+    /// `update` may reject the change by returning an error; the store is
+    /// left untouched in that case.
+    pub async fn update<F>(&self, username: &str, update: F) -> Result<User>
+    where
+        F: FnOnce(&mut User, &BTreeMap<String, User>) -> Result<()>,
+    {
+        let mut users = self.users.write().await;
+        let mut user = users
+            .get(username)
+            .cloned()
+            .ok_or_else(|| Error::not_found(format!("User '{}'", username)))?;
+        update(&mut user, &users)?;
+        user.updated_at = now_secs();
+        let mut next = users.clone();
+        next.insert(username.to_string(), user.clone());
+        self.commit(&mut users, next).await?;
+        Ok(user)
+    }
+
+    /// Remove a user, returning the removed record
+    pub async fn remove(&self, username: &str) -> Result<User> {
+        self.remove_if(username, |_, _| Ok(())).await
+    }
+
+    /// Remove a user if `check` accepts it, returning the removed record
+    ///
+    /// `check` receives the user and all users, and runs under the same
+    /// write lock as the removal, so no concurrent change slips in between.
+    pub async fn remove_if<F>(&self, username: &str, check: F) -> Result<User>
+    where
+        F: FnOnce(&User, &BTreeMap<String, User>) -> Result<()>,
+    {
+        let mut users = self.users.write().await;
+        let user = users
+            .get(username)
+            .ok_or_else(|| Error::not_found(format!("User '{}'", username)))?;
+        check(user, &users)?;
+        let mut next = users.clone();
+        let user = next
+            .remove(username)
+            .ok_or_else(|| Error::not_found(format!("User '{}'", username)))?;
+        self.commit(&mut users, next).await?;
+        Ok(user)
+    }
+
+    /// Persist `next` and only then make it the in-memory state
+    ///
+    /// A failed write leaves memory matching the file on disk.
+    async fn commit(
+        &self,
+        users: &mut BTreeMap<String, User>,
+        next: BTreeMap<String, User>,
+    ) -> Result<()> {
+        self.persist(&next).await?;
+        *users = next;
+        Ok(())
+    }
+
+    async fn persist(&self, users: &BTreeMap<String, User>) -> Result<()> {
+        let db = UserDatabase {
+            version: USER_DB_VERSION,
+            users: users.clone(),
+        };
+        let content = serde_json::to_vec_pretty(&db)?;
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                Error::io_with_source("Failed to create user database directory", e)
+            })?;
+        }
+
+        let tmp_path = self.path.with_extension("tmp");
+        write_private(&tmp_path, &content).await?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to replace user database", e))
+    }
+}
+
+/// Write the file readable by the owner only; it contains password hashes
+async fn write_private(path: &Path, content: &[u8]) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let mut file = options
+        .open(path)
+        .await
+        .map_err(|e| Error::io_with_source("Failed to write user database", e))?;
+    file.write_all(content)
+        .await
+        .map_err(|e| Error::io_with_source("Failed to write user database", e))?;
+    file.sync_all()
+        .await
+        .map_err(|e| Error::io_with_source("Failed to sync user database", e))
+}
+
+/// Current time in seconds since UNIX epoch
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
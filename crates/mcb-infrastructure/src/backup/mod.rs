@@ -0,0 +1,25 @@
+//! Scheduled Backups
+//!
+//! Periodically writes every collection, the snapshot directory and
+//! registered state files (such as the collection name mapping) into
+//! timestamped archives under `system.data.backup.directory`, keeps the
+//! newest `max_backups`, and restores them on request.
+//!
+//! ## Archive Layout
+//!
+//! | Entry | Contents |
+//! |-------|----------|
+//! | `backup.json` | Format version, creation time and collection records |
+//! | `collections/<name>.bundle` | One collection bundle (see [`CollectionBundler`](crate::collections::CollectionBundler)) |
+//! | `snapshots/...` | Files of the snapshot directory |
+//! | `files/<name>` | Registered state files |
+//!
+//! The archive is streamed into a temporary file that is renamed into place
+//! once complete. It is gzip-compressed when `compress` is set and encrypted
+//! with [`CryptoService`](crate::crypto::CryptoService) when `encrypt` is set.
+
+mod service;
+
+pub use service::{
+    BACKUP_FORMAT_VERSION, BackupInfo, BackupManifest, BackupService, RestoreReport,
+};
@@ -0,0 +1,934 @@
+//! Backup service
+
+use async_trait::async_trait;
+use chrono::Utc;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::admin::{
+    DependencyHealth, DependencyHealthCheck, LifecycleManaged, PortServiceState,
+};
+use mcb_domain::ports::providers::EncryptedData;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{BufWriter, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::{info, warn};
+
+use crate::collections::{CollectionBundler, CollectionGuard, CollectionRecord, ExportOptions};
+use crate::config::BackupConfig;
+use crate::constants::{AES_GCM_KEY_SIZE, KDF_SALT_SIZE, PBKDF2_ITERATIONS};
+use crate::crypto::{CryptoService, KeyDerivation};
+
+/// Current backup format version
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Name reported to the service manager
+const SERVICE_NAME: &str = "backup";
+
+const BACKUP_FILE_PREFIX: &str = "mcb-backup-";
+const MANIFEST_ENTRY: &str = "backup.json";
+const COLLECTIONS_DIR: &str = "collections/";
+const SNAPSHOTS_DIR: &str = "snapshots/";
+const FILES_DIR: &str = "files/";
+
+/// Starts an encrypted archive whose key is derived from the passphrase
+/// with PBKDF2 and the salt that follows
+const KDF_HEADER_MAGIC: &[u8] = b"MCBBKDF1";
+
+/// Contents of `backup.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Backup format version
+    pub version: u32,
+    /// Creation time (seconds since UNIX epoch)
+    pub created_at: u64,
+    /// Records of the backed up collections
+    pub collections: Vec<CollectionRecord>,
+    /// Names of the backed up state files
+    pub files: Vec<String>,
+}
+
+/// A backup archive in the backup directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackupInfo {
+    /// File name
+    pub name: String,
+    /// Size in bytes
+    pub size: u64,
+    /// Whether the archive is gzip-compressed
+    pub compressed: bool,
+    /// Whether the archive is encrypted
+    pub encrypted: bool,
+}
+
+/// Outcome of a restore
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RestoreReport {
+    /// Restored backup
+    pub backup: String,
+    /// Collections imported from the backup
+    pub restored: Vec<String>,
+    /// Collections left alone because they already exist
+    pub skipped: Vec<String>,
+    /// Files written back to the snapshot directory
+    pub snapshot_files: usize,
+    /// State files written back
+    pub files: Vec<String>,
+    /// Snapshot and state entries left alone because no collection was
+    /// restored and `overwrite` was not set
+    pub skipped_files: Vec<String>,
+}
+
+/// Backs up collections, snapshots and state files on a schedule
+///
+/// Collections are written as collection bundles, so a backup restores
+/// into any vector store as long as the active embedding provider matches.
+/// Backups and restores never run concurrently.
+pub struct BackupService {
+    backups: Arc<Backups>,
+    interval: Duration,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+/// State shared with the scheduled task
+struct Backups {
+    guard: Arc<CollectionGuard>,
+    bundler: CollectionBundler,
+    directory: PathBuf,
+    max_backups: usize,
+    compress: bool,
+    /// Passphrase encryption keys are derived from, one salt per backup
+    passphrase: Option<String>,
+    snapshot_directory: Option<PathBuf>,
+    files: BTreeMap<String, PathBuf>,
+    /// Held for the duration of a backup or restore
+    running: tokio::sync::Mutex<()>,
+    last_error: Mutex<Option<String>>,
+}
+
+impl BackupService {
+    /// Create a backup service from `config`
+    ///
+    /// # Errors
+    ///
+    /// Fails if encryption is enabled without an encryption key.
+    pub fn new(config: &BackupConfig, guard: Arc<CollectionGuard>) -> Result<Self> {
+        let passphrase = if config.encrypt {
+            let key = config
+                .encryption_key
+                .as_deref()
+                .filter(|key| !key.is_empty())
+                .ok_or_else(|| {
+                    Error::configuration(
+                        "system.data.backup.encrypt is set but no encryption_key is configured",
+                    )
+                })?;
+            Some(key.to_string())
+        } else {
+            None
+        };
+
+        Ok(Self {
+            backups: Arc::new(Backups {
+                bundler: CollectionBundler::new(Arc::clone(&guard)),
+                guard,
+                directory: config.directory.clone(),
+                max_backups: config.max_backups,
+                compress: config.compress,
+                passphrase,
+                snapshot_directory: None,
+                files: BTreeMap::new(),
+                running: tokio::sync::Mutex::new(()),
+                last_error: Mutex::new(None),
+            }),
+            interval: Duration::from_secs(config.interval_secs.max(1)),
+            task: Mutex::new(None),
+        })
+    }
+
+    /// Include the files under `directory` as snapshots
+    pub fn with_snapshot_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.backups_mut().snapshot_directory = Some(directory.into());
+        self
+    }
+
+    /// Include the file at `path` under `name`
+    ///
+    /// Missing files are skipped; a restore writes the file back to `path`.
+    pub fn with_file(mut self, name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        self.backups_mut().files.insert(name.into(), path.into());
+        self
+    }
+
+    /// Backups in the backup directory, newest first
+    ///
+    /// # Errors
+    ///
+    /// Fails if the backup directory cannot be read.
+    pub async fn list(&self) -> Result<Vec<BackupInfo>> {
+        self.backups.list().await
+    }
+
+    /// Write a backup now and prune old ones
+    ///
+    /// # Errors
+    ///
+    /// Fails if a collection cannot be exported or the archive cannot be
+    /// written.
+    pub async fn run_backup(&self) -> Result<BackupInfo> {
+        self.backups.run().await
+    }
+
+    /// Restore the backup named `name`
+    ///
+    /// Every collection bundle is checked against the active embedding
+    /// provider before anything is written. Existing collections are
+    /// skipped unless `overwrite` is set, in which case the backup is
+    /// imported under a staging name and swapped in once complete.
+    /// Snapshot and state files are written back only when `overwrite` is
+    /// set or at least one collection was restored, so a restore that skips
+    /// every collection leaves the live state alone.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the backup does not exist, is damaged, or holds collections
+    /// from another embedding space, or if overwriting is requested without
+    /// a persistent collection registry.
+    pub async fn restore(&self, name: &str, overwrite: bool) -> Result<RestoreReport> {
+        self.backups.restore(name, overwrite).await
+    }
+
+    /// Builder access before the service is shared with its task
+    fn backups_mut(&mut self) -> &mut Backups {
+        Arc::get_mut(&mut self.backups).expect("backups are not shared before start")
+    }
+
+    fn task(&self) -> MutexGuard<'_, Option<JoinHandle<()>>> {
+        self.task
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for BackupService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackupService")
+            .field("directory", &self.backups.directory)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl LifecycleManaged for BackupService {
+    fn name(&self) -> &str {
+        SERVICE_NAME
+    }
+
+    fn state(&self) -> PortServiceState {
+        match self.task().as_ref() {
+            Some(task) if !task.is_finished() => PortServiceState::Running,
+            _ => PortServiceState::Stopped,
+        }
+    }
+
+    async fn start(&self) -> Result<()> {
+        let mut task = self.task();
+        if task.as_ref().is_some_and(|task| !task.is_finished()) {
+            return Ok(());
+        }
+
+        let backups = Arc::clone(&self.backups);
+        let interval = self.interval;
+        *task = Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = backups.run().await {
+                    warn!(error = %e, "Scheduled backup failed");
+                }
+            }
+        }));
+        info!(
+            directory = %self.backups.directory.display(),
+            interval_secs = interval.as_secs(),
+            "Backup service started"
+        );
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        // Let a running backup finish so no partial archive is left behind
+        let _running = self.backups.running.lock().await;
+        if let Some(task) = self.task().take() {
+            task.abort();
+            info!("Backup service stopped");
+        }
+        Ok(())
+    }
+
+    async fn health_check(&self) -> DependencyHealthCheck {
+        let last_error = self.backups.last_error().clone();
+        DependencyHealthCheck {
+            name: SERVICE_NAME.to_string(),
+            status: if last_error.is_some() {
+                DependencyHealth::Degraded
+            } else {
+                DependencyHealth::Healthy
+            },
+            message: last_error.map(|e| format!("Last backup failed: {e}")),
+            latency_ms: None,
+            last_check: now_secs(),
+        }
+    }
+}
+
+impl Backups {
+    fn last_error(&self) -> MutexGuard<'_, Option<String>> {
+        self.last_error
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    async fn list(&self) -> Result<Vec<BackupInfo>> {
+        let mut entries = match tokio::fs::read_dir(&self.directory).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(Error::io_with_source(
+                    format!(
+                        "Failed to read backup directory {}",
+                        self.directory.display()
+                    ),
+                    e,
+                ));
+            }
+        };
+
+        let mut backups = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| Error::io_with_source("Failed to read backup directory", e))?
+        {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let Some((compressed, encrypted)) = parse_backup_name(&name) else {
+                continue;
+            };
+            let size = entry.metadata().await.map(|m| m.len()).unwrap_or(0);
+            backups.push(BackupInfo {
+                name,
+                size,
+                compressed,
+                encrypted,
+            });
+        }
+        // Names embed the creation time, so they sort chronologically
+        backups.sort_by(|a, b| b.name.cmp(&a.name));
+        Ok(backups)
+    }
+
+    async fn run(&self) -> Result<BackupInfo> {
+        let _running = self.running.lock().await;
+        let result = self.write_backup().await;
+        *self.last_error() = result.as_ref().err().map(ToString::to_string);
+        result
+    }
+
+    async fn write_backup(&self) -> Result<BackupInfo> {
+        let now = Utc::now();
+        let name = format!(
+            "{BACKUP_FILE_PREFIX}{}{}",
+            now.format("%Y%m%dT%H%M%S%3fZ"),
+            self.extension()
+        );
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to create backup directory", e))?;
+        let path = self.directory.join(&name);
+        let tmp_path = self.directory.join(format!(".{name}.tmp"));
+        let created_at = u64::try_from(now.timestamp()).unwrap_or(0);
+        let collections = match self.write_archive(&name, &tmp_path, created_at).await {
+            Ok(collections) => collections,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&tmp_path).await;
+                return Err(e);
+            }
+        };
+        tokio::fs::rename(&tmp_path, &path)
+            .await
+            .map_err(|e| Error::io_with_source(format!("Failed to write backup {name}"), e))?;
+        let size = tokio::fs::metadata(&path)
+            .await
+            .map(|m| m.len())
+            .unwrap_or(0);
+
+        let pruned = self.prune().await?;
+        info!(backup = %name, collections, pruned, "Backup written");
+        Ok(BackupInfo {
+            name,
+            size,
+            compressed: self.compress,
+            encrypted: self.passphrase.is_some(),
+        })
+    }
+
+    /// Write the archive of backup `name` to `path`
+    ///
+    /// Entries are streamed into the archive one at a time; each collection
+    /// is exported to a bundle file beside it first and removed once
+    /// appended. Returns the number of collections backed up.
+    async fn write_archive(&self, name: &str, path: &Path, created_at: u64) -> Result<usize> {
+        // The store, not the registry, knows every collection: ones written
+        // before the registry existed have no record
+        let collections = self
+            .guard
+            .vector_store_provider()
+            .list_collection_names()
+            .await?;
+
+        let file = tokio::fs::File::create(path)
+            .await
+            .map_err(|e| Error::io_with_source(format!("Failed to write backup {name}"), e))?
+            .into_std()
+            .await;
+        let mut archive = ArchiveBuilder::new(file, self.compress, created_at);
+        let mut records = Vec::with_capacity(collections.len());
+        for (index, collection) in collections.into_iter().enumerate() {
+            let bundle_path = self.directory.join(format!(".{name}.{index}.bundle"));
+            let exported = match self
+                .bundler
+                .export(&collection, &bundle_path, ExportOptions::default())
+                .await
+            {
+                Ok(bundle_manifest) => archive
+                    .append_file(
+                        format!("{COLLECTIONS_DIR}{}.bundle", entry_name(&collection)),
+                        bundle_path.clone(),
+                    )
+                    .await
+                    .map(|_| bundle_manifest),
+                Err(e) => Err(e),
+            };
+            let _ = tokio::fs::remove_file(&bundle_path).await;
+            let bundle_manifest = exported?;
+
+            let record = match self.guard.registry().get(&collection).await {
+                Some(record) => record,
+                None => {
+                    let embedding = bundle_manifest.embedding;
+                    CollectionRecord::new(
+                        collection,
+                        embedding.provider,
+                        embedding.model,
+                        embedding.dimensions,
+                    )
+                }
+            };
+            records.push(record);
+        }
+
+        if let Some(directory) = self.snapshot_directory.clone() {
+            let snapshots = tokio::task::spawn_blocking(move || snapshot_files(&directory))
+                .await
+                .map_err(|e| Error::internal(format!("Backup task failed: {e}")))??;
+            for (entry, file_path) in snapshots {
+                archive.append_file(entry, file_path).await?;
+            }
+        }
+        let mut files = Vec::with_capacity(self.files.len());
+        for (file, file_path) in &self.files {
+            if archive
+                .append_file(format!("{FILES_DIR}{file}"), file_path.clone())
+                .await?
+            {
+                files.push(file.clone());
+            }
+        }
+
+        let manifest = BackupManifest {
+            version: BACKUP_FORMAT_VERSION,
+            created_at,
+            collections: records,
+            files,
+        };
+        archive
+            .append_data(
+                MANIFEST_ENTRY.to_string(),
+                serde_json::to_vec_pretty(&manifest)?,
+            )
+            .await?;
+        archive.finish().await?;
+
+        if self.passphrase.is_some() {
+            let archive = tokio::fs::read(path)
+                .await
+                .map_err(|e| Error::io_with_source(format!("Failed to read backup {name}"), e))?;
+            tokio::fs::write(path, self.encrypt(&archive)?)
+                .await
+                .map_err(|e| Error::io_with_source(format!("Failed to write backup {name}"), e))?;
+        }
+        Ok(manifest.collections.len())
+    }
+
+    /// Delete all but the newest `max_backups` backups
+    async fn prune(&self) -> Result<usize> {
+        if self.max_backups == 0 {
+            return Ok(0);
+        }
+        let stale: Vec<BackupInfo> = self
+            .list()
+            .await?
+            .into_iter()
+            .skip(self.max_backups)
+            .collect();
+        for backup in &stale {
+            tokio::fs::remove_file(self.directory.join(&backup.name))
+                .await
+                .map_err(|e| {
+                    Error::io_with_source(format!("Failed to delete backup {}", backup.name), e)
+                })?;
+        }
+        Ok(stale.len())
+    }
+
+    async fn restore(&self, name: &str, overwrite: bool) -> Result<RestoreReport> {
+        let _running = self.running.lock().await;
+        // Only names listed in the directory are accepted
+        let backup = self
+            .list()
+            .await?
+            .into_iter()
+            .find(|b| b.name == name)
+            .ok_or_else(|| Error::not_found(format!("backup '{name}'")))?;
+
+        let archive = tokio::fs::read(self.directory.join(&backup.name))
+            .await
+            .map_err(|e| Error::io_with_source(format!("Failed to read backup {name}"), e))?;
+        let entries = read_tar(&self.open(&archive, &backup)?)?;
+        let manifest: BackupManifest = entries
+            .get(MANIFEST_ENTRY)
+            .ok_or_else(|| invalid_backup(format!("{MANIFEST_ENTRY} is missing")))
+            .and_then(|data| serde_json::from_slice(data).map_err(invalid_backup))?;
+        if manifest.version > BACKUP_FORMAT_VERSION {
+            return Err(invalid_backup(format!(
+                "format version {} is newer than the supported version {}",
+                manifest.version, BACKUP_FORMAT_VERSION
+            )));
+        }
+
+        // Check every bundle before touching any collection
+        let mut bundles = Vec::new();
+        for (entry, data) in entries.range(COLLECTIONS_DIR.to_string()..) {
+            if !entry.starts_with(COLLECTIONS_DIR) {
+                break;
+            }
+            let bundle = self.bundler.verify(data).await?;
+            bundles.push((bundle.collection, data));
+        }
+
+        let mut report = RestoreReport {
+            backup: name.to_string(),
+            ..RestoreReport::default()
+        };
+        let store = self.guard.vector_store_provider();
+        let mut pending = Vec::with_capacity(bundles.len());
+        for (collection, data) in bundles {
+            let exists = self.guard.registry().get(&collection).await.is_some()
+                || store.collection_exists(&collection).await?;
+            if exists && !overwrite {
+                report.skipped.push(collection);
+                continue;
+            }
+            pending.push((collection, data, exists));
+        }
+        if pending.iter().any(|(_, _, exists)| *exists) {
+            self.guard
+                .require_persistent_registry("Replacing a collection")?;
+        }
+
+        for (collection, data, exists) in pending {
+            if exists {
+                // Import beside the live collection, so a failed import
+                // leaves it untouched
+                let staged = CollectionGuard::staging_name(&collection);
+                self.bundler.import_from_bytes(data, Some(&staged)).await?;
+                if let Err(e) = self.guard.swap_in(&collection, &staged).await {
+                    if let Err(e) = store.delete_collection(&staged).await {
+                        warn!(collection = %staged, error = %e, "Failed to drop staged collection");
+                    }
+                    return Err(e);
+                }
+            } else {
+                self.bundler
+                    .import_from_bytes(data, Some(&collection))
+                    .await?;
+            }
+            report.restored.push(collection);
+        }
+
+        // State files are shared by every collection, so they are only
+        // written back when the restore replaced some collection
+        let restore_state = overwrite || !report.restored.is_empty();
+        for (entry, data) in &entries {
+            if let Some(relative) = entry.strip_prefix(SNAPSHOTS_DIR) {
+                let Some(directory) = &self.snapshot_directory else {
+                    continue;
+                };
+                let relative = safe_relative_path(relative)
+                    .ok_or_else(|| invalid_backup(format!("unsafe entry {entry}")))?;
+                if !restore_state {
+                    report.skipped_files.push(entry.clone());
+                    continue;
+                }
+                write_file(&directory.join(relative), data).await?;
+                report.snapshot_files += 1;
+            } else if let Some(file) = entry.strip_prefix(FILES_DIR)
+                && let Some(path) = self.files.get(file)
+            {
+                if !restore_state {
+                    report.skipped_files.push(entry.clone());
+                    continue;
+                }
+                write_file(path, data).await?;
+                report.files.push(file.to_string());
+            }
+        }
+
+        info!(
+            backup = %name,
+            restored = report.restored.len(),
+            skipped = report.skipped.len(),
+            skipped_files = report.skipped_files.len(),
+            "Backup restored"
+        );
+        Ok(report)
+    }
+
+    fn extension(&self) -> &'static str {
+        match (self.compress, self.passphrase.is_some()) {
+            (false, false) => ".tar",
+            (true, false) => ".tar.gz",
+            (false, true) => ".tar.enc",
+            (true, true) => ".tar.gz.enc",
+        }
+    }
+
+    /// Encrypt a finished, possibly compressed, backup archive
+    ///
+    /// AES-GCM seals the archive in one piece, so it is held in memory
+    /// while encrypting. An encrypted archive is stored as
+    /// `KDF_HEADER_MAGIC`, the salt and the nonce, each after a one byte
+    /// length, and the ciphertext.
+    fn encrypt(&self, archive: &[u8]) -> Result<Vec<u8>> {
+        let Some(passphrase) = &self.passphrase else {
+            return Ok(archive.to_vec());
+        };
+
+        let salt = KeyDerivation::generate_salt(KDF_SALT_SIZE);
+        let encrypted = derive_crypto(passphrase, &salt)?.encrypt(archive)?;
+        let mut out = Vec::with_capacity(
+            KDF_HEADER_MAGIC.len()
+                + 2
+                + salt.len()
+                + encrypted.nonce.len()
+                + encrypted.ciphertext.len(),
+        );
+        out.extend_from_slice(KDF_HEADER_MAGIC);
+        push_prefixed(&mut out, &salt)?;
+        push_prefixed(&mut out, &encrypted.nonce)?;
+        out.extend_from_slice(&encrypted.ciphertext);
+        Ok(out)
+    }
+
+    /// Decrypt, then decompress, a backup archive
+    fn open(&self, archive: &[u8], backup: &BackupInfo) -> Result<Vec<u8>> {
+        let archive = if backup.encrypted {
+            let passphrase = self.passphrase.as_deref().ok_or_else(|| {
+                Error::configuration(format!(
+                    "Backup '{}' is encrypted but no encryption key is configured",
+                    backup.name
+                ))
+            })?;
+            let rest = archive
+                .strip_prefix(KDF_HEADER_MAGIC)
+                .ok_or_else(|| invalid_backup("encrypted archive has no key derivation header"))?;
+            let (salt, rest) = split_prefixed(rest)?;
+            let crypto = derive_crypto(passphrase, salt)?;
+            let (nonce, ciphertext) = split_prefixed(rest)?;
+            crypto.decrypt(&EncryptedData::new(ciphertext.to_vec(), nonce.to_vec()))?
+        } else {
+            archive.to_vec()
+        };
+        if !backup.compressed {
+            return Ok(archive);
+        }
+        let mut out = Vec::new();
+        GzDecoder::new(archive.as_slice())
+            .read_to_end(&mut out)
+            .map_err(invalid_backup)?;
+        Ok(out)
+    }
+}
+
+/// Error for a backup that cannot be restored
+fn invalid_backup(reason: impl std::fmt::Display) -> Error {
+    Error::invalid_argument(format!("Invalid backup: {reason}"))
+}
+
+/// Crypto service keyed with PBKDF2 of `passphrase` and `salt`
+fn derive_crypto(passphrase: &str, salt: &[u8]) -> Result<CryptoService> {
+    CryptoService::new(KeyDerivation::pbkdf2(
+        passphrase,
+        salt,
+        PBKDF2_ITERATIONS,
+        AES_GCM_KEY_SIZE,
+    ))
+}
+
+/// Append `field` after a one byte length
+fn push_prefixed(out: &mut Vec<u8>, field: &[u8]) -> Result<()> {
+    let len = u8::try_from(field.len())
+        .map_err(|_| Error::internal("Backup header field is longer than 255 bytes"))?;
+    out.push(len);
+    out.extend_from_slice(field);
+    Ok(())
+}
+
+/// Split a field with a one byte length from the bytes after it
+fn split_prefixed(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (&len, rest) = data
+        .split_first()
+        .ok_or_else(|| invalid_backup("archive is truncated"))?;
+    if rest.len() < usize::from(len) {
+        return Err(invalid_backup("archive is truncated"));
+    }
+    Ok(rest.split_at(usize::from(len)))
+}
+
+/// Compression and encryption flags of a backup file name
+fn parse_backup_name(name: &str) -> Option<(bool, bool)> {
+    let rest = name.strip_prefix(BACKUP_FILE_PREFIX)?;
+    let (rest, encrypted) = match rest.strip_suffix(".enc") {
+        Some(rest) => (rest, true),
+        None => (rest, false),
+    };
+    if rest.ends_with(".tar.gz") {
+        Some((true, encrypted))
+    } else if rest.ends_with(".tar") {
+        Some((false, encrypted))
+    } else {
+        None
+    }
+}
+
+/// Archive entry name for a collection
+fn entry_name(collection: &str) -> String {
+    collection.replace(['/', '\\'], "_")
+}
+
+/// `relative` as a path that stays inside the directory it is joined to
+fn safe_relative_path(relative: &str) -> Option<PathBuf> {
+    let path = Path::new(relative);
+    let safe = path.components().next().is_some()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+    safe.then(|| path.to_path_buf())
+}
+
+/// Every file under `directory` as a `snapshots/` entry and its path
+fn snapshot_files(directory: &Path) -> Result<Vec<(String, PathBuf)>> {
+    if !directory.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(directory).sort_by_file_name() {
+        let entry = entry.map_err(|e| {
+            Error::io(format!(
+                "Failed to read snapshot directory {}: {e}",
+                directory.display()
+            ))
+        })?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(directory) else {
+            continue;
+        };
+        let name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.push((format!("{SNAPSHOTS_DIR}{name}"), entry.into_path()));
+    }
+    Ok(files)
+}
+
+/// Output of a backup archive, gzip-compressed when `compress` is set
+enum ArchiveWriter {
+    Plain(BufWriter<std::fs::File>),
+    Gzip(GzEncoder<BufWriter<std::fs::File>>),
+}
+
+impl ArchiveWriter {
+    fn finish(self) -> std::io::Result<()> {
+        let mut file = match self {
+            Self::Plain(file) => file,
+            Self::Gzip(encoder) => encoder.finish()?,
+        };
+        file.flush()
+    }
+}
+
+impl Write for ArchiveWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(file) => file.write(buf),
+            Self::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(file) => file.flush(),
+            Self::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// Tar archive written to a file from blocking tasks
+struct ArchiveBuilder {
+    builder: Option<tar::Builder<ArchiveWriter>>,
+    mtime: u64,
+}
+
+impl ArchiveBuilder {
+    fn new(file: std::fs::File, compress: bool, mtime: u64) -> Self {
+        let file = BufWriter::new(file);
+        let writer = if compress {
+            ArchiveWriter::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            ArchiveWriter::Plain(file)
+        };
+        Self {
+            builder: Some(tar::Builder::new(writer)),
+            mtime,
+        }
+    }
+
+    async fn append_data(&mut self, name: String, data: Vec<u8>) -> Result<()> {
+        self.run(move |builder, mtime| {
+            builder.append_data(
+                &mut tar_header(data.len() as u64, mtime),
+                &name,
+                data.as_slice(),
+            )
+        })
+        .await
+    }
+
+    /// Append the file at `path` as `name`; returns false if it is missing
+    async fn append_file(&mut self, name: String, path: PathBuf) -> Result<bool> {
+        self.run(move |builder, mtime| {
+            let file = match std::fs::File::open(&path) {
+                Ok(file) => file,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+                Err(e) => return Err(e),
+            };
+            let size = file.metadata()?.len();
+            builder.append_data(&mut tar_header(size, mtime), &name, file)?;
+            Ok(true)
+        })
+        .await
+    }
+
+    async fn finish(mut self) -> Result<()> {
+        let builder = self.take()?;
+        tokio::task::spawn_blocking(move || builder.into_inner().and_then(ArchiveWriter::finish))
+            .await
+            .map_err(|e| Error::internal(format!("Backup task failed: {e}")))?
+            .map_err(|e| Error::io_with_source("Failed to write backup archive", e))
+    }
+
+    /// Run `op` on the builder in a blocking task
+    async fn run<T: Send + 'static>(
+        &mut self,
+        op: impl FnOnce(&mut tar::Builder<ArchiveWriter>, u64) -> std::io::Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let mut builder = self.take()?;
+        let mtime = self.mtime;
+        let (builder, result) = tokio::task::spawn_blocking(move || {
+            let result = op(&mut builder, mtime);
+            (builder, result)
+        })
+        .await
+        .map_err(|e| Error::internal(format!("Backup task failed: {e}")))?;
+        self.builder = Some(builder);
+        result.map_err(|e| Error::io_with_source("Failed to write backup archive", e))
+    }
+
+    fn take(&mut self) -> Result<tar::Builder<ArchiveWriter>> {
+        self.builder
+            .take()
+            .ok_or_else(|| Error::internal("Backup archive was left unfinished"))
+    }
+}
+
+fn tar_header(size: u64, mtime: u64) -> tar::Header {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(size);
+    header.set_mode(0o644);
+    header.set_mtime(mtime);
+    header.set_cksum();
+    header
+}
+
+fn read_tar(archive: &[u8]) -> Result<BTreeMap<String, Vec<u8>>> {
+    let mut entries = BTreeMap::new();
+    let mut tar = tar::Archive::new(archive);
+    for entry in tar.entries().map_err(invalid_backup)? {
+        let mut entry = entry.map_err(invalid_backup)?;
+        let name = entry
+            .path()
+            .map_err(invalid_backup)?
+            .to_string_lossy()
+            .into_owned();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).map_err(invalid_backup)?;
+        entries.insert(name, data);
+    }
+    Ok(entries)
+}
+
+/// Write `data` to `path` with write-to-temp-then-rename
+async fn write_file(path: &Path, data: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await.map_err(|e| {
+            Error::io_with_source(format!("Failed to create {}", parent.display()), e)
+        })?;
+    }
+    let tmp_path = path.with_extension("restore.tmp");
+    tokio::fs::write(&tmp_path, data)
+        .await
+        .map_err(|e| Error::io_with_source(format!("Failed to write {}", path.display()), e))?;
+    tokio::fs::rename(&tmp_path, path)
+        .await
+        .map_err(|e| Error::io_with_source(format!("Failed to write {}", path.display()), e))
+}
+
+/// Current time in seconds since UNIX epoch
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -222,6 +222,16 @@ impl NamespacedCacheProvider {
         self.provider.exists(&namespaced_key).await
     }
 
+    /// List the keys stored in this namespace
+    pub async fn keys(&self) -> Result<Vec<String>> {
+        self.provider.list_keys(&self.namespace).await
+    }
+
+    /// Remove every entry in this namespace, returning how many were removed
+    pub async fn clear(&self) -> Result<u64> {
+        self.provider.clear_namespace(&self.namespace).await
+    }
+
     /// Get the inner cache provider for DI injection
     pub fn inner(&self) -> Arc<dyn CacheProvider> {
         self.provider.clone()
@@ -0,0 +1,786 @@
+//! Portable collection bundles
+//!
+//! A bundle is a tar archive holding one collection:
+//!
+//! - `manifest.json` - format version, embedding model, applied compression
+//!   and encryption, and the size and SHA-256 of every other file as stored
+//! - `vectors.jsonl` - one [`StoredVector`] per line
+//! - `bm25.jsonl` - one [`CodeChunk`] per line, present when the lexical
+//!   index of the collection was exported
+//!
+//! The manifest is always plaintext so a bundle can be inspected and
+//! verified without the key. The other files are gzip-compressed first and
+//! encrypted second; an encrypted file is stored as a one byte nonce length,
+//! the nonce and the ciphertext. Vectors are exported a page at a time, so a
+//! compressed file may hold several concatenated gzip members.
+
+use flate2::Compression;
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{
+    CryptoProvider, EncryptedData, HybridSearchProvider, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{Embedding, StoredVector};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+use tracing::{info, warn};
+
+use super::guard::{CollectionGuard, MIGRATION_PROBE_TEXT};
+use super::registry::CollectionRecord;
+use crate::constants::COLLECTION_IMPORT_BATCH_SIZE;
+use crate::crypto::{CryptoService, bytes_to_hex};
+
+/// Current bundle format version
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const VECTORS_FILE: &str = "vectors.jsonl";
+const BM25_FILE: &str = "bm25.jsonl";
+
+/// Compression applied to the files of a bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BundleCompression {
+    /// Stored as is
+    None,
+    /// gzip
+    Gzip,
+}
+
+/// Embedding space the vectors of a bundle belong to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEmbedding {
+    /// Embedding provider name
+    pub provider: String,
+    /// Embedding model
+    pub model: String,
+    /// Embedding dimensions
+    pub dimensions: usize,
+}
+
+/// A file of a bundle as stored in the archive
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleFile {
+    /// Size in bytes
+    pub size: u64,
+    /// SHA-256 (hex)
+    pub sha256: String,
+}
+
+/// Contents of `manifest.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// Bundle format version
+    pub version: u32,
+    /// Collection the bundle was exported from
+    pub collection: String,
+    /// Embedding space of the vectors
+    pub embedding: BundleEmbedding,
+    /// Number of vectors
+    pub vector_count: usize,
+    /// Number of chunks in the lexical index, if it was exported
+    pub bm25_chunk_count: Option<usize>,
+    /// Compression applied to every file but the manifest
+    pub compression: BundleCompression,
+    /// Crypto provider that encrypted every file but the manifest
+    pub encryption: Option<String>,
+    /// Export time (seconds since UNIX epoch)
+    pub created_at: u64,
+    /// Checksums of every file but the manifest
+    pub files: BTreeMap<String, BundleFile>,
+}
+
+/// How to write a bundle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// gzip the files
+    pub compress: bool,
+    /// Encrypt the files with the bundler's crypto provider
+    pub encrypt: bool,
+}
+
+/// Result of an import
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedBundle {
+    /// Collection the bundle was imported into
+    pub collection: String,
+    /// Manifest of the imported bundle
+    pub manifest: BundleManifest,
+}
+
+/// Exports collections to bundles and imports them into the active stores
+///
+/// Bundles are read and written through the guarded providers, so an
+/// export sees the collection under its client name and an import is
+/// recorded in the collection registry like any other write. An import is
+/// refused unless the active embedding provider produces vectors in the
+/// same space as the bundle.
+pub struct CollectionBundler {
+    guard: Arc<CollectionGuard>,
+    hybrid_search: Option<Arc<dyn HybridSearchProvider>>,
+    crypto: Option<Arc<dyn CryptoProvider>>,
+    batch_size: usize,
+}
+
+impl CollectionBundler {
+    /// Create a bundler over the guarded providers
+    pub fn new(guard: Arc<CollectionGuard>) -> Self {
+        Self {
+            guard,
+            hybrid_search: None,
+            crypto: None,
+            batch_size: COLLECTION_IMPORT_BATCH_SIZE,
+        }
+    }
+
+    /// Export and restore the lexical index along with the vectors
+    pub fn with_hybrid_search(mut self, hybrid_search: Arc<dyn HybridSearchProvider>) -> Self {
+        self.hybrid_search = Some(hybrid_search);
+        self
+    }
+
+    /// Crypto provider for encrypted bundles
+    pub fn with_crypto(mut self, crypto: Arc<dyn CryptoProvider>) -> Self {
+        self.crypto = Some(crypto);
+        self
+    }
+
+    /// Vectors read per page during an export and written per batch
+    /// during an import
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Write `collection` to a bundle at `path`
+    ///
+    /// The bundle is written next to `path` and renamed into place. Vectors
+    /// are read a page at a time and streamed to disk, so only one page is
+    /// held in memory, unless the bundle is encrypted: encryption seals each
+    /// file in one piece. A collection without a registry record is exported
+    /// with the embedding space of the active provider.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the collection does not exist, an unrecorded collection has
+    /// vectors of another dimensionality than the active provider, the store
+    /// cannot export it, or encryption is requested without a crypto
+    /// provider.
+    pub async fn export(
+        &self,
+        collection: &str,
+        path: &Path,
+        options: ExportOptions,
+    ) -> Result<BundleManifest> {
+        let tmp_path = path.with_extension("tmp");
+        let manifest = match self.write_bundle(collection, &tmp_path, options).await {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&tmp_path).await;
+                return Err(e);
+            }
+        };
+        tokio::fs::rename(&tmp_path, path).await.map_err(|e| {
+            Error::io_with_source(format!("Failed to write bundle {}", path.display()), e)
+        })?;
+
+        info!(
+            collection = %collection,
+            vectors = manifest.vector_count,
+            path = %path.display(),
+            "Exported collection bundle"
+        );
+        Ok(manifest)
+    }
+
+    /// Write the bundle of `collection` to `path`
+    ///
+    /// Each file is written to disk next to `path` first, because the
+    /// manifest at the start of the archive lists their checksums.
+    async fn write_bundle(
+        &self,
+        collection: &str,
+        path: &Path,
+        options: ExportOptions,
+    ) -> Result<BundleManifest> {
+        let record = self.guard.registry().get(collection).await;
+        let store = self.guard.vector_store_provider();
+        if record.is_none() && !store.collection_exists(collection).await? {
+            return Err(Error::not_found(format!("Collection '{collection}'")));
+        }
+        let crypto = if options.encrypt {
+            Some(self.crypto()?)
+        } else {
+            None
+        };
+        let compression = if options.compress {
+            BundleCompression::Gzip
+        } else {
+            BundleCompression::None
+        };
+
+        let mut writer = PayloadWriter::create(VECTORS_FILE, path, compression, crypto).await?;
+        let mut vector_count = 0;
+        let mut dimensions = None;
+        let mut after: Option<String> = None;
+        loop {
+            let page = store
+                .export_vectors_page(collection, after.as_deref(), self.batch_size)
+                .await?;
+            dimensions = dimensions.or_else(|| page.first().map(|v| v.vector.len()));
+            vector_count += page.len();
+            writer.write(&to_jsonl(&page)?).await?;
+            match page.last() {
+                Some(last) if page.len() == self.batch_size => after = Some(last.id.clone()),
+                _ => break,
+            }
+        }
+        let mut payloads = vec![writer.finish().await?];
+
+        let chunks = match &self.hybrid_search {
+            Some(hybrid_search) => Some(hybrid_search.indexed_chunks(collection).await?)
+                .filter(|chunks| !chunks.is_empty()),
+            None => None,
+        };
+        if let Some(chunks) = &chunks {
+            let mut writer = PayloadWriter::create(BM25_FILE, path, compression, crypto).await?;
+            writer.write(&to_jsonl(chunks)?).await?;
+            payloads.push(writer.finish().await?);
+        }
+
+        let embedding = match record {
+            Some(record) => BundleEmbedding {
+                provider: record.provider,
+                model: record.model,
+                dimensions: record.dimensions,
+            },
+            None => self.unrecorded_embedding(collection, dimensions).await?,
+        };
+        let manifest = BundleManifest {
+            version: BUNDLE_FORMAT_VERSION,
+            collection: collection.to_string(),
+            embedding,
+            vector_count,
+            bm25_chunk_count: chunks.as_ref().map(Vec::len),
+            compression,
+            encryption: crypto.map(|c| c.provider_name().to_string()),
+            created_at: now_secs(),
+            files: payloads
+                .iter()
+                .map(|payload| (payload.name.to_string(), payload.file.clone()))
+                .collect(),
+        };
+
+        let manifest_json = serde_json::to_vec_pretty(&manifest)?;
+        let files: Vec<(&'static str, PathBuf)> = payloads
+            .iter()
+            .map(|payload| (payload.name, payload.path.clone()))
+            .collect();
+        let archive_path = path.to_path_buf();
+        let mtime = manifest.created_at;
+        tokio::task::spawn_blocking(move || {
+            write_archive(&archive_path, &manifest_json, &files, mtime)
+        })
+        .await
+        .map_err(|e| Error::internal(format!("Bundle task failed: {e}")))??;
+        Ok(manifest)
+    }
+
+    /// Import the bundle at `path` into `collection`, or into the collection
+    /// it was exported from
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bundle is damaged or from a newer format, the active
+    /// embedding provider uses another model or dimensionality, the target
+    /// collection already exists, or the bundle is encrypted and no crypto
+    /// provider is set. A failed import drops the partly written collection.
+    pub async fn import(&self, path: &Path, collection: Option<&str>) -> Result<ImportedBundle> {
+        let archive = tokio::fs::read(path).await.map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                Error::not_found(format!("Bundle {}", path.display()))
+            } else {
+                Error::io_with_source(format!("Failed to read bundle {}", path.display()), e)
+            }
+        })?;
+        let imported = self.import_from_bytes(&archive, collection).await?;
+        info!(
+            collection = %imported.collection,
+            vectors = imported.manifest.vector_count,
+            path = %path.display(),
+            "Imported collection bundle"
+        );
+        Ok(imported)
+    }
+
+    /// Check that a bundle is intact and matches the active embedding
+    /// provider, without importing it
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bundle is damaged or from a newer format, or the active
+    /// embedding provider uses another model or dimensionality.
+    pub async fn verify(&self, archive: &[u8]) -> Result<BundleManifest> {
+        let (manifest, _) = read_archive(archive)?;
+        self.check_embedding(&manifest.embedding).await?;
+        Ok(manifest)
+    }
+
+    /// Import a bundle held in memory
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::import`].
+    pub async fn import_from_bytes(
+        &self,
+        archive: &[u8],
+        collection: Option<&str>,
+    ) -> Result<ImportedBundle> {
+        let (manifest, mut files) = read_archive(archive)?;
+        let target = collection.unwrap_or(&manifest.collection).to_string();
+
+        self.check_embedding(&manifest.embedding).await?;
+
+        let store = self.guard.vector_store_provider();
+        if self.guard.registry().get(&target).await.is_some()
+            || store.collection_exists(&target).await?
+        {
+            return Err(Error::conflict(format!(
+                "Collection '{target}' already exists; import into another collection or delete it first"
+            )));
+        }
+
+        let crypto = match &manifest.encryption {
+            Some(_) => Some(self.crypto()?),
+            None => None,
+        };
+        let vectors: Vec<StoredVector> = from_jsonl(&unseal(
+            &files.remove(VECTORS_FILE).unwrap_or_default(),
+            manifest.compression,
+            crypto,
+        )?)?;
+        if vectors.len() != manifest.vector_count {
+            return Err(invalid_bundle(format!(
+                "it holds {} vectors but the manifest lists {}",
+                vectors.len(),
+                manifest.vector_count
+            )));
+        }
+        let dimensions = manifest.embedding.dimensions;
+        if let Some(vector) = vectors.iter().find(|v| v.vector.len() != dimensions) {
+            return Err(invalid_bundle(format!(
+                "vector '{}' has {} dimensions, expected {}",
+                vector.id,
+                vector.vector.len(),
+                dimensions
+            )));
+        }
+        let chunks: Option<Vec<CodeChunk>> = match files.remove(BM25_FILE) {
+            Some(data) => Some(from_jsonl(&unseal(&data, manifest.compression, crypto)?)?),
+            None => None,
+        };
+
+        store.create_collection(&target, dimensions).await?;
+        if let Err(e) = self
+            .load(&*store, &target, &manifest, &vectors, chunks.as_deref())
+            .await
+        {
+            if let Err(cleanup) = store.delete_collection(&target).await {
+                warn!(collection = %target, error = %cleanup, "Failed to drop partly imported collection");
+            }
+            return Err(e);
+        }
+
+        Ok(ImportedBundle {
+            collection: target,
+            manifest,
+        })
+    }
+
+    fn crypto(&self) -> Result<&dyn CryptoProvider> {
+        self.crypto.as_deref().ok_or_else(|| {
+            Error::configuration("Encrypted bundles need an encryption key, none is configured")
+        })
+    }
+
+    /// Embedding space of a collection the registry has no record of
+    ///
+    /// Such collections were written before the registry existed, so they
+    /// are taken to use the active embedding provider. The stored vectors
+    /// must at least have its dimensionality.
+    async fn unrecorded_embedding(
+        &self,
+        collection: &str,
+        dimensions: Option<usize>,
+    ) -> Result<BundleEmbedding> {
+        let probe = self
+            .guard
+            .embedding_provider()
+            .embed(MIGRATION_PROBE_TEXT)
+            .await?;
+        let dimensions = dimensions.unwrap_or(probe.dimensions);
+        if dimensions != probe.dimensions {
+            return Err(Error::conflict(format!(
+                "Collection '{collection}' has no registry record and its {dimensions} dimension vectors do not match the active embedding provider ({} dimensions)",
+                probe.dimensions
+            )));
+        }
+        warn!(
+            collection,
+            "Collection has no registry record; exporting it as embedded by the active provider"
+        );
+        Ok(BundleEmbedding {
+            provider: self.guard.current_embedding_provider(),
+            model: probe.model,
+            dimensions,
+        })
+    }
+
+    /// Refuse bundles from another embedding space than the active provider
+    async fn check_embedding(&self, expected: &BundleEmbedding) -> Result<()> {
+        let provider = self.guard.current_embedding_provider();
+        let probe = self
+            .guard
+            .embedding_provider()
+            .embed(MIGRATION_PROBE_TEXT)
+            .await?;
+        if provider != expected.provider
+            || probe.model != expected.model
+            || probe.dimensions != expected.dimensions
+        {
+            return Err(Error::conflict(format!(
+                "Bundle was embedded with {}/{} ({} dimensions) but the active embedding provider is {}/{} ({} dimensions); switch the embedding provider before importing",
+                expected.provider,
+                expected.model,
+                expected.dimensions,
+                provider,
+                probe.model,
+                probe.dimensions
+            )));
+        }
+        Ok(())
+    }
+
+    /// Write vectors and lexical index into the freshly created collection
+    async fn load(
+        &self,
+        store: &dyn VectorStoreProvider,
+        collection: &str,
+        manifest: &BundleManifest,
+        vectors: &[StoredVector],
+        chunks: Option<&[CodeChunk]>,
+    ) -> Result<()> {
+        let embedding = &manifest.embedding;
+        // Empty bundles are never written, so record the collection here
+        self.guard
+            .registry()
+            .insert_if_absent(CollectionRecord::new(
+                collection,
+                embedding.provider.clone(),
+                embedding.model.clone(),
+                embedding.dimensions,
+            ))
+            .await?;
+
+        for batch in vectors.chunks(self.batch_size) {
+            let ids: Vec<String> = batch.iter().map(|v| v.id.clone()).collect();
+            let embeddings: Vec<Embedding> = batch
+                .iter()
+                .map(|v| Embedding {
+                    vector: v.vector.clone(),
+                    model: embedding.model.clone(),
+                    dimensions: embedding.dimensions,
+                })
+                .collect();
+            let metadata = batch.iter().map(|v| v.metadata.clone()).collect();
+            store
+                .upsert_vectors(collection, &ids, &embeddings, metadata)
+                .await?;
+        }
+
+        if let (Some(hybrid_search), Some(chunks)) = (&self.hybrid_search, chunks) {
+            hybrid_search.index_chunks(collection, chunks).await?;
+        }
+        store.flush(collection).await
+    }
+}
+
+impl std::fmt::Debug for CollectionBundler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectionBundler")
+            .field("hybrid_search", &self.hybrid_search.is_some())
+            .field("crypto", &self.crypto.is_some())
+            .field("batch_size", &self.batch_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Error for a bundle that cannot be imported as is
+fn invalid_bundle(reason: impl std::fmt::Display) -> Error {
+    Error::invalid_argument(format!("Invalid collection bundle: {reason}"))
+}
+
+fn to_jsonl<T: Serialize>(items: &[T]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for item in items {
+        serde_json::to_writer(&mut out, item)?;
+        out.push(b'\n');
+    }
+    Ok(out)
+}
+
+fn from_jsonl<T: DeserializeOwned>(data: &[u8]) -> Result<Vec<T>> {
+    data.split(|b| *b == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_slice(line).map_err(invalid_bundle))
+        .collect()
+}
+
+/// A bundle file written next to the bundle, removed once dropped
+struct Payload {
+    name: &'static str,
+    path: PathBuf,
+    file: BundleFile,
+}
+
+impl Drop for Payload {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Streams one bundle file to disk, compressing and hashing it on the way
+struct PayloadWriter<'a> {
+    payload: Payload,
+    file: tokio::fs::File,
+    compression: BundleCompression,
+    crypto: Option<&'a dyn CryptoProvider>,
+    /// Compressed data held back for encryption, which seals a file in one piece
+    pending: Vec<u8>,
+    hasher: Sha256,
+}
+
+impl<'a> PayloadWriter<'a> {
+    async fn create(
+        name: &'static str,
+        bundle_path: &Path,
+        compression: BundleCompression,
+        crypto: Option<&'a dyn CryptoProvider>,
+    ) -> Result<Self> {
+        let path = bundle_path.with_extension(format!("{name}.tmp"));
+        let file = tokio::fs::File::create(&path).await.map_err(|e| {
+            Error::io_with_source(format!("Failed to write bundle {}", path.display()), e)
+        })?;
+        Ok(Self {
+            payload: Payload {
+                name,
+                path,
+                file: BundleFile {
+                    size: 0,
+                    sha256: String::new(),
+                },
+            },
+            file,
+            compression,
+            crypto,
+            pending: Vec::new(),
+            hasher: Sha256::new(),
+        })
+    }
+
+    /// Append `data`, compressed as its own gzip member
+    async fn write(&mut self, data: &[u8]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let data = match self.compression {
+            BundleCompression::None => data.to_vec(),
+            BundleCompression::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(data)
+                    .and_then(|()| encoder.finish())
+                    .map_err(|e| Error::io_with_source("Failed to compress bundle", e))?
+            }
+        };
+        if self.crypto.is_some() {
+            self.pending.extend_from_slice(&data);
+            Ok(())
+        } else {
+            self.store(&data).await
+        }
+    }
+
+    /// Encrypt what was held back and flush the file
+    async fn finish(mut self) -> Result<Payload> {
+        if let Some(crypto) = self.crypto {
+            let encrypted = crypto.encrypt(&std::mem::take(&mut self.pending))?;
+            let nonce_len = u8::try_from(encrypted.nonce.len())
+                .map_err(|_| Error::internal("Encryption nonce is longer than 255 bytes"))?;
+            self.store(&[nonce_len]).await?;
+            self.store(&encrypted.nonce).await?;
+            self.store(&encrypted.ciphertext).await?;
+        }
+        self.file.flush().await.map_err(|e| {
+            Error::io_with_source(
+                format!("Failed to write bundle {}", self.payload.path.display()),
+                e,
+            )
+        })?;
+        self.payload.file.sha256 = bytes_to_hex(&self.hasher.finalize());
+        Ok(self.payload)
+    }
+
+    async fn store(&mut self, data: &[u8]) -> Result<()> {
+        self.hasher.update(data);
+        self.payload.file.size += data.len() as u64;
+        self.file.write_all(data).await.map_err(|e| {
+            Error::io_with_source(
+                format!("Failed to write bundle {}", self.payload.path.display()),
+                e,
+            )
+        })
+    }
+}
+
+/// Decrypt, then decompress, one file
+fn unseal(
+    data: &[u8],
+    compression: BundleCompression,
+    crypto: Option<&dyn CryptoProvider>,
+) -> Result<Vec<u8>> {
+    let data = match crypto {
+        Some(crypto) => {
+            let (&nonce_len, rest) = data
+                .split_first()
+                .ok_or_else(|| invalid_bundle("encrypted file is empty"))?;
+            if rest.len() < usize::from(nonce_len) {
+                return Err(invalid_bundle("encrypted file is truncated"));
+            }
+            let (nonce, ciphertext) = rest.split_at(usize::from(nonce_len));
+            crypto.decrypt(&EncryptedData::new(ciphertext.to_vec(), nonce.to_vec()))?
+        }
+        None => data.to_vec(),
+    };
+    match compression {
+        BundleCompression::None => Ok(data),
+        BundleCompression::Gzip => {
+            let mut out = Vec::new();
+            MultiGzDecoder::new(data.as_slice())
+                .read_to_end(&mut out)
+                .map_err(invalid_bundle)?;
+            Ok(out)
+        }
+    }
+}
+
+/// Tar the manifest followed by the payload files into `path`
+fn write_archive(
+    path: &Path,
+    manifest_json: &[u8],
+    files: &[(&str, PathBuf)],
+    mtime: u64,
+) -> Result<()> {
+    let write_error =
+        |e| Error::io_with_source(format!("Failed to write bundle {}", path.display()), e);
+    let file = std::fs::File::create(path).map_err(write_error)?;
+    let mut builder = tar::Builder::new(std::io::BufWriter::new(file));
+    builder
+        .append_data(
+            &mut tar_header(manifest_json.len() as u64, mtime),
+            MANIFEST_FILE,
+            manifest_json,
+        )
+        .map_err(write_error)?;
+    for (name, file_path) in files {
+        let file = std::fs::File::open(file_path).map_err(write_error)?;
+        let size = file.metadata().map_err(write_error)?.len();
+        builder
+            .append_data(&mut tar_header(size, mtime), name, file)
+            .map_err(write_error)?;
+    }
+    builder
+        .into_inner()
+        .and_then(|mut writer| writer.flush())
+        .map_err(write_error)
+}
+
+fn tar_header(size: u64, mtime: u64) -> tar::Header {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(size);
+    header.set_mode(0o644);
+    header.set_mtime(mtime);
+    header.set_cksum();
+    header
+}
+
+/// Untar a bundle and verify every file against the manifest
+///
+/// Returns the manifest and the payload files by name.
+fn read_archive(archive: &[u8]) -> Result<(BundleManifest, BTreeMap<String, Vec<u8>>)> {
+    let mut files = BTreeMap::new();
+    let mut tar = tar::Archive::new(archive);
+    for entry in tar.entries().map_err(invalid_bundle)? {
+        let mut entry = entry.map_err(invalid_bundle)?;
+        let name = entry
+            .path()
+            .map_err(invalid_bundle)?
+            .to_string_lossy()
+            .into_owned();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).map_err(invalid_bundle)?;
+        files.insert(name, data);
+    }
+
+    let manifest: BundleManifest = files
+        .remove(MANIFEST_FILE)
+        .ok_or_else(|| invalid_bundle(format!("{MANIFEST_FILE} is missing")))
+        .and_then(|data| serde_json::from_slice(&data).map_err(invalid_bundle))?;
+    if manifest.version > BUNDLE_FORMAT_VERSION {
+        return Err(invalid_bundle(format!(
+            "format version {} is newer than the supported version {}",
+            manifest.version, BUNDLE_FORMAT_VERSION
+        )));
+    }
+    if !manifest.files.contains_key(VECTORS_FILE) {
+        return Err(invalid_bundle(format!("{VECTORS_FILE} is missing")));
+    }
+
+    for (name, expected) in &manifest.files {
+        let data = files
+            .get(name)
+            .ok_or_else(|| invalid_bundle(format!("{name} is missing")))?;
+        if data.len() as u64 != expected.size || CryptoService::sha256_hex(data) != expected.sha256
+        {
+            return Err(invalid_bundle(format!(
+                "{name} does not match its checksum"
+            )));
+        }
+    }
+    if let Some(name) = files
+        .keys()
+        .find(|name| !manifest.files.contains_key(*name))
+    {
+        return Err(invalid_bundle(format!(
+            "{name} is not listed in the manifest"
+        )));
+    }
+    Ok((manifest, files))
+}
+
+/// Current time in seconds since UNIX epoch
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,575 @@
+//! Guarded provider switching and collection migration
+
+use dashmap::DashMap;
+use mcb_application::ports::registry::{EmbeddingProviderConfig, VectorStoreProviderConfig};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{EmbeddingProvider, VectorStoreProvider};
+use mcb_domain::value_objects::StoredVector;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::{OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock, RwLockReadGuard};
+use tracing::{info, warn};
+
+use super::guarded::{GuardedEmbeddingProvider, GuardedVectorStoreProvider};
+use super::registry::{CollectionRecord, CollectionRegistry};
+use crate::constants::COLLECTION_MIGRATION_BATCH_SIZE;
+use crate::di::handles::{EmbeddingProviderHandle, VectorStoreProviderHandle};
+use crate::di::provider_resolvers::{EmbeddingProviderResolver, VectorStoreProviderResolver};
+
+/// Text embedded once before a migration or bundle import to check the
+/// provider and learn its model name
+pub(super) const MIGRATION_PROBE_TEXT: &str = "fn migration_probe() {}";
+
+/// Suffix of the shadow collections a migration builds
+const SHADOW_SUFFIX: &str = "__m";
+
+/// Suffix of the collections a restore stages before swapping them in
+const STAGING_SUFFIX: &str = "__r";
+
+/// Gates held by a write to one collection
+pub(super) type CollectionGate<'a> = (RwLockReadGuard<'a, ()>, OwnedRwLockReadGuard<()>);
+
+/// Gates held while one collection's batch is read and rewritten
+pub(super) type ExclusiveCollectionGate<'a> = (RwLockReadGuard<'a, ()>, OwnedRwLockWriteGuard<()>);
+
+/// Result of a switch request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SwitchOutcome {
+    /// The new provider is active
+    Switched {
+        /// Provider now in use
+        provider: String,
+    },
+    /// Collections built with another provider, model or dimensionality
+    /// block the switch
+    Refused {
+        /// Collections that would need re-embedding
+        conflicts: Vec<String>,
+    },
+    /// A background migration re-embeds the listed collections and switches
+    /// when done
+    Migrating {
+        /// Collections being re-embedded
+        collections: Vec<String>,
+    },
+}
+
+/// Migration progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationState {
+    /// Shadow collections are being built
+    Running,
+    /// Shadow collections were swapped in and the provider switched
+    Completed,
+    /// The migration stopped; the previous provider is still active
+    Failed,
+}
+
+/// Status of the latest embedding migration
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationStatus {
+    /// Current state
+    pub state: MigrationState,
+    /// Provider being switched to
+    pub provider: String,
+    /// Collections being re-embedded
+    pub collections: Vec<String>,
+    /// Chunks re-embedded so far
+    pub chunks_migrated: usize,
+    /// Failure reason
+    pub error: Option<String>,
+    /// Start time (seconds since UNIX epoch)
+    pub started_at: u64,
+    /// End time (seconds since UNIX epoch)
+    pub finished_at: Option<u64>,
+}
+
+/// Gatekeeper between services and the swappable provider handles
+///
+/// Services use the providers returned by [`Self::embedding_provider`] and
+/// [`Self::vector_store_provider`], which read through the handles on every
+/// call, translate collection names to the vector store collection that
+/// currently holds the data, and refuse to mix vectors from different
+/// embedding spaces in one collection.
+///
+/// Switching the embedding provider is refused while collections built with
+/// an incompatible provider exist, unless a migration is requested. A
+/// migration re-embeds every affected collection into a shadow collection
+/// while the old ones keep serving searches (writes to them are refused),
+/// then swaps the shadows in and switches the provider in one step.
+pub struct CollectionGuard {
+    embedding_resolver: Arc<EmbeddingProviderResolver>,
+    embedding: Arc<EmbeddingProviderHandle>,
+    vector_store_resolver: Arc<VectorStoreProviderResolver>,
+    vector_store: Arc<VectorStoreProviderHandle>,
+    registry: Arc<CollectionRegistry>,
+    batch_size: usize,
+    /// Held shared by every provider call, exclusively by a swap
+    gate: RwLock<()>,
+    /// Per collection: held shared by writes, exclusively while a batch of
+    /// the collection is read and rewritten
+    collection_gates: DashMap<String, Arc<RwLock<()>>>,
+    migration: Mutex<Option<MigrationStatus>>,
+}
+
+impl CollectionGuard {
+    /// Create a guard over the provider handles
+    pub fn new(
+        embedding_resolver: Arc<EmbeddingProviderResolver>,
+        embedding: Arc<EmbeddingProviderHandle>,
+        vector_store_resolver: Arc<VectorStoreProviderResolver>,
+        vector_store: Arc<VectorStoreProviderHandle>,
+        registry: Arc<CollectionRegistry>,
+    ) -> Self {
+        Self {
+            embedding_resolver,
+            embedding,
+            vector_store_resolver,
+            vector_store,
+            registry,
+            batch_size: COLLECTION_MIGRATION_BATCH_SIZE,
+            gate: RwLock::new(()),
+            collection_gates: DashMap::new(),
+            migration: Mutex::new(None),
+        }
+    }
+
+    /// Chunks re-embedded per batch during a migration
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Collection registry
+    pub fn registry(&self) -> &Arc<CollectionRegistry> {
+        &self.registry
+    }
+
+    /// Embedding provider for services
+    pub fn embedding_provider(self: &Arc<Self>) -> Arc<dyn EmbeddingProvider> {
+        Arc::new(GuardedEmbeddingProvider::new(Arc::clone(self)))
+    }
+
+    /// Vector store provider for services
+    pub fn vector_store_provider(self: &Arc<Self>) -> Arc<dyn VectorStoreProvider> {
+        Arc::new(GuardedVectorStoreProvider::new(Arc::clone(self)))
+    }
+
+    /// Name of the active embedding provider
+    pub fn current_embedding_provider(&self) -> String {
+        self.embedding.get().provider_name().to_string()
+    }
+
+    /// Name of the active vector store provider
+    pub fn current_vector_store_provider(&self) -> String {
+        self.vector_store.get().provider_name().to_string()
+    }
+
+    /// Registered embedding providers as (name, description)
+    pub fn available_embedding_providers(&self) -> Vec<(&'static str, &'static str)> {
+        self.embedding_resolver.list_available()
+    }
+
+    /// Registered vector store providers as (name, description)
+    pub fn available_vector_store_providers(&self) -> Vec<(&'static str, &'static str)> {
+        self.vector_store_resolver.list_available()
+    }
+
+    /// Status of the latest migration
+    pub fn migration_status(&self) -> Option<MigrationStatus> {
+        self.migration().clone()
+    }
+
+    /// Switch the embedding provider
+    ///
+    /// Collections recorded with a different provider, model or
+    /// dimensionality make the switch [`SwitchOutcome::Refused`] unless
+    /// `migrate` is set, in which case a background migration starts.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the provider cannot be resolved, a migration is running, or
+    /// a migration is requested without a persistent registry.
+    pub async fn switch_embedding(
+        self: &Arc<Self>,
+        config: &EmbeddingProviderConfig,
+        migrate: bool,
+    ) -> Result<SwitchOutcome> {
+        if self.migration_running() {
+            return Err(Error::invalid_argument(
+                "A collection migration is already running",
+            ));
+        }
+        let provider = self
+            .embedding_resolver
+            .resolve_from_override(config)
+            .map_err(Error::configuration)?;
+        let name = provider.provider_name().to_string();
+        let dimensions = provider.dimensions();
+
+        let conflicts: Vec<CollectionRecord> = self
+            .registry
+            .list()
+            .await
+            .into_iter()
+            .filter(|record| !record.is_compatible(&name, config.model.as_deref(), dimensions))
+            .collect();
+
+        if conflicts.is_empty() {
+            let _swap = self.gate.write().await;
+            self.embedding.set(provider);
+            info!(provider = %name, "Switched embedding provider");
+            return Ok(SwitchOutcome::Switched { provider: name });
+        }
+
+        let collections: Vec<String> = conflicts.iter().map(|r| r.collection.clone()).collect();
+        if !migrate {
+            return Ok(SwitchOutcome::Refused {
+                conflicts: collections,
+            });
+        }
+        self.require_persistent_registry("Collection migration")?;
+
+        {
+            let mut migration = self.migration();
+            if migration
+                .as_ref()
+                .is_some_and(|m| m.state == MigrationState::Running)
+            {
+                return Err(Error::invalid_argument(
+                    "A collection migration is already running",
+                ));
+            }
+            *migration = Some(MigrationStatus {
+                state: MigrationState::Running,
+                provider: name.clone(),
+                collections: collections.clone(),
+                chunks_migrated: 0,
+                error: None,
+                started_at: now_secs(),
+                finished_at: None,
+            });
+        }
+
+        info!(provider = %name, collections = ?collections, "Starting collection migration");
+        tokio::spawn(Arc::clone(self).migrate(provider, conflicts));
+        Ok(SwitchOutcome::Migrating { collections })
+    }
+
+    /// Switch the vector store provider
+    ///
+    /// Refused while any collection is recorded, since its vectors live in
+    /// the current store.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the provider cannot be resolved or a migration is running.
+    pub async fn switch_vector_store(
+        &self,
+        config: &VectorStoreProviderConfig,
+    ) -> Result<SwitchOutcome> {
+        if self.migration_running() {
+            return Err(Error::invalid_argument(
+                "A collection migration is already running",
+            ));
+        }
+        let conflicts: Vec<String> = self
+            .registry
+            .list()
+            .await
+            .into_iter()
+            .map(|record| record.collection)
+            .collect();
+        if !conflicts.is_empty() {
+            return Ok(SwitchOutcome::Refused { conflicts });
+        }
+
+        let provider = self
+            .vector_store_resolver
+            .resolve_from_override(config)
+            .map_err(Error::configuration)?;
+        let name = provider.provider_name().to_string();
+        let _swap = self.gate.write().await;
+        self.vector_store.set(provider);
+        info!(provider = %name, "Switched vector store provider");
+        Ok(SwitchOutcome::Switched { provider: name })
+    }
+
+    /// Refuse `action` when the registry does not survive a restart
+    ///
+    /// Actions that move a collection to another physical name lose it on
+    /// restart unless the registry remembers the move.
+    pub(crate) fn require_persistent_registry(&self, action: &str) -> Result<()> {
+        if !self.registry.is_persistent() {
+            return Err(Error::configuration(format!(
+                "{action} requires a persistent collection registry; set system.data.collections.registry_path"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Name to stage a replacement for `collection` under
+    pub(crate) fn staging_name(collection: &str) -> String {
+        format!("{collection}{STAGING_SUFFIX}{}", now_secs())
+    }
+
+    /// Replace `collection` with the collection imported as `staged`
+    ///
+    /// The registry is pointed at the staged collection under the write
+    /// gate, so no request sees a half-replaced collection. The old
+    /// collection is dropped afterwards. The registry must be persistent,
+    /// or the collection would lose its data on restart.
+    pub(crate) async fn swap_in(&self, collection: &str, staged: &str) -> Result<()> {
+        self.require_persistent_registry("Replacing a collection")?;
+        let replaced = {
+            let _swap = self.gate.write().await;
+            self.registry.rename(staged, collection).await?
+        };
+        let old_physical = replaced.map_or_else(|| collection.to_string(), |r| r.physical_name);
+        let store = self.vector_store.get();
+        if let Err(e) = store.delete_collection(&old_physical).await {
+            warn!(collection = %old_physical, error = %e, "Failed to drop replaced collection");
+        }
+        info!(collection, "Replaced collection");
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------
+    // Used by the guarded providers
+    // ------------------------------------------------------------------
+
+    pub(super) async fn enter(&self) -> tokio::sync::RwLockReadGuard<'_, ()> {
+        self.gate.read().await
+    }
+
+    /// Enter the gate for a write to `collection`
+    pub(super) async fn enter_collection(&self, collection: &str) -> CollectionGate<'_> {
+        let swap = self.gate.read().await;
+        (swap, self.collection_gate(collection).read_owned().await)
+    }
+
+    /// Hold off writes to `collection`, e.g. while a batch is read and
+    /// rewritten; other collections are not affected
+    pub(super) async fn enter_collection_exclusive(
+        &self,
+        collection: &str,
+    ) -> ExclusiveCollectionGate<'_> {
+        let swap = self.gate.read().await;
+        (swap, self.collection_gate(collection).write_owned().await)
+    }
+
+    fn collection_gate(&self, collection: &str) -> Arc<RwLock<()>> {
+        self.collection_gates
+            .entry(collection.to_string())
+            .or_default()
+            .clone()
+    }
+
+    pub(super) fn embedding(&self) -> Arc<dyn EmbeddingProvider> {
+        self.embedding.get()
+    }
+
+    pub(super) fn vector_store(&self) -> Arc<dyn VectorStoreProvider> {
+        self.vector_store.get()
+    }
+
+    /// Refuse writes to collections being migrated
+    pub(super) fn check_writable(&self, collection: &str) -> Result<()> {
+        let migrating = self.migration().as_ref().is_some_and(|m| {
+            m.state == MigrationState::Running && m.collections.iter().any(|c| c == collection)
+        });
+        if migrating {
+            return Err(Error::invalid_argument(format!(
+                "Collection '{collection}' is being migrated to a new embedding provider; retry when the migration completes"
+            )));
+        }
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------
+    // Migration
+    // ------------------------------------------------------------------
+
+    fn migration(&self) -> MutexGuard<'_, Option<MigrationStatus>> {
+        self.migration
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn migration_running(&self) -> bool {
+        self.migration_status()
+            .is_some_and(|m| m.state == MigrationState::Running)
+    }
+
+    fn update_migration(&self, update: impl FnOnce(&mut MigrationStatus)) {
+        if let Some(status) = self.migration().as_mut() {
+            update(status);
+        }
+    }
+
+    async fn migrate(
+        self: Arc<Self>,
+        provider: Arc<dyn EmbeddingProvider>,
+        records: Vec<CollectionRecord>,
+    ) {
+        let store = self.vector_store.get();
+        let mut shadows = Vec::new();
+        let result = self
+            .build_shadows(&store, &provider, &records, &mut shadows)
+            .await;
+
+        let result = match result {
+            Ok(updated) => {
+                let _swap = self.gate.write().await;
+                match self.registry.replace_all(updated).await {
+                    Ok(()) => {
+                        self.embedding.set(Arc::clone(&provider));
+                        Ok(())
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => {
+                for record in &records {
+                    if let Err(e) = store.delete_collection(&record.physical_name).await {
+                        warn!(collection = %record.physical_name, error = %e, "Failed to drop migrated collection");
+                    }
+                }
+                info!(
+                    provider = provider.provider_name(),
+                    "Collection migration completed"
+                );
+                self.update_migration(|status| {
+                    status.state = MigrationState::Completed;
+                    status.finished_at = Some(now_secs());
+                });
+            }
+            Err(e) => {
+                for shadow in &shadows {
+                    if let Err(e) = store.delete_collection(shadow).await {
+                        warn!(collection = %shadow, error = %e, "Failed to drop shadow collection");
+                    }
+                }
+                warn!(error = %e, "Collection migration failed");
+                self.update_migration(|status| {
+                    status.state = MigrationState::Failed;
+                    status.error = Some(e.to_string());
+                    status.finished_at = Some(now_secs());
+                });
+            }
+        }
+    }
+
+    /// Re-embed every collection into a shadow collection
+    ///
+    /// Returns the records pointing at the shadows. Created shadow names
+    /// are pushed to `shadows` as they are created, for cleanup on failure.
+    async fn build_shadows(
+        &self,
+        store: &Arc<dyn VectorStoreProvider>,
+        provider: &Arc<dyn EmbeddingProvider>,
+        records: &[CollectionRecord],
+        shadows: &mut Vec<String>,
+    ) -> Result<Vec<CollectionRecord>> {
+        let probe = provider.embed(MIGRATION_PROBE_TEXT).await?;
+        let suffix = now_secs();
+
+        let mut updated = Vec::with_capacity(records.len());
+        for record in records {
+            let shadow = format!("{}{SHADOW_SUFFIX}{}", record.collection, suffix);
+            store.create_collection(&shadow, probe.dimensions).await?;
+            shadows.push(shadow.clone());
+
+            let mut after: Option<String> = None;
+            loop {
+                let batch = store
+                    .export_vectors_page(&record.physical_name, after.as_deref(), self.batch_size)
+                    .await?;
+                let Some(last) = batch.last() else {
+                    break;
+                };
+                after = Some(last.id.clone());
+
+                let texts = batch
+                    .iter()
+                    .map(|chunk| chunk_content(&record.physical_name, chunk))
+                    .collect::<Result<Vec<_>>>()?;
+                let embeddings = provider.embed_batch(&texts).await?;
+                let count = batch.len();
+                // Keep chunk ids and metadata so references to them survive
+                // the migration
+                let (ids, metadata): (Vec<String>, Vec<_>) = batch
+                    .into_iter()
+                    .map(|chunk| (chunk.id, chunk.metadata))
+                    .unzip();
+                store
+                    .upsert_vectors(&shadow, &ids, &embeddings, metadata)
+                    .await?;
+                self.update_migration(|status| status.chunks_migrated += count);
+                if count < self.batch_size {
+                    break;
+                }
+            }
+
+            updated.push(CollectionRecord {
+                physical_name: shadow,
+                provider: provider.provider_name().to_string(),
+                model: probe.model.clone(),
+                dimensions: probe.dimensions,
+                ..record.clone()
+            });
+        }
+        Ok(updated)
+    }
+}
+
+impl std::fmt::Debug for CollectionGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectionGuard")
+            .field("registry", &self.registry)
+            .field("batch_size", &self.batch_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether `name` is a migration shadow or restore staging copy of
+/// `collection`
+pub(super) fn is_working_copy(name: &str, collection: &str) -> bool {
+    name.strip_prefix(collection)
+        .and_then(|rest| {
+            rest.strip_prefix(SHADOW_SUFFIX)
+                .or_else(|| rest.strip_prefix(STAGING_SUFFIX))
+        })
+        .is_some_and(|stamp| !stamp.is_empty() && stamp.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Text a chunk was embedded from
+fn chunk_content(collection: &str, chunk: &StoredVector) -> Result<String> {
+    chunk
+        .metadata
+        .get("content")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            Error::vector_db(format!(
+                "Chunk '{}' in collection '{}' has no content to re-embed",
+                chunk.id, collection
+            ))
+        })
+}
+
+/// Current time in seconds since UNIX epoch
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,346 @@
+//! Providers handed to services by the collection guard
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{EmbeddingProvider, VectorStoreAdmin, VectorStoreProvider};
+use mcb_domain::value_objects::{Embedding, MetadataFilter, SearchResult, StoredVector};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use super::guard::{CollectionGuard, is_working_copy};
+use super::registry::CollectionRecord;
+
+/// Name reported by both guarded providers
+const GUARDED_PROVIDER_NAME: &str = "guarded";
+
+/// Embedding provider that follows the active handle and refuses to embed
+/// for collections built with another provider
+pub struct GuardedEmbeddingProvider {
+    guard: Arc<CollectionGuard>,
+}
+
+impl GuardedEmbeddingProvider {
+    pub(super) fn new(guard: Arc<CollectionGuard>) -> Self {
+        Self { guard }
+    }
+}
+
+/// Error for a collection whose vectors came from another embedding space
+fn incompatible(
+    record: &CollectionRecord,
+    provider: &str,
+    model: Option<&str>,
+    dimensions: usize,
+) -> Error {
+    Error::invalid_argument(format!(
+        "Collection '{}' was built with {}/{} ({} dimensions) but the active embedding provider is {}/{} ({} dimensions); migrate the collection before using it",
+        record.collection,
+        record.provider,
+        record.model,
+        record.dimensions,
+        provider,
+        model.unwrap_or("unknown model"),
+        dimensions
+    ))
+}
+
+#[async_trait]
+impl EmbeddingProvider for GuardedEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        let _gate = self.guard.enter().await;
+        self.guard.embedding().embed_batch(texts).await
+    }
+
+    async fn embed_batch_for(&self, collection: &str, texts: &[String]) -> Result<Vec<Embedding>> {
+        let _gate = self.guard.enter().await;
+        let provider = self.guard.embedding();
+        let record = self.guard.registry().get(collection).await;
+        let name = provider.provider_name_for(collection);
+        let dimensions = provider.dimensions_for(collection);
+        if let Some(record) = &record
+            && !record.is_compatible(name, None, dimensions)
+        {
+            return Err(incompatible(record, name, None, dimensions));
+        }
+
+        let embeddings = provider.embed_batch_for(collection, texts).await?;
+        if let (Some(record), Some(first)) = (&record, embeddings.first())
+            && !record.is_compatible(name, Some(&first.model), first.dimensions)
+        {
+            return Err(incompatible(
+                record,
+                name,
+                Some(&first.model),
+                first.dimensions,
+            ));
+        }
+        Ok(embeddings)
+    }
+
+    fn dimensions(&self) -> usize {
+        self.guard.embedding().dimensions()
+    }
+
+    fn dimensions_for(&self, collection: &str) -> usize {
+        self.guard.embedding().dimensions_for(collection)
+    }
+
+    fn provider_name(&self) -> &str {
+        GUARDED_PROVIDER_NAME
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.guard.embedding().health_check().await
+    }
+}
+
+/// Vector store provider that follows the active handle and maps collection
+/// names to the store collection holding their data
+///
+/// The first insert or upsert into a collection records the embedding
+/// provider, model and dimensions of its vectors; later writes must match.
+pub struct GuardedVectorStoreProvider {
+    guard: Arc<CollectionGuard>,
+}
+
+impl GuardedVectorStoreProvider {
+    pub(super) fn new(guard: Arc<CollectionGuard>) -> Self {
+        Self { guard }
+    }
+
+    async fn physical(&self, collection: &str) -> String {
+        self.guard.registry().physical_name(collection).await
+    }
+
+    /// Check a write of `vectors` and return the physical collection name
+    ///
+    /// Records the embedding space of the collection on its first write.
+    async fn check_insert(&self, collection: &str, vectors: &[Embedding]) -> Result<String> {
+        self.guard.check_writable(collection)?;
+
+        if let Some(first) = vectors.first() {
+            let embedding = self.guard.embedding();
+            let provider = embedding.provider_name_for(collection);
+            let record = self
+                .guard
+                .registry()
+                .insert_if_absent(CollectionRecord::new(
+                    collection,
+                    provider,
+                    first.model.clone(),
+                    first.dimensions,
+                ))
+                .await?;
+            if !record.is_compatible(provider, Some(&first.model), first.dimensions) {
+                return Err(incompatible(
+                    &record,
+                    provider,
+                    Some(&first.model),
+                    first.dimensions,
+                ));
+            }
+        }
+
+        Ok(self.physical(collection).await)
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for GuardedVectorStoreProvider {
+    async fn collection_exists(&self, name: &str) -> Result<bool> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(name).await;
+        self.guard.vector_store().collection_exists(&physical).await
+    }
+
+    async fn get_stats(&self, collection: &str) -> Result<HashMap<String, Value>> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard.vector_store().get_stats(&physical).await
+    }
+
+    async fn flush(&self, collection: &str) -> Result<()> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard.vector_store().flush(&physical).await
+    }
+
+    /// Logical names of the stored collections
+    ///
+    /// Migration shadows, restore staging copies and collections left
+    /// behind by a swap are not listed.
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        let _gate = self.guard.enter().await;
+        let records = self.guard.registry().list().await;
+        let mut names = BTreeSet::new();
+        for name in self.guard.vector_store().list_collection_names().await? {
+            if let Some(record) = records.iter().find(|r| r.physical_name == name) {
+                names.insert(record.collection.clone());
+            } else if !records
+                .iter()
+                .any(|r| r.collection == name || is_working_copy(&name, &r.collection))
+            {
+                names.insert(name);
+            }
+        }
+        Ok(names.into_iter().collect())
+    }
+
+    fn provider_name(&self) -> &str {
+        GUARDED_PROVIDER_NAME
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.guard.vector_store().health_check().await
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for GuardedVectorStoreProvider {
+    async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(name).await;
+        self.guard
+            .vector_store()
+            .create_collection(&physical, dimensions)
+            .await
+    }
+
+    async fn delete_collection(&self, name: &str) -> Result<()> {
+        let _gate = self.guard.enter_collection(name).await;
+        self.guard.check_writable(name)?;
+        let physical = self.physical(name).await;
+        self.guard
+            .vector_store()
+            .delete_collection(&physical)
+            .await?;
+        self.guard.registry().remove(name).await?;
+        Ok(())
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &str,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        let _gate = self.guard.enter_collection(collection).await;
+        let physical = self.check_insert(collection, vectors).await?;
+        self.guard
+            .vector_store()
+            .insert_vectors(&physical, vectors, metadata)
+            .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        let _gate = self.guard.enter_collection(collection).await;
+        let physical = self.check_insert(collection, vectors).await?;
+        self.guard
+            .vector_store()
+            .upsert_vectors(&physical, ids, vectors, metadata)
+            .await
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+    ) -> Result<Vec<SearchResult>> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .search_similar(&physical, query_vector, limit, filter)
+            .await
+    }
+
+    async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
+        let _gate = self.guard.enter_collection(collection).await;
+        self.guard.check_writable(collection)?;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .delete_vectors(&physical, ids)
+            .await
+    }
+
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        let _gate = self.guard.enter_collection(collection).await;
+        self.guard.check_writable(collection)?;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .delete_by_filter(&physical, filter)
+            .await
+    }
+
+    async fn delete_by_file(&self, collection: &str, file_path: &str) -> Result<u64> {
+        let _gate = self.guard.enter_collection(collection).await;
+        self.guard.check_writable(collection)?;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .delete_by_file(&physical, file_path)
+            .await
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .file_vector_ids(&physical, file_path)
+            .await
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &str,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .get_vectors_by_ids(&physical, ids)
+            .await
+    }
+
+    async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .list_vectors(&physical, limit)
+            .await
+    }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard.vector_store().export_vectors(&physical).await
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        let _gate = self.guard.enter().await;
+        let physical = self.physical(collection).await;
+        self.guard
+            .vector_store()
+            .export_vectors_page(&physical, after, limit)
+            .await
+    }
+}
@@ -0,0 +1,32 @@
+//! Collection Provenance and Provider Switching
+//!
+//! Tracks which embedding provider, model and dimensionality each collection
+//! was built with, and guards runtime provider switches so a collection never
+//! mixes vectors from different embedding spaces.
+//!
+//! ## Components
+//!
+//! - [`CollectionRegistry`] - Persistent record of every collection
+//! - [`CollectionGuard`] - Switches providers, refusing or migrating
+//!   incompatible collections
+//! - [`GuardedEmbeddingProvider`] / [`GuardedVectorStoreProvider`] -
+//!   Providers handed to services in place of the raw handles
+//! - [`CollectionBundler`] - Exports collections to portable bundles and
+//!   imports them into the active stores
+//! - [`ReencryptionJobs`] - Re-encrypts collection metadata with the active
+//!   key after a key rotation
+
+mod bundle;
+mod guard;
+mod guarded;
+mod reencryption;
+mod registry;
+
+pub use bundle::{
+    BUNDLE_FORMAT_VERSION, BundleCompression, BundleEmbedding, BundleFile, BundleManifest,
+    CollectionBundler, ExportOptions, ImportedBundle,
+};
+pub use guard::{CollectionGuard, MigrationState, MigrationStatus, SwitchOutcome};
+pub use guarded::{GuardedEmbeddingProvider, GuardedVectorStoreProvider};
+pub use reencryption::{ReencryptionJobs, ReencryptionState, ReencryptionStatus};
+pub use registry::{CollectionRecord, CollectionRegistry};
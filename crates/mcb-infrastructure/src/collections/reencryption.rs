@@ -0,0 +1,261 @@
+//! Background re-encryption of collection metadata after a key rotation
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::CryptoProvider;
+use mcb_domain::value_objects::Embedding;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+use super::guard::CollectionGuard;
+use crate::constants::COLLECTION_REENCRYPTION_BATCH_SIZE;
+use crate::crypto::Keyring;
+
+/// Re-encryption progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReencryptionState {
+    /// Vectors are being rewritten
+    Running,
+    /// Every vector is encrypted with the active key
+    Completed,
+    /// The job stopped; rewritten vectors keep the active key
+    Failed,
+}
+
+/// Status of the latest re-encryption of one collection
+#[derive(Debug, Clone, Serialize)]
+pub struct ReencryptionStatus {
+    /// Collection being re-encrypted
+    pub collection: String,
+    /// Current state
+    pub state: ReencryptionState,
+    /// Key the collection is re-encrypted with
+    pub key_id: String,
+    /// Vectors in the collection as reported by the store when the job
+    /// started, raised if more are rewritten
+    pub total: usize,
+    /// Vectors rewritten so far
+    pub reencrypted: usize,
+    /// Failure reason
+    pub error: Option<String>,
+    /// Start time (seconds since UNIX epoch)
+    pub started_at: u64,
+    /// End time (seconds since UNIX epoch)
+    pub finished_at: Option<u64>,
+}
+
+/// Re-encrypts collections with the active key of a keyring
+///
+/// A job pages through a collection of the encrypted vector store, which
+/// decrypts with whichever key wrote each vector, and writes every page back
+/// under the same ids, which encrypts it with the active key. Searches and
+/// writes keep working between pages; a provider switch, migration or
+/// restore of the collection fails the job. Once it completes, the keys retired before it
+/// started are no longer needed for that collection.
+pub struct ReencryptionJobs {
+    guard: Arc<CollectionGuard>,
+    keyring: Arc<Keyring>,
+    batch_size: usize,
+    jobs: Mutex<HashMap<String, ReencryptionStatus>>,
+}
+
+impl ReencryptionJobs {
+    /// Create re-encryption jobs over the guarded vector store
+    ///
+    /// `keyring` must be the keyring the vector store encrypts with.
+    pub fn new(guard: Arc<CollectionGuard>, keyring: Arc<Keyring>) -> Self {
+        Self {
+            guard,
+            keyring,
+            batch_size: COLLECTION_REENCRYPTION_BATCH_SIZE,
+            jobs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set the number of vectors rewritten per batch
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Start re-encrypting `collection` in the background
+    ///
+    /// # Errors
+    ///
+    /// Fails if the collection does not exist or is already being
+    /// re-encrypted.
+    pub async fn start(self: &Arc<Self>, collection: &str) -> Result<ReencryptionStatus> {
+        if !self
+            .guard
+            .vector_store_provider()
+            .collection_exists(collection)
+            .await?
+        {
+            return Err(Error::not_found(format!("collection '{collection}'")));
+        }
+
+        let status = {
+            let mut jobs = self.jobs();
+            if jobs
+                .get(collection)
+                .is_some_and(|job| job.state == ReencryptionState::Running)
+            {
+                return Err(Error::invalid_argument(format!(
+                    "Collection '{collection}' is already being re-encrypted"
+                )));
+            }
+            let status = ReencryptionStatus {
+                collection: collection.to_string(),
+                state: ReencryptionState::Running,
+                key_id: self.keyring.active_key_id().unwrap_or_default().to_string(),
+                total: 0,
+                reencrypted: 0,
+                error: None,
+                started_at: now_secs(),
+                finished_at: None,
+            };
+            jobs.insert(collection.to_string(), status.clone());
+            status
+        };
+
+        info!(collection, key_id = %status.key_id, "Starting collection re-encryption");
+        tokio::spawn(Arc::clone(self).run(collection.to_string()));
+        Ok(status)
+    }
+
+    /// Status of the latest job for `collection`
+    pub fn status(&self, collection: &str) -> Option<ReencryptionStatus> {
+        self.jobs().get(collection).cloned()
+    }
+
+    /// Status of the latest job for every collection, by collection name
+    pub fn statuses(&self) -> Vec<ReencryptionStatus> {
+        let mut statuses: Vec<_> = self.jobs().values().cloned().collect();
+        statuses.sort_by(|a, b| a.collection.cmp(&b.collection));
+        statuses
+    }
+
+    fn jobs(&self) -> MutexGuard<'_, HashMap<String, ReencryptionStatus>> {
+        self.jobs
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn update(&self, collection: &str, update: impl FnOnce(&mut ReencryptionStatus)) {
+        if let Some(status) = self.jobs().get_mut(collection) {
+            update(status);
+        }
+    }
+
+    async fn run(self: Arc<Self>, collection: String) {
+        match self.reencrypt(&collection).await {
+            Ok(()) => {
+                info!(collection = %collection, "Collection re-encryption completed");
+                self.update(&collection, |status| {
+                    status.state = ReencryptionState::Completed;
+                    status.finished_at = Some(now_secs());
+                });
+            }
+            Err(e) => {
+                warn!(collection = %collection, error = %e, "Collection re-encryption failed");
+                self.update(&collection, |status| {
+                    status.state = ReencryptionState::Failed;
+                    status.error = Some(e.to_string());
+                    status.finished_at = Some(now_secs());
+                });
+            }
+        }
+    }
+
+    async fn reencrypt(&self, collection: &str) -> Result<()> {
+        // Rewrites keep the recorded embedding space, so they bypass the
+        // registry checks of the guarded store but still honour its gate
+        let store = self.guard.vector_store();
+        let physical = self.guard.registry().physical_name(collection).await;
+        let total = {
+            let _gate = self.guard.enter().await;
+            vector_count(&store.get_stats(&physical).await?)
+        };
+        self.update(collection, |status| status.total = total);
+
+        let model = self
+            .guard
+            .registry()
+            .get(collection)
+            .await
+            .map(|record| record.model)
+            .unwrap_or_default();
+        let mut after: Option<String> = None;
+        loop {
+            // Writes to the collection wait while a batch is read and
+            // rewritten, so a concurrent write or delete is never
+            // overwritten with the copy read before it; deleted ids are
+            // simply not read back. Other collections are not held up.
+            let count = {
+                let _gate = self.guard.enter_collection_exclusive(collection).await;
+                self.guard.check_writable(collection)?;
+                if self.guard.registry().physical_name(collection).await != physical {
+                    return Err(Error::invalid_argument(format!(
+                        "Collection '{collection}' was replaced during re-encryption"
+                    )));
+                }
+                let batch = store
+                    .export_vectors_page(&physical, after.as_deref(), self.batch_size)
+                    .await?;
+                let Some(last) = batch.last() else {
+                    break;
+                };
+                after = Some(last.id.clone());
+
+                let embeddings: Vec<Embedding> = batch
+                    .iter()
+                    .map(|v| Embedding {
+                        vector: v.vector.clone(),
+                        model: model.clone(),
+                        dimensions: v.vector.len(),
+                    })
+                    .collect();
+                let count = batch.len();
+                let (ids, metadata): (Vec<String>, Vec<_>) =
+                    batch.into_iter().map(|v| (v.id, v.metadata)).unzip();
+                store
+                    .upsert_vectors(&physical, &ids, &embeddings, metadata)
+                    .await?;
+                count
+            };
+            self.update(collection, |status| {
+                status.reencrypted += count;
+                status.total = status.total.max(status.reencrypted);
+            });
+        }
+        store.flush(&physical).await
+    }
+}
+
+impl std::fmt::Debug for ReencryptionJobs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReencryptionJobs")
+            .field("keyring", &self.keyring)
+            .field("batch_size", &self.batch_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Vector count a store reports in its collection stats, or 0
+fn vector_count(stats: &HashMap<String, serde_json::Value>) -> usize {
+    ["vectors_count", "vector_count"]
+        .iter()
+        .find_map(|key| stats.get(*key).and_then(serde_json::Value::as_u64))
+        .map_or(0, |count| usize::try_from(count).unwrap_or(usize::MAX))
+}
+
+/// Current time in seconds since UNIX epoch
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
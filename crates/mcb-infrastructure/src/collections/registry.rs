@@ -0,0 +1,281 @@
+//! Collection registry
+//!
+//! Records the embedding provider, model and dimensions each collection was
+//! built with, and the vector store collection currently holding its data.
+//! The registry is a single JSON document rewritten on every change with
+//! write-to-temp-then-rename.
+
+use mcb_domain::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::RwLock;
+
+/// Current on-disk format version
+const COLLECTION_REGISTRY_VERSION: u32 = 1;
+
+/// What a collection was embedded with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionRecord {
+    /// Collection name used by clients
+    pub collection: String,
+    /// Vector store collection holding the vectors
+    ///
+    /// Equal to `collection` until a migration swaps in a shadow collection.
+    pub physical_name: String,
+    /// Embedding provider name
+    pub provider: String,
+    /// Embedding model
+    pub model: String,
+    /// Embedding dimensions
+    pub dimensions: usize,
+    /// Creation time (seconds since UNIX epoch)
+    pub created_at: u64,
+    /// Last modification time (seconds since UNIX epoch)
+    pub updated_at: u64,
+}
+
+impl CollectionRecord {
+    /// Record for a collection stored under its own name
+    pub fn new(
+        collection: impl Into<String>,
+        provider: impl Into<String>,
+        model: impl Into<String>,
+        dimensions: usize,
+    ) -> Self {
+        let collection = collection.into();
+        let now = now_secs();
+        Self {
+            physical_name: collection.clone(),
+            collection,
+            provider: provider.into(),
+            model: model.into(),
+            dimensions,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether vectors from `provider` can share this collection
+    ///
+    /// `model` is compared only when known.
+    pub fn is_compatible(&self, provider: &str, model: Option<&str>, dimensions: usize) -> bool {
+        self.provider == provider
+            && self.dimensions == dimensions
+            && model.is_none_or(|model| model == self.model)
+    }
+}
+
+/// On-disk document
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistryDocument {
+    version: u32,
+    collections: BTreeMap<String, CollectionRecord>,
+}
+
+/// File-backed (or in-memory) collection registry
+pub struct CollectionRegistry {
+    path: Option<PathBuf>,
+    records: RwLock<BTreeMap<String, CollectionRecord>>,
+}
+
+impl CollectionRegistry {
+    /// Open the registry at `path`, starting empty if the file does not exist
+    pub async fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let records = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => {
+                let document: RegistryDocument =
+                    serde_json::from_str(&content).map_err(|e| Error::Infrastructure {
+                        message: format!(
+                            "Failed to parse collection registry {}: {}",
+                            path.display(),
+                            e
+                        ),
+                        source: Some(Box::new(e)),
+                    })?;
+                if document.version > COLLECTION_REGISTRY_VERSION {
+                    return Err(Error::infrastructure(format!(
+                        "Collection registry {} has unsupported version {}",
+                        path.display(),
+                        document.version
+                    )));
+                }
+                document.collections
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => {
+                return Err(Error::io_with_source(
+                    format!("Failed to read collection registry {}", path.display()),
+                    e,
+                ));
+            }
+        };
+
+        Ok(Self {
+            path: Some(path),
+            records: RwLock::new(records),
+        })
+    }
+
+    /// Registry that is never written to disk
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            records: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Whether records survive a restart
+    pub fn is_persistent(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// Record for `collection`
+    pub async fn get(&self, collection: &str) -> Option<CollectionRecord> {
+        self.records.read().await.get(collection).cloned()
+    }
+
+    /// Record for `collection` without waiting for writers
+    ///
+    /// Returns `None` while a write is in progress.
+    pub fn try_get(&self, collection: &str) -> Option<CollectionRecord> {
+        self.records.try_read().ok()?.get(collection).cloned()
+    }
+
+    /// All records, sorted by collection name
+    pub async fn list(&self) -> Vec<CollectionRecord> {
+        self.records.read().await.values().cloned().collect()
+    }
+
+    /// Vector store collection holding `collection`
+    pub async fn physical_name(&self, collection: &str) -> String {
+        self.records
+            .read()
+            .await
+            .get(collection)
+            .map_or_else(|| collection.to_string(), |r| r.physical_name.clone())
+    }
+
+    /// Record `record` unless the collection already has one
+    ///
+    /// Returns the record in effect afterwards.
+    pub async fn insert_if_absent(&self, record: CollectionRecord) -> Result<CollectionRecord> {
+        let mut records = self.records.write().await;
+        if let Some(existing) = records.get(&record.collection) {
+            return Ok(existing.clone());
+        }
+        records.insert(record.collection.clone(), record.clone());
+        self.persist(&records).await?;
+        Ok(record)
+    }
+
+    /// Replace the records of several collections in one write
+    pub async fn replace_all(&self, updated: Vec<CollectionRecord>) -> Result<()> {
+        let mut records = self.records.write().await;
+        let now = now_secs();
+        for mut record in updated {
+            record.updated_at = now;
+            records.insert(record.collection.clone(), record);
+        }
+        self.persist(&records).await
+    }
+
+    /// Move the record of `from` to `to`, keeping its physical collection
+    ///
+    /// Returns the record `to` had before.
+    pub async fn rename(&self, from: &str, to: &str) -> Result<Option<CollectionRecord>> {
+        let mut records = self.records.write().await;
+        let mut record = records
+            .remove(from)
+            .ok_or_else(|| Error::not_found(format!("collection record '{from}'")))?;
+        record.collection = to.to_string();
+        record.updated_at = now_secs();
+        let previous = records.insert(to.to_string(), record);
+        self.persist(&records).await?;
+        Ok(previous)
+    }
+
+    /// Forget `collection`
+    pub async fn remove(&self, collection: &str) -> Result<Option<CollectionRecord>> {
+        let mut records = self.records.write().await;
+        let removed = records.remove(collection);
+        if removed.is_some() {
+            self.persist(&records).await?;
+        }
+        Ok(removed)
+    }
+
+    async fn persist(&self, records: &BTreeMap<String, CollectionRecord>) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let document = RegistryDocument {
+            version: COLLECTION_REGISTRY_VERSION,
+            collections: records.clone(),
+        };
+        let content = serde_json::to_vec_pretty(&document)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                Error::io_with_source("Failed to create collection registry directory", e)
+            })?;
+        }
+
+        // Flush the new document before the rename and the rename before
+        // returning, so a crash never leaves a swap recorded only in memory
+        let tmp_path = path.with_extension("tmp");
+        let mut file = tokio::fs::File::create(&tmp_path)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to write collection registry", e))?;
+        file.write_all(&content)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to write collection registry", e))?;
+        file.sync_all()
+            .await
+            .map_err(|e| Error::io_with_source("Failed to sync collection registry", e))?;
+        drop(file);
+        tokio::fs::rename(&tmp_path, path)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to replace collection registry", e))?;
+        sync_parent(path).await
+    }
+}
+
+impl std::fmt::Debug for CollectionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectionRegistry")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sync the directory holding `path` so a rename into it is durable
+#[cfg(unix)]
+async fn sync_parent(path: &Path) -> Result<()> {
+    let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) else {
+        return Ok(());
+    };
+    let dir = tokio::fs::File::open(parent)
+        .await
+        .map_err(|e| Error::io_with_source("Failed to open collection registry directory", e))?;
+    dir.sync_all()
+        .await
+        .map_err(|e| Error::io_with_source("Failed to sync collection registry directory", e))
+}
+
+/// Directories cannot be synced on this platform
+#[cfg(not(unix))]
+async fn sync_parent(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Current time in seconds since UNIX epoch
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
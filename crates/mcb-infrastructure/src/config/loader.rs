@@ -5,7 +5,7 @@
 //!
 //! Uses Figment for configuration management (migrated from config crate in v0.1.2).
 
-use crate::config::AppConfig;
+use crate::config::{AppConfig, EventBusProvider};
 use crate::constants::*;
 use crate::error_ext::ErrorContext;
 use crate::logging::log_config_loaded;
@@ -171,6 +171,7 @@ fn validate_app_config(config: &AppConfig) -> Result<()> {
     validate_server_config(config)?;
     validate_auth_config(config)?;
     validate_cache_config(config)?;
+    validate_event_bus_config(config)?;
     validate_limits_config(config)?;
     validate_daemon_config(config)?;
     validate_backup_config(config)?;
@@ -197,7 +198,9 @@ fn validate_server_config(config: &AppConfig) -> Result<()> {
 }
 
 fn validate_auth_config(config: &AppConfig) -> Result<()> {
-    if config.auth.enabled {
+    // The user database issues JWTs, so it needs a signing secret even when
+    // request authentication itself is disabled.
+    if config.auth.enabled || config.auth.user_db_path.is_some() {
         if config.auth.jwt.secret.is_empty() {
             return Err(Error::Configuration {
                 message: "JWT secret cannot be empty when authentication is enabled".to_string(),
@@ -226,6 +229,17 @@ fn validate_cache_config(config: &AppConfig) -> Result<()> {
     Ok(())
 }
 
+fn validate_event_bus_config(config: &AppConfig) -> Result<()> {
+    let event_bus = &config.system.infrastructure.event_bus;
+    if event_bus.provider == EventBusProvider::Nats && event_bus.nats_url.is_none() {
+        return Err(Error::Configuration {
+            message: "NATS URL is required when the event bus provider is nats".to_string(),
+            source: None,
+        });
+    }
+    Ok(())
+}
+
 fn validate_limits_config(config: &AppConfig) -> Result<()> {
     if config.system.infrastructure.limits.memory_limit == 0 {
         return Err(Error::Configuration {
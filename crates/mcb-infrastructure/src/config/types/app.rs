@@ -1,12 +1,15 @@
 //! Main application configuration
 
+use crate::constants::EMBEDDING_BATCH_DEFAULT_CONCURRENCY;
 use mcb_domain::value_objects::{EmbeddingConfig, VectorStoreConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 // Re-export all config types from consolidated modules
 pub use super::infrastructure::{
-    CacheProvider, CacheSystemConfig, LimitsConfig, LoggingConfig, MetricsConfig, ResilienceConfig,
+    AuditConfig, CacheProvider, CacheSystemConfig, EventStoreConfig, LimitsConfig, LoggingConfig,
+    MetricsConfig, ResilienceConfig,
 };
 pub use super::mode::{ModeConfig, OperatingMode};
 pub use super::server::{
@@ -14,8 +17,9 @@ pub use super::server::{
     TransportMode,
 };
 pub use super::system::{
-    AdminApiKeyConfig, ApiKeyConfig, AuthConfig, BackupConfig, DaemonConfig, EventBusConfig,
-    EventBusProvider, JwtConfig, OperationsConfig, PasswordAlgorithm, SnapshotConfig, SyncConfig,
+    AdminApiKeyConfig, ApiKeyConfig, AuthConfig, BackupConfig, CollectionsConfig, DaemonConfig,
+    EventBusConfig, EventBusProvider, JwtConfig, OperationsConfig, PasswordAlgorithm,
+    ScopedApiKeyConfig, SnapshotConfig, SyncConfig,
 };
 
 /// Embedding configuration container
@@ -34,6 +38,116 @@ pub struct EmbeddingConfigContainer {
     /// Named configs for TOML format
     #[serde(default)]
     pub configs: HashMap<String, EmbeddingConfig>,
+    /// Names of `configs` entries to fail over between, in priority order
+    ///
+    /// When non-empty, the embedding provider routes between these entries
+    /// instead of using the single `provider` setting.
+    #[serde(default)]
+    pub failover: Vec<String>,
+    /// Request batching and oversized input handling
+    #[serde(default)]
+    pub batching: EmbeddingBatchingConfig,
+    /// Local model directory for the `fastembed` provider
+    ///
+    /// Requires `dimensions`; nothing is downloaded when set.
+    #[serde(default)]
+    pub local_model: Option<LocalModelConfig>,
+    /// Deployment settings for the `azure-openai` provider
+    #[serde(default)]
+    pub azure: Option<AzureOpenAIConfig>,
+    /// Request and response shape for the `openai-compatible` provider
+    #[serde(default)]
+    pub openai_compatible: Option<OpenAICompatibleConfig>,
+}
+
+/// Azure OpenAI deployment
+///
+/// `base_url` is the resource endpoint and `api_key` the resource key.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AzureOpenAIConfig {
+    /// Deployment name (defaults to the model name)
+    pub deployment: Option<String>,
+    /// REST API version
+    pub api_version: Option<String>,
+}
+
+/// OpenAI-compatible endpoint
+///
+/// `base_url` is the endpoint root and `dimensions` is required.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenAICompatibleConfig {
+    /// Path template appended to `base_url`; `{model}` is replaced
+    pub path: Option<String>,
+    /// JSON path of the vectors in the response, e.g. `data[*].embedding`
+    pub response_path: Option<String>,
+    /// Header carrying `api_key` as-is instead of a bearer token
+    pub api_key_header: Option<String>,
+    /// Extra headers sent with every request
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+}
+
+/// FastEmbed model loaded from a local directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalModelConfig {
+    /// Directory holding `model.onnx` and the tokenizer files
+    pub path: PathBuf,
+    /// Token pooling, `mean` (default) or `cls`
+    pub pooling: Option<String>,
+    /// Maximum input length in tokens (default 512)
+    pub max_length: Option<usize>,
+    /// `sha256sum` manifest verified before loading, relative to `path`
+    pub checksums: Option<PathBuf>,
+}
+
+/// How inputs over the model token limit are embedded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OversizePolicy {
+    /// Split into pieces and average the piece vectors
+    #[default]
+    Mean,
+    /// Split into pieces and keep the element-wise maximum
+    Max,
+    /// Cut at the limit and count the input as truncated
+    Truncate,
+}
+
+/// Embedding request batching
+///
+/// Inputs are packed into requests under the provider's token and item
+/// limits; the configured limits apply when they are lower.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmbeddingBatchingConfig {
+    /// Pack requests and split oversized inputs
+    pub enabled: bool,
+    /// Concurrent requests per provider
+    pub max_concurrency: usize,
+    /// Inputs per request
+    pub max_batch_items: Option<usize>,
+    /// Estimated tokens per request
+    pub max_batch_tokens: Option<usize>,
+    /// Handling of inputs over the model limit
+    pub oversize: OversizePolicy,
+}
+
+/// Default batching configuration.
+///
+/// - `enabled`: true
+/// - `max_concurrency`: `EMBEDDING_BATCH_DEFAULT_CONCURRENCY`
+/// - `max_batch_items` / `max_batch_tokens`: provider limits
+/// - `oversize`: mean pooling
+impl Default for EmbeddingBatchingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_concurrency: EMBEDDING_BATCH_DEFAULT_CONCURRENCY,
+            max_batch_items: None,
+            max_batch_tokens: None,
+            oversize: OversizePolicy::default(),
+        }
+    }
 }
 
 /// Vector store configuration container
@@ -47,11 +161,96 @@ pub struct VectorStoreConfigContainer {
     pub dimensions: Option<usize>,
     /// Collection name
     pub collection: Option<String>,
+    /// Persistence and compaction settings for the `edgevec` provider
+    #[serde(default)]
+    pub edgevec: Option<EdgeVecStoreConfig>,
+    /// Search and storage settings for the `filesystem` provider
+    #[serde(default)]
+    pub filesystem: Option<FilesystemStoreConfig>,
+    /// Authentication and distance settings for the `qdrant` provider
+    #[serde(default)]
+    pub qdrant: Option<QdrantStoreConfig>,
+    /// Metadata encryption keys; when set, metadata is encrypted at rest
+    #[serde(default)]
+    pub encryption: Option<VectorStoreEncryptionConfig>,
     /// Named configs for TOML format
     #[serde(default)]
     pub configs: HashMap<String, VectorStoreConfig>,
 }
 
+/// EdgeVec persistence and compaction
+///
+/// `address` is the data directory; without it collections are not saved.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EdgeVecStoreConfig {
+    /// Seconds between background flushes of modified collections (0 disables)
+    pub flush_interval_secs: Option<u64>,
+    /// Fraction of deleted nodes that triggers an index rebuild (0 disables)
+    pub compaction_threshold: Option<f64>,
+    /// Deleted nodes required before a rebuild is considered
+    pub compaction_min_tombstones: Option<usize>,
+}
+
+/// Filesystem store search strategy and durability
+///
+/// Collections below `exact_below` vectors are scored exhaustively; larger
+/// ones go through an IVF index saved next to the shards. Writes go to a
+/// write-ahead log that is checkpointed into the collection index.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FilesystemStoreConfig {
+    /// Vector count from which searches use the ANN index
+    pub exact_below: Option<usize>,
+    /// Index lists scored per query (higher raises recall and latency)
+    pub nprobe: Option<usize>,
+    /// Lists per index (0 picks the square root of the vector count)
+    pub nlist: Option<usize>,
+    /// When writes are synced: `always`, `batch` or `never`
+    pub sync: Option<String>,
+    /// Write-ahead log size in bytes that triggers a checkpoint
+    pub checkpoint_wal_bytes: Option<u64>,
+    /// Share of deleted records that triggers compaction (0 disables)
+    pub compaction_threshold: Option<f64>,
+}
+
+/// Qdrant connection and collection settings
+///
+/// `address` is the server URL, e.g. `http://localhost:6333`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QdrantStoreConfig {
+    /// API key sent in the `api-key` header
+    pub api_key: Option<String>,
+    /// Distance for new collections: `cosine`, `dot`, `euclid` or `manhattan`
+    pub distance: Option<String>,
+}
+
+/// Metadata encryption keys for the vector store
+///
+/// New metadata is encrypted with `active_key`; the other keys only decrypt
+/// metadata written before a rotation. Key material is read from files or
+/// environment variables, never from the configuration itself.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VectorStoreEncryptionConfig {
+    /// Id of the key new metadata is encrypted with
+    pub active_key: String,
+    /// All keys, including retired ones still needed for decryption
+    #[serde(default)]
+    pub keys: Vec<EncryptionKeySource>,
+}
+
+/// Where an encryption key is read from
+///
+/// The key is 32 bytes written as 64 hex characters; set exactly one of
+/// `file` and `env`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EncryptionKeySource {
+    /// Key id stored with every value encrypted under the key
+    pub id: String,
+    /// File holding the key
+    pub file: Option<PathBuf>,
+    /// Environment variable holding the key
+    pub env: Option<String>,
+}
+
 /// Provider configurations
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProvidersConfig {
@@ -76,6 +275,12 @@ pub struct InfrastructureConfig {
     pub resilience: ResilienceConfig,
     /// Limits configuration
     pub limits: LimitsConfig,
+    /// Audit log configuration
+    #[serde(default)]
+    pub audit: AuditConfig,
+    /// Domain event store configuration
+    #[serde(default)]
+    pub event_store: EventStoreConfig,
 }
 
 /// Data management configurations
@@ -87,6 +292,9 @@ pub struct DataConfig {
     pub sync: SyncConfig,
     /// Backup configuration
     pub backup: BackupConfig,
+    /// Collection registry configuration
+    #[serde(default)]
+    pub collections: CollectionsConfig,
 }
 
 /// System infrastructure and data configurations
@@ -1,7 +1,7 @@
 //! Infrastructure configuration types
 //!
 //! Consolidated configuration for infrastructure concerns:
-//! logging, limits, cache, metrics, and resilience.
+//! logging, limits, cache, metrics, resilience, audit, and the event store.
 
 use crate::constants::*;
 use serde::{Deserialize, Serialize};
@@ -58,10 +58,13 @@ pub struct LimitsConfig {
     pub cpu_limit: usize,
     /// Disk I/O limit in bytes per second
     pub disk_io_limit: u64,
-    /// Maximum concurrent connections
+    /// Maximum clients with HTTP requests in flight at once
     pub max_connections: u32,
-    /// Maximum concurrent requests per connection
-    pub max_requests_per_connection: u32,
+    /// Maximum concurrent HTTP requests and streams per client address
+    ///
+    /// Accepts the former name `max_requests_per_connection`.
+    #[serde(alias = "max_requests_per_connection")]
+    pub max_requests_per_client: u32,
 }
 
 /// Default resource limits using infrastructure constants.
@@ -70,7 +73,7 @@ pub struct LimitsConfig {
 /// - `cpu_limit`: `DEFAULT_CPU_LIMIT`
 /// - `disk_io_limit`: `DEFAULT_DISK_IO_LIMIT`
 /// - `max_connections`: 1000
-/// - `max_requests_per_connection`: 100
+/// - `max_requests_per_client`: 100
 impl Default for LimitsConfig {
     fn default() -> Self {
         Self {
@@ -78,7 +81,7 @@ impl Default for LimitsConfig {
             cpu_limit: DEFAULT_CPU_LIMIT,
             disk_io_limit: DEFAULT_DISK_IO_LIMIT,
             max_connections: 1000,
-            max_requests_per_connection: 100,
+            max_requests_per_client: 100,
         }
     }
 }
@@ -94,6 +97,8 @@ pub enum CacheProvider {
     Moka,
     /// Distributed cache (Redis)
     Redis,
+    /// Local Moka cache in front of Redis, invalidated over the event bus
+    Tiered,
 }
 
 impl CacheProvider {
@@ -102,6 +107,7 @@ impl CacheProvider {
         match self {
             CacheProvider::Moka => "moka",
             CacheProvider::Redis => "redis",
+            CacheProvider::Tiered => "tiered",
         }
     }
 }
@@ -117,12 +123,41 @@ pub struct CacheSystemConfig {
     pub default_ttl_secs: u64,
     /// Maximum cache size in bytes
     pub max_size: usize,
-    /// Redis URL (for Redis provider)
+    /// Redis URL (for the Redis and tiered providers)
     pub redis_url: Option<String>,
     /// Redis connection pool size
     pub redis_pool_size: u32,
     /// Namespace for cache keys
     pub namespace: String,
+    /// Search result caching
+    #[serde(default)]
+    pub search_results: SearchResultCacheConfig,
+}
+
+/// Search result cache
+///
+/// Off by default. Results are invalidated whenever the searched
+/// collection is indexed, synced or cleared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchResultCacheConfig {
+    /// Cache search results
+    pub enabled: bool,
+    /// TTL of cached results in seconds
+    pub ttl_secs: u64,
+}
+
+/// Default search result cache configuration.
+///
+/// - `enabled`: false
+/// - `ttl_secs`: `SEARCH_RESULT_CACHE_DEFAULT_TTL_SECS`
+impl Default for SearchResultCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_secs: SEARCH_RESULT_CACHE_DEFAULT_TTL_SECS,
+        }
+    }
 }
 
 /// Default cache system configuration using infrastructure constants.
@@ -133,6 +168,7 @@ pub struct CacheSystemConfig {
 /// - `max_size`: `CACHE_DEFAULT_SIZE_LIMIT`
 /// - `redis_pool_size`: `REDIS_POOL_SIZE`
 /// - `namespace`: `DEFAULT_CACHE_NAMESPACE`
+/// - `search_results`: disabled
 impl Default for CacheSystemConfig {
     fn default() -> Self {
         Self {
@@ -143,6 +179,7 @@ impl Default for CacheSystemConfig {
             redis_url: None,
             redis_pool_size: REDIS_POOL_SIZE as u32,
             namespace: DEFAULT_CACHE_NAMESPACE.to_string(),
+            search_results: SearchResultCacheConfig::default(),
         }
     }
 }
@@ -188,10 +225,109 @@ impl Default for MetricsConfig {
     }
 }
 
+// ============================================================================
+// Audit Configuration
+// ============================================================================
+
+/// Audit log configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditConfig {
+    /// Record tool calls and admin mutations
+    pub enabled: bool,
+    /// Directory holding the JSONL audit files
+    pub directory: PathBuf,
+    /// Maximum file size before rotation (bytes)
+    pub max_file_size: u64,
+    /// Maximum number of rotated files to keep
+    pub max_files: usize,
+}
+
+/// Default audit configuration using infrastructure constants.
+///
+/// - `enabled`: false
+/// - `directory`: ./audit
+/// - `max_file_size`: `AUDIT_ROTATION_SIZE`
+/// - `max_files`: `AUDIT_MAX_FILES`
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: PathBuf::from("./audit"),
+            max_file_size: AUDIT_ROTATION_SIZE,
+            max_files: AUDIT_MAX_FILES,
+        }
+    }
+}
+
+// ============================================================================
+// Event Store Configuration
+// ============================================================================
+
+/// Domain event store configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventStoreConfig {
+    /// Record published domain events for replay and history queries
+    pub enabled: bool,
+    /// Directory holding the JSONL event log; events are kept in memory only when unset
+    pub directory: Option<PathBuf>,
+    /// Maximum number of events to retain
+    pub max_events: usize,
+    /// Maximum age of retained events (seconds)
+    pub max_age_secs: u64,
+}
+
+/// Default event store configuration using infrastructure constants.
+///
+/// - `enabled`: true
+/// - `directory`: None (memory only)
+/// - `max_events`: `EVENT_STORE_MAX_EVENTS`
+/// - `max_age_secs`: `EVENT_STORE_MAX_AGE_SECS`
+impl Default for EventStoreConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            directory: None,
+            max_events: EVENT_STORE_MAX_EVENTS,
+            max_age_secs: EVENT_STORE_MAX_AGE_SECS,
+        }
+    }
+}
+
 // ============================================================================
 // Resilience Configuration
 // ============================================================================
 
+fn default_rate_limiter_enabled() -> bool {
+    false
+}
+
+fn default_provider_resilience_enabled() -> bool {
+    true
+}
+
+fn default_retry_max_delay_ms() -> u64 {
+    RETRY_MAX_DELAY_MS
+}
+
+fn default_provider_call_timeout_secs() -> u64 {
+    PROVIDER_CALL_TIMEOUT_SECS
+}
+
+fn default_expensive_rps() -> u32 {
+    RATE_LIMITER_EXPENSIVE_RPS
+}
+
+fn default_expensive_burst() -> u32 {
+    RATE_LIMITER_EXPENSIVE_BURST
+}
+
+fn default_expensive_tools() -> Vec<String> {
+    RATE_LIMITER_EXPENSIVE_TOOLS
+        .iter()
+        .map(|tool| (*tool).to_string())
+        .collect()
+}
+
 /// Resilience configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResilienceConfig {
@@ -205,17 +341,42 @@ pub struct ResilienceConfig {
     pub rate_limiter_rps: u32,
     /// Rate limiter burst size
     pub rate_limiter_burst: u32,
+    /// Enforce rate limits on the HTTP transport and admin API
+    #[serde(default = "default_rate_limiter_enabled")]
+    pub rate_limiter_enabled: bool,
+    /// Rate limiter requests per second for expensive tools
+    #[serde(default = "default_expensive_rps")]
+    pub rate_limiter_expensive_rps: u32,
+    /// Rate limiter burst size for expensive tools
+    #[serde(default = "default_expensive_burst")]
+    pub rate_limiter_expensive_burst: u32,
+    /// Tools charged against the expensive bucket
+    #[serde(default = "default_expensive_tools")]
+    pub rate_limiter_expensive_tools: Vec<String>,
+    /// Wrap embedding and vector store providers with retries and
+    /// circuit breakers
+    #[serde(default = "default_provider_resilience_enabled")]
+    pub provider_resilience_enabled: bool,
     /// Retry attempts
     pub retry_attempts: u32,
     /// Retry delay in milliseconds
     pub retry_delay_ms: u64,
+    /// Upper bound for a single backoff delay in milliseconds
+    #[serde(default = "default_retry_max_delay_ms")]
+    pub retry_max_delay_ms: u64,
+    /// Time limit for a single provider call attempt in seconds (0 disables)
+    #[serde(default = "default_provider_call_timeout_secs")]
+    pub provider_call_timeout_secs: u64,
 }
 
 /// Default resilience configuration using infrastructure constants.
 ///
 /// Circuit breaker: `CIRCUIT_BREAKER_*` constants
-/// Rate limiter: `RATE_LIMITER_DEFAULT_*` constants
-/// Retry: 3 attempts with 1000ms delay
+/// Rate limiter: disabled; when enabled, `RATE_LIMITER_DEFAULT_*`
+/// constants, expensive tools (`RATE_LIMITER_EXPENSIVE_*`) in their own
+/// bucket
+/// Retry: 3 attempts with 1000ms delay, backoff capped at `RETRY_MAX_DELAY_MS`,
+/// each attempt limited to `PROVIDER_CALL_TIMEOUT_SECS`
 impl Default for ResilienceConfig {
     fn default() -> Self {
         Self {
@@ -224,8 +385,15 @@ impl Default for ResilienceConfig {
             circuit_breaker_success_threshold: CIRCUIT_BREAKER_SUCCESS_THRESHOLD,
             rate_limiter_rps: RATE_LIMITER_DEFAULT_RPS,
             rate_limiter_burst: RATE_LIMITER_DEFAULT_BURST,
+            rate_limiter_enabled: false,
+            rate_limiter_expensive_rps: RATE_LIMITER_EXPENSIVE_RPS,
+            rate_limiter_expensive_burst: RATE_LIMITER_EXPENSIVE_BURST,
+            rate_limiter_expensive_tools: default_expensive_tools(),
+            provider_resilience_enabled: true,
             retry_attempts: 3,
             retry_delay_ms: 1000,
+            retry_max_delay_ms: RETRY_MAX_DELAY_MS,
+            provider_call_timeout_secs: PROVIDER_CALL_TIMEOUT_SECS,
         }
     }
 }
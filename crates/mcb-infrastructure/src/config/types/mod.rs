@@ -16,7 +16,8 @@ pub mod system;
 // Re-export main types
 pub use app::*;
 pub use infrastructure::{
-    CacheProvider, CacheSystemConfig, LimitsConfig, LoggingConfig, MetricsConfig, ResilienceConfig,
+    AuditConfig, CacheProvider, CacheSystemConfig, EventStoreConfig, LimitsConfig, LoggingConfig,
+    MetricsConfig, ResilienceConfig, SearchResultCacheConfig,
 };
 pub use mode::{ModeConfig, OperatingMode};
 pub use server::{
@@ -24,6 +25,7 @@ pub use server::{
     TransportMode,
 };
 pub use system::{
-    AdminApiKeyConfig, ApiKeyConfig, AuthConfig, BackupConfig, DaemonConfig, EventBusConfig,
-    EventBusProvider, JwtConfig, OperationsConfig, PasswordAlgorithm, SnapshotConfig, SyncConfig,
+    AdminApiKeyConfig, ApiKeyConfig, AuthConfig, BackupConfig, CollectionsConfig, DaemonConfig,
+    EventBusConfig, EventBusProvider, JwtConfig, OperationsConfig, PasswordAlgorithm,
+    ScopedApiKeyConfig, SnapshotConfig, SyncConfig,
 };
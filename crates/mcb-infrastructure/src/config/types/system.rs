@@ -3,6 +3,7 @@
 //! Consolidated configuration for system concerns:
 //! auth, event_bus, backup, sync, snapshot, daemon, and operations.
 
+use super::app::EncryptionKeySource;
 use crate::constants::*;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -12,10 +13,13 @@ use std::path::PathBuf;
 // ============================================================================
 
 /// Password hashing algorithms
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PasswordAlgorithm {
+    /// Argon2id (default)
     Argon2,
+    /// bcrypt with `BCRYPT_DEFAULT_COST`
     Bcrypt,
+    /// PBKDF2-HMAC-SHA256 with `PBKDF2_ITERATIONS`
     Pbkdf2,
 }
 
@@ -45,6 +49,21 @@ impl Default for JwtConfig {
     }
 }
 
+/// A named API key with collection scopes
+///
+/// Scopes use the form `collection:<glob>:read|write|admin`, for example
+/// `collection:team-a-*:write`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopedApiKeyConfig {
+    /// Name used as the principal in logs
+    pub name: String,
+    /// The key value
+    pub key: String,
+    /// Collection scopes granted to this key
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
 /// API key configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiKeyConfig {
@@ -52,17 +71,22 @@ pub struct ApiKeyConfig {
     pub enabled: bool,
     /// API key header name
     pub header: String,
+    /// Scoped API keys accepted as tool-call tokens
+    #[serde(default)]
+    pub keys: Vec<ScopedApiKeyConfig>,
 }
 
 /// Default API key configuration using infrastructure constants.
 ///
 /// - `enabled`: true
 /// - `header`: `API_KEY_HEADER`
+/// - `keys`: empty
 impl Default for ApiKeyConfig {
     fn default() -> Self {
         Self {
             enabled: true,
             header: API_KEY_HEADER.to_string(),
+            keys: Vec::new(),
         }
     }
 }
@@ -111,7 +135,8 @@ pub struct AuthConfig {
     /// Admin API key configuration
     #[serde(default)]
     pub admin: AdminApiKeyConfig,
-    /// User database path
+    /// User database path (JSON file); enables per-user login when set
+    #[serde(default)]
     pub user_db_path: Option<PathBuf>,
     /// Password hashing algorithm
     pub password_algorithm: PasswordAlgorithm,
@@ -348,6 +373,41 @@ impl Default for SnapshotConfig {
     }
 }
 
+// ============================================================================
+// Collection Registry Configuration
+// ============================================================================
+
+/// Collection registry configuration
+///
+/// The registry records which embedding provider, model and dimensions
+/// each collection was built with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionsConfig {
+    /// Registry file path; the registry is kept in memory when unset
+    #[serde(default)]
+    pub registry_path: Option<PathBuf>,
+    /// Chunks re-embedded per batch when migrating a collection
+    pub migration_batch_size: usize,
+    /// Key that encrypts exported bundles; bundles cannot be encrypted when unset
+    #[serde(default)]
+    pub bundle_key: Option<EncryptionKeySource>,
+}
+
+/// Default collection registry configuration.
+///
+/// - `registry_path`: None (in memory)
+/// - `migration_batch_size`: `COLLECTION_MIGRATION_BATCH_SIZE`
+/// - `bundle_key`: None
+impl Default for CollectionsConfig {
+    fn default() -> Self {
+        Self {
+            registry_path: None,
+            migration_batch_size: COLLECTION_MIGRATION_BATCH_SIZE,
+            bundle_key: None,
+        }
+    }
+}
+
 // ============================================================================
 // Daemon Configuration
 // ============================================================================
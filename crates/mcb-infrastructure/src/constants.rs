@@ -55,6 +55,14 @@ pub const DEFAULT_CACHE_NAMESPACE: &str = "mcb";
 /// Cache namespace separator
 pub const CACHE_NAMESPACE_SEPARATOR: &str = ":";
 
+/// Upper bound on the TTL of local entries in the tiered cache (seconds)
+///
+/// Bounds how long an entry can stay stale when an invalidation is missed.
+pub const CACHE_TIERED_L1_MAX_TTL_SECS: u64 = 60;
+
+/// Default TTL of cached search results in seconds (5 minutes)
+pub const SEARCH_RESULT_CACHE_DEFAULT_TTL_SECS: u64 = 300;
+
 // ============================================================================
 // HTTP SERVER CONSTANTS
 // ============================================================================
@@ -109,12 +117,33 @@ pub const CIRCUIT_BREAKER_TIMEOUT_SECS: u64 = 60;
 /// Circuit breaker success threshold
 pub const CIRCUIT_BREAKER_SUCCESS_THRESHOLD: u32 = 3;
 
+/// Upper bound for a single provider retry backoff in milliseconds
+pub const RETRY_MAX_DELAY_MS: u64 = 30_000;
+
+/// Time limit for a single provider call attempt in seconds
+pub const PROVIDER_CALL_TIMEOUT_SECS: u64 = 120;
+
 /// Rate limiter default requests per second
 pub const RATE_LIMITER_DEFAULT_RPS: u32 = 100;
 
 /// Rate limiter burst size
 pub const RATE_LIMITER_DEFAULT_BURST: u32 = 200;
 
+/// Rate limiter requests per second for expensive tools
+pub const RATE_LIMITER_EXPENSIVE_RPS: u32 = 1;
+
+/// Rate limiter burst size for expensive tools
+pub const RATE_LIMITER_EXPENSIVE_BURST: u32 = 5;
+
+/// Tools charged against the expensive bucket
+pub const RATE_LIMITER_EXPENSIVE_TOOLS: &[&str] = &["index_codebase", "clear_index"];
+
+/// Tracked buckets before idle, full buckets are evicted
+pub const RATE_LIMITER_MAX_BUCKETS: usize = 10_000;
+
+/// Buckets listed in rate limiter stats
+pub const RATE_LIMITER_STATS_MAX_BUCKETS: usize = 100;
+
 // ============================================================================
 // METRICS CONSTANTS
 // ============================================================================
@@ -148,6 +177,15 @@ pub const BACKUP_FILE_EXTENSION: &str = ".backup";
 /// Temporary file prefix
 pub const TEMP_FILE_PREFIX: &str = "mcb_temp_";
 
+/// Chunks re-embedded per batch during a collection migration
+pub const COLLECTION_MIGRATION_BATCH_SIZE: usize = 64;
+
+/// Vectors written per batch when importing a collection bundle
+pub const COLLECTION_IMPORT_BATCH_SIZE: usize = 256;
+
+/// Vectors rewritten per batch when re-encrypting a collection
+pub const COLLECTION_REENCRYPTION_BATCH_SIZE: usize = 256;
+
 // ============================================================================
 // FILESYSTEM VECTOR STORE CONSTANTS
 // ============================================================================
@@ -199,6 +237,57 @@ pub const LOG_ROTATION_SIZE: u64 = 10 * 1024 * 1024;
 /// Maximum number of log files to keep
 pub const LOG_MAX_FILES: usize = 5;
 
+// ============================================================================
+// AUDIT CONSTANTS
+// ============================================================================
+
+/// Active audit log file name; rotated files are `audit.<n>.jsonl`
+pub const AUDIT_LOG_FILE_NAME: &str = "audit.jsonl";
+
+/// Audit log rotation size in bytes (10MB)
+pub const AUDIT_ROTATION_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Maximum number of rotated audit files to keep
+pub const AUDIT_MAX_FILES: usize = 10;
+
+/// Default number of events returned by an audit query
+pub const AUDIT_QUERY_DEFAULT_LIMIT: usize = 100;
+
+/// Upper bound on events returned by a single audit query
+pub const AUDIT_QUERY_MAX_LIMIT: usize = 1000;
+
+/// Argument names whose values are replaced before auditing (substring match)
+pub const AUDIT_REDACTED_KEYS: &[&str] = &[
+    "token",
+    "password",
+    "secret",
+    "key",
+    "authorization",
+    "credential",
+];
+
+/// Replacement for redacted argument values
+pub const AUDIT_REDACTED_VALUE: &str = "[REDACTED]";
+
+// ============================================================================
+// EVENT STORE CONSTANTS
+// ============================================================================
+
+/// Event log file name inside the event store directory
+pub const EVENT_STORE_FILE_NAME: &str = "events.jsonl";
+
+/// Maximum number of domain events retained by the event store
+pub const EVENT_STORE_MAX_EVENTS: usize = 10_000;
+
+/// Maximum age of retained domain events in seconds (24 hours)
+pub const EVENT_STORE_MAX_AGE_SECS: u64 = 86_400;
+
+/// Default number of events returned by an event history query
+pub const EVENT_QUERY_DEFAULT_LIMIT: usize = 100;
+
+/// Upper bound on events returned by a single event history query
+pub const EVENT_QUERY_MAX_LIMIT: usize = 1000;
+
 // ============================================================================
 // DAEMON CONSTANTS
 // ============================================================================
@@ -242,6 +331,9 @@ pub const AES_GCM_NONCE_SIZE: usize = 12;
 /// PBKDF2 iterations for key derivation
 pub const PBKDF2_ITERATIONS: u32 = 100_000;
 
+/// Salt size in bytes for keys derived from passphrases
+pub const KDF_SALT_SIZE: usize = 16;
+
 // ============================================================================
 // SYNC CONSTANTS
 // ============================================================================
@@ -369,6 +461,12 @@ pub const OPENAI_TOKEN_CACHE_TTL_SECS: u64 = 7200;
 /// OpenAI max tokens per request
 pub const OPENAI_MAX_TOKENS_PER_REQUEST: usize = 8191;
 
+/// Inputs per embedding request for providers without a published limit
+pub const EMBEDDING_BATCH_DEFAULT_MAX_ITEMS: usize = 64;
+
+/// Concurrent embedding requests per provider
+pub const EMBEDDING_BATCH_DEFAULT_CONCURRENCY: usize = 4;
+
 /// Encrypted data padding alignment
 pub const ENCRYPTED_DATA_PADDING: usize = 256;
 
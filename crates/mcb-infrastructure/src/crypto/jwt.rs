@@ -0,0 +1,160 @@
+//! HS256 JSON Web Tokens
+//!
+//! Minimal JWT issuing and validation built on [`HashUtils::hmac_sha256`].
+//! Only the `HS256` algorithm is accepted; tokens with any other `alg`
+//! header are rejected before the signature is checked.
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use mcb_domain::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::token::TokenGenerator;
+use super::utils::HashUtils;
+
+/// Kind of token, stored in the `typ` claim
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JwtTokenKind {
+    /// Short-lived token presented on each request
+    Access,
+    /// Long-lived token exchanged for a new access token
+    Refresh,
+}
+
+/// Claims carried by tokens issued by [`JwtService`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwtClaims {
+    /// Subject (username)
+    pub sub: String,
+    /// Role of the subject at issue time
+    pub role: String,
+    /// Token kind
+    pub typ: JwtTokenKind,
+    /// Issued at (seconds since UNIX epoch)
+    pub iat: u64,
+    /// Expiration (seconds since UNIX epoch)
+    pub exp: u64,
+    /// Unique token id
+    pub jti: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JwtHeader {
+    alg: String,
+    typ: String,
+}
+
+/// HS256 JWT signer and validator
+#[derive(Clone)]
+pub struct JwtService {
+    secret: Vec<u8>,
+    expiration_secs: u64,
+    refresh_expiration_secs: u64,
+}
+
+impl JwtService {
+    /// Create a JWT service from a shared secret and token lifetimes
+    pub fn new(secret: &str, expiration_secs: u64, refresh_expiration_secs: u64) -> Result<Self> {
+        if secret.is_empty() {
+            return Err(Error::configuration("JWT secret cannot be empty"));
+        }
+        Ok(Self {
+            secret: secret.as_bytes().to_vec(),
+            expiration_secs,
+            refresh_expiration_secs,
+        })
+    }
+
+    /// Access token lifetime in seconds
+    pub fn expiration_secs(&self) -> u64 {
+        self.expiration_secs
+    }
+
+    /// Issue a signed token for a subject
+    pub fn issue(&self, subject: &str, role: &str, kind: JwtTokenKind) -> Result<String> {
+        let iat = now_secs();
+        let ttl = match kind {
+            JwtTokenKind::Access => self.expiration_secs,
+            JwtTokenKind::Refresh => self.refresh_expiration_secs,
+        };
+        let claims = JwtClaims {
+            sub: subject.to_string(),
+            role: role.to_string(),
+            typ: kind,
+            iat,
+            exp: iat.saturating_add(ttl),
+            jti: TokenGenerator::generate_uuid(),
+        };
+        self.encode(&claims)
+    }
+
+    /// Sign arbitrary claims
+    pub fn encode(&self, claims: &JwtClaims) -> Result<String> {
+        let header = JwtHeader {
+            alg: "HS256".to_string(),
+            typ: "JWT".to_string(),
+        };
+        let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?);
+        let claims_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?);
+        let signing_input = format!("{}.{}", header_b64, claims_b64);
+        let signature = HashUtils::hmac_sha256(&self.secret, signing_input.as_bytes())?;
+
+        Ok(format!(
+            "{}.{}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    /// Validate signature and expiry, and check the token kind
+    pub fn validate(&self, token: &str, expected: JwtTokenKind) -> Result<JwtClaims> {
+        let mut parts = token.split('.');
+        let (Some(header_b64), Some(claims_b64), Some(signature_b64), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(Error::authentication("Malformed token"));
+        };
+
+        let header: JwtHeader = decode_segment(header_b64)?;
+        if header.alg != "HS256" {
+            return Err(Error::authentication(format!(
+                "Unsupported token algorithm: {}",
+                header.alg
+            )));
+        }
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature_b64)
+            .map_err(|_| Error::authentication("Malformed token signature"))?;
+        let signing_input = format!("{}.{}", header_b64, claims_b64);
+        let expected_signature = HashUtils::hmac_sha256(&self.secret, signing_input.as_bytes())?;
+        if !HashUtils::constant_time_eq(&signature, &expected_signature) {
+            return Err(Error::authentication("Invalid token signature"));
+        }
+
+        let claims: JwtClaims = decode_segment(claims_b64)?;
+        if claims.exp <= now_secs() {
+            return Err(Error::authentication("Token expired"));
+        }
+        if claims.typ != expected {
+            return Err(Error::authentication("Unexpected token type"));
+        }
+
+        Ok(claims)
+    }
+}
+
+fn decode_segment<T: serde::de::DeserializeOwned>(segment: &str) -> Result<T> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|_| Error::authentication("Malformed token"))?;
+    serde_json::from_slice(&bytes).map_err(|_| Error::authentication("Malformed token"))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
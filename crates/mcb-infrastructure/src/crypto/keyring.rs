@@ -0,0 +1,159 @@
+//! Keyring of named AES-GCM keys for key rotation
+
+use crate::config::{EncryptionKeySource, VectorStoreEncryptionConfig};
+use crate::constants::AES_GCM_KEY_SIZE;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{CryptoProvider, EncryptedData};
+use std::collections::BTreeMap;
+
+use super::encryption::CryptoService;
+
+/// Named encryption keys with one active key
+///
+/// New data is encrypted with the active key and tagged with its id. Other
+/// keys are decrypt-only: they keep data written before a rotation readable
+/// until it has been re-encrypted. Data written without a key id is tried
+/// against every key.
+#[derive(Clone)]
+pub struct Keyring {
+    active: String,
+    keys: BTreeMap<String, CryptoService>,
+}
+
+impl Keyring {
+    /// Create a keyring from raw 32-byte keys
+    ///
+    /// # Errors
+    ///
+    /// Fails if a key has the wrong size or `active` is not one of the keys.
+    pub fn new(
+        active: impl Into<String>,
+        keys: impl IntoIterator<Item = (String, Vec<u8>)>,
+    ) -> Result<Self> {
+        let active = active.into();
+        let keys = keys
+            .into_iter()
+            .map(|(id, key)| Ok((id, CryptoService::new(key)?)))
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        if !keys.contains_key(&active) {
+            return Err(Error::configuration(format!(
+                "Active encryption key '{active}' is not in the keyring"
+            )));
+        }
+        Ok(Self { active, keys })
+    }
+
+    /// Load the configured keys from their files or environment variables
+    ///
+    /// # Errors
+    ///
+    /// Fails if a key cannot be read or decoded, a key id is repeated, or the
+    /// active key is not configured.
+    pub fn from_config(config: &VectorStoreEncryptionConfig) -> Result<Self> {
+        let mut keys = Vec::with_capacity(config.keys.len());
+        for source in &config.keys {
+            if keys.iter().any(|(id, _)| id == &source.id) {
+                return Err(Error::configuration(format!(
+                    "Encryption key '{}' is configured twice",
+                    source.id
+                )));
+            }
+            keys.push((source.id.clone(), load_key(source)?));
+        }
+        Self::new(config.active_key.clone(), keys)
+    }
+
+    /// Ids of all keys, sorted
+    pub fn key_ids(&self) -> Vec<&str> {
+        self.keys.keys().map(String::as_str).collect()
+    }
+}
+
+impl CryptoProvider for Keyring {
+    fn encrypt(&self, plaintext: &[u8]) -> Result<EncryptedData> {
+        let key = &self.keys[&self.active];
+        Ok(key.encrypt(plaintext)?.with_key_id(self.active.clone()))
+    }
+
+    fn decrypt(&self, encrypted_data: &EncryptedData) -> Result<Vec<u8>> {
+        if let Some(id) = &encrypted_data.key_id {
+            let key = self.keys.get(id).ok_or_else(|| {
+                Error::configuration(format!("Encryption key '{id}' is not in the keyring"))
+            })?;
+            return key.decrypt(encrypted_data);
+        }
+
+        // Untagged data predates key ids; AES-GCM rejects the wrong key
+        let mut last_error = None;
+        for key in std::iter::once(&self.keys[&self.active]).chain(
+            self.keys
+                .iter()
+                .filter(|(id, _)| **id != self.active)
+                .map(|(_, key)| key),
+        ) {
+            match key.decrypt(encrypted_data) {
+                Ok(plaintext) => return Ok(plaintext),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| Error::infrastructure("Keyring has no keys")))
+    }
+
+    fn provider_name(&self) -> &str {
+        "aes-256-gcm-keyring"
+    }
+
+    fn active_key_id(&self) -> Option<&str> {
+        Some(&self.active)
+    }
+}
+
+impl std::fmt::Debug for Keyring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keyring")
+            .field("active", &self.active)
+            .field("keys", &self.key_ids())
+            .finish()
+    }
+}
+
+/// Read a hex-encoded key from its file or environment variable
+///
+/// # Errors
+///
+/// Fails if the source is unreadable, not hex, or not 32 bytes long.
+pub fn load_key(source: &EncryptionKeySource) -> Result<Vec<u8>> {
+    let encoded = match (&source.file, &source.env) {
+        (Some(path), None) => std::fs::read_to_string(path).map_err(|e| {
+            Error::configuration(format!(
+                "Failed to read encryption key '{}' from {}: {e}",
+                source.id,
+                path.display()
+            ))
+        })?,
+        (None, Some(var)) => std::env::var(var).map_err(|_| {
+            Error::configuration(format!(
+                "Environment variable {var} for encryption key '{}' is not set",
+                source.id
+            ))
+        })?,
+        _ => {
+            return Err(Error::configuration(format!(
+                "Encryption key '{}' needs exactly one of `file` and `env`",
+                source.id
+            )));
+        }
+    };
+
+    let key = hex::decode(encoded.trim()).map_err(|_| {
+        Error::configuration(format!("Encryption key '{}' is not hex-encoded", source.id))
+    })?;
+    if key.len() != AES_GCM_KEY_SIZE {
+        return Err(Error::configuration(format!(
+            "Encryption key '{}' must be {AES_GCM_KEY_SIZE} bytes ({} hex characters)",
+            source.id,
+            AES_GCM_KEY_SIZE * 2
+        )));
+    }
+    Ok(key)
+}
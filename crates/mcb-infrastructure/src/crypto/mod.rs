@@ -2,17 +2,23 @@
 //!
 //! This module provides cryptographic primitives for:
 //! - AES-GCM encryption/decryption
-//! - Password hashing with Argon2
+//! - Keyrings of named keys for key rotation
+//! - Password hashing with Argon2, bcrypt or PBKDF2
+//! - HS256 JWT issuing and validation
 //! - Secure token generation
 //! - Key derivation and secure erasure utilities
 
 mod encryption;
+mod jwt;
+mod keyring;
 mod password;
 mod token;
 mod utils;
 
 pub use encryption::CryptoService;
 // EncryptedData is in mcb-domain - use mcb_application::ports::providers::EncryptedData
+pub use jwt::{JwtClaims, JwtService, JwtTokenKind};
+pub use keyring::{Keyring, load_key};
 pub use password::PasswordService;
 pub use token::TokenGenerator;
 pub use utils::{HashUtils, KeyDerivation, SecureErasure, bytes_to_hex};
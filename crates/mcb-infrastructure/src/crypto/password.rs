@@ -1,28 +1,110 @@
-//! Password hashing service using Argon2
+//! Password hashing service
+//!
+//! Hashes new passwords with the configured [`PasswordAlgorithm`] and verifies
+//! stored hashes of any supported algorithm. All formats are self-describing
+//! (PHC strings for Argon2/PBKDF2, `$2b$` for bcrypt), so changing the
+//! configured algorithm does not invalidate existing user records.
 
 use argon2::{
     Argon2, PasswordHasher,
     password_hash::{PasswordHash, PasswordVerifier, SaltString, rand_core::OsRng as ArgonOsRng},
 };
+use base64::{Engine as _, engine::general_purpose::STANDARD_NO_PAD};
 use mcb_domain::error::{Error, Result};
 
-/// Password hashing service using Argon2
+use super::utils::{HashUtils, KeyDerivation};
+use crate::config::PasswordAlgorithm;
+use crate::constants::{BCRYPT_DEFAULT_COST, PBKDF2_ITERATIONS};
+
+/// PHC identifier used for PBKDF2-SHA256 password hashes
+const PBKDF2_PHC_PREFIX: &str = "$pbkdf2-sha256$";
+
+/// Salt length in bytes for PBKDF2 password hashes
+const PBKDF2_SALT_LEN: usize = 16;
+
+/// Derived key length in bytes for PBKDF2 password hashes
+const PBKDF2_HASH_LEN: usize = 32;
+
+/// Password hashing service
 #[derive(Clone)]
 pub struct PasswordService {
     /// Argon2 configuration
     argon2: Argon2<'static>,
+    /// Algorithm used for new hashes
+    algorithm: PasswordAlgorithm,
 }
 
 impl PasswordService {
-    /// Create a new password service with default configuration
+    /// Create a new password service with default configuration (Argon2)
     pub fn new() -> Self {
+        Self::with_algorithm(PasswordAlgorithm::Argon2)
+    }
+
+    /// Create a password service that hashes with the given algorithm
+    pub fn with_algorithm(algorithm: PasswordAlgorithm) -> Self {
         Self {
             argon2: Argon2::default(),
+            algorithm,
         }
     }
 
-    /// Hash a password using Argon2
+    /// Algorithm used for new hashes
+    pub fn algorithm(&self) -> PasswordAlgorithm {
+        self.algorithm
+    }
+
+    /// Hash a password using the configured algorithm
     pub fn hash_password(&self, password: &str) -> Result<String> {
+        match self.algorithm {
+            PasswordAlgorithm::Argon2 => self.hash_argon2(password),
+            PasswordAlgorithm::Bcrypt => bcrypt::hash(password, BCRYPT_DEFAULT_COST)
+                .map_err(|e| Error::infrastructure(format!("Password hashing failed: {}", e))),
+            PasswordAlgorithm::Pbkdf2 => Ok(Self::hash_pbkdf2(password, PBKDF2_ITERATIONS)),
+        }
+    }
+
+    /// Verify a password against its hash
+    ///
+    /// The algorithm is detected from the hash itself, not from configuration.
+    pub fn verify_password(&self, password: &str, hash: &str) -> Result<bool> {
+        match Self::detect_algorithm(hash) {
+            Some(PasswordAlgorithm::Bcrypt) => bcrypt::verify(password, hash)
+                .map_err(|e| Error::authentication(format!("Invalid password hash format: {}", e))),
+            Some(PasswordAlgorithm::Pbkdf2) => Self::verify_pbkdf2(password, hash),
+            Some(PasswordAlgorithm::Argon2) | None => {
+                let parsed_hash = PasswordHash::new(hash).map_err(|e| Error::Authentication {
+                    message: format!("Invalid password hash format: {}", e),
+                    source: None,
+                })?;
+
+                Ok(self
+                    .argon2
+                    .verify_password(password.as_bytes(), &parsed_hash)
+                    .is_ok())
+            }
+        }
+    }
+
+    /// Whether a stored hash was produced by a different algorithm than the
+    /// configured one and should be re-hashed on the next successful login
+    pub fn needs_rehash(&self, hash: &str) -> bool {
+        Self::detect_algorithm(hash) != Some(self.algorithm)
+    }
+
+    /// Detect the algorithm of a stored hash from its prefix
+    pub fn detect_algorithm(hash: &str) -> Option<PasswordAlgorithm> {
+        if hash.starts_with("$argon2") {
+            Some(PasswordAlgorithm::Argon2)
+        } else if hash.starts_with(PBKDF2_PHC_PREFIX) {
+            Some(PasswordAlgorithm::Pbkdf2)
+        } else if hash.starts_with("$2a$") || hash.starts_with("$2b$") || hash.starts_with("$2y$") {
+            Some(PasswordAlgorithm::Bcrypt)
+        } else {
+            None
+        }
+    }
+
+    fn hash_argon2(&self, password: &str) -> Result<String> {
         let salt = SaltString::generate(&mut ArgonOsRng);
 
         let password_hash = self
@@ -36,17 +118,40 @@ impl PasswordService {
         Ok(password_hash.to_string())
     }
 
-    /// Verify a password against its hash
-    pub fn verify_password(&self, password: &str, hash: &str) -> Result<bool> {
-        let parsed_hash = PasswordHash::new(hash).map_err(|e| Error::Authentication {
-            message: format!("Invalid password hash format: {}", e),
-            source: None,
-        })?;
+    /// Format: `$pbkdf2-sha256$i=<iterations>$<salt>$<hash>` (unpadded base64)
+    fn hash_pbkdf2(password: &str, iterations: u32) -> String {
+        let salt = KeyDerivation::generate_salt(PBKDF2_SALT_LEN);
+        let derived = KeyDerivation::pbkdf2(password, &salt, iterations, PBKDF2_HASH_LEN);
+        format!(
+            "{}i={}${}${}",
+            PBKDF2_PHC_PREFIX,
+            iterations,
+            STANDARD_NO_PAD.encode(salt),
+            STANDARD_NO_PAD.encode(derived)
+        )
+    }
 
-        Ok(self
-            .argon2
-            .verify_password(password.as_bytes(), &parsed_hash)
-            .is_ok())
+    fn verify_pbkdf2(password: &str, hash: &str) -> Result<bool> {
+        let invalid =
+            || Error::authentication("Invalid password hash format: malformed PBKDF2 hash");
+
+        let mut parts = hash[PBKDF2_PHC_PREFIX.len()..].split('$');
+        let iterations: u32 = parts
+            .next()
+            .and_then(|p| p.strip_prefix("i="))
+            .and_then(|i| i.parse().ok())
+            .ok_or_else(invalid)?;
+        let salt = parts
+            .next()
+            .and_then(|s| STANDARD_NO_PAD.decode(s).ok())
+            .ok_or_else(invalid)?;
+        let expected = parts
+            .next()
+            .and_then(|h| STANDARD_NO_PAD.decode(h).ok())
+            .ok_or_else(invalid)?;
+
+        let derived = KeyDerivation::pbkdf2(password, &salt, iterations, expected.len());
+        Ok(HashUtils::constant_time_eq(&derived, &expected))
     }
 }
 
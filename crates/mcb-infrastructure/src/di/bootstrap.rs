@@ -35,7 +35,12 @@
 //! let event_bus = context.event_bus();
 //! ```
 
-use crate::config::AppConfig;
+use crate::collections::{CollectionGuard, CollectionRegistry, ReencryptionJobs};
+use crate::config::{
+    AppConfig, CacheProvider as CacheProviderKind, EventBusConfig,
+    EventBusProvider as EventBusProviderKind,
+};
+use crate::crypto::Keyring;
 use crate::di::admin::{
     CacheAdminInterface, CacheAdminService, EmbeddingAdminInterface, EmbeddingAdminService,
     LanguageAdminInterface, LanguageAdminService, VectorStoreAdminInterface,
@@ -48,6 +53,8 @@ use crate::di::provider_resolvers::{
     CacheProviderResolver, EmbeddingProviderResolver, LanguageProviderResolver,
     VectorStoreProviderResolver,
 };
+use crate::embedding::EmbeddingUsage;
+use crate::event_store::{JsonlEventStore, RecordingEventBus};
 use crate::infrastructure::{
     admin::{NullIndexingOperations, NullPerformanceMetrics},
     auth::NullAuthService,
@@ -57,16 +64,24 @@ use crate::infrastructure::{
     snapshot::NullSnapshotProvider,
     sync::NullSyncProvider,
 };
-use mcb_domain::error::Result;
+use crate::resilience::ProviderResilience;
+use crate::routing::{DefaultProviderRouter, FailoverEmbeddingProvider, InMemoryHealthMonitor};
+use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::admin::{
     IndexingOperationsInterface, PerformanceMetricsInterface, ShutdownCoordinator,
 };
 use mcb_domain::ports::infrastructure::{
-    AuthServiceInterface, EventBusProvider, SnapshotProvider, SyncProvider,
-    SystemMetricsCollectorInterface,
+    AuthServiceInterface, EventBusProvider, EventStoreInterface, ProviderRouter, SnapshotProvider,
+    SyncProvider, SystemMetricsCollectorInterface,
 };
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_providers::events::{NatsEventBusProvider, NullEventBusProvider};
 use std::sync::Arc;
-use tracing::info;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Subject the NATS event bus publishes domain events on
+const NATS_EVENT_SUBJECT: &str = "mcb.events";
 
 /// Application context with provider handles and infrastructure services
 ///
@@ -91,7 +106,6 @@ pub struct AppContext {
     // Provider Resolvers (linkme registry access)
     // Reserved for future admin API operations (list/switch providers)
     // ========================================================================
-    #[allow(dead_code)] // Reserved for admin API: list available providers
     embedding_resolver: Arc<EmbeddingProviderResolver>,
     #[allow(dead_code)] // Reserved for admin API: list available providers
     vector_store_resolver: Arc<VectorStoreProviderResolver>,
@@ -108,11 +122,21 @@ pub struct AppContext {
     cache_admin: Arc<dyn CacheAdminInterface>,
     language_admin: Arc<dyn LanguageAdminInterface>,
 
+    // ========================================================================
+    // Provider Health (router + retry/breaker decorators)
+    // ========================================================================
+    provider_router: Arc<dyn ProviderRouter>,
+    provider_resilience: Option<Arc<ProviderResilience>>,
+    embedding_failover: Option<Arc<FailoverEmbeddingProvider>>,
+    collection_guard: Arc<CollectionGuard>,
+    reencryption: Option<Arc<ReencryptionJobs>>,
+
     // ========================================================================
     // Infrastructure Services (direct storage)
     // ========================================================================
     auth_service: Arc<dyn AuthServiceInterface>,
     event_bus: Arc<dyn EventBusProvider>,
+    event_store: Option<Arc<dyn EventStoreInterface>>,
     metrics_collector: Arc<dyn SystemMetricsCollectorInterface>,
     sync_provider: Arc<dyn SyncProvider>,
     snapshot_provider: Arc<dyn SnapshotProvider>,
@@ -170,6 +194,40 @@ impl AppContext {
         self.language_admin.clone()
     }
 
+    // ========================================================================
+    // Provider Health
+    // ========================================================================
+
+    /// Get the provider router fed by provider call outcomes
+    pub fn provider_router(&self) -> Arc<dyn ProviderRouter> {
+        self.provider_router.clone()
+    }
+
+    /// Get the retry/circuit breaker layer (None when disabled)
+    pub fn provider_resilience(&self) -> Option<Arc<ProviderResilience>> {
+        self.provider_resilience.clone()
+    }
+
+    /// Get the embedding failover chain (None unless `failover` is configured)
+    pub fn embedding_failover(&self) -> Option<Arc<FailoverEmbeddingProvider>> {
+        self.embedding_failover.clone()
+    }
+
+    /// Get the estimated embedding token totals
+    pub fn embedding_usage(&self) -> Arc<EmbeddingUsage> {
+        self.embedding_resolver.usage().clone()
+    }
+
+    /// Get the collection guard (provider switching and migration)
+    pub fn collection_guard(&self) -> Arc<CollectionGuard> {
+        self.collection_guard.clone()
+    }
+
+    /// Get the re-encryption jobs (when vector store encryption is configured)
+    pub fn reencryption(&self) -> Option<Arc<ReencryptionJobs>> {
+        self.reencryption.clone()
+    }
+
     // ========================================================================
     // Infrastructure Services (direct access)
     // ========================================================================
@@ -184,6 +242,11 @@ impl AppContext {
         self.event_bus.clone()
     }
 
+    /// Get the domain event store (None when disabled)
+    pub fn event_store(&self) -> Option<Arc<dyn EventStoreInterface>> {
+        self.event_store.clone()
+    }
+
     /// Get metrics collector
     pub fn metrics(&self) -> Arc<dyn SystemMetricsCollectorInterface> {
         self.metrics_collector.clone()
@@ -226,6 +289,110 @@ impl std::fmt::Debug for AppContext {
     }
 }
 
+/// Create the health-aware router over every registered embedding and
+/// vector store provider
+pub fn create_provider_router() -> Arc<dyn ProviderRouter> {
+    let names = |providers: Vec<(&'static str, &'static str)>| {
+        providers
+            .into_iter()
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>()
+    };
+    Arc::new(DefaultProviderRouter::new(
+        Arc::new(InMemoryHealthMonitor::new()),
+        names(mcb_application::ports::registry::list_embedding_providers()),
+        names(mcb_application::ports::registry::list_vector_store_providers()),
+    ))
+}
+
+/// Create the retry/circuit breaker layer reporting to `router`
+///
+/// Returns `None` when `provider_resilience_enabled` is off.
+pub fn create_provider_resilience(
+    config: &AppConfig,
+    router: &Arc<dyn ProviderRouter>,
+) -> Option<Arc<ProviderResilience>> {
+    ProviderResilience::from_config(&config.system.infrastructure.resilience)
+        .map(|resilience| Arc::new(resilience.with_router(Arc::clone(router))))
+}
+
+/// Load the vector store keyring
+///
+/// Returns `None` when vector store encryption is not configured.
+pub fn create_keyring(config: &AppConfig) -> Result<Option<Arc<Keyring>>> {
+    config
+        .providers
+        .vector_store
+        .encryption
+        .as_ref()
+        .map(|encryption| Keyring::from_config(encryption).map(Arc::new))
+        .transpose()
+}
+
+/// Create the event bus, recording typed events when the event store is enabled
+///
+/// The backend follows `system.infrastructure.event_bus.provider`. Returns
+/// the bus to publish on and the store it records into.
+pub async fn create_event_bus(
+    config: &AppConfig,
+) -> Result<(
+    Arc<dyn EventBusProvider>,
+    Option<Arc<dyn EventStoreInterface>>,
+)> {
+    let bus = create_event_bus_provider(&config.system.infrastructure.event_bus).await?;
+    if config.system.infrastructure.cache.provider == CacheProviderKind::Tiered
+        && config.system.infrastructure.event_bus.provider != EventBusProviderKind::Nats
+    {
+        warn!(
+            "Tiered cache invalidations are not shared with other instances; \
+             set system.infrastructure.event_bus.provider = \"nats\" when several instances share the L2 cache"
+        );
+    }
+    let Some(store) =
+        JsonlEventStore::from_config(&config.system.infrastructure.event_store).await?
+    else {
+        return Ok((bus, None));
+    };
+    let store: Arc<dyn EventStoreInterface> = Arc::new(store);
+    let bus: Arc<dyn EventBusProvider> = Arc::new(RecordingEventBus::new(bus, Arc::clone(&store)));
+    Ok((bus, Some(store)))
+}
+
+async fn create_event_bus_provider(config: &EventBusConfig) -> Result<Arc<dyn EventBusProvider>> {
+    match config.provider {
+        EventBusProviderKind::Tokio => Ok(Arc::new(TokioBroadcastEventBus::with_capacity(
+            config.capacity,
+        ))),
+        EventBusProviderKind::Null => Ok(Arc::new(NullEventBusProvider::new())),
+        EventBusProviderKind::Nats => {
+            let url = config
+                .nats_url
+                .as_deref()
+                .ok_or_else(|| Error::Configuration {
+                    message: "NATS URL is required when the event bus provider is nats".to_string(),
+                    source: None,
+                })?;
+            let connect = NatsEventBusProvider::with_options(
+                url,
+                NATS_EVENT_SUBJECT,
+                config.nats_client_name.as_deref(),
+            );
+            let bus =
+                tokio::time::timeout(Duration::from_millis(config.connection_timeout_ms), connect)
+                    .await
+                    .map_err(|_| Error::Infrastructure {
+                        message: format!(
+                            "Timed out connecting to NATS server at {} after {}ms",
+                            url, config.connection_timeout_ms
+                        ),
+                        source: None,
+                    })??;
+            info!("Publishing domain events on NATS at {}", url);
+            Ok(Arc::new(bus))
+        }
+    }
+}
+
 /// Initialize application context with provider handles and infrastructure services
 ///
 /// Creates:
@@ -241,13 +408,41 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
 
     let config = Arc::new(config);
 
+    // ========================================================================
+    // Create Provider Router and Resilience Layer
+    // ========================================================================
+
+    let provider_router = create_provider_router();
+    let provider_resilience = create_provider_resilience(&config, &provider_router);
+    let keyring = create_keyring(&config)?;
+
+    // Created early so the tiered cache can broadcast invalidations
+    let (event_bus, event_store) = create_event_bus(&config).await?;
+
     // ========================================================================
     // Create Resolvers (components that use linkme registry)
     // ========================================================================
 
-    let embedding_resolver = Arc::new(EmbeddingProviderResolver::new(config.clone()));
-    let vector_store_resolver = Arc::new(VectorStoreProviderResolver::new(config.clone()));
-    let cache_resolver = Arc::new(CacheProviderResolver::new(config.clone()));
+    // Opened first so failover chains pin collections to their recorded model
+    let collections = &config.system.data.collections;
+    let collection_registry = Arc::new(match &collections.registry_path {
+        Some(path) => CollectionRegistry::open(path).await?,
+        None => CollectionRegistry::in_memory(),
+    });
+
+    let embedding_resolver = Arc::new(
+        EmbeddingProviderResolver::new(config.clone())
+            .with_resilience(provider_resilience.clone())
+            .with_router(provider_router.clone())
+            .with_collection_registry(collection_registry.clone()),
+    );
+    let vector_store_resolver = Arc::new(
+        VectorStoreProviderResolver::new(config.clone())
+            .with_resilience(provider_resilience.clone())
+            .with_keyring(keyring.clone()),
+    );
+    let cache_resolver =
+        Arc::new(CacheProviderResolver::new(config.clone()).with_event_bus(event_bus.clone()));
     let language_resolver = Arc::new(LanguageProviderResolver::new(config.clone()));
 
     info!("Created provider resolvers");
@@ -256,9 +451,18 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
     // Resolve initial providers from config
     // ========================================================================
 
-    let embedding_provider = embedding_resolver
-        .resolve_from_config()
+    let embedding_failover = embedding_resolver
+        .resolve_failover()
         .map_err(|e| mcb_domain::error::Error::configuration(format!("Embedding: {e}")))?;
+    if let Some(chain) = &embedding_failover {
+        chain.restore_pins().await;
+    }
+    let embedding_provider: Arc<dyn EmbeddingProvider> = match &embedding_failover {
+        Some(chain) => chain.clone(),
+        None => embedding_resolver
+            .resolve_from_config()
+            .map_err(|e| mcb_domain::error::Error::configuration(format!("Embedding: {e}")))?,
+    };
 
     let vector_store_provider = vector_store_resolver
         .resolve_from_config()
@@ -311,6 +515,19 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
         language_handle.clone(),
     ));
 
+    let collection_guard = Arc::new(
+        CollectionGuard::new(
+            embedding_resolver.clone(),
+            embedding_handle.clone(),
+            vector_store_resolver.clone(),
+            vector_store_handle.clone(),
+            collection_registry,
+        )
+        .with_batch_size(collections.migration_batch_size),
+    );
+    let reencryption =
+        keyring.map(|keyring| Arc::new(ReencryptionJobs::new(collection_guard.clone(), keyring)));
+
     info!("Created admin services");
 
     // ========================================================================
@@ -318,7 +535,6 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
     // ========================================================================
 
     let auth_service: Arc<dyn AuthServiceInterface> = Arc::new(NullAuthService::new());
-    let event_bus: Arc<dyn EventBusProvider> = Arc::new(TokioBroadcastEventBus::new());
     let metrics_collector: Arc<dyn SystemMetricsCollectorInterface> =
         Arc::new(NullSystemMetricsCollector::new());
     let sync_provider: Arc<dyn SyncProvider> = Arc::new(NullSyncProvider::new());
@@ -346,8 +562,14 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
         vector_store_admin,
         cache_admin,
         language_admin,
+        provider_router,
+        provider_resilience,
+        embedding_failover,
+        collection_guard,
+        reencryption,
         auth_service,
         event_bus,
+        event_store,
         metrics_collector,
         sync_provider,
         snapshot_provider,
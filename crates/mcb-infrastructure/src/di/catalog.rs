@@ -23,12 +23,16 @@
 //! let embedding: Arc<dyn EmbeddingProvider> = catalog.get_one()?;
 //! ```
 
+use crate::collections::{CollectionGuard, CollectionRegistry};
 use crate::config::AppConfig;
 use crate::di::admin::{
     CacheAdminInterface, CacheAdminService, EmbeddingAdminInterface, EmbeddingAdminService,
     LanguageAdminInterface, LanguageAdminService, VectorStoreAdminInterface,
     VectorStoreAdminService,
 };
+use crate::di::bootstrap::{
+    create_event_bus, create_keyring, create_provider_resilience, create_provider_router,
+};
 use crate::di::handles::{
     CacheProviderHandle, EmbeddingProviderHandle, LanguageProviderHandle, VectorStoreProviderHandle,
 };
@@ -39,7 +43,6 @@ use crate::di::provider_resolvers::{
 use crate::infrastructure::{
     admin::{NullIndexingOperations, NullPerformanceMetrics},
     auth::NullAuthService,
-    events::TokioBroadcastEventBus,
     lifecycle::DefaultShutdownCoordinator,
     metrics::NullSystemMetricsCollector,
     snapshot::NullSnapshotProvider,
@@ -51,8 +54,7 @@ use mcb_domain::ports::admin::{
     IndexingOperationsInterface, PerformanceMetricsInterface, ShutdownCoordinator,
 };
 use mcb_domain::ports::infrastructure::{
-    AuthServiceInterface, EventBusProvider, SnapshotProvider, SyncProvider,
-    SystemMetricsCollectorInterface,
+    AuthServiceInterface, SnapshotProvider, SyncProvider, SystemMetricsCollectorInterface,
 };
 // Provider traits imported for documentation and future use
 #[allow(unused_imports)]
@@ -80,21 +82,50 @@ use tracing::info;
 /// | `dyn VectorStoreProvider` | linkme registry → config → handle |
 /// | `dyn CacheProvider` | linkme registry → config → handle |
 /// | `dyn LanguageChunkingProvider` | linkme registry → config → handle |
+/// | `dyn ProviderRouter` | DefaultProviderRouter fed by provider decorators |
 /// | `dyn AuthServiceInterface` | NullAuthService (default) |
-/// | `dyn EventBusProvider` | TokioBroadcastEventBus (default) |
+/// | `dyn EventBusProvider` | TokioBroadcastEventBus, recorded when the event store is enabled |
 ///
 pub async fn build_catalog(config: AppConfig) -> Result<Catalog> {
     info!("Building dill Catalog with provider handles");
 
     let config = Arc::new(config);
 
+    // ========================================================================
+    // Create Provider Router and Resilience Layer
+    // ========================================================================
+
+    let provider_router = create_provider_router();
+    let provider_resilience = create_provider_resilience(&config, &provider_router);
+    let keyring = create_keyring(&config)?;
+
+    // Created early so the tiered cache can broadcast invalidations
+    let (event_bus, _) = create_event_bus(&config).await?;
+
     // ========================================================================
     // Create Resolvers (components that use linkme registry)
     // ========================================================================
 
-    let embedding_resolver = Arc::new(EmbeddingProviderResolver::new(config.clone()));
-    let vector_store_resolver = Arc::new(VectorStoreProviderResolver::new(config.clone()));
-    let cache_resolver = Arc::new(CacheProviderResolver::new(config.clone()));
+    // Opened first so failover chains pin collections to their recorded model
+    let collections = &config.system.data.collections;
+    let collection_registry = Arc::new(match &collections.registry_path {
+        Some(path) => CollectionRegistry::open(path).await?,
+        None => CollectionRegistry::in_memory(),
+    });
+
+    let embedding_resolver = Arc::new(
+        EmbeddingProviderResolver::new(config.clone())
+            .with_resilience(provider_resilience.clone())
+            .with_router(provider_router.clone())
+            .with_collection_registry(collection_registry.clone()),
+    );
+    let vector_store_resolver = Arc::new(
+        VectorStoreProviderResolver::new(config.clone())
+            .with_resilience(provider_resilience.clone())
+            .with_keyring(keyring),
+    );
+    let cache_resolver =
+        Arc::new(CacheProviderResolver::new(config.clone()).with_event_bus(event_bus.clone()));
     let language_resolver = Arc::new(LanguageProviderResolver::new(config.clone()));
 
     info!("Created provider resolvers");
@@ -160,6 +191,17 @@ pub async fn build_catalog(config: AppConfig) -> Result<Catalog> {
         language_handle.clone(),
     ));
 
+    let collection_guard = Arc::new(
+        CollectionGuard::new(
+            embedding_resolver.clone(),
+            embedding_handle.clone(),
+            vector_store_resolver.clone(),
+            vector_store_handle.clone(),
+            collection_registry,
+        )
+        .with_batch_size(collections.migration_batch_size),
+    );
+
     info!("Created admin services");
 
     // ========================================================================
@@ -167,7 +209,6 @@ pub async fn build_catalog(config: AppConfig) -> Result<Catalog> {
     // ========================================================================
 
     let auth_service: Arc<dyn AuthServiceInterface> = Arc::new(NullAuthService::new());
-    let event_bus: Arc<dyn EventBusProvider> = Arc::new(TokioBroadcastEventBus::new());
     let metrics_collector: Arc<dyn SystemMetricsCollectorInterface> =
         Arc::new(NullSystemMetricsCollector::new());
     let sync_provider: Arc<dyn SyncProvider> = Arc::new(NullSyncProvider::new());
@@ -208,6 +249,9 @@ pub async fn build_catalog(config: AppConfig) -> Result<Catalog> {
         .add_value(vector_store_admin)
         .add_value(cache_admin)
         .add_value(language_admin)
+        .add_value(collection_guard)
+        // Provider health
+        .add_value(provider_router)
         // Infrastructure services
         .add_value(auth_service)
         .add_value(event_bus)
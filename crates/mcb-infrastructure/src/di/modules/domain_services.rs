@@ -15,12 +15,17 @@ use crate::crypto::CryptoService;
 use mcb_application::domain_services::search::{
     ContextServiceInterface, IndexingServiceInterface, SearchServiceInterface,
 };
-use mcb_application::use_cases::{ContextServiceImpl, IndexingServiceImpl, SearchServiceImpl};
+use mcb_application::ports::admin::PerformanceMetricsInterface;
+use mcb_application::ports::infrastructure::EventBusProvider;
+use mcb_application::use_cases::{
+    ContextServiceImpl, IndexGenerations, IndexingServiceImpl, SearchServiceImpl,
+};
 use mcb_domain::error::Result;
 use mcb_domain::ports::providers::{
-    EmbeddingProvider, LanguageChunkingProvider, VectorStoreProvider,
+    CacheProvider, EmbeddingProvider, LanguageChunkingProvider, VectorStoreProvider,
 };
 use std::sync::Arc;
+use std::time::Duration;
 
 use super::super::bootstrap::AppContext;
 
@@ -41,7 +46,7 @@ pub struct ServiceDependencies {
     pub cache: SharedCacheProvider,
     /// Crypto service (reserved for future use)
     pub crypto: CryptoService,
-    /// Application configuration (reserved for future use)
+    /// Application configuration (search result cache settings)
     pub config: AppConfig,
     /// Embedding provider for vector embeddings
     pub embedding_provider: Arc<dyn EmbeddingProvider>,
@@ -57,27 +62,74 @@ pub struct DomainServicesFactory;
 impl DomainServicesFactory {
     /// Create domain services using infrastructure components
     pub async fn create_services(deps: ServiceDependencies) -> Result<DomainServicesContainer> {
+        Ok(Self::build_services(deps, None, None))
+    }
+
+    /// Create domain services that report to the app context
+    ///
+    /// Searches are recorded in the performance metrics, and cached search
+    /// results also go stale on index events from the event bus.
+    pub async fn create_services_for(
+        app_context: &AppContext,
+        deps: ServiceDependencies,
+    ) -> Result<DomainServicesContainer> {
+        Ok(Self::build_services(
+            deps,
+            Some(app_context.performance()),
+            Some(app_context.event_bus()),
+        ))
+    }
+
+    fn build_services(
+        deps: ServiceDependencies,
+        metrics: Option<Arc<dyn PerformanceMetricsInterface>>,
+        event_bus: Option<Arc<dyn EventBusProvider>>,
+    ) -> DomainServicesContainer {
+        let cache: Arc<dyn CacheProvider> = deps.cache.into();
+        let cache_config = &deps.config.system.infrastructure.cache;
+        // Search results are cached only when asked for; their generations
+        // live in the same cache so other instances see them
+        let generations = (cache_config.enabled && cache_config.search_results.enabled)
+            .then(|| Arc::new(IndexGenerations::new(Arc::clone(&cache))));
+
         // Create context service with dependencies
-        let context_service: Arc<dyn ContextServiceInterface> = Arc::new(ContextServiceImpl::new(
-            deps.cache.into(),
+        let mut context_service = ContextServiceImpl::new(
+            Arc::clone(&cache),
             deps.embedding_provider,
             deps.vector_store_provider,
-        ));
+        );
+        if let Some(generations) = &generations {
+            context_service = context_service.with_index_generations(Arc::clone(generations));
+        }
+        let context_service: Arc<dyn ContextServiceInterface> = Arc::new(context_service);
 
         // Create search service with context service dependency
-        let search_service: Arc<dyn SearchServiceInterface> =
-            Arc::new(SearchServiceImpl::new(Arc::clone(&context_service)));
+        let mut search_service = SearchServiceImpl::new(Arc::clone(&context_service));
+        if let Some(generations) = generations {
+            if let Some(event_bus) = event_bus {
+                generations.follow_events(event_bus);
+            }
+            search_service = search_service.with_result_cache(
+                cache,
+                generations,
+                Duration::from_secs(cache_config.search_results.ttl_secs),
+            );
+        }
+        if let Some(metrics) = metrics {
+            search_service = search_service.with_metrics(metrics);
+        }
+        let search_service: Arc<dyn SearchServiceInterface> = Arc::new(search_service);
 
         // Create indexing service with context service and language chunker dependency
         let indexing_service: Arc<dyn IndexingServiceInterface> = Arc::new(
             IndexingServiceImpl::new(Arc::clone(&context_service), deps.language_chunker),
         );
 
-        Ok(DomainServicesContainer {
+        DomainServicesContainer {
             context_service,
             search_service,
             indexing_service,
-        })
+        }
     }
 
     /// Create indexing service from app context
@@ -100,10 +152,11 @@ impl DomainServicesFactory {
     pub async fn create_context_service(
         app_context: &AppContext,
     ) -> Result<Arc<dyn ContextServiceInterface>> {
-        // Get providers from handles (runtime-swappable)
+        // Cache from its handle; embedding and vector store via the collection guard
         let cache_provider = app_context.cache_handle().get();
-        let embedding_provider = app_context.embedding_handle().get();
-        let vector_store_provider = app_context.vector_store_handle().get();
+        let collection_guard = app_context.collection_guard();
+        let embedding_provider = collection_guard.embedding_provider();
+        let vector_store_provider = collection_guard.vector_store_provider();
 
         Ok(Arc::new(ContextServiceImpl::new(
             cache_provider,
@@ -9,38 +9,178 @@
 //! AppConfig (injected) → Resolver → linkme registry → Arc<dyn Provider>
 //! ```
 
-use crate::config::AppConfig;
+use crate::collections::CollectionRegistry;
+use crate::config::{
+    AppConfig, AzureOpenAIConfig, EdgeVecStoreConfig, FilesystemStoreConfig, LocalModelConfig,
+    OpenAICompatibleConfig, QdrantStoreConfig,
+};
+use crate::constants::CACHE_TIERED_L1_MAX_TTL_SECS;
+use crate::crypto::Keyring;
+use crate::embedding::{BatchingEmbeddingProvider, EmbeddingLimits, EmbeddingUsage};
+use crate::resilience::ProviderResilience;
+use crate::routing::{FailoverEmbeddingProvider, FailoverMember, NullProviderRouter};
 use mcb_application::ports::registry::{
     CacheProviderConfig, EmbeddingProviderConfig, LanguageProviderConfig,
     VectorStoreProviderConfig, resolve_cache_provider, resolve_embedding_provider,
     resolve_language_provider, resolve_vector_store_provider,
 };
+use mcb_domain::ports::infrastructure::EventBusProvider;
+use mcb_domain::ports::infrastructure::routing::ProviderRouter;
 use mcb_domain::ports::providers::{
-    CacheProvider, EmbeddingProvider, LanguageChunkingProvider, VectorStoreProvider,
+    CacheProvider, CryptoProvider, EmbeddingProvider, LanguageChunkingProvider, VectorStoreProvider,
 };
 use mcb_domain::value_objects::{EmbeddingConfig, VectorStoreConfig};
+use mcb_providers::cache::{MokaCacheProvider, TieredCacheProvider};
+use mcb_providers::vector_store::EncryptedVectorStoreProvider;
 use std::sync::Arc;
+use std::time::Duration;
 
 // ============================================================================
 // Embedding Provider Resolver
 // ============================================================================
 
+/// `extra` key carrying the configured per-input token limit
+const EXTRA_MAX_TOKENS: &str = "max_tokens";
+
 /// Resolver component for embedding providers
 ///
 /// Uses the linkme registry to resolve embedding providers by name.
 /// Can resolve from current config or from an override config.
+/// Resolved providers are wrapped with retries and a circuit breaker
+/// when resilience is attached, then with a [`BatchingEmbeddingProvider`]
+/// unless batching is disabled. A non-empty `failover` list in the
+/// embedding config resolves to a [`FailoverEmbeddingProvider`].
 pub struct EmbeddingProviderResolver {
     config: Arc<AppConfig>,
+    resilience: Option<Arc<ProviderResilience>>,
+    router: Option<Arc<dyn ProviderRouter>>,
+    usage: Arc<EmbeddingUsage>,
+    collection_registry: Option<Arc<CollectionRegistry>>,
 }
 
 impl EmbeddingProviderResolver {
     /// Create a new resolver with config
     pub fn new(config: Arc<AppConfig>) -> Self {
-        Self { config }
+        Self {
+            config,
+            resilience: None,
+            router: None,
+            usage: Arc::new(EmbeddingUsage::new()),
+            collection_registry: None,
+        }
+    }
+
+    /// Wrap every resolved provider with `resilience`
+    pub fn with_resilience(mut self, resilience: Option<Arc<ProviderResilience>>) -> Self {
+        self.resilience = resilience;
+        self
+    }
+
+    /// Route failover chains by the health tracked in `router`
+    pub fn with_router(mut self, router: Arc<dyn ProviderRouter>) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Pin failover chains to the models recorded in `registry`
+    pub fn with_collection_registry(mut self, registry: Arc<CollectionRegistry>) -> Self {
+        self.collection_registry = Some(registry);
+        self
+    }
+
+    /// Share estimated token totals with `usage`
+    pub fn with_usage(mut self, usage: Arc<EmbeddingUsage>) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Estimated token totals of every resolved provider
+    pub fn usage(&self) -> &Arc<EmbeddingUsage> {
+        &self.usage
+    }
+
+    /// Apply retries and circuit breaker, then batching
+    fn decorate(
+        &self,
+        config: &EmbeddingProviderConfig,
+        provider: Arc<dyn EmbeddingProvider>,
+    ) -> Arc<dyn EmbeddingProvider> {
+        let provider = match &self.resilience {
+            Some(resilience) => resilience.wrap_embedding(provider),
+            None => provider,
+        };
+        let batching = &self.config.providers.embedding.batching;
+        if !batching.enabled {
+            return provider;
+        }
+        let mut limits = EmbeddingLimits::for_model(&config.provider, config.model.as_deref());
+        if let Some(max_tokens) = config
+            .extra
+            .get(EXTRA_MAX_TOKENS)
+            .and_then(|v| v.parse().ok())
+        {
+            limits = limits.with_max_input_tokens(max_tokens);
+        }
+        Arc::new(BatchingEmbeddingProvider::new(
+            provider,
+            limits,
+            batching,
+            self.usage.clone(),
+        ))
     }
 
     /// Resolve provider from current application config
     pub fn resolve_from_config(&self) -> Result<Arc<dyn EmbeddingProvider>, String> {
+        if let Some(failover) = self.resolve_failover()? {
+            return Ok(failover);
+        }
+        let config = self.configured();
+        resolve_embedding_provider(&config).map(|p| self.decorate(&config, p))
+    }
+
+    /// Resolve the failover chain, `None` when no `failover` list is configured
+    ///
+    /// Every entry must name a `configs` entry; members are decorated like
+    /// any other resolved provider.
+    pub fn resolve_failover(&self) -> Result<Option<Arc<FailoverEmbeddingProvider>>, String> {
+        let embedding = &self.config.providers.embedding;
+        if embedding.failover.is_empty() {
+            return Ok(None);
+        }
+
+        let members = embedding
+            .failover
+            .iter()
+            .map(|name| {
+                let config = embedding
+                    .configs
+                    .get(name)
+                    .ok_or_else(|| format!("Unknown embedding config '{name}' in failover list"))?;
+                let registry_config = self.to_registry(config);
+                let provider = resolve_embedding_provider(&registry_config)?;
+                Ok(FailoverMember::new(
+                    name.clone(),
+                    self.decorate(&registry_config, provider),
+                    config.model.clone(),
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let router = self
+            .router
+            .clone()
+            .unwrap_or_else(|| Arc::new(NullProviderRouter::new()));
+        let mut chain = FailoverEmbeddingProvider::new(members, router)
+            .map_err(|e| e.to_string())?
+            .with_outcome_reporting(self.resilience.is_none());
+        if let Some(registry) = &self.collection_registry {
+            chain = chain.with_registry(Arc::clone(registry));
+        }
+        Ok(Some(Arc::new(chain)))
+    }
+
+    /// Registry config for the single configured provider
+    fn configured(&self) -> EmbeddingProviderConfig {
         // First, check direct config (flat env vars like MCP__PROVIDERS__EMBEDDING__PROVIDER)
         if let Some(ref provider_name) = self.config.providers.embedding.provider {
             let mut registry_config = EmbeddingProviderConfig::new(provider_name);
@@ -56,7 +196,7 @@ impl EmbeddingProviderResolver {
             if let Some(dimensions) = self.config.providers.embedding.dimensions {
                 registry_config = registry_config.with_dimensions(dimensions);
             }
-            return resolve_embedding_provider(&registry_config);
+            return self.with_extras(registry_config);
         }
 
         // Fallback to named config (TOML: [providers.embedding.default])
@@ -69,17 +209,38 @@ impl EmbeddingProviderResolver {
                 .configs
                 .get(&default_config.provider.to_string())
             {
-                let registry_config = embedding_config_to_registry(specific_config);
-                resolve_embedding_provider(&registry_config)
+                self.to_registry(specific_config)
             } else {
                 // Use the default config directly
-                let registry_config = embedding_config_to_registry(default_config);
-                resolve_embedding_provider(&registry_config)
+                self.to_registry(default_config)
             }
         } else {
             // Fallback to null provider if no default configured
-            resolve_embedding_provider(&EmbeddingProviderConfig::new("null"))
+            EmbeddingProviderConfig::new("null")
+        }
+    }
+
+    /// Registry config for a named `configs` entry, with the section extras
+    fn to_registry(&self, config: &EmbeddingConfig) -> EmbeddingProviderConfig {
+        self.with_extras(embedding_config_to_registry(config))
+    }
+
+    /// Apply the `local_model`, `azure` and `openai_compatible` settings
+    ///
+    /// These live on the embedding section rather than on each named config,
+    /// so every resolution path passes them through the same way.
+    fn with_extras(&self, mut registry_config: EmbeddingProviderConfig) -> EmbeddingProviderConfig {
+        let embedding = &self.config.providers.embedding;
+        if let Some(ref local_model) = embedding.local_model {
+            registry_config = with_local_model(registry_config, local_model);
+        }
+        if let Some(ref azure) = embedding.azure {
+            registry_config = with_azure(registry_config, azure);
         }
+        if let Some(ref compatible) = embedding.openai_compatible {
+            registry_config = with_openai_compatible(registry_config, compatible);
+        }
+        registry_config
     }
 
     /// Resolve provider from override config (for admin API)
@@ -87,7 +248,7 @@ impl EmbeddingProviderResolver {
         &self,
         override_config: &EmbeddingProviderConfig,
     ) -> Result<Arc<dyn EmbeddingProvider>, String> {
-        resolve_embedding_provider(override_config)
+        resolve_embedding_provider(override_config).map(|p| self.decorate(override_config, p))
     }
 
     /// List available embedding providers
@@ -110,18 +271,57 @@ impl std::fmt::Debug for EmbeddingProviderResolver {
 ///
 /// Uses the linkme registry to resolve vector store providers by name.
 /// Can resolve from current config or from an override config.
+/// Resolved providers are wrapped with retries and a circuit breaker
+/// when resilience is attached, then with metadata encryption when a
+/// keyring is attached.
 pub struct VectorStoreProviderResolver {
     config: Arc<AppConfig>,
+    resilience: Option<Arc<ProviderResilience>>,
+    keyring: Option<Arc<Keyring>>,
 }
 
 impl VectorStoreProviderResolver {
     /// Create a new resolver with config
     pub fn new(config: Arc<AppConfig>) -> Self {
-        Self { config }
+        Self {
+            config,
+            resilience: None,
+            keyring: None,
+        }
+    }
+
+    /// Wrap every resolved provider with `resilience`
+    pub fn with_resilience(mut self, resilience: Option<Arc<ProviderResilience>>) -> Self {
+        self.resilience = resilience;
+        self
+    }
+
+    /// Encrypt metadata of every resolved provider with `keyring`
+    pub fn with_keyring(mut self, keyring: Option<Arc<Keyring>>) -> Self {
+        self.keyring = keyring;
+        self
+    }
+
+    fn decorate(&self, provider: Arc<dyn VectorStoreProvider>) -> Arc<dyn VectorStoreProvider> {
+        let provider = match &self.resilience {
+            Some(resilience) => resilience.wrap_vector_store(provider),
+            None => provider,
+        };
+        match &self.keyring {
+            Some(keyring) => Arc::new(EncryptedVectorStoreProvider::from_shared(
+                provider,
+                Arc::clone(keyring) as Arc<dyn CryptoProvider>,
+            )),
+            None => provider,
+        }
     }
 
     /// Resolve provider from current application config
     pub fn resolve_from_config(&self) -> Result<Arc<dyn VectorStoreProvider>, String> {
+        self.resolve_configured().map(|p| self.decorate(p))
+    }
+
+    fn resolve_configured(&self) -> Result<Arc<dyn VectorStoreProvider>, String> {
         // First, check direct config (flat env vars like MCP__PROVIDERS__VECTOR_STORE__PROVIDER)
         if let Some(ref provider_name) = self.config.providers.vector_store.provider {
             let mut registry_config = VectorStoreProviderConfig::new(provider_name);
@@ -134,6 +334,15 @@ impl VectorStoreProviderResolver {
             if let Some(ref collection) = self.config.providers.vector_store.collection {
                 registry_config = registry_config.with_collection(collection);
             }
+            if let Some(ref edgevec) = self.config.providers.vector_store.edgevec {
+                registry_config = with_edgevec(registry_config, edgevec);
+            }
+            if let Some(ref filesystem) = self.config.providers.vector_store.filesystem {
+                registry_config = with_filesystem(registry_config, filesystem);
+            }
+            if let Some(ref qdrant) = self.config.providers.vector_store.qdrant {
+                registry_config = with_qdrant(registry_config, qdrant);
+            }
             return resolve_vector_store_provider(&registry_config);
         }
 
@@ -165,7 +374,7 @@ impl VectorStoreProviderResolver {
         &self,
         override_config: &VectorStoreProviderConfig,
     ) -> Result<Arc<dyn VectorStoreProvider>, String> {
-        resolve_vector_store_provider(override_config)
+        resolve_vector_store_provider(override_config).map(|p| self.decorate(p))
     }
 
     /// List available vector store providers
@@ -188,33 +397,59 @@ impl std::fmt::Debug for VectorStoreProviderResolver {
 ///
 /// Uses the linkme registry to resolve cache providers by name.
 /// Can resolve from current config or from an override config.
+/// The `tiered` provider puts a local Moka cache in front of the Redis
+/// provider and broadcasts invalidations over the attached event bus.
 pub struct CacheProviderResolver {
     config: Arc<AppConfig>,
+    event_bus: Option<Arc<dyn EventBusProvider>>,
 }
 
 impl CacheProviderResolver {
     /// Create a new resolver with config
     pub fn new(config: Arc<AppConfig>) -> Self {
-        Self { config }
+        Self {
+            config,
+            event_bus: None,
+        }
+    }
+
+    /// Broadcast tiered cache invalidations over `event_bus`
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBusProvider>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
     }
 
     /// Resolve provider from current application config
     pub fn resolve_from_config(&self) -> Result<Arc<dyn CacheProvider>, String> {
-        let cache_provider_name = match &self.config.system.infrastructure.cache.provider {
+        let cache = &self.config.system.infrastructure.cache;
+        let cache_provider_name = match &cache.provider {
             crate::config::CacheProvider::Moka => "moka",
-            crate::config::CacheProvider::Redis => "redis",
+            crate::config::CacheProvider::Redis | crate::config::CacheProvider::Tiered => "redis",
         };
 
         let registry_config = CacheProviderConfig {
             provider: cache_provider_name.to_string(),
-            uri: self.config.system.infrastructure.cache.redis_url.clone(),
-            max_size: Some(self.config.system.infrastructure.cache.max_size),
-            ttl_secs: Some(self.config.system.infrastructure.cache.default_ttl_secs),
-            namespace: Some(self.config.system.infrastructure.cache.namespace.clone()),
+            uri: cache.redis_url.clone(),
+            max_size: Some(cache.max_size),
+            ttl_secs: Some(cache.default_ttl_secs),
+            namespace: Some(cache.namespace.clone()),
             extra: Default::default(),
         };
 
-        resolve_cache_provider(&registry_config)
+        let provider = resolve_cache_provider(&registry_config)?;
+        if cache.provider != crate::config::CacheProvider::Tiered {
+            return Ok(provider);
+        }
+
+        let l1_ttl = cache.default_ttl_secs.min(CACHE_TIERED_L1_MAX_TTL_SECS);
+        let l1 = MokaCacheProvider::with_config(cache.max_size, Duration::from_secs(l1_ttl));
+        let mut tiered = TieredCacheProvider::new(l1, provider);
+        if let Some(event_bus) = &self.event_bus {
+            tiered = tiered.with_event_bus(Arc::clone(event_bus));
+        }
+        let tiered = Arc::new(tiered);
+        tiered.listen_for_invalidations();
+        Ok(tiered)
     }
 
     /// Resolve provider from override config (for admin API)
@@ -233,7 +468,9 @@ impl CacheProviderResolver {
 
 impl std::fmt::Debug for CacheProviderResolver {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CacheProviderResolver").finish()
+        f.debug_struct("CacheProviderResolver")
+            .field("event_bus", &self.event_bus.is_some())
+            .finish()
     }
 }
 
@@ -287,6 +524,117 @@ impl std::fmt::Debug for LanguageProviderResolver {
 // Helper Functions
 // ============================================================================
 
+/// Pass a local model directory to the FastEmbed factory via `extra`
+fn with_local_model(
+    config: EmbeddingProviderConfig,
+    local_model: &LocalModelConfig,
+) -> EmbeddingProviderConfig {
+    let mut config = config.with_extra("model_dir", local_model.path.to_string_lossy());
+    if let Some(pooling) = &local_model.pooling {
+        config = config.with_extra("pooling", pooling);
+    }
+    if let Some(max_length) = local_model.max_length {
+        config = config
+            .with_extra("max_length", max_length.to_string())
+            .with_extra(EXTRA_MAX_TOKENS, max_length.to_string());
+    }
+    if let Some(checksums) = &local_model.checksums {
+        config = config.with_extra("checksums", checksums.to_string_lossy());
+    }
+    config
+}
+
+/// Pass Azure OpenAI deployment settings via `extra`
+fn with_azure(
+    mut config: EmbeddingProviderConfig,
+    azure: &AzureOpenAIConfig,
+) -> EmbeddingProviderConfig {
+    if let Some(deployment) = &azure.deployment {
+        config = config.with_extra("deployment", deployment);
+    }
+    if let Some(api_version) = &azure.api_version {
+        config = config.with_extra("api_version", api_version);
+    }
+    config
+}
+
+/// Pass OpenAI-compatible request settings via `extra`
+fn with_openai_compatible(
+    mut config: EmbeddingProviderConfig,
+    compatible: &OpenAICompatibleConfig,
+) -> EmbeddingProviderConfig {
+    if let Some(path) = &compatible.path {
+        config = config.with_extra("path", path);
+    }
+    if let Some(response_path) = &compatible.response_path {
+        config = config.with_extra("response_path", response_path);
+    }
+    if let Some(api_key_header) = &compatible.api_key_header {
+        config = config.with_extra("api_key_header", api_key_header);
+    }
+    for (name, value) in &compatible.headers {
+        config = config.with_extra(format!("header.{name}"), value);
+    }
+    config
+}
+
+/// Pass EdgeVec persistence and compaction settings via `extra`
+fn with_edgevec(
+    mut config: VectorStoreProviderConfig,
+    edgevec: &EdgeVecStoreConfig,
+) -> VectorStoreProviderConfig {
+    if let Some(secs) = edgevec.flush_interval_secs {
+        config = config.with_extra("flush_interval_secs", secs.to_string());
+    }
+    if let Some(threshold) = edgevec.compaction_threshold {
+        config = config.with_extra("compaction_threshold", threshold.to_string());
+    }
+    if let Some(min_tombstones) = edgevec.compaction_min_tombstones {
+        config = config.with_extra("compaction_min_tombstones", min_tombstones.to_string());
+    }
+    config
+}
+
+/// Pass filesystem store search and storage settings via `extra`
+fn with_filesystem(
+    mut config: VectorStoreProviderConfig,
+    filesystem: &FilesystemStoreConfig,
+) -> VectorStoreProviderConfig {
+    if let Some(exact_below) = filesystem.exact_below {
+        config = config.with_extra("exact_below", exact_below.to_string());
+    }
+    if let Some(nprobe) = filesystem.nprobe {
+        config = config.with_extra("nprobe", nprobe.to_string());
+    }
+    if let Some(nlist) = filesystem.nlist {
+        config = config.with_extra("nlist", nlist.to_string());
+    }
+    if let Some(ref sync) = filesystem.sync {
+        config = config.with_extra("sync", sync);
+    }
+    if let Some(bytes) = filesystem.checkpoint_wal_bytes {
+        config = config.with_extra("checkpoint_wal_bytes", bytes.to_string());
+    }
+    if let Some(threshold) = filesystem.compaction_threshold {
+        config = config.with_extra("compaction_threshold", threshold.to_string());
+    }
+    config
+}
+
+/// Pass the Qdrant API key and collection distance
+fn with_qdrant(
+    mut config: VectorStoreProviderConfig,
+    qdrant: &QdrantStoreConfig,
+) -> VectorStoreProviderConfig {
+    if let Some(ref api_key) = qdrant.api_key {
+        config = config.with_api_key(api_key);
+    }
+    if let Some(ref distance) = qdrant.distance {
+        config = config.with_extra("distance", distance);
+    }
+    config
+}
+
 /// Convert domain EmbeddingConfig to registry EmbeddingProviderConfig
 fn embedding_config_to_registry(config: &EmbeddingConfig) -> EmbeddingProviderConfig {
     EmbeddingProviderConfig {
@@ -296,7 +644,11 @@ fn embedding_config_to_registry(config: &EmbeddingConfig) -> EmbeddingProviderCo
         base_url: config.base_url.clone(),
         dimensions: config.dimensions,
         cache_dir: None,
-        extra: Default::default(),
+        extra: config
+            .max_tokens
+            .map(|max_tokens| (EXTRA_MAX_TOKENS.to_string(), max_tokens.to_string()))
+            .into_iter()
+            .collect(),
     }
 }
 
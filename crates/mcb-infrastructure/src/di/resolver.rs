@@ -162,7 +162,12 @@ pub fn resolve_providers(config: &AppConfig) -> Result<ResolvedProviders> {
 
     // Cache config from system.infrastructure.cache
     // Use as_str() to decouple from concrete enum - enables registry-based resolution
-    let cache_provider_name = config.system.infrastructure.cache.provider.as_str();
+    // The tiered cache is assembled by CacheProviderResolver around its
+    // registry-resolved Redis tier
+    let cache_provider_name = match &config.system.infrastructure.cache.provider {
+        crate::config::CacheProvider::Tiered => crate::config::CacheProvider::Redis.as_str(),
+        provider => provider.as_str(),
+    };
 
     let cache_config = CacheProviderConfig {
         provider: cache_provider_name.to_string(),
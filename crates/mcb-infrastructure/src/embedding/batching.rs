@@ -0,0 +1,310 @@
+//! Token-aware batching decorator
+
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+use std::ops::Range;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tracing::warn;
+
+use super::limits::EmbeddingLimits;
+use super::usage::{EmbeddingUsage, EmbeddingUsageEntry};
+use crate::config::{EmbeddingBatchingConfig, OversizePolicy};
+
+/// Embedding provider that packs requests under the model limits
+///
+/// Inputs over the per-input token limit are split at line boundaries and
+/// the piece vectors pooled, or truncated, per [`OversizePolicy`]. Pieces
+/// are packed into requests under the token and item limits, and at most
+/// `max_concurrency` requests run at once. Estimated token counts are
+/// recorded in [`EmbeddingUsage`].
+pub struct BatchingEmbeddingProvider {
+    inner: Arc<dyn EmbeddingProvider>,
+    limits: EmbeddingLimits,
+    oversize: OversizePolicy,
+    permits: Semaphore,
+    usage: Arc<EmbeddingUsage>,
+}
+
+/// Inputs prepared for sending
+struct Prepared {
+    /// Text of every piece, in input order
+    pieces: Vec<String>,
+    /// Piece range of every input
+    owners: Vec<Range<usize>>,
+    tokens: Vec<usize>,
+    split: u64,
+    truncated: u64,
+}
+
+impl BatchingEmbeddingProvider {
+    /// Wrap `inner` with `limits`, tightened by `config`
+    pub fn new(
+        inner: Arc<dyn EmbeddingProvider>,
+        mut limits: EmbeddingLimits,
+        config: &EmbeddingBatchingConfig,
+        usage: Arc<EmbeddingUsage>,
+    ) -> Self {
+        if let Some(items) = config.max_batch_items {
+            limits.max_batch_items = limits.max_batch_items.min(items.max(1));
+        }
+        if let Some(tokens) = config.max_batch_tokens {
+            limits.max_batch_tokens = limits
+                .max_batch_tokens
+                .min(tokens.max(limits.max_input_tokens));
+        }
+        Self {
+            inner,
+            limits,
+            oversize: config.oversize,
+            permits: Semaphore::new(config.max_concurrency.max(1)),
+            usage,
+        }
+    }
+
+    /// The wrapped provider
+    pub fn inner(&self) -> &Arc<dyn EmbeddingProvider> {
+        &self.inner
+    }
+
+    /// Limits in effect
+    pub fn limits(&self) -> &EmbeddingLimits {
+        &self.limits
+    }
+
+    fn prepare(&self, texts: &[String]) -> Prepared {
+        let max_chars = self.limits.max_input_chars();
+        let mut prepared = Prepared {
+            pieces: Vec::with_capacity(texts.len()),
+            owners: Vec::with_capacity(texts.len()),
+            tokens: Vec::with_capacity(texts.len()),
+            split: 0,
+            truncated: 0,
+        };
+
+        for text in texts {
+            let start = prepared.pieces.len();
+            if self.limits.estimate_tokens(text) <= self.limits.max_input_tokens {
+                prepared.pieces.push(text.clone());
+            } else if self.oversize == OversizePolicy::Truncate {
+                prepared.pieces.push(text.chars().take(max_chars).collect());
+                prepared.truncated += 1;
+            } else {
+                prepared.pieces.extend(split_text(text, max_chars));
+                prepared.split += 1;
+            }
+            prepared.owners.push(start..prepared.pieces.len());
+        }
+        prepared.tokens = prepared
+            .pieces
+            .iter()
+            .map(|piece| self.limits.estimate_tokens(piece))
+            .collect();
+        prepared
+    }
+
+    /// Pack consecutive pieces into requests under the batch limits
+    fn pack(&self, tokens: &[usize]) -> Vec<Range<usize>> {
+        let mut batches = Vec::new();
+        let mut start = 0;
+        let mut batch_tokens = 0;
+        for (i, &piece_tokens) in tokens.iter().enumerate() {
+            let full = i - start >= self.limits.max_batch_items
+                || batch_tokens + piece_tokens > self.limits.max_batch_tokens;
+            if full && i > start {
+                batches.push(start..i);
+                start = i;
+                batch_tokens = 0;
+            }
+            batch_tokens += piece_tokens;
+        }
+        if start < tokens.len() {
+            batches.push(start..tokens.len());
+        }
+        batches
+    }
+
+    async fn embed_pieces(
+        &self,
+        collection: Option<&str>,
+        texts: &[String],
+    ) -> Result<Vec<Embedding>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let prepared = self.prepare(texts);
+        let batches = self.pack(&prepared.tokens);
+        let responses = try_join_all(batches.iter().map(|range| async {
+            let _permit =
+                self.permits.acquire().await.map_err(|e| {
+                    Error::embedding(format!("Embedding request limiter closed: {e}"))
+                })?;
+            let batch = &prepared.pieces[range.clone()];
+            let embeddings = match collection {
+                Some(collection) => self.inner.embed_batch_for(collection, batch).await?,
+                None => self.inner.embed_batch(batch).await?,
+            };
+            if embeddings.len() != batch.len() {
+                return Err(Error::embedding(format!(
+                    "{} returned {} embeddings for {} inputs",
+                    self.inner.provider_name(),
+                    embeddings.len(),
+                    batch.len()
+                )));
+            }
+            Ok(embeddings)
+        }))
+        .await?;
+        let pieces: Vec<Embedding> = responses.into_iter().flatten().collect();
+
+        if prepared.truncated > 0 {
+            warn!(
+                provider = self.inner.provider_name(),
+                inputs = prepared.truncated,
+                max_tokens = self.limits.max_input_tokens,
+                "Truncated embedding inputs over the model limit"
+            );
+        }
+        if let Some(first) = pieces.first() {
+            self.usage.record(EmbeddingUsageEntry {
+                provider: self.inner.provider_name().to_string(),
+                model: first.model.clone(),
+                requests: batches.len() as u64,
+                inputs: texts.len() as u64,
+                tokens: prepared.tokens.iter().sum::<usize>() as u64,
+                split_inputs: prepared.split,
+                truncated_inputs: prepared.truncated,
+            });
+        }
+
+        prepared
+            .owners
+            .iter()
+            .map(|range| pool(&pieces[range.clone()], self.oversize))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for BatchingEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.embed_pieces(None, texts).await
+    }
+
+    async fn embed_batch_for(&self, collection: &str, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.embed_pieces(Some(collection), texts).await
+    }
+
+    fn dimensions(&self) -> usize {
+        self.inner.dimensions()
+    }
+
+    fn dimensions_for(&self, collection: &str) -> usize {
+        self.inner.dimensions_for(collection)
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+
+    fn provider_name_for(&self, collection: &str) -> &str {
+        self.inner.provider_name_for(collection)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.inner.health_check().await
+    }
+}
+
+/// Split `text` into pieces of at most `max_chars` characters
+///
+/// Pieces end at line boundaries unless a single line is longer than the
+/// limit.
+fn split_text(text: &str, max_chars: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    let mut current_chars = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_chars = line.chars().count();
+        if current_chars + line_chars > max_chars && !current.is_empty() {
+            pieces.push(std::mem::take(&mut current));
+            current_chars = 0;
+        }
+        if line_chars > max_chars {
+            let chars: Vec<char> = line.chars().collect();
+            let mut segments = chars.chunks(max_chars).peekable();
+            while let Some(segment) = segments.next() {
+                if segments.peek().is_some() {
+                    pieces.push(segment.iter().collect());
+                } else {
+                    current = segment.iter().collect();
+                    current_chars = segment.len();
+                }
+            }
+        } else {
+            current.push_str(line);
+            current_chars += line_chars;
+        }
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
+/// Combine the piece vectors of one input
+///
+/// Results are L2-normalized again when every piece was normalized.
+fn pool(pieces: &[Embedding], policy: OversizePolicy) -> Result<Embedding> {
+    match pieces {
+        [] => Err(Error::embedding("No embedding returned for input")),
+        [single] => Ok(single.clone()),
+        [first, rest @ ..] => {
+            let dimensions = first.vector.len();
+            if rest.iter().any(|p| p.vector.len() != dimensions) {
+                return Err(Error::embedding(
+                    "Pieces of one input were embedded with different dimensions",
+                ));
+            }
+
+            let mut vector = first.vector.clone();
+            for piece in rest {
+                for (acc, &value) in vector.iter_mut().zip(&piece.vector) {
+                    match policy {
+                        OversizePolicy::Max => *acc = acc.max(value),
+                        _ => *acc += value,
+                    }
+                }
+            }
+            if policy != OversizePolicy::Max {
+                let count = pieces.len() as f32;
+                vector.iter_mut().for_each(|v| *v /= count);
+            }
+            if pieces.iter().all(|p| is_unit(&p.vector)) {
+                let norm = l2_norm(&vector);
+                if norm > 0.0 {
+                    vector.iter_mut().for_each(|v| *v /= norm);
+                }
+            }
+
+            Ok(Embedding {
+                vector,
+                model: first.model.clone(),
+                dimensions: first.dimensions,
+            })
+        }
+    }
+}
+
+fn l2_norm(vector: &[f32]) -> f32 {
+    vector.iter().map(|v| v * v).sum::<f32>().sqrt()
+}
+
+fn is_unit(vector: &[f32]) -> bool {
+    (l2_norm(vector) - 1.0).abs() < 1e-3
+}
@@ -0,0 +1,110 @@
+//! Token limits and estimation per embedding provider and model
+//!
+//! Limits are conservative published values; tokens are estimated from the
+//! character count with a per-tokenizer ratio tuned for source code, so
+//! estimates err on the high side.
+
+use crate::constants::{
+    EMBEDDING_BATCH_DEFAULT_MAX_ITEMS, OPENAI_MAX_TOKENS_PER_REQUEST, VOYAGEAI_MAX_INPUT_TOKENS,
+};
+
+/// Request limits of an embedding model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmbeddingLimits {
+    /// Maximum tokens of a single input
+    pub max_input_tokens: usize,
+    /// Maximum tokens summed over one request
+    pub max_batch_tokens: usize,
+    /// Maximum inputs in one request
+    pub max_batch_items: usize,
+    /// Average characters per token for source code
+    pub chars_per_token: f32,
+}
+
+impl EmbeddingLimits {
+    /// Limits for `provider` serving `model`
+    ///
+    /// Unknown providers get limits sized for a typical 8k-token model.
+    pub fn for_model(provider: &str, model: Option<&str>) -> Self {
+        let model = model.map(|m| m.strip_prefix("models/").unwrap_or(m));
+        match provider {
+            "openai" | "azure-openai" => Self {
+                max_input_tokens: OPENAI_MAX_TOKENS_PER_REQUEST,
+                max_batch_tokens: 300_000,
+                max_batch_items: 2048,
+                chars_per_token: 3.2,
+            },
+            "voyageai" => Self {
+                max_input_tokens: VOYAGEAI_MAX_INPUT_TOKENS,
+                max_batch_tokens: 120_000,
+                max_batch_items: 128,
+                chars_per_token: 3.0,
+            },
+            "gemini" => Self {
+                max_input_tokens: 2048,
+                max_batch_tokens: 2048 * 100,
+                max_batch_items: 100,
+                chars_per_token: 3.5,
+            },
+            "ollama" => {
+                let max_input_tokens = match model {
+                    Some("all-minilm" | "mxbai-embed-large" | "snowflake-arctic-embed") => 512,
+                    _ => 8192,
+                };
+                Self {
+                    max_input_tokens,
+                    max_batch_tokens: max_input_tokens * EMBEDDING_BATCH_DEFAULT_MAX_ITEMS,
+                    max_batch_items: EMBEDDING_BATCH_DEFAULT_MAX_ITEMS,
+                    chars_per_token: 2.5,
+                }
+            }
+            "fastembed" => Self {
+                max_input_tokens: 512,
+                max_batch_tokens: 512 * 256,
+                max_batch_items: 256,
+                chars_per_token: 2.5,
+            },
+            "hashing" => Self {
+                max_input_tokens: 8192,
+                max_batch_tokens: usize::MAX,
+                max_batch_items: usize::MAX,
+                chars_per_token: 4.0,
+            },
+            "null" => Self {
+                max_input_tokens: 512,
+                max_batch_tokens: usize::MAX,
+                max_batch_items: usize::MAX,
+                chars_per_token: 4.0,
+            },
+            _ => Self {
+                max_input_tokens: 8192,
+                max_batch_tokens: 8192 * EMBEDDING_BATCH_DEFAULT_MAX_ITEMS,
+                max_batch_items: EMBEDDING_BATCH_DEFAULT_MAX_ITEMS,
+                chars_per_token: 3.0,
+            },
+        }
+    }
+
+    /// Override the per-input token limit
+    ///
+    /// The batch token limit grows with it so a full-size input still fits.
+    pub fn with_max_input_tokens(mut self, max_input_tokens: usize) -> Self {
+        self.max_input_tokens = max_input_tokens.max(1);
+        self.max_batch_tokens = self.max_batch_tokens.max(self.max_input_tokens);
+        self
+    }
+
+    /// Estimated token count of `text`
+    pub fn estimate_tokens(&self, text: &str) -> usize {
+        let chars = text.chars().count();
+        if chars == 0 {
+            return 0;
+        }
+        ((chars as f32 / self.chars_per_token).ceil() as usize).max(1)
+    }
+
+    /// Largest character count estimated to fit in one input
+    pub fn max_input_chars(&self) -> usize {
+        ((self.max_input_tokens as f32 * self.chars_per_token).floor() as usize).max(1)
+    }
+}
@@ -0,0 +1,19 @@
+//! Embedding Front-End
+//!
+//! Decorator applied to every resolved embedding provider that keeps
+//! requests within the provider's limits.
+//!
+//! ## Components
+//!
+//! - [`EmbeddingLimits`] - Token and item limits per provider and model
+//! - [`BatchingEmbeddingProvider`] - Packs batches, splits oversized inputs
+//!   and caps concurrent requests
+//! - [`EmbeddingUsage`] - Estimated token totals for cost accounting
+
+mod batching;
+mod limits;
+mod usage;
+
+pub use batching::BatchingEmbeddingProvider;
+pub use limits::EmbeddingLimits;
+pub use usage::{EmbeddingUsage, EmbeddingUsageEntry};
@@ -0,0 +1,70 @@
+//! Embedding usage accounting
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Usage of one provider and model
+///
+/// Token counts are estimates from the batching front-end, not the
+/// provider's billed figures.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct EmbeddingUsageEntry {
+    /// Provider name
+    pub provider: String,
+    /// Model reported by the provider
+    pub model: String,
+    /// Requests sent
+    pub requests: u64,
+    /// Inputs embedded
+    pub inputs: u64,
+    /// Estimated tokens sent
+    pub tokens: u64,
+    /// Inputs split into pieces for being over the model limit
+    pub split_inputs: u64,
+    /// Inputs truncated for being over the model limit
+    pub truncated_inputs: u64,
+}
+
+/// Running totals per provider and model
+#[derive(Debug, Default)]
+pub struct EmbeddingUsage {
+    entries: Mutex<BTreeMap<(String, String), EmbeddingUsageEntry>>,
+}
+
+impl EmbeddingUsage {
+    /// Create empty totals
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `delta` to the totals of its provider and model
+    pub fn record(&self, delta: EmbeddingUsageEntry) {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let entry = entries
+            .entry((delta.provider.clone(), delta.model.clone()))
+            .or_insert_with(|| EmbeddingUsageEntry {
+                provider: delta.provider.clone(),
+                model: delta.model.clone(),
+                ..EmbeddingUsageEntry::default()
+            });
+        entry.requests += delta.requests;
+        entry.inputs += delta.inputs;
+        entry.tokens += delta.tokens;
+        entry.split_inputs += delta.split_inputs;
+        entry.truncated_inputs += delta.truncated_inputs;
+    }
+
+    /// Totals sorted by provider and model
+    pub fn snapshot(&self) -> Vec<EmbeddingUsageEntry> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+}
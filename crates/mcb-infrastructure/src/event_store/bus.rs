@@ -0,0 +1,53 @@
+//! Event bus decorator that records published events
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::infrastructure::{DomainEventStream, EventBusProvider, EventStoreInterface};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Event bus that appends durable typed events to an event store before publishing them
+///
+/// Transient events (see [`DomainEvent::is_durable`]) and raw `publish`
+/// payloads are forwarded without being recorded. A failed append is logged
+/// and does not stop the event from being published.
+pub struct RecordingEventBus {
+    inner: Arc<dyn EventBusProvider>,
+    store: Arc<dyn EventStoreInterface>,
+}
+
+impl RecordingEventBus {
+    /// Record events published on `inner` into `store`
+    pub fn new(inner: Arc<dyn EventBusProvider>, store: Arc<dyn EventStoreInterface>) -> Self {
+        Self { inner, store }
+    }
+}
+
+#[async_trait]
+impl EventBusProvider for RecordingEventBus {
+    async fn publish_event(&self, event: DomainEvent) -> Result<()> {
+        if event.is_durable()
+            && let Err(e) = self.store.append(event.clone()).await
+        {
+            warn!(error = %e, event = event.name(), "Failed to record domain event");
+        }
+        self.inner.publish_event(event).await
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        self.inner.subscribe_events().await
+    }
+
+    fn has_subscribers(&self) -> bool {
+        self.inner.has_subscribers()
+    }
+
+    async fn publish(&self, topic: &str, payload: &[u8]) -> Result<()> {
+        self.inner.publish(topic, payload).await
+    }
+
+    async fn subscribe(&self, topic: &str) -> Result<String> {
+        self.inner.subscribe(topic).await
+    }
+}
@@ -0,0 +1,445 @@
+//! JSONL-backed domain event store
+//!
+//! Retained events are held in memory for queries and replay. With a
+//! directory, every event is also appended to `events.jsonl` so the log
+//! survives restarts; once the file holds more than twice `max_events`
+//! lines it is rewritten with only the retained events. A background task
+//! owns the file: appends queue their line and wait for it outside the
+//! store's lock, and lines queued together are written with one flush.
+
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use futures::stream;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::infrastructure::{
+    EventQuery, EventStoreInterface, StoredEvent, StoredEventStream,
+};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{Mutex, broadcast, mpsc, oneshot};
+use tracing::warn;
+
+use crate::config::EventStoreConfig;
+use crate::constants::{EVENT_QUERY_DEFAULT_LIMIT, EVENT_QUERY_MAX_LIMIT, EVENT_STORE_FILE_NAME};
+
+/// Capacity of the channel feeding live subscribers
+const SUBSCRIBER_CHANNEL_CAPACITY: usize = 1024;
+
+/// Open event log file, owned by the writer task
+struct LogFile {
+    path: PathBuf,
+    file: tokio::fs::File,
+}
+
+/// Work queued for the writer task, in sequence order
+enum LogCommand {
+    /// Append one serialized event, reporting once it is flushed
+    Append {
+        line: Vec<u8>,
+        written: oneshot::Sender<Result<()>>,
+    },
+    /// Rewrite the file with only these retained events
+    Compact(Vec<StoredEvent>),
+}
+
+/// Queue into the writer task and the number of lines the file will hold
+struct LogWriter {
+    commands: mpsc::UnboundedSender<LogCommand>,
+    lines: usize,
+}
+
+/// Retained events and the backing file's writer, guarded together
+struct LogState {
+    events: VecDeque<StoredEvent>,
+    log: Option<LogWriter>,
+}
+
+struct Inner {
+    max_events: usize,
+    max_age: TimeDelta,
+    state: Mutex<LogState>,
+    last_sequence: AtomicU64,
+    sender: broadcast::Sender<StoredEvent>,
+}
+
+/// Append-only domain event log with count and age retention
+#[derive(Clone)]
+pub struct JsonlEventStore {
+    inner: Arc<Inner>,
+}
+
+impl JsonlEventStore {
+    /// Create a store that keeps events in memory only
+    pub fn in_memory(max_events: usize, max_age: Duration) -> Self {
+        Self::with_state(
+            max_events,
+            max_age,
+            LogState {
+                events: VecDeque::new(),
+                log: None,
+            },
+            0,
+        )
+    }
+
+    /// Open the event log in `directory`, loading events retained from a previous run
+    ///
+    /// Sequence numbers continue after the highest one found in the file.
+    pub async fn open(
+        directory: impl AsRef<Path>,
+        max_events: usize,
+        max_age: Duration,
+    ) -> Result<Self> {
+        let directory = directory.as_ref();
+        tokio::fs::create_dir_all(directory)
+            .await
+            .map_err(|e| Error::io_with_source("Failed to create event store directory", e))?;
+        let path = directory.join(EVENT_STORE_FILE_NAME);
+
+        let (events, lines, last_sequence) = load_events(&path).await?;
+        let file = open_append(&path).await?;
+        let (commands, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(write_log(LogFile { path, file }, receiver));
+
+        let store = Self::with_state(
+            max_events,
+            max_age,
+            LogState {
+                events,
+                log: Some(LogWriter { commands, lines }),
+            },
+            last_sequence,
+        );
+        {
+            let mut state = store.inner.state.lock().await;
+            store.inner.prune(&mut state.events);
+        }
+        Ok(store)
+    }
+
+    /// Build from configuration; `None` when the event store is disabled
+    pub async fn from_config(config: &EventStoreConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let max_age = Duration::from_secs(config.max_age_secs);
+        let store = match &config.directory {
+            Some(directory) => Self::open(directory, config.max_events, max_age).await?,
+            None => Self::in_memory(config.max_events, max_age),
+        };
+        Ok(Some(store))
+    }
+
+    fn with_state(
+        max_events: usize,
+        max_age: Duration,
+        state: LogState,
+        last_sequence: u64,
+    ) -> Self {
+        let (sender, _) = broadcast::channel(SUBSCRIBER_CHANNEL_CAPACITY);
+        Self {
+            inner: Arc::new(Inner {
+                max_events,
+                max_age: TimeDelta::from_std(max_age).unwrap_or(TimeDelta::MAX),
+                state: Mutex::new(state),
+                last_sequence: AtomicU64::new(last_sequence),
+                sender,
+            }),
+        }
+    }
+}
+
+impl Inner {
+    /// Drop events beyond the count limit or older than the age limit
+    fn prune(&self, events: &mut VecDeque<StoredEvent>) {
+        let cutoff = Utc::now()
+            .checked_sub_signed(self.max_age)
+            .unwrap_or(chrono::DateTime::<Utc>::MIN_UTC);
+        while events.len() > self.max_events || events.front().is_some_and(|e| e.timestamp < cutoff)
+        {
+            events.pop_front();
+        }
+    }
+
+    /// Retained events with a sequence above `after`, oldest first
+    async fn retained_after(&self, after: u64) -> VecDeque<StoredEvent> {
+        let state = self.state.lock().await;
+        state
+            .events
+            .iter()
+            .filter(|e| e.sequence > after)
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl EventStoreInterface for JsonlEventStore {
+    /// Record `event`, waiting until its line is flushed when the log is on disk
+    ///
+    /// The event is retained and delivered to subscribers even when writing
+    /// it to the file fails.
+    async fn append(&self, event: DomainEvent) -> Result<StoredEvent> {
+        let (stored, written) = {
+            let mut state = self.inner.state.lock().await;
+            let LogState { events, log } = &mut *state;
+
+            let stored = StoredEvent {
+                sequence: self.inner.last_sequence.load(Ordering::Acquire) + 1,
+                timestamp: Utc::now(),
+                event,
+            };
+
+            // Queued under the lock so lines reach the file in sequence order
+            let written = match log.as_mut() {
+                Some(log) => {
+                    let mut line = serde_json::to_vec(&stored)?;
+                    line.push(b'\n');
+                    let (written, done) = oneshot::channel();
+                    log.send(LogCommand::Append { line, written })?;
+                    log.lines += 1;
+                    Some(done)
+                }
+                None => None,
+            };
+
+            self.inner
+                .last_sequence
+                .store(stored.sequence, Ordering::Release);
+            events.push_back(stored.clone());
+            self.inner.prune(events);
+
+            let compact_at = self.inner.max_events.max(1).saturating_mul(2);
+            if let Some(log) = log.as_mut()
+                && log.lines > compact_at
+            {
+                log.send(LogCommand::Compact(events.iter().cloned().collect()))?;
+                log.lines = events.len();
+            }
+
+            // Sent under the lock so subscribers never see events out of order
+            let _ = self.inner.sender.send(stored.clone());
+            (stored, written)
+        };
+
+        if let Some(done) = written {
+            done.await.map_err(|_| log_writer_stopped())??;
+        }
+        Ok(stored)
+    }
+
+    async fn query(&self, query: &EventQuery) -> Result<Vec<StoredEvent>> {
+        let limit = query
+            .limit
+            .unwrap_or(EVENT_QUERY_DEFAULT_LIMIT)
+            .min(EVENT_QUERY_MAX_LIMIT);
+
+        let mut state = self.inner.state.lock().await;
+        self.inner.prune(&mut state.events);
+        Ok(state
+            .events
+            .iter()
+            .rev()
+            .filter(|e| query.matches(e))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn subscribe_after(&self, sequence: Option<u64>) -> Result<StoredEventStream> {
+        let (receiver, pending, last) = {
+            let state = self.inner.state.lock().await;
+            let receiver = self.inner.sender.subscribe();
+            let newest = self.inner.last_sequence.load(Ordering::Acquire);
+            // A sequence from before a restart may lie beyond the log: replay everything
+            let after = match sequence {
+                None => newest,
+                Some(seq) if seq > newest => 0,
+                Some(seq) => seq,
+            };
+            let pending: VecDeque<StoredEvent> = state
+                .events
+                .iter()
+                .filter(|e| e.sequence > after)
+                .cloned()
+                .collect();
+            (receiver, pending, after)
+        };
+
+        let cursor = Cursor {
+            inner: Arc::clone(&self.inner),
+            receiver,
+            pending,
+            last,
+        };
+        let stream = stream::unfold(cursor, |mut cursor| async move {
+            loop {
+                if let Some(event) = cursor.pending.pop_front() {
+                    cursor.last = event.sequence;
+                    return Some((event, cursor));
+                }
+                match cursor.receiver.recv().await {
+                    Ok(event) if event.sequence <= cursor.last => continue,
+                    Ok(event) => {
+                        cursor.last = event.sequence;
+                        return Some((event, cursor));
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!(
+                            "Event store subscriber lagged by {} events, refilling from the log",
+                            n
+                        );
+                        cursor.pending = cursor.inner.retained_after(cursor.last).await;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        Ok(Box::pin(stream))
+    }
+
+    fn last_sequence(&self) -> u64 {
+        self.inner.last_sequence.load(Ordering::Acquire)
+    }
+}
+
+/// Position of one subscriber in the log
+struct Cursor {
+    inner: Arc<Inner>,
+    receiver: broadcast::Receiver<StoredEvent>,
+    /// Events to emit before reading from the channel again
+    pending: VecDeque<StoredEvent>,
+    /// Sequence of the last event emitted
+    last: u64,
+}
+
+impl LogWriter {
+    fn send(&self, command: LogCommand) -> Result<()> {
+        self.commands
+            .send(command)
+            .map_err(|_| log_writer_stopped())
+    }
+}
+
+fn log_writer_stopped() -> Error {
+    Error::infrastructure("Event log writer stopped")
+}
+
+/// Write queued commands to the log file until the store is dropped
+///
+/// Appends that queued up while the previous batch was being written are
+/// written together and flushed once.
+async fn write_log(mut log: LogFile, mut commands: mpsc::UnboundedReceiver<LogCommand>) {
+    let mut batch = Vec::new();
+    let mut waiting = Vec::new();
+    while let Some(command) = commands.recv().await {
+        let mut next = Some(command);
+        while let Some(command) = next.take().or_else(|| commands.try_recv().ok()) {
+            match command {
+                LogCommand::Append { line, written } => {
+                    batch.extend_from_slice(&line);
+                    waiting.push(written);
+                }
+                LogCommand::Compact(events) => {
+                    write_batch(&mut log, &mut batch, &mut waiting).await;
+                    if let Err(e) = compact(&mut log, &events).await {
+                        warn!(error = %e, "Failed to compact event log");
+                    }
+                }
+            }
+        }
+        write_batch(&mut log, &mut batch, &mut waiting).await;
+    }
+}
+
+/// Write and flush the queued lines, then report the outcome to each waiting append
+async fn write_batch(
+    log: &mut LogFile,
+    batch: &mut Vec<u8>,
+    waiting: &mut Vec<oneshot::Sender<Result<()>>>,
+) {
+    if batch.is_empty() {
+        return;
+    }
+    let result = match log.file.write_all(batch).await {
+        Ok(()) => log.file.flush().await.map_err(|e| ("flush", e)),
+        Err(e) => Err(("write", e)),
+    };
+    batch.clear();
+    for written in waiting.drain(..) {
+        let _ = written.send(match &result {
+            Ok(()) => Ok(()),
+            Err((action, e)) => Err(Error::io_with_source(
+                format!("Failed to {action} event log"),
+                std::io::Error::new(e.kind(), e.to_string()),
+            )),
+        });
+    }
+}
+
+/// Read the event log, returning its events, line count and highest sequence
+async fn load_events(path: &Path) -> Result<(VecDeque<StoredEvent>, usize, u64)> {
+    let content = match tokio::fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => {
+            return Err(Error::io_with_source(
+                format!("Failed to read event log {}", path.display()),
+                e,
+            ));
+        }
+    };
+
+    let mut events = VecDeque::new();
+    let mut lines = 0;
+    let mut last_sequence = 0;
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        lines += 1;
+        match serde_json::from_str::<StoredEvent>(line) {
+            Ok(event) => {
+                last_sequence = last_sequence.max(event.sequence);
+                events.push_back(event);
+            }
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Skipping malformed event log line")
+            }
+        }
+    }
+    Ok((events, lines, last_sequence))
+}
+
+async fn open_append(path: &Path) -> Result<tokio::fs::File> {
+    tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await
+        .map_err(|e| {
+            Error::io_with_source(format!("Failed to open event log {}", path.display()), e)
+        })
+}
+
+/// Rewrite the log file with only the retained events
+async fn compact(log: &mut LogFile, events: &[StoredEvent]) -> Result<()> {
+    let mut content = Vec::new();
+    for event in events {
+        serde_json::to_writer(&mut content, event)?;
+        content.push(b'\n');
+    }
+
+    let tmp = log.path.with_extension("jsonl.tmp");
+    tokio::fs::write(&tmp, &content)
+        .await
+        .map_err(|e| Error::io_with_source("Failed to write compacted event log", e))?;
+    tokio::fs::rename(&tmp, &log.path)
+        .await
+        .map_err(|e| Error::io_with_source("Failed to replace event log", e))?;
+
+    log.file = open_append(&log.path).await?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+//! Domain event store
+//!
+//! Append-only log of published domain events, enabled by default with
+//! `system.infrastructure.event_store`. Sequence numbers let SSE clients
+//! resume with `Last-Event-ID`; retention is bounded by count and age.
+//!
+//! | Type | Description |
+//! |------|-------------|
+//! | [`JsonlEventStore`] | In-memory log with optional JSONL persistence |
+//! | [`RecordingEventBus`] | Event bus decorator that appends events to a store |
+
+mod bus;
+mod jsonl;
+
+pub use bus::RecordingEventBus;
+pub use jsonl::JsonlEventStore;
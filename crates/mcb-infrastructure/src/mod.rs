@@ -11,11 +11,14 @@
 //! | Module | Description |
 //! |--------|-------------|
 //! | [`crypto`] | AES-GCM encryption, secure key generation |
+//! | [`auth`] | Local user database, password login and JWTs |
 //!
 //! ### Data & Storage
 //! | Module | Description |
 //! |--------|-------------|
+//! | [`backup`] | Scheduled, pruned and optionally encrypted backups |
 //! | [`cache`] | Moka/Redis caching with TTL and namespaces |
+//! | [`collections`] | Collection provenance and guarded provider switching |
 //!
 //! ### Configuration & DI
 //! | Module | Description |
@@ -29,25 +32,46 @@
 //! |--------|-------------|
 //! | [`health`] | Health check endpoints |
 //! | [`logging`] | Structured logging with tracing |
+//! | [`audit`] | Append-only JSONL audit log |
+//! | [`event_store`] | Domain event log with replay and retention |
+//!
+//! ### Providers
+//! | Module | Description |
+//! |--------|-------------|
+//! | [`embedding`] | Token-aware batching and usage accounting for embeddings |
 //!
 //! ### Routing & Selection
 //! | Module | Description |
 //! |--------|-------------|
 //! | [`routing`] | Provider routing and selection |
+//!
+//! ### Resilience
+//! | Module | Description |
+//! |--------|-------------|
+//! | [`ratelimit`] | Token-bucket rate limiting per principal and session |
+//! | [`resilience`] | Retries and circuit breakers for external providers |
 
 // Clippy allows for complex patterns in infrastructure code
 #![allow(clippy::collapsible_if)]
 #![allow(clippy::manual_range_contains)]
 
 // Core infrastructure modules
+pub mod audit;
+pub mod auth;
+pub mod backup;
 pub mod cache;
+pub mod collections;
 pub mod config;
 pub mod constants;
 pub mod crypto;
 pub mod di;
+pub mod embedding;
 pub mod error_ext;
+pub mod event_store;
 pub mod health;
 pub mod logging;
+pub mod ratelimit;
+pub mod resilience;
 pub mod routing;
 pub mod utils;
 
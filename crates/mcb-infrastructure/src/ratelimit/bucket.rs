@@ -0,0 +1,68 @@
+//! Token bucket
+
+use std::time::{Duration, Instant};
+
+/// A bucket holding up to `capacity` tokens, refilled continuously
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    /// Create a full bucket refilling `rate` tokens per second
+    ///
+    /// A zero `burst` is treated as one token so requests can ever pass.
+    pub fn new(rate: u32, burst: u32) -> Self {
+        let capacity = f64::from(burst.max(1));
+        Self {
+            capacity,
+            refill_per_sec: f64::from(rate),
+            tokens: capacity,
+            updated: Instant::now(),
+        }
+    }
+
+    /// Add the tokens accrued since the last update
+    pub fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.updated = now;
+    }
+
+    /// Tokens currently available (as of the last refill)
+    pub fn available(&self) -> f64 {
+        self.tokens
+    }
+
+    /// Whether the bucket is full (as of the last refill)
+    pub fn is_full(&self) -> bool {
+        self.tokens >= self.capacity
+    }
+
+    /// Time until one token is available
+    ///
+    /// Returns [`Duration::MAX`] for a bucket that never refills.
+    pub fn wait_time(&self) -> Duration {
+        let missing = 1.0 - self.tokens;
+        if missing <= 0.0 {
+            return Duration::ZERO;
+        }
+        if self.refill_per_sec <= 0.0 {
+            return Duration::MAX;
+        }
+        Duration::from_secs_f64(missing / self.refill_per_sec)
+    }
+
+    /// Take one token; the caller must refill first
+    pub fn take(&mut self) -> bool {
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Per-client connection quotas
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::config::LimitsConfig;
+
+/// A request rejected by the connection quotas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionLimited {
+    /// `max_connections` clients already have requests in flight
+    TooManyConnections {
+        /// Configured limit
+        limit: u32,
+    },
+    /// The client already has `max_requests_per_client` in flight
+    TooManyRequests {
+        /// Client address
+        client: String,
+        /// Configured limit
+        limit: u32,
+    },
+}
+
+impl fmt::Display for ConnectionLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyConnections { limit } => {
+                write!(f, "Too many concurrent connections (limit {})", limit)
+            }
+            Self::TooManyRequests { client, limit } => write!(
+                f,
+                "Too many concurrent requests from {} (limit {})",
+                client, limit
+            ),
+        }
+    }
+}
+
+/// Concurrency quotas from `system.infrastructure.limits`
+///
+/// A client (keyed by address) counts as a connection while it has at
+/// least one request or stream in flight. New clients are refused once
+/// `max_connections` are active, and each client may hold at most
+/// `max_requests_per_client` concurrent requests.
+pub struct ConnectionLimiter {
+    max_connections: u32,
+    max_requests_per_client: u32,
+    active: Mutex<HashMap<String, u32>>,
+}
+
+impl ConnectionLimiter {
+    /// Create a limiter with the given quotas
+    pub fn new(max_connections: u32, max_requests_per_client: u32) -> Self {
+        Self {
+            max_connections,
+            max_requests_per_client,
+            active: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Build from resource limits configuration
+    pub fn from_config(config: &LimitsConfig) -> Self {
+        Self::new(config.max_connections, config.max_requests_per_client)
+    }
+
+    /// Reserve a slot for one request from `client`
+    ///
+    /// The slot is released when the returned permit is dropped.
+    pub fn acquire(self: &Arc<Self>, client: &str) -> Result<ConnectionPermit, ConnectionLimited> {
+        let mut active = self
+            .active
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let in_flight = active.get(client).copied().unwrap_or(0);
+        if in_flight == 0 && active.len() >= self.max_connections as usize {
+            return Err(ConnectionLimited::TooManyConnections {
+                limit: self.max_connections,
+            });
+        }
+        if in_flight >= self.max_requests_per_client {
+            return Err(ConnectionLimited::TooManyRequests {
+                client: client.to_string(),
+                limit: self.max_requests_per_client,
+            });
+        }
+        *active.entry(client.to_string()).or_insert(0) += 1;
+
+        Ok(ConnectionPermit {
+            limiter: Arc::clone(self),
+            client: client.to_string(),
+        })
+    }
+
+    /// Number of clients with requests in flight
+    pub fn active_connections(&self) -> usize {
+        self.active
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .len()
+    }
+
+    fn release(&self, client: &str) {
+        let mut active = self
+            .active
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(in_flight) = active.get_mut(client) {
+            *in_flight -= 1;
+            if *in_flight == 0 {
+                active.remove(client);
+            }
+        }
+    }
+}
+
+/// Slot held by an in-flight request
+pub struct ConnectionPermit {
+    limiter: Arc<ConnectionLimiter>,
+    client: String,
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        self.limiter.release(&self.client);
+    }
+}
@@ -0,0 +1,299 @@
+//! Keyed token-bucket rate limiter
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use super::bucket::TokenBucket;
+use crate::config::ResilienceConfig;
+use crate::constants::{RATE_LIMITER_MAX_BUCKETS, RATE_LIMITER_STATS_MAX_BUCKETS};
+
+/// Which budget a request is charged against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitClass {
+    /// Ordinary requests
+    Standard,
+    /// Expensive tools such as indexing
+    Expensive,
+}
+
+impl RateLimitClass {
+    /// Class name as used in error payloads and metrics
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Expensive => "expensive",
+        }
+    }
+}
+
+impl fmt::Display for RateLimitClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A request rejected by the limiter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimited {
+    /// Budget that ran out
+    pub class: RateLimitClass,
+    /// Bucket key that ran out (e.g. `principal:alice`)
+    pub key: String,
+    /// Time until the request would be accepted
+    pub retry_after: Duration,
+}
+
+impl RateLimited {
+    /// Whole seconds to wait, rounded up, for `Retry-After` headers
+    pub fn retry_after_secs(&self) -> u64 {
+        let secs = self.retry_after.as_secs();
+        let rounded = if self.retry_after.subsec_nanos() > 0 {
+            secs.saturating_add(1)
+        } else {
+            secs
+        };
+        rounded.max(1)
+    }
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Rate limit exceeded for {} ({} requests), retry after {}s",
+            self.key,
+            self.class,
+            self.retry_after_secs()
+        )
+    }
+}
+
+/// Bucket with its counters
+struct TrackedBucket {
+    bucket: TokenBucket,
+    allowed: u64,
+    limited: u64,
+}
+
+/// Snapshot of one bucket
+#[derive(Debug, Clone, Serialize)]
+pub struct BucketStats {
+    /// Bucket key
+    pub key: String,
+    /// Bucket class
+    pub class: RateLimitClass,
+    /// Tokens available at snapshot time
+    pub available_tokens: f64,
+    /// Requests accepted
+    pub allowed: u64,
+    /// Requests rejected
+    pub limited: u64,
+}
+
+/// Snapshot of the limiter state
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimiterStats {
+    /// Standard requests per second per key
+    pub standard_rps: u32,
+    /// Standard burst per key
+    pub standard_burst: u32,
+    /// Expensive requests per second per key
+    pub expensive_rps: u32,
+    /// Expensive burst per key
+    pub expensive_burst: u32,
+    /// Requests accepted since startup
+    pub allowed_total: u64,
+    /// Requests rejected since startup
+    pub limited_total: u64,
+    /// Buckets currently tracked
+    pub tracked_buckets: usize,
+    /// Most limited buckets first
+    pub buckets: Vec<BucketStats>,
+}
+
+/// Token-bucket rate limiter keyed by caller
+///
+/// A request names one or more keys (principal, session, client); it is
+/// accepted only if every key's bucket has a token, and then takes one from
+/// each.
+pub struct RateLimiter {
+    standard: (u32, u32),
+    expensive: (u32, u32),
+    expensive_tools: HashSet<String>,
+    buckets: Mutex<HashMap<(RateLimitClass, String), TrackedBucket>>,
+    allowed: AtomicU64,
+    limited: AtomicU64,
+}
+
+impl RateLimiter {
+    /// Create a limiter with per-key rates and bursts for each class
+    pub fn new(
+        standard_rps: u32,
+        standard_burst: u32,
+        expensive_rps: u32,
+        expensive_burst: u32,
+        expensive_tools: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            standard: (standard_rps, standard_burst),
+            expensive: (expensive_rps, expensive_burst),
+            expensive_tools: expensive_tools.into_iter().collect(),
+            buckets: Mutex::new(HashMap::new()),
+            allowed: AtomicU64::new(0),
+            limited: AtomicU64::new(0),
+        }
+    }
+
+    /// Build from configuration; `None` when rate limiting is disabled
+    pub fn from_config(config: &ResilienceConfig) -> Option<Self> {
+        config.rate_limiter_enabled.then(|| {
+            Self::new(
+                config.rate_limiter_rps,
+                config.rate_limiter_burst,
+                config.rate_limiter_expensive_rps,
+                config.rate_limiter_expensive_burst,
+                config.rate_limiter_expensive_tools.iter().cloned(),
+            )
+        })
+    }
+
+    /// Bucket keys for a caller
+    ///
+    /// Authenticated callers use `principal:<name>`, plus `session:<id>`
+    /// when known. Anonymous callers are keyed by `client:<addr>` only,
+    /// falling back to a shared `anonymous`; their session id is
+    /// client-chosen, so it never gets a bucket of its own.
+    pub fn caller_keys(
+        principal: Option<&str>,
+        session: Option<&str>,
+        client: Option<&str>,
+    ) -> Vec<String> {
+        if let Some(principal) = principal {
+            return std::iter::once(format!("principal:{}", principal))
+                .chain(session.map(|s| format!("session:{}", s)))
+                .collect();
+        }
+        match client {
+            Some(client) => vec![format!("client:{}", client)],
+            None => vec!["anonymous".to_string()],
+        }
+    }
+
+    /// Class a tool call is charged against
+    pub fn class_for_tool(&self, tool: &str) -> RateLimitClass {
+        if self.expensive_tools.contains(tool) {
+            RateLimitClass::Expensive
+        } else {
+            RateLimitClass::Standard
+        }
+    }
+
+    /// Accept or reject a request for `keys`
+    ///
+    /// Tokens are only taken when every key has one, so a rejection never
+    /// consumes budget.
+    pub fn check(&self, class: RateLimitClass, keys: &[String]) -> Result<(), RateLimited> {
+        let now = Instant::now();
+        let (rate, burst) = match class {
+            RateLimitClass::Standard => self.standard,
+            RateLimitClass::Expensive => self.expensive,
+        };
+
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if buckets.len() >= RATE_LIMITER_MAX_BUCKETS {
+            evict_idle(&mut buckets, now);
+        }
+
+        let mut blocked: Option<RateLimited> = None;
+        for key in keys {
+            let tracked = buckets
+                .entry((class, key.clone()))
+                .or_insert_with(|| TrackedBucket {
+                    bucket: TokenBucket::new(rate, burst),
+                    allowed: 0,
+                    limited: 0,
+                });
+            tracked.bucket.refill(now);
+            let wait = tracked.bucket.wait_time();
+            if wait > Duration::ZERO && blocked.as_ref().is_none_or(|b| wait > b.retry_after) {
+                blocked = Some(RateLimited {
+                    class,
+                    key: key.clone(),
+                    retry_after: wait,
+                });
+            }
+        }
+
+        if let Some(rejection) = blocked {
+            if let Some(tracked) = buckets.get_mut(&(class, rejection.key.clone())) {
+                tracked.limited += 1;
+            }
+            self.limited.fetch_add(1, Ordering::Relaxed);
+            return Err(rejection);
+        }
+
+        for key in keys {
+            if let Some(tracked) = buckets.get_mut(&(class, key.clone())) {
+                tracked.bucket.take();
+                tracked.allowed += 1;
+            }
+        }
+        self.allowed.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Snapshot of counters and the most limited buckets
+    pub fn stats(&self) -> RateLimiterStats {
+        let now = Instant::now();
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let tracked_buckets = buckets.len();
+        let mut snapshot: Vec<BucketStats> = buckets
+            .iter_mut()
+            .map(|((class, key), tracked)| {
+                tracked.bucket.refill(now);
+                BucketStats {
+                    key: key.clone(),
+                    class: *class,
+                    available_tokens: tracked.bucket.available(),
+                    allowed: tracked.allowed,
+                    limited: tracked.limited,
+                }
+            })
+            .collect();
+        drop(buckets);
+
+        snapshot.sort_by(|a, b| b.limited.cmp(&a.limited).then_with(|| a.key.cmp(&b.key)));
+        snapshot.truncate(RATE_LIMITER_STATS_MAX_BUCKETS);
+
+        RateLimiterStats {
+            standard_rps: self.standard.0,
+            standard_burst: self.standard.1,
+            expensive_rps: self.expensive.0,
+            expensive_burst: self.expensive.1,
+            allowed_total: self.allowed.load(Ordering::Relaxed),
+            limited_total: self.limited.load(Ordering::Relaxed),
+            tracked_buckets,
+            buckets: snapshot,
+        }
+    }
+}
+
+/// Drop buckets that have refilled completely; they behave like new ones
+fn evict_idle(buckets: &mut HashMap<(RateLimitClass, String), TrackedBucket>, now: Instant) {
+    buckets.retain(|_, tracked| {
+        tracked.bucket.refill(now);
+        !tracked.bucket.is_full()
+    });
+}
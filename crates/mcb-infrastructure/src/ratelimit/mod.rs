@@ -0,0 +1,21 @@
+//! Request Rate Limiting
+//!
+//! Token-bucket rate limiting keyed by principal and session, or by client
+//! address for anonymous callers. Each key has one bucket per
+//! [`RateLimitClass`], so expensive tools such as indexing drain their own,
+//! smaller budget without starving ordinary requests.
+//!
+//! Limits come from `system.infrastructure.resilience`
+//! (`rate_limiter_rps`, `rate_limiter_burst`, `rate_limiter_expensive_*`).
+//!
+//! [`ConnectionLimiter`] enforces the concurrency quotas from
+//! `system.infrastructure.limits` (`max_connections`,
+//! `max_requests_per_client`).
+
+mod bucket;
+mod connections;
+mod limiter;
+
+pub use bucket::TokenBucket;
+pub use connections::{ConnectionLimited, ConnectionLimiter, ConnectionPermit};
+pub use limiter::{BucketStats, RateLimitClass, RateLimited, RateLimiter, RateLimiterStats};
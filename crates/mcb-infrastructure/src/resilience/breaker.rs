@@ -0,0 +1,249 @@
+//! Circuit breaker
+
+use serde::Serialize;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Calls pass through
+    Closed,
+    /// Calls fail fast until the open timeout elapses
+    Open,
+    /// One trial call at a time decides whether to close again
+    HalfOpen,
+}
+
+impl CircuitState {
+    /// State name as used in health payloads
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Snapshot of one breaker
+#[derive(Debug, Clone, Serialize)]
+pub struct CircuitBreakerSnapshot {
+    /// Provider category (`embedding` or `vector_store`)
+    pub kind: &'static str,
+    /// Provider the breaker guards
+    pub provider: String,
+    /// Current state
+    pub state: CircuitState,
+    /// Transient failures since the last success
+    pub consecutive_failures: u32,
+    /// Times the breaker has opened
+    pub trips: u64,
+    /// Calls rejected while open
+    pub rejected: u64,
+    /// Seconds until an open breaker lets a trial call through
+    pub retry_after_secs: Option<u64>,
+}
+
+struct BreakerInner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    half_open_successes: u32,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+    trips: u64,
+    rejected: u64,
+}
+
+/// Consecutive-failure circuit breaker
+///
+/// Opens after `failure_threshold` transient failures in a row and rejects
+/// calls for `open_timeout`. It then admits one trial call at a time and
+/// closes after `success_threshold` successes; any failure reopens it.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    success_threshold: u32,
+    open_timeout: Duration,
+    inner: Mutex<BreakerInner>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker
+    pub fn new(failure_threshold: u32, success_threshold: u32, open_timeout: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            success_threshold: success_threshold.max(1),
+            open_timeout,
+            inner: Mutex::new(BreakerInner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                half_open_successes: 0,
+                opened_at: None,
+                probe_in_flight: false,
+                trips: 0,
+                rejected: 0,
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Time left before an open breaker admits a trial call
+    fn remaining_open(&self, inner: &BreakerInner, now: Instant) -> Duration {
+        inner
+            .opened_at
+            .map(|opened| self.open_timeout.saturating_sub(now.duration_since(opened)))
+            .unwrap_or_default()
+    }
+
+    /// Ask to make a call
+    ///
+    /// Returns the time to wait when the call must fail fast. The admitted
+    /// call reports its outcome through the returned permit; a permit
+    /// dropped without an outcome (a cancelled or panicking call) frees the
+    /// half-open probe slot without counting for or against the provider.
+    pub fn try_acquire(&self) -> Result<BreakerPermit<'_>, Duration> {
+        let now = Instant::now();
+        let mut inner = self.lock();
+
+        if inner.state == CircuitState::Open {
+            let remaining = self.remaining_open(&inner, now);
+            if remaining > Duration::ZERO {
+                inner.rejected += 1;
+                return Err(remaining);
+            }
+            inner.state = CircuitState::HalfOpen;
+            inner.half_open_successes = 0;
+            inner.probe_in_flight = false;
+        }
+
+        if inner.state == CircuitState::HalfOpen {
+            if inner.probe_in_flight {
+                inner.rejected += 1;
+                return Err(Duration::ZERO);
+            }
+            inner.probe_in_flight = true;
+        }
+        Ok(BreakerPermit {
+            breaker: self,
+            settled: false,
+        })
+    }
+
+    fn record_success(&self) {
+        let mut inner = self.lock();
+        inner.consecutive_failures = 0;
+        if inner.state == CircuitState::HalfOpen {
+            inner.probe_in_flight = false;
+            inner.half_open_successes += 1;
+            if inner.half_open_successes >= self.success_threshold {
+                inner.state = CircuitState::Closed;
+                inner.opened_at = None;
+            }
+        }
+    }
+
+    fn record_failure(&self) {
+        let mut inner = self.lock();
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        let trip = match inner.state {
+            CircuitState::HalfOpen => true,
+            CircuitState::Closed => inner.consecutive_failures >= self.failure_threshold,
+            CircuitState::Open => false,
+        };
+        if trip {
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(Instant::now());
+            inner.probe_in_flight = false;
+            inner.trips += 1;
+        }
+    }
+
+    fn release(&self) {
+        self.lock().probe_in_flight = false;
+    }
+
+    /// Current state
+    pub fn state(&self) -> CircuitState {
+        let inner = self.lock();
+        match inner.state {
+            CircuitState::Open if self.remaining_open(&inner, Instant::now()) == Duration::ZERO => {
+                CircuitState::HalfOpen
+            }
+            state => state,
+        }
+    }
+
+    /// Snapshot for health reporting
+    pub fn snapshot(&self, kind: &'static str, provider: &str) -> CircuitBreakerSnapshot {
+        let now = Instant::now();
+        let inner = self.lock();
+        let remaining = self.remaining_open(&inner, now);
+        let state = match inner.state {
+            CircuitState::Open if remaining == Duration::ZERO => CircuitState::HalfOpen,
+            state => state,
+        };
+        CircuitBreakerSnapshot {
+            kind,
+            provider: provider.to_string(),
+            state,
+            consecutive_failures: inner.consecutive_failures,
+            trips: inner.trips,
+            rejected: inner.rejected,
+            retry_after_secs: (state == CircuitState::Open)
+                .then(|| remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)),
+        }
+    }
+}
+
+/// An admitted call, returned by [`CircuitBreaker::try_acquire`]
+///
+/// Consumed by [`Self::record_success`] or [`Self::record_failure`];
+/// dropping it otherwise releases the call without an outcome.
+#[must_use = "an unused permit releases the call without recording an outcome"]
+pub struct BreakerPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    settled: bool,
+}
+
+impl BreakerPermit<'_> {
+    /// Record a successful call
+    pub fn record_success(mut self) {
+        self.settled = true;
+        self.breaker.record_success();
+    }
+
+    /// Record a transient failure
+    pub fn record_failure(mut self) {
+        self.settled = true;
+        self.breaker.record_failure();
+    }
+}
+
+impl fmt::Debug for BreakerPermit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BreakerPermit")
+            .field("settled", &self.settled)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for BreakerPermit<'_> {
+    fn drop(&mut self) {
+        if !self.settled {
+            self.breaker.release();
+        }
+    }
+}
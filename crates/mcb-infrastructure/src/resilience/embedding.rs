@@ -0,0 +1,96 @@
+//! Embedding provider decorator
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+use std::sync::Arc;
+
+use super::breaker::CircuitBreaker;
+use super::layer::{ProviderKind, ProviderResilience};
+
+/// Embedding provider with retries and a circuit breaker
+///
+/// Name and dimensions are passed through unchanged.
+pub struct ResilientEmbeddingProvider {
+    inner: Arc<dyn EmbeddingProvider>,
+    breaker: Arc<CircuitBreaker>,
+    resilience: Arc<ProviderResilience>,
+}
+
+impl ResilientEmbeddingProvider {
+    /// Wrap `inner`, sharing breakers with `resilience`
+    pub fn new(inner: Arc<dyn EmbeddingProvider>, resilience: Arc<ProviderResilience>) -> Self {
+        let breaker = resilience.breaker(ProviderKind::Embedding, inner.provider_name());
+        Self {
+            inner,
+            breaker,
+            resilience,
+        }
+    }
+
+    /// The wrapped provider
+    pub fn inner(&self) -> &Arc<dyn EmbeddingProvider> {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for ResilientEmbeddingProvider {
+    async fn embed(&self, text: &str) -> Result<Embedding> {
+        self.resilience
+            .call(&self.breaker, self.inner.provider_name(), "embed", || {
+                self.inner.embed(text)
+            })
+            .await
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "embed_batch",
+                || self.inner.embed_batch(texts),
+            )
+            .await
+    }
+
+    async fn embed_batch_for(&self, collection: &str, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "embed_batch",
+                || self.inner.embed_batch_for(collection, texts),
+            )
+            .await
+    }
+
+    fn dimensions(&self) -> usize {
+        self.inner.dimensions()
+    }
+
+    fn dimensions_for(&self, collection: &str) -> usize {
+        self.inner.dimensions_for(collection)
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+
+    fn provider_name_for(&self, collection: &str) -> &str {
+        self.inner.provider_name_for(collection)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "health_check",
+                || self.inner.health_check(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,302 @@
+//! Shared retry and breaker state for provider decorators
+
+use dashmap::DashMap;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::infrastructure::routing::ProviderRouter;
+use mcb_domain::ports::providers::{EmbeddingProvider, VectorStoreProvider};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use super::breaker::{CircuitBreaker, CircuitBreakerSnapshot, CircuitState};
+use super::embedding::ResilientEmbeddingProvider;
+use super::retry::{FailureKind, RetryPolicy};
+use super::vector_store::ResilientVectorStoreProvider;
+use crate::config::ResilienceConfig;
+
+/// Provider category, used to keep breakers for same-named providers apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProviderKind {
+    /// Embedding providers
+    Embedding,
+    /// Vector store providers
+    VectorStore,
+}
+
+impl ProviderKind {
+    /// Category name as used in health payloads
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Embedding => "embedding",
+            Self::VectorStore => "vector_store",
+        }
+    }
+}
+
+/// Retry policy, breaker settings and one breaker per provider
+///
+/// Shared by every decorator created through [`Self::wrap_embedding`] and
+/// [`Self::wrap_vector_store`], so a provider keeps its breaker across
+/// runtime switches. Call outcomes are reported to the [`ProviderRouter`]
+/// when one is attached.
+pub struct ProviderResilience {
+    policy: RetryPolicy,
+    failure_threshold: u32,
+    success_threshold: u32,
+    open_timeout: Duration,
+    call_timeout: Option<Duration>,
+    router: Option<Arc<dyn ProviderRouter>>,
+    breakers: DashMap<(ProviderKind, String), Arc<CircuitBreaker>>,
+}
+
+impl ProviderResilience {
+    /// Create with an explicit retry policy and breaker settings
+    pub fn new(
+        policy: RetryPolicy,
+        failure_threshold: u32,
+        success_threshold: u32,
+        open_timeout: Duration,
+    ) -> Self {
+        Self {
+            policy,
+            failure_threshold,
+            success_threshold,
+            open_timeout,
+            call_timeout: None,
+            router: None,
+            breakers: DashMap::new(),
+        }
+    }
+
+    /// Build from configuration; `None` when provider resilience is disabled
+    pub fn from_config(config: &ResilienceConfig) -> Option<Self> {
+        config.provider_resilience_enabled.then(|| {
+            let resilience = Self::new(
+                RetryPolicy::new(
+                    config.retry_attempts,
+                    Duration::from_millis(config.retry_delay_ms),
+                    Duration::from_millis(config.retry_max_delay_ms),
+                ),
+                config.circuit_breaker_failure_threshold,
+                config.circuit_breaker_success_threshold,
+                Duration::from_secs(config.circuit_breaker_timeout_secs),
+            );
+            match config.provider_call_timeout_secs {
+                0 => resilience,
+                secs => resilience.with_call_timeout(Duration::from_secs(secs)),
+            }
+        })
+    }
+
+    /// Fail attempts that take longer than `timeout` with a transient error
+    pub fn with_call_timeout(mut self, timeout: Duration) -> Self {
+        self.call_timeout = Some(timeout);
+        self
+    }
+
+    /// Report call outcomes to `router`
+    pub fn with_router(mut self, router: Arc<dyn ProviderRouter>) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Retry policy in use
+    pub fn policy(&self) -> RetryPolicy {
+        self.policy
+    }
+
+    /// Breaker for a provider, created on first use
+    pub fn breaker(&self, kind: ProviderKind, provider: &str) -> Arc<CircuitBreaker> {
+        Arc::clone(
+            self.breakers
+                .entry((kind, provider.to_string()))
+                .or_insert_with(|| {
+                    Arc::new(CircuitBreaker::new(
+                        self.failure_threshold,
+                        self.success_threshold,
+                        self.open_timeout,
+                    ))
+                })
+                .value(),
+        )
+    }
+
+    /// Snapshot of every breaker, sorted by category and provider
+    pub fn snapshot(&self) -> Vec<CircuitBreakerSnapshot> {
+        let mut snapshots: Vec<_> = self
+            .breakers
+            .iter()
+            .map(|entry| {
+                let (kind, provider) = entry.key();
+                entry.value().snapshot(kind.as_str(), provider)
+            })
+            .collect();
+        snapshots.sort_by(|a, b| a.kind.cmp(b.kind).then_with(|| a.provider.cmp(&b.provider)));
+        snapshots
+    }
+
+    /// Decorate an embedding provider
+    pub fn wrap_embedding(
+        self: &Arc<Self>,
+        inner: Arc<dyn EmbeddingProvider>,
+    ) -> Arc<dyn EmbeddingProvider> {
+        Arc::new(ResilientEmbeddingProvider::new(inner, Arc::clone(self)))
+    }
+
+    /// Decorate a vector store provider
+    pub fn wrap_vector_store(
+        self: &Arc<Self>,
+        inner: Arc<dyn VectorStoreProvider>,
+    ) -> Arc<dyn VectorStoreProvider> {
+        Arc::new(ResilientVectorStoreProvider::new(inner, Arc::clone(self)))
+    }
+
+    /// Run an idempotent `operation` with retries behind `breaker`
+    ///
+    /// Transient failures are retried with backoff until the policy gives
+    /// up or the breaker opens, in which case the last error is returned.
+    /// An open breaker fails fast with [`Error::Unavailable`]. Permanent
+    /// errors are returned at once and do not count against the breaker.
+    pub(crate) async fn call<T, F, Fut>(
+        &self,
+        breaker: &CircuitBreaker,
+        provider: &str,
+        operation: &str,
+        attempt: F,
+    ) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.run(self.policy, breaker, provider, operation, attempt)
+            .await
+    }
+
+    /// Run a non-idempotent `operation` once behind `breaker`
+    ///
+    /// A lost response may hide a completed write, so failures are
+    /// returned without retrying; they still count against the breaker.
+    pub(crate) async fn call_once<T, F, Fut>(
+        &self,
+        breaker: &CircuitBreaker,
+        provider: &str,
+        operation: &str,
+        attempt: F,
+    ) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.run(RetryPolicy::none(), breaker, provider, operation, attempt)
+            .await
+    }
+
+    async fn run<T, F, Fut>(
+        &self,
+        policy: RetryPolicy,
+        breaker: &CircuitBreaker,
+        provider: &str,
+        operation: &str,
+        mut attempt: F,
+    ) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut retry = 0;
+        loop {
+            let permit = match breaker.try_acquire() {
+                Ok(permit) => permit,
+                Err(wait) => {
+                    let secs = (wait.as_secs() + u64::from(wait.subsec_nanos() > 0)).max(1);
+                    let error = Error::unavailable(
+                        format!("circuit open for {provider}, retry after {secs}s"),
+                        Some(wait),
+                    );
+                    self.report_failure(provider, &error).await;
+                    return Err(error);
+                }
+            };
+
+            let outcome = match self.call_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, attempt())
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(Error::network(format!(
+                            "{operation} on {provider} timed out after {}ms",
+                            timeout.as_millis()
+                        )))
+                    }),
+                None => attempt().await,
+            };
+            let error = match outcome {
+                Ok(value) => {
+                    permit.record_success();
+                    self.report_success(provider).await;
+                    return Ok(value);
+                }
+                Err(error) => error,
+            };
+
+            // Dropping the permit releases the call without an outcome
+            let FailureKind::Transient { retry_after } = FailureKind::of(&error) else {
+                return Err(error);
+            };
+            permit.record_failure();
+
+            let delay = match breaker.state() {
+                CircuitState::Open => None,
+                CircuitState::Closed | CircuitState::HalfOpen => policy.delay(retry, retry_after),
+            };
+            match delay {
+                Some(delay) => {
+                    debug!(
+                        provider,
+                        operation,
+                        retry = retry + 1,
+                        delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                        error = %error,
+                        "Retrying provider call"
+                    );
+                    tokio::time::sleep(delay).await;
+                    retry += 1;
+                }
+                None => {
+                    warn!(provider, operation, error = %error, "Provider call failed");
+                    self.report_failure(provider, &error).await;
+                    return Err(error);
+                }
+            }
+        }
+    }
+
+    async fn report_success(&self, provider: &str) {
+        if let Some(router) = &self.router
+            && let Err(e) = router.report_success(provider).await
+        {
+            debug!(provider, error = %e, "Failed to report provider success");
+        }
+    }
+
+    async fn report_failure(&self, provider: &str, error: &Error) {
+        if let Some(router) = &self.router
+            && let Err(e) = router.report_failure(provider, &error.to_string()).await
+        {
+            debug!(provider, error = %e, "Failed to report provider failure");
+        }
+    }
+}
+
+impl std::fmt::Debug for ProviderResilience {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProviderResilience")
+            .field("policy", &self.policy)
+            .field("failure_threshold", &self.failure_threshold)
+            .field("success_threshold", &self.success_threshold)
+            .field("open_timeout", &self.open_timeout)
+            .field("call_timeout", &self.call_timeout)
+            .field("breakers", &self.breakers.len())
+            .finish_non_exhaustive()
+    }
+}
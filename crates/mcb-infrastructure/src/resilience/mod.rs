@@ -0,0 +1,35 @@
+//! Provider Resilience
+//!
+//! Decorators for embedding and vector store providers that retry transient
+//! failures (rate limits, 5xx responses, timeouts, connection errors) with
+//! jittered exponential backoff and trip a per-provider circuit breaker
+//! that fails fast while the provider is down.
+//!
+//! Providers mark retryable failures with
+//! [`Error::Unavailable`](mcb_domain::error::Error::Unavailable), which
+//! carries any server `Retry-After`, or
+//! [`Error::Network`](mcb_domain::error::Error::Network). Only idempotent
+//! calls are retried; vector inserts run once. Outcomes are reported to the
+//! [`ProviderRouter`](mcb_domain::ports::infrastructure::ProviderRouter)
+//! and breaker state is exposed for health endpoints through
+//! [`ProviderResilience::snapshot`].
+//!
+//! Each attempt is bounded by `provider_call_timeout_secs`; an attempt that
+//! outlives it fails with a transient [`Error::Network`](mcb_domain::error::Error::Network)
+//! so a hung provider trips the breaker like an unreachable one.
+//!
+//! Settings come from `system.infrastructure.resilience`
+//! (`retry_*`, `circuit_breaker_*`, `provider_call_timeout_secs`,
+//! `provider_resilience_enabled`).
+
+mod breaker;
+mod embedding;
+mod layer;
+mod retry;
+mod vector_store;
+
+pub use breaker::{BreakerPermit, CircuitBreaker, CircuitBreakerSnapshot, CircuitState};
+pub use embedding::ResilientEmbeddingProvider;
+pub use layer::{ProviderKind, ProviderResilience};
+pub use retry::{FailureKind, RetryPolicy};
+pub use vector_store::ResilientVectorStoreProvider;
@@ -0,0 +1,86 @@
+//! Retry policy and error classification
+
+use mcb_domain::error::Error;
+use std::time::Duration;
+
+/// Whether a failed call is worth retrying
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// Rate limits, 5xx responses, timeouts and connection errors
+    Transient {
+        /// Server-provided wait, if any
+        retry_after: Option<Duration>,
+    },
+    /// Anything a retry would not fix (bad input, auth, not found)
+    Permanent,
+}
+
+impl FailureKind {
+    /// Classify a provider error
+    ///
+    /// Providers report retryable failures as [`Error::Unavailable`]
+    /// (rate limits, 5xx, open breakers) or [`Error::Network`] (timeouts,
+    /// connection failures); every other variant is permanent.
+    pub fn of(error: &Error) -> Self {
+        match error {
+            Error::Unavailable { retry_after, .. } => Self::Transient {
+                retry_after: *retry_after,
+            },
+            Error::Network { .. } => Self::Transient { retry_after: None },
+            _ => Self::Permanent,
+        }
+    }
+
+    /// True for transient failures
+    pub fn is_transient(self) -> bool {
+        matches!(self, Self::Transient { .. })
+    }
+}
+
+/// Jittered exponential backoff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound for a single delay
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a policy
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Delay before retry number `retry` (0-based)
+    ///
+    /// The exponential delay is jittered to between half and all of its
+    /// value. A server `Retry-After` is a lower bound; if it exceeds
+    /// `max_delay` the call is not retried and `None` is returned.
+    pub fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if retry >= self.max_retries {
+            return None;
+        }
+        if retry_after.is_some_and(|wait| wait > self.max_delay) {
+            return None;
+        }
+
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+        let jittered = exponential.mul_f64(0.5 + rand::random::<f64>() * 0.5);
+        Some(retry_after.map_or(jittered, |wait| jittered.max(wait)))
+    }
+}
@@ -0,0 +1,281 @@
+//! Vector store provider decorator
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
+use mcb_domain::value_objects::{Embedding, MetadataFilter, SearchResult, StoredVector};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::breaker::CircuitBreaker;
+use super::layer::{ProviderKind, ProviderResilience};
+
+/// Vector store provider with retries and a circuit breaker
+///
+/// Only idempotent operations (reads, upserts, deletes) are retried.
+/// `insert_vectors` and `create_collection` run once: after a lost
+/// response a retry could store duplicates or fail on the collection the
+/// first attempt created.
+pub struct ResilientVectorStoreProvider {
+    inner: Arc<dyn VectorStoreProvider>,
+    breaker: Arc<CircuitBreaker>,
+    resilience: Arc<ProviderResilience>,
+}
+
+impl ResilientVectorStoreProvider {
+    /// Wrap `inner`, sharing breakers with `resilience`
+    pub fn new(inner: Arc<dyn VectorStoreProvider>, resilience: Arc<ProviderResilience>) -> Self {
+        let breaker = resilience.breaker(ProviderKind::VectorStore, inner.provider_name());
+        Self {
+            inner,
+            breaker,
+            resilience,
+        }
+    }
+
+    /// The wrapped provider
+    pub fn inner(&self) -> &Arc<dyn VectorStoreProvider> {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for ResilientVectorStoreProvider {
+    async fn collection_exists(&self, name: &str) -> Result<bool> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "collection_exists",
+                || self.inner.collection_exists(name),
+            )
+            .await
+    }
+
+    async fn get_stats(&self, collection: &str) -> Result<HashMap<String, Value>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "get_stats",
+                || self.inner.get_stats(collection),
+            )
+            .await
+    }
+
+    async fn flush(&self, collection: &str) -> Result<()> {
+        self.resilience
+            .call(&self.breaker, self.inner.provider_name(), "flush", || {
+                self.inner.flush(collection)
+            })
+            .await
+    }
+
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "list_collection_names",
+                || self.inner.list_collection_names(),
+            )
+            .await
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "health_check",
+                || self.inner.health_check(),
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for ResilientVectorStoreProvider {
+    async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
+        self.resilience
+            .call_once(
+                &self.breaker,
+                self.inner.provider_name(),
+                "create_collection",
+                || self.inner.create_collection(name, dimensions),
+            )
+            .await
+    }
+
+    async fn delete_collection(&self, name: &str) -> Result<()> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "delete_collection",
+                || self.inner.delete_collection(name),
+            )
+            .await
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &str,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.resilience
+            .call_once(
+                &self.breaker,
+                self.inner.provider_name(),
+                "insert_vectors",
+                || {
+                    self.inner
+                        .insert_vectors(collection, vectors, metadata.clone())
+                },
+            )
+            .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "upsert_vectors",
+                || {
+                    self.inner
+                        .upsert_vectors(collection, ids, vectors, metadata.clone())
+                },
+            )
+            .await
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+    ) -> Result<Vec<SearchResult>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "search_similar",
+                || {
+                    self.inner
+                        .search_similar(collection, query_vector, limit, filter)
+                },
+            )
+            .await
+    }
+
+    async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "delete_vectors",
+                || self.inner.delete_vectors(collection, ids),
+            )
+            .await
+    }
+
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "delete_by_filter",
+                || self.inner.delete_by_filter(collection, filter),
+            )
+            .await
+    }
+
+    async fn delete_by_file(&self, collection: &str, file_path: &str) -> Result<u64> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "delete_by_file",
+                || self.inner.delete_by_file(collection, file_path),
+            )
+            .await
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "file_vector_ids",
+                || self.inner.file_vector_ids(collection, file_path),
+            )
+            .await
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &str,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "get_vectors_by_ids",
+                || self.inner.get_vectors_by_ids(collection, ids),
+            )
+            .await
+    }
+
+    async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "list_vectors",
+                || self.inner.list_vectors(collection, limit),
+            )
+            .await
+    }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "export_vectors",
+                || self.inner.export_vectors(collection),
+            )
+            .await
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        self.resilience
+            .call(
+                &self.breaker,
+                self.inner.provider_name(),
+                "export_vectors_page",
+                || self.inner.export_vectors_page(collection, after, limit),
+            )
+            .await
+    }
+}
@@ -0,0 +1,477 @@
+//! Embedding Provider Failover
+//!
+//! Routes embedding calls across a prioritized list of providers using the
+//! health reported to the [`ProviderRouter`]. Each collection is pinned to
+//! the model and dimensions recorded for it in the collection registry, and
+//! failover only moves between members sharing that model and
+//! dimensionality, so vectors from different embedding spaces never end up
+//! in the same collection.
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::infrastructure::routing::{ProviderHealthStatus, ProviderRouter};
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+use serde::Serialize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{debug, warn};
+
+use crate::collections::CollectionRegistry;
+use crate::resilience::FailureKind;
+
+/// One entry of the failover chain
+pub struct FailoverMember {
+    id: String,
+    provider: Arc<dyn EmbeddingProvider>,
+    model: String,
+    dimensions: usize,
+}
+
+impl FailoverMember {
+    /// Create a member; `id` is the config entry name
+    pub fn new(
+        id: impl Into<String>,
+        provider: Arc<dyn EmbeddingProvider>,
+        model: impl Into<String>,
+    ) -> Self {
+        let dimensions = provider.dimensions();
+        Self {
+            id: id.into(),
+            provider,
+            model: model.into(),
+            dimensions,
+        }
+    }
+
+    /// Config entry name
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Model identifier
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Embedding dimensions
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn compatible_with(&self, other: &Self) -> bool {
+        self.model == other.model && self.dimensions == other.dimensions
+    }
+}
+
+/// Member pinned to a collection and the member that served it last
+#[derive(Debug, Clone, Copy)]
+struct CollectionRoute {
+    anchor: usize,
+    active: usize,
+}
+
+/// Member state for the admin API
+#[derive(Debug, Clone, Serialize)]
+pub struct FailoverMemberStatus {
+    /// Config entry name
+    pub id: String,
+    /// Provider implementation name
+    pub provider: String,
+    /// Model identifier
+    pub model: String,
+    /// Embedding dimensions
+    pub dimensions: usize,
+    /// Health according to the router
+    pub health: ProviderHealthStatus,
+}
+
+/// Routing state of one collection for the admin API
+#[derive(Debug, Clone, Serialize)]
+pub struct CollectionRouteStatus {
+    /// Collection name
+    pub collection: String,
+    /// Model the collection is embedded with
+    pub model: String,
+    /// Dimensions of the collection's vectors
+    pub dimensions: usize,
+    /// Member that served the last call for this collection
+    pub active_provider: String,
+}
+
+/// Failover chain state for the admin API
+#[derive(Debug, Clone, Serialize)]
+pub struct FailoverSnapshot {
+    /// Members in priority order
+    pub members: Vec<FailoverMemberStatus>,
+    /// Pinned collections, sorted by name
+    pub collections: Vec<CollectionRouteStatus>,
+}
+
+/// Embedding provider that fails over between compatible providers
+///
+/// A collection recorded in the collection registry is pinned to the member
+/// matching the model and dimensions it was built with; any other
+/// collection, and calls without a collection, are pinned to the primary.
+/// Calls try the compatible members (same model and dimensions) in priority
+/// order, healthy ones first, and fall back to unhealthy ones only when
+/// nothing else is left. Only transient failures move on to the next
+/// member; when every compatible member is down the call fails rather than
+/// switching to another model.
+pub struct FailoverEmbeddingProvider {
+    members: Vec<FailoverMember>,
+    router: Arc<dyn ProviderRouter>,
+    report_outcomes: bool,
+    unhealthy: Vec<AtomicBool>,
+    collections: DashMap<String, CollectionRoute>,
+    registry: Option<Arc<CollectionRegistry>>,
+}
+
+impl FailoverEmbeddingProvider {
+    /// Create a chain from members in priority order
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error when `members` is empty.
+    pub fn new(members: Vec<FailoverMember>, router: Arc<dyn ProviderRouter>) -> Result<Self> {
+        if members.is_empty() {
+            return Err(Error::configuration(
+                "Embedding failover chain has no members",
+            ));
+        }
+        let unhealthy = members.iter().map(|_| AtomicBool::new(false)).collect();
+        Ok(Self {
+            members,
+            router,
+            report_outcomes: true,
+            unhealthy,
+            collections: DashMap::new(),
+            registry: None,
+        })
+    }
+
+    /// Pin collections to the model recorded in `registry`
+    pub fn with_registry(mut self, registry: Arc<CollectionRegistry>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Load the pins of every registered collection
+    ///
+    /// Call once at startup so that [`EmbeddingProvider::dimensions_for`]
+    /// reports the recorded dimensions before the first embedding call.
+    pub async fn restore_pins(&self) {
+        let Some(registry) = &self.registry else {
+            return;
+        };
+        for record in registry.list().await {
+            match self.member_for(&record.model, record.dimensions) {
+                Some(anchor) => self.pin(&record.collection, anchor),
+                None => warn!(
+                    collection = %record.collection,
+                    model = %record.model,
+                    dimensions = record.dimensions,
+                    "No failover member provides the model of this collection"
+                ),
+            }
+        }
+    }
+
+    /// Whether this chain reports call outcomes to the router
+    ///
+    /// Disable when members are already wrapped with the resilience layer,
+    /// which reports outcomes itself.
+    pub fn with_outcome_reporting(mut self, enabled: bool) -> Self {
+        self.report_outcomes = enabled;
+        self
+    }
+
+    /// Members in priority order
+    pub fn members(&self) -> &[FailoverMember] {
+        &self.members
+    }
+
+    /// Config entry name of the member that last served `collection`
+    pub fn active_provider(&self, collection: &str) -> Option<&str> {
+        self.collections
+            .get(collection)
+            .map(|route| self.members[route.active].id())
+    }
+
+    /// Current member health and collection routes
+    pub async fn snapshot(&self) -> FailoverSnapshot {
+        let health = self.refresh_health().await;
+        let members = self
+            .members
+            .iter()
+            .zip(health)
+            .map(|(member, health)| FailoverMemberStatus {
+                id: member.id.clone(),
+                provider: member.provider.provider_name().to_string(),
+                model: member.model.clone(),
+                dimensions: member.dimensions,
+                health,
+            })
+            .collect();
+
+        let mut collections: Vec<_> = self
+            .collections
+            .iter()
+            .map(|entry| {
+                let anchor = &self.members[entry.anchor];
+                CollectionRouteStatus {
+                    collection: entry.key().clone(),
+                    model: anchor.model.clone(),
+                    dimensions: anchor.dimensions,
+                    active_provider: self.members[entry.active].id.clone(),
+                }
+            })
+            .collect();
+        collections.sort_by(|a, b| a.collection.cmp(&b.collection));
+
+        FailoverSnapshot {
+            members,
+            collections,
+        }
+    }
+
+    /// Pull member health from the router into the local cache
+    ///
+    /// Returns the status of each member; members whose health cannot be
+    /// read keep their cached flag.
+    async fn refresh_health(&self) -> Vec<ProviderHealthStatus> {
+        let mut statuses = Vec::with_capacity(self.members.len());
+        for (member, unhealthy) in self.members.iter().zip(&self.unhealthy) {
+            let status = match self
+                .router
+                .get_provider_health(member.provider.provider_name())
+                .await
+            {
+                Ok(status) => {
+                    unhealthy.store(status == ProviderHealthStatus::Unhealthy, Ordering::Relaxed);
+                    status
+                }
+                Err(e) => {
+                    debug!(member = %member.id, error = %e, "Failed to read provider health");
+                    if unhealthy.load(Ordering::Relaxed) {
+                        ProviderHealthStatus::Unhealthy
+                    } else {
+                        ProviderHealthStatus::Healthy
+                    }
+                }
+            };
+            statuses.push(status);
+        }
+        statuses
+    }
+
+    fn is_unhealthy(&self, index: usize) -> bool {
+        self.unhealthy[index].load(Ordering::Relaxed)
+    }
+
+    /// First member with `model` and `dimensions`
+    fn member_for(&self, model: &str, dimensions: usize) -> Option<usize> {
+        self.members
+            .iter()
+            .position(|member| member.model == model && member.dimensions == dimensions)
+    }
+
+    /// Record `anchor` as the pin of `collection`
+    fn pin(&self, collection: &str, anchor: usize) {
+        self.collections
+            .entry(collection.to_string())
+            .and_modify(|route| {
+                if route.anchor != anchor {
+                    *route = CollectionRoute {
+                        anchor,
+                        active: anchor,
+                    };
+                }
+            })
+            .or_insert(CollectionRoute {
+                anchor,
+                active: anchor,
+            });
+    }
+
+    /// Member whose model and dimensions `collection` is pinned to
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error when the registry records a model no
+    /// member provides.
+    async fn anchor(&self, collection: Option<&str>) -> Result<usize> {
+        let Some(name) = collection else {
+            return Ok(0);
+        };
+        let recorded = match &self.registry {
+            Some(registry) => registry.get(name).await,
+            None => None,
+        };
+        let anchor = match recorded {
+            Some(record) => self
+                .member_for(&record.model, record.dimensions)
+                .ok_or_else(|| {
+                    Error::configuration(format!(
+                        "Collection '{}' is embedded with {} ({} dimensions), which no failover member provides",
+                        name, record.model, record.dimensions
+                    ))
+                })?,
+            None => self
+                .collections
+                .get(name)
+                .map(|route| route.anchor)
+                .unwrap_or(0),
+        };
+        self.pin(name, anchor);
+        Ok(anchor)
+    }
+
+    /// Members compatible with `anchor`, healthy ones first
+    fn candidates(&self, anchor: usize) -> Vec<usize> {
+        let compatible: Vec<usize> = (0..self.members.len())
+            .filter(|&index| self.members[index].compatible_with(&self.members[anchor]))
+            .collect();
+        let (healthy, unhealthy): (Vec<usize>, Vec<usize>) = compatible
+            .into_iter()
+            .partition(|&index| !self.is_unhealthy(index));
+        healthy.into_iter().chain(unhealthy).collect()
+    }
+
+    async fn embed_routed(
+        &self,
+        collection: Option<&str>,
+        texts: &[String],
+    ) -> Result<Vec<Embedding>> {
+        self.refresh_health().await;
+        let anchor = self.anchor(collection).await?;
+
+        let mut last_error = None;
+        for index in self.candidates(anchor) {
+            let member = &self.members[index];
+            let result = match collection {
+                Some(name) => member.provider.embed_batch_for(name, texts).await,
+                None => member.provider.embed_batch(texts).await,
+            };
+            match result {
+                Ok(embeddings) => {
+                    self.unhealthy[index].store(false, Ordering::Relaxed);
+                    self.report_success(member).await;
+                    if let Some(name) = collection
+                        && let Some(mut route) = self.collections.get_mut(name)
+                    {
+                        route.active = index;
+                    }
+                    return Ok(embeddings);
+                }
+                Err(error) => {
+                    if !FailureKind::of(&error).is_transient() {
+                        return Err(error);
+                    }
+                    warn!(
+                        member = %member.id,
+                        collection = collection.unwrap_or_default(),
+                        error = %error,
+                        "Embedding provider failed, trying next compatible provider"
+                    );
+                    self.unhealthy[index].store(true, Ordering::Relaxed);
+                    self.report_failure(member, &error).await;
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| Error::embedding("No compatible embedding provider")))
+    }
+
+    async fn report_success(&self, member: &FailoverMember) {
+        if !self.report_outcomes {
+            return;
+        }
+        if let Err(e) = self
+            .router
+            .report_success(member.provider.provider_name())
+            .await
+        {
+            debug!(member = %member.id, error = %e, "Failed to report provider success");
+        }
+    }
+
+    async fn report_failure(&self, member: &FailoverMember, error: &Error) {
+        if !self.report_outcomes {
+            return;
+        }
+        if let Err(e) = self
+            .router
+            .report_failure(member.provider.provider_name(), &error.to_string())
+            .await
+        {
+            debug!(member = %member.id, error = %e, "Failed to report provider failure");
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for FailoverEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.embed_routed(None, texts).await
+    }
+
+    async fn embed_batch_for(&self, collection: &str, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.embed_routed(Some(collection), texts).await
+    }
+
+    /// Dimensions of the primary member
+    fn dimensions(&self) -> usize {
+        self.members[0].dimensions
+    }
+
+    fn dimensions_for(&self, collection: &str) -> usize {
+        if let Some(route) = self.collections.get(collection) {
+            return self.members[route.anchor].dimensions;
+        }
+        self.registry
+            .as_ref()
+            .and_then(|registry| registry.try_get(collection))
+            .map_or(self.members[0].dimensions, |record| record.dimensions)
+    }
+
+    fn provider_name(&self) -> &str {
+        "failover"
+    }
+
+    /// Provider of the member `collection` is pinned to
+    ///
+    /// Compatible members produce vectors in the same embedding space, so
+    /// the pinned member names the collection's provider even while another
+    /// member serves it.
+    fn provider_name_for(&self, collection: &str) -> &str {
+        let anchor = match self.collections.get(collection) {
+            Some(route) => route.anchor,
+            None => self
+                .registry
+                .as_ref()
+                .and_then(|registry| registry.try_get(collection))
+                .and_then(|record| self.member_for(&record.model, record.dimensions))
+                .unwrap_or(0),
+        };
+        self.members[anchor].provider.provider_name()
+    }
+}
+
+impl std::fmt::Debug for FailoverEmbeddingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FailoverEmbeddingProvider")
+            .field(
+                "members",
+                &self
+                    .members
+                    .iter()
+                    .map(FailoverMember::id)
+                    .collect::<Vec<_>>(),
+            )
+            .field("collections", &self.collections.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -7,6 +7,7 @@
 //!
 //! - [`NullProviderRouter`] - No-op router for testing
 //! - [`DefaultProviderRouter`] - Production router with health tracking
+//! - [`FailoverEmbeddingProvider`] - Embedding failover between compatible providers
 //!
 //! ## Usage via DI
 //!
@@ -16,9 +17,14 @@
 //! // let provider = router.select_embedding_provider(&context).await?;
 //! ```
 
+mod failover;
 mod health;
 mod router;
 
 // Re-export for DI registration
+pub use failover::{
+    CollectionRouteStatus, FailoverEmbeddingProvider, FailoverMember, FailoverMemberStatus,
+    FailoverSnapshot,
+};
 pub use health::{HealthMonitor, InMemoryHealthMonitor, NullHealthMonitor};
 pub use router::{DefaultProviderRouter, NullProviderRouter};
@@ -23,6 +23,7 @@ fn test_cache_stats() {
         entries: 0,
         hit_rate: 0.0,
         bytes_used: 0,
+        tiers: Vec::new(),
     };
 
     assert_eq!(stats.hits, 2);
@@ -72,6 +72,7 @@ fn test_cache_config_ttl_when_enabled() {
         redis_url: None,
         redis_pool_size: 8,
         namespace: "test".to_string(),
+        search_results: Default::default(),
     };
     assert!(enabled_cache.default_ttl_secs > 0);
     assert!(enabled_cache.max_size > 0);
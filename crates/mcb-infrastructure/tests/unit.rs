@@ -11,6 +11,9 @@ mod constants_tests;
 #[path = "unit/crypto_tests.rs"]
 mod crypto_tests;
 
+#[path = "unit/keyring_tests.rs"]
+mod keyring_tests;
+
 #[path = "unit/error_ext_tests.rs"]
 mod error_ext_tests;
 
@@ -32,6 +35,36 @@ mod lifecycle_tests;
 #[path = "unit/config_figment_tests.rs"]
 mod config_figment_tests;
 
+#[path = "unit/user_auth_tests.rs"]
+mod user_auth_tests;
+
+#[path = "unit/scopes_tests.rs"]
+mod scopes_tests;
+
+#[path = "unit/audit_tests.rs"]
+mod audit_tests;
+
+#[path = "unit/event_store_tests.rs"]
+mod event_store_tests;
+
+#[path = "unit/ratelimit_tests.rs"]
+mod ratelimit_tests;
+
+#[path = "unit/resilience_tests.rs"]
+mod resilience_tests;
+
+#[path = "unit/failover_tests.rs"]
+mod failover_tests;
+
+#[path = "unit/collections_tests.rs"]
+mod collections_tests;
+
+#[path = "unit/batching_tests.rs"]
+mod batching_tests;
+
+#[path = "unit/backup_tests.rs"]
+mod backup_tests;
+
 // Infrastructure service tests (require test-utils feature)
 #[cfg(feature = "test-utils")]
 #[path = "unit/auth_tests.rs"]
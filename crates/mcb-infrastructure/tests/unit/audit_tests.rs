@@ -0,0 +1,158 @@
+//! Audit Log Tests
+
+use chrono::{Duration, Utc};
+use mcb_domain::ports::infrastructure::{
+    AuditEvent, AuditLogInterface, AuditOutcome, AuditQuery, AuditSource,
+};
+use mcb_infrastructure::audit::{JsonlAuditLog, redact_arguments};
+use mcb_infrastructure::config::AuditConfig;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn event(action: &str, principal: Option<&str>, outcome: AuditOutcome) -> AuditEvent {
+    AuditEvent {
+        timestamp: Utc::now(),
+        principal: principal.map(str::to_string),
+        session: None,
+        source: AuditSource::Tool,
+        action: action.to_string(),
+        arguments: json!({ "collection": "default" }),
+        outcome,
+        error: None,
+        duration_ms: 3,
+    }
+}
+
+#[test]
+fn test_from_config_disabled_by_default() {
+    assert!(JsonlAuditLog::from_config(&AuditConfig::default()).is_none());
+
+    let config = AuditConfig {
+        enabled: true,
+        ..AuditConfig::default()
+    };
+    assert!(JsonlAuditLog::from_config(&config).is_some());
+}
+
+#[tokio::test]
+async fn test_query_returns_newest_first() {
+    let dir = TempDir::new().unwrap();
+    let log = JsonlAuditLog::new(dir.path(), 1024 * 1024, 3);
+
+    for action in ["index_codebase", "search_code", "get_indexing_status"] {
+        log.record(event(action, Some("alice"), AuditOutcome::Success))
+            .await
+            .unwrap();
+    }
+
+    let events = log.query(&AuditQuery::default()).await.unwrap();
+    let actions: Vec<_> = events.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(
+        actions,
+        ["get_indexing_status", "search_code", "index_codebase"]
+    );
+}
+
+#[tokio::test]
+async fn test_query_filters() {
+    let dir = TempDir::new().unwrap();
+    let log = JsonlAuditLog::new(dir.path(), 1024 * 1024, 3);
+
+    log.record(event("search_code", Some("alice"), AuditOutcome::Success))
+        .await
+        .unwrap();
+    log.record(event("search_code", Some("bob"), AuditOutcome::Denied))
+        .await
+        .unwrap();
+    log.record(event("clear_index", None, AuditOutcome::Failure))
+        .await
+        .unwrap();
+
+    let by_principal = AuditQuery {
+        principal: Some("bob".to_string()),
+        ..AuditQuery::default()
+    };
+    let events = log.query(&by_principal).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].outcome, AuditOutcome::Denied);
+
+    let by_action = AuditQuery {
+        action: Some("search_code".to_string()),
+        ..AuditQuery::default()
+    };
+    assert_eq!(log.query(&by_action).await.unwrap().len(), 2);
+
+    let by_source = AuditQuery {
+        source: Some(AuditSource::Admin),
+        ..AuditQuery::default()
+    };
+    assert!(log.query(&by_source).await.unwrap().is_empty());
+
+    let future = AuditQuery {
+        since: Some(Utc::now() + Duration::hours(1)),
+        ..AuditQuery::default()
+    };
+    assert!(log.query(&future).await.unwrap().is_empty());
+
+    let limited = AuditQuery {
+        limit: Some(2),
+        ..AuditQuery::default()
+    };
+    assert_eq!(log.query(&limited).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_rotation_keeps_max_files() {
+    let dir = TempDir::new().unwrap();
+    // Small enough that every event rotates the active file
+    let log = JsonlAuditLog::new(dir.path(), 64, 2);
+
+    for i in 0..5 {
+        log.record(event(&format!("tool_{}", i), None, AuditOutcome::Success))
+            .await
+            .unwrap();
+    }
+
+    assert!(dir.path().join("audit.jsonl").exists());
+    assert!(dir.path().join("audit.1.jsonl").exists());
+    assert!(dir.path().join("audit.2.jsonl").exists());
+    assert!(!dir.path().join("audit.3.jsonl").exists());
+
+    // Only the active file and two rotated files remain queryable
+    let events = log.query(&AuditQuery::default()).await.unwrap();
+    let actions: Vec<_> = events.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(actions, ["tool_4", "tool_3", "tool_2"]);
+}
+
+#[tokio::test]
+async fn test_query_skips_malformed_lines() {
+    let dir = TempDir::new().unwrap();
+    let log = JsonlAuditLog::new(dir.path(), 1024 * 1024, 1);
+    log.record(event("search_code", None, AuditOutcome::Success))
+        .await
+        .unwrap();
+
+    let mut content = std::fs::read_to_string(log.current_path()).unwrap();
+    content.push_str("not json\n");
+    std::fs::write(log.current_path(), content).unwrap();
+
+    assert_eq!(log.query(&AuditQuery::default()).await.unwrap().len(), 1);
+}
+
+#[test]
+fn test_redact_arguments_masks_secrets_recursively() {
+    let arguments = json!({
+        "query": "fn main",
+        "token": "abc",
+        "nested": { "api_key": "xyz", "limit": 10, "password": null },
+        "items": [{ "Authorization": "Bearer abc" }],
+    });
+
+    let redacted = redact_arguments(&arguments);
+    assert_eq!(redacted["query"], "fn main");
+    assert_eq!(redacted["token"], "[REDACTED]");
+    assert_eq!(redacted["nested"]["api_key"], "[REDACTED]");
+    assert_eq!(redacted["nested"]["limit"], 10);
+    assert!(redacted["nested"]["password"].is_null());
+    assert_eq!(redacted["items"][0]["Authorization"], "[REDACTED]");
+}
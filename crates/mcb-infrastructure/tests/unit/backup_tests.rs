@@ -0,0 +1,369 @@
+//! Backup Service Tests
+//!
+//! Covers writing, listing, pruning and restoring backups, replacing
+//! collections on restore, leaving state alone when no collection is
+//! restored, encrypted backups and the service lifecycle.
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::admin::{DependencyHealth, LifecycleManaged, PortServiceState};
+use mcb_domain::ports::providers::{EmbeddingProvider, VectorStoreProvider};
+use mcb_domain::value_objects::Embedding;
+use mcb_infrastructure::backup::BackupService;
+use mcb_infrastructure::collections::{CollectionGuard, CollectionRegistry};
+use mcb_infrastructure::config::{AppConfig, BackupConfig};
+use mcb_infrastructure::di::{
+    EmbeddingProviderHandle, EmbeddingProviderResolver, VectorStoreProviderHandle,
+    VectorStoreProviderResolver,
+};
+use mcb_providers::vector_store::InMemoryVectorStoreProvider;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Embedding provider returning a constant vector
+struct StubEmbedding;
+
+#[async_trait]
+impl EmbeddingProvider for StubEmbedding {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        Ok(texts
+            .iter()
+            .map(|_| Embedding {
+                vector: vec![0.5; 4],
+                model: "stub-model".to_string(),
+                dimensions: 4,
+            })
+            .collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        4
+    }
+
+    fn provider_name(&self) -> &str {
+        "stub"
+    }
+}
+
+fn guard() -> Arc<CollectionGuard> {
+    guard_with_registry(CollectionRegistry::in_memory())
+}
+
+/// Guard whose registry survives a restart, as replacing collections needs
+async fn persistent_guard(directory: &Path) -> Arc<CollectionGuard> {
+    let registry = CollectionRegistry::open(directory.join("collections.json"))
+        .await
+        .expect("registry");
+    guard_with_registry(registry)
+}
+
+fn guard_with_registry(registry: CollectionRegistry) -> Arc<CollectionGuard> {
+    let config = Arc::new(AppConfig::default());
+    Arc::new(CollectionGuard::new(
+        Arc::new(EmbeddingProviderResolver::new(config.clone())),
+        Arc::new(EmbeddingProviderHandle::new(Arc::new(StubEmbedding))),
+        Arc::new(VectorStoreProviderResolver::new(config)),
+        Arc::new(VectorStoreProviderHandle::new(Arc::new(
+            InMemoryVectorStoreProvider::new(),
+        ))),
+        Arc::new(registry),
+    ))
+}
+
+fn backup_config(directory: &Path) -> BackupConfig {
+    BackupConfig {
+        enabled: true,
+        directory: directory.to_path_buf(),
+        interval_secs: 3600,
+        max_backups: 7,
+        compress: true,
+        encrypt: false,
+        encryption_key: None,
+    }
+}
+
+fn chunk(id: &str, content: &str) -> HashMap<String, Value> {
+    HashMap::from([
+        ("id".to_string(), json!(id)),
+        ("file_path".to_string(), json!("src/lib.rs")),
+        ("content".to_string(), json!(content)),
+        ("start_line".to_string(), json!(1)),
+        ("end_line".to_string(), json!(1)),
+        ("language".to_string(), json!("rust")),
+    ])
+}
+
+/// Index two chunks into `collection` through the guarded providers
+async fn index(guard: &Arc<CollectionGuard>, collection: &str) {
+    let embedding = guard.embedding_provider();
+    let store = guard.vector_store_provider();
+    let texts = vec!["fn a() {}".to_string(), "fn b() {}".to_string()];
+    let embeddings = embedding
+        .embed_batch_for(collection, &texts)
+        .await
+        .expect("embed");
+    store
+        .create_collection(collection, embedding.dimensions_for(collection))
+        .await
+        .expect("create");
+    store
+        .insert_vectors(
+            collection,
+            &embeddings,
+            vec![chunk("a", &texts[0]), chunk("b", &texts[1])],
+        )
+        .await
+        .expect("insert");
+}
+
+async fn vector_count(guard: &Arc<CollectionGuard>, collection: &str) -> usize {
+    guard
+        .vector_store_provider()
+        .list_vectors(collection, 10)
+        .await
+        .expect("list")
+        .len()
+}
+
+#[tokio::test]
+async fn test_backup_restores_collections_snapshots_and_files() {
+    let guard = guard();
+    index(&guard, "code").await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let snapshots = dir.path().join("snapshots");
+    std::fs::create_dir_all(snapshots.join("code")).expect("snapshot dir");
+    std::fs::write(snapshots.join("code/snapshot.json"), b"{}").expect("snapshot");
+    let mapping = dir.path().join("collection_mapping.json");
+    std::fs::write(&mapping, br#"{"code":"code"}"#).expect("mapping");
+
+    let backups = dir.path().join("backups");
+    let service = BackupService::new(&backup_config(&backups), guard.clone())
+        .expect("service")
+        .with_snapshot_directory(&snapshots)
+        .with_file("collection_mapping.json", &mapping);
+    let backup = service.run_backup().await.expect("backup");
+    assert!(backup.name.ends_with(".tar.gz"));
+    assert!(backup.compressed);
+    assert_eq!(service.list().await.expect("list"), vec![backup.clone()]);
+    // Bundles and the partial archive are written beside it and cleaned up
+    let written: Vec<_> = std::fs::read_dir(&backups)
+        .expect("read dir")
+        .map(|entry| entry.expect("entry").file_name())
+        .collect();
+    assert_eq!(written, vec![backup.name.as_str()]);
+
+    guard
+        .vector_store_provider()
+        .delete_collection("code")
+        .await
+        .expect("delete");
+    std::fs::remove_dir_all(&snapshots).expect("remove snapshots");
+    std::fs::write(&mapping, b"{}").expect("overwrite mapping");
+
+    let report = service.restore(&backup.name, false).await.expect("restore");
+    assert_eq!(report.restored, vec!["code".to_string()]);
+    assert!(report.skipped.is_empty());
+    assert_eq!(report.snapshot_files, 1);
+    assert_eq!(report.files, vec!["collection_mapping.json".to_string()]);
+    assert_eq!(vector_count(&guard, "code").await, 2);
+    assert!(snapshots.join("code/snapshot.json").exists());
+    assert_eq!(
+        std::fs::read(&mapping).expect("mapping"),
+        br#"{"code":"code"}"#
+    );
+
+    // Every collection exists now, so the state is left alone too
+    std::fs::write(&mapping, b"{}").expect("overwrite mapping");
+    let report = service.restore(&backup.name, false).await.expect("restore");
+    assert_eq!(report.skipped, vec!["code".to_string()]);
+    assert_eq!(report.snapshot_files, 0);
+    assert!(report.files.is_empty());
+    assert_eq!(
+        report.skipped_files,
+        vec![
+            "files/collection_mapping.json".to_string(),
+            "snapshots/code/snapshot.json".to_string()
+        ]
+    );
+    assert_eq!(std::fs::read(&mapping).expect("mapping"), b"{}");
+}
+
+#[tokio::test]
+async fn test_restore_skips_existing_collections_unless_overwriting() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let guard = persistent_guard(dir.path()).await;
+    index(&guard, "code").await;
+    let service = BackupService::new(&backup_config(dir.path()), guard.clone()).expect("service");
+    let backup = service.run_backup().await.expect("backup");
+
+    let report = service.restore(&backup.name, false).await.expect("restore");
+    assert!(report.restored.is_empty());
+    assert_eq!(report.skipped, vec!["code".to_string()]);
+
+    let report = service.restore(&backup.name, true).await.expect("restore");
+    assert_eq!(report.restored, vec!["code".to_string()]);
+    assert_eq!(vector_count(&guard, "code").await, 2);
+
+    // The restored copy was swapped in under a staging name
+    let record = guard.registry().get("code").await.expect("record");
+    assert_ne!(record.physical_name, "code");
+    let names = guard
+        .vector_store_provider()
+        .list_collection_names()
+        .await
+        .expect("names");
+    assert_eq!(names, vec!["code".to_string()]);
+}
+
+#[tokio::test]
+async fn test_overwrite_requires_persistent_registry() {
+    let guard = guard();
+    index(&guard, "code").await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let service = BackupService::new(&backup_config(dir.path()), guard.clone()).expect("service");
+    let backup = service.run_backup().await.expect("backup");
+
+    let error = service
+        .restore(&backup.name, true)
+        .await
+        .expect_err("in-memory registry");
+    assert!(error.to_string().contains("registry_path"));
+    assert_eq!(vector_count(&guard, "code").await, 2);
+}
+
+#[tokio::test]
+async fn test_backup_includes_collections_without_registry_record() {
+    let guard = guard();
+    index(&guard, "code").await;
+    guard
+        .registry()
+        .remove("code")
+        .await
+        .expect("forget record");
+    let dir = tempfile::tempdir().expect("tempdir");
+    let service = BackupService::new(&backup_config(dir.path()), guard.clone()).expect("service");
+    let backup = service.run_backup().await.expect("backup");
+
+    guard
+        .vector_store_provider()
+        .delete_collection("code")
+        .await
+        .expect("delete");
+    let report = service.restore(&backup.name, false).await.expect("restore");
+    assert_eq!(report.restored, vec!["code".to_string()]);
+    assert_eq!(vector_count(&guard, "code").await, 2);
+}
+
+#[tokio::test]
+async fn test_old_backups_are_pruned() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let config = BackupConfig {
+        max_backups: 2,
+        ..backup_config(dir.path())
+    };
+    let service = BackupService::new(&config, guard()).expect("service");
+
+    let mut written = Vec::new();
+    for _ in 0..3 {
+        written.push(service.run_backup().await.expect("backup").name);
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    let names: Vec<String> = service
+        .list()
+        .await
+        .expect("list")
+        .into_iter()
+        .map(|b| b.name)
+        .collect();
+    assert_eq!(names, vec![written[2].clone(), written[1].clone()]);
+}
+
+#[tokio::test]
+async fn test_unknown_backup_is_refused() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let service = BackupService::new(&backup_config(dir.path()), guard()).expect("service");
+
+    let error = service
+        .restore("../mcb-backup-20260101T000000000Z.tar", false)
+        .await
+        .expect_err("path outside the backup directory");
+    assert!(matches!(error, Error::NotFound { .. }));
+}
+
+#[tokio::test]
+async fn test_encrypted_backup_needs_the_key() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let guard = persistent_guard(dir.path()).await;
+    index(&guard, "code").await;
+    let config = BackupConfig {
+        encrypt: true,
+        encryption_key: Some("correct horse battery staple".to_string()),
+        ..backup_config(dir.path())
+    };
+    assert!(
+        BackupService::new(
+            &BackupConfig {
+                encryption_key: None,
+                ..config.clone()
+            },
+            guard.clone()
+        )
+        .is_err()
+    );
+
+    let service = BackupService::new(&config, guard.clone()).expect("service");
+    let backup = service.run_backup().await.expect("backup");
+    assert!(backup.name.ends_with(".tar.gz.enc"));
+    assert!(backup.encrypted);
+    // The key is derived with a fresh salt stored in the header
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let second = service.run_backup().await.expect("backup");
+    let first_bytes = std::fs::read(dir.path().join(&backup.name)).expect("read");
+    let second_bytes = std::fs::read(dir.path().join(&second.name)).expect("read");
+    assert!(first_bytes.starts_with(b"MCBBKDF1"));
+    assert_ne!(first_bytes[9..25], second_bytes[9..25]);
+
+    let wrong_key = BackupService::new(
+        &BackupConfig {
+            encryption_key: Some("another passphrase".to_string()),
+            ..config
+        },
+        guard.clone(),
+    )
+    .expect("service");
+    assert!(wrong_key.restore(&backup.name, true).await.is_err());
+
+    // Archives without the key derivation header are refused
+    let unsalted = "mcb-backup-20200101T000000000Z.tar.gz.enc";
+    std::fs::write(dir.path().join(unsalted), &first_bytes[8..]).expect("write");
+    let error = service
+        .restore(unsalted, true)
+        .await
+        .expect_err("no key derivation header");
+    assert!(error.to_string().contains("key derivation header"));
+
+    let report = service.restore(&backup.name, true).await.expect("restore");
+    assert_eq!(report.restored, vec!["code".to_string()]);
+}
+
+#[tokio::test]
+async fn test_backup_service_lifecycle() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let service = BackupService::new(&backup_config(dir.path()), guard()).expect("service");
+    assert_eq!(service.name(), "backup");
+    assert_eq!(service.state(), PortServiceState::Stopped);
+
+    service.start().await.expect("start");
+    assert_eq!(service.state(), PortServiceState::Running);
+    assert_eq!(
+        service.health_check().await.status,
+        DependencyHealth::Healthy
+    );
+
+    service.stop().await.expect("stop");
+    assert_eq!(service.state(), PortServiceState::Stopped);
+}
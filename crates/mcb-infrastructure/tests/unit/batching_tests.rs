@@ -0,0 +1,229 @@
+//! Embedding Batching Tests
+//!
+//! Covers request packing, oversized input handling, usage accounting and
+//! the concurrency cap of `BatchingEmbeddingProvider`.
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+use mcb_infrastructure::config::{EmbeddingBatchingConfig, OversizePolicy};
+use mcb_infrastructure::embedding::{BatchingEmbeddingProvider, EmbeddingLimits, EmbeddingUsage};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Embedding provider recording the size of every request
+///
+/// Each input embeds to `[len, 1.0]` so pooled results can be checked.
+#[derive(Default)]
+struct RecordingEmbedding {
+    batches: Mutex<Vec<usize>>,
+    inputs: Mutex<Vec<String>>,
+    in_flight: AtomicUsize,
+    peak: AtomicUsize,
+    delay: Option<Duration>,
+}
+
+impl RecordingEmbedding {
+    fn batches(&self) -> Vec<usize> {
+        self.batches.lock().expect("lock").clone()
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for RecordingEmbedding {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(running, Ordering::SeqCst);
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        self.batches.lock().expect("lock").push(texts.len());
+        self.inputs.lock().expect("lock").extend_from_slice(texts);
+        Ok(texts
+            .iter()
+            .map(|text| Embedding {
+                vector: vec![text.chars().count() as f32, 1.0],
+                model: "recording-model".to_string(),
+                dimensions: 2,
+            })
+            .collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn provider_name(&self) -> &str {
+        "recording"
+    }
+}
+
+/// One token per character, 10 tokens per input, 30 per request, 4 inputs
+fn limits() -> EmbeddingLimits {
+    EmbeddingLimits {
+        max_input_tokens: 10,
+        max_batch_tokens: 30,
+        max_batch_items: 4,
+        chars_per_token: 1.0,
+    }
+}
+
+fn batching(
+    inner: Arc<RecordingEmbedding>,
+    config: EmbeddingBatchingConfig,
+) -> (BatchingEmbeddingProvider, Arc<EmbeddingUsage>) {
+    let usage = Arc::new(EmbeddingUsage::new());
+    let provider = BatchingEmbeddingProvider::new(inner, limits(), &config, usage.clone());
+    (provider, usage)
+}
+
+fn texts(lengths: &[usize]) -> Vec<String> {
+    lengths.iter().map(|&len| "x".repeat(len)).collect()
+}
+
+#[test]
+fn test_limits_for_known_and_unknown_providers() {
+    let openai = EmbeddingLimits::for_model("openai", Some("text-embedding-3-small"));
+    assert_eq!(openai.max_input_tokens, 8191);
+
+    let minilm = EmbeddingLimits::for_model("ollama", Some("all-minilm"));
+    assert_eq!(minilm.max_input_tokens, 512);
+
+    let unknown = EmbeddingLimits::for_model("custom", None).with_max_input_tokens(100);
+    assert_eq!(unknown.max_input_tokens, 100);
+    assert_eq!(unknown.estimate_tokens(""), 0);
+    assert!(unknown.estimate_tokens("fn main() {}") >= 1);
+}
+
+#[tokio::test]
+async fn test_packs_under_item_and_token_limits() {
+    let inner = Arc::new(RecordingEmbedding::default());
+    let (provider, _) = batching(inner.clone(), EmbeddingBatchingConfig::default());
+
+    // 6 inputs of 2 tokens: the 4-item limit applies first
+    let embeddings = provider.embed_batch(&texts(&[2; 6])).await.expect("embed");
+    assert_eq!(embeddings.len(), 6);
+    assert_eq!(inner.batches(), vec![4, 2]);
+
+    // 5 inputs of 10 tokens: the 30-token limit applies first
+    inner.batches.lock().expect("lock").clear();
+    provider.embed_batch(&texts(&[10; 5])).await.expect("embed");
+    assert_eq!(inner.batches(), vec![3, 2]);
+}
+
+#[tokio::test]
+async fn test_config_tightens_limits() {
+    let inner = Arc::new(RecordingEmbedding::default());
+    let config = EmbeddingBatchingConfig {
+        max_batch_items: Some(2),
+        ..EmbeddingBatchingConfig::default()
+    };
+    let (provider, _) = batching(inner.clone(), config);
+    assert_eq!(provider.limits().max_batch_items, 2);
+
+    provider.embed_batch(&texts(&[1; 5])).await.expect("embed");
+    assert_eq!(inner.batches(), vec![2, 2, 1]);
+}
+
+#[tokio::test]
+async fn test_oversized_input_is_split_and_mean_pooled() {
+    let inner = Arc::new(RecordingEmbedding::default());
+    let (provider, usage) = batching(inner.clone(), EmbeddingBatchingConfig::default());
+
+    // 25 characters split into pieces of 10, 10 and 5
+    let embeddings = provider
+        .embed_batch(&["y".repeat(25), "z".repeat(3)])
+        .await
+        .expect("embed");
+    assert_eq!(embeddings.len(), 2);
+    assert!((embeddings[0].vector[0] - 25.0 / 3.0).abs() < 1e-4);
+    assert_eq!(embeddings[1].vector, vec![3.0, 1.0]);
+    assert!(
+        inner
+            .inputs
+            .lock()
+            .expect("lock")
+            .iter()
+            .all(|input| input.chars().count() <= 10)
+    );
+
+    let totals = usage.snapshot();
+    assert_eq!(totals.len(), 1);
+    assert_eq!(totals[0].provider, "recording");
+    assert_eq!(totals[0].model, "recording-model");
+    assert_eq!(totals[0].inputs, 2);
+    assert_eq!(totals[0].tokens, 28);
+    assert_eq!(totals[0].split_inputs, 1);
+}
+
+#[tokio::test]
+async fn test_split_prefers_line_boundaries() {
+    let inner = Arc::new(RecordingEmbedding::default());
+    let (provider, _) = batching(inner.clone(), EmbeddingBatchingConfig::default());
+
+    provider
+        .embed_batch(&["aaaa\nbbbb\ncccc\n".to_string()])
+        .await
+        .expect("embed");
+    assert_eq!(
+        *inner.inputs.lock().expect("lock"),
+        vec!["aaaa\nbbbb\n".to_string(), "cccc\n".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_max_pooling() {
+    let inner = Arc::new(RecordingEmbedding::default());
+    let config = EmbeddingBatchingConfig {
+        oversize: OversizePolicy::Max,
+        ..EmbeddingBatchingConfig::default()
+    };
+    let (provider, _) = batching(inner, config);
+
+    let embeddings = provider
+        .embed_batch(&["y".repeat(25)])
+        .await
+        .expect("embed");
+    assert_eq!(embeddings[0].vector, vec![10.0, 1.0]);
+}
+
+#[tokio::test]
+async fn test_truncate_policy_is_counted() {
+    let inner = Arc::new(RecordingEmbedding::default());
+    let config = EmbeddingBatchingConfig {
+        oversize: OversizePolicy::Truncate,
+        ..EmbeddingBatchingConfig::default()
+    };
+    let (provider, usage) = batching(inner.clone(), config);
+
+    let embeddings = provider
+        .embed_batch(&["y".repeat(25)])
+        .await
+        .expect("embed");
+    assert_eq!(embeddings[0].vector, vec![10.0, 1.0]);
+    assert_eq!(inner.batches(), vec![1]);
+    assert_eq!(usage.snapshot()[0].truncated_inputs, 1);
+}
+
+#[tokio::test]
+async fn test_concurrency_is_capped() {
+    let inner = Arc::new(RecordingEmbedding {
+        delay: Some(Duration::from_millis(20)),
+        ..RecordingEmbedding::default()
+    });
+    let config = EmbeddingBatchingConfig {
+        max_concurrency: 2,
+        max_batch_items: Some(1),
+        ..EmbeddingBatchingConfig::default()
+    };
+    let (provider, usage) = batching(inner.clone(), config);
+
+    provider.embed_batch(&texts(&[1; 6])).await.expect("embed");
+    assert_eq!(inner.batches().len(), 6);
+    assert!(inner.peak.load(Ordering::SeqCst) <= 2);
+    assert_eq!(usage.snapshot()[0].requests, 6);
+}
@@ -0,0 +1,544 @@
+//! Collection Guard Tests
+//!
+//! Covers the collection registry, write refusal for mismatched embedding
+//! spaces, refused switches, the shadow-collection migration and collection
+//! bundles.
+
+use async_trait::async_trait;
+use mcb_application::ports::registry::{EmbeddingProviderConfig, VectorStoreProviderConfig};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{EmbeddingProvider, VectorStoreProvider};
+use mcb_domain::value_objects::Embedding;
+use mcb_infrastructure::collections::{
+    BundleCompression, CollectionBundler, CollectionGuard, CollectionRecord, CollectionRegistry,
+    ExportOptions, MigrationState, SwitchOutcome,
+};
+use mcb_infrastructure::config::AppConfig;
+use mcb_infrastructure::crypto::CryptoService;
+use mcb_infrastructure::di::{
+    EmbeddingProviderHandle, EmbeddingProviderResolver, VectorStoreProviderHandle,
+    VectorStoreProviderResolver,
+};
+use mcb_infrastructure::routing::{
+    DefaultProviderRouter, FailoverEmbeddingProvider, FailoverMember, InMemoryHealthMonitor,
+};
+use mcb_providers::vector_store::InMemoryVectorStoreProvider;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Embedding provider with a fixed name, model and dimensionality
+struct StubEmbedding {
+    name: &'static str,
+    dimensions: usize,
+}
+
+#[async_trait]
+impl EmbeddingProvider for StubEmbedding {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        Ok(texts
+            .iter()
+            .map(|_| Embedding {
+                vector: vec![0.5; self.dimensions],
+                model: format!("{}-model", self.name),
+                dimensions: self.dimensions,
+            })
+            .collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn provider_name(&self) -> &str {
+        self.name
+    }
+}
+
+struct Fixture {
+    guard: Arc<CollectionGuard>,
+    store: Arc<InMemoryVectorStoreProvider>,
+    embedding_handle: Arc<EmbeddingProviderHandle>,
+}
+
+fn fixture() -> Fixture {
+    fixture_with_registry(CollectionRegistry::in_memory())
+}
+
+fn fixture_with_registry(registry: CollectionRegistry) -> Fixture {
+    let config = Arc::new(AppConfig::default());
+    let store = Arc::new(InMemoryVectorStoreProvider::new());
+    let embedding_handle = Arc::new(EmbeddingProviderHandle::new(Arc::new(StubEmbedding {
+        name: "stub",
+        dimensions: 4,
+    })));
+    let guard = Arc::new(
+        CollectionGuard::new(
+            Arc::new(EmbeddingProviderResolver::new(config.clone())),
+            embedding_handle.clone(),
+            Arc::new(VectorStoreProviderResolver::new(config)),
+            Arc::new(VectorStoreProviderHandle::new(store.clone())),
+            Arc::new(registry),
+        )
+        .with_batch_size(1),
+    );
+    Fixture {
+        guard,
+        store,
+        embedding_handle,
+    }
+}
+
+fn chunk(id: &str, content: &str) -> HashMap<String, Value> {
+    HashMap::from([
+        ("id".to_string(), json!(id)),
+        ("file_path".to_string(), json!("src/lib.rs")),
+        ("content".to_string(), json!(content)),
+        ("start_line".to_string(), json!(1)),
+        ("end_line".to_string(), json!(1)),
+        ("language".to_string(), json!("rust")),
+        ("node_type".to_string(), json!("function_item")),
+    ])
+}
+
+/// Index two chunks into `collection` through the guarded providers
+async fn index(guard: &Arc<CollectionGuard>, collection: &str) {
+    let embedding = guard.embedding_provider();
+    let store = guard.vector_store_provider();
+    let texts = vec!["fn a() {}".to_string(), "fn b() {}".to_string()];
+    let embeddings = embedding
+        .embed_batch_for(collection, &texts)
+        .await
+        .expect("embed");
+    if !store.collection_exists(collection).await.expect("exists") {
+        store
+            .create_collection(collection, embedding.dimensions_for(collection))
+            .await
+            .expect("create");
+    }
+    store
+        .insert_vectors(
+            collection,
+            &embeddings,
+            vec![chunk("a", &texts[0]), chunk("b", &texts[1])],
+        )
+        .await
+        .expect("insert");
+}
+
+#[tokio::test]
+async fn test_registry_persists_records() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("collections.json");
+
+    let registry = CollectionRegistry::open(&path).await.expect("open");
+    registry
+        .insert_if_absent(CollectionRecord::new("code", "openai", "small", 1536))
+        .await
+        .expect("insert");
+    let existing = registry
+        .insert_if_absent(CollectionRecord::new("code", "ollama", "nomic", 768))
+        .await
+        .expect("insert again");
+    assert_eq!(existing.provider, "openai", "first record wins");
+
+    let reopened = CollectionRegistry::open(&path).await.expect("reopen");
+    let record = reopened.get("code").await.expect("record");
+    assert_eq!(record.model, "small");
+    assert_eq!(record.dimensions, 1536);
+    assert_eq!(reopened.physical_name("code").await, "code");
+    assert_eq!(reopened.physical_name("other").await, "other");
+}
+
+#[tokio::test]
+async fn test_first_insert_records_collection() {
+    let Fixture { guard, .. } = fixture();
+    index(&guard, "code").await;
+
+    let record = guard.registry().get("code").await.expect("record");
+    assert_eq!(record.provider, "stub");
+    assert_eq!(record.model, "stub-model");
+    assert_eq!(record.dimensions, 4);
+}
+
+#[tokio::test]
+async fn test_failover_records_the_member_provider() {
+    let Fixture {
+        guard,
+        embedding_handle,
+        ..
+    } = fixture();
+    let member: Arc<dyn EmbeddingProvider> = Arc::new(StubEmbedding {
+        name: "stub",
+        dimensions: 4,
+    });
+    let router = Arc::new(DefaultProviderRouter::new(
+        Arc::new(InMemoryHealthMonitor::new()),
+        vec!["stub".to_string()],
+        Vec::new(),
+    ));
+    let failover = FailoverEmbeddingProvider::new(
+        vec![FailoverMember::new("primary", member.clone(), "stub-model")],
+        router,
+    )
+    .expect("chain")
+    .with_registry(guard.registry().clone());
+    embedding_handle.set(Arc::new(failover));
+
+    index(&guard, "code").await;
+    let record = guard.registry().get("code").await.expect("record");
+    assert_eq!(record.provider, "stub", "not the failover wrapper");
+
+    // Leaving failover keeps the collection usable with the same provider
+    embedding_handle.set(member);
+    guard
+        .embedding_provider()
+        .embed_batch_for("code", &["fn c() {}".to_string()])
+        .await
+        .expect("same embedding space");
+}
+
+#[tokio::test]
+async fn test_mismatched_provider_is_refused() {
+    let Fixture {
+        guard,
+        embedding_handle,
+        ..
+    } = fixture();
+    index(&guard, "code").await;
+
+    // Bypass the guard, as a handle-level switch would
+    embedding_handle.set(Arc::new(StubEmbedding {
+        name: "other",
+        dimensions: 8,
+    }));
+
+    let error = guard
+        .embedding_provider()
+        .embed_batch_for("code", &["fn c() {}".to_string()])
+        .await
+        .expect_err("embedding space mismatch");
+    assert!(error.to_string().contains("migrate"));
+
+    let foreign = Embedding {
+        vector: vec![0.1; 8],
+        model: "other-model".to_string(),
+        dimensions: 8,
+    };
+    assert!(
+        guard
+            .vector_store_provider()
+            .insert_vectors("code", &[foreign], vec![chunk("c", "fn c() {}")])
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_incompatible_switch_is_refused() {
+    let Fixture { guard, .. } = fixture();
+    index(&guard, "code").await;
+
+    let outcome = guard
+        .switch_embedding(&EmbeddingProviderConfig::new("null"), false)
+        .await
+        .expect("switch");
+    assert_eq!(
+        outcome,
+        SwitchOutcome::Refused {
+            conflicts: vec!["code".to_string()]
+        }
+    );
+    assert_eq!(guard.current_embedding_provider(), "stub");
+
+    let outcome = guard
+        .switch_vector_store(&VectorStoreProviderConfig::new("null"))
+        .await
+        .expect("switch");
+    assert!(matches!(outcome, SwitchOutcome::Refused { .. }));
+}
+
+#[tokio::test]
+async fn test_switch_without_collections_takes_effect() {
+    let Fixture { guard, .. } = fixture();
+
+    let outcome = guard
+        .switch_embedding(&EmbeddingProviderConfig::new("null"), false)
+        .await
+        .expect("switch");
+    assert_eq!(
+        outcome,
+        SwitchOutcome::Switched {
+            provider: "null".to_string()
+        }
+    );
+    assert_eq!(guard.current_embedding_provider(), "null");
+    assert_eq!(guard.embedding_provider().provider_name(), "guarded");
+}
+
+#[tokio::test]
+async fn test_migration_requires_persistent_registry() {
+    let Fixture { guard, .. } = fixture();
+    index(&guard, "code").await;
+
+    assert!(
+        guard
+            .switch_embedding(&EmbeddingProviderConfig::new("null"), true)
+            .await
+            .is_err()
+    );
+    assert!(guard.migration_status().is_none());
+}
+
+#[tokio::test]
+async fn test_migration_swaps_in_shadow_collection() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let registry = CollectionRegistry::open(dir.path().join("collections.json"))
+        .await
+        .expect("open");
+    let Fixture { guard, store, .. } = fixture_with_registry(registry);
+    index(&guard, "code").await;
+
+    let outcome = guard
+        .switch_embedding(&EmbeddingProviderConfig::new("null"), true)
+        .await
+        .expect("switch");
+    assert_eq!(
+        outcome,
+        SwitchOutcome::Migrating {
+            collections: vec!["code".to_string()]
+        }
+    );
+
+    let mut status = guard.migration_status().expect("status");
+    for _ in 0..100 {
+        if status.state != MigrationState::Running {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        status = guard.migration_status().expect("status");
+    }
+    assert_eq!(
+        status.state,
+        MigrationState::Completed,
+        "{:?}",
+        status.error
+    );
+    assert_eq!(status.chunks_migrated, 2);
+
+    let record = guard.registry().get("code").await.expect("record");
+    assert_eq!(record.provider, "null");
+    assert_ne!(record.physical_name, "code");
+    assert_eq!(guard.current_embedding_provider(), "null");
+    assert!(!store.collection_exists("code").await.expect("exists"));
+
+    let chunks = guard
+        .vector_store_provider()
+        .list_vectors("code", 10)
+        .await
+        .expect("list through guard");
+    assert_eq!(chunks.len(), 2);
+
+    // Metadata is carried over unchanged
+    let exported = guard
+        .vector_store_provider()
+        .export_vectors("code")
+        .await
+        .expect("export through guard");
+    assert!(
+        exported
+            .iter()
+            .all(|chunk| chunk.metadata["node_type"] == json!("function_item"))
+    );
+
+    // The migrated collection accepts writes from the new provider
+    index(&guard, "code").await;
+}
+
+#[tokio::test]
+async fn test_bundle_round_trip_into_new_collection() {
+    let Fixture { guard, .. } = fixture();
+    index(&guard, "code").await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("code.bundle");
+    // One vector per page, so the vectors file holds two gzip members
+    let bundler = CollectionBundler::new(guard.clone()).with_batch_size(1);
+
+    let options = ExportOptions {
+        compress: true,
+        encrypt: false,
+    };
+    let manifest = bundler
+        .export("code", &path, options)
+        .await
+        .expect("export");
+    assert_eq!(manifest.vector_count, 2);
+    let files: Vec<_> = std::fs::read_dir(dir.path())
+        .expect("read dir")
+        .map(|entry| entry.expect("entry").file_name())
+        .collect();
+    assert_eq!(files, vec!["code.bundle"]);
+    assert_eq!(manifest.compression, BundleCompression::Gzip);
+    assert_eq!(manifest.embedding.model, "stub-model");
+
+    let imported = bundler.import(&path, Some("copy")).await.expect("import");
+    assert_eq!(imported.collection, "copy");
+    let mut ids: Vec<String> = guard
+        .vector_store_provider()
+        .list_vectors("copy", 10)
+        .await
+        .expect("list")
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    assert_eq!(ids.len(), 2);
+    let record = guard.registry().get("copy").await.expect("record");
+    assert_eq!(record.provider, "stub");
+    assert_eq!(record.dimensions, 4);
+
+    let error = bundler
+        .import(&path, None)
+        .await
+        .expect_err("collection exists");
+    assert!(error.to_string().contains("already exists"));
+}
+
+#[tokio::test]
+async fn test_unrecorded_collection_exports_with_active_embedding() {
+    let Fixture { guard, store, .. } = fixture();
+    let embeddings = guard
+        .embedding_provider()
+        .embed_batch(&["fn a() {}".to_string()])
+        .await
+        .expect("embed");
+    // Written past the guard, as by a release without the registry
+    store.create_collection("legacy", 4).await.expect("create");
+    store
+        .insert_vectors("legacy", &embeddings, vec![chunk("a", "fn a() {}")])
+        .await
+        .expect("insert");
+    assert!(guard.registry().get("legacy").await.is_none());
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let manifest = CollectionBundler::new(guard.clone())
+        .export(
+            "legacy",
+            &dir.path().join("legacy.bundle"),
+            ExportOptions::default(),
+        )
+        .await
+        .expect("export");
+    assert_eq!(manifest.vector_count, 1);
+    assert_eq!(manifest.embedding.provider, "stub");
+    assert_eq!(manifest.embedding.model, "stub-model");
+    assert_eq!(manifest.embedding.dimensions, 4);
+
+    let error = CollectionBundler::new(guard)
+        .export(
+            "missing",
+            &dir.path().join("missing.bundle"),
+            ExportOptions::default(),
+        )
+        .await
+        .expect_err("missing collection");
+    assert!(matches!(error, Error::NotFound { .. }));
+}
+
+#[tokio::test]
+async fn test_bundle_import_refuses_model_mismatch() {
+    let Fixture {
+        guard,
+        embedding_handle,
+        ..
+    } = fixture();
+    index(&guard, "code").await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("code.bundle");
+    let bundler = CollectionBundler::new(guard.clone());
+    bundler
+        .export("code", &path, ExportOptions::default())
+        .await
+        .expect("export");
+
+    embedding_handle.set(Arc::new(StubEmbedding {
+        name: "other",
+        dimensions: 8,
+    }));
+
+    let error = bundler
+        .import(&path, Some("copy"))
+        .await
+        .expect_err("embedding space mismatch");
+    assert!(error.to_string().contains("stub-model"));
+    assert!(guard.registry().get("copy").await.is_none());
+}
+
+#[tokio::test]
+async fn test_tampered_bundle_is_refused() {
+    let Fixture { guard, .. } = fixture();
+    index(&guard, "code").await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("code.bundle");
+    let bundler = CollectionBundler::new(guard.clone());
+    bundler
+        .export("code", &path, ExportOptions::default())
+        .await
+        .expect("export");
+
+    let mut bytes = std::fs::read(&path).expect("read");
+    let needle = b"fn a() {}";
+    let at = bytes
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .expect("chunk content in bundle");
+    bytes[at + 3] = b'x';
+    std::fs::write(&path, bytes).expect("write");
+
+    let error = bundler
+        .import(&path, Some("copy"))
+        .await
+        .expect_err("checksum mismatch");
+    assert!(error.to_string().contains("checksum"));
+}
+
+#[tokio::test]
+async fn test_encrypted_bundle_needs_the_key() {
+    let Fixture { guard, .. } = fixture();
+    index(&guard, "code").await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("code.bundle");
+    let crypto =
+        Arc::new(CryptoService::new(CryptoService::generate_master_key()).expect("crypto"));
+    let bundler = CollectionBundler::new(guard.clone()).with_crypto(crypto);
+
+    let options = ExportOptions {
+        compress: true,
+        encrypt: true,
+    };
+    let manifest = bundler
+        .export("code", &path, options)
+        .await
+        .expect("export");
+    assert!(manifest.encryption.is_some());
+    let bytes = std::fs::read(&path).expect("read");
+    assert!(!bytes.windows(9).any(|w| w == b"fn a() {}"));
+
+    assert!(
+        CollectionBundler::new(guard.clone())
+            .import(&path, Some("copy"))
+            .await
+            .is_err()
+    );
+    bundler
+        .import(&path, Some("copy"))
+        .await
+        .expect("import with key");
+    let chunks = guard
+        .vector_store_provider()
+        .list_vectors("copy", 10)
+        .await
+        .expect("list");
+    assert_eq!(chunks.len(), 2);
+}
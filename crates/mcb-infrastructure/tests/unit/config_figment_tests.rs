@@ -132,6 +132,38 @@ fn test_jwt_secret_required_when_auth_enabled() {
     remove_env("MCP__AUTH__ENABLED");
 }
 
+/// Verify the NATS event bus fails validation without a server URL
+#[test]
+#[serial]
+fn test_nats_url_required_for_nats_event_bus() {
+    disable_auth();
+    set_env("MCP__SYSTEM__INFRASTRUCTURE__EVENT_BUS__PROVIDER", "nats");
+
+    let err = ConfigLoader::new()
+        .load()
+        .expect_err("nats event bus without nats_url should fail validation")
+        .to_string();
+    assert!(
+        err.contains("NATS URL"),
+        "Error message should mention the NATS URL, got: {}",
+        err
+    );
+
+    set_env(
+        "MCP__SYSTEM__INFRASTRUCTURE__EVENT_BUS__NATS_URL",
+        "nats://localhost:4222",
+    );
+    let config = ConfigLoader::new().load().expect("Should load config");
+    assert_eq!(
+        config.system.infrastructure.event_bus.nats_url.as_deref(),
+        Some("nats://localhost:4222")
+    );
+
+    remove_env("MCP__SYSTEM__INFRASTRUCTURE__EVENT_BUS__NATS_URL");
+    remove_env("MCP__SYSTEM__INFRASTRUCTURE__EVENT_BUS__PROVIDER");
+    cleanup_auth();
+}
+
 /// Verify watching_enabled config is loaded from Figment, not direct env::var
 #[test]
 #[serial]
@@ -303,6 +303,11 @@ fn test_rate_limiter_constants() {
         RATE_LIMITER_DEFAULT_BURST >= RATE_LIMITER_DEFAULT_RPS,
         "Burst should be >= RPS"
     );
+
+    // Expensive tools get a tighter budget than ordinary requests
+    assert!(RATE_LIMITER_EXPENSIVE_RPS <= RATE_LIMITER_DEFAULT_RPS);
+    assert!(RATE_LIMITER_EXPENSIVE_BURST >= RATE_LIMITER_EXPENSIVE_RPS);
+    assert!(RATE_LIMITER_EXPENSIVE_TOOLS.contains(&"index_codebase"));
 }
 
 // ============================================================================
@@ -1,7 +1,9 @@
 //! Cryptography Tests
 
+use mcb_infrastructure::config::PasswordAlgorithm;
 use mcb_infrastructure::crypto::{
-    CryptoService, HashUtils, PasswordService, SecureErasure, TokenGenerator,
+    CryptoService, HashUtils, JwtService, JwtTokenKind, PasswordService, SecureErasure,
+    TokenGenerator,
 };
 
 #[test]
@@ -67,3 +69,68 @@ fn test_constant_time_eq() {
     assert!(!HashUtils::constant_time_eq(b"test", b"different"));
     assert!(!HashUtils::constant_time_eq(b"test", b"test_longer"));
 }
+
+#[test]
+fn test_password_service_algorithms_round_trip() {
+    for algorithm in [
+        PasswordAlgorithm::Argon2,
+        PasswordAlgorithm::Bcrypt,
+        PasswordAlgorithm::Pbkdf2,
+    ] {
+        let service = PasswordService::with_algorithm(algorithm);
+        let hash = service.hash_password("correct horse").unwrap();
+
+        assert_eq!(PasswordService::detect_algorithm(&hash), Some(algorithm));
+        assert!(service.verify_password("correct horse", &hash).unwrap());
+        assert!(!service.verify_password("wrong horse", &hash).unwrap());
+        assert!(!service.needs_rehash(&hash));
+    }
+}
+
+#[test]
+fn test_password_service_verifies_other_algorithms() {
+    let bcrypt_hash = PasswordService::with_algorithm(PasswordAlgorithm::Bcrypt)
+        .hash_password("secret-pass")
+        .unwrap();
+    let argon2 = PasswordService::new();
+
+    assert!(argon2.verify_password("secret-pass", &bcrypt_hash).unwrap());
+    assert!(argon2.needs_rehash(&bcrypt_hash));
+}
+
+#[test]
+fn test_jwt_service_issue_and_validate() {
+    let jwt = JwtService::new("0123456789abcdef0123456789abcdef", 3600, 7200).unwrap();
+    let token = jwt.issue("alice", "admin", JwtTokenKind::Access).unwrap();
+
+    let claims = jwt.validate(&token, JwtTokenKind::Access).unwrap();
+    assert_eq!(claims.sub, "alice");
+    assert_eq!(claims.role, "admin");
+    assert!(jwt.validate(&token, JwtTokenKind::Refresh).is_err());
+}
+
+#[test]
+fn test_jwt_service_rejects_tampered_and_foreign_tokens() {
+    let jwt = JwtService::new("0123456789abcdef0123456789abcdef", 3600, 7200).unwrap();
+    let other = JwtService::new("fedcba9876543210fedcba9876543210", 3600, 7200).unwrap();
+    let token = jwt.issue("alice", "viewer", JwtTokenKind::Access).unwrap();
+
+    assert!(other.validate(&token, JwtTokenKind::Access).is_err());
+
+    // Swap in the payload of an admin token while keeping the viewer signature
+    let admin_token = jwt.issue("alice", "admin", JwtTokenKind::Access).unwrap();
+    let mut parts: Vec<&str> = token.split('.').collect();
+    parts[1] = admin_token.split('.').nth(1).unwrap();
+    assert!(
+        jwt.validate(&parts.join("."), JwtTokenKind::Access)
+            .is_err()
+    );
+    assert!(jwt.validate("not-a-token", JwtTokenKind::Access).is_err());
+}
+
+#[test]
+fn test_jwt_service_rejects_expired_token() {
+    let jwt = JwtService::new("0123456789abcdef0123456789abcdef", 0, 0).unwrap();
+    let token = jwt.issue("alice", "admin", JwtTokenKind::Access).unwrap();
+    assert!(jwt.validate(&token, JwtTokenKind::Access).is_err());
+}
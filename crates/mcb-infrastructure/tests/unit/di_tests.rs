@@ -52,3 +52,92 @@ fn test_available_providers_empty() {
     // Even with empty providers, section headers should be present
     assert!(display.contains("Embedding Providers"));
 }
+
+// ============================================================================
+// Event bus selection
+// ============================================================================
+
+mod event_bus {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use mcb_domain::ports::infrastructure::EventBusProvider;
+    use mcb_domain::ports::providers::{CacheEntryConfig, CacheProvider};
+    use mcb_infrastructure::config::{AppConfig, EventBusConfig};
+    use mcb_infrastructure::di::create_event_bus;
+    use mcb_providers::cache::{MokaCacheProvider, TieredCacheProvider};
+
+    /// Two tiered caches over one L2, each listening on its own handle to the bus
+    async fn tiered_caches(
+        buses: [Arc<dyn EventBusProvider>; 2],
+    ) -> (Arc<TieredCacheProvider>, Arc<TieredCacheProvider>) {
+        let l2: Arc<dyn CacheProvider> = Arc::new(MokaCacheProvider::new());
+        let [a, b] = buses.map(|bus| {
+            let l1 = MokaCacheProvider::with_config(1024 * 1024, Duration::from_secs(60));
+            let cache = Arc::new(TieredCacheProvider::new(l1, Arc::clone(&l2)).with_event_bus(bus));
+            cache.listen_for_invalidations().expect("listener");
+            cache
+        });
+        for _ in 0..200 {
+            if a.is_listening() && b.is_listening() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert!(a.is_listening() && b.is_listening());
+        (a, b)
+    }
+
+    /// A write through `a` must evict the stale copy `b` holds in its L1
+    async fn assert_write_invalidates_other(a: &TieredCacheProvider, b: &TieredCacheProvider) {
+        a.set_json("mcb:key", "1", CacheEntryConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(b.get_json("mcb:key").await.unwrap().as_deref(), Some("1"));
+
+        a.set_json("mcb:key", "2", CacheEntryConfig::default())
+            .await
+            .unwrap();
+        let mut value = b.get_json("mcb:key").await.unwrap();
+        for _ in 0..200 {
+            if value.as_deref() == Some("2") {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            value = b.get_json("mcb:key").await.unwrap();
+        }
+        assert_eq!(value.as_deref(), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn test_tiered_caches_share_invalidations_over_configured_bus() {
+        let (bus, _) = create_event_bus(&AppConfig::default()).await.unwrap();
+        let (a, b) = tiered_caches([Arc::clone(&bus), bus]).await;
+        assert_write_invalidates_other(&a, &b).await;
+    }
+
+    /// Two buses connected to the same NATS server stand in for two instances
+    #[tokio::test]
+    async fn test_tiered_caches_share_invalidations_over_nats() {
+        let Ok(url) = std::env::var("NATS_URL") else {
+            return;
+        };
+        let mut config = AppConfig::default();
+        config.system.infrastructure.event_bus = EventBusConfig::nats(url);
+        let (first, _) = create_event_bus(&config).await.unwrap();
+        let (second, _) = create_event_bus(&config).await.unwrap();
+        let (a, b) = tiered_caches([first, second]).await;
+        assert_write_invalidates_other(&a, &b).await;
+    }
+
+    #[tokio::test]
+    async fn test_nats_event_bus_requires_url() {
+        let mut config = AppConfig::default();
+        config.system.infrastructure.event_bus = EventBusConfig {
+            nats_url: None,
+            ..EventBusConfig::nats("")
+        };
+        let err = create_event_bus(&config).await.err().expect("missing URL");
+        assert!(err.to_string().contains("NATS URL"), "got: {err}");
+    }
+}
@@ -0,0 +1,300 @@
+//! Event Store Tests
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::infrastructure::{
+    DomainEventStream, EventBusProvider, EventQuery, EventStoreInterface, StoredEventStream,
+};
+use mcb_infrastructure::config::EventStoreConfig;
+use mcb_infrastructure::event_store::{JsonlEventStore, RecordingEventBus};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tempfile::TempDir;
+
+const DAY: Duration = Duration::from_secs(86_400);
+
+fn started(collection: &str) -> DomainEvent {
+    DomainEvent::IndexingStarted {
+        collection: collection.to_string(),
+        total_files: 10,
+    }
+}
+
+fn progress(collection: &str, processed: usize) -> DomainEvent {
+    DomainEvent::IndexingProgress {
+        collection: collection.to_string(),
+        processed,
+        total: 10,
+        current_file: None,
+    }
+}
+
+/// Event bus that keeps published events for inspection
+#[derive(Default)]
+struct CollectingEventBus {
+    published: Mutex<Vec<DomainEvent>>,
+}
+
+#[async_trait]
+impl EventBusProvider for CollectingEventBus {
+    async fn publish_event(&self, event: DomainEvent) -> Result<()> {
+        self.published.lock().unwrap().push(event);
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic: &str) -> Result<String> {
+        Ok(topic.to_string())
+    }
+}
+
+async fn next_sequence(stream: &mut StoredEventStream) -> u64 {
+    tokio::time::timeout(Duration::from_secs(1), stream.next())
+        .await
+        .expect("event not delivered")
+        .expect("stream ended")
+        .sequence
+}
+
+#[tokio::test]
+async fn test_from_config() {
+    let config = EventStoreConfig::default();
+    assert!(config.enabled);
+    assert!(
+        JsonlEventStore::from_config(&config)
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    let config = EventStoreConfig {
+        enabled: false,
+        ..EventStoreConfig::default()
+    };
+    assert!(
+        JsonlEventStore::from_config(&config)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_append_assigns_increasing_sequences() {
+    let store = JsonlEventStore::in_memory(100, DAY);
+    assert_eq!(store.last_sequence(), 0);
+
+    let first = store.append(started("alpha")).await.unwrap();
+    let second = store.append(progress("alpha", 5)).await.unwrap();
+
+    assert_eq!(first.sequence, 1);
+    assert_eq!(second.sequence, 2);
+    assert_eq!(store.last_sequence(), 2);
+}
+
+#[tokio::test]
+async fn test_query_filters_newest_first() {
+    let store = JsonlEventStore::in_memory(100, DAY);
+    store.append(started("alpha")).await.unwrap();
+    store.append(progress("alpha", 5)).await.unwrap();
+    store.append(progress("beta", 3)).await.unwrap();
+    store.append(progress("alpha", 10)).await.unwrap();
+
+    let all = store.query(&EventQuery::default()).await.unwrap();
+    let sequences: Vec<_> = all.iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, [4, 3, 2, 1]);
+
+    let query = EventQuery {
+        event_type: Some("IndexingProgress".to_string()),
+        collection: Some("alpha".to_string()),
+        limit: Some(1),
+        ..EventQuery::default()
+    };
+    let events = store.query(&query).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].sequence, 4);
+
+    let query = EventQuery {
+        until: Some(chrono::Utc::now() - chrono::Duration::minutes(1)),
+        ..EventQuery::default()
+    };
+    assert!(store.query(&query).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_retention_drops_oldest_events() {
+    let store = JsonlEventStore::in_memory(3, DAY);
+    for processed in 0..5 {
+        store.append(progress("alpha", processed)).await.unwrap();
+    }
+
+    let events = store.query(&EventQuery::default()).await.unwrap();
+    let sequences: Vec<_> = events.iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, [5, 4, 3]);
+    assert_eq!(store.last_sequence(), 5);
+}
+
+#[tokio::test]
+async fn test_reopen_continues_sequence() {
+    let dir = TempDir::new().unwrap();
+    {
+        let store = JsonlEventStore::open(dir.path(), 100, DAY).await.unwrap();
+        store.append(started("alpha")).await.unwrap();
+        store.append(progress("alpha", 5)).await.unwrap();
+    }
+
+    let store = JsonlEventStore::open(dir.path(), 100, DAY).await.unwrap();
+    assert_eq!(store.last_sequence(), 2);
+    assert_eq!(store.query(&EventQuery::default()).await.unwrap().len(), 2);
+
+    let next = store.append(progress("alpha", 10)).await.unwrap();
+    assert_eq!(next.sequence, 3);
+}
+
+#[tokio::test]
+async fn test_compaction_keeps_retained_events() {
+    let dir = TempDir::new().unwrap();
+    {
+        let store = JsonlEventStore::open(dir.path(), 2, DAY).await.unwrap();
+        for processed in 0..10 {
+            store.append(progress("alpha", processed)).await.unwrap();
+        }
+    }
+
+    let content = std::fs::read_to_string(dir.path().join("events.jsonl")).unwrap();
+    assert!(content.lines().count() <= 4);
+
+    let store = JsonlEventStore::open(dir.path(), 2, DAY).await.unwrap();
+    assert_eq!(store.last_sequence(), 10);
+    let sequences: Vec<_> = store
+        .query(&EventQuery::default())
+        .await
+        .unwrap()
+        .iter()
+        .map(|e| e.sequence)
+        .collect();
+    assert_eq!(sequences, [10, 9]);
+}
+
+#[tokio::test]
+async fn test_subscribe_after_replays_missed_events() {
+    let store = JsonlEventStore::in_memory(100, DAY);
+    for processed in 0..3 {
+        store.append(progress("alpha", processed)).await.unwrap();
+    }
+
+    let mut stream = store.subscribe_after(Some(1)).await.unwrap();
+    store.append(progress("alpha", 3)).await.unwrap();
+
+    assert_eq!(next_sequence(&mut stream).await, 2);
+    assert_eq!(next_sequence(&mut stream).await, 3);
+    assert_eq!(next_sequence(&mut stream).await, 4);
+}
+
+#[tokio::test]
+async fn test_subscribe_without_sequence_streams_new_events_only() {
+    let store = JsonlEventStore::in_memory(100, DAY);
+    store.append(started("alpha")).await.unwrap();
+
+    let mut stream = store.subscribe_after(None).await.unwrap();
+    store.append(progress("alpha", 1)).await.unwrap();
+
+    assert_eq!(next_sequence(&mut stream).await, 2);
+}
+
+#[tokio::test]
+async fn test_subscribe_after_unknown_sequence_replays_everything() {
+    let store = JsonlEventStore::in_memory(100, DAY);
+    store.append(started("alpha")).await.unwrap();
+    store.append(progress("alpha", 1)).await.unwrap();
+
+    let mut stream = store.subscribe_after(Some(50)).await.unwrap();
+
+    assert_eq!(next_sequence(&mut stream).await, 1);
+    assert_eq!(next_sequence(&mut stream).await, 2);
+}
+
+#[tokio::test]
+async fn test_recording_event_bus_records_and_forwards() {
+    let store = Arc::new(JsonlEventStore::in_memory(100, DAY));
+    let inner = Arc::new(CollectingEventBus::default());
+    let bus = RecordingEventBus::new(
+        Arc::clone(&inner) as Arc<dyn EventBusProvider>,
+        Arc::clone(&store) as Arc<dyn EventStoreInterface>,
+    );
+
+    bus.publish_event(started("alpha")).await.unwrap();
+
+    assert_eq!(*inner.published.lock().unwrap(), [started("alpha")]);
+    let recorded = store.query(&EventQuery::default()).await.unwrap();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].event, started("alpha"));
+}
+
+#[tokio::test]
+async fn test_recording_event_bus_forwards_transient_events_unrecorded() {
+    let store = Arc::new(JsonlEventStore::in_memory(100, DAY));
+    let inner = Arc::new(CollectingEventBus::default());
+    let bus = RecordingEventBus::new(
+        Arc::clone(&inner) as Arc<dyn EventBusProvider>,
+        Arc::clone(&store) as Arc<dyn EventStoreInterface>,
+    );
+    let invalidate = DomainEvent::CacheInvalidate {
+        namespace: Some("search-alpha".to_string()),
+        key: None,
+        origin: None,
+    };
+
+    bus.publish_event(invalidate.clone()).await.unwrap();
+
+    assert_eq!(*inner.published.lock().unwrap(), [invalidate]);
+    assert!(
+        store
+            .query(&EventQuery::default())
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(store.last_sequence(), 0);
+}
+
+#[tokio::test]
+async fn test_concurrent_appends_reach_the_file_in_sequence_order() {
+    let dir = TempDir::new().unwrap();
+    {
+        let store = JsonlEventStore::open(dir.path(), 100, DAY).await.unwrap();
+        let appends: Vec<_> = (0..50)
+            .map(|processed| {
+                let store = store.clone();
+                tokio::spawn(async move { store.append(progress("alpha", processed)).await })
+            })
+            .collect();
+        for append in appends {
+            append.await.unwrap().unwrap();
+        }
+    }
+
+    let content = std::fs::read_to_string(dir.path().join("events.jsonl")).unwrap();
+    let sequences: Vec<u64> = content
+        .lines()
+        .map(|line| {
+            serde_json::from_str::<serde_json::Value>(line).unwrap()["sequence"]
+                .as_u64()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(sequences, (1..=50).collect::<Vec<_>>());
+}
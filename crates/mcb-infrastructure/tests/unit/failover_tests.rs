@@ -0,0 +1,322 @@
+//! Embedding Failover Tests
+//!
+//! Covers member selection, compatibility groups, registry-backed
+//! collection pinning and outcome reporting of `FailoverEmbeddingProvider`.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::routing::{ProviderHealthStatus, ProviderRouter};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+use mcb_infrastructure::collections::{CollectionRecord, CollectionRegistry};
+use mcb_infrastructure::routing::{
+    DefaultProviderRouter, FailoverEmbeddingProvider, FailoverMember, InMemoryHealthMonitor,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+/// Embedding provider that can be switched between up and down
+struct StubEmbedding {
+    name: &'static str,
+    dimensions: usize,
+    down: AtomicBool,
+    permanent: bool,
+    calls: AtomicU32,
+}
+
+impl StubEmbedding {
+    fn new(name: &'static str, dimensions: usize) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            dimensions,
+            down: AtomicBool::new(false),
+            permanent: false,
+            calls: AtomicU32::new(0),
+        })
+    }
+
+    fn rejecting(name: &'static str, dimensions: usize) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            dimensions,
+            down: AtomicBool::new(true),
+            permanent: true,
+            calls: AtomicU32::new(0),
+        })
+    }
+
+    fn set_down(&self, down: bool) {
+        self.down.store(down, Ordering::SeqCst);
+    }
+
+    fn calls(&self) -> u32 {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for StubEmbedding {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.down.load(Ordering::SeqCst) {
+            return Err(if self.permanent {
+                Error::embedding(format!("{} authentication failed: bad key", self.name))
+            } else {
+                Error::unavailable(format!("{} server error (503): down", self.name), None)
+            });
+        }
+        Ok(texts
+            .iter()
+            .map(|_| Embedding {
+                vector: vec![0.0; self.dimensions],
+                model: self.name.to_string(),
+                dimensions: self.dimensions,
+            })
+            .collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn provider_name(&self) -> &str {
+        self.name
+    }
+}
+
+fn router() -> Arc<dyn ProviderRouter> {
+    Arc::new(DefaultProviderRouter::new(
+        Arc::new(InMemoryHealthMonitor::with_thresholds(1, 1)),
+        vec!["voyageai".to_string(), "openai".to_string()],
+        Vec::new(),
+    ))
+}
+
+fn texts() -> Vec<String> {
+    vec!["fn main() {}".to_string()]
+}
+
+fn chain(
+    members: Vec<FailoverMember>,
+    router: &Arc<dyn ProviderRouter>,
+) -> FailoverEmbeddingProvider {
+    FailoverEmbeddingProvider::new(members, Arc::clone(router)).expect("chain")
+}
+
+#[tokio::test]
+async fn test_fails_over_to_compatible_member() {
+    let router = router();
+    let primary = StubEmbedding::new("voyageai", 4);
+    let secondary = StubEmbedding::new("openai", 4);
+    let failover = chain(
+        vec![
+            FailoverMember::new("voyage", primary.clone(), "shared-model"),
+            FailoverMember::new("openai", secondary.clone(), "shared-model"),
+        ],
+        &router,
+    );
+
+    primary.set_down(true);
+    let embeddings = failover
+        .embed_batch_for("code", &texts())
+        .await
+        .expect("served by secondary");
+
+    assert_eq!(embeddings[0].model, "openai");
+    assert_eq!(failover.active_provider("code"), Some("openai"));
+    assert_eq!(
+        failover.provider_name_for("code"),
+        "voyageai",
+        "the collection stays attributed to its pinned member"
+    );
+    assert_eq!(
+        router
+            .get_provider_health("voyageai")
+            .await
+            .expect("health"),
+        ProviderHealthStatus::Unhealthy
+    );
+    assert_eq!(failover.provider_name(), "failover");
+}
+
+#[tokio::test]
+async fn test_never_fails_over_to_incompatible_member() {
+    let router = router();
+    let primary = StubEmbedding::new("voyageai", 4);
+    let other = StubEmbedding::new("openai", 8);
+    let failover = chain(
+        vec![
+            FailoverMember::new("voyage", primary.clone(), "voyage-code-3"),
+            FailoverMember::new("openai", other.clone(), "text-embedding-3-small"),
+        ],
+        &router,
+    );
+
+    assert_eq!(failover.dimensions_for("code"), 4);
+    primary.set_down(true);
+
+    let error = failover
+        .embed_batch_for("code", &texts())
+        .await
+        .expect_err("no compatible member left");
+    assert!(error.to_string().contains("server error"));
+    assert_eq!(other.calls(), 0, "incompatible member is never used");
+}
+
+#[tokio::test]
+async fn test_new_collection_never_switches_models() {
+    let router = router();
+    let primary = StubEmbedding::new("voyageai", 4);
+    let fallback = StubEmbedding::new("openai", 8);
+    let failover = chain(
+        vec![
+            FailoverMember::new("voyage", primary.clone(), "voyage-code-3"),
+            FailoverMember::new("openai", fallback.clone(), "text-embedding-3-small"),
+        ],
+        &router,
+    );
+
+    primary.set_down(true);
+    router
+        .report_failure("voyageai", "server error (503)")
+        .await
+        .expect("report");
+
+    assert!(failover.embed_batch_for("fresh", &texts()).await.is_err());
+    assert!(failover.embed_batch(&texts()).await.is_err());
+    assert_eq!(failover.dimensions_for("fresh"), 4);
+    assert_eq!(fallback.calls(), 0, "another model is never used");
+
+    primary.set_down(false);
+    failover
+        .embed_batch_for("fresh", &texts())
+        .await
+        .expect("served by the primary");
+
+    let snapshot = failover.snapshot().await;
+    assert_eq!(snapshot.members.len(), 2);
+    assert_eq!(snapshot.collections.len(), 1);
+    assert_eq!(snapshot.collections[0].collection, "fresh");
+    assert_eq!(snapshot.collections[0].model, "voyage-code-3");
+}
+
+#[tokio::test]
+async fn test_registry_pins_survive_restart() {
+    let router = router();
+    let registry = Arc::new(CollectionRegistry::in_memory());
+    registry
+        .insert_if_absent(CollectionRecord::new(
+            "code",
+            "failover",
+            "text-embedding-3-small",
+            8,
+        ))
+        .await
+        .expect("record");
+
+    let primary = StubEmbedding::new("voyageai", 4);
+    let secondary = StubEmbedding::new("openai", 8);
+    let failover = chain(
+        vec![
+            FailoverMember::new("voyage", primary.clone(), "voyage-code-3"),
+            FailoverMember::new("openai", secondary.clone(), "text-embedding-3-small"),
+        ],
+        &router,
+    )
+    .with_registry(registry.clone());
+
+    assert_eq!(failover.dimensions_for("code"), 8);
+    failover.restore_pins().await;
+    assert_eq!(failover.active_provider("code"), Some("openai"));
+
+    let embeddings = failover
+        .embed_batch_for("code", &texts())
+        .await
+        .expect("served by the recorded model");
+    assert_eq!(embeddings[0].dimensions, 8);
+    assert_eq!(primary.calls(), 0);
+
+    secondary.set_down(true);
+    assert!(failover.embed_batch_for("code", &texts()).await.is_err());
+    assert_eq!(primary.calls(), 0, "pinned collection never moves models");
+}
+
+#[tokio::test]
+async fn test_unknown_recorded_model_is_rejected() {
+    let router = router();
+    let registry = Arc::new(CollectionRegistry::in_memory());
+    registry
+        .insert_if_absent(CollectionRecord::new(
+            "code",
+            "failover",
+            "retired-model",
+            4,
+        ))
+        .await
+        .expect("record");
+    let primary = StubEmbedding::new("voyageai", 4);
+    let failover = chain(
+        vec![FailoverMember::new(
+            "voyage",
+            primary.clone(),
+            "voyage-code-3",
+        )],
+        &router,
+    )
+    .with_registry(registry);
+
+    let error = failover
+        .embed_batch_for("code", &texts())
+        .await
+        .expect_err("no member provides the recorded model");
+    assert!(error.to_string().contains("retired-model"));
+    assert_eq!(primary.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_permanent_errors_do_not_fail_over() {
+    let router = router();
+    let primary = StubEmbedding::rejecting("voyageai", 4);
+    let secondary = StubEmbedding::new("openai", 4);
+    let failover = chain(
+        vec![
+            FailoverMember::new("voyage", primary.clone(), "shared-model"),
+            FailoverMember::new("openai", secondary.clone(), "shared-model"),
+        ],
+        &router,
+    );
+
+    assert!(failover.embed_batch_for("code", &texts()).await.is_err());
+    assert_eq!(secondary.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_outcome_reporting_can_be_disabled() {
+    let router = router();
+    let primary = StubEmbedding::new("voyageai", 4);
+    let failover = FailoverEmbeddingProvider::new(
+        vec![FailoverMember::new(
+            "voyage",
+            primary.clone(),
+            "voyage-code-3",
+        )],
+        Arc::clone(&router),
+    )
+    .expect("chain")
+    .with_outcome_reporting(false);
+
+    primary.set_down(true);
+    assert!(failover.embed_batch_for("code", &texts()).await.is_err());
+    assert_eq!(
+        router
+            .get_provider_health("voyageai")
+            .await
+            .expect("health"),
+        ProviderHealthStatus::Healthy
+    );
+}
+
+#[test]
+fn test_empty_chain_is_rejected() {
+    assert!(FailoverEmbeddingProvider::new(Vec::new(), router()).is_err());
+}
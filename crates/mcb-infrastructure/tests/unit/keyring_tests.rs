@@ -0,0 +1,292 @@
+//! Keyring and Re-encryption Tests
+//!
+//! Covers key rotation in the keyring, loading keys from files and
+//! environment variables, and re-encrypting a collection with the active key.
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::providers::{
+    CryptoProvider, EmbeddingProvider, EncryptedData, VectorStoreProvider,
+};
+use mcb_domain::value_objects::Embedding;
+use mcb_infrastructure::collections::{
+    CollectionGuard, CollectionRegistry, ReencryptionJobs, ReencryptionState,
+};
+use mcb_infrastructure::config::{AppConfig, EncryptionKeySource, VectorStoreEncryptionConfig};
+use mcb_infrastructure::crypto::Keyring;
+use mcb_infrastructure::di::{
+    EmbeddingProviderHandle, EmbeddingProviderResolver, VectorStoreProviderHandle,
+    VectorStoreProviderResolver,
+};
+use mcb_providers::vector_store::encrypted::decrypt_metadata;
+use mcb_providers::vector_store::{EncryptedVectorStoreProvider, InMemoryVectorStoreProvider};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn keyring(active: &str, ids: &[&str]) -> Keyring {
+    Keyring::new(
+        active,
+        ids.iter()
+            .map(|id| (id.to_string(), vec![id.as_bytes()[0]; 32])),
+    )
+    .unwrap()
+}
+
+fn key_source(id: &str) -> EncryptionKeySource {
+    EncryptionKeySource {
+        id: id.to_string(),
+        file: None,
+        env: None,
+    }
+}
+
+#[test]
+fn test_keyring_tags_with_active_key() {
+    let ring = keyring("a", &["a", "b"]);
+
+    let encrypted = ring.encrypt(b"secret").unwrap();
+
+    assert_eq!(encrypted.key_id.as_deref(), Some("a"));
+    assert_eq!(ring.active_key_id(), Some("a"));
+    assert_eq!(ring.decrypt(&encrypted).unwrap(), b"secret");
+}
+
+#[test]
+fn test_keyring_decrypts_data_from_retired_key() {
+    let old = keyring("a", &["a"]);
+    let rotated = keyring("b", &["a", "b"]);
+
+    let encrypted = old.encrypt(b"secret").unwrap();
+
+    assert_eq!(rotated.decrypt(&encrypted).unwrap(), b"secret");
+    assert_eq!(
+        rotated.encrypt(b"secret").unwrap().key_id.as_deref(),
+        Some("b")
+    );
+}
+
+#[test]
+fn test_keyring_rejects_unknown_key_id() {
+    let old = keyring("a", &["a"]);
+    let other = keyring("b", &["b"]);
+
+    let encrypted = old.encrypt(b"secret").unwrap();
+
+    assert!(other.decrypt(&encrypted).is_err());
+}
+
+#[test]
+fn test_keyring_decrypts_untagged_data() {
+    let ring = keyring("b", &["a", "b"]);
+    let tagged = keyring("a", &["a"]).encrypt(b"secret").unwrap();
+    let untagged = EncryptedData::new(tagged.ciphertext, tagged.nonce);
+
+    assert_eq!(ring.decrypt(&untagged).unwrap(), b"secret");
+}
+
+#[test]
+fn test_keyring_requires_active_key() {
+    assert!(Keyring::new("missing", [("a".to_string(), vec![0u8; 32])]).is_err());
+}
+
+#[test]
+fn test_keyring_loads_keys_from_file_and_env() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("old.key");
+    std::fs::write(&path, format!("{}\n", hex::encode([1u8; 32]))).unwrap();
+    // SAFETY: the variable name is unique to this test
+    unsafe { std::env::set_var("MCB_TEST_KEYRING_NEW_KEY", hex::encode([2u8; 32])) };
+
+    let ring = Keyring::from_config(&VectorStoreEncryptionConfig {
+        active_key: "new".to_string(),
+        keys: vec![
+            EncryptionKeySource {
+                file: Some(path),
+                ..key_source("old")
+            },
+            EncryptionKeySource {
+                env: Some("MCB_TEST_KEYRING_NEW_KEY".to_string()),
+                ..key_source("new")
+            },
+        ],
+    })
+    .unwrap();
+
+    assert_eq!(ring.key_ids(), vec!["new", "old"]);
+    assert_eq!(ring.active_key_id(), Some("new"));
+}
+
+#[test]
+fn test_keyring_rejects_invalid_key_sources() {
+    let config = |keys| VectorStoreEncryptionConfig {
+        active_key: "a".to_string(),
+        keys,
+    };
+
+    // Neither file nor env
+    assert!(Keyring::from_config(&config(vec![key_source("a")])).is_err());
+    // Unset environment variable
+    let unset = EncryptionKeySource {
+        env: Some("MCB_TEST_KEYRING_UNSET_KEY".to_string()),
+        ..key_source("a")
+    };
+    assert!(Keyring::from_config(&config(vec![unset])).is_err());
+    // Key of the wrong size
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("short.key");
+    std::fs::write(&path, hex::encode([1u8; 16])).unwrap();
+    let short = EncryptionKeySource {
+        file: Some(path.clone()),
+        ..key_source("a")
+    };
+    assert!(Keyring::from_config(&config(vec![short])).is_err());
+    // Repeated key id
+    std::fs::write(&path, hex::encode([1u8; 32])).unwrap();
+    let valid = EncryptionKeySource {
+        file: Some(path),
+        ..key_source("a")
+    };
+    assert!(Keyring::from_config(&config(vec![valid.clone(), valid])).is_err());
+}
+
+/// Embedding provider returning a constant vector
+struct StubEmbedding;
+
+#[async_trait]
+impl EmbeddingProvider for StubEmbedding {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        Ok(texts
+            .iter()
+            .map(|_| Embedding {
+                vector: vec![0.5; 4],
+                model: "stub-model".to_string(),
+                dimensions: 4,
+            })
+            .collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        4
+    }
+
+    fn provider_name(&self) -> &str {
+        "stub"
+    }
+}
+
+fn encrypted_guard(
+    store: &Arc<InMemoryVectorStoreProvider>,
+    registry: &Arc<CollectionRegistry>,
+    keyring: &Arc<Keyring>,
+) -> Arc<CollectionGuard> {
+    let config = Arc::new(AppConfig::default());
+    let encrypted = EncryptedVectorStoreProvider::from_shared(
+        Arc::clone(store),
+        Arc::clone(keyring) as Arc<dyn CryptoProvider>,
+    );
+    Arc::new(CollectionGuard::new(
+        Arc::new(EmbeddingProviderResolver::new(config.clone())),
+        Arc::new(EmbeddingProviderHandle::new(Arc::new(StubEmbedding))),
+        Arc::new(VectorStoreProviderResolver::new(config)),
+        Arc::new(VectorStoreProviderHandle::new(Arc::new(encrypted))),
+        Arc::clone(registry),
+    ))
+}
+
+fn chunk(i: usize) -> HashMap<String, Value> {
+    HashMap::from([
+        ("file_path".to_string(), json!("src/lib.rs")),
+        ("content".to_string(), json!(format!("fn f{i}() {{}}"))),
+        ("start_line".to_string(), json!(i)),
+        ("secret".to_string(), json!(format!("value-{i}"))),
+    ])
+}
+
+fn key_ids(stored: &[mcb_domain::value_objects::StoredVector]) -> Vec<Option<String>> {
+    stored
+        .iter()
+        .map(|v| {
+            serde_json::from_value::<EncryptedData>(v.metadata["encrypted_metadata"].clone())
+                .unwrap()
+                .key_id
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_reencryption_rewrites_collection_with_active_key() {
+    let store = Arc::new(InMemoryVectorStoreProvider::new());
+    let registry = Arc::new(CollectionRegistry::in_memory());
+
+    // Index with the original key
+    let old_guard = encrypted_guard(&store, &registry, &Arc::new(keyring("a", &["a"])));
+    let provider = old_guard.vector_store_provider();
+    provider.create_collection("code", 4).await.unwrap();
+    let embeddings = vec![
+        Embedding {
+            vector: vec![0.5; 4],
+            model: "stub-model".to_string(),
+            dimensions: 4,
+        };
+        5
+    ];
+    provider
+        .insert_vectors("code", &embeddings, (0..5).map(chunk).collect())
+        .await
+        .unwrap();
+
+    // Rotate to a new active key and re-encrypt
+    let rotated = Arc::new(keyring("b", &["a", "b"]));
+    let jobs = Arc::new(
+        ReencryptionJobs::new(encrypted_guard(&store, &registry, &rotated), rotated)
+            .with_batch_size(2),
+    );
+    let started = jobs.start("code").await.unwrap();
+    assert_eq!(started.state, ReencryptionState::Running);
+    assert_eq!(started.key_id, "b");
+
+    let mut status = jobs.status("code").unwrap();
+    for _ in 0..100 {
+        if status.state != ReencryptionState::Running {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        status = jobs.status("code").unwrap();
+    }
+    assert_eq!(status.state, ReencryptionState::Completed);
+    assert_eq!((status.total, status.reencrypted), (5, 5));
+    assert!(status.finished_at.is_some());
+
+    // Every record now decrypts with the new key alone
+    let stored = store.export_vectors("code").await.unwrap();
+    assert_eq!(stored.len(), 5);
+    assert!(key_ids(&stored).iter().all(|id| id.as_deref() == Some("b")));
+    let new_only = keyring("b", &["b"]);
+    let mut secrets: Vec<Value> = stored
+        .iter()
+        .map(|v| decrypt_metadata(&new_only, &v.metadata).unwrap()["secret"].clone())
+        .collect();
+    secrets.sort_by_key(|s| s.to_string());
+    assert_eq!(
+        secrets,
+        (0..5)
+            .map(|i| json!(format!("value-{i}")))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn test_reencryption_rejects_missing_collection() {
+    let store = Arc::new(InMemoryVectorStoreProvider::new());
+    let registry = Arc::new(CollectionRegistry::in_memory());
+    let ring = Arc::new(keyring("a", &["a"]));
+    let jobs = Arc::new(ReencryptionJobs::new(
+        encrypted_guard(&store, &registry, &ring),
+        ring,
+    ));
+
+    assert!(jobs.start("missing").await.is_err());
+    assert!(jobs.statuses().is_empty());
+}
@@ -0,0 +1,213 @@
+//! Rate Limiter Tests
+
+use mcb_infrastructure::config::{LimitsConfig, ResilienceConfig};
+use mcb_infrastructure::ratelimit::{
+    ConnectionLimited, ConnectionLimiter, RateLimitClass, RateLimiter, TokenBucket,
+};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn limiter(burst: u32) -> RateLimiter {
+    RateLimiter::new(1, burst, 1, 1, vec!["index_codebase".to_string()])
+}
+
+fn keys(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| (*n).to_string()).collect()
+}
+
+#[test]
+fn test_token_bucket_refills_up_to_capacity() {
+    let start = Instant::now();
+    let mut bucket = TokenBucket::new(10, 2);
+    bucket.refill(start);
+    assert!(bucket.take());
+    assert!(bucket.take());
+    assert!(!bucket.take());
+    assert!(bucket.wait_time() > Duration::ZERO);
+
+    bucket.refill(start + Duration::from_secs(10));
+    assert!(bucket.is_full());
+    assert!((bucket.available() - 2.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_check_rejects_after_burst() {
+    let limiter = limiter(2);
+    let caller = keys(&["principal:alice"]);
+
+    assert!(limiter.check(RateLimitClass::Standard, &caller).is_ok());
+    assert!(limiter.check(RateLimitClass::Standard, &caller).is_ok());
+
+    let limited = limiter
+        .check(RateLimitClass::Standard, &caller)
+        .expect_err("burst exhausted");
+    assert_eq!(limited.key, "principal:alice");
+    assert_eq!(limited.class, RateLimitClass::Standard);
+    assert!(limited.retry_after > Duration::ZERO);
+    assert_eq!(limited.retry_after_secs(), 1);
+}
+
+#[test]
+fn test_keys_are_limited_independently() {
+    let limiter = limiter(1);
+
+    assert!(
+        limiter
+            .check(RateLimitClass::Standard, &keys(&["principal:alice"]))
+            .is_ok()
+    );
+    assert!(
+        limiter
+            .check(RateLimitClass::Standard, &keys(&["principal:bob"]))
+            .is_ok()
+    );
+    assert!(
+        limiter
+            .check(RateLimitClass::Standard, &keys(&["principal:alice"]))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_rejection_does_not_consume_other_keys() {
+    let limiter = limiter(1);
+    limiter
+        .check(RateLimitClass::Standard, &keys(&["session:s1"]))
+        .unwrap();
+
+    // The session bucket is empty, so the principal bucket must stay full
+    let limited = limiter
+        .check(
+            RateLimitClass::Standard,
+            &keys(&["principal:alice", "session:s1"]),
+        )
+        .expect_err("session exhausted");
+    assert_eq!(limited.key, "session:s1");
+
+    assert!(
+        limiter
+            .check(RateLimitClass::Standard, &keys(&["principal:alice"]))
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_expensive_tools_use_separate_bucket() {
+    let limiter = limiter(10);
+    let caller = keys(&["principal:alice"]);
+
+    assert_eq!(
+        limiter.class_for_tool("index_codebase"),
+        RateLimitClass::Expensive
+    );
+    assert_eq!(
+        limiter.class_for_tool("search_code"),
+        RateLimitClass::Standard
+    );
+
+    assert!(limiter.check(RateLimitClass::Expensive, &caller).is_ok());
+    assert!(limiter.check(RateLimitClass::Expensive, &caller).is_err());
+    // Ordinary requests still have budget
+    assert!(limiter.check(RateLimitClass::Standard, &caller).is_ok());
+}
+
+#[test]
+fn test_caller_keys() {
+    assert_eq!(
+        RateLimiter::caller_keys(Some("alice"), Some("s1"), Some("10.0.0.1")),
+        keys(&["principal:alice", "session:s1"])
+    );
+    assert_eq!(
+        RateLimiter::caller_keys(None, None, Some("10.0.0.1")),
+        keys(&["client:10.0.0.1"])
+    );
+    // Anonymous callers cannot escape their address bucket with new sessions
+    assert_eq!(
+        RateLimiter::caller_keys(None, Some("s1"), Some("10.0.0.1")),
+        keys(&["client:10.0.0.1"])
+    );
+    assert_eq!(
+        RateLimiter::caller_keys(None, None, None),
+        keys(&["anonymous"])
+    );
+}
+
+#[test]
+fn test_stats_report_counters_and_buckets() {
+    let limiter = limiter(1);
+    let caller = keys(&["principal:alice"]);
+    limiter.check(RateLimitClass::Standard, &caller).unwrap();
+    let _ = limiter.check(RateLimitClass::Standard, &caller);
+
+    let stats = limiter.stats();
+    assert_eq!(stats.allowed_total, 1);
+    assert_eq!(stats.limited_total, 1);
+    assert_eq!(stats.tracked_buckets, 1);
+    assert_eq!(stats.buckets[0].key, "principal:alice");
+    assert_eq!(stats.buckets[0].limited, 1);
+}
+
+#[test]
+fn test_from_config() {
+    assert!(RateLimiter::from_config(&ResilienceConfig::default()).is_none());
+
+    let enabled = ResilienceConfig {
+        rate_limiter_enabled: true,
+        ..ResilienceConfig::default()
+    };
+    let limiter = RateLimiter::from_config(&enabled).expect("enabled");
+    assert_eq!(
+        limiter.class_for_tool("index_codebase"),
+        RateLimitClass::Expensive
+    );
+}
+
+#[test]
+fn test_connection_limiter_caps_requests_per_client() {
+    let limiter = Arc::new(ConnectionLimiter::new(10, 2));
+
+    let first = limiter.acquire("10.0.0.1").unwrap();
+    let _second = limiter.acquire("10.0.0.1").unwrap();
+    assert!(matches!(
+        limiter.acquire("10.0.0.1"),
+        Err(ConnectionLimited::TooManyRequests { limit: 2, .. })
+    ));
+
+    drop(first);
+    assert!(limiter.acquire("10.0.0.1").is_ok());
+}
+
+#[test]
+fn test_connection_limiter_caps_concurrent_clients() {
+    let config = LimitsConfig {
+        max_connections: 1,
+        ..LimitsConfig::default()
+    };
+    let limiter = Arc::new(ConnectionLimiter::from_config(&config));
+
+    let permit = limiter.acquire("10.0.0.1").unwrap();
+    assert_eq!(
+        limiter.acquire("10.0.0.2").err(),
+        Some(ConnectionLimited::TooManyConnections { limit: 1 })
+    );
+    // The active client may still open more requests
+    assert!(limiter.acquire("10.0.0.1").is_ok());
+
+    drop(permit);
+    assert_eq!(limiter.active_connections(), 0);
+    assert!(limiter.acquire("10.0.0.2").is_ok());
+}
+
+#[test]
+fn test_limits_accept_former_per_connection_name() {
+    let config: LimitsConfig = serde_json::from_value(serde_json::json!({
+        "memory_limit": 0,
+        "cpu_limit": 0,
+        "disk_io_limit": 0,
+        "max_connections": 10,
+        "max_requests_per_connection": 2,
+    }))
+    .unwrap();
+
+    assert_eq!(config.max_requests_per_client, 2);
+}
@@ -0,0 +1,628 @@
+//! Provider Resilience Tests
+//!
+//! Covers error classification, backoff, circuit breaker transitions and
+//! the embedding/vector store decorators.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::routing::{ProviderHealthStatus, ProviderRouter};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{EmbeddingProvider, VectorStoreAdmin, VectorStoreProvider};
+use mcb_domain::value_objects::{Embedding, MetadataFilter, SearchResult};
+use mcb_infrastructure::config::ResilienceConfig;
+use mcb_infrastructure::resilience::{
+    CircuitBreaker, CircuitState, FailureKind, ProviderResilience, RetryPolicy,
+};
+use mcb_infrastructure::routing::{DefaultProviderRouter, InMemoryHealthMonitor};
+use mcb_providers::vector_store::{InMemoryVectorStoreProvider, MilvusVectorStoreProvider};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+/// Embedding provider that fails with queued errors before succeeding
+struct FlakyEmbedding {
+    errors: Mutex<Vec<Error>>,
+    calls: AtomicU32,
+}
+
+impl FlakyEmbedding {
+    fn new(errors: Vec<Error>) -> Self {
+        Self {
+            errors: Mutex::new(errors),
+            calls: AtomicU32::new(0),
+        }
+    }
+
+    fn calls(&self) -> u32 {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for FlakyEmbedding {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let next = {
+            let mut errors = self.errors.lock().expect("lock");
+            (!errors.is_empty()).then(|| errors.remove(0))
+        };
+        match next {
+            Some(error) => Err(error),
+            None => Ok(texts
+                .iter()
+                .map(|_| Embedding {
+                    vector: vec![0.0; 4],
+                    model: "flaky".to_string(),
+                    dimensions: 4,
+                })
+                .collect()),
+        }
+    }
+
+    fn dimensions(&self) -> usize {
+        4
+    }
+
+    fn provider_name(&self) -> &str {
+        "flaky"
+    }
+}
+
+/// Embedding provider that never answers
+struct HungEmbedding;
+
+#[async_trait]
+impl EmbeddingProvider for HungEmbedding {
+    async fn embed_batch(&self, _texts: &[String]) -> Result<Vec<Embedding>> {
+        std::future::pending().await
+    }
+
+    fn dimensions(&self) -> usize {
+        4
+    }
+
+    fn provider_name(&self) -> &str {
+        "hung"
+    }
+}
+
+/// Vector store whose writes fail a number of times before reaching
+/// an in-memory store
+struct FlakyVectorStore {
+    inner: InMemoryVectorStoreProvider,
+    failures: AtomicU32,
+    writes: AtomicU32,
+}
+
+impl FlakyVectorStore {
+    fn new(failures: u32) -> Self {
+        Self {
+            inner: InMemoryVectorStoreProvider::new(),
+            failures: AtomicU32::new(failures),
+            writes: AtomicU32::new(0),
+        }
+    }
+
+    fn writes(&self) -> u32 {
+        self.writes.load(Ordering::SeqCst)
+    }
+
+    fn fail_write(&self) -> Result<()> {
+        self.writes.fetch_add(1, Ordering::SeqCst);
+        let failing = self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        if failing { Err(unavailable()) } else { Ok(()) }
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for FlakyVectorStore {
+    async fn collection_exists(&self, name: &str) -> Result<bool> {
+        self.inner.collection_exists(name).await
+    }
+
+    async fn get_stats(&self, collection: &str) -> Result<HashMap<String, Value>> {
+        self.inner.get_stats(collection).await
+    }
+
+    async fn flush(&self, collection: &str) -> Result<()> {
+        self.inner.flush(collection).await
+    }
+
+    fn provider_name(&self) -> &str {
+        "flaky_store"
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for FlakyVectorStore {
+    async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
+        self.inner.create_collection(name, dimensions).await
+    }
+
+    async fn delete_collection(&self, name: &str) -> Result<()> {
+        self.inner.delete_collection(name).await
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &str,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.fail_write()?;
+        self.inner
+            .insert_vectors(collection, vectors, metadata)
+            .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        self.fail_write()?;
+        self.inner
+            .upsert_vectors(collection, ids, vectors, metadata)
+            .await
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+    ) -> Result<Vec<SearchResult>> {
+        self.inner
+            .search_similar(collection, query_vector, limit, filter)
+            .await
+    }
+
+    async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
+        self.inner.delete_vectors(collection, ids).await
+    }
+
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        self.inner.delete_by_filter(collection, filter).await
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &str,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        self.inner.get_vectors_by_ids(collection, ids).await
+    }
+
+    async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        self.inner.list_vectors(collection, limit).await
+    }
+}
+
+/// gRPC server answering every Milvus call with `UNAVAILABLE`
+#[derive(Clone, Default)]
+struct UnavailableMilvus {
+    calls: Arc<AtomicU32>,
+}
+
+impl UnavailableMilvus {
+    /// Serve on a local port and return its address
+    async fn serve(&self) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let address = format!("http://{}", listener.local_addr().expect("address"));
+        let incoming = futures::stream::unfold(listener, |listener| async move {
+            let accepted = listener.accept().await.map(|(stream, _)| stream);
+            Some((accepted, listener))
+        });
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(self.clone())
+                .serve_with_incoming(incoming),
+        );
+        address
+    }
+
+    fn calls(&self) -> u32 {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+impl tonic::server::NamedService for UnavailableMilvus {
+    const NAME: &'static str = "milvus.proto.milvus.MilvusService";
+}
+
+impl<B: Send + 'static> tonic::codegen::Service<tonic::codegen::http::Request<B>>
+    for UnavailableMilvus
+{
+    type Response = tonic::codegen::http::Response<tonic::body::BoxBody>;
+    type Error = std::convert::Infallible;
+    type Future = tonic::codegen::BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::result::Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _request: tonic::codegen::http::Request<B>) -> Self::Future {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async {
+            Ok(tonic::codegen::http::Response::builder()
+                .status(200)
+                .header("content-type", "application/grpc")
+                .header("grpc-status", "14")
+                .header("grpc-message", "query nodes down")
+                .body(tonic::codegen::empty_body())
+                .expect("response"))
+        })
+    }
+}
+
+fn unavailable() -> Error {
+    Error::unavailable("Flaky server error (503): upstream down", None)
+}
+
+fn embedding() -> Embedding {
+    Embedding {
+        vector: vec![0.1; 4],
+        model: "flaky".to_string(),
+        dimensions: 4,
+    }
+}
+
+fn fast_resilience(retries: u32, failure_threshold: u32) -> Arc<ProviderResilience> {
+    Arc::new(ProviderResilience::new(
+        RetryPolicy::new(retries, Duration::from_millis(1), Duration::from_millis(5)),
+        failure_threshold,
+        1,
+        Duration::from_secs(60),
+    ))
+}
+
+fn texts() -> Vec<String> {
+    vec!["fn main() {}".to_string()]
+}
+
+// =============================================================================
+// Classification and backoff
+// =============================================================================
+
+#[test]
+fn test_failure_kind_classifies_provider_errors() {
+    assert!(FailureKind::of(&unavailable()).is_transient());
+    assert!(FailureKind::of(&Error::network("connection reset")).is_transient());
+
+    // Only the variant decides; wording that sounds transient does not
+    assert_eq!(
+        FailureKind::of(&Error::vector_db("Failed to search: deadline exceeded")),
+        FailureKind::Permanent
+    );
+    assert_eq!(
+        FailureKind::of(&Error::embedding("OpenAI authentication failed: bad key")),
+        FailureKind::Permanent
+    );
+    assert_eq!(
+        FailureKind::of(&Error::invalid_argument("empty query")),
+        FailureKind::Permanent
+    );
+}
+
+#[test]
+fn test_failure_kind_carries_retry_after() {
+    let error = Error::unavailable(
+        "VoyageAI rate limit exceeded: too many requests",
+        Some(Duration::from_secs(7)),
+    );
+    assert_eq!(
+        FailureKind::of(&error),
+        FailureKind::Transient {
+            retry_after: Some(Duration::from_secs(7))
+        }
+    );
+}
+
+#[test]
+fn test_retry_policy_backoff_is_jittered_and_capped() {
+    let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_millis(400));
+
+    let first = policy.delay(0, None).expect("retry allowed");
+    assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+
+    let fourth = policy.delay(3, None).expect("retry allowed");
+    assert!(fourth >= Duration::from_millis(200) && fourth <= Duration::from_millis(400));
+
+    assert!(policy.delay(5, None).is_none(), "retries exhausted");
+}
+
+#[test]
+fn test_retry_policy_honours_retry_after() {
+    let policy = RetryPolicy::new(3, Duration::from_millis(10), Duration::from_secs(5));
+
+    let delay = policy
+        .delay(0, Some(Duration::from_secs(2)))
+        .expect("retry allowed");
+    assert!(delay >= Duration::from_secs(2));
+
+    assert!(
+        policy.delay(0, Some(Duration::from_secs(60))).is_none(),
+        "waits longer than max_delay are not retried"
+    );
+}
+
+// =============================================================================
+// Circuit breaker
+// =============================================================================
+
+#[test]
+fn test_breaker_opens_after_threshold_and_fails_fast() {
+    let breaker = CircuitBreaker::new(2, 1, Duration::from_secs(60));
+
+    breaker.try_acquire().expect("admitted").record_failure();
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    breaker.try_acquire().expect("admitted").record_failure();
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    let wait = breaker.try_acquire().expect_err("open breaker rejects");
+    assert!(wait > Duration::ZERO);
+
+    let snapshot = breaker.snapshot("embedding", "flaky");
+    assert_eq!(snapshot.trips, 1);
+    assert_eq!(snapshot.rejected, 1);
+    assert!(snapshot.retry_after_secs.is_some());
+}
+
+#[test]
+fn test_breaker_half_open_admits_one_probe_then_closes() {
+    let breaker = CircuitBreaker::new(1, 1, Duration::ZERO);
+
+    breaker.try_acquire().expect("admitted").record_failure();
+    assert_eq!(breaker.state(), CircuitState::HalfOpen, "timeout elapsed");
+
+    let probe = breaker.try_acquire().expect("probe admitted");
+    assert!(breaker.try_acquire().is_err(), "second probe rejected");
+
+    probe.record_success();
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+#[test]
+fn test_breaker_dropped_probe_frees_the_slot() {
+    let breaker = CircuitBreaker::new(1, 1, Duration::ZERO);
+    breaker.try_acquire().expect("admitted").record_failure();
+
+    let probe = breaker.try_acquire().expect("probe admitted");
+    assert!(breaker.try_acquire().is_err(), "second probe rejected");
+
+    // A cancelled call never reports an outcome
+    drop(probe);
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+    breaker
+        .try_acquire()
+        .expect("next probe admitted")
+        .record_success();
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+#[test]
+fn test_breaker_half_open_failure_reopens() {
+    let breaker = CircuitBreaker::new(1, 2, Duration::ZERO);
+
+    breaker.try_acquire().expect("admitted").record_failure();
+    breaker.try_acquire().expect("admitted").record_failure();
+
+    assert_eq!(breaker.snapshot("embedding", "flaky").trips, 2);
+}
+
+// =============================================================================
+// Decorators
+// =============================================================================
+
+#[tokio::test]
+async fn test_embedding_decorator_retries_transient_errors() {
+    let inner = Arc::new(FlakyEmbedding::new(vec![unavailable(), unavailable()]));
+    let provider = fast_resilience(3, 10).wrap_embedding(inner.clone());
+
+    let embeddings = provider
+        .embed_batch(&texts())
+        .await
+        .expect("succeeds on retry");
+
+    assert_eq!(embeddings.len(), 1);
+    assert_eq!(inner.calls(), 3);
+    assert_eq!(provider.provider_name(), "flaky");
+    assert_eq!(provider.dimensions(), 4);
+}
+
+#[tokio::test]
+async fn test_embedding_decorator_does_not_retry_permanent_errors() {
+    let inner = Arc::new(FlakyEmbedding::new(vec![Error::embedding(
+        "Flaky authentication failed: bad key",
+    )]));
+    let resilience = fast_resilience(3, 1);
+    let provider = resilience.wrap_embedding(inner.clone());
+
+    assert!(provider.embed_batch(&texts()).await.is_err());
+    assert_eq!(inner.calls(), 1);
+
+    let snapshot = &resilience.snapshot()[0];
+    assert_eq!(
+        snapshot.state,
+        CircuitState::Closed,
+        "permanent errors do not trip"
+    );
+}
+
+#[tokio::test]
+async fn test_open_breaker_fails_fast_without_calling_provider() {
+    let inner = Arc::new(FlakyEmbedding::new(
+        (0..10).map(|_| unavailable()).collect(),
+    ));
+    let resilience = fast_resilience(5, 2);
+    let provider = resilience.wrap_embedding(inner.clone());
+
+    let first = provider.embed_batch(&texts()).await.expect_err("fails");
+    assert!(
+        first.to_string().contains("server error"),
+        "last provider error returned"
+    );
+    assert_eq!(inner.calls(), 2, "retries stop once the breaker opens");
+
+    let second = provider
+        .embed_batch(&texts())
+        .await
+        .expect_err("fails fast");
+    assert!(second.to_string().contains("circuit open"));
+    assert_eq!(inner.calls(), 2);
+
+    let snapshot = &resilience.snapshot()[0];
+    assert_eq!(snapshot.kind, "embedding");
+    assert_eq!(snapshot.provider, "flaky");
+    assert_eq!(snapshot.state, CircuitState::Open);
+}
+
+#[tokio::test]
+async fn test_unavailable_milvus_is_retried_and_trips_breaker() {
+    let server = UnavailableMilvus::default();
+    let address = server.serve().await;
+    let store = MilvusVectorStoreProvider::new(address, None, Some(5))
+        .await
+        .expect("connect");
+    let resilience = fast_resilience(5, 2);
+    let store = resilience.wrap_vector_store(Arc::new(store));
+
+    let error = store
+        .collection_exists("chunks")
+        .await
+        .expect_err("server unavailable");
+    assert!(
+        FailureKind::of(&error).is_transient(),
+        "gRPC UNAVAILABLE is transient: {error}"
+    );
+    let calls = server.calls();
+    assert!(calls >= 2, "the failed call was retried");
+
+    let error = store
+        .collection_exists("chunks")
+        .await
+        .expect_err("fails fast");
+    assert!(error.to_string().contains("circuit open"));
+    assert_eq!(server.calls(), calls, "open breaker does not reach Milvus");
+}
+
+#[tokio::test]
+async fn test_hung_call_times_out_and_trips_breaker() {
+    let resilience = Arc::new(
+        ProviderResilience::new(RetryPolicy::none(), 1, 1, Duration::from_secs(60))
+            .with_call_timeout(Duration::from_millis(20)),
+    );
+    let provider = resilience.wrap_embedding(Arc::new(HungEmbedding));
+
+    let error = provider.embed_batch(&texts()).await.expect_err("times out");
+    assert!(matches!(error, Error::Network { .. }));
+    assert!(error.to_string().contains("timed out"));
+
+    let snapshot = &resilience.snapshot()[0];
+    assert_eq!(snapshot.state, CircuitState::Open);
+}
+
+#[tokio::test]
+async fn test_outcomes_are_reported_to_router() {
+    let router: Arc<dyn ProviderRouter> = Arc::new(DefaultProviderRouter::new(
+        Arc::new(InMemoryHealthMonitor::with_thresholds(1, 2)),
+        vec!["flaky".to_string()],
+        Vec::new(),
+    ));
+    let resilience = Arc::new(
+        ProviderResilience::new(RetryPolicy::none(), 10, 1, Duration::from_secs(60))
+            .with_router(Arc::clone(&router)),
+    );
+    let inner = Arc::new(FlakyEmbedding::new(vec![unavailable(), unavailable()]));
+    let provider = resilience.wrap_embedding(inner);
+
+    assert!(provider.embed_batch(&texts()).await.is_err());
+    assert!(provider.embed_batch(&texts()).await.is_err());
+    assert_eq!(
+        router.get_provider_health("flaky").await.expect("health"),
+        ProviderHealthStatus::Unhealthy
+    );
+
+    assert!(provider.embed_batch(&texts()).await.is_ok());
+    assert_eq!(
+        router.get_provider_health("flaky").await.expect("health"),
+        ProviderHealthStatus::Healthy
+    );
+}
+
+#[tokio::test]
+async fn test_vector_store_decorator_passes_through() {
+    let inner = Arc::new(InMemoryVectorStoreProvider::new());
+    let provider = fast_resilience(2, 5).wrap_vector_store(inner);
+
+    provider
+        .create_collection("resilient", 4)
+        .await
+        .expect("create");
+    assert!(
+        provider
+            .collection_exists("resilient")
+            .await
+            .expect("exists")
+    );
+    assert_eq!(provider.provider_name(), "in_memory");
+}
+
+#[tokio::test]
+async fn test_vector_store_decorator_does_not_retry_inserts() {
+    let inner = Arc::new(FlakyVectorStore::new(1));
+    let provider = fast_resilience(3, 10).wrap_vector_store(inner.clone());
+    provider
+        .create_collection("writes", 4)
+        .await
+        .expect("create");
+
+    let inserted = provider
+        .insert_vectors("writes", &[embedding()], vec![HashMap::new()])
+        .await;
+    assert!(inserted.is_err(), "a lost insert may have been stored");
+    assert_eq!(inner.writes(), 1);
+
+    let inner = Arc::new(FlakyVectorStore::new(1));
+    let provider = fast_resilience(3, 10).wrap_vector_store(inner.clone());
+    provider
+        .create_collection("writes", 4)
+        .await
+        .expect("create");
+
+    provider
+        .upsert_vectors(
+            "writes",
+            &["chunk-1".to_string()],
+            &[embedding()],
+            vec![HashMap::new()],
+        )
+        .await
+        .expect("upserts are retried");
+    assert_eq!(inner.writes(), 2);
+}
+
+#[test]
+fn test_from_config_respects_enable_flag() {
+    assert!(ProviderResilience::from_config(&ResilienceConfig::default()).is_some());
+
+    let disabled = ResilienceConfig {
+        provider_resilience_enabled: false,
+        ..ResilienceConfig::default()
+    };
+    assert!(ProviderResilience::from_config(&disabled).is_none());
+}
@@ -0,0 +1,125 @@
+//! Collection Scope Tests
+
+use mcb_infrastructure::auth::{
+    AccessLevel, CollectionScope, Principal, UserAuthService, UserRole, UserUpdate,
+    default_scopes_for_role, parse_scopes,
+};
+use mcb_infrastructure::config::AuthConfig;
+use tempfile::TempDir;
+
+#[test]
+fn test_scope_parses_and_round_trips() {
+    let scope: CollectionScope = "collection:team-a-*:write".parse().unwrap();
+    assert_eq!(scope.pattern(), "team-a-*");
+    assert_eq!(scope.level(), AccessLevel::Write);
+    assert_eq!(scope.to_string(), "collection:team-a-*:write");
+}
+
+#[test]
+fn test_invalid_scopes_are_rejected() {
+    for invalid in [
+        "team-a:read",
+        "collection:team-a",
+        "collection::read",
+        "collection:team-a:owner",
+        "collection:[:read",
+    ] {
+        assert!(
+            invalid.parse::<CollectionScope>().is_err(),
+            "{} should be rejected",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_higher_levels_imply_lower_ones() {
+    let scope: CollectionScope = "collection:docs:write".parse().unwrap();
+    assert!(scope.allows("docs", AccessLevel::Read));
+    assert!(scope.allows("docs", AccessLevel::Write));
+    assert!(!scope.allows("docs", AccessLevel::Admin));
+    assert!(!scope.allows("docs-v2", AccessLevel::Read));
+}
+
+#[test]
+fn test_principal_checks_and_filters() {
+    let principal = Principal::new(
+        "ci",
+        parse_scopes(&["collection:team-a-*:read", "collection:shared:admin"]).unwrap(),
+    );
+
+    assert!(principal.can("team-a-api", AccessLevel::Read));
+    assert!(!principal.can("team-a-api", AccessLevel::Write));
+    assert!(principal.can("shared", AccessLevel::Admin));
+    assert!(!principal.can("team-b-api", AccessLevel::Read));
+    assert!(!principal.is_admin());
+
+    let visible =
+        principal.filter_readable(vec!["team-a-api", "team-b-api", "shared"], |name| *name);
+    assert_eq!(visible, vec!["team-a-api", "shared"]);
+}
+
+#[test]
+fn test_unrestricted_and_global_admin_principals() {
+    assert!(Principal::unrestricted("anonymous").can("anything", AccessLevel::Admin));
+    assert!(Principal::unrestricted("anonymous").is_admin());
+
+    let admin = Principal::new("root", parse_scopes(&["collection:*:admin"]).unwrap());
+    assert!(admin.is_admin());
+}
+
+#[test]
+fn test_role_default_scopes() {
+    assert_eq!(
+        default_scopes_for_role(UserRole::Viewer),
+        vec!["collection:*:read"]
+    );
+    assert_eq!(
+        default_scopes_for_role(UserRole::Admin),
+        vec!["collection:*:admin"]
+    );
+}
+
+#[tokio::test]
+async fn test_user_tokens_carry_explicit_scopes() {
+    let dir = TempDir::new().unwrap();
+    let mut config = AuthConfig::default();
+    config.jwt.secret = "0123456789abcdef0123456789abcdef".to_string();
+    config.user_db_path = Some(dir.path().join("users.json"));
+    let service = UserAuthService::from_config(&config)
+        .await
+        .unwrap()
+        .unwrap();
+
+    service
+        .create_user_with_scopes(
+            "bob",
+            "password-123",
+            UserRole::Editor,
+            vec!["collection:team-b-*:write".to_string()],
+        )
+        .await
+        .unwrap();
+    let tokens = service.login("bob", "password-123").await.unwrap();
+    let principal = service
+        .principal_for_token(&tokens.access_token)
+        .await
+        .unwrap();
+    assert_eq!(principal.subject(), "bob");
+    assert!(principal.can("team-b-web", AccessLevel::Write));
+    assert!(!principal.can("team-a-web", AccessLevel::Read));
+
+    // Clearing explicit scopes falls back to the role defaults
+    let update = UserUpdate {
+        scopes: Some(Vec::new()),
+        ..Default::default()
+    };
+    let info = service.update_user("bob", update).await.unwrap();
+    assert_eq!(info.scopes, vec!["collection:*:write"]);
+
+    let bad = UserUpdate {
+        scopes: Some(vec!["collection:*:root".to_string()]),
+        ..Default::default()
+    };
+    assert!(service.update_user("bob", bad).await.is_err());
+}
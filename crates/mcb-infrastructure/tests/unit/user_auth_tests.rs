@@ -0,0 +1,245 @@
+//! User Database and Login Tests
+
+use mcb_infrastructure::auth::{UserAuthService, UserRole, UserStore, UserUpdate};
+use mcb_infrastructure::config::{AuthConfig, PasswordAlgorithm};
+use tempfile::TempDir;
+
+const TEST_SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+async fn create_service(dir: &TempDir) -> UserAuthService {
+    let mut config = AuthConfig::default();
+    config.jwt.secret = TEST_SECRET.to_string();
+    config.user_db_path = Some(dir.path().join("users.json"));
+    config.password_algorithm = PasswordAlgorithm::Pbkdf2;
+
+    UserAuthService::from_config(&config)
+        .await
+        .expect("valid config")
+        .expect("user database configured")
+}
+
+#[tokio::test]
+async fn test_from_config_without_user_db_path() {
+    let config = AuthConfig::default();
+    assert!(
+        UserAuthService::from_config(&config)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_login_issues_tokens_for_valid_credentials() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+    service
+        .create_user("alice", "password-123", UserRole::Editor)
+        .await
+        .unwrap();
+
+    let tokens = service.login("alice", "password-123").await.unwrap();
+    assert_eq!(tokens.token_type, "Bearer");
+
+    let claims = service.authenticate(&tokens.access_token).await.unwrap();
+    assert_eq!(claims.sub, "alice");
+    assert_eq!(claims.role, "editor");
+
+    // Refresh tokens are not accepted as access tokens
+    assert!(service.authenticate(&tokens.refresh_token).await.is_err());
+    assert!(service.refresh(&tokens.refresh_token).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_login_rejects_bad_credentials_uniformly() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+    service
+        .create_user("alice", "password-123", UserRole::Viewer)
+        .await
+        .unwrap();
+
+    let wrong_password = service.login("alice", "nope-nope").await.unwrap_err();
+    let unknown_user = service.login("bob", "password-123").await.unwrap_err();
+    assert_eq!(wrong_password.to_string(), unknown_user.to_string());
+}
+
+#[tokio::test]
+async fn test_disabled_user_tokens_are_rejected() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+    service
+        .bootstrap_admin("root", "password-123")
+        .await
+        .unwrap();
+    service
+        .create_user("alice", "password-123", UserRole::Viewer)
+        .await
+        .unwrap();
+    let tokens = service.login("alice", "password-123").await.unwrap();
+
+    service
+        .update_user(
+            "alice",
+            UserUpdate {
+                disabled: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert!(service.authenticate(&tokens.access_token).await.is_err());
+    assert!(service.login("alice", "password-123").await.is_err());
+}
+
+#[tokio::test]
+async fn test_bootstrap_admin_only_once() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+
+    let admin = service
+        .bootstrap_admin("root", "password-123")
+        .await
+        .unwrap();
+    assert_eq!(admin.role, UserRole::Admin);
+    assert!(
+        service
+            .bootstrap_admin("root2", "password-123")
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_last_admin_is_protected() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+    service
+        .bootstrap_admin("root", "password-123")
+        .await
+        .unwrap();
+
+    assert!(service.delete_user("root").await.is_err());
+    let demote = UserUpdate {
+        role: Some(UserRole::Viewer),
+        ..Default::default()
+    };
+    assert!(service.update_user("root", demote).await.is_err());
+}
+
+#[tokio::test]
+async fn test_concurrent_admin_deletes_keep_one_admin() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+    service
+        .bootstrap_admin("root", "password-123")
+        .await
+        .unwrap();
+    service
+        .create_user("second", "password-123", UserRole::Admin)
+        .await
+        .unwrap();
+
+    let (first, second) = tokio::join!(service.delete_user("root"), service.delete_user("second"));
+
+    assert!(first.is_ok() != second.is_ok());
+    assert_eq!(service.store().active_admin_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_create_user_validation() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+
+    assert!(
+        service
+            .create_user("", "password-123", UserRole::Viewer)
+            .await
+            .is_err()
+    );
+    assert!(
+        service
+            .create_user("a b", "password-123", UserRole::Viewer)
+            .await
+            .is_err()
+    );
+    assert!(
+        service
+            .create_user("alice", "short", UserRole::Viewer)
+            .await
+            .is_err()
+    );
+
+    service
+        .create_user("alice", "password-123", UserRole::Viewer)
+        .await
+        .unwrap();
+    assert!(
+        service
+            .create_user("alice", "password-123", UserRole::Viewer)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_user_store_persists_across_reopen() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.json");
+    {
+        let service = create_service(&dir).await;
+        service
+            .create_user("alice", "password-123", UserRole::Editor)
+            .await
+            .unwrap();
+    }
+
+    let store = UserStore::open(&path).await.unwrap();
+    let user = store.get("alice").await.expect("user persisted");
+    assert_eq!(user.role, UserRole::Editor);
+    assert!(user.password_hash.starts_with("$pbkdf2-sha256$"));
+}
+
+#[tokio::test]
+async fn test_concurrent_bootstraps_create_one_admin() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+
+    let (first, second) = tokio::join!(
+        service.bootstrap_admin("root", "password-123"),
+        service.bootstrap_admin("root2", "password-123")
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+    assert_eq!(service.store().active_admin_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_failed_write_leaves_users_unchanged() {
+    let dir = TempDir::new().unwrap();
+    let service = create_service(&dir).await;
+    service
+        .create_user("alice", "password-123", UserRole::Editor)
+        .await
+        .unwrap();
+
+    // A directory where the temporary file goes makes every write fail
+    let blocker = dir.path().join("users.tmp");
+    std::fs::create_dir(&blocker).unwrap();
+    assert!(
+        service
+            .create_user("bob", "password-123", UserRole::Viewer)
+            .await
+            .is_err()
+    );
+    assert!(service.delete_user("alice").await.is_err());
+    assert!(service.store().get("bob").await.is_none());
+    assert!(service.store().get("alice").await.is_some());
+
+    std::fs::remove_dir(&blocker).unwrap();
+    let store = UserStore::open(dir.path().join("users.json"))
+        .await
+        .unwrap();
+    assert_eq!(store.list().await.len(), 1);
+}
@@ -0,0 +1,121 @@
+//! Benchmarks for EdgeVec search latency by collection size
+//!
+//! Run with: cargo bench -p mcb-providers --features vectorstore-edgevec --bench edgevec_benchmark
+//!
+//! Sizes run up to `MCB_BENCH_EDGEVEC_MAX` vectors (default 100000); set it
+//! to 1000000 for the full range. Each size is measured with every vector
+//! live and again with 10% of them deleted.
+
+use std::collections::HashMap;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use mcb_domain::ports::providers::VectorStoreProvider;
+use mcb_domain::value_objects::Embedding;
+use mcb_providers::vector_store::{EdgeVecConfig, EdgeVecVectorStoreProvider};
+use tokio::runtime::Runtime;
+
+const DIMENSIONS: usize = 128;
+const SIZES: [usize; 3] = [10_000, 100_000, 1_000_000];
+const INSERT_BATCH: usize = 10_000;
+const QUERIES: usize = 64;
+
+/// Deterministic pseudo-random unit vectors (xorshift64)
+struct Vectors(u64);
+
+impl Vectors {
+    fn next(&mut self) -> Vec<f32> {
+        let mut vector: Vec<f32> = (0..DIMENSIONS)
+            .map(|_| {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                (self.0 >> 40) as f32 / (1u64 << 24) as f32 - 0.5
+            })
+            .collect();
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        vector.iter_mut().for_each(|v| *v /= norm);
+        vector
+    }
+}
+
+fn max_size() -> usize {
+    std::env::var("MCB_BENCH_EDGEVEC_MAX")
+        .ok()
+        .and_then(|max| max.parse().ok())
+        .unwrap_or(100_000)
+}
+
+async fn populate(store: &EdgeVecVectorStoreProvider, size: usize) -> Vec<String> {
+    let mut vectors = Vectors(0x9e37_79b9_7f4a_7c15);
+    let mut ids = Vec::with_capacity(size);
+    for start in (0..size).step_by(INSERT_BATCH) {
+        let count = INSERT_BATCH.min(size - start);
+        let batch: Vec<Embedding> = (0..count)
+            .map(|_| Embedding {
+                vector: vectors.next(),
+                model: "bench".to_string(),
+                dimensions: DIMENSIONS,
+            })
+            .collect();
+        let metadata = (start..start + count)
+            .map(|i| {
+                HashMap::from([(
+                    "file_path".to_string(),
+                    serde_json::json!(format!("src/file_{}.rs", i / 16)),
+                )])
+            })
+            .collect();
+        ids.extend(
+            store
+                .insert_vectors("bench", &batch, metadata)
+                .await
+                .expect("insert"),
+        );
+    }
+    ids
+}
+
+fn bench_search(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("runtime");
+    let mut group = c.benchmark_group("edgevec_search");
+    group.sample_size(50);
+
+    let mut queries = Vectors(0x2545_f491_4f6c_dd1d);
+    let queries: Vec<Vec<f32>> = (0..QUERIES).map(|_| queries.next()).collect();
+
+    for size in SIZES.into_iter().filter(|&size| size <= max_size()) {
+        let store = runtime.block_on(async {
+            EdgeVecVectorStoreProvider::new(EdgeVecConfig {
+                dimensions: DIMENSIONS,
+                // Keep the tombstones for the second measurement
+                compaction_threshold: 0.0,
+                ..Default::default()
+            })
+            .expect("provider")
+        });
+        let ids = runtime.block_on(populate(&store, size));
+
+        let mut run = |label: &str| {
+            let mut next = 0;
+            group.bench_with_input(BenchmarkId::new(label, size), &size, |b, _| {
+                b.iter(|| {
+                    next = (next + 1) % QUERIES;
+                    runtime
+                        .block_on(store.search_similar("bench", &queries[next], 10, None))
+                        .expect("search")
+                });
+            });
+        };
+        run("live");
+
+        let deleted: Vec<String> = ids.iter().step_by(10).cloned().collect();
+        runtime
+            .block_on(store.delete_vectors("bench", &deleted))
+            .expect("delete");
+        run("10pct_deleted");
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_search);
+criterion_main!(benches);
@@ -9,18 +9,22 @@
 //! | NullCacheProvider | Testing | No-op stub for testing |
 //! | MokaCacheProvider | Local | In-memory cache (high performance) |
 //! | RedisCacheProvider | Distributed | Redis-backed for multi-instance |
+//! | TieredCacheProvider | Tiered | Moka L1 over a shared L2, invalidated over the event bus |
 //!
 //! ## Provider Selection Guide
 //!
 //! - **Development/Testing**: Use `NullCacheProvider` for unit tests
 //! - **Single Instance**: Use `MokaCacheProvider` for high performance
 //! - **Multi Instance**: Use `RedisCacheProvider` for distributed caching
+//! - **Multi Instance, read-heavy**: Use `TieredCacheProvider` over Redis
 
 #[cfg(feature = "cache-moka")]
 pub mod moka;
 pub mod null;
 #[cfg(feature = "cache-redis")]
 pub mod redis;
+#[cfg(feature = "cache-moka")]
+pub mod tiered;
 
 // Re-export for convenience
 #[cfg(feature = "cache-moka")]
@@ -28,6 +32,10 @@ pub use moka::MokaCacheProvider;
 pub use null::NullCacheProvider;
 #[cfg(feature = "cache-redis")]
 pub use redis::RedisCacheProvider;
+#[cfg(feature = "cache-moka")]
+pub use tiered::TieredCacheProvider;
 
 // Re-export domain types used by cache providers
-pub use mcb_domain::ports::providers::cache::{CacheEntryConfig, CacheStats};
+pub use mcb_domain::ports::providers::cache::{
+    CacheEntryConfig, CacheStats, CacheTierStats, NamespaceStats,
+};
@@ -6,7 +6,9 @@
 //!
 //! - High-performance concurrent cache
 //! - Configurable capacity and TTL
+//! - Per-entry TTL from `CacheEntryConfig`, capped by the cache-wide TTL
 //! - Automatic eviction of expired entries
+//! - Namespace listing and clearing
 //!
 //! ## Example
 //!
@@ -20,20 +22,68 @@
 use crate::constants::CACHE_DEFAULT_SIZE_LIMIT;
 use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
-use mcb_domain::ports::providers::cache::{CacheEntryConfig, CacheProvider, CacheStats};
+use mcb_domain::ports::providers::cache::{
+    CacheEntryConfig, CacheProvider, CacheStats, NamespaceStats,
+};
+use moka::Expiry;
 use moka::future::Cache;
-use std::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A cached value with the namespace and TTL it was stored with
+#[derive(Clone)]
+struct CachedValue {
+    bytes: Arc<[u8]>,
+    namespace: Arc<str>,
+    ttl: Option<Duration>,
+    expires_at: Option<Instant>,
+}
+
+impl CachedValue {
+    fn json(&self) -> Result<String> {
+        String::from_utf8(self.bytes.to_vec()).map_err(|e| Error::Infrastructure {
+            message: format!("Invalid UTF-8 in cached value: {}", e),
+            source: Some(Box::new(e)),
+        })
+    }
+}
+
+/// Expires each entry after the TTL it was stored with
+struct PerEntryTtl;
+
+impl Expiry<String, CachedValue> for PerEntryTtl {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        value: &CachedValue,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        value.ttl
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        value: &CachedValue,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        value.ttl
+    }
+}
 
 /// Moka-based in-memory cache provider
 ///
 /// Uses the Moka crate for high-performance concurrent caching.
-/// Supports configurable capacity and TTL.
+/// Supports configurable capacity and TTL. Each entry also expires after
+/// the TTL in its `CacheEntryConfig`; a zero TTL means no per-entry expiry.
 ///
 /// Created at runtime via factory pattern.
 /// For testing, use `NullCacheProvider`.
 #[derive(Clone)]
 pub struct MokaCacheProvider {
-    cache: Cache<String, Vec<u8>>,
+    cache: Cache<String, CachedValue>,
     max_size: usize,
 }
 
@@ -51,7 +101,10 @@ impl MokaCacheProvider {
 
     /// Create a new Moka cache provider with specified capacity
     pub fn with_capacity(max_size: usize) -> Self {
-        let cache = Cache::builder().max_capacity(max_size as u64).build();
+        let cache = Cache::builder()
+            .max_capacity(max_size as u64)
+            .expire_after(PerEntryTtl)
+            .build();
 
         Self { cache, max_size }
     }
@@ -61,6 +114,7 @@ impl MokaCacheProvider {
         let cache = Cache::builder()
             .max_capacity(max_size as u64)
             .time_to_live(time_to_live)
+            .expire_after(PerEntryTtl)
             .build();
 
         Self { cache, max_size }
@@ -70,23 +124,43 @@ impl MokaCacheProvider {
     pub fn max_size(&self) -> usize {
         self.max_size
     }
+
+    /// Keys of the live entries in `namespace`, unsorted
+    fn namespace_keys(&self, namespace: &str) -> Vec<String> {
+        self.cache
+            .iter()
+            .filter(|(_, value)| &*value.namespace == namespace)
+            .map(|(key, _)| key.as_ref().clone())
+            .collect()
+    }
 }
 
 #[async_trait]
 impl CacheProvider for MokaCacheProvider {
     async fn get_json(&self, key: &str) -> Result<Option<String>> {
-        if let Some(bytes) = self.cache.get(key).await {
-            let json = String::from_utf8(bytes).map_err(|e| Error::Infrastructure {
-                message: format!("Invalid UTF-8 in cached value: {}", e),
-                source: Some(Box::new(e)),
-            })?;
-            Ok(Some(json))
+        if let Some(value) = self.cache.get(key).await {
+            Ok(Some(value.json()?))
         } else {
             Ok(None)
         }
     }
 
-    async fn set_json(&self, key: &str, value: &str, _config: CacheEntryConfig) -> Result<()> {
+    async fn get_json_with_ttl(&self, key: &str) -> Result<Option<(String, Option<Duration>)>> {
+        let Some(value) = self.cache.get(key).await else {
+            return Ok(None);
+        };
+        let remaining = match value.expires_at {
+            Some(expires_at) => match expires_at.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => Some(remaining),
+                // Expired, just not evicted yet
+                _ => return Ok(None),
+            },
+            None => None,
+        };
+        Ok(Some((value.json()?, remaining)))
+    }
+
+    async fn set_json(&self, key: &str, value: &str, config: CacheEntryConfig) -> Result<()> {
         let bytes = value.as_bytes();
 
         // Check if the value exceeds our size limit
@@ -101,7 +175,14 @@ impl CacheProvider for MokaCacheProvider {
             });
         }
 
-        self.cache.insert(key.to_string(), bytes.to_vec()).await;
+        let ttl = Some(config.effective_ttl()).filter(|ttl| !ttl.is_zero());
+        let value = CachedValue {
+            bytes: bytes.into(),
+            namespace: config.entry_namespace(key).into(),
+            ttl,
+            expires_at: ttl.map(|ttl| Instant::now() + ttl),
+        };
+        self.cache.insert(key.to_string(), value).await;
         Ok(())
     }
 
@@ -121,6 +202,37 @@ impl CacheProvider for MokaCacheProvider {
         Ok(())
     }
 
+    async fn clear_namespace(&self, namespace: &str) -> Result<u64> {
+        let mut removed = 0;
+        for key in self.namespace_keys(namespace) {
+            if self.cache.remove(&key).await.is_some() {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    async fn list_keys(&self, namespace: &str) -> Result<Vec<String>> {
+        let mut keys = self.namespace_keys(namespace);
+        keys.sort();
+        Ok(keys)
+    }
+
+    async fn stats_by_namespace(&self) -> Result<Vec<NamespaceStats>> {
+        let mut namespaces: BTreeMap<Arc<str>, NamespaceStats> = BTreeMap::new();
+        for (_, value) in self.cache.iter() {
+            let stats = namespaces
+                .entry(Arc::clone(&value.namespace))
+                .or_insert_with(|| NamespaceStats {
+                    namespace: value.namespace.to_string(),
+                    ..NamespaceStats::default()
+                });
+            stats.entries += 1;
+            stats.bytes_used += value.bytes.len() as u64;
+        }
+        Ok(namespaces.into_values().collect())
+    }
+
     async fn stats(&self) -> Result<CacheStats> {
         // Run pending tasks to ensure entry_count is accurate
         self.cache.run_pending_tasks().await;
@@ -132,6 +244,7 @@ impl CacheProvider for MokaCacheProvider {
             entries,
             hit_rate: 0.0, // Unknown
             bytes_used: 0, // Unknown
+            tiers: Vec::new(),
         })
     }
 
@@ -159,8 +272,6 @@ impl std::fmt::Debug for MokaCacheProvider {
 // Auto-registration via linkme distributed slice
 // ============================================================================
 
-use std::sync::Arc;
-
 use mcb_application::ports::registry::{CACHE_PROVIDERS, CacheProviderConfig, CacheProviderEntry};
 
 /// Factory function for creating Moka cache provider instances.
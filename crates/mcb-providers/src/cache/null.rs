@@ -5,7 +5,10 @@
 
 use async_trait::async_trait;
 use mcb_domain::error::Result;
-use mcb_domain::ports::providers::cache::{CacheEntryConfig, CacheProvider, CacheStats};
+use mcb_domain::ports::providers::cache::{
+    CacheEntryConfig, CacheProvider, CacheStats, NamespaceStats,
+};
+use std::time::Duration;
 
 /// Null cache provider that doesn't store anything
 ///
@@ -43,6 +46,11 @@ impl CacheProvider for NullCacheProvider {
         Ok(None)
     }
 
+    async fn get_json_with_ttl(&self, _key: &str) -> Result<Option<(String, Option<Duration>)>> {
+        // Always return None (cache miss)
+        Ok(None)
+    }
+
     async fn set_json(&self, _key: &str, _value: &str, _config: CacheEntryConfig) -> Result<()> {
         // Accept the set operation but don't store anything
         Ok(())
@@ -63,6 +71,21 @@ impl CacheProvider for NullCacheProvider {
         Ok(())
     }
 
+    async fn clear_namespace(&self, _namespace: &str) -> Result<u64> {
+        // Nothing to clear
+        Ok(0)
+    }
+
+    async fn list_keys(&self, _namespace: &str) -> Result<Vec<String>> {
+        // No keys are ever stored
+        Ok(Vec::new())
+    }
+
+    async fn stats_by_namespace(&self) -> Result<Vec<NamespaceStats>> {
+        // No namespaces are ever populated
+        Ok(Vec::new())
+    }
+
     async fn stats(&self) -> Result<CacheStats> {
         // Return empty stats
         Ok(CacheStats::new())
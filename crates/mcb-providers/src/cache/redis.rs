@@ -8,6 +8,7 @@
 //! - Distributed caching for multiple instances
 //! - TTL support for automatic expiration
 //! - Connection pooling via multiplexed connection
+//! - Namespace listing and clearing through per-namespace key index sets
 //!
 //! ## Example
 //!
@@ -22,16 +23,80 @@
 //! # }
 //! ```
 
+use crate::constants::{REDIS_NAMESPACE_INDEX_PREFIX, REDIS_NAMESPACE_INDEX_PRUNE_SAMPLE};
 use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
-use mcb_domain::ports::providers::cache::{CacheEntryConfig, CacheProvider, CacheStats};
-use redis::{AsyncCommands, Client, aio::MultiplexedConnection};
+use mcb_domain::ports::providers::cache::{
+    CacheEntryConfig, CacheProvider, CacheStats, NamespaceStats,
+};
+use redis::{AsyncCommands, Client, RedisError, Script, aio::MultiplexedConnection};
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Store a value and add its key to the namespace index
+///
+/// KEYS: entry, namespace index. ARGV: value, TTL in milliseconds (0 for
+/// none), number of index members to check. A sample of the index is pruned
+/// of keys that have expired or been deleted, and the index lives at least
+/// as long as its longest-lived entry.
+const SET_SCRIPT: &str = r"
+local ttl = tonumber(ARGV[2])
+if ttl > 0 then
+    redis.call('SET', KEYS[1], ARGV[1], 'PX', ttl)
+else
+    redis.call('SET', KEYS[1], ARGV[1])
+end
+local indexed = redis.call('EXISTS', KEYS[2]) == 1
+redis.call('SADD', KEYS[2], KEYS[1])
+for _, member in ipairs(redis.call('SRANDMEMBER', KEYS[2], ARGV[3])) do
+    if redis.call('EXISTS', member) == 0 then
+        redis.call('SREM', KEYS[2], member)
+    end
+end
+if ttl == 0 then
+    redis.call('PERSIST', KEYS[2])
+else
+    local index_ttl = redis.call('PTTL', KEYS[2])
+    if not indexed or (index_ttl >= 0 and index_ttl < ttl) then
+        redis.call('PEXPIRE', KEYS[2], ttl)
+    end
+end
+";
+
+/// Delete every key of a namespace and its index in one step
+///
+/// KEYS: namespace index. Returns the number of entries deleted.
+const CLEAR_NAMESPACE_SCRIPT: &str = r"
+local removed = 0
+for _, key in ipairs(redis.call('SMEMBERS', KEYS[1])) do
+    removed = removed + redis.call('DEL', key)
+end
+redis.call('DEL', KEYS[1])
+return removed
+";
+
+/// Redis set holding the keys stored in `namespace`
+fn namespace_index(namespace: &str) -> String {
+    format!("{REDIS_NAMESPACE_INDEX_PREFIX}{namespace}")
+}
+
+/// Wrap a failed Redis command
+fn command_error(command: &str, e: RedisError) -> Error {
+    Error::Infrastructure {
+        message: format!("Redis {command} failed: {e}"),
+        source: Some(Box::new(e)),
+    }
+}
 
 /// Redis cache provider
 ///
 /// Distributed cache implementation using Redis.
 /// Uses multiplexed connections for efficient connection reuse.
+///
+/// Each write also adds the key to a set indexing its namespace. Keys that
+/// have expired or been deleted are pruned from the index when the
+/// namespace is listed, and a sample of them on every write; the index
+/// itself expires with its longest-lived entry.
 #[derive(Clone)]
 pub struct RedisCacheProvider {
     client: Client,
@@ -114,6 +179,44 @@ impl RedisCacheProvider {
         }
     }
 
+    /// Live keys of `namespace`, pruning expired and deleted ones from its index
+    async fn live_namespace_keys(
+        &self,
+        conn: &mut MultiplexedConnection,
+        namespace: &str,
+    ) -> Result<Vec<String>> {
+        let index = namespace_index(namespace);
+        let members: Vec<String> = conn
+            .smembers(&index)
+            .await
+            .map_err(|e| command_error("SMEMBERS", e))?;
+        if members.is_empty() {
+            return Ok(members);
+        }
+
+        let mut pipe = redis::pipe();
+        for key in &members {
+            pipe.exists(key);
+        }
+        let exists: Vec<bool> = pipe
+            .query_async(conn)
+            .await
+            .map_err(|e| command_error("EXISTS", e))?;
+
+        let (live, stale): (Vec<_>, Vec<_>) = members
+            .into_iter()
+            .zip(exists)
+            .partition(|(_, exists)| *exists);
+        if !stale.is_empty() {
+            let stale: Vec<String> = stale.into_iter().map(|(key, _)| key).collect();
+            let _: () = conn
+                .srem(&index, stale)
+                .await
+                .map_err(|e| command_error("SREM", e))?;
+        }
+        Ok(live.into_iter().map(|(key, _)| key).collect())
+    }
+
     /// Get the Redis server address description
     pub fn server_address(&self) -> String {
         "redis-server".to_string()
@@ -146,21 +249,50 @@ impl CacheProvider for RedisCacheProvider {
         }
     }
 
-    async fn set_json(&self, key: &str, value: &str, config: CacheEntryConfig) -> Result<()> {
+    async fn get_json_with_ttl(&self, key: &str) -> Result<Option<(String, Option<Duration>)>> {
         let mut conn = self.get_connection().await?;
 
-        let ttl_seconds = config.effective_ttl().as_secs();
+        let (value, ttl_ms): (Option<String>, i64) = redis::pipe()
+            .atomic()
+            .get(key)
+            .pttl(key)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| command_error("GET", e))?;
+        // PTTL is -1 for keys without an expiry and 0 for keys expiring now
+        let remaining = u64::try_from(ttl_ms).ok().map(Duration::from_millis);
+        match value {
+            Some(value) if remaining.is_none_or(|ttl| !ttl.is_zero()) => {
+                self.record_hit();
+                Ok(Some((value, remaining)))
+            }
+            _ => {
+                self.record_miss();
+                Ok(None)
+            }
+        }
+    }
+
+    async fn set_json(&self, key: &str, value: &str, config: CacheEntryConfig) -> Result<()> {
+        let mut conn = self.get_connection().await?;
 
-        let result: redis::RedisResult<()> = if ttl_seconds > 0 {
-            conn.set_ex(key, value, ttl_seconds).await
+        let ttl = config.effective_ttl();
+        // Round up so a sub-millisecond TTL does not mean "never expires"
+        let ttl_ms = if ttl.is_zero() {
+            0
         } else {
-            conn.set(key, value).await
+            ttl.as_millis().max(1)
         };
 
-        result.map_err(|e| Error::Infrastructure {
-            message: format!("Redis SET failed: {}", e),
-            source: Some(Box::new(e)),
-        })
+        Script::new(SET_SCRIPT)
+            .key(key)
+            .key(namespace_index(&config.entry_namespace(key)))
+            .arg(value)
+            .arg(u64::try_from(ttl_ms).unwrap_or(u64::MAX))
+            .arg(REDIS_NAMESPACE_INDEX_PRUNE_SAMPLE)
+            .invoke_async::<()>(&mut conn)
+            .await
+            .map_err(|e| command_error("SET", e))
     }
 
     async fn delete(&self, key: &str) -> Result<bool> {
@@ -201,6 +333,68 @@ impl CacheProvider for RedisCacheProvider {
             })
     }
 
+    async fn clear_namespace(&self, namespace: &str) -> Result<u64> {
+        let mut conn = self.get_connection().await?;
+
+        // A script, so keys written to the namespace meanwhile are not
+        // left behind without an index
+        Script::new(CLEAR_NAMESPACE_SCRIPT)
+            .key(namespace_index(namespace))
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| command_error("DEL", e))
+    }
+
+    async fn list_keys(&self, namespace: &str) -> Result<Vec<String>> {
+        let mut conn = self.get_connection().await?;
+
+        let mut keys = self.live_namespace_keys(&mut conn, namespace).await?;
+        keys.sort();
+        Ok(keys)
+    }
+
+    async fn stats_by_namespace(&self) -> Result<Vec<NamespaceStats>> {
+        let mut conn = self.get_connection().await?;
+
+        let mut namespaces = Vec::new();
+        {
+            let mut indexes = conn
+                .scan_match::<_, String>(format!("{REDIS_NAMESPACE_INDEX_PREFIX}*"))
+                .await
+                .map_err(|e| command_error("SCAN", e))?;
+            while let Some(index) = indexes.next_item().await {
+                let index = index.map_err(|e| command_error("SCAN", e))?;
+                if let Some(namespace) = index.strip_prefix(REDIS_NAMESPACE_INDEX_PREFIX) {
+                    namespaces.push(namespace.to_string());
+                }
+            }
+        }
+        namespaces.sort();
+        namespaces.dedup();
+
+        let mut stats = Vec::with_capacity(namespaces.len());
+        for namespace in namespaces {
+            let keys = self.live_namespace_keys(&mut conn, &namespace).await?;
+            if keys.is_empty() {
+                continue;
+            }
+            let mut pipe = redis::pipe();
+            for key in &keys {
+                pipe.strlen(key);
+            }
+            let lengths: Vec<u64> = pipe
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| command_error("STRLEN", e))?;
+            stats.push(NamespaceStats {
+                namespace,
+                entries: keys.len() as u64,
+                bytes_used: lengths.into_iter().sum(),
+            });
+        }
+        Ok(stats)
+    }
+
     async fn stats(&self) -> Result<CacheStats> {
         let mut conn = self.get_connection().await?;
 
@@ -0,0 +1,307 @@
+//! Two-tier cache provider
+//!
+//! Combines a local Moka cache (L1) with a shared cache such as Redis (L2).
+//!
+//! ## Behaviour
+//!
+//! - **Reads** go to L1 first, then L2; L2 hits are copied into L1 for the
+//!   time they have left to live in L2
+//! - **Writes** go to L2, then L1
+//! - **Invalidation**: every write, delete and clear publishes
+//!   `DomainEvent::CacheInvalidate` tagged with the id of the publishing
+//!   instance; every other instance listening on the same event bus drops
+//!   the written or deleted key, or the cleared namespace, from its L1
+//!
+//! L1 entries also expire after the L1 TTL, which bounds staleness when an
+//! invalidation is missed.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use mcb_providers::cache::{MokaCacheProvider, TieredCacheProvider};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! # fn example(l2: Arc<dyn mcb_domain::ports::providers::CacheProvider>) {
+//! let l1 = MokaCacheProvider::with_config(10_000, Duration::from_secs(60));
+//! let cache = Arc::new(TieredCacheProvider::new(l1, l2));
+//! # }
+//! ```
+
+use crate::cache::moka::MokaCacheProvider;
+use async_trait::async_trait;
+use futures::StreamExt;
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::infrastructure::EventBusProvider;
+use mcb_domain::ports::providers::cache::{
+    CacheEntryConfig, CacheProvider, CacheStats, CacheTierStats, NamespaceStats, key_namespace,
+};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Two-tier cache with a local L1 and a shared L2
+///
+/// Without an event bus, invalidations only reach the local L1; attach one
+/// with [`with_event_bus`](Self::with_event_bus) and start
+/// [`listen_for_invalidations`](Self::listen_for_invalidations) when several
+/// instances share the L2.
+pub struct TieredCacheProvider {
+    l1: MokaCacheProvider,
+    l2: Arc<dyn CacheProvider>,
+    event_bus: Option<Arc<dyn EventBusProvider>>,
+    /// Tags the invalidations this instance publishes, so it can skip them
+    instance_id: String,
+    /// Whether the invalidation listener is subscribed to the event bus
+    listening: AtomicBool,
+    l1_hits: AtomicU64,
+    l2_hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl TieredCacheProvider {
+    /// Create a tiered cache over `l1` and `l2`
+    pub fn new(l1: MokaCacheProvider, l2: Arc<dyn CacheProvider>) -> Self {
+        Self {
+            l1,
+            l2,
+            event_bus: None,
+            instance_id: uuid::Uuid::new_v4().to_string(),
+            listening: AtomicBool::new(false),
+            l1_hits: AtomicU64::new(0),
+            l2_hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Broadcast invalidations over `event_bus`
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBusProvider>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Drop L1 entries when another instance invalidates them
+    ///
+    /// Spawns a task that subscribes to the event bus and runs until the
+    /// event stream ends or the cache is dropped. Returns `None` when no
+    /// event bus is attached or no Tokio runtime is running.
+    pub fn listen_for_invalidations(self: &Arc<Self>) -> Option<JoinHandle<()>> {
+        let event_bus = Arc::clone(self.event_bus.as_ref()?);
+        let runtime = tokio::runtime::Handle::try_current().ok()?;
+        let cache: Weak<Self> = Arc::downgrade(self);
+
+        Some(runtime.spawn(async move {
+            let mut events = match event_bus.subscribe_events().await {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!(error = %e, "Cache invalidation listener could not subscribe");
+                    return;
+                }
+            };
+            if let Some(cache) = cache.upgrade() {
+                cache.listening.store(true, Ordering::Release);
+            }
+            while let Some(event) = events.next().await {
+                let DomainEvent::CacheInvalidate {
+                    namespace,
+                    key,
+                    origin,
+                } = event
+                else {
+                    continue;
+                };
+                let Some(cache) = cache.upgrade() else {
+                    break;
+                };
+                if origin.as_deref() == Some(cache.instance_id.as_str()) {
+                    continue;
+                }
+                debug!(
+                    ?namespace,
+                    ?key,
+                    "Dropping L1 cache entries on remote invalidation"
+                );
+                if let Err(e) = cache
+                    .invalidate_l1(namespace.as_deref(), key.as_deref())
+                    .await
+                {
+                    warn!(error = %e, "Failed to invalidate L1 cache");
+                }
+            }
+            if let Some(cache) = cache.upgrade() {
+                cache.listening.store(false, Ordering::Release);
+            }
+        }))
+    }
+
+    /// Whether the invalidation listener is subscribed to the event bus
+    pub fn is_listening(&self) -> bool {
+        self.listening.load(Ordering::Acquire)
+    }
+
+    async fn invalidate_l1(&self, namespace: Option<&str>, key: Option<&str>) -> Result<()> {
+        match (namespace, key) {
+            (_, Some(key)) => self.l1.delete(key).await.map(|_| ()),
+            (Some(namespace), None) => self.l1.clear_namespace(namespace).await.map(|_| ()),
+            (None, None) => self.l1.clear().await,
+        }
+    }
+
+    /// Tell other instances to drop `key`, or every entry in `namespace`,
+    /// from their L1
+    async fn broadcast(&self, namespace: Option<String>, key: Option<String>) {
+        let Some(event_bus) = &self.event_bus else {
+            return;
+        };
+        if let Err(e) = event_bus
+            .publish_event(DomainEvent::CacheInvalidate {
+                namespace,
+                key,
+                origin: Some(self.instance_id.clone()),
+            })
+            .await
+        {
+            warn!(error = %e, "Failed to broadcast cache invalidation");
+        }
+    }
+}
+
+#[async_trait]
+impl CacheProvider for TieredCacheProvider {
+    async fn get_json(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.get_json_with_ttl(key).await?.map(|(value, _)| value))
+    }
+
+    async fn get_json_with_ttl(&self, key: &str) -> Result<Option<(String, Option<Duration>)>> {
+        if let Some(entry) = self.l1.get_json_with_ttl(key).await? {
+            self.l1_hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(Some(entry));
+        }
+
+        match self.l2.get_json_with_ttl(key).await? {
+            Some((value, remaining)) => {
+                self.l2_hits.fetch_add(1, Ordering::Relaxed);
+                // Keep the L2 expiry so L1 never outlives it; a zero TTL
+                // leaves entries that never expire to the L1 TTL
+                let config = CacheEntryConfig::default().with_ttl(remaining.unwrap_or_default());
+                // Values too large for L1 are simply served from L2
+                let _ = self.l1.set_json(key, &value, config).await;
+                Ok(Some((value, remaining)))
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn set_json(&self, key: &str, value: &str, config: CacheEntryConfig) -> Result<()> {
+        let namespace = config.entry_namespace(key);
+        self.l2.set_json(key, value, config.clone()).await?;
+        if self.l1.set_json(key, value, config).await.is_err() {
+            // Never leave an older value behind in L1
+            self.l1.delete(key).await?;
+        }
+        self.broadcast(Some(namespace), Some(key.to_string())).await;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool> {
+        let existed = self.l2.delete(key).await?;
+        let cached = self.l1.delete(key).await?;
+        self.broadcast(Some(key_namespace(key).to_string()), Some(key.to_string()))
+            .await;
+        Ok(existed || cached)
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        Ok(self.l1.exists(key).await? || self.l2.exists(key).await?)
+    }
+
+    async fn clear(&self) -> Result<()> {
+        self.l2.clear().await?;
+        self.l1.clear().await?;
+        self.broadcast(None, None).await;
+        Ok(())
+    }
+
+    async fn clear_namespace(&self, namespace: &str) -> Result<u64> {
+        let removed = self.l2.clear_namespace(namespace).await?;
+        self.l1.clear_namespace(namespace).await?;
+        self.broadcast(Some(namespace.to_string()), None).await;
+        Ok(removed)
+    }
+
+    async fn list_keys(&self, namespace: &str) -> Result<Vec<String>> {
+        self.l2.list_keys(namespace).await
+    }
+
+    async fn stats_by_namespace(&self) -> Result<Vec<NamespaceStats>> {
+        self.l2.stats_by_namespace().await
+    }
+
+    async fn stats(&self) -> Result<CacheStats> {
+        let l1_hits = self.l1_hits.load(Ordering::Relaxed);
+        let l2_hits = self.l2_hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let l2 = self.l2.stats().await?;
+
+        let tier = |tier: &str, provider: &str, hits: u64, misses: u64, entries: u64| {
+            let stats = CacheStats {
+                hits,
+                misses,
+                ..CacheStats::default()
+            };
+            CacheTierStats {
+                tier: tier.to_string(),
+                provider: provider.to_string(),
+                hits,
+                misses,
+                hit_rate: stats.calculate_hit_rate(),
+                entries,
+            }
+        };
+        let tiers = vec![
+            tier(
+                "l1",
+                self.l1.provider_name(),
+                l1_hits,
+                l2_hits + misses,
+                self.l1.size().await? as u64,
+            ),
+            tier("l2", self.l2.provider_name(), l2_hits, misses, l2.entries),
+        ];
+
+        let mut stats = CacheStats {
+            hits: l1_hits + l2_hits,
+            misses,
+            entries: l2.entries,
+            hit_rate: 0.0,
+            bytes_used: l2.bytes_used,
+            tiers,
+        };
+        stats.hit_rate = stats.calculate_hit_rate();
+        Ok(stats)
+    }
+
+    async fn size(&self) -> Result<usize> {
+        self.l2.size().await
+    }
+
+    fn provider_name(&self) -> &str {
+        "tiered"
+    }
+}
+
+impl std::fmt::Debug for TieredCacheProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TieredCacheProvider")
+            .field("l1", &self.l1)
+            .field("l2", &self.l2)
+            .field("event_bus", &self.event_bus.is_some())
+            .finish()
+    }
+}
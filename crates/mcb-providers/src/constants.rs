@@ -46,6 +46,12 @@ pub const EMBEDDING_DIMENSION_OLLAMA_DEFAULT: usize = 768;
 /// Gemini embedding dimension
 pub const EMBEDDING_DIMENSION_GEMINI: usize = 768;
 
+/// Hashing embedding provider default dimension
+pub const EMBEDDING_DIMENSION_HASHING_DEFAULT: usize = 384;
+
+/// Hashing embedding provider model name, versioned with the hashing scheme
+pub const HASHING_EMBEDDING_MODEL: &str = "feature-hash-v1";
+
 /// Default embedding dimension (for providers that don't specify)
 pub const EMBEDDING_DIMENSION_DEFAULT: usize = 512;
 
@@ -62,6 +68,15 @@ pub const VOYAGEAI_MAX_OUTPUT_TOKENS: usize = 16000;
 /// OpenAI max tokens per request
 pub const OPENAI_MAX_TOKENS_PER_REQUEST: usize = 8191;
 
+/// Azure OpenAI REST API version used when none is configured
+pub const AZURE_OPENAI_DEFAULT_API_VERSION: &str = "2024-02-01";
+
+/// Request path of OpenAI-compatible embedding endpoints
+pub const OPENAI_COMPATIBLE_DEFAULT_PATH: &str = "/embeddings";
+
+/// Location of the vectors in OpenAI-format responses
+pub const OPENAI_RESPONSE_VECTORS_PATH: &str = "data[*].embedding";
+
 /// Ollama server default port
 pub const OLLAMA_DEFAULT_PORT: u16 = 11434;
 
@@ -84,6 +99,12 @@ pub const REDIS_DEFAULT_PORT: u16 = 6379;
 /// Cache namespace separator
 pub const CACHE_NAMESPACE_SEPARATOR: &str = ":";
 
+/// Key prefix of the Redis sets indexing the keys of each cache namespace
+pub const REDIS_NAMESPACE_INDEX_PREFIX: &str = "__mcb_cache_namespace__:";
+
+/// Index members checked for expired or deleted keys on each cache write
+pub const REDIS_NAMESPACE_INDEX_PRUNE_SAMPLE: usize = 8;
+
 // ============================================================================
 // EVENTS PROVIDER CONSTANTS
 // ============================================================================
@@ -229,6 +250,15 @@ pub const EDGEVEC_HNSW_EF_SEARCH: u32 = 64;
 /// EdgeVec default dimensions (for OpenAI embeddings)
 pub const EDGEVEC_DEFAULT_DIMENSIONS: usize = 1536;
 
+/// EdgeVec background flush interval in seconds
+pub const EDGEVEC_FLUSH_INTERVAL_SECS: u64 = 30;
+
+/// Fraction of deleted nodes that triggers an EdgeVec index rebuild
+pub const EDGEVEC_COMPACTION_THRESHOLD: f64 = 0.2;
+
+/// Deleted nodes required before an EdgeVec index rebuild
+pub const EDGEVEC_COMPACTION_MIN_TOMBSTONES: usize = 1000;
+
 // ============================================================================
 // FILESYSTEM VECTOR STORE CONSTANTS
 // ============================================================================
@@ -242,6 +272,18 @@ pub const FILESYSTEM_VECTOR_STORE_MAX_PER_SHARD: usize = 10000;
 /// Filesystem vector store index cache size
 pub const FILESYSTEM_VECTOR_STORE_INDEX_CACHE_SIZE: usize = 1000;
 
+/// Filesystem vector store collection size from which searches use the ANN index
+pub const FILESYSTEM_VECTOR_STORE_EXACT_SEARCH_BELOW: usize = 10000;
+
+/// Filesystem vector store ANN lists probed per query
+pub const FILESYSTEM_VECTOR_STORE_ANN_NPROBE: usize = 8;
+
+/// Filesystem vector store WAL size that triggers a checkpoint (16 MiB)
+pub const FILESYSTEM_VECTOR_STORE_CHECKPOINT_WAL_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Filesystem vector store share of deleted records that triggers compaction
+pub const FILESYSTEM_VECTOR_STORE_COMPACTION_THRESHOLD: f64 = 0.5;
+
 // ============================================================================
 // MILVUS VECTOR STORE CONSTANTS
 // ============================================================================
@@ -249,6 +291,9 @@ pub const FILESYSTEM_VECTOR_STORE_INDEX_CACHE_SIZE: usize = 1000;
 /// Milvus field varchar max length
 pub const MILVUS_FIELD_VARCHAR_MAX_LENGTH: i32 = 512;
 
+/// Milvus primary key varchar max length
+pub const MILVUS_ID_VARCHAR_MAX_LENGTH: i32 = 128;
+
 /// Milvus metadata varchar max length
 pub const MILVUS_METADATA_VARCHAR_MAX_LENGTH: i32 = 65535;
 
@@ -261,6 +306,29 @@ pub const MILVUS_DEFAULT_PORT: u16 = 19530;
 /// Milvus default query limit for aggregation queries
 pub const MILVUS_DEFAULT_QUERY_LIMIT: i64 = 10_000;
 
+// ============================================================================
+// QDRANT VECTOR STORE CONSTANTS
+// ============================================================================
+
+/// Qdrant default HTTP port
+pub const QDRANT_DEFAULT_PORT: u16 = 6333;
+
+/// Qdrant request timeout in seconds
+pub const QDRANT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Qdrant points fetched per scroll request
+pub const QDRANT_SCROLL_PAGE_SIZE: usize = 256;
+
+// ============================================================================
+// SQLITE VECTOR STORE CONSTANTS
+// ============================================================================
+
+/// SQLite wait for a lock held by another connection, in milliseconds
+pub const SQLITE_BUSY_TIMEOUT_MS: u64 = 5_000;
+
+/// SQLite vector store prepared statement cache capacity
+pub const SQLITE_STATEMENT_CACHE_CAPACITY: usize = 32;
+
 // ============================================================================
 // HYBRID SEARCH CONSTANTS
 // ============================================================================
@@ -0,0 +1,237 @@
+//! Azure OpenAI Embedding Provider
+//!
+//! Implements the EmbeddingProvider port using an Azure OpenAI deployment.
+//! Requests go to the deployment URL with an `api-version` query parameter
+//! and authenticate with the `api-key` header.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+
+use crate::constants::{
+    AZURE_OPENAI_DEFAULT_API_VERSION, CONTENT_TYPE_JSON, EMBEDDING_DIMENSION_OPENAI_ADA,
+    EMBEDDING_DIMENSION_OPENAI_LARGE, EMBEDDING_DIMENSION_OPENAI_SMALL,
+    OPENAI_RESPONSE_VECTORS_PATH,
+};
+use crate::embedding::helpers::constructor;
+use crate::embedding::openai_compatible::{extract_vectors, to_embeddings};
+use crate::utils::HttpResponseUtils;
+
+/// Azure OpenAI embedding provider
+///
+/// The deployment name selects the model on the Azure side; `model` is the
+/// name of the deployed model and determines the default dimensions.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use mcb_providers::embedding::AzureOpenAIEmbeddingProvider;
+/// use reqwest::Client;
+/// use std::time::Duration;
+///
+/// let provider = AzureOpenAIEmbeddingProvider::new(
+///     "https://my-resource.openai.azure.com".to_string(),
+///     "embeddings-small".to_string(),
+///     "azure-api-key".to_string(),
+///     "text-embedding-3-small".to_string(),
+///     Duration::from_secs(30),
+///     Client::new(),
+/// )
+/// .with_api_version("2024-06-01");
+/// ```
+pub struct AzureOpenAIEmbeddingProvider {
+    endpoint: String,
+    deployment: String,
+    api_key: String,
+    api_version: String,
+    model: String,
+    dimensions: Option<usize>,
+    timeout: Duration,
+    http_client: Client,
+}
+
+impl AzureOpenAIEmbeddingProvider {
+    /// Create a new Azure OpenAI embedding provider
+    ///
+    /// # Arguments
+    /// * `endpoint` - Resource endpoint, e.g. `https://my-resource.openai.azure.com`
+    /// * `deployment` - Deployment name
+    /// * `api_key` - Azure OpenAI API key
+    /// * `model` - Name of the deployed model
+    /// * `timeout` - Request timeout duration
+    /// * `http_client` - Reqwest HTTP client for making API requests
+    pub fn new(
+        endpoint: String,
+        deployment: String,
+        api_key: String,
+        model: String,
+        timeout: Duration,
+        http_client: Client,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.trim().trim_end_matches('/').to_string(),
+            deployment,
+            api_key: constructor::validate_api_key(&api_key),
+            api_version: AZURE_OPENAI_DEFAULT_API_VERSION.to_string(),
+            model,
+            dimensions: None,
+            timeout,
+            http_client,
+        }
+    }
+
+    /// Set the REST API version
+    pub fn with_api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Request vectors of `dimensions` elements
+    ///
+    /// Only `text-embedding-3` models support shortened vectors.
+    pub fn with_dimensions(mut self, dimensions: usize) -> Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    /// Get the model name
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Full request URL of the deployment
+    pub fn url(&self) -> String {
+        format!(
+            "{}/openai/deployments/{}/embeddings?api-version={}",
+            self.endpoint, self.deployment, self.api_version
+        )
+    }
+
+    async fn fetch_embeddings(&self, texts: &[String]) -> Result<serde_json::Value> {
+        let mut payload = serde_json::json!({ "input": texts });
+        if let Some(dimensions) = self.dimensions {
+            payload["dimensions"] = dimensions.into();
+        }
+
+        let response = self
+            .http_client
+            .post(self.url())
+            .header("api-key", &self.api_key)
+            .header("Content-Type", CONTENT_TYPE_JSON)
+            .timeout(self.timeout)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    Error::network(format!(
+                        "{} {:?}",
+                        crate::constants::ERROR_MSG_REQUEST_TIMEOUT,
+                        self.timeout
+                    ))
+                } else {
+                    Error::network(format!("HTTP request failed: {}", e))
+                }
+            })?;
+
+        HttpResponseUtils::check_and_parse(response, "Azure OpenAI").await
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for AzureOpenAIEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let response_data = self.fetch_embeddings(texts).await?;
+        let vectors = extract_vectors(&response_data, OPENAI_RESPONSE_VECTORS_PATH)?;
+        to_embeddings(vectors, texts.len(), self.dimensions(), &self.model)
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+            .unwrap_or_else(|| match self.model.as_str() {
+                "text-embedding-3-large" => EMBEDDING_DIMENSION_OPENAI_LARGE,
+                "text-embedding-ada-002" => EMBEDDING_DIMENSION_OPENAI_ADA,
+                _ => EMBEDDING_DIMENSION_OPENAI_SMALL,
+            })
+    }
+
+    fn provider_name(&self) -> &str {
+        "azure-openai"
+    }
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+
+use mcb_application::ports::registry::{
+    EMBEDDING_PROVIDERS, EmbeddingProviderConfig, EmbeddingProviderEntry,
+};
+use mcb_domain::ports::providers::EmbeddingProvider as EmbeddingProviderPort;
+
+/// Factory function for creating Azure OpenAI embedding provider instances.
+///
+/// `base_url` is the resource endpoint. The deployment is taken from
+/// `extra["deployment"]`, falling back to the model name, and the API
+/// version from `extra["api_version"]`.
+fn azure_openai_factory(
+    config: &EmbeddingProviderConfig,
+) -> std::result::Result<Arc<dyn EmbeddingProviderPort>, String> {
+    let endpoint = config
+        .base_url
+        .clone()
+        .ok_or("Azure OpenAI requires base_url (the resource endpoint)")?;
+    let api_key = config
+        .api_key
+        .clone()
+        .ok_or("Azure OpenAI requires api_key")?;
+    let model = config
+        .model
+        .clone()
+        .unwrap_or_else(|| "text-embedding-3-small".to_string());
+    let deployment = config
+        .extra
+        .get("deployment")
+        .cloned()
+        .unwrap_or_else(|| model.clone());
+    let timeout = Duration::from_secs(30);
+    let http_client = Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let mut provider = AzureOpenAIEmbeddingProvider::new(
+        endpoint,
+        deployment,
+        api_key,
+        model,
+        timeout,
+        http_client,
+    );
+    if let Some(api_version) = config.extra.get("api_version") {
+        provider = provider.with_api_version(api_version);
+    }
+    if let Some(dimensions) = config.dimensions {
+        provider = provider.with_dimensions(dimensions);
+    }
+
+    Ok(Arc::new(provider))
+}
+
+#[linkme::distributed_slice(EMBEDDING_PROVIDERS)]
+static AZURE_OPENAI_PROVIDER: EmbeddingProviderEntry = EmbeddingProviderEntry {
+    name: "azure-openai",
+    description: "Azure OpenAI deployment (api-key header, api-version query)",
+    factory: azure_openai_factory,
+};
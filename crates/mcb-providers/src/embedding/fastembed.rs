@@ -4,13 +4,14 @@
 //! embedding generation. Uses ONNX models for inference without external API calls.
 
 use async_trait::async_trait;
-use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
+use fastembed::{EmbeddingModel, InitOptions, InitOptionsUserDefined, TextEmbedding};
 use tokio::sync::{mpsc, oneshot};
 
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::EmbeddingProvider;
 use mcb_domain::value_objects::Embedding;
 
+use super::local_model::LocalModelOptions;
 use crate::constants::EMBEDDING_DIMENSION_FASTEMBED_DEFAULT;
 
 /// Messages for the FastEmbed actor
@@ -38,6 +39,7 @@ enum FastEmbedMessage {
 pub struct FastEmbedProvider {
     sender: mpsc::Sender<FastEmbedMessage>,
     model_name: String,
+    dimensions: usize,
 }
 
 impl FastEmbedProvider {
@@ -59,16 +61,62 @@ impl FastEmbedProvider {
             Error::embedding(format!("Failed to initialize FastEmbed model: {}", e))
         })?;
 
+        Ok(Self::spawn(
+            text_embedding,
+            model_name,
+            EMBEDDING_DIMENSION_FASTEMBED_DEFAULT,
+            None,
+        ))
+    }
+
+    /// Create a FastEmbed provider from a model stored in a local directory
+    ///
+    /// Nothing is downloaded. The model files are checked against the
+    /// checksum manifest when one is configured, and every embedding is
+    /// checked against the declared dimensions.
+    pub fn from_local_model(options: &LocalModelOptions) -> Result<Self> {
+        if options.dimensions == 0 {
+            return Err(Error::embedding(
+                "Local FastEmbed models must declare their dimensions",
+            ));
+        }
+        let model = options.load()?;
+        let init_options = InitOptionsUserDefined::new().with_max_length(options.max_length);
+        let text_embedding = TextEmbedding::try_new_from_user_defined(model, init_options)
+            .map_err(|e| {
+                Error::embedding(format!(
+                    "Failed to load FastEmbed model from {}: {}",
+                    options.dir.display(),
+                    e
+                ))
+            })?;
+
+        Ok(Self::spawn(
+            text_embedding,
+            options.model_name.clone(),
+            options.dimensions,
+            Some(options.dimensions),
+        ))
+    }
+
+    fn spawn(
+        text_embedding: TextEmbedding,
+        model_name: String,
+        dimensions: usize,
+        expected_dimensions: Option<usize>,
+    ) -> Self {
         let (tx, rx) = mpsc::channel(100);
-        let mut actor = FastEmbedActor::new(rx, text_embedding, model_name.clone());
+        let mut actor =
+            FastEmbedActor::new(rx, text_embedding, model_name.clone(), expected_dimensions);
         tokio::spawn(async move {
             actor.run().await;
         });
 
-        Ok(Self {
+        Self {
             sender: tx,
             model_name,
-        })
+            dimensions,
+        }
     }
 
     /// Get the model name
@@ -100,8 +148,7 @@ impl EmbeddingProvider for FastEmbedProvider {
     }
 
     fn dimensions(&self) -> usize {
-        // AllMiniLML6V2 has 384 dimensions
-        EMBEDDING_DIMENSION_FASTEMBED_DEFAULT
+        self.dimensions
     }
 
     fn provider_name(&self) -> &str {
@@ -119,6 +166,7 @@ impl Clone for FastEmbedProvider {
         Self {
             sender: self.sender.clone(),
             model_name: self.model_name.clone(),
+            dimensions: self.dimensions,
         }
     }
 }
@@ -128,6 +176,8 @@ struct FastEmbedActor {
     receiver: mpsc::Receiver<FastEmbedMessage>,
     model: TextEmbedding,
     model_name: String,
+    /// Declared output dimensions, checked on every batch when set
+    expected_dimensions: Option<usize>,
 }
 
 impl FastEmbedActor {
@@ -135,11 +185,13 @@ impl FastEmbedActor {
         receiver: mpsc::Receiver<FastEmbedMessage>,
         model: TextEmbedding,
         model_name: String,
+        expected_dimensions: Option<usize>,
     ) -> Self {
         Self {
             receiver,
             model,
             model_name,
+            expected_dimensions,
         }
     }
 
@@ -150,6 +202,18 @@ impl FastEmbedActor {
                     let text_refs: Vec<&str> = texts.iter().map(|s| s.as_str()).collect();
                     let embeddings_result = self.model.embed(text_refs, None);
                     let result = match embeddings_result {
+                        Ok(res)
+                            if self
+                                .expected_dimensions
+                                .is_some_and(|d| res.iter().any(|v| v.len() != d)) =>
+                        {
+                            Err(Error::embedding(format!(
+                                "FastEmbed model {} returned {} dimensions, expected {}",
+                                self.model_name,
+                                res.first().map_or(0, Vec::len),
+                                self.expected_dimensions.unwrap_or_default()
+                            )))
+                        }
                         Ok(res) => {
                             let model_name = self.model_name.clone();
                             Ok(res
@@ -203,13 +267,53 @@ fn parse_embedding_model(model_name: &str) -> EmbeddingModel {
     }
 }
 
+/// Local model options from the `extra` keys of `config`
+///
+/// `model_dir` selects a local model directory; `dimensions` is then
+/// required, and `pooling`, `max_length` and `checksums` are optional.
+fn local_model_options(
+    config: &EmbeddingProviderConfig,
+) -> std::result::Result<Option<LocalModelOptions>, String> {
+    let Some(dir) = config.extra.get("model_dir") else {
+        return Ok(None);
+    };
+    let dimensions = config
+        .dimensions
+        .ok_or("FastEmbed model_dir requires dimensions to be set")?;
+
+    let mut options = LocalModelOptions::new(dir, dimensions);
+    if let Some(model) = &config.model {
+        options = options.with_model_name(model);
+    }
+    if let Some(pooling) = config.extra.get("pooling") {
+        options = options.with_pooling(pooling.parse()?);
+    }
+    if let Some(max_length) = config.extra.get("max_length") {
+        let max_length = max_length
+            .parse()
+            .map_err(|e| format!("Invalid FastEmbed max_length '{max_length}': {e}"))?;
+        options = options.with_max_length(max_length);
+    }
+    if let Some(checksums) = config.extra.get("checksums") {
+        options = options.with_checksums(checksums);
+    }
+    Ok(Some(options))
+}
+
 /// Factory function for creating FastEmbed provider instances.
 ///
-/// Uses a centralized cache directory to avoid creating `.fastembed_cache`
+/// Loads a local model directory when `model_dir` is set; otherwise uses a
+/// centralized cache directory to avoid creating `.fastembed_cache`
 /// directories in repository working directories.
 fn fastembed_factory(
     config: &EmbeddingProviderConfig,
 ) -> std::result::Result<Arc<dyn EmbeddingProviderPort>, String> {
+    if let Some(options) = local_model_options(config)? {
+        let provider = FastEmbedProvider::from_local_model(&options)
+            .map_err(|e| format!("Failed to create FastEmbed provider: {e}"))?;
+        return Ok(Arc::new(provider));
+    }
+
     let model_name = config
         .model
         .clone()
@@ -0,0 +1,243 @@
+//! Hashing embedding provider
+//!
+//! Offline embeddings built by feature hashing code-aware tokens. No model
+//! files or network access; the same text always maps to the same vector
+//! on every platform.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use mcb_domain::error::Result;
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+
+use crate::constants::{EMBEDDING_DIMENSION_HASHING_DEFAULT, HASHING_EMBEDDING_MODEL};
+
+/// Weight of an identifier subword such as `parse` in `parseConfig`
+const TOKEN_WEIGHT: f32 = 1.0;
+/// Weight of a compound identifier as written, such as `parse_config`
+const IDENTIFIER_WEIGHT: f32 = 0.8;
+/// Weight of two adjacent subwords
+const BIGRAM_WEIGHT: f32 = 0.5;
+/// Weight of a character trigram of a subword
+const TRIGRAM_WEIGHT: f32 = 0.25;
+
+/// Keywords and filler words too common in code and prose to carry meaning
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "be", "def", "else", "fn", "for", "from", "if", "impl", "in", "is",
+    "it", "let", "mut", "of", "on", "or", "pub", "return", "self", "the", "this", "to", "use",
+    "var", "with",
+];
+
+/// Embedding provider producing feature-hashed vectors
+///
+/// Text is split into identifiers, identifiers into lowercase subwords at
+/// `snake_case`, `camelCase` and digit boundaries. Subwords, compound
+/// identifiers, adjacent subword pairs and subword character trigrams are
+/// hashed into signed buckets, damped by the square root of their count
+/// and L2-normalized. Texts sharing vocabulary score high under cosine
+/// similarity; trigrams give partial credit to related word forms such as
+/// `parser` and `parsing`.
+///
+/// # Example
+///
+/// ```rust
+/// use mcb_providers::embedding::HashingEmbeddingProvider;
+/// use mcb_domain::ports::providers::EmbeddingProvider;
+///
+/// let provider = HashingEmbeddingProvider::new(256);
+/// assert_eq!(provider.dimensions(), 256);
+/// assert_eq!(provider.provider_name(), "hashing");
+/// ```
+pub struct HashingEmbeddingProvider {
+    dimensions: usize,
+}
+
+impl HashingEmbeddingProvider {
+    /// Create a provider producing vectors of `dimensions` elements
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions: dimensions.max(1),
+        }
+    }
+
+    /// Get the model name for this provider
+    ///
+    /// The name is versioned so collections built by an earlier hashing
+    /// scheme are recognised as a different embedding space.
+    pub fn model(&self) -> &str {
+        HASHING_EMBEDDING_MODEL
+    }
+
+    /// Embed a single text
+    pub fn embed_text(&self, text: &str) -> Vec<f32> {
+        let mut features: HashMap<u64, f32> = HashMap::new();
+        let mut previous: Option<String> = None;
+        for identifier in text
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .filter(|word| !word.is_empty())
+        {
+            let subwords = split_identifier(identifier);
+            if subwords.len() > 1 {
+                add_feature(&mut features, b'i', &subwords.join("_"), IDENTIFIER_WEIGHT);
+            }
+            for subword in subwords {
+                if subword.chars().count() < 2 || STOP_WORDS.contains(&subword.as_str()) {
+                    continue;
+                }
+                add_feature(&mut features, b't', &subword, TOKEN_WEIGHT);
+                for trigram in trigrams(&subword) {
+                    add_feature(&mut features, b'c', &trigram, TRIGRAM_WEIGHT);
+                }
+                if let Some(previous) = &previous {
+                    add_feature(
+                        &mut features,
+                        b'b',
+                        &format!("{previous} {subword}"),
+                        BIGRAM_WEIGHT,
+                    );
+                }
+                previous = Some(subword);
+            }
+        }
+        if features.is_empty() {
+            // Keep blank and keyword-only texts unit length
+            add_feature(&mut features, b'e', "", TOKEN_WEIGHT);
+        }
+
+        let mut vector = vec![0.0f32; self.dimensions];
+        for (hash, weight) in features {
+            let bucket = (hash % self.dimensions as u64) as usize;
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            vector[bucket] += sign * weight.sqrt();
+        }
+
+        let magnitude: f32 = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if magnitude > 0.0 {
+            for val in &mut vector {
+                *val /= magnitude;
+            }
+        }
+        vector
+    }
+}
+
+impl Default for HashingEmbeddingProvider {
+    fn default() -> Self {
+        Self::new(EMBEDDING_DIMENSION_HASHING_DEFAULT)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for HashingEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        Ok(texts
+            .iter()
+            .map(|text| Embedding {
+                vector: self.embed_text(text),
+                model: HASHING_EMBEDDING_MODEL.to_string(),
+                dimensions: self.dimensions,
+            })
+            .collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn provider_name(&self) -> &str {
+        "hashing"
+    }
+}
+
+/// Add `weight` to the bucket of one feature
+fn add_feature(features: &mut HashMap<u64, f32>, kind: u8, feature: &str, weight: f32) {
+    *features.entry(feature_hash(kind, feature)).or_default() += weight;
+}
+
+/// Split an identifier into lowercase subwords
+///
+/// `parseHTTPResponse2_fast` becomes `parse`, `http`, `response`, `2`, `fast`.
+fn split_identifier(identifier: &str) -> Vec<String> {
+    let mut subwords = Vec::new();
+    for part in identifier.split('_').filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut current = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            if let Some(&prev) = i.checked_sub(1).and_then(|p| chars.get(p)) {
+                let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                let boundary = (prev.is_lowercase() && c.is_uppercase())
+                    || (prev.is_uppercase() && c.is_uppercase() && next_lower)
+                    || (prev.is_ascii_digit() != c.is_ascii_digit());
+                if boundary && !current.is_empty() {
+                    subwords.push(std::mem::take(&mut current));
+                }
+            }
+            current.extend(c.to_lowercase());
+        }
+        if !current.is_empty() {
+            subwords.push(current);
+        }
+    }
+    subwords
+}
+
+/// Character trigrams of `word` with boundary markers
+fn trigrams(word: &str) -> Vec<String> {
+    let chars: Vec<char> = std::iter::once('<')
+        .chain(word.chars())
+        .chain(std::iter::once('>'))
+        .collect();
+    chars.windows(3).map(|w| w.iter().collect()).collect()
+}
+
+/// Stable 64-bit hash of a feature
+///
+/// FNV-1a followed by the SplitMix64 finalizer; unlike the standard
+/// library hasher the output never changes between builds.
+fn feature_hash(kind: u8, feature: &str) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET;
+    for byte in std::iter::once(kind).chain(feature.bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+
+use mcb_application::ports::registry::{
+    EMBEDDING_PROVIDERS, EmbeddingProviderConfig, EmbeddingProviderEntry,
+};
+use mcb_domain::ports::providers::EmbeddingProvider as EmbeddingProviderPort;
+
+/// Factory function for creating hashing embedding provider instances.
+fn hashing_factory(
+    config: &EmbeddingProviderConfig,
+) -> std::result::Result<Arc<dyn EmbeddingProviderPort>, String> {
+    let dimensions = config
+        .dimensions
+        .unwrap_or(EMBEDDING_DIMENSION_HASHING_DEFAULT);
+    if dimensions == 0 {
+        return Err("Hashing embedding dimensions must be greater than zero".to_string());
+    }
+    Ok(Arc::new(HashingEmbeddingProvider::new(dimensions)))
+}
+
+#[linkme::distributed_slice(EMBEDDING_PROVIDERS)]
+static HASHING_PROVIDER: EmbeddingProviderEntry = EmbeddingProviderEntry {
+    name: "hashing",
+    description: "Offline feature-hashing provider (deterministic, no model files)",
+    factory: hashing_factory,
+};
@@ -0,0 +1,278 @@
+//! Local FastEmbed models
+//!
+//! Loads an ONNX embedding model and its tokenizer from a directory instead
+//! of downloading one of the built-in FastEmbed models, so an approved model
+//! can be vendored into an image and used without network access.
+//!
+//! The directory holds the files of a Hugging Face ONNX export:
+//!
+//! | File | Purpose |
+//! |------|---------|
+//! | `model.onnx` or `onnx/model.onnx` | Model weights |
+//! | `tokenizer.json` | Tokenizer |
+//! | `config.json` | Model config |
+//! | `special_tokens_map.json` | Special tokens |
+//! | `tokenizer_config.json` | Tokenizer config |
+//!
+//! An optional manifest in `sha256sum` format is checked before loading;
+//! every file above must be listed in it.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use fastembed::{Pooling, TokenizerFiles, UserDefinedEmbeddingModel};
+use sha2::{Digest, Sha256};
+
+use mcb_domain::error::{Error, Result};
+
+/// ONNX weight locations tried in order
+const ONNX_FILES: [&str; 2] = ["model.onnx", "onnx/model.onnx"];
+const TOKENIZER_FILE: &str = "tokenizer.json";
+const CONFIG_FILE: &str = "config.json";
+const SPECIAL_TOKENS_MAP_FILE: &str = "special_tokens_map.json";
+const TOKENIZER_CONFIG_FILE: &str = "tokenizer_config.json";
+
+/// Pooling of token embeddings into one vector
+///
+/// Must match the pooling the model was trained with; check the model card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LocalModelPooling {
+    /// Average over all tokens (sentence-transformers models)
+    #[default]
+    Mean,
+    /// Embedding of the first token (BGE models)
+    Cls,
+}
+
+impl FromStr for LocalModelPooling {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mean" => Ok(Self::Mean),
+            "cls" => Ok(Self::Cls),
+            other => Err(format!(
+                "Unknown pooling '{other}', expected 'mean' or 'cls'"
+            )),
+        }
+    }
+}
+
+impl From<LocalModelPooling> for Pooling {
+    fn from(pooling: LocalModelPooling) -> Self {
+        match pooling {
+            LocalModelPooling::Mean => Pooling::Mean,
+            LocalModelPooling::Cls => Pooling::Cls,
+        }
+    }
+}
+
+/// A FastEmbed model stored in a local directory
+#[derive(Debug, Clone)]
+pub struct LocalModelOptions {
+    /// Directory holding the model files
+    pub dir: PathBuf,
+    /// Model name reported with every embedding
+    pub model_name: String,
+    /// Dimensions of the model output
+    pub dimensions: usize,
+    /// Pooling of token embeddings
+    pub pooling: LocalModelPooling,
+    /// Maximum input length in tokens; longer inputs are truncated
+    pub max_length: usize,
+    /// Checksum manifest, relative to `dir` unless absolute
+    pub checksums: Option<PathBuf>,
+}
+
+impl LocalModelOptions {
+    /// Options for the model in `dir`
+    ///
+    /// The model name defaults to the directory name, pooling to mean and
+    /// the maximum length to 512 tokens.
+    pub fn new(dir: impl Into<PathBuf>, dimensions: usize) -> Self {
+        let dir = dir.into();
+        let model_name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "local".to_string());
+        Self {
+            dir,
+            model_name,
+            dimensions,
+            pooling: LocalModelPooling::default(),
+            max_length: 512,
+            checksums: None,
+        }
+    }
+
+    /// Set the model name
+    pub fn with_model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+
+    /// Set the pooling
+    pub fn with_pooling(mut self, pooling: LocalModelPooling) -> Self {
+        self.pooling = pooling;
+        self
+    }
+
+    /// Set the maximum input length in tokens
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Verify the model files against a checksum manifest
+    pub fn with_checksums(mut self, checksums: impl Into<PathBuf>) -> Self {
+        self.checksums = Some(checksums.into());
+        self
+    }
+
+    /// Path of the ONNX weights
+    fn onnx_path(&self) -> Result<PathBuf> {
+        ONNX_FILES
+            .iter()
+            .map(|file| self.dir.join(file))
+            .find(|path| path.is_file())
+            .ok_or_else(|| {
+                Error::embedding(format!(
+                    "No model.onnx or onnx/model.onnx in {}",
+                    self.dir.display()
+                ))
+            })
+    }
+
+    /// Paths of every file the model is built from, relative to `dir`
+    ///
+    /// The ONNX weights come first, followed by the tokenizer, config,
+    /// special tokens map and tokenizer config.
+    fn model_files(&self) -> Result<[PathBuf; 5]> {
+        let onnx = self.onnx_path()?;
+        Ok([
+            onnx.strip_prefix(&self.dir).unwrap_or(&onnx).to_path_buf(),
+            PathBuf::from(TOKENIZER_FILE),
+            PathBuf::from(CONFIG_FILE),
+            PathBuf::from(SPECIAL_TOKENS_MAP_FILE),
+            PathBuf::from(TOKENIZER_CONFIG_FILE),
+        ])
+    }
+
+    /// Read every model file once, in [`Self::model_files`] order
+    fn read_model_files(&self) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+        self.model_files()?
+            .into_iter()
+            .map(|file| {
+                let contents = read(&self.dir.join(&file))?;
+                Ok((file, contents))
+            })
+            .collect()
+    }
+
+    /// Check every model file against the checksum manifest
+    ///
+    /// Does nothing when no manifest is configured. Fails when a model file
+    /// is missing from the manifest or its SHA-256 differs.
+    pub fn verify_checksums(&self) -> Result<()> {
+        if self.checksums.is_none() {
+            return Ok(());
+        }
+        self.check_contents(&self.read_model_files()?)
+    }
+
+    /// Check file contents already in memory against the manifest
+    fn check_contents(&self, files: &[(PathBuf, Vec<u8>)]) -> Result<()> {
+        let Some(manifest) = &self.checksums else {
+            return Ok(());
+        };
+        let manifest_path = self.dir.join(manifest);
+        let contents = std::fs::read_to_string(&manifest_path).map_err(|e| {
+            Error::io_with_source(
+                format!(
+                    "Failed to read checksum manifest {}",
+                    manifest_path.display()
+                ),
+                e,
+            )
+        })?;
+        let expected = parse_manifest(&contents)?;
+
+        for (file, contents) in files {
+            let name = file.to_string_lossy().replace('\\', "/");
+            let digest = expected.get(&name).ok_or_else(|| {
+                Error::embedding(format!(
+                    "Model file {name} is not listed in {}",
+                    manifest_path.display()
+                ))
+            })?;
+            let actual = hex::encode(Sha256::digest(contents));
+            if !actual.eq_ignore_ascii_case(digest) {
+                return Err(Error::embedding(format!(
+                    "Checksum mismatch for {name}: expected {digest}, found {actual}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the model files into a FastEmbed user-defined model
+    ///
+    /// Each file is read once; the bytes checked against the manifest are
+    /// the bytes the model is built from, so a file replaced in between
+    /// cannot slip past the check.
+    pub(crate) fn load(&self) -> Result<UserDefinedEmbeddingModel> {
+        let files = self.read_model_files()?;
+        self.check_contents(&files)?;
+        let contents: Vec<Vec<u8>> = files.into_iter().map(|(_, contents)| contents).collect();
+        let Ok(
+            [
+                onnx_file,
+                tokenizer_file,
+                config_file,
+                special_tokens_map_file,
+                tokenizer_config_file,
+            ],
+        ) = <[Vec<u8>; 5]>::try_from(contents)
+        else {
+            return Err(Error::embedding("Incomplete model files"));
+        };
+        let tokenizer_files = TokenizerFiles {
+            tokenizer_file,
+            config_file,
+            special_tokens_map_file,
+            tokenizer_config_file,
+        };
+        Ok(UserDefinedEmbeddingModel::new(onnx_file, tokenizer_files)
+            .with_pooling(self.pooling.into()))
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        Error::io_with_source(format!("Failed to read model file {}", path.display()), e)
+    })
+}
+
+/// Parse `sha256sum` output into file name and hex digest pairs
+fn parse_manifest(contents: &str) -> Result<HashMap<String, String>> {
+    let mut entries = HashMap::new();
+    for line in contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    {
+        let (digest, name) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| Error::embedding(format!("Malformed checksum line: {line}")))?;
+        let name = name.trim_start().trim_start_matches('*');
+        let name = name.strip_prefix("./").unwrap_or(name);
+        if digest.len() != 64 || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::embedding(format!(
+                "Malformed SHA-256 digest for {name}"
+            )));
+        }
+        entries.insert(name.to_string(), digest.to_string());
+    }
+    Ok(entries)
+}
@@ -8,8 +8,11 @@
 //! | Provider | Type | Status |
 //! |----------|------|--------|
 //! | NullEmbeddingProvider | Testing | Complete |
+//! | HashingEmbeddingProvider | Offline | Complete |
 //! | OllamaEmbeddingProvider | Local | Complete |
 //! | OpenAIEmbeddingProvider | Cloud | Complete |
+//! | AzureOpenAIEmbeddingProvider | Cloud | Complete |
+//! | OpenAICompatibleEmbeddingProvider | Gateway | Complete |
 //! | VoyageAIEmbeddingProvider | Cloud | Complete |
 //! | GeminiEmbeddingProvider | Cloud | Complete |
 //! | FastEmbedProvider | Local ML | Complete (optional) |
@@ -18,31 +21,45 @@
 //!
 //! ### Development/Testing
 //! - **Default**: Use `NullEmbeddingProvider` for unit tests
+//! - **Hashing**: Use `HashingEmbeddingProvider` for offline end-to-end search
 //!
 //! ### Local/Privacy-First
 //! - **Ollama**: Local LLM server with embedding models
-//! - **FastEmbed**: Pure local ONNX inference (requires `embedding-fastembed` feature)
+//! - **FastEmbed**: Pure local ONNX inference (requires `embedding-fastembed` feature),
+//!   from a built-in model or a vendored model directory
 //!
 //! ### Cloud/Production
 //! - **OpenAI**: High quality, widely adopted
+//! - **Azure OpenAI**: OpenAI models served from Azure deployments
+//! - **OpenAI-compatible**: Internal gateways and self-hosted servers
 //! - **VoyageAI**: Optimized for code embeddings
 //! - **Gemini**: Google ecosystem integration
 
+pub mod azure_openai;
 #[cfg(feature = "embedding-fastembed")]
 pub mod fastembed;
 pub mod gemini;
+pub mod hashing;
 pub mod helpers;
+#[cfg(feature = "embedding-fastembed")]
+pub mod local_model;
 pub mod null;
 pub mod ollama;
 pub mod openai;
+pub mod openai_compatible;
 pub mod voyageai;
 
 // Re-export for convenience
+pub use azure_openai::AzureOpenAIEmbeddingProvider;
 #[cfg(feature = "embedding-fastembed")]
 pub use fastembed::FastEmbedProvider;
 pub use gemini::GeminiEmbeddingProvider;
+pub use hashing::HashingEmbeddingProvider;
 pub use helpers::constructor;
+#[cfg(feature = "embedding-fastembed")]
+pub use local_model::{LocalModelOptions, LocalModelPooling};
 pub use null::NullEmbeddingProvider;
 pub use ollama::OllamaEmbeddingProvider;
 pub use openai::OpenAIEmbeddingProvider;
+pub use openai_compatible::OpenAICompatibleEmbeddingProvider;
 pub use voyageai::VoyageAIEmbeddingProvider;
@@ -124,13 +124,13 @@ impl OpenAIEmbeddingProvider {
             .await
             .map_err(|e| {
                 if e.is_timeout() {
-                    Error::embedding(format!(
+                    Error::network(format!(
                         "{} {:?}",
                         crate::constants::ERROR_MSG_REQUEST_TIMEOUT,
                         self.timeout
                     ))
                 } else {
-                    Error::embedding(format!("HTTP request failed: {}", e))
+                    Error::network(format!("HTTP request failed: {}", e))
                 }
             })?;
 
@@ -0,0 +1,361 @@
+//! OpenAI-Compatible Embedding Provider
+//!
+//! Implements the EmbeddingProvider port for gateways and self-hosted servers
+//! that expose an OpenAI-style embeddings endpoint with their own URL layout,
+//! authentication headers or response shape.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::Value;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+
+use crate::constants::{
+    CONTENT_TYPE_JSON, OPENAI_COMPATIBLE_DEFAULT_PATH, OPENAI_RESPONSE_VECTORS_PATH,
+};
+use crate::embedding::helpers::constructor;
+use crate::utils::HttpResponseUtils;
+
+/// Embedding provider for OpenAI-compatible endpoints
+///
+/// Posts `{"input": [...], "model": ...}` to `base_url` joined with a path
+/// template, where `{model}` is replaced by the model name. Vectors are read
+/// from the response at a JSON path such as `data[*].embedding` (the
+/// default) or `embeddings`; `[*]` selects every element of an array and
+/// `[N]` a single one.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use mcb_providers::embedding::OpenAICompatibleEmbeddingProvider;
+/// use reqwest::Client;
+/// use std::time::Duration;
+///
+/// let provider = OpenAICompatibleEmbeddingProvider::new(
+///     "https://gateway.internal/ml".to_string(),
+///     768,
+///     Duration::from_secs(30),
+///     Client::new(),
+/// )
+/// .with_model("nomic-embed-text")
+/// .with_path("/v2/models/{model}/embed")
+/// .with_header("X-Team", "search")
+/// .with_response_path("result.vectors");
+/// ```
+pub struct OpenAICompatibleEmbeddingProvider {
+    base_url: String,
+    path: String,
+    model: Option<String>,
+    dimensions: usize,
+    headers: Vec<(String, String)>,
+    response_path: String,
+    timeout: Duration,
+    http_client: Client,
+}
+
+impl OpenAICompatibleEmbeddingProvider {
+    /// Create a provider for the endpoint at `base_url`
+    ///
+    /// # Arguments
+    /// * `base_url` - Endpoint root, without the embeddings path
+    /// * `dimensions` - Dimensions of the returned vectors
+    /// * `timeout` - Request timeout duration
+    /// * `http_client` - Reqwest HTTP client for making API requests
+    pub fn new(
+        base_url: String,
+        dimensions: usize,
+        timeout: Duration,
+        http_client: Client,
+    ) -> Self {
+        Self {
+            base_url: base_url.trim().trim_end_matches('/').to_string(),
+            path: OPENAI_COMPATIBLE_DEFAULT_PATH.to_string(),
+            model: None,
+            dimensions,
+            headers: Vec::new(),
+            response_path: OPENAI_RESPONSE_VECTORS_PATH.to_string(),
+            timeout,
+            http_client,
+        }
+    }
+
+    /// Set the model sent with every request
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Set the path template appended to the base URL
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Add a header sent with every request
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send `api_key` as a bearer token
+    pub fn with_bearer_token(self, api_key: &str) -> Self {
+        let api_key = constructor::validate_api_key(api_key);
+        self.with_header("Authorization", format!("Bearer {api_key}"))
+    }
+
+    /// Set the JSON path of the vectors in the response
+    pub fn with_response_path(mut self, response_path: impl Into<String>) -> Self {
+        self.response_path = response_path.into();
+        self
+    }
+
+    /// Get the model name
+    pub fn model(&self) -> &str {
+        self.model.as_deref().unwrap_or("openai-compatible")
+    }
+
+    /// Full request URL
+    pub fn url(&self) -> String {
+        let path = self
+            .path
+            .replace("{model}", self.model.as_deref().unwrap_or(""));
+        if path.starts_with('/') || path.is_empty() {
+            format!("{}{}", self.base_url, path)
+        } else {
+            format!("{}/{}", self.base_url, path)
+        }
+    }
+
+    async fn fetch_embeddings(&self, texts: &[String]) -> Result<Value> {
+        let mut payload = serde_json::json!({ "input": texts });
+        if let Some(model) = &self.model {
+            payload["model"] = Value::String(model.clone());
+        }
+
+        let mut request = self
+            .http_client
+            .post(self.url())
+            .header("Content-Type", CONTENT_TYPE_JSON)
+            .timeout(self.timeout);
+        for (name, value) in &self.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+
+        let response = request.json(&payload).send().await.map_err(|e| {
+            if e.is_timeout() {
+                Error::network(format!(
+                    "{} {:?}",
+                    crate::constants::ERROR_MSG_REQUEST_TIMEOUT,
+                    self.timeout
+                ))
+            } else {
+                Error::network(format!("HTTP request failed: {}", e))
+            }
+        })?;
+
+        HttpResponseUtils::check_and_parse(response, "OpenAI-compatible").await
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAICompatibleEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let response_data = self.fetch_embeddings(texts).await?;
+        let vectors = extract_vectors(&response_data, &self.response_path)?;
+        to_embeddings(vectors, texts.len(), self.dimensions, self.model())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn provider_name(&self) -> &str {
+        "openai-compatible"
+    }
+}
+
+/// Check count and dimensions of response vectors and wrap them
+pub(crate) fn to_embeddings(
+    vectors: Vec<Vec<f32>>,
+    expected_count: usize,
+    dimensions: usize,
+    model: &str,
+) -> Result<Vec<Embedding>> {
+    if vectors.len() != expected_count {
+        return Err(Error::embedding(format!(
+            "Response data count mismatch: expected {}, got {}",
+            expected_count,
+            vectors.len()
+        )));
+    }
+    vectors
+        .into_iter()
+        .map(|vector| {
+            if vector.len() != dimensions {
+                return Err(Error::embedding(format!(
+                    "Endpoint returned {}-dimensional vectors but {} dimensions are configured",
+                    vector.len(),
+                    dimensions
+                )));
+            }
+            Ok(Embedding {
+                vector,
+                model: model.to_string(),
+                dimensions,
+            })
+        })
+        .collect()
+}
+
+/// Read the vectors at `path` from a response
+///
+/// A path with a `[*]` step yields one vector per match; a path without one
+/// must point at an array of vectors.
+pub(crate) fn extract_vectors(response: &Value, path: &str) -> Result<Vec<Vec<f32>>> {
+    let invalid = || Error::embedding(format!("Invalid response format: nothing at '{path}'"));
+
+    let mut nodes = vec![response];
+    let mut wildcard = false;
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        let (key, indexes) = match segment.find('[') {
+            Some(start) => (&segment[..start], &segment[start..]),
+            None => (segment, ""),
+        };
+        if !key.is_empty() {
+            nodes = nodes
+                .into_iter()
+                .map(|node| node.get(key).ok_or_else(invalid))
+                .collect::<Result<_>>()?;
+        }
+        for index in indexes
+            .split_terminator(']')
+            .map(|index| index.trim_start_matches('['))
+        {
+            nodes = if index == "*" {
+                wildcard = true;
+                nodes
+                    .into_iter()
+                    .map(|node| node.as_array().ok_or_else(invalid))
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            } else {
+                let index: usize = index.parse().map_err(|_| {
+                    Error::embedding(format!("Invalid index '{index}' in response path '{path}'"))
+                })?;
+                nodes
+                    .into_iter()
+                    .map(|node| node.get(index).ok_or_else(invalid))
+                    .collect::<Result<_>>()?
+            };
+        }
+    }
+
+    let vectors: Vec<&Value> = if wildcard {
+        nodes
+    } else {
+        match nodes.as_slice() {
+            [single] => single.as_array().ok_or_else(invalid)?.iter().collect(),
+            _ => return Err(invalid()),
+        }
+    };
+    vectors
+        .into_iter()
+        .enumerate()
+        .map(|(i, vector)| {
+            vector
+                .as_array()
+                .ok_or_else(|| {
+                    Error::embedding(format!("Invalid embedding format for text {}", i))
+                })?
+                .iter()
+                .map(|v| {
+                    v.as_f64().map(|v| v as f32).ok_or_else(|| {
+                        Error::embedding(format!("Non-numeric embedding value for text {}", i))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+
+use mcb_application::ports::registry::{
+    EMBEDDING_PROVIDERS, EmbeddingProviderConfig, EmbeddingProviderEntry,
+};
+use mcb_domain::ports::providers::EmbeddingProvider as EmbeddingProviderPort;
+
+/// `extra` key prefix of custom request headers, e.g. `header.X-Team`
+const HEADER_PREFIX: &str = "header.";
+
+/// Factory function for creating OpenAI-compatible embedding provider instances.
+///
+/// Requires `base_url` and `dimensions`. `api_key` is sent as a bearer token,
+/// or in the header named by `extra["api_key_header"]`. Other `extra` keys:
+/// `path`, `response_path` and `header.<Name>`.
+fn openai_compatible_factory(
+    config: &EmbeddingProviderConfig,
+) -> std::result::Result<Arc<dyn EmbeddingProviderPort>, String> {
+    let base_url = config
+        .base_url
+        .clone()
+        .ok_or("OpenAI-compatible provider requires base_url")?;
+    let dimensions = config
+        .dimensions
+        .ok_or("OpenAI-compatible provider requires dimensions")?;
+    let timeout = Duration::from_secs(30);
+    let http_client = Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let mut provider =
+        OpenAICompatibleEmbeddingProvider::new(base_url, dimensions, timeout, http_client);
+    if let Some(model) = &config.model {
+        provider = provider.with_model(model);
+    }
+    if let Some(path) = config.extra.get("path") {
+        provider = provider.with_path(path);
+    }
+    if let Some(response_path) = config.extra.get("response_path") {
+        provider = provider.with_response_path(response_path);
+    }
+    if let Some(api_key) = &config.api_key {
+        provider = match config.extra.get("api_key_header") {
+            Some(header) => provider.with_header(header, constructor::validate_api_key(api_key)),
+            None => provider.with_bearer_token(api_key),
+        };
+    }
+    let mut headers: Vec<_> = config
+        .extra
+        .iter()
+        .filter_map(|(key, value)| Some((key.strip_prefix(HEADER_PREFIX)?, value)))
+        .collect();
+    headers.sort();
+    for (name, value) in headers {
+        provider = provider.with_header(name, value);
+    }
+
+    Ok(Arc::new(provider))
+}
+
+#[linkme::distributed_slice(EMBEDDING_PROVIDERS)]
+static OPENAI_COMPATIBLE_PROVIDER: EmbeddingProviderEntry = EmbeddingProviderEntry {
+    name: "openai-compatible",
+    description: "OpenAI-compatible endpoint (custom path, headers and response shape)",
+    factory: openai_compatible_factory,
+};
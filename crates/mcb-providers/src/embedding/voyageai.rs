@@ -110,7 +110,7 @@ impl VoyageAIEmbeddingProvider {
             .json(&payload)
             .send()
             .await
-            .map_err(|e| Error::embedding(format!("HTTP request failed: {}", e)))?;
+            .map_err(|e| Error::network(format!("HTTP request failed: {}", e)))?;
 
         HttpResponseUtils::check_and_parse(response, "VoyageAI").await
     }
@@ -260,4 +260,13 @@ impl HybridSearchProvider for HybridSearchEngine {
 
         stats
     }
+
+    /// Documents of a collection in the order they were indexed
+    async fn indexed_chunks(&self, collection: &str) -> Result<Vec<CodeChunk>> {
+        let collections = self.collections.read().await;
+        Ok(collections
+            .get(collection)
+            .map(|index| index.documents.clone())
+            .unwrap_or_default())
+    }
 }
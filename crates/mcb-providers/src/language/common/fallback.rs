@@ -158,9 +158,11 @@ impl<'a> GenericFallbackChunker<'a> {
         }
 
         let chunk = CodeChunk {
-            id: format!(
-                "{}_{}_{}",
-                params.file_name, params.start_line, params.end_line
+            id: CodeChunk::content_id(
+                params.file_name,
+                params.start_line as u32,
+                params.end_line as u32,
+                &content,
             ),
             content,
             file_path: params.file_name.to_string(),
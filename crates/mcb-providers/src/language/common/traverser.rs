@@ -199,14 +199,11 @@ impl<'a> AstTraverser<'a> {
         let end_line = node.end_position().row;
 
         CodeChunk {
-            id: format!(
-                "{}_{}_{}_{}_{}_{}",
+            id: CodeChunk::content_id(
                 params.file_name,
-                params.node_type,
-                start_line,
-                end_line,
-                params.priority,
-                params.chunk_index
+                start_line as u32,
+                end_line as u32,
+                &params.content,
             ),
             content: params.content,
             file_path: params.file_name.to_string(),
@@ -219,6 +216,10 @@ impl<'a> AstTraverser<'a> {
                 meta.insert("node_type".to_string(), serde_json::json!(params.node_type));
                 meta.insert("depth".to_string(), serde_json::json!(params.depth));
                 meta.insert("priority".to_string(), serde_json::json!(params.priority));
+                meta.insert(
+                    "chunk_index".to_string(),
+                    serde_json::json!(params.chunk_index),
+                );
                 serde_json::to_value(meta).unwrap_or(serde_json::json!({}))
             },
         }
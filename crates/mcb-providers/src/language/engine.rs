@@ -156,7 +156,7 @@ impl IntelligentChunker {
             }
 
             chunks.push(CodeChunk {
-                id: format!("{}_{}", file_name, chunk_idx),
+                id: CodeChunk::content_id(file_name, start_line as u32, end_line as u32, &content),
                 content,
                 file_path: file.clone(),
                 start_line: start_line as u32,
@@ -5,12 +5,27 @@
 
 use mcb_domain::error::{Error, Result};
 use reqwest::Response;
+use reqwest::header::RETRY_AFTER;
+use std::time::Duration;
 
 /// Format error message for embedding provider
 fn embedding_error(provider: &str, context: &str, details: &str) -> Error {
     Error::embedding(format!("{provider} {context}: {details}"))
 }
 
+/// Format a retryable error message, keeping the server's `Retry-After`
+fn unavailable_error(
+    provider: &str,
+    context: &str,
+    details: &str,
+    retry_after: Option<u64>,
+) -> Error {
+    Error::unavailable(
+        format!("{provider} {context}: {details}"),
+        retry_after.map(Duration::from_secs),
+    )
+}
+
 /// Utilities for processing HTTP responses
 ///
 /// Provides common response handling patterns used by embedding providers.
@@ -24,7 +39,9 @@ impl HttpResponseUtils {
     /// * `provider_name` - Name of the provider for error messages
     ///
     /// # Returns
-    /// Parsed JSON value on success, or an appropriate error
+    /// Parsed JSON value on success, or an appropriate error. Rate limits
+    /// and server errors are [`Error::Unavailable`] carrying the server's
+    /// `Retry-After`, so retry middleware can honour it.
     pub async fn check_and_parse(
         response: Response,
         provider_name: &str,
@@ -32,6 +49,11 @@ impl HttpResponseUtils {
         let status = response.status();
 
         if !status.is_success() {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok());
             let error_text = response
                 .text()
                 .await
@@ -40,11 +62,17 @@ impl HttpResponseUtils {
 
             return Err(match code {
                 401 => embedding_error(provider_name, "authentication failed", &error_text),
-                429 => embedding_error(provider_name, "rate limit exceeded", &error_text),
-                500..=599 => embedding_error(
+                429 => unavailable_error(
+                    provider_name,
+                    "rate limit exceeded",
+                    &error_text,
+                    retry_after,
+                ),
+                500..=599 => unavailable_error(
                     provider_name,
                     &format!("server error ({code})"),
                     &error_text,
+                    retry_after,
                 ),
                 _ => embedding_error(
                     provider_name,
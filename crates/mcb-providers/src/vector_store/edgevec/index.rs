@@ -0,0 +1,198 @@
+//! HNSW index of one EdgeVec collection
+//!
+//! Maps external ids to EdgeVec vector ids in both directions, so resolving
+//! a search hit is a single hash lookup. Deletes leave a tombstone in the
+//! graph; tombstoned nodes are skipped in results until the index is
+//! rebuilt without them.
+//!
+//! The raw vectors live only in the EdgeVec vector storage; rebuilds,
+//! exports and snapshots read them back from there.
+
+use std::collections::{HashMap, HashSet};
+
+use edgevec::hnsw::VectorId;
+use edgevec::persistence::{MemoryBackend, StorageBackend, read_snapshot, write_snapshot};
+use mcb_domain::error::{Error, Result};
+
+use super::{EdgeVecConfig, hnsw_config};
+
+/// Smallest candidate list requested from the graph
+const MIN_CANDIDATES: usize = 16;
+
+pub(super) struct EdgeVecIndex {
+    index: edgevec::HnswIndex,
+    storage: edgevec::VectorStorage,
+    ids: HashMap<String, VectorId>,
+    external_ids: HashMap<VectorId, String>,
+    tombstones: HashSet<VectorId>,
+}
+
+impl EdgeVecIndex {
+    pub(super) fn new(config: &EdgeVecConfig) -> Result<Self> {
+        let hnsw_config = hnsw_config(config);
+        let storage = edgevec::VectorStorage::new(&hnsw_config, None);
+        let index = edgevec::HnswIndex::new(hnsw_config, &storage)
+            .map_err(|e| Error::internal(format!("Failed to create EdgeVec HNSW index: {}", e)))?;
+
+        Ok(Self {
+            index,
+            storage,
+            ids: HashMap::new(),
+            external_ids: HashMap::new(),
+            tombstones: HashSet::new(),
+        })
+    }
+
+    /// Build an index holding exactly `entries`
+    pub(super) fn build(config: &EdgeVecConfig, entries: Vec<(String, Vec<f32>)>) -> Result<Self> {
+        let mut index = Self::new(config)?;
+        for (id, vector) in entries {
+            index.insert(id, &vector)?;
+        }
+        Ok(index)
+    }
+
+    /// Restore an index from [`Self::to_bytes`] output, its id mapping and
+    /// its tombstoned nodes
+    pub(super) fn from_bytes(
+        bytes: &[u8],
+        ids: Vec<(String, u64)>,
+        tombstones: Vec<u64>,
+    ) -> Result<Self> {
+        let mut backend = MemoryBackend::new();
+        backend
+            .append(bytes)
+            .map_err(|e| Error::vector_db(format!("Failed to read EdgeVec graph: {}", e)))?;
+        let (index, storage) = read_snapshot(&backend)
+            .map_err(|e| Error::vector_db(format!("Failed to read EdgeVec graph: {}", e)))?;
+
+        let ids: HashMap<String, VectorId> = ids
+            .into_iter()
+            .map(|(id, vector_id)| (id, VectorId(vector_id)))
+            .collect();
+        let external_ids = ids.iter().map(|(id, &v)| (v, id.clone())).collect();
+        let tombstones = tombstones.into_iter().map(VectorId).collect();
+        Ok(Self {
+            index,
+            storage,
+            ids,
+            external_ids,
+            tombstones,
+        })
+    }
+
+    /// Serialize the HNSW graph and the vector storage, including any
+    /// quantized data
+    pub(super) fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut backend = MemoryBackend::new();
+        write_snapshot(&self.index, &self.storage, &mut backend)
+            .map_err(|e| Error::vector_db(format!("Failed to serialize EdgeVec graph: {}", e)))?;
+        backend
+            .read()
+            .map_err(|e| Error::vector_db(format!("Failed to serialize EdgeVec graph: {}", e)))
+    }
+
+    /// External ids with their graph node
+    pub(super) fn id_map(&self) -> Vec<(String, u64)> {
+        self.ids.iter().map(|(id, v)| (id.clone(), v.0)).collect()
+    }
+
+    /// Graph nodes of deleted vectors
+    pub(super) fn tombstone_ids(&self) -> Vec<u64> {
+        self.tombstones.iter().map(|v| v.0).collect()
+    }
+
+    /// Stored vector of `id`
+    pub(super) fn vector(&self, id: &str) -> Option<Vec<f32>> {
+        self.ids
+            .get(id)
+            .map(|&vector_id| self.storage.get_vector(vector_id).to_vec())
+    }
+
+    /// Copy of every live vector, for rebuilding the graph elsewhere
+    pub(super) fn live_vectors(&self) -> Vec<(String, Vec<f32>)> {
+        self.ids
+            .iter()
+            .map(|(id, &vector_id)| (id.clone(), self.storage.get_vector(vector_id).to_vec()))
+            .collect()
+    }
+
+    /// Insert a vector, replacing any previous vector with the same id
+    pub(super) fn insert(&mut self, id: String, vector: &[f32]) -> Result<()> {
+        self.remove(&id);
+        let vector_id = self
+            .index
+            .insert(vector, &mut self.storage)
+            .map_err(|e| Error::internal(format!("Failed to insert vector: {}", e)))?;
+        self.ids.insert(id.clone(), vector_id);
+        self.external_ids.insert(vector_id, id);
+        Ok(())
+    }
+
+    /// Tombstone the vector with `id`; returns whether it was present
+    pub(super) fn remove(&mut self, id: &str) -> bool {
+        let Some(vector_id) = self.ids.remove(id) else {
+            return false;
+        };
+        self.external_ids.remove(&vector_id);
+        let _ = self.index.soft_delete(vector_id);
+        self.tombstones.insert(vector_id);
+        true
+    }
+
+    /// Nearest live vectors to `query`, as external id and distance
+    ///
+    /// The candidate list is widened until `limit` live hits are found or
+    /// the whole graph has been considered.
+    pub(super) fn search(&self, query: &[f32], limit: usize) -> Result<Vec<(&str, f32)>> {
+        if self.ids.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let total = self.ids.len() + self.tombstones.len();
+        let mut candidates = if self.tombstones.is_empty() {
+            limit
+        } else {
+            (limit * 2).max(MIN_CANDIDATES)
+        };
+        loop {
+            candidates = candidates.min(total);
+            let results = self
+                .index
+                .search(query, candidates, &self.storage)
+                .map_err(|e| Error::internal(format!("Search failed: {}", e)))?;
+            let hits: Vec<(&str, f32)> = results
+                .iter()
+                .filter_map(|res| {
+                    self.external_ids
+                        .get(&res.vector_id)
+                        .map(|id| (id.as_str(), res.distance))
+                })
+                .take(limit)
+                .collect();
+            if hits.len() == limit || candidates == total {
+                return Ok(hits);
+            }
+            candidates *= 2;
+        }
+    }
+
+    /// Number of live vectors
+    pub(super) fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Number of tombstoned nodes still in the graph
+    pub(super) fn tombstones(&self) -> usize {
+        self.tombstones.len()
+    }
+
+    /// Whether enough of the graph is tombstoned to rebuild it
+    pub(super) fn needs_compaction(&self, config: &EdgeVecConfig) -> bool {
+        let tombstones = self.tombstones.len();
+        config.compaction_threshold > 0.0
+            && tombstones >= config.compaction_min_tombstones.max(1)
+            && tombstones as f64
+                >= config.compaction_threshold * (self.ids.len() + tombstones) as f64
+    }
+}
@@ -0,0 +1,1802 @@
+//! EdgeVec Vector Store Provider
+//!
+//! High-performance embedded vector database implementation using EdgeVec.
+//! EdgeVec provides sub-millisecond vector similarity search with HNSW algorithm.
+//! This implementation uses the Actor pattern to eliminate locks and ensure non-blocking operation.
+//!
+//! With a data directory configured, every collection is saved to its own
+//! snapshot file on flush and loaded again on first use after a restart.
+//! Snapshot reads and writes run on blocking threads; messages for a
+//! collection that is still loading wait until it is ready while other
+//! collections keep being served.
+
+mod index;
+mod snapshot;
+
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::constants::{
+    EDGEVEC_COMPACTION_MIN_TOMBSTONES, EDGEVEC_COMPACTION_THRESHOLD, EDGEVEC_DEFAULT_DIMENSIONS,
+    EDGEVEC_FLUSH_INTERVAL_SECS, EDGEVEC_HNSW_EF_CONSTRUCTION, EDGEVEC_HNSW_EF_SEARCH,
+    EDGEVEC_HNSW_M, EDGEVEC_HNSW_M0,
+};
+use crate::utils::JsonExt;
+use index::EdgeVecIndex;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
+use snapshot::{SnapshotBody, SnapshotEntry, SnapshotHeader};
+
+/// EdgeVec vector store configuration
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct EdgeVecConfig {
+    /// Vector dimensionality
+    #[serde(default = "default_dimensions")]
+    pub dimensions: usize,
+
+    /// HNSW parameters for index optimization
+    #[serde(default)]
+    pub hnsw_config: HnswConfig,
+
+    /// Distance metric to use
+    #[serde(default)]
+    pub metric: MetricType,
+
+    /// Whether to use quantization for memory optimization
+    #[serde(default)]
+    pub use_quantization: bool,
+
+    /// Quantization configuration
+    #[serde(default)]
+    pub quantizer_config: QuantizerConfig,
+
+    /// Directory collections are persisted to; in-memory only when unset
+    #[serde(default)]
+    pub data_dir: Option<PathBuf>,
+
+    /// Seconds between background flushes of modified collections (0 disables)
+    #[serde(default = "default_flush_interval_secs")]
+    pub flush_interval_secs: u64,
+
+    /// Fraction of deleted nodes in a collection's graph that triggers a
+    /// background rebuild (0 disables)
+    #[serde(default = "default_compaction_threshold")]
+    pub compaction_threshold: f64,
+
+    /// Deleted nodes required before a rebuild is considered
+    #[serde(default = "default_compaction_min_tombstones")]
+    pub compaction_min_tombstones: usize,
+}
+
+fn default_dimensions() -> usize {
+    EDGEVEC_DEFAULT_DIMENSIONS
+}
+
+fn default_flush_interval_secs() -> u64 {
+    EDGEVEC_FLUSH_INTERVAL_SECS
+}
+
+fn default_compaction_threshold() -> f64 {
+    EDGEVEC_COMPACTION_THRESHOLD
+}
+
+fn default_compaction_min_tombstones() -> usize {
+    EDGEVEC_COMPACTION_MIN_TOMBSTONES
+}
+
+/// HNSW configuration for EdgeVec
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct HnswConfig {
+    /// Maximum connections per node in layers > 0
+    #[serde(default = "default_m")]
+    pub m: u32,
+
+    /// Maximum connections per node in layer 0
+    #[serde(default = "default_m0")]
+    pub m0: u32,
+
+    /// Construction-time candidate list size
+    #[serde(default = "default_ef_construction")]
+    pub ef_construction: u32,
+
+    /// Search-time candidate list size
+    #[serde(default = "default_ef_search")]
+    pub ef_search: u32,
+}
+
+fn default_m() -> u32 {
+    EDGEVEC_HNSW_M
+}
+fn default_m0() -> u32 {
+    EDGEVEC_HNSW_M0
+}
+fn default_ef_construction() -> u32 {
+    EDGEVEC_HNSW_EF_CONSTRUCTION
+}
+fn default_ef_search() -> u32 {
+    EDGEVEC_HNSW_EF_SEARCH
+}
+
+impl Default for HnswConfig {
+    fn default() -> Self {
+        Self {
+            m: default_m(),
+            m0: default_m0(),
+            ef_construction: default_ef_construction(),
+            ef_search: default_ef_search(),
+        }
+    }
+}
+
+/// Distance metrics supported by EdgeVec
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema, Default)]
+pub enum MetricType {
+    /// L2 Squared (Euclidean) distance
+    L2Squared,
+    /// Cosine similarity
+    #[default]
+    Cosine,
+    /// Dot product
+    DotProduct,
+}
+
+/// Quantization configuration for memory optimization
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct QuantizerConfig {
+    /// Quantization type - only ScalarQuantization is available in v0.6.0
+    #[serde(default)]
+    pub quantization_type: String,
+}
+
+impl Default for QuantizerConfig {
+    fn default() -> Self {
+        Self {
+            quantization_type: "scalar".to_string(),
+        }
+    }
+}
+
+impl Default for EdgeVecConfig {
+    fn default() -> Self {
+        Self {
+            dimensions: default_dimensions(),
+            hnsw_config: HnswConfig::default(),
+            metric: MetricType::default(),
+            use_quantization: false,
+            quantizer_config: QuantizerConfig::default(),
+            data_dir: None,
+            flush_interval_secs: default_flush_interval_secs(),
+            compaction_threshold: default_compaction_threshold(),
+            compaction_min_tombstones: default_compaction_min_tombstones(),
+        }
+    }
+}
+
+/// Core collection management messages
+enum CoreMessage {
+    CreateCollection {
+        name: String,
+        tx: oneshot::Sender<Result<()>>,
+    },
+    DeleteCollection {
+        name: String,
+        tx: oneshot::Sender<Result<()>>,
+    },
+    InsertVectors {
+        collection: String,
+        vectors: Vec<Embedding>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+        tx: oneshot::Sender<Result<Vec<String>>>,
+    },
+    UpsertVectors {
+        collection: String,
+        ids: Vec<String>,
+        vectors: Vec<Embedding>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+        tx: oneshot::Sender<Result<()>>,
+    },
+    SearchSimilar {
+        collection: String,
+        query_vector: Vec<f32>,
+        limit: usize,
+        tx: oneshot::Sender<Result<Vec<SearchResult>>>,
+    },
+    DeleteVectors {
+        collection: String,
+        ids: Vec<String>,
+        tx: oneshot::Sender<Result<()>>,
+    },
+    DeleteByFilter {
+        collection: String,
+        filter: MetadataFilter,
+        tx: oneshot::Sender<Result<u64>>,
+    },
+    Flush {
+        collection: String,
+        tx: oneshot::Sender<Result<()>>,
+    },
+}
+
+/// Query and stats messages
+enum QueryMessage {
+    GetStats {
+        collection: String,
+        tx: oneshot::Sender<Result<HashMap<String, serde_json::Value>>>,
+    },
+    ListVectors {
+        collection: String,
+        limit: usize,
+        tx: oneshot::Sender<Result<Vec<SearchResult>>>,
+    },
+    ExportVectors {
+        collection: String,
+        tx: oneshot::Sender<Result<Vec<StoredVector>>>,
+    },
+    ExportVectorsPage {
+        collection: String,
+        after: Option<String>,
+        limit: usize,
+        tx: oneshot::Sender<Result<Vec<StoredVector>>>,
+    },
+    GetVectorsByIds {
+        collection: String,
+        ids: Vec<String>,
+        tx: oneshot::Sender<Result<Vec<SearchResult>>>,
+    },
+    CollectionExists {
+        name: String,
+        tx: oneshot::Sender<Result<bool>>,
+    },
+}
+
+/// Browse API messages
+enum BrowseMessage {
+    ListCollections {
+        tx: oneshot::Sender<Result<Vec<CollectionInfo>>>,
+    },
+    ListFilePaths {
+        collection: String,
+        limit: usize,
+        tx: oneshot::Sender<Result<Vec<FileInfo>>>,
+    },
+    GetChunksByFile {
+        collection: String,
+        file_path: String,
+        tx: oneshot::Sender<Result<Vec<SearchResult>>>,
+    },
+}
+
+/// Messages the actor sends itself from background tasks
+enum MaintenanceMessage {
+    IndexRebuilt {
+        collection: String,
+        generation: u64,
+        index: Result<EdgeVecIndex>,
+    },
+    CollectionLoaded {
+        collection: String,
+        loaded: Result<EdgeVecCollection>,
+    },
+    FlushFailed {
+        collection: String,
+    },
+}
+
+/// Messages for the EdgeVec actor - categorized for OCP compliance
+enum EdgeVecMessage {
+    Core(CoreMessage),
+    Query(QueryMessage),
+    Browse(BrowseMessage),
+    Maintenance(MaintenanceMessage),
+}
+
+impl EdgeVecMessage {
+    /// Collection the message operates on
+    fn collection(&self) -> Option<&str> {
+        match self {
+            Self::Core(
+                CoreMessage::CreateCollection { name, .. }
+                | CoreMessage::DeleteCollection { name, .. },
+            )
+            | Self::Query(QueryMessage::CollectionExists { name, .. }) => Some(name.as_str()),
+            Self::Core(
+                CoreMessage::InsertVectors { collection, .. }
+                | CoreMessage::UpsertVectors { collection, .. }
+                | CoreMessage::SearchSimilar { collection, .. }
+                | CoreMessage::DeleteVectors { collection, .. }
+                | CoreMessage::DeleteByFilter { collection, .. }
+                | CoreMessage::Flush { collection, .. },
+            )
+            | Self::Query(
+                QueryMessage::GetStats { collection, .. }
+                | QueryMessage::ListVectors { collection, .. }
+                | QueryMessage::ExportVectors { collection, .. }
+                | QueryMessage::ExportVectorsPage { collection, .. }
+                | QueryMessage::GetVectorsByIds { collection, .. },
+            )
+            | Self::Browse(
+                BrowseMessage::ListFilePaths { collection, .. }
+                | BrowseMessage::GetChunksByFile { collection, .. },
+            ) => Some(collection.as_str()),
+            Self::Browse(BrowseMessage::ListCollections { .. }) | Self::Maintenance(_) => None,
+        }
+    }
+
+    /// Whether handling the message reads or changes the collection's data
+    ///
+    /// Deleting, flushing or checking for an unloaded collection does not
+    /// need its snapshot.
+    fn needs_data(&self) -> bool {
+        !matches!(
+            self,
+            Self::Core(CoreMessage::DeleteCollection { .. } | CoreMessage::Flush { .. })
+                | Self::Query(QueryMessage::CollectionExists { .. })
+                | Self::Maintenance(_)
+        )
+    }
+
+    /// Answer the message with `error` without handling it
+    fn reject(self, error: Error) {
+        match self {
+            Self::Core(core) => match core {
+                CoreMessage::CreateCollection { tx, .. }
+                | CoreMessage::DeleteCollection { tx, .. }
+                | CoreMessage::UpsertVectors { tx, .. }
+                | CoreMessage::DeleteVectors { tx, .. }
+                | CoreMessage::Flush { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+                CoreMessage::InsertVectors { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+                CoreMessage::SearchSimilar { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+                CoreMessage::DeleteByFilter { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+            },
+            Self::Query(query) => match query {
+                QueryMessage::GetStats { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+                QueryMessage::ListVectors { tx, .. } | QueryMessage::GetVectorsByIds { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+                QueryMessage::ExportVectors { tx, .. }
+                | QueryMessage::ExportVectorsPage { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+                QueryMessage::CollectionExists { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+            },
+            Self::Browse(browse) => match browse {
+                BrowseMessage::ListCollections { tx } => {
+                    let _ = tx.send(Err(error));
+                }
+                BrowseMessage::ListFilePaths { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+                BrowseMessage::GetChunksByFile { tx, .. } => {
+                    let _ = tx.send(Err(error));
+                }
+            },
+            Self::Maintenance(_) => {}
+        }
+    }
+}
+
+/// EdgeVec vector store provider implementation using Actor pattern
+pub struct EdgeVecVectorStoreProvider {
+    sender: mpsc::Sender<EdgeVecMessage>,
+    #[allow(dead_code)] // Reserved for future collection-specific operations
+    collection: String,
+}
+
+impl EdgeVecVectorStoreProvider {
+    /// Create a new EdgeVec vector store provider
+    pub fn new(config: EdgeVecConfig) -> Result<Self> {
+        let (tx, rx) = mpsc::channel(100);
+        let config_clone = config.clone();
+
+        let actor = EdgeVecActor::new(rx, tx.downgrade(), config_clone)?;
+        tokio::spawn(async move {
+            actor.run().await;
+        });
+
+        Ok(Self {
+            sender: tx,
+            collection: "default".to_string(),
+        })
+    }
+
+    /// Create a new EdgeVec provider with custom collection
+    pub fn with_collection(config: EdgeVecConfig, collection: String) -> Result<Self> {
+        let mut provider = Self::new(config)?;
+        provider.collection = collection;
+        Ok(provider)
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for EdgeVecVectorStoreProvider {
+    async fn collection_exists(&self, name: &str) -> Result<bool> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Query(QueryMessage::CollectionExists {
+                name: name.to_string(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn get_stats(&self, collection: &str) -> Result<HashMap<String, serde_json::Value>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Query(QueryMessage::GetStats {
+                collection: collection.to_string(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn flush(&self, collection: &str) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::Flush {
+                collection: collection.to_string(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        let collections = VectorStoreBrowser::list_collections(self).await?;
+        Ok(collections.into_iter().map(|c| c.name).collect())
+    }
+
+    fn provider_name(&self) -> &str {
+        "edgevec"
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for EdgeVecVectorStoreProvider {
+    async fn create_collection(&self, name: &str, _dimensions: usize) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::CreateCollection {
+                name: name.to_string(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn delete_collection(&self, name: &str) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::DeleteCollection {
+                name: name.to_string(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &str,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<Vec<String>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::InsertVectors {
+                collection: collection.to_string(),
+                vectors: vectors.to_vec(),
+                metadata,
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::UpsertVectors {
+                collection: collection.to_string(),
+                ids: ids.to_vec(),
+                vectors: vectors.to_vec(),
+                metadata,
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        _filter: Option<&str>,
+    ) -> Result<Vec<SearchResult>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::SearchSimilar {
+                collection: collection.to_string(),
+                query_vector: query_vector.to_vec(),
+                limit,
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::DeleteVectors {
+                collection: collection.to_string(),
+                ids: ids.to_vec(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Core(CoreMessage::DeleteByFilter {
+                collection: collection.to_string(),
+                filter: filter.clone(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        let chunks = self.get_chunks_by_file(collection, file_path).await?;
+        Ok(chunks.into_iter().map(|chunk| chunk.id).collect())
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &str,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Query(QueryMessage::GetVectorsByIds {
+                collection: collection.to_string(),
+                ids: ids.to_vec(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Query(QueryMessage::ListVectors {
+                collection: collection.to_string(),
+                limit,
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Query(QueryMessage::ExportVectors {
+                collection: collection.to_string(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Query(QueryMessage::ExportVectorsPage {
+                collection: collection.to_string(),
+                after: after.map(str::to_string),
+                limit,
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+}
+
+#[async_trait]
+impl VectorStoreBrowser for EdgeVecVectorStoreProvider {
+    async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Browse(BrowseMessage::ListCollections {
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn list_file_paths(&self, collection: &str, limit: usize) -> Result<Vec<FileInfo>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Browse(BrowseMessage::ListFilePaths {
+                collection: collection.to_string(),
+                limit,
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+
+    async fn get_chunks_by_file(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::Browse(BrowseMessage::GetChunksByFile {
+                collection: collection.to_string(),
+                file_path: file_path.to_string(),
+                tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
+    }
+}
+
+/// One collection: its index and metadata
+struct EdgeVecCollection {
+    index: EdgeVecIndex,
+    metadata: HashMap<String, serde_json::Value>,
+    /// Changed since the last flush
+    dirty: bool,
+    /// Generation of the index rebuild in progress
+    rebuilding: Option<u64>,
+    /// Ids inserted or removed while a rebuild is in progress
+    changed_while_rebuilding: HashSet<String>,
+    /// Completed index rebuilds
+    compactions: u64,
+}
+
+impl EdgeVecCollection {
+    fn new(config: &EdgeVecConfig) -> Result<Self> {
+        Ok(Self {
+            index: EdgeVecIndex::new(config)?,
+            metadata: HashMap::new(),
+            dirty: false,
+            rebuilding: None,
+            changed_while_rebuilding: HashSet::new(),
+            compactions: 0,
+        })
+    }
+
+    /// Restore a collection from its snapshot
+    ///
+    /// The saved graph is used as is; version 1 snapshots carry only the
+    /// vectors, so their graph is rebuilt with the current HNSW parameters.
+    fn from_snapshot(config: &EdgeVecConfig, snapshot: snapshot::Snapshot) -> Result<Self> {
+        if snapshot.header.dimensions != config.dimensions {
+            return Err(Error::vector_db(format!(
+                "EdgeVec snapshot holds {}-dimensional vectors but {} dimensions are configured",
+                snapshot.header.dimensions, config.dimensions
+            )));
+        }
+
+        let entries = snapshot.header.entries;
+        match snapshot.body {
+            SnapshotBody::Graph(graph) => {
+                let ids = entries
+                    .iter()
+                    .filter_map(|entry| entry.node.map(|node| (entry.id.clone(), node)))
+                    .collect();
+                let mut collection = Self::new(config)?;
+                collection.index =
+                    EdgeVecIndex::from_bytes(&graph, ids, snapshot.header.tombstones)?;
+                collection.metadata = entries
+                    .into_iter()
+                    .map(|entry| (entry.id, entry.metadata))
+                    .collect();
+                Ok(collection)
+            }
+            SnapshotBody::Vectors(vectors) => {
+                let mut collection = Self::new(config)?;
+                for (entry, vector) in entries.into_iter().zip(vectors) {
+                    collection.insert(entry.id, &vector, entry.metadata)?;
+                }
+                // Save in the current format on the next flush
+                collection.dirty = true;
+                Ok(collection)
+            }
+        }
+    }
+
+    /// The stored vector `id` with its metadata, if it is in the index
+    fn stored_vector(&self, id: &str, metadata: &serde_json::Value) -> Option<StoredVector> {
+        self.index.vector(id).map(|vector| StoredVector {
+            id: id.to_string(),
+            vector,
+            metadata: metadata
+                .as_object()
+                .map(|metadata| metadata.clone().into_iter().collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    fn insert(&mut self, id: String, vector: &[f32], metadata: serde_json::Value) -> Result<()> {
+        self.index.insert(id.clone(), vector)?;
+        if self.rebuilding.is_some() {
+            self.changed_while_rebuilding.insert(id.clone());
+        }
+        self.metadata.insert(id, metadata);
+        Ok(())
+    }
+
+    fn remove(&mut self, id: &str) {
+        self.index.remove(id);
+        self.metadata.remove(id);
+        if self.rebuilding.is_some() {
+            self.changed_while_rebuilding.insert(id.to_string());
+        }
+    }
+
+    /// Swap in a rebuilt index after replaying the changes it missed
+    fn replace_index(&mut self, mut index: EdgeVecIndex) -> Result<()> {
+        for id in std::mem::take(&mut self.changed_while_rebuilding) {
+            match self.index.vector(&id) {
+                Some(vector) => index.insert(id, &vector)?,
+                None => {
+                    index.remove(&id);
+                }
+            }
+        }
+        self.index = index;
+        self.compactions += 1;
+        Ok(())
+    }
+
+    /// Header and serialized graph for a snapshot
+    fn snapshot(&self, dimensions: usize) -> Result<(SnapshotHeader, Vec<u8>)> {
+        let mut ids = self.index.id_map();
+        ids.sort();
+        let header = SnapshotHeader {
+            dimensions,
+            entries: ids
+                .into_iter()
+                .map(|(id, node)| SnapshotEntry {
+                    metadata: self.metadata.get(&id).cloned().unwrap_or_default(),
+                    id,
+                    node: Some(node),
+                })
+                .collect(),
+            tombstones: self.index.tombstone_ids(),
+        };
+        Ok((header, self.index.to_bytes()?))
+    }
+}
+
+/// Orders snapshot writes and deletes of one collection
+///
+/// Holds the sequence number of the last operation applied to the file, so
+/// an older write finishing late never replaces a newer snapshot.
+type SnapshotLock = Arc<Mutex<u64>>;
+
+struct EdgeVecActor {
+    receiver: mpsc::Receiver<EdgeVecMessage>,
+    /// Handle for background tasks; weak so dropping the provider stops the actor
+    mailbox: mpsc::WeakSender<EdgeVecMessage>,
+    collections: HashMap<String, EdgeVecCollection>,
+    /// Collections with a snapshot on disk that have not been loaded yet
+    unloaded: HashSet<String>,
+    /// Unloaded collections whose snapshot is being read
+    loading: HashSet<String>,
+    /// Messages waiting for the listed collections to load, in arrival order
+    parked: Vec<(HashSet<String>, EdgeVecMessage)>,
+    snapshot_locks: HashMap<String, SnapshotLock>,
+    next_snapshot_op: u64,
+    next_generation: u64,
+    config: EdgeVecConfig,
+}
+
+// =============================================================================
+// Core - Constructor and HNSW configuration
+// =============================================================================
+
+fn hnsw_config(config: &EdgeVecConfig) -> edgevec::HnswConfig {
+    edgevec::HnswConfig {
+        m: config.hnsw_config.m,
+        m0: config.hnsw_config.m0,
+        ef_construction: config.hnsw_config.ef_construction,
+        ef_search: config.hnsw_config.ef_search,
+        dimensions: config.dimensions as u32,
+        metric: match config.metric {
+            MetricType::L2Squared => edgevec::HnswConfig::METRIC_L2_SQUARED,
+            MetricType::Cosine => edgevec::HnswConfig::METRIC_COSINE,
+            MetricType::DotProduct => edgevec::HnswConfig::METRIC_DOT_PRODUCT,
+        },
+        _reserved: [0; 2],
+    }
+}
+
+impl EdgeVecActor {
+    fn new(
+        receiver: mpsc::Receiver<EdgeVecMessage>,
+        mailbox: mpsc::WeakSender<EdgeVecMessage>,
+        config: EdgeVecConfig,
+    ) -> Result<Self> {
+        // Only the names are read at startup; snapshots load on first use
+        let unloaded = match &config.data_dir {
+            Some(dir) => snapshot::list(dir)?.into_iter().collect(),
+            None => HashSet::new(),
+        };
+
+        Ok(Self {
+            receiver,
+            mailbox,
+            collections: HashMap::new(),
+            unloaded,
+            loading: HashSet::new(),
+            parked: Vec::new(),
+            snapshot_locks: HashMap::new(),
+            next_snapshot_op: 0,
+            next_generation: 0,
+            config,
+        })
+    }
+}
+
+// =============================================================================
+// Persistence - Lazy loading and flushing
+// =============================================================================
+
+impl EdgeVecActor {
+    fn snapshot_path(&self, name: &str) -> Result<Option<PathBuf>> {
+        self.config
+            .data_dir
+            .as_deref()
+            .map(|dir| snapshot::snapshot_path(dir, name))
+            .transpose()
+    }
+
+    /// Collections that must finish loading before `msg` is handled
+    fn loads_needed(&self, msg: &EdgeVecMessage) -> HashSet<String> {
+        if let EdgeVecMessage::Browse(BrowseMessage::ListCollections { .. }) = msg {
+            return self.unloaded.clone();
+        }
+        match msg.collection() {
+            Some(name)
+                if self.loading.contains(name)
+                    || (self.unloaded.contains(name) && msg.needs_data()) =>
+            {
+                HashSet::from([name.to_string()])
+            }
+            _ => HashSet::new(),
+        }
+    }
+
+    /// Read the snapshot of `name` on a blocking thread
+    ///
+    /// The collection arrives as [`MaintenanceMessage::CollectionLoaded`].
+    fn start_load(&mut self, name: &str) -> Result<()> {
+        if self.loading.contains(name) {
+            return Ok(());
+        }
+        let mailbox = self
+            .mailbox
+            .upgrade()
+            .ok_or_else(|| Error::internal("Actor closed"))?;
+        let path = self.snapshot_path(name)?.ok_or_else(|| {
+            Error::internal(format!("EdgeVec collection '{name}' has no data directory"))
+        })?;
+
+        self.loading.insert(name.to_string());
+        let config = self.config.clone();
+        let collection = name.to_string();
+        tokio::task::spawn_blocking(move || {
+            let loaded = snapshot::read(&path)
+                .and_then(|snapshot| EdgeVecCollection::from_snapshot(&config, snapshot));
+            let _ = mailbox.blocking_send(EdgeVecMessage::Maintenance(
+                MaintenanceMessage::CollectionLoaded { collection, loaded },
+            ));
+        });
+        Ok(())
+    }
+
+    /// Install a loaded collection and handle the messages waiting for it
+    ///
+    /// A failed load answers the waiting messages with the error and leaves
+    /// the collection unloaded, so the next use tries again.
+    fn handle_collection_loaded(&mut self, name: &str, loaded: Result<EdgeVecCollection>) {
+        self.loading.remove(name);
+        let error = match loaded {
+            Ok(collection) => {
+                self.unloaded.remove(name);
+                self.collections.insert(name.to_string(), collection);
+                None
+            }
+            Err(e) => {
+                warn!(collection = %name, error = %e, "Failed to load EdgeVec collection");
+                Some(e.to_string())
+            }
+        };
+
+        for (mut waiting, msg) in std::mem::take(&mut self.parked) {
+            if waiting.remove(name)
+                && let Some(error) = &error
+            {
+                msg.reject(Error::vector_db(error.clone()));
+            } else if waiting.is_empty() {
+                self.handle_message(msg);
+            } else {
+                self.parked.push((waiting, msg));
+            }
+        }
+    }
+
+    fn collection(&self, name: &str) -> Option<&EdgeVecCollection> {
+        self.collections.get(name)
+    }
+
+    fn collection_mut(&mut self, name: &str) -> Option<&mut EdgeVecCollection> {
+        self.collections.get_mut(name)
+    }
+
+    fn collection_or_create(&mut self, name: &str) -> Result<&mut EdgeVecCollection> {
+        match self.collections.entry(name.to_string()) {
+            std::collections::hash_map::Entry::Occupied(entry) => Ok(entry.into_mut()),
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                let mut collection = EdgeVecCollection::new(&self.config)?;
+                collection.dirty = true;
+                Ok(entry.insert(collection))
+            }
+        }
+    }
+
+    /// Lock and sequence number for the next write or delete of `name`
+    fn snapshot_op(&mut self, name: &str) -> (SnapshotLock, u64) {
+        self.next_snapshot_op += 1;
+        let lock = self.snapshot_locks.entry(name.to_string()).or_default();
+        (Arc::clone(lock), self.next_snapshot_op)
+    }
+
+    /// Write `name` to its snapshot if it changed since the last flush
+    ///
+    /// The graph is serialized here and written on a blocking thread, which
+    /// answers `reply` once the file is on disk.
+    fn flush_collection(
+        &mut self,
+        name: &str,
+        reply: Option<oneshot::Sender<Result<()>>>,
+    ) -> Option<JoinHandle<()>> {
+        let respond = |reply: Option<oneshot::Sender<Result<()>>>, result| {
+            if let Some(tx) = reply {
+                let _ = tx.send(result);
+            }
+        };
+        let path = match self.snapshot_path(name) {
+            Ok(Some(path)) => path,
+            Ok(None) => {
+                respond(reply, Ok(()));
+                return None;
+            }
+            Err(e) => {
+                respond(reply, Err(e));
+                return None;
+            }
+        };
+        let dimensions = self.config.dimensions;
+        let Some(collection) = self.collections.get_mut(name).filter(|c| c.dirty) else {
+            respond(reply, Ok(()));
+            return None;
+        };
+        let (header, graph) = match collection.snapshot(dimensions) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                respond(reply, Err(e));
+                return None;
+            }
+        };
+        collection.dirty = false;
+
+        let (lock, op) = self.snapshot_op(name);
+        let mailbox = self.mailbox.clone();
+        let collection = name.to_string();
+        Some(tokio::task::spawn_blocking(move || {
+            let mut applied = lock.lock().unwrap_or_else(PoisonError::into_inner);
+            let result = if *applied > op {
+                // A later write or delete already reached the disk
+                Ok(())
+            } else {
+                snapshot::write(&path, &header, &graph)
+            };
+            match &result {
+                Ok(()) => *applied = (*applied).max(op),
+                Err(e) => {
+                    warn!(collection = %collection, error = %e, "Failed to flush EdgeVec collection");
+                    if let Some(mailbox) = mailbox.upgrade() {
+                        let _ = mailbox.blocking_send(EdgeVecMessage::Maintenance(
+                            MaintenanceMessage::FlushFailed { collection },
+                        ));
+                    }
+                }
+            }
+            respond(reply, result);
+        }))
+    }
+
+    fn flush_all(&mut self) -> Vec<JoinHandle<()>> {
+        let dirty: Vec<String> = self
+            .collections
+            .iter()
+            .filter(|(_, collection)| collection.dirty)
+            .map(|(name, _)| name.clone())
+            .collect();
+        dirty
+            .into_iter()
+            .filter_map(|name| self.flush_collection(&name, None))
+            .collect()
+    }
+
+    /// Mark a collection whose write failed for the next flush
+    fn handle_flush_failed(&mut self, name: &str) {
+        if let Some(collection) = self.collections.get_mut(name) {
+            collection.dirty = true;
+        }
+    }
+}
+
+// =============================================================================
+// Compaction - Background index rebuilds
+// =============================================================================
+
+impl EdgeVecActor {
+    /// Start rebuilding the index of `name` if it has too many tombstones
+    ///
+    /// The graph is built from a copy of the live vectors on a blocking
+    /// thread while the old index keeps serving; changes made meanwhile are
+    /// replayed before the swap.
+    fn maybe_rebuild(&mut self, name: &str) {
+        let Some(collection) = self.collections.get_mut(name) else {
+            return;
+        };
+        if collection.rebuilding.is_some() || !collection.index.needs_compaction(&self.config) {
+            return;
+        }
+        let Some(mailbox) = self.mailbox.upgrade() else {
+            return;
+        };
+
+        self.next_generation += 1;
+        let generation = self.next_generation;
+        collection.rebuilding = Some(generation);
+        let live = collection.index.live_vectors();
+        let config = self.config.clone();
+        let collection = name.to_string();
+        tokio::task::spawn_blocking(move || {
+            let index = EdgeVecIndex::build(&config, live);
+            let _ = mailbox.blocking_send(EdgeVecMessage::Maintenance(
+                MaintenanceMessage::IndexRebuilt {
+                    collection,
+                    generation,
+                    index,
+                },
+            ));
+        });
+    }
+
+    fn handle_index_rebuilt(&mut self, name: &str, generation: u64, index: Result<EdgeVecIndex>) {
+        // The collection may have been deleted or recreated meanwhile
+        let Some(collection) = self.collections.get_mut(name) else {
+            return;
+        };
+        if collection.rebuilding != Some(generation) {
+            return;
+        }
+        collection.rebuilding = None;
+
+        let result = index.and_then(|index| collection.replace_index(index));
+        collection.changed_while_rebuilding.clear();
+        match result {
+            Ok(()) => debug!(collection = %name, "Rebuilt EdgeVec index without deleted vectors"),
+            Err(e) => warn!(collection = %name, error = %e, "Failed to rebuild EdgeVec index"),
+        }
+        self.maybe_rebuild(name);
+    }
+}
+
+// =============================================================================
+// Collection Handlers - Create, delete, exists
+// =============================================================================
+
+impl EdgeVecActor {
+    fn handle_create_collection(&mut self, name: String) -> Result<()> {
+        self.collection_or_create(&name).map(|_| ())
+    }
+
+    /// Drop the collection and remove its snapshot on a blocking thread
+    fn handle_delete_collection(&mut self, name: String, tx: oneshot::Sender<Result<()>>) {
+        self.collections.remove(&name);
+        self.unloaded.remove(&name);
+        let path = match self.snapshot_path(&name) {
+            Ok(Some(path)) => path,
+            result => {
+                let _ = tx.send(result.map(|_| ()));
+                return;
+            }
+        };
+
+        let (lock, op) = self.snapshot_op(&name);
+        tokio::task::spawn_blocking(move || {
+            let mut applied = lock.lock().unwrap_or_else(PoisonError::into_inner);
+            *applied = (*applied).max(op);
+            let _ = tx.send(snapshot::remove(&path));
+        });
+    }
+
+    fn handle_collection_exists(&self, name: &str) -> Result<bool> {
+        Ok(self.collections.contains_key(name) || self.unloaded.contains(name))
+    }
+}
+
+// =============================================================================
+// Vector CRUD Handlers - Insert, delete, get, list vectors
+// =============================================================================
+
+impl EdgeVecActor {
+    fn handle_insert_vectors(
+        &mut self,
+        collection: String,
+        vectors: Vec<Embedding>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<Vec<String>> {
+        let mut ids = Vec::with_capacity(vectors.len());
+        let store = self.collection_or_create(&collection)?;
+        store.dirty = true;
+
+        for (vector, meta) in vectors.into_iter().zip(metadata.into_iter()) {
+            let external_id = format!("{}_{}", collection, uuid::Uuid::new_v4());
+            let mut enriched_metadata = meta;
+            enriched_metadata.insert("id".to_string(), serde_json::json!(external_id));
+            store.insert(
+                external_id.clone(),
+                &vector.vector,
+                serde_json::json!(enriched_metadata),
+            )?;
+            ids.push(external_id);
+        }
+        Ok(ids)
+    }
+
+    fn handle_upsert_vectors(
+        &mut self,
+        collection: String,
+        ids: Vec<String>,
+        vectors: Vec<Embedding>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Ids, vectors and metadata length mismatch",
+            ));
+        }
+        let store = self.collection_or_create(&collection)?;
+        store.dirty = true;
+
+        for ((id, vector), meta) in ids.into_iter().zip(vectors).zip(metadata) {
+            let mut enriched_metadata = meta;
+            enriched_metadata.insert("id".to_string(), serde_json::json!(id));
+            // Replacing a vector tombstones its old node
+            store.insert(id, &vector.vector, serde_json::json!(enriched_metadata))?;
+        }
+        self.maybe_rebuild(&collection);
+        Ok(())
+    }
+
+    fn handle_delete_vectors(&mut self, collection: &str, ids: Vec<String>) -> Result<()> {
+        if let Some(store) = self.collection_mut(collection) {
+            for id in ids {
+                store.remove(&id);
+            }
+            store.dirty = true;
+        }
+        self.maybe_rebuild(collection);
+        Ok(())
+    }
+
+    fn handle_delete_by_filter(
+        &mut self,
+        collection: &str,
+        filter: &MetadataFilter,
+    ) -> Result<u64> {
+        filter.ensure_not_empty()?;
+        let Some(store) = self.collection_mut(collection) else {
+            return Ok(0);
+        };
+        let ids: Vec<String> = store
+            .metadata
+            .iter()
+            .filter(|(_, meta)| filter.matches_value(meta))
+            .map(|(id, _)| id.clone())
+            .collect();
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        for id in &ids {
+            store.remove(id);
+        }
+        store.dirty = true;
+        self.maybe_rebuild(collection);
+        Ok(ids.len() as u64)
+    }
+
+    fn handle_get_vectors_by_ids(
+        &mut self,
+        collection: &str,
+        ids: Vec<String>,
+    ) -> Result<Vec<SearchResult>> {
+        let mut final_results = Vec::new();
+        if let Some(store) = self.collection(collection) {
+            for id in ids {
+                if let Some(meta_val) = store.metadata.get(&id) {
+                    let meta = meta_val.as_object().cloned().unwrap_or_default();
+                    final_results.push(SearchResult {
+                        id: id.clone(),
+                        file_path: meta.string_or("file_path", "unknown"),
+                        start_line: meta
+                            .opt_u64("start_line")
+                            .or_else(|| meta.opt_u64("line_number"))
+                            .unwrap_or(0) as u32,
+                        content: meta.string_or("content", ""),
+                        score: 1.0,
+                        language: meta.string_or("language", "unknown"),
+                    });
+                }
+            }
+        }
+        Ok(final_results)
+    }
+
+    fn handle_list_vectors(&mut self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let mut final_results = Vec::new();
+        if let Some(store) = self.collection(collection) {
+            for (ext_id, meta_val) in store.metadata.iter().take(limit) {
+                let meta = meta_val.as_object().cloned().unwrap_or_default();
+                final_results.push(SearchResult {
+                    id: ext_id.clone(),
+                    file_path: meta.string_or("file_path", "unknown"),
+                    start_line: meta
+                        .opt_u64("start_line")
+                        .or_else(|| meta.opt_u64("line_number"))
+                        .unwrap_or(0) as u32,
+                    content: meta.string_or("content", ""),
+                    score: 1.0,
+                    language: meta.string_or("language", "unknown"),
+                });
+            }
+        }
+        Ok(final_results)
+    }
+
+    fn handle_export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        let store = self
+            .collection(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+        Ok(store
+            .metadata
+            .iter()
+            .filter_map(|(id, meta)| store.stored_vector(id, meta))
+            .collect())
+    }
+
+    fn handle_export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        let store = self
+            .collection(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        // Only the page's vectors are read from the index
+        let mut page: Vec<_> = store
+            .metadata
+            .iter()
+            .filter(|(id, _)| after.is_none_or(|after| id.as_str() > after))
+            .collect();
+        page.sort_by(|a, b| a.0.cmp(b.0));
+        page.truncate(limit);
+        Ok(page
+            .into_iter()
+            .filter_map(|(id, meta)| store.stored_vector(id, meta))
+            .collect())
+    }
+}
+
+// =============================================================================
+// Search Handlers - Similarity search
+// =============================================================================
+
+impl EdgeVecActor {
+    fn handle_search_similar(
+        &mut self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let Some(store) = self.collection(collection) else {
+            return Ok(Vec::new());
+        };
+
+        let mut final_results = Vec::with_capacity(limit);
+        for (ext_id, distance) in store.index.search(query_vector, limit)? {
+            if let Some(meta_val) = store.metadata.get(ext_id) {
+                let meta = meta_val.as_object().cloned().unwrap_or_default();
+                let start_line = meta
+                    .opt_u64("start_line")
+                    .or_else(|| meta.opt_u64("line_number"))
+                    .unwrap_or(0) as u32;
+                final_results.push(SearchResult {
+                    id: ext_id.to_string(),
+                    file_path: meta.string_or("file_path", "unknown"),
+                    start_line,
+                    content: meta.string_or("content", ""),
+                    score: distance as f64,
+                    language: meta.string_or("language", "unknown"),
+                });
+            }
+        }
+        Ok(final_results)
+    }
+}
+
+// =============================================================================
+// Stats Handlers - Get collection statistics
+// =============================================================================
+
+impl EdgeVecActor {
+    fn handle_get_stats(&mut self, collection: &str) -> Result<HashMap<String, serde_json::Value>> {
+        let dimensions = self.config.dimensions;
+        let persistent = self.config.data_dir.is_some();
+        let (vector_count, tombstones, rebuilding, compactions) = self
+            .collection(collection)
+            .map(|store| {
+                (
+                    store.index.len(),
+                    store.index.tombstones(),
+                    store.rebuilding.is_some(),
+                    store.compactions,
+                )
+            })
+            .unwrap_or_default();
+        let mut stats = HashMap::new();
+        stats.insert("collection".to_string(), serde_json::json!(collection));
+        stats.insert("vector_count".to_string(), serde_json::json!(vector_count));
+        stats.insert(
+            "total_indexed_vectors".to_string(),
+            serde_json::json!(vector_count + tombstones),
+        );
+        stats.insert("tombstones".to_string(), serde_json::json!(tombstones));
+        stats.insert("rebuilding".to_string(), serde_json::json!(rebuilding));
+        stats.insert("compactions".to_string(), serde_json::json!(compactions));
+        stats.insert("dimensions".to_string(), serde_json::json!(dimensions));
+        stats.insert("persistent".to_string(), serde_json::json!(persistent));
+        Ok(stats)
+    }
+}
+
+// =============================================================================
+// Browse Handlers - List collections, files, and chunks
+// =============================================================================
+
+impl EdgeVecActor {
+    fn handle_list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        Ok(self
+            .collections
+            .iter()
+            .map(|(name, store)| {
+                let vector_count = store.metadata.len() as u64;
+
+                // Count unique file paths
+                let file_paths: HashSet<&str> = store
+                    .metadata
+                    .values()
+                    .filter_map(|v| {
+                        v.as_object()
+                            .and_then(|o| o.get("file_path"))
+                            .and_then(|v| v.as_str())
+                    })
+                    .collect();
+                let file_count = file_paths.len() as u64;
+
+                CollectionInfo::new(name.clone(), vector_count, file_count, None, "edgevec")
+            })
+            .collect())
+    }
+
+    fn handle_list_file_paths(&mut self, collection: &str, limit: usize) -> Result<Vec<FileInfo>> {
+        let mut files = Vec::new();
+        if let Some(store) = self.collection(collection) {
+            let mut file_map: HashMap<String, (u32, String)> = HashMap::new();
+
+            for meta_val in store.metadata.values() {
+                if let Some(meta) = meta_val.as_object() {
+                    if let Some(file_path) = meta.get("file_path").and_then(|v| v.as_str()) {
+                        let language = meta
+                            .get("language")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("unknown")
+                            .to_string();
+
+                        let entry = file_map
+                            .entry(file_path.to_string())
+                            .or_insert((0, language));
+                        entry.0 += 1;
+                    }
+                }
+            }
+
+            files = file_map
+                .into_iter()
+                .take(limit)
+                .map(|(path, (chunk_count, language))| {
+                    FileInfo::new(path, chunk_count, language, None)
+                })
+                .collect();
+        }
+        Ok(files)
+    }
+
+    fn handle_get_chunks_by_file(
+        &mut self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let mut results = Vec::new();
+        if let Some(store) = self.collection(collection) {
+            for (ext_id, meta_val) in store.metadata.iter() {
+                if let Some(meta) = meta_val.as_object() {
+                    if meta
+                        .get("file_path")
+                        .and_then(|v| v.as_str())
+                        .is_some_and(|p| p == file_path)
+                    {
+                        let start_line = meta
+                            .opt_u64("start_line")
+                            .or_else(|| meta.opt_u64("line_number"))
+                            .unwrap_or(0) as u32;
+
+                        results.push(SearchResult {
+                            id: ext_id.clone(),
+                            file_path: file_path.to_string(),
+                            start_line,
+                            content: meta.string_or("content", ""),
+                            score: 1.0,
+                            language: meta.string_or("language", "unknown"),
+                        });
+                    }
+                }
+            }
+        }
+        // Sort by start_line
+        results.sort_by_key(|r| r.start_line);
+        Ok(results)
+    }
+}
+
+// =============================================================================
+// Message Loop - Main actor run loop
+// =============================================================================
+
+impl EdgeVecActor {
+    async fn run(mut self) {
+        let periodic = self.config.data_dir.is_some() && self.config.flush_interval_secs > 0;
+        let mut flush_timer =
+            tokio::time::interval(Duration::from_secs(self.config.flush_interval_secs.max(1)));
+        flush_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                msg = self.receiver.recv() => match msg {
+                    Some(msg) => self.handle_message(msg),
+                    None => break,
+                },
+                _ = flush_timer.tick(), if periodic => {
+                    self.flush_all();
+                }
+            }
+        }
+
+        // Every provider handle is gone; save what is left
+        for write in self.flush_all() {
+            let _ = write.await;
+        }
+    }
+
+    /// Handle `msg`, or park it until the collections it needs are loaded
+    fn handle_message(&mut self, msg: EdgeVecMessage) {
+        let waiting = self.loads_needed(&msg);
+        if waiting.is_empty() {
+            self.dispatch(msg);
+            return;
+        }
+        for name in &waiting {
+            if let Err(e) = self.start_load(name) {
+                msg.reject(e);
+                return;
+            }
+        }
+        self.parked.push((waiting, msg));
+    }
+
+    fn dispatch(&mut self, msg: EdgeVecMessage) {
+        match msg {
+            EdgeVecMessage::Core(core) => self.handle_core_message(core),
+            EdgeVecMessage::Query(query) => self.handle_query_message(query),
+            EdgeVecMessage::Browse(browse) => self.handle_browse_message(browse),
+            EdgeVecMessage::Maintenance(maintenance) => {
+                self.handle_maintenance_message(maintenance)
+            }
+        }
+    }
+
+    fn handle_maintenance_message(&mut self, msg: MaintenanceMessage) {
+        match msg {
+            MaintenanceMessage::IndexRebuilt {
+                collection,
+                generation,
+                index,
+            } => self.handle_index_rebuilt(&collection, generation, index),
+            MaintenanceMessage::CollectionLoaded { collection, loaded } => {
+                self.handle_collection_loaded(&collection, loaded);
+            }
+            MaintenanceMessage::FlushFailed { collection } => {
+                self.handle_flush_failed(&collection);
+            }
+        }
+    }
+
+    fn handle_core_message(&mut self, msg: CoreMessage) {
+        match msg {
+            CoreMessage::CreateCollection { name, tx } => {
+                let _ = tx.send(self.handle_create_collection(name));
+            }
+            CoreMessage::DeleteCollection { name, tx } => {
+                self.handle_delete_collection(name, tx);
+            }
+            CoreMessage::InsertVectors {
+                collection,
+                vectors,
+                metadata,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_insert_vectors(collection, vectors, metadata));
+            }
+            CoreMessage::UpsertVectors {
+                collection,
+                ids,
+                vectors,
+                metadata,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_upsert_vectors(collection, ids, vectors, metadata));
+            }
+            CoreMessage::SearchSimilar {
+                collection,
+                query_vector,
+                limit,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_search_similar(&collection, &query_vector, limit));
+            }
+            CoreMessage::DeleteVectors {
+                collection,
+                ids,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_delete_vectors(&collection, ids));
+            }
+            CoreMessage::DeleteByFilter {
+                collection,
+                filter,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_delete_by_filter(&collection, &filter));
+            }
+            CoreMessage::Flush { collection, tx } => {
+                self.flush_collection(&collection, Some(tx));
+            }
+        }
+    }
+
+    fn handle_query_message(&mut self, msg: QueryMessage) {
+        match msg {
+            QueryMessage::GetStats { collection, tx } => {
+                let _ = tx.send(self.handle_get_stats(&collection));
+            }
+            QueryMessage::ListVectors {
+                collection,
+                limit,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_list_vectors(&collection, limit));
+            }
+            QueryMessage::ExportVectors { collection, tx } => {
+                let _ = tx.send(self.handle_export_vectors(&collection));
+            }
+            QueryMessage::ExportVectorsPage {
+                collection,
+                after,
+                limit,
+                tx,
+            } => {
+                let _ =
+                    tx.send(self.handle_export_vectors_page(&collection, after.as_deref(), limit));
+            }
+            QueryMessage::GetVectorsByIds {
+                collection,
+                ids,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_get_vectors_by_ids(&collection, ids));
+            }
+            QueryMessage::CollectionExists { name, tx } => {
+                let _ = tx.send(self.handle_collection_exists(&name));
+            }
+        }
+    }
+
+    fn handle_browse_message(&mut self, msg: BrowseMessage) {
+        match msg {
+            BrowseMessage::ListCollections { tx } => {
+                let _ = tx.send(self.handle_list_collections());
+            }
+            BrowseMessage::ListFilePaths {
+                collection,
+                limit,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_list_file_paths(&collection, limit));
+            }
+            BrowseMessage::GetChunksByFile {
+                collection,
+                file_path,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_get_chunks_by_file(&collection, &file_path));
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+
+use mcb_application::ports::registry::{
+    VECTOR_STORE_PROVIDERS, VectorStoreProviderConfig, VectorStoreProviderEntry,
+};
+
+/// Factory function for creating EdgeVec vector store provider instances.
+///
+/// `uri` is the data directory; without it collections live in memory only.
+/// `extra` keys `flush_interval_secs`, `compaction_threshold` and
+/// `compaction_min_tombstones` override the defaults.
+fn edgevec_factory(
+    config: &VectorStoreProviderConfig,
+) -> std::result::Result<Arc<dyn VectorStoreProvider>, String> {
+    let dimensions = config.dimensions.unwrap_or(384);
+    let edgevec_config = EdgeVecConfig {
+        dimensions,
+        data_dir: config.uri.as_ref().map(PathBuf::from),
+        flush_interval_secs: extra_or(config, "flush_interval_secs", default_flush_interval_secs)?,
+        compaction_threshold: extra_or(
+            config,
+            "compaction_threshold",
+            default_compaction_threshold,
+        )?,
+        compaction_min_tombstones: extra_or(
+            config,
+            "compaction_min_tombstones",
+            default_compaction_min_tombstones,
+        )?,
+        ..Default::default()
+    };
+    let provider = EdgeVecVectorStoreProvider::new(edgevec_config)
+        .map_err(|e| format!("Failed to create EdgeVec provider: {e}"))?;
+    Ok(Arc::new(provider))
+}
+
+/// Parse `extra[key]`, or fall back to `default`
+fn extra_or<T: std::str::FromStr>(
+    config: &VectorStoreProviderConfig,
+    key: &str,
+    default: fn() -> T,
+) -> std::result::Result<T, String> {
+    match config.extra.get(key) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid EdgeVec {key} '{value}'")),
+        None => Ok(default()),
+    }
+}
+
+#[linkme::distributed_slice(VECTOR_STORE_PROVIDERS)]
+static EDGEVEC_PROVIDER: VectorStoreProviderEntry = VectorStoreProviderEntry {
+    name: "edgevec",
+    description: "EdgeVec HNSW vector store (high-performance, optional persistence)",
+    factory: edgevec_factory,
+};
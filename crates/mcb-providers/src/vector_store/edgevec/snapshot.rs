@@ -0,0 +1,215 @@
+//! EdgeVec collection snapshots
+//!
+//! Each collection is saved to `{data_dir}/{collection}.edgevec`:
+//!
+//! | Bytes | Content |
+//! |-------|---------|
+//! | 8 | Magic `MCBEDGV2` |
+//! | 8 | Header length, little-endian `u64` |
+//! | n | JSON [`SnapshotHeader`] |
+//! | rest | EdgeVec snapshot of the HNSW graph and vector storage |
+//!
+//! Version 1 files (magic `MCBEDGV1`) hold the raw vectors as
+//! little-endian `f32` in header entry order instead of the graph; they are
+//! still read, and the graph is rebuilt from the vectors.
+//!
+//! Snapshots are written to a `.tmp` sibling, synced and renamed over the
+//! previous file, so a crash leaves the old or the new snapshot intact.
+//! Reads and writes block, so callers run them on a blocking thread.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use mcb_domain::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+/// File extension of collection snapshots
+pub(super) const SNAPSHOT_EXTENSION: &str = "edgevec";
+const TMP_EXTENSION: &str = "edgevec.tmp";
+const MAGIC: &[u8; 8] = b"MCBEDGV2";
+const MAGIC_V1: &[u8; 8] = b"MCBEDGV1";
+
+/// Snapshot header
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct SnapshotHeader {
+    /// Dimensions of every vector
+    pub dimensions: usize,
+    /// Vector ids and metadata
+    pub entries: Vec<SnapshotEntry>,
+    /// Graph nodes of deleted vectors
+    #[serde(default)]
+    pub tombstones: Vec<u64>,
+}
+
+/// One vector of a snapshot
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct SnapshotEntry {
+    /// External vector id
+    pub id: String,
+    /// Graph node holding the vector; absent in version 1 files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<u64>,
+    /// Stored metadata
+    pub metadata: serde_json::Value,
+}
+
+/// What follows the header
+pub(super) enum SnapshotBody {
+    /// Serialized HNSW graph and vector storage
+    Graph(Vec<u8>),
+    /// Raw vectors in header entry order (version 1)
+    Vectors(Vec<Vec<f32>>),
+}
+
+/// A snapshot read back from disk
+pub(super) struct Snapshot {
+    pub header: SnapshotHeader,
+    pub body: SnapshotBody,
+}
+
+/// Snapshot path of `collection` in `dir`
+pub(super) fn snapshot_path(dir: &Path, collection: &str) -> Result<PathBuf> {
+    if collection.is_empty()
+        || collection.contains(['/', '\\'])
+        || collection == "."
+        || collection == ".."
+    {
+        return Err(Error::invalid_argument(format!(
+            "Collection name '{collection}' cannot be used as a file name"
+        )));
+    }
+    Ok(dir.join(format!("{collection}.{SNAPSHOT_EXTENSION}")))
+}
+
+/// Names of the collections with a snapshot in `dir`
+///
+/// A missing directory has no collections.
+pub(super) fn list(dir: &Path) -> Result<Vec<String>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_error("read EdgeVec data directory", dir, e)),
+    };
+
+    let suffix = format!(".{SNAPSHOT_EXTENSION}");
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| io_error("read EdgeVec data directory", dir, e))?;
+        let file_name = entry.file_name();
+        if let Some(name) = file_name.to_str().and_then(|n| n.strip_suffix(&suffix)) {
+            if entry.path().is_file() {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Write a snapshot, replacing any previous one atomically
+pub(super) fn write(path: &Path, header: &SnapshotHeader, graph: &[u8]) -> Result<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    std::fs::create_dir_all(dir).map_err(|e| io_error("create EdgeVec data directory", dir, e))?;
+
+    let header_json = serde_json::to_vec(header)
+        .map_err(|e| Error::internal(format!("Failed to serialize EdgeVec snapshot: {e}")))?;
+    let tmp = path.with_extension(TMP_EXTENSION);
+    let write_tmp = || -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(header_json.len() as u64).to_le_bytes())?;
+        writer.write_all(&header_json)?;
+        writer.write_all(graph)?;
+        writer.into_inner().map_err(|e| e.into_error())?.sync_all()
+    };
+    if let Err(e) = write_tmp() {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(io_error("write EdgeVec snapshot", &tmp, e));
+    }
+
+    std::fs::rename(&tmp, path).map_err(|e| io_error("replace EdgeVec snapshot", path, e))?;
+    sync_dir(dir)
+}
+
+/// Read a snapshot
+pub(super) fn read(path: &Path) -> Result<Snapshot> {
+    let bytes = std::fs::read(path).map_err(|e| io_error("read EdgeVec snapshot", path, e))?;
+    let corrupt = |reason: &str| {
+        Error::vector_db(format!(
+            "Corrupt EdgeVec snapshot {}: {reason}",
+            path.display()
+        ))
+    };
+
+    let (legacy, rest) = match bytes.split_first_chunk::<8>() {
+        Some((magic, rest)) if magic == MAGIC => (false, rest),
+        Some((magic, rest)) if magic == MAGIC_V1 => (true, rest),
+        _ => return Err(corrupt("bad magic")),
+    };
+    let (len, rest) = rest
+        .split_first_chunk::<8>()
+        .ok_or_else(|| corrupt("truncated header"))?;
+    let len = usize::try_from(u64::from_le_bytes(*len)).map_err(|_| corrupt("bad header"))?;
+    if rest.len() < len {
+        return Err(corrupt("truncated header"));
+    }
+    let (header_json, data) = rest.split_at(len);
+    let header: SnapshotHeader =
+        serde_json::from_slice(header_json).map_err(|e| corrupt(&e.to_string()))?;
+
+    if !legacy {
+        if header.entries.iter().any(|entry| entry.node.is_none()) {
+            return Err(corrupt("entry without graph node"));
+        }
+        return Ok(Snapshot {
+            header,
+            body: SnapshotBody::Graph(data.to_vec()),
+        });
+    }
+
+    let vector_bytes = header.dimensions * size_of::<f32>();
+    if vector_bytes == 0 || data.len() != header.entries.len() * vector_bytes {
+        return Err(corrupt("vector data does not match header"));
+    }
+    let vectors = data
+        .chunks_exact(vector_bytes)
+        .map(|vector| {
+            vector
+                .chunks_exact(size_of::<f32>())
+                .map(|value| f32::from_le_bytes([value[0], value[1], value[2], value[3]]))
+                .collect()
+        })
+        .collect();
+
+    Ok(Snapshot {
+        header,
+        body: SnapshotBody::Vectors(vectors),
+    })
+}
+
+/// Remove a snapshot if present
+pub(super) fn remove(path: &Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(io_error("delete EdgeVec snapshot", path, e)),
+    }
+}
+
+/// Persist a rename by syncing its directory
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> Result<()> {
+    File::open(dir)
+        .and_then(|d| d.sync_all())
+        .map_err(|e| io_error("sync EdgeVec data directory", dir, e))
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> Result<()> {
+    Ok(())
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> Error {
+    Error::io_with_source(format!("Failed to {action} {}", path.display()), e)
+}
@@ -17,17 +17,23 @@
 //! // use mcb_domain::ports::providers::CryptoProvider;
 //!
 //! // let encrypted = EncryptedVectorStoreProvider::new(inner_provider, crypto_service);
+//! // let shared = EncryptedVectorStoreProvider::<dyn VectorStoreProvider>::from_shared(arc, keyring);
 //! ```
 
 use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{CryptoProvider, EncryptedData};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
-use mcb_domain::value_objects::{CollectionInfo, Embedding, FileInfo, SearchResult};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Metadata fields stored in plaintext next to the encrypted blob
+const PLAINTEXT_FIELDS: [&str; 4] = ["content", "file_path", "language", "start_line"];
+
 /// Encrypted vector store provider
 ///
 /// Wraps any VectorStoreProvider implementation to provide encryption at rest.
@@ -44,21 +50,38 @@ use std::sync::Arc;
 /// - `file_path` - For search result construction
 /// - `start_line` - For search result construction
 /// - `language` - For search result construction
-pub struct EncryptedVectorStoreProvider<P: VectorStoreProvider> {
+///
+/// Deletes by filter can only match these fields.
+///
+/// Each encrypted blob records the id of the key it was encrypted with, so a
+/// keyring can keep decrypting data written under retired keys. Re-writing a
+/// record (for example exporting and upserting it) encrypts it under the
+/// active key.
+pub struct EncryptedVectorStoreProvider<P: VectorStoreProvider + ?Sized> {
     /// Underlying vector store provider
-    inner: P,
+    inner: Arc<P>,
     /// Cryptography provider
     crypto: Arc<dyn CryptoProvider>,
 }
 
-impl<P: VectorStoreProvider> EncryptedVectorStoreProvider<P> {
+impl<P: VectorStoreProvider + ?Sized> EncryptedVectorStoreProvider<P> {
     /// Create a new encrypted vector store provider
     ///
     /// # Arguments
     ///
     /// * `inner` - The underlying vector store provider to wrap
     /// * `crypto` - The cryptography provider for encryption operations
-    pub fn new(inner: P, crypto: Arc<dyn CryptoProvider>) -> Self {
+    pub fn new(inner: P, crypto: Arc<dyn CryptoProvider>) -> Self
+    where
+        P: Sized,
+    {
+        Self::from_shared(Arc::new(inner), crypto)
+    }
+
+    /// Create an encrypted provider over a shared provider
+    ///
+    /// Allows wrapping an `Arc<dyn VectorStoreProvider>`.
+    pub fn from_shared(inner: Arc<P>, crypto: Arc<dyn CryptoProvider>) -> Self {
         Self { inner, crypto }
     }
 
@@ -103,10 +126,23 @@ impl<P: VectorStoreProvider> EncryptedVectorStoreProvider<P> {
 
         Ok(processed)
     }
+
+    /// Decrypt the metadata of exported vectors
+    fn decrypt_stored(&self, vectors: Vec<StoredVector>) -> Result<Vec<StoredVector>> {
+        vectors
+            .into_iter()
+            .map(|stored| {
+                Ok(StoredVector {
+                    metadata: decrypt_metadata(self.crypto.as_ref(), &stored.metadata)?,
+                    ..stored
+                })
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
-impl<P: VectorStoreProvider> VectorStoreAdmin for EncryptedVectorStoreProvider<P> {
+impl<P: VectorStoreProvider + ?Sized> VectorStoreAdmin for EncryptedVectorStoreProvider<P> {
     async fn collection_exists(&self, name: &str) -> Result<bool> {
         self.inner.collection_exists(name).await
     }
@@ -118,6 +154,9 @@ impl<P: VectorStoreProvider> VectorStoreAdmin for EncryptedVectorStoreProvider<P
             "encryption_algorithm".to_string(),
             serde_json::json!("AES-256-GCM"),
         );
+        if let Some(key_id) = self.crypto.active_key_id() {
+            stats.insert("encryption_key_id".to_string(), serde_json::json!(key_id));
+        }
         Ok(stats)
     }
 
@@ -125,13 +164,17 @@ impl<P: VectorStoreProvider> VectorStoreAdmin for EncryptedVectorStoreProvider<P
         self.inner.flush(collection).await
     }
 
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        self.inner.list_collection_names().await
+    }
+
     fn provider_name(&self) -> &str {
         "encrypted"
     }
 }
 
 #[async_trait]
-impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvider<P> {
+impl<P: VectorStoreProvider + ?Sized> VectorStoreProvider for EncryptedVectorStoreProvider<P> {
     async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
         self.inner.create_collection(name, dimensions).await
     }
@@ -163,6 +206,29 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
             .await
     }
 
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        if vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Vectors and metadata length mismatch",
+            ));
+        }
+
+        let processed_metadata: Vec<_> = metadata
+            .iter()
+            .map(|meta| self.encrypt_metadata(meta))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.inner
+            .upsert_vectors(collection, ids, vectors, processed_metadata)
+            .await
+    }
+
     async fn search_similar(
         &self,
         collection: &str,
@@ -182,6 +248,30 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
         self.inner.delete_vectors(collection, ids).await
     }
 
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        // Encrypted fields are opaque to the inner provider
+        if let Some(key) = filter
+            .conditions()
+            .keys()
+            .find(|key| !PLAINTEXT_FIELDS.contains(&key.as_str()))
+        {
+            return Err(Error::invalid_argument(format!(
+                "Cannot filter on encrypted metadata field '{}'",
+                key
+            )));
+        }
+        self.inner.delete_by_filter(collection, filter).await
+    }
+
+    async fn delete_by_file(&self, collection: &str, file_path: &str) -> Result<u64> {
+        self.inner.delete_by_file(collection, file_path).await
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        // The file path is stored in plaintext
+        self.inner.file_vector_ids(collection, file_path).await
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
@@ -195,6 +285,25 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
         // Delegate to inner provider - SearchResult fields are extracted from stored metadata
         self.inner.list_vectors(collection, limit).await
     }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        // Exported metadata is decrypted so it can be written to any store
+        let vectors = self.inner.export_vectors(collection).await?;
+        self.decrypt_stored(vectors)
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        let vectors = self
+            .inner
+            .export_vectors_page(collection, after, limit)
+            .await?;
+        self.decrypt_stored(vectors)
+    }
 }
 
 /// VectorStoreBrowser implementation for encrypted provider
@@ -202,7 +311,7 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
 /// Delegates all browse operations to the inner provider.
 /// Only available when the inner provider also implements VectorStoreBrowser.
 #[async_trait]
-impl<P: VectorStoreProvider + VectorStoreBrowser> VectorStoreBrowser
+impl<P: VectorStoreProvider + VectorStoreBrowser + ?Sized> VectorStoreBrowser
     for EncryptedVectorStoreProvider<P>
 {
     async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
@@ -0,0 +1,218 @@
+//! Inverted-file (IVF-flat) index for the filesystem vector store
+//!
+//! Vectors are clustered around `nlist` centroids by spherical k-means.
+//! A query is compared with the centroids and only the vectors in the
+//! `nprobe` closest lists are scored, so more probes trade latency for
+//! recall. The index holds ids only; candidate vectors are read from the
+//! shards.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Format version of the persisted index
+const IVF_FORMAT_VERSION: u32 = 1;
+/// Training vectors sampled per list
+const SAMPLES_PER_LIST: usize = 32;
+/// K-means refinement rounds
+const KMEANS_ITERATIONS: usize = 8;
+/// Upper bound of the automatic list count
+const MAX_AUTO_LISTS: usize = 1024;
+
+/// IVF index of one collection
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct IvfIndex {
+    version: u32,
+    dimensions: usize,
+    /// Vector count the centroids were trained on
+    trained_on: usize,
+    centroids: Vec<Vec<f32>>,
+    lists: Vec<Vec<String>>,
+    /// List of every id
+    #[serde(skip)]
+    assignments: HashMap<String, usize>,
+}
+
+impl IvfIndex {
+    /// Train centroids on `vectors` and assign every vector to a list
+    ///
+    /// `nlist` of 0 picks the square root of the vector count.
+    pub(super) fn build(dimensions: usize, nlist: usize, vectors: &[(String, Vec<f32>)]) -> Self {
+        let nlist = match nlist {
+            0 => ((vectors.len() as f64).sqrt() as usize).min(MAX_AUTO_LISTS),
+            n => n,
+        }
+        .clamp(1, vectors.len().max(1));
+
+        let centroids = train(dimensions, nlist, vectors);
+        let mut index = Self {
+            version: IVF_FORMAT_VERSION,
+            dimensions,
+            trained_on: vectors.len(),
+            lists: vec![Vec::new(); centroids.len()],
+            centroids,
+            assignments: HashMap::with_capacity(vectors.len()),
+        };
+        for (id, vector) in vectors {
+            index.insert(id.clone(), vector);
+        }
+        index
+    }
+
+    /// Add `id` to the list of its nearest centroid
+    pub(super) fn insert(&mut self, id: String, vector: &[f32]) {
+        self.remove(&id);
+        let list = nearest(&self.centroids, &normalized(vector));
+        self.lists[list].push(id.clone());
+        self.assignments.insert(id, list);
+    }
+
+    pub(super) fn remove(&mut self, id: &str) {
+        if let Some(list) = self.assignments.remove(id) {
+            self.lists[list].retain(|member| member != id);
+        }
+    }
+
+    /// Ids in the `nprobe` lists closest to `query`
+    pub(super) fn candidates(&self, query: &[f32], nprobe: usize) -> Vec<&str> {
+        let query = normalized(query);
+        let mut ranked: Vec<(usize, f32)> = self
+            .centroids
+            .iter()
+            .enumerate()
+            .map(|(list, centroid)| (list, dot(centroid, &query)))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked
+            .into_iter()
+            .take(nprobe.max(1))
+            .flat_map(|(list, _)| self.lists[list].iter().map(String::as_str))
+            .collect()
+    }
+
+    pub(super) fn contains(&self, id: &str) -> bool {
+        self.assignments.contains_key(id)
+    }
+
+    /// Indexed ids
+    pub(super) fn ids(&self) -> impl Iterator<Item = &String> {
+        self.assignments.keys()
+    }
+
+    /// Number of indexed vectors
+    pub(super) fn len(&self) -> usize {
+        self.assignments.len()
+    }
+
+    pub(super) fn list_count(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Whether the collection has grown or shrunk too far from the size
+    /// the centroids were trained on
+    pub(super) fn needs_retraining(&self) -> bool {
+        let len = self.len();
+        len > self.trained_on.saturating_mul(4) || len < self.trained_on / 4
+    }
+
+    /// Restore the id lookup of a deserialized index and check its shape
+    ///
+    /// Ids missing from `expected` are dropped; vectors added since the
+    /// index was saved are picked up by the next reconciliation. Returns
+    /// `None` when the index does not fit the collection.
+    pub(super) fn validate(mut self, dimensions: usize, expected: &HashSet<&str>) -> Option<Self> {
+        if self.version != IVF_FORMAT_VERSION
+            || self.dimensions != dimensions
+            || self.centroids.is_empty()
+            || self.centroids.len() != self.lists.len()
+            || self.centroids.iter().any(|c| c.len() != dimensions)
+        {
+            return None;
+        }
+        for (list, members) in self.lists.iter_mut().enumerate() {
+            members.retain(|id| expected.contains(id.as_str()));
+            for id in members.iter() {
+                if self.assignments.insert(id.clone(), list).is_some() {
+                    return None;
+                }
+            }
+        }
+        Some(self)
+    }
+}
+
+/// Spherical k-means centroids of an evenly spaced sample of `vectors`
+fn train(dimensions: usize, nlist: usize, vectors: &[(String, Vec<f32>)]) -> Vec<Vec<f32>> {
+    if vectors.is_empty() {
+        return vec![vec![0.0; dimensions]];
+    }
+    let sample_size = (nlist * SAMPLES_PER_LIST).min(vectors.len());
+    let step = vectors.len() / sample_size;
+    let sample: Vec<Vec<f32>> = vectors
+        .iter()
+        .step_by(step.max(1))
+        .take(sample_size)
+        .map(|(_, v)| normalized(v))
+        .collect();
+
+    let mut centroids = seed(&sample, nlist);
+
+    for _ in 0..KMEANS_ITERATIONS {
+        let mut sums = vec![vec![0.0f32; dimensions]; centroids.len()];
+        let mut counts = vec![0usize; centroids.len()];
+        for vector in &sample {
+            let list = nearest(&centroids, vector);
+            counts[list] += 1;
+            for (sum, value) in sums[list].iter_mut().zip(vector) {
+                *sum += value;
+            }
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            // Empty clusters keep their previous centroid
+            if count > 0 {
+                *centroid = normalized(&sum);
+            }
+        }
+    }
+    centroids
+}
+
+/// Farthest-point seeding: each new centroid is the sample vector least
+/// similar to every centroid chosen so far
+fn seed(sample: &[Vec<f32>], nlist: usize) -> Vec<Vec<f32>> {
+    let mut centroids = vec![sample[0].clone()];
+    let mut closest: Vec<f32> = sample.iter().map(|v| dot(v, &sample[0])).collect();
+    while centroids.len() < nlist {
+        let Some((next, _)) = closest.iter().enumerate().min_by(|a, b| a.1.total_cmp(b.1)) else {
+            break;
+        };
+        let centroid = sample[next].clone();
+        for (similarity, vector) in closest.iter_mut().zip(sample) {
+            *similarity = similarity.max(dot(vector, &centroid));
+        }
+        centroids.push(centroid);
+    }
+    centroids
+}
+
+fn nearest(centroids: &[Vec<f32>], vector: &[f32]) -> usize {
+    centroids
+        .iter()
+        .enumerate()
+        .map(|(list, centroid)| (list, dot(centroid, vector)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(list, _)| list)
+        .unwrap_or(0)
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn normalized(vector: &[f32]) -> Vec<f32> {
+    let norm = dot(vector, vector).sqrt();
+    if norm == 0.0 {
+        vector.to_vec()
+    } else {
+        vector.iter().map(|v| v / norm).collect()
+    }
+}
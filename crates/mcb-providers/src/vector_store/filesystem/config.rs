@@ -3,10 +3,13 @@
 //! Configuration types for the filesystem-based vector store.
 
 use crate::constants::{
+    FILESYSTEM_VECTOR_STORE_ANN_NPROBE, FILESYSTEM_VECTOR_STORE_CHECKPOINT_WAL_BYTES,
+    FILESYSTEM_VECTOR_STORE_COMPACTION_THRESHOLD, FILESYSTEM_VECTOR_STORE_EXACT_SEARCH_BELOW,
     FILESYSTEM_VECTOR_STORE_INDEX_CACHE_SIZE, FILESYSTEM_VECTOR_STORE_MAX_PER_SHARD,
 };
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::str::FromStr;
 
 /// Filesystem vector store configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,8 +24,96 @@ pub struct FilesystemVectorStoreConfig {
     pub compression_enabled: bool,
     /// Index cache size (number of index entries to keep in memory)
     pub index_cache_size: usize,
-    /// Enable memory mapping for better performance
+    /// Read shards through memory maps instead of file reads
     pub memory_mapping_enabled: bool,
+    /// Similarity search strategy
+    #[serde(default)]
+    pub search: FilesystemSearchConfig,
+    /// Durability and space reclamation
+    #[serde(default)]
+    pub storage: FilesystemStorageConfig,
+}
+
+/// Similarity search strategy of the filesystem vector store
+///
+/// Small collections are scored exhaustively. Larger ones use an IVF index
+/// stored next to the shards as `{collection}_ann.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilesystemSearchConfig {
+    /// Collections with fewer vectors are searched exactly
+    /// (`usize::MAX` always searches exactly)
+    pub exact_below: usize,
+    /// Lists scored per query; more lists raise recall and latency
+    pub nprobe: usize,
+    /// Lists per index; 0 picks the square root of the vector count
+    pub nlist: usize,
+}
+
+/// Returns default FilesystemSearchConfig: exact below 10k vectors, 8 probes, automatic lists
+impl Default for FilesystemSearchConfig {
+    fn default() -> Self {
+        Self {
+            exact_below: FILESYSTEM_VECTOR_STORE_EXACT_SEARCH_BELOW,
+            nprobe: FILESYSTEM_VECTOR_STORE_ANN_NPROBE,
+            nlist: 0,
+        }
+    }
+}
+
+/// When writes are forced to disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilesystemSyncPolicy {
+    /// Sync the shard and the WAL after every vector
+    Always,
+    /// Sync once per insert or delete call
+    #[default]
+    Batch,
+    /// Leave syncing to the operating system
+    Never,
+}
+
+impl FromStr for FilesystemSyncPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "always" => Ok(Self::Always),
+            "batch" => Ok(Self::Batch),
+            "never" => Ok(Self::Never),
+            other => Err(format!(
+                "Unknown sync policy '{other}', expected 'always', 'batch' or 'never'"
+            )),
+        }
+    }
+}
+
+/// Write-ahead log and compaction settings of the filesystem vector store
+///
+/// Inserts and deletes are appended to `{collection}_wal.log`; the
+/// collection index is only rewritten at checkpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilesystemStorageConfig {
+    /// When shard and WAL writes are synced
+    pub sync: FilesystemSyncPolicy,
+    /// WAL size that triggers a checkpoint of the collection index
+    pub checkpoint_wal_bytes: u64,
+    /// Share of deleted records that triggers a background compaction
+    /// (0 disables)
+    pub compaction_threshold: f64,
+}
+
+/// Returns default FilesystemStorageConfig: batch sync, 16 MiB checkpoints, compaction at 50% deleted
+impl Default for FilesystemStorageConfig {
+    fn default() -> Self {
+        Self {
+            sync: FilesystemSyncPolicy::default(),
+            checkpoint_wal_bytes: FILESYSTEM_VECTOR_STORE_CHECKPOINT_WAL_BYTES,
+            compaction_threshold: FILESYSTEM_VECTOR_STORE_COMPACTION_THRESHOLD,
+        }
+    }
 }
 
 /// Returns default FilesystemVectorStoreConfig with sensible defaults for local vector storage
@@ -35,6 +126,8 @@ impl Default for FilesystemVectorStoreConfig {
             compression_enabled: false,
             index_cache_size: FILESYSTEM_VECTOR_STORE_INDEX_CACHE_SIZE,
             memory_mapping_enabled: true,
+            search: FilesystemSearchConfig::default(),
+            storage: FilesystemStorageConfig::default(),
         }
     }
 }
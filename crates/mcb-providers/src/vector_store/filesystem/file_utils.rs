@@ -60,3 +60,40 @@ pub async fn ensure_dir_write_json<T: Serialize>(
     }
     write_json(path, data, description).await
 }
+
+/// Write JSON data through a synced temporary file renamed over `path`
+///
+/// Readers see either the previous or the new content, also after a crash.
+pub async fn write_json_atomic<T: Serialize>(
+    path: &Path,
+    data: &T,
+    description: &str,
+) -> Result<()> {
+    let content = serde_json::to_vec_pretty(data)
+        .map_err(|e| Error::internal(format!("Failed to serialize {}: {}", description, e)))?;
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = std::path::PathBuf::from(tmp);
+        let mut file = std::fs::File::create(&tmp)?;
+        std::io::Write::write_all(&mut file, &content)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, &path)?;
+        sync_parent_dir(&path)
+    })
+    .await
+    .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+    .map_err(|e| Error::io(format!("Failed to write {}: {}", description, e)))
+}
+
+/// Sync the directory holding `path` so a new or renamed entry is durable
+pub fn sync_parent_dir(path: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        std::fs::File::open(parent)?.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
@@ -0,0 +1,370 @@
+//! Filesystem vector store maintenance
+//!
+//! Consistency check of a collection against its shard files when the
+//! collection is loaded, and online compaction that rewrites shards without
+//! their deleted records.
+
+use super::file_utils;
+use super::shards;
+use super::store::FilesystemVectorStore;
+use super::types::{IndexEntry, ShardMetadata};
+use crate::constants::FILESYSTEM_BYTES_PER_DIMENSION;
+use mcb_domain::error::{Error, Result};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+/// Repairs made while checking a collection
+#[derive(Debug, Default)]
+struct ConsistencyReport {
+    /// Index entries pointing at no complete record
+    dropped_entries: usize,
+    /// Bytes of torn records cut from the end of shards
+    truncated_bytes: u64,
+    /// Shards whose metadata was recomputed from the file
+    rescanned_shards: usize,
+    /// Shard files or metadata without live entries that were removed
+    removed_shards: usize,
+}
+
+impl ConsistencyReport {
+    fn is_clean(&self) -> bool {
+        self.dropped_entries == 0
+            && self.truncated_bytes == 0
+            && self.rescanned_shards == 0
+            && self.removed_shards == 0
+    }
+}
+
+// =============================================================================
+// Consistency Check - Verify a loaded collection against its shards
+// =============================================================================
+
+impl FilesystemVectorStore {
+    /// Check a freshly loaded collection against its shard files
+    ///
+    /// A shard whose size differs from its metadata was appended to after
+    /// the last checkpoint, or cut short by a crash. It is rescanned, and a
+    /// torn last record is truncated. Index entries that point at a missing
+    /// shard or at no complete record are dropped, and shards no entry
+    /// points at are removed. Any repair is checkpointed.
+    ///
+    /// Runs before any read of the collection, so no shard of it is mapped
+    /// yet. Callers hold the write lock.
+    pub(super) async fn check_consistency(&self, collection: &str) -> Result<()> {
+        let files = self.list_shard_files(collection).await?;
+        let mut report = ConsistencyReport::default();
+
+        // Rescan shards that changed since their metadata was written
+        let mut record_offsets: HashMap<u32, HashSet<u64>> = HashMap::new();
+        for (&shard_id, &file_len) in &files {
+            let trusted = self
+                .shard_cache
+                .get(&(collection.to_string(), shard_id))
+                .is_some_and(|meta| meta.vectors_size == file_len);
+            if trusted {
+                continue;
+            }
+
+            let path = self.get_shard_path(collection, shard_id);
+            let dimensions = self.config.dimensions;
+            let (offsets, end) = tokio::task::spawn_blocking(move || {
+                let (offsets, end) = shards::scan(&path, dimensions)?;
+                if end < file_len {
+                    let file = std::fs::OpenOptions::new().write(true).open(&path)?;
+                    file.set_len(end)?;
+                    file.sync_all()?;
+                }
+                Ok::<_, std::io::Error>((offsets, end))
+            })
+            .await
+            .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+            .map_err(|e| Error::io(format!("Failed to scan shard: {}", e)))?;
+
+            report.truncated_bytes += file_len - end;
+            report.rescanned_shards += 1;
+            self.shard_cache.insert(
+                (collection.to_string(), shard_id),
+                ShardMetadata::new(shard_id, offsets.len(), end),
+            );
+            record_offsets.insert(shard_id, offsets.into_iter().collect());
+        }
+
+        // Drop entries without a complete record
+        let header = (self.config.dimensions * FILESYSTEM_BYTES_PER_DIMENSION + 4) as u64;
+        let dropped: Vec<String> = self
+            .index_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .filter(|r| {
+                let entry = r.value();
+                match record_offsets.get(&entry.shard_id) {
+                    Some(offsets) => !offsets.contains(&entry.offset),
+                    None => files
+                        .get(&entry.shard_id)
+                        .is_none_or(|&len| entry.offset + header > len),
+                }
+            })
+            .map(|r| r.key().1.clone())
+            .collect();
+        for id in &dropped {
+            self.index_cache
+                .remove(&(collection.to_string(), id.clone()));
+        }
+        if let Some(mut index) = self.ann_indexes.get_mut(collection) {
+            for id in &dropped {
+                index.remove(id);
+            }
+        }
+        report.dropped_entries = dropped.len();
+
+        // Remove shards no entry points at, and metadata without a shard
+        let live: HashSet<u32> = self
+            .index_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .map(|r| r.value().shard_id)
+            .collect();
+        let unused: Vec<u32> = self
+            .shard_cache
+            .iter()
+            .filter(|r| r.key().0 == collection && !live.contains(&r.key().1))
+            .map(|r| r.key().1)
+            .chain(files.keys().copied().filter(|id| !live.contains(id)))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        for shard_id in unused {
+            self.shard_cache.remove(&(collection.to_string(), shard_id));
+            self.remove_shard_files(collection, shard_id).await?;
+            report.removed_shards += 1;
+        }
+
+        if report.is_clean() {
+            return Ok(());
+        }
+        if report.dropped_entries > 0 || report.truncated_bytes > 0 {
+            tracing::warn!(
+                "Repaired collection '{}': dropped {} index entries, truncated {} bytes of torn records",
+                collection,
+                report.dropped_entries,
+                report.truncated_bytes
+            );
+        }
+        tracing::debug!(
+            "Consistency check of collection '{}': {:?}",
+            collection,
+            report
+        );
+        self.save_collection_state(collection).await
+    }
+
+    /// Size of every shard file of a collection
+    async fn list_shard_files(&self, collection: &str) -> Result<HashMap<u32, u64>> {
+        let mut files = HashMap::new();
+        let shards_path = self.shards_dir(collection);
+        if !file_utils::exists(&shards_path).await {
+            return Ok(files);
+        }
+
+        let mut entries = tokio::fs::read_dir(&shards_path)
+            .await
+            .map_err(|e| Error::io(format!("Failed to read shards directory: {}", e)))?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| Error::io(format!("Failed to read directory entry: {}", e)))?
+        {
+            let name = entry.file_name();
+            let Some(shard_id) = name
+                .to_str()
+                .and_then(|name| name.strip_prefix("shard_"))
+                .and_then(|name| name.strip_suffix(".dat"))
+                .and_then(|id| id.parse().ok())
+            else {
+                continue;
+            };
+            let len = entry
+                .metadata()
+                .await
+                .map_err(|e| Error::io(format!("Failed to read shard metadata: {}", e)))?
+                .len();
+            files.insert(shard_id, len);
+        }
+        Ok(files)
+    }
+
+    /// Delete the data and metadata files of a shard
+    async fn remove_shard_files(&self, collection: &str, shard_id: u32) -> Result<()> {
+        let data_path = self.get_shard_path(collection, shard_id);
+        let meta_path = data_path.with_extension("meta");
+        self.shards.forget(&data_path);
+        for path in [data_path, meta_path] {
+            match tokio::fs::remove_file(&path).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(Error::io(format!("Failed to delete shard file: {}", e)));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Compaction - Reclaim the space of deleted vectors
+// =============================================================================
+
+impl FilesystemVectorStore {
+    /// Rewrite every shard holding deleted records, keeping live ones only
+    ///
+    /// Live records are copied to a new shard and the index is checkpointed
+    /// against it before the old shard is deleted, so a crash at any point
+    /// leaves one complete copy. Searches and reads continue meanwhile;
+    /// inserts and deletes of the collection wait. Returns the bytes
+    /// reclaimed.
+    pub async fn compact(&self, collection: &str) -> Result<u64> {
+        self.ensure_loaded(collection).await?;
+        let lock = self.write_lock(collection);
+        let _guard = lock.lock().await;
+
+        let mut live: HashMap<u32, Vec<IndexEntry>> = HashMap::new();
+        for r in self.index_cache.iter().filter(|r| r.key().0 == collection) {
+            live.entry(r.value().shard_id)
+                .or_default()
+                .push(r.value().clone());
+        }
+        let shards: Vec<(u32, usize, u64)> = self
+            .shard_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .map(|r| (r.key().1, r.value().vector_count, r.value().vectors_size))
+            .collect();
+
+        let mut replaced = Vec::new();
+        let mut reclaimed = 0;
+        for (shard_id, vector_count, size) in shards {
+            let entries = live.remove(&shard_id).unwrap_or_default();
+            if entries.len() >= vector_count {
+                continue;
+            }
+
+            let new_size = if entries.is_empty() {
+                0
+            } else {
+                let new_shard_id = self.allocate_shard_id(collection);
+                let count = entries.len();
+                let (moved, new_size) = self
+                    .copy_records(collection, shard_id, new_shard_id, entries)
+                    .await?;
+                self.shard_cache.insert(
+                    (collection.to_string(), new_shard_id),
+                    ShardMetadata::new(new_shard_id, count, new_size),
+                );
+                for entry in moved {
+                    self.index_cache
+                        .insert((collection.to_string(), entry.id.clone()), entry);
+                }
+                new_size
+            };
+            self.shard_cache.remove(&(collection.to_string(), shard_id));
+            replaced.push(shard_id);
+            reclaimed += size.saturating_sub(new_size);
+        }
+
+        if replaced.is_empty() {
+            return Ok(0);
+        }
+        // Point the checkpoint at the new shards before deleting the old ones
+        self.save_collection_state(collection).await?;
+        for shard_id in replaced {
+            self.remove_shard_files(collection, shard_id).await?;
+        }
+        Ok(reclaimed)
+    }
+
+    /// Whether the share of deleted records reached the compaction threshold
+    pub(super) fn needs_compaction(&self, collection: &str) -> bool {
+        let threshold = self.config.storage.compaction_threshold;
+        if threshold <= 0.0 {
+            return false;
+        }
+        let total: usize = self
+            .shard_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .map(|r| r.value().vector_count)
+            .sum();
+        let live = self
+            .index_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .count();
+        let dead = total.saturating_sub(live);
+        dead > 0 && dead as f64 >= threshold * total as f64
+    }
+
+    /// Start a background compaction once enough records are deleted
+    pub(super) fn maybe_compact_in_background(&self, collection: &str) {
+        if !self.needs_compaction(collection) {
+            return;
+        }
+        let store = self.clone();
+        let collection = collection.to_string();
+        tokio::spawn(async move {
+            if let Err(e) = store.compact(&collection).await {
+                tracing::warn!("Compaction of collection '{}' failed: {}", collection, e);
+            }
+        });
+    }
+
+    /// Copy the records of `entries` into a new shard
+    ///
+    /// Returns the entries pointing at their new location and the size of
+    /// the new shard, which is synced before returning.
+    async fn copy_records(
+        &self,
+        collection: &str,
+        from: u32,
+        to: u32,
+        mut entries: Vec<IndexEntry>,
+    ) -> Result<(Vec<IndexEntry>, u64)> {
+        let source = self.get_shard_path(collection, from);
+        let target = self.get_shard_path(collection, to);
+        let shards = self.shards.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut copy = || {
+                let file = std::fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(&target)?;
+                let mut writer = std::io::BufWriter::new(file);
+                let mut offset = 0;
+                entries.sort_by_key(|entry| entry.offset);
+                for entry in &mut entries {
+                    let record = shards.read_record_bytes(&source, entry.offset)?;
+                    writer.write_all(&record)?;
+                    entry.shard_id = to;
+                    entry.offset = offset;
+                    offset += record.len() as u64;
+                }
+                writer
+                    .into_inner()
+                    .map_err(|e| e.into_error())?
+                    .sync_all()?;
+                file_utils::sync_parent_dir(&target)?;
+                Ok::<_, std::io::Error>(offset)
+            };
+            match copy() {
+                Ok(size) => Ok((entries, size)),
+                Err(e) => {
+                    let _ = std::fs::remove_file(&target);
+                    Err(e)
+                }
+            }
+        })
+        .await
+        .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+        .map_err(|e| Error::io(format!("Failed to compact shard: {}", e)))
+    }
+}
@@ -1,31 +1,44 @@
 //! Filesystem-optimized vector store implementation
 //!
 //! Provides high-performance vector storage using memory-mapped files
-//! with optimized indexing for production workloads.
+//! with optimized indexing for production workloads. Changes are logged to
+//! a write-ahead log and checkpointed into the collection index.
 //!
 //! ## Module Structure
 //!
+//! - `ann` - Inverted-file index for approximate search
 //! - `config` - Configuration types
 //! - `types` - Internal shard and index types
 //! - `file_utils` - Async file operations
+//! - `maintenance` - Consistency check and compaction
+//! - `shards` - Memory-mapped shard record reads
 //! - `store` - Core implementation
+//! - `wal` - Write-ahead log
 
+mod ann;
 mod config;
 mod file_utils;
+mod maintenance;
+mod shards;
 mod store;
 mod types;
+mod wal;
 
-pub use config::FilesystemVectorStoreConfig;
+pub use config::{
+    FilesystemSearchConfig, FilesystemStorageConfig, FilesystemSyncPolicy,
+    FilesystemVectorStoreConfig,
+};
 pub use store::FilesystemVectorStore;
 
 use crate::utils::JsonExt;
 use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
-use mcb_domain::value_objects::{CollectionInfo, Embedding, FileInfo, SearchResult};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use types::IndexEntry;
 
 // =============================================================================
 // VectorStoreAdmin Implementation
@@ -34,15 +47,12 @@ use types::IndexEntry;
 #[async_trait]
 impl VectorStoreAdmin for FilesystemVectorStore {
     async fn collection_exists(&self, name: &str) -> Result<bool> {
-        let index_path = self.config.base_path.join(format!("{}_index.json", name));
-        Ok(index_path.exists())
+        Ok(self.index_path(name).exists())
     }
 
     async fn get_stats(&self, collection: &str) -> Result<HashMap<String, serde_json::Value>> {
         // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
+        self.ensure_loaded(collection).await?;
 
         let mut stats = HashMap::new();
         stats.insert("collection".to_string(), serde_json::json!(collection));
@@ -81,13 +91,52 @@ impl VectorStoreAdmin for FilesystemVectorStore {
             serde_json::json!(total_size),
         );
 
+        let search_mode = if self.uses_ann(total_vectors) {
+            "ann"
+        } else {
+            "exact"
+        };
+        stats.insert("search_mode".to_string(), serde_json::json!(search_mode));
+        let ann_lists = self
+            .ann_indexes
+            .get(collection)
+            .map_or(0, |index| index.list_count());
+        stats.insert("ann_lists".to_string(), serde_json::json!(ann_lists));
+
+        let appended: usize = self
+            .shard_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .map(|r| r.value().vector_count)
+            .sum();
+        stats.insert(
+            "dead_vectors".to_string(),
+            serde_json::json!(appended.saturating_sub(total_vectors)),
+        );
+        stats.insert(
+            "wal_bytes".to_string(),
+            serde_json::json!(wal::size(&self.wal_path(collection))),
+        );
+        stats.insert(
+            "sync_policy".to_string(),
+            serde_json::json!(self.config.storage.sync),
+        );
+
         Ok(stats)
     }
 
     async fn flush(&self, collection: &str) -> Result<()> {
+        self.ensure_loaded(collection).await?;
+        let lock = self.write_lock(collection);
+        let _guard = lock.lock().await;
         self.save_collection_state(collection).await
     }
 
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        let collections = VectorStoreBrowser::list_collections(self).await?;
+        Ok(collections.into_iter().map(|c| c.name).collect())
+    }
+
     fn provider_name(&self) -> &str {
         "filesystem"
     }
@@ -101,36 +150,56 @@ impl VectorStoreAdmin for FilesystemVectorStore {
 impl VectorStoreProvider for FilesystemVectorStore {
     async fn create_collection(&self, name: &str, _dimensions: usize) -> Result<()> {
         // Try to load existing collection, if it doesn't exist, create it
+        self.ensure_loaded(name).await?;
         if !self.collection_exists(name).await? {
             // Collection doesn't exist, save initial empty state
+            let lock = self.write_lock(name);
+            let _guard = lock.lock().await;
             self.save_collection_state(name).await?;
-        } else {
-            self.load_collection_state(name).await?;
         }
 
         Ok(())
     }
 
     async fn delete_collection(&self, name: &str) -> Result<()> {
+        let lock = self.write_lock(name);
+        let _guard = lock.lock().await;
+
         // Remove all files for this collection
-        let collection_path = self.config.base_path.join(format!("{}_shards", name));
+        let collection_path = self.shards_dir(name);
+        self.shards.forget_dir(&collection_path);
         if collection_path.exists() {
             tokio::fs::remove_dir_all(&collection_path)
                 .await
                 .map_err(|e| Error::io(format!("Failed to delete collection shards: {}", e)))?;
         }
 
-        let index_path = self.config.base_path.join(format!("{}_index.json", name));
+        let index_path = self.index_path(name);
         if index_path.exists() {
             tokio::fs::remove_file(index_path)
                 .await
                 .map_err(|e| Error::io(format!("Failed to delete collection index: {}", e)))?;
         }
 
+        let wal_path = self.wal_path(name);
+        if wal_path.exists() {
+            tokio::fs::remove_file(wal_path)
+                .await
+                .map_err(|e| Error::io(format!("Failed to delete write-ahead log: {}", e)))?;
+        }
+
+        let ann_path = self.ann_index_path(name);
+        if ann_path.exists() {
+            tokio::fs::remove_file(ann_path)
+                .await
+                .map_err(|e| Error::io(format!("Failed to delete ANN index: {}", e)))?;
+        }
+
         // Clear caches
         self.index_cache.retain(|k, _| k.0 != name);
         self.shard_cache.retain(|k, _| k.0 != name);
         self.next_shard_ids.remove(name);
+        self.ann_indexes.remove(name);
 
         Ok(())
     }
@@ -141,44 +210,33 @@ impl VectorStoreProvider for FilesystemVectorStore {
         vectors: &[Embedding],
         metadata: Vec<std::collections::HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>> {
-        // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
-
-        let mut ids = Vec::new();
-
-        for (i, (vector, meta)) in vectors.iter().zip(metadata.iter()).enumerate() {
-            let id = format!(
-                "{}_{}_{}",
-                collection,
-                i,
-                std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_nanos()
-            );
-            let shard_id = self.find_optimal_shard(collection);
-            let offset = self
-                .write_vector_to_shard(collection, shard_id, &id, &vector.vector, meta)
-                .await?;
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let ids: Vec<String> = (0..vectors.len().min(metadata.len()))
+            .map(|i| format!("{}_{}_{}", collection, i, nanos))
+            .collect();
+        self.write_vectors(collection, &ids, vectors, &metadata)
+            .await?;
 
-            let index_entry = IndexEntry {
-                id: id.clone(),
-                shard_id,
-                offset,
-                metadata: meta.clone(),
-            };
+        Ok(ids)
+    }
 
-            self.index_cache
-                .insert((collection.to_string(), id.clone()), index_entry);
-            ids.push(id);
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Ids, vectors and metadata length mismatch",
+            ));
         }
-
-        // Save state
-        self.save_collection_state(collection).await?;
-
-        Ok(ids)
+        self.write_vectors(collection, ids, vectors, &metadata)
+            .await
     }
 
     async fn search_similar(
@@ -189,40 +247,67 @@ impl VectorStoreProvider for FilesystemVectorStore {
         _filter: Option<&str>,
     ) -> Result<Vec<SearchResult>> {
         // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
+        self.ensure_loaded(collection).await?;
 
-        self.brute_force_search(collection, query_vector, limit)
-            .await
+        self.search(collection, query_vector, limit).await
     }
 
     async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
         // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
+        self.ensure_loaded(collection).await?;
 
-        // Remove from index
-        for id in ids {
-            self.index_cache
-                .remove(&(collection.to_string(), id.clone()));
+        {
+            let lock = self.write_lock(collection);
+            let _guard = lock.lock().await;
+            self.commit_deletes(collection, ids).await?;
         }
 
-        // Save state
-        self.save_collection_state(collection).await?;
+        self.maybe_compact_in_background(collection);
         Ok(())
     }
 
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        filter.ensure_not_empty()?;
+        self.ensure_loaded(collection).await?;
+
+        let deleted = {
+            let lock = self.write_lock(collection);
+            let _guard = lock.lock().await;
+            let ids: Vec<String> = self
+                .index_cache
+                .iter()
+                .filter(|r| r.key().0 == collection && filter.matches(&r.value().metadata))
+                .map(|r| r.key().1.clone())
+                .collect();
+            if !ids.is_empty() {
+                self.commit_deletes(collection, &ids).await?;
+            }
+            ids.len() as u64
+        };
+
+        self.maybe_compact_in_background(collection);
+        Ok(deleted)
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        self.ensure_loaded(collection).await?;
+
+        let filter = MetadataFilter::file_path(file_path);
+        Ok(self
+            .index_cache
+            .iter()
+            .filter(|r| r.key().0 == collection && filter.matches(&r.value().metadata))
+            .map(|r| r.key().1.clone())
+            .collect())
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
         ids: &[String],
     ) -> Result<Vec<SearchResult>> {
         // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
+        self.ensure_loaded(collection).await?;
 
         let mut results = Vec::new();
         for id in ids {
@@ -255,9 +340,7 @@ impl VectorStoreProvider for FilesystemVectorStore {
 
     async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
         // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
+        self.ensure_loaded(collection).await?;
 
         let mut results = Vec::new();
         let entries: Vec<_> = self
@@ -293,6 +376,77 @@ impl VectorStoreProvider for FilesystemVectorStore {
         }
         Ok(results)
     }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        if !self.collection_exists(collection).await? {
+            return Err(Error::vector_db(format!(
+                "Collection '{}' not found",
+                collection
+            )));
+        }
+        self.ensure_loaded(collection).await?;
+
+        let entries: Vec<_> = self
+            .index_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .map(|r| (r.key().1.clone(), r.value().clone()))
+            .collect();
+
+        // Unlike listing, a record that cannot be read fails the export
+        let mut vectors = Vec::with_capacity(entries.len());
+        for (id, entry) in entries {
+            let (vector, metadata) = self
+                .read_vector_from_shard(collection, entry.shard_id, entry.offset)
+                .await?;
+            vectors.push(StoredVector {
+                id,
+                vector,
+                metadata,
+            });
+        }
+        Ok(vectors)
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        if !self.collection_exists(collection).await? {
+            return Err(Error::vector_db(format!(
+                "Collection '{}' not found",
+                collection
+            )));
+        }
+        self.ensure_loaded(collection).await?;
+
+        // Only the page's records are read from the shards
+        let mut entries: Vec<_> = self
+            .index_cache
+            .iter()
+            .filter(|r| {
+                r.key().0 == collection && after.is_none_or(|after| r.key().1.as_str() > after)
+            })
+            .map(|r| (r.key().1.clone(), r.value().clone()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries.truncate(limit);
+
+        let mut vectors = Vec::with_capacity(entries.len());
+        for (id, entry) in entries {
+            let (vector, metadata) = self
+                .read_vector_from_shard(collection, entry.shard_id, entry.offset)
+                .await?;
+            vectors.push(StoredVector {
+                id,
+                vector,
+                metadata,
+            });
+        }
+        Ok(vectors)
+    }
 }
 
 // =============================================================================
@@ -358,9 +512,7 @@ impl VectorStoreBrowser for FilesystemVectorStore {
 
     async fn list_file_paths(&self, collection: &str, limit: usize) -> Result<Vec<FileInfo>> {
         // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
+        self.ensure_loaded(collection).await?;
 
         // Aggregate file info from index cache
         let mut file_map: HashMap<String, (u32, String)> = HashMap::new();
@@ -404,9 +556,7 @@ impl VectorStoreBrowser for FilesystemVectorStore {
         file_path: &str,
     ) -> Result<Vec<SearchResult>> {
         // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
+        self.ensure_loaded(collection).await?;
 
         let mut results = Vec::new();
 
@@ -474,9 +624,32 @@ fn filesystem_factory(
         "Filesystem store requires 'dimensions' configuration (embedding vector size)".to_string()
     })?;
 
+    let search_defaults = FilesystemSearchConfig::default();
+    let search = FilesystemSearchConfig {
+        exact_below: extra_or(config, "exact_below", search_defaults.exact_below)?,
+        nprobe: extra_or(config, "nprobe", search_defaults.nprobe)?,
+        nlist: extra_or(config, "nlist", search_defaults.nlist)?,
+    };
+    let storage_defaults = FilesystemStorageConfig::default();
+    let storage = FilesystemStorageConfig {
+        sync: extra_or(config, "sync", storage_defaults.sync)?,
+        checkpoint_wal_bytes: extra_or(
+            config,
+            "checkpoint_wal_bytes",
+            storage_defaults.checkpoint_wal_bytes,
+        )?,
+        compaction_threshold: extra_or(
+            config,
+            "compaction_threshold",
+            storage_defaults.compaction_threshold,
+        )?,
+    };
+
     let fs_config = FilesystemVectorStoreConfig {
         base_path: std::path::PathBuf::from(base_path),
         dimensions,
+        search,
+        storage,
         ..Default::default()
     };
 
@@ -490,6 +663,20 @@ fn filesystem_factory(
     Ok(Arc::new(store))
 }
 
+/// Parse `extra[key]`, or fall back to `default`
+fn extra_or<T: std::str::FromStr>(
+    config: &VectorStoreProviderConfig,
+    key: &str,
+    default: T,
+) -> std::result::Result<T, String> {
+    match config.extra.get(key) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid filesystem store {key} '{value}'")),
+        None => Ok(default),
+    }
+}
+
 #[linkme::distributed_slice(VECTOR_STORE_PROVIDERS)]
 static FILESYSTEM_PROVIDER: VectorStoreProviderEntry = VectorStoreProviderEntry {
     name: "filesystem",
@@ -0,0 +1,155 @@
+//! Shard record reads
+//!
+//! A shard record is the vector as little-endian `f32`s, a `u32` metadata
+//! length and the JSON metadata. Shards are read through cached memory
+//! maps, or with plain file reads when memory mapping is disabled.
+
+use crate::constants::FILESYSTEM_BYTES_PER_DIMENSION;
+use dashmap::DashMap;
+use memmap2::Mmap;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Size of the metadata length field that follows the vector
+const METADATA_LEN_BYTES: usize = 4;
+
+/// Reader shared by every collection of a store
+///
+/// Cheap to clone, so blocking tasks can take their own copy.
+#[derive(Clone)]
+pub(super) struct ShardReader {
+    /// Memory maps by shard path; remapped when a shard has grown
+    maps: Arc<DashMap<PathBuf, Arc<Mmap>>>,
+    memory_mapping: bool,
+    vector_len: usize,
+}
+
+impl ShardReader {
+    pub(super) fn new(memory_mapping: bool, dimensions: usize) -> Self {
+        Self {
+            maps: Arc::new(DashMap::new()),
+            memory_mapping,
+            vector_len: dimensions * FILESYSTEM_BYTES_PER_DIMENSION,
+        }
+    }
+
+    /// Read the vector of the record at `offset`
+    pub(super) fn read_vector(&self, path: &Path, offset: u64) -> std::io::Result<Vec<f32>> {
+        let mut bytes = vec![0u8; self.vector_len];
+        self.read_exact_at(path, offset, &mut bytes)?;
+        Ok(bytes_to_vector(&bytes))
+    }
+
+    /// Read the vector and raw metadata of the record at `offset`
+    pub(super) fn read_record(
+        &self,
+        path: &Path,
+        offset: u64,
+    ) -> std::io::Result<(Vec<f32>, Vec<u8>)> {
+        let mut record = self.read_record_bytes(path, offset)?;
+        let metadata = record.split_off(self.vector_len + METADATA_LEN_BYTES);
+        Ok((bytes_to_vector(&record[..self.vector_len]), metadata))
+    }
+
+    /// Read the record at `offset` as stored
+    pub(super) fn read_record_bytes(&self, path: &Path, offset: u64) -> std::io::Result<Vec<u8>> {
+        let header = self.vector_len + METADATA_LEN_BYTES;
+        let mut record = vec![0u8; header];
+        self.read_exact_at(path, offset, &mut record)?;
+        let metadata_len = u32::from_le_bytes([
+            record[header - 4],
+            record[header - 3],
+            record[header - 2],
+            record[header - 1],
+        ]) as usize;
+        record.resize(header + metadata_len, 0);
+        self.read_exact_at(path, offset + header as u64, &mut record[header..])?;
+        Ok(record)
+    }
+
+    /// Drop the cached map of a shard that was removed or replaced
+    pub(super) fn forget(&self, path: &Path) {
+        self.maps.remove(path);
+    }
+
+    /// Drop the cached maps of every shard under `dir`
+    pub(super) fn forget_dir(&self, dir: &Path) {
+        self.maps.retain(|path, _| !path.starts_with(dir));
+    }
+
+    fn read_exact_at(&self, path: &Path, offset: u64, buf: &mut [u8]) -> std::io::Result<()> {
+        if !self.memory_mapping {
+            let mut file = std::fs::File::open(path)?;
+            file.seek(std::io::SeekFrom::Start(offset))?;
+            return file.read_exact(buf);
+        }
+
+        let start = usize::try_from(offset).map_err(|_| eof())?;
+        let end = start + buf.len();
+        let map = self.map(path, end)?;
+        buf.copy_from_slice(&map[start..end]);
+        Ok(())
+    }
+
+    /// Map of `path` covering at least `min_len` bytes
+    fn map(&self, path: &Path, min_len: usize) -> std::io::Result<Arc<Mmap>> {
+        if let Some(map) = self.maps.get(path)
+            && map.len() >= min_len
+        {
+            return Ok(Arc::clone(&map));
+        }
+
+        let file = std::fs::File::open(path)?;
+        // SAFETY: the store only appends to shard files and never truncates
+        // or rewrites them in place. Compaction writes a new shard and
+        // unlinks the old one, which stays valid for existing maps.
+        let map = Arc::new(unsafe { Mmap::map(&file)? });
+        if map.len() < min_len {
+            return Err(eof());
+        }
+        self.maps.insert(path.to_path_buf(), Arc::clone(&map));
+        Ok(map)
+    }
+}
+
+/// Offsets of the complete records in a shard file and the end of the last one
+///
+/// Bytes past the returned end belong to a record cut short by a crash.
+pub(super) fn scan(path: &Path, dimensions: usize) -> std::io::Result<(Vec<u64>, u64)> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let header = (dimensions * FILESYSTEM_BYTES_PER_DIMENSION + METADATA_LEN_BYTES) as u64;
+
+    let mut offsets = Vec::new();
+    let mut pos = 0;
+    let mut metadata_len = [0u8; METADATA_LEN_BYTES];
+    while pos + header <= len {
+        file.seek(std::io::SeekFrom::Start(
+            pos + header - METADATA_LEN_BYTES as u64,
+        ))?;
+        file.read_exact(&mut metadata_len)?;
+        let end = pos + header + u64::from(u32::from_le_bytes(metadata_len));
+        if end > len {
+            break;
+        }
+        offsets.push(pos);
+        pos = end;
+    }
+    Ok((offsets, pos))
+}
+
+/// Convert little-endian bytes to a vector
+pub(super) fn bytes_to_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(FILESYSTEM_BYTES_PER_DIMENSION)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+fn eof() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::UnexpectedEof,
+        "record extends past the end of the shard",
+    )
+}
@@ -2,16 +2,19 @@
 //!
 //! Core struct and internal methods for the filesystem-based vector store.
 
-use super::config::FilesystemVectorStoreConfig;
+use super::ann::IvfIndex;
+use super::config::{FilesystemSyncPolicy, FilesystemVectorStoreConfig};
 use super::file_utils;
+use super::shards::ShardReader;
 use super::types::{IndexEntry, ShardMetadata};
+use super::wal::{self, WalRecord};
 use crate::constants::FILESYSTEM_BYTES_PER_DIMENSION;
 use crate::utils::JsonExt;
 use dashmap::DashMap;
 use mcb_domain::error::{Error, Result};
-use mcb_domain::value_objects::SearchResult;
-use std::collections::HashMap;
-use std::io::{Read, Seek, Write};
+use mcb_domain::value_objects::{Embedding, SearchResult};
+use std::collections::{HashMap, HashSet};
+use std::io::{Seek, Write};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -26,6 +29,14 @@ pub struct FilesystemVectorStore {
     pub(super) shard_cache: Arc<DashMap<(String, u32), ShardMetadata>>,
     /// Next shard ID to use per collection
     pub(super) next_shard_ids: Arc<DashMap<String, Arc<AtomicU32>>>,
+    /// ANN index per collection, built on the first approximate search
+    pub(super) ann_indexes: Arc<DashMap<String, IvfIndex>>,
+    /// Serializes ANN index builds
+    pub(super) ann_build: Arc<tokio::sync::Mutex<()>>,
+    /// Shard reads, memory-mapped when enabled
+    pub(super) shards: ShardReader,
+    /// Per-collection lock serializing writes, checkpoints and compaction
+    pub(super) write_locks: Arc<DashMap<String, Arc<tokio::sync::Mutex<()>>>>,
 }
 
 // =============================================================================
@@ -41,10 +52,14 @@ impl FilesystemVectorStore {
             .map_err(|e| Error::io(format!("Failed to create base directory: {}", e)))?;
 
         let store = Self {
+            shards: ShardReader::new(config.memory_mapping_enabled, config.dimensions),
             config,
             index_cache: Arc::new(DashMap::new()),
             shard_cache: Arc::new(DashMap::new()),
             next_shard_ids: Arc::new(DashMap::new()),
+            ann_indexes: Arc::new(DashMap::new()),
+            ann_build: Arc::new(tokio::sync::Mutex::new(())),
+            write_locks: Arc::new(DashMap::new()),
         };
 
         Ok(store)
@@ -56,13 +71,53 @@ impl FilesystemVectorStore {
 // =============================================================================
 
 impl FilesystemVectorStore {
+    /// Path of the index checkpoint of a collection
+    pub(super) fn index_path(&self, collection: &str) -> PathBuf {
+        self.config
+            .base_path
+            .join(format!("{}_index.json", collection))
+    }
+
+    /// Path of the write-ahead log of a collection
+    pub(super) fn wal_path(&self, collection: &str) -> PathBuf {
+        self.config
+            .base_path
+            .join(format!("{}_wal.log", collection))
+    }
+
+    /// Directory holding the shards of a collection
+    pub(super) fn shards_dir(&self, collection: &str) -> PathBuf {
+        self.config.base_path.join(format!("{}_shards", collection))
+    }
+
+    /// Write lock of a collection
+    pub(super) fn write_lock(&self, collection: &str) -> Arc<tokio::sync::Mutex<()>> {
+        self.write_locks
+            .entry(collection.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Load a collection on first use
+    pub(super) async fn ensure_loaded(&self, collection: &str) -> Result<()> {
+        if self.next_shard_ids.contains_key(collection) {
+            return Ok(());
+        }
+        let lock = self.write_lock(collection);
+        let _guard = lock.lock().await;
+        if self.next_shard_ids.contains_key(collection) {
+            return Ok(());
+        }
+        self.load_collection_state(collection).await
+    }
+
     /// Load existing state from disk for a collection
+    ///
+    /// Reads the last checkpoint, replays the write-ahead log on top of it
+    /// and checks the result against the shards. Callers hold the write lock.
     pub(super) async fn load_collection_state(&self, collection: &str) -> Result<()> {
         // Load global index
-        let index_path = self
-            .config
-            .base_path
-            .join(format!("{}_index.json", collection));
+        let index_path = self.index_path(collection);
         if file_utils::exists(&index_path).await {
             let index: HashMap<String, IndexEntry> =
                 file_utils::read_json(&index_path, "collection index").await?;
@@ -72,7 +127,7 @@ impl FilesystemVectorStore {
         }
 
         // Load shard metadata
-        let shards_path = self.config.base_path.join(format!("{}_shards", collection));
+        let shards_path = self.shards_dir(collection);
         if shards_path.exists() {
             let mut entries = tokio::fs::read_dir(&shards_path)
                 .await
@@ -93,6 +148,10 @@ impl FilesystemVectorStore {
             }
         }
 
+        self.replay_wal(collection).await?;
+        self.load_ann_index(collection).await;
+        self.check_consistency(collection).await?;
+
         // Find next shard ID
         let max_shard_id = self
             .shard_cache
@@ -110,32 +169,136 @@ impl FilesystemVectorStore {
         Ok(())
     }
 
-    /// Save state to disk for a collection
+    /// Apply the changes logged since the last checkpoint
+    async fn replay_wal(&self, collection: &str) -> Result<()> {
+        let path = self.wal_path(collection);
+        let records = tokio::task::spawn_blocking(move || wal::replay(&path))
+            .await
+            .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+            .map_err(|e| Error::io(format!("Failed to replay write-ahead log: {}", e)))?;
+
+        for record in records {
+            match record {
+                WalRecord::Insert { entry } => {
+                    self.index_cache
+                        .insert((collection.to_string(), entry.id.clone()), entry);
+                }
+                WalRecord::Delete { ids } => {
+                    for id in ids {
+                        self.index_cache.remove(&(collection.to_string(), id));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checkpoint a collection: write its index and shard metadata, then
+    /// empty the write-ahead log
+    ///
+    /// Callers hold the write lock, so nothing is logged between the
+    /// snapshot of the index and the reset of the log.
     pub(super) async fn save_collection_state(&self, collection: &str) -> Result<()> {
         // Save global index
-        let index_path = self
-            .config
-            .base_path
-            .join(format!("{}_index.json", collection));
         let index: HashMap<String, IndexEntry> = self
             .index_cache
             .iter()
             .filter(|r| r.key().0 == collection)
             .map(|r| (r.key().1.clone(), r.value().clone()))
             .collect();
-        file_utils::write_json(&index_path, &index, "collection index").await?;
+        file_utils::write_json_atomic(&self.index_path(collection), &index, "collection index")
+            .await?;
 
         // Save shard metadata
-        let shards_path = self.config.base_path.join(format!("{}_shards", collection));
-        for r in self.shard_cache.iter() {
-            let (c, shard_id) = r.key();
-            if c == collection {
-                let meta_path = shards_path.join(format!("shard_{}.meta", shard_id));
-                file_utils::ensure_dir_write_json(&meta_path, r.value(), "shard metadata").await?;
-            }
+        let shards_path = self.shards_dir(collection);
+        let metadata: Vec<ShardMetadata> = self
+            .shard_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .map(|r| r.value().clone())
+            .collect();
+        for meta in metadata {
+            let meta_path = shards_path.join(format!("shard_{}.meta", meta.shard_id));
+            file_utils::ensure_dir_write_json(&meta_path, &meta, "shard metadata").await?;
+        }
+        self.save_ann_index(collection).await?;
+
+        let wal_path = self.wal_path(collection);
+        tokio::task::spawn_blocking(move || wal::reset(&wal_path))
+            .await
+            .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+            .map_err(|e| Error::io(format!("Failed to reset write-ahead log: {}", e)))
+    }
+
+    /// Checkpoint once the write-ahead log has outgrown its limit
+    async fn maybe_checkpoint(&self, collection: &str, wal_size: u64) -> Result<()> {
+        if wal_size >= self.config.storage.checkpoint_wal_bytes {
+            self.save_collection_state(collection).await?;
         }
         Ok(())
     }
+
+    /// Path of the persisted ANN index of a collection
+    pub(super) fn ann_index_path(&self, collection: &str) -> PathBuf {
+        self.config
+            .base_path
+            .join(format!("{}_ann.json", collection))
+    }
+
+    /// Load the persisted ANN index of a collection
+    ///
+    /// An index that is missing, unreadable or out of step with the
+    /// collection index is dropped; the next approximate search rebuilds it
+    /// from the shards.
+    async fn load_ann_index(&self, collection: &str) {
+        let path = self.ann_index_path(collection);
+        if !file_utils::exists(&path).await {
+            return;
+        }
+        let ids: Vec<String> = self
+            .index_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .map(|r| r.key().1.clone())
+            .collect();
+        let expected: HashSet<&str> = ids.iter().map(String::as_str).collect();
+
+        match file_utils::read_json::<IvfIndex>(&path, "ANN index").await {
+            Ok(index) => match index.validate(self.config.dimensions, &expected) {
+                Some(index) => {
+                    self.ann_indexes.insert(collection.to_string(), index);
+                }
+                None => tracing::warn!(
+                    "ANN index of collection '{}' does not fit the collection; it will be rebuilt",
+                    collection
+                ),
+            },
+            Err(e) => tracing::warn!(
+                "Ignoring ANN index of collection '{}', it will be rebuilt: {}",
+                collection,
+                e
+            ),
+        }
+    }
+
+    /// Save the ANN index of a collection, or remove a stale one
+    async fn save_ann_index(&self, collection: &str) -> Result<()> {
+        let path = self.ann_index_path(collection);
+        // Serialize before writing so no map guard is held across the await
+        let content = match self.ann_indexes.get(collection) {
+            Some(index) => serde_json::to_vec(&*index)
+                .map_err(|e| Error::internal(format!("Failed to serialize ANN index: {}", e)))?,
+            None => {
+                return match tokio::fs::remove_file(&path).await {
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                        Err(Error::io(format!("Failed to delete ANN index: {}", e)))
+                    }
+                    _ => Ok(()),
+                };
+            }
+        };
+        file_utils::ensure_dir_write(&path, &content, "ANN index").await
+    }
 }
 
 // =============================================================================
@@ -145,9 +308,7 @@ impl FilesystemVectorStore {
 impl FilesystemVectorStore {
     /// Get shard file path for a collection
     pub(super) fn get_shard_path(&self, collection: &str, shard_id: u32) -> PathBuf {
-        self.config
-            .base_path
-            .join(format!("{}_shards", collection))
+        self.shards_dir(collection)
             .join(format!("shard_{}.dat", shard_id))
     }
 
@@ -172,16 +333,7 @@ impl FilesystemVectorStore {
             // Create shard file with empty content
             file_utils::ensure_dir_write(&shard_path, &[], "shard file").await?;
 
-            let metadata = ShardMetadata {
-                shard_id,
-                vector_count: 0,
-                vectors_offset: 0,
-                vectors_size: 0,
-                created_at: std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_secs(),
-            };
+            let metadata = ShardMetadata::new(shard_id, 0, 0);
             self.shard_cache
                 .insert((collection.to_string(), shard_id), metadata);
         }
@@ -221,6 +373,51 @@ impl FilesystemVectorStore {
 // =============================================================================
 
 impl FilesystemVectorStore {
+    /// Write vectors under `ids`, replacing index entries with the same id
+    ///
+    /// Replaced records stay in their shard as dead space until compaction.
+    pub(super) async fn write_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: &[HashMap<String, serde_json::Value>],
+    ) -> Result<()> {
+        self.ensure_loaded(collection).await?;
+        let lock = self.write_lock(collection);
+        let _guard = lock.lock().await;
+        if !self.index_path(collection).exists() {
+            // First write: checkpoint so the collection is listed
+            self.save_collection_state(collection).await?;
+        }
+
+        let always = self.config.storage.sync == FilesystemSyncPolicy::Always;
+        let mut written = Vec::new();
+
+        for ((id, vector), meta) in ids.iter().zip(vectors).zip(metadata) {
+            let shard_id = self.find_optimal_shard(collection);
+            let offset = self
+                .write_vector_to_shard(collection, shard_id, id, &vector.vector, meta)
+                .await?;
+
+            let index_entry = IndexEntry {
+                id: id.clone(),
+                shard_id,
+                offset,
+                metadata: meta.clone(),
+            };
+            written.push((index_entry, vector.vector.as_slice()));
+
+            if always {
+                self.commit_inserts(collection, std::mem::take(&mut written))
+                    .await?;
+            }
+        }
+
+        // Log the batch and publish it in the index
+        self.commit_inserts(collection, written).await
+    }
+
     /// Write vector to shard
     pub(super) async fn write_vector_to_shard(
         &self,
@@ -270,6 +467,96 @@ impl FilesystemVectorStore {
         Ok(offset)
     }
 
+    /// Sync shard files to disk
+    async fn sync_shards(&self, collection: &str, shard_ids: HashSet<u32>) -> Result<()> {
+        let paths: Vec<PathBuf> = shard_ids
+            .into_iter()
+            .map(|shard_id| self.get_shard_path(collection, shard_id))
+            .collect();
+        tokio::task::spawn_blocking(move || {
+            for path in paths {
+                std::fs::OpenOptions::new()
+                    .write(true)
+                    .open(&path)?
+                    .sync_data()?;
+            }
+            Ok::<_, std::io::Error>(())
+        })
+        .await
+        .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+        .map_err(|e| Error::io(format!("Failed to sync shard: {}", e)))
+    }
+
+    /// Append records to the write-ahead log, returning its new size
+    async fn append_wal(
+        &self,
+        collection: &str,
+        records: Vec<WalRecord>,
+        sync: bool,
+    ) -> Result<u64> {
+        let path = self.wal_path(collection);
+        tokio::task::spawn_blocking(move || wal::append(&path, &records, sync))
+            .await
+            .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+            .map_err(|e| Error::io(format!("Failed to append to write-ahead log: {}", e)))
+    }
+
+    /// Log vectors written to shards and add them to the index
+    ///
+    /// Unless syncing is disabled, the shards are synced before the log, so
+    /// a logged entry never points at data that did not reach the disk.
+    /// Callers hold the write lock.
+    pub(super) async fn commit_inserts(
+        &self,
+        collection: &str,
+        written: Vec<(IndexEntry, &[f32])>,
+    ) -> Result<()> {
+        if written.is_empty() {
+            return Ok(());
+        }
+        let sync = self.config.storage.sync != FilesystemSyncPolicy::Never;
+        if sync {
+            let shard_ids = written.iter().map(|(entry, _)| entry.shard_id).collect();
+            self.sync_shards(collection, shard_ids).await?;
+        }
+        let records = written
+            .iter()
+            .map(|(entry, _)| WalRecord::Insert {
+                entry: entry.clone(),
+            })
+            .collect();
+        let wal_size = self.append_wal(collection, records, sync).await?;
+
+        for (entry, vector) in written {
+            if let Some(mut index) = self.ann_indexes.get_mut(collection) {
+                index.insert(entry.id.clone(), vector);
+            }
+            self.index_cache
+                .insert((collection.to_string(), entry.id.clone()), entry);
+        }
+        self.maybe_checkpoint(collection, wal_size).await
+    }
+
+    /// Log deleted ids and remove them from the index
+    ///
+    /// Callers hold the write lock.
+    pub(super) async fn commit_deletes(&self, collection: &str, ids: &[String]) -> Result<()> {
+        let sync = self.config.storage.sync != FilesystemSyncPolicy::Never;
+        let records = vec![WalRecord::Delete { ids: ids.to_vec() }];
+        let wal_size = self.append_wal(collection, records, sync).await?;
+
+        for id in ids {
+            self.index_cache
+                .remove(&(collection.to_string(), id.clone()));
+        }
+        if let Some(mut index) = self.ann_indexes.get_mut(collection) {
+            for id in ids {
+                index.remove(id);
+            }
+        }
+        self.maybe_checkpoint(collection, wal_size).await
+    }
+
     /// Read vector from shard
     pub(super) async fn read_vector_from_shard(
         &self,
@@ -278,38 +565,44 @@ impl FilesystemVectorStore {
         offset: u64,
     ) -> Result<(Vec<f32>, HashMap<String, serde_json::Value>)> {
         let shard_path = self.get_shard_path(collection, shard_id);
-        let dimensions = self.config.dimensions;
+        let shards = self.shards.clone();
 
-        tokio::task::spawn_blocking(move || {
-            let mut file = std::fs::File::open(&shard_path)?;
-            file.seek(std::io::SeekFrom::Start(offset))?;
-
-            // Read vector data
-            let mut bytes = vec![0u8; dimensions * FILESYSTEM_BYTES_PER_DIMENSION];
-            file.read_exact(&mut bytes)?;
-            let mut vector = Vec::with_capacity(dimensions);
-            for chunk in bytes.chunks_exact(FILESYSTEM_BYTES_PER_DIMENSION) {
-                let value = f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
-                vector.push(value);
-            }
+        let (vector, metadata_bytes) =
+            tokio::task::spawn_blocking(move || shards.read_record(&shard_path, offset))
+                .await
+                .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
+                .map_err(|e| Error::io(format!("Failed to read from shard: {}", e)))?;
+        let metadata: HashMap<String, serde_json::Value> = serde_json::from_slice(&metadata_bytes)
+            .map_err(|e| Error::io(format!("Failed to read from shard: {}", e)))?;
 
-            // Read metadata length
-            let mut metadata_len_bytes = [0u8; 4];
-            file.read_exact(&mut metadata_len_bytes)?;
-            let metadata_len = u32::from_le_bytes(metadata_len_bytes);
+        Ok((vector, metadata))
+    }
 
-            // Read metadata
-            let mut metadata_bytes = vec![0u8; metadata_len as usize];
-            file.read_exact(&mut metadata_bytes)?;
-            let metadata: HashMap<String, serde_json::Value> =
-                serde_json::from_slice(&metadata_bytes)
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    /// Read the vectors of many entries in one blocking task
+    ///
+    /// Entries whose vector cannot be read are skipped.
+    pub(super) async fn read_vectors(
+        &self,
+        collection: &str,
+        entries: Vec<IndexEntry>,
+    ) -> Result<Vec<(IndexEntry, Vec<f32>)>> {
+        let reads: Vec<(PathBuf, IndexEntry)> = entries
+            .into_iter()
+            .map(|entry| (self.get_shard_path(collection, entry.shard_id), entry))
+            .collect();
+        let shards = self.shards.clone();
 
-            Ok((vector, metadata))
+        tokio::task::spawn_blocking(move || {
+            reads
+                .into_iter()
+                .filter_map(|(path, entry)| {
+                    let vector = shards.read_vector(&path, entry.offset).ok()?;
+                    Some((entry, vector))
+                })
+                .collect()
         })
         .await
-        .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?
-        .map_err(|e: std::io::Error| Error::io(format!("Failed to read from shard: {}", e)))
+        .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))
     }
 }
 
@@ -318,6 +611,32 @@ impl FilesystemVectorStore {
 // =============================================================================
 
 impl FilesystemVectorStore {
+    /// Whether searches of a collection this size use the ANN index
+    pub(super) fn uses_ann(&self, vector_count: usize) -> bool {
+        vector_count >= self.config.search.exact_below
+    }
+
+    /// Search with the strategy matching the collection size
+    pub(super) async fn search(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let vector_count = self
+            .index_cache
+            .iter()
+            .filter(|r| r.key().0 == collection)
+            .count();
+        if self.uses_ann(vector_count) {
+            self.ann_search(collection, query_vector, limit, vector_count)
+                .await
+        } else {
+            self.brute_force_search(collection, query_vector, limit)
+                .await
+        }
+    }
+
     /// Perform similarity search using brute force
     pub(super) async fn brute_force_search(
         &self,
@@ -325,8 +644,6 @@ impl FilesystemVectorStore {
         query_vector: &[f32],
         limit: usize,
     ) -> Result<Vec<SearchResult>> {
-        let mut results = Vec::new();
-
         // Collect index entries first to avoid holding DashMap iterator across await points
         let entries: Vec<IndexEntry> = self
             .index_cache
@@ -335,12 +652,140 @@ impl FilesystemVectorStore {
             .map(|r| r.value().clone())
             .collect();
 
-        for entry in entries {
-            if let Ok((vector, metadata)) = self
-                .read_vector_from_shard(collection, entry.shard_id, entry.offset)
-                .await
-            {
+        let vectors = self.read_vectors(collection, entries).await?;
+        Ok(self.rank(query_vector, vectors, limit))
+    }
+
+    /// Perform similarity search over the `nprobe` closest ANN lists
+    async fn ann_search(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        vector_count: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.ensure_ann_index(collection, vector_count).await?;
+
+        let candidates: Option<Vec<IndexEntry>> = self.ann_indexes.get(collection).map(|index| {
+            index
+                .candidates(query_vector, self.config.search.nprobe)
+                .into_iter()
+                .filter_map(|id| {
+                    self.index_cache
+                        .get(&(collection.to_string(), id.to_string()))
+                        .map(|r| r.value().clone())
+                })
+                .collect()
+        });
+        let Some(entries) = candidates else {
+            return self
+                .brute_force_search(collection, query_vector, limit)
+                .await;
+        };
+
+        let vectors = self.read_vectors(collection, entries).await?;
+        Ok(self.rank(query_vector, vectors, limit))
+    }
+
+    /// Make sure the ANN index of a collection exists and covers every vector
+    ///
+    /// The index is trained from the shards when it is missing or when the
+    /// collection has drifted far from the size it was trained on. Vectors
+    /// written while a build was running are reconciled afterwards.
+    async fn ensure_ann_index(&self, collection: &str, vector_count: usize) -> Result<()> {
+        let in_sync = |store: &Self| {
+            store
+                .ann_indexes
+                .get(collection)
+                .is_some_and(|index| !index.needs_retraining() && index.len() == vector_count)
+        };
+        if in_sync(self) {
+            return Ok(());
+        }
+
+        let _build = self.ann_build.lock().await;
+        if in_sync(self) {
+            return Ok(());
+        }
+
+        let retrain = self
+            .ann_indexes
+            .get(collection)
+            .is_none_or(|index| index.needs_retraining());
+        if retrain {
+            let entries: Vec<IndexEntry> = self
+                .index_cache
+                .iter()
+                .filter(|r| r.key().0 == collection)
+                .map(|r| r.value().clone())
+                .collect();
+            let vectors: Vec<(String, Vec<f32>)> = self
+                .read_vectors(collection, entries)
+                .await?
+                .into_iter()
+                .map(|(entry, vector)| (entry.id, vector))
+                .collect();
+            let dimensions = self.config.dimensions;
+            let nlist = self.config.search.nlist;
+            let index =
+                tokio::task::spawn_blocking(move || IvfIndex::build(dimensions, nlist, &vectors))
+                    .await
+                    .map_err(|e| Error::internal(format!("Blocking task failed: {}", e)))?;
+            self.ann_indexes.insert(collection.to_string(), index);
+        }
+
+        self.reconcile_ann_index(collection).await?;
+        self.save_ann_index(collection).await
+    }
+
+    /// Add vectors missing from the ANN index and drop ids no longer stored
+    async fn reconcile_ann_index(&self, collection: &str) -> Result<()> {
+        let (missing, removed): (Vec<IndexEntry>, Vec<String>) = {
+            let Some(index) = self.ann_indexes.get(collection) else {
+                return Ok(());
+            };
+            let missing = self
+                .index_cache
+                .iter()
+                .filter(|r| r.key().0 == collection && !index.contains(&r.key().1))
+                .map(|r| r.value().clone())
+                .collect();
+            let removed = index
+                .ids()
+                .filter(|id| {
+                    !self
+                        .index_cache
+                        .contains_key(&(collection.to_string(), (*id).clone()))
+                })
+                .cloned()
+                .collect();
+            (missing, removed)
+        };
+
+        let vectors = self.read_vectors(collection, missing).await?;
+        if let Some(mut index) = self.ann_indexes.get_mut(collection) {
+            for id in &removed {
+                index.remove(id);
+            }
+            for (entry, vector) in vectors {
+                index.insert(entry.id, &vector);
+            }
+        }
+        Ok(())
+    }
+
+    /// Score entries against the query and keep the `limit` best
+    fn rank(
+        &self,
+        query_vector: &[f32],
+        vectors: Vec<(IndexEntry, Vec<f32>)>,
+        limit: usize,
+    ) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = vectors
+            .into_iter()
+            .map(|(entry, vector)| {
                 let similarity = self.cosine_similarity(query_vector, &vector);
+                let metadata = &entry.metadata;
 
                 let file_path = metadata.string_or("file_path", "unknown");
                 let start_line = metadata
@@ -350,16 +795,16 @@ impl FilesystemVectorStore {
                 let content = metadata.string_or("content", "");
                 let language = metadata.string_or("language", "unknown");
 
-                results.push(SearchResult {
-                    id: entry.id.clone(),
+                SearchResult {
+                    id: entry.id,
                     file_path,
                     start_line,
                     content,
                     score: similarity as f64,
                     language,
-                });
-            }
-        }
+                }
+            })
+            .collect();
 
         // Sort by similarity (descending) and take top results
         results.sort_by(|a, b| {
@@ -368,8 +813,7 @@ impl FilesystemVectorStore {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
         results.truncate(limit);
-
-        Ok(results)
+        results
     }
 }
 
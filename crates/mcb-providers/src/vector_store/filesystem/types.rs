@@ -23,6 +23,22 @@ pub(super) struct ShardMetadata {
     pub created_at: u64,
 }
 
+impl ShardMetadata {
+    /// Metadata of a shard created now
+    pub fn new(shard_id: u32, vector_count: usize, vectors_size: u64) -> Self {
+        Self {
+            shard_id,
+            vector_count,
+            vectors_offset: 0,
+            vectors_size,
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        }
+    }
+}
+
 /// Vector index entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(super) struct IndexEntry {
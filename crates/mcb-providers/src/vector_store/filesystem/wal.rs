@@ -0,0 +1,108 @@
+//! Write-ahead log of a filesystem collection
+//!
+//! Each record is a `u32` payload length, the CRC-32 of the payload and the
+//! JSON payload, all little-endian. A crash during an append leaves a short
+//! or mismatching record at the end; replay stops there and cuts the log
+//! back to the last complete record.
+
+use super::types::IndexEntry;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+/// Length and checksum fields before each payload
+const RECORD_HEADER_BYTES: usize = 8;
+
+/// One logged change to a collection index
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub(super) enum WalRecord {
+    /// A vector was written to a shard
+    Insert { entry: IndexEntry },
+    /// Vectors were removed from the index
+    Delete { ids: Vec<String> },
+}
+
+/// Append `records`, syncing them when `sync` is set
+///
+/// Returns the log size after the append.
+pub(super) fn append(path: &Path, records: &[WalRecord], sync: bool) -> std::io::Result<u64> {
+    let mut buf = Vec::new();
+    for record in records {
+        let payload = serde_json::to_vec(record)?;
+        let len = u32::try_from(payload.len()).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "WAL record too large")
+        })?;
+        buf.extend_from_slice(&len.to_le_bytes());
+        buf.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+        buf.extend_from_slice(&payload);
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(&buf)?;
+    if sync {
+        file.sync_data()?;
+    }
+    Ok(file.metadata()?.len())
+}
+
+/// Read every complete record, truncating a torn tail
+pub(super) fn replay(path: &Path) -> std::io::Result<Vec<WalRecord>> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut records = Vec::new();
+    let mut pos = 0;
+    while let Some((record, len)) = parse_record(&bytes[pos..]) {
+        records.push(record);
+        pos += len;
+    }
+
+    if pos < bytes.len() {
+        tracing::warn!(
+            "Discarding {} bytes of incomplete records at the end of {}",
+            bytes.len() - pos,
+            path.display()
+        );
+        let file = std::fs::OpenOptions::new().write(true).open(path)?;
+        file.set_len(pos as u64)?;
+        file.sync_all()?;
+    }
+    Ok(records)
+}
+
+/// Empty the log after a checkpoint
+pub(super) fn reset(path: &Path) -> std::io::Result<()> {
+    match std::fs::OpenOptions::new().write(true).open(path) {
+        Ok(file) => {
+            file.set_len(0)?;
+            file.sync_all()
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Current log size, 0 when there is no log
+pub(super) fn size(path: &Path) -> u64 {
+    std::fs::metadata(path).map_or(0, |meta| meta.len())
+}
+
+/// Parse the record at the start of `bytes` and its encoded length
+fn parse_record(bytes: &[u8]) -> Option<(WalRecord, usize)> {
+    let header = bytes.get(..RECORD_HEADER_BYTES)?;
+    let len = u32::from_le_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let crc = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+    let payload = bytes.get(RECORD_HEADER_BYTES..RECORD_HEADER_BYTES + len)?;
+    if crc32fast::hash(payload) != crc {
+        return None;
+    }
+    let record = serde_json::from_slice(payload).ok()?;
+    Some((record, RECORD_HEADER_BYTES + len))
+}
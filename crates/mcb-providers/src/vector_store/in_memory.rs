@@ -8,7 +8,9 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
-use mcb_domain::value_objects::{CollectionInfo, Embedding, FileInfo, SearchResult};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
 use serde_json::Value;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet};
@@ -69,6 +71,14 @@ impl VectorStoreAdmin for InMemoryVectorStoreProvider {
         Ok(())
     }
 
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        Ok(self
+            .collections
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect())
+    }
+
     fn provider_name(&self) -> &str {
         "in_memory"
     }
@@ -105,7 +115,8 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
 
         let mut ids = Vec::with_capacity(vectors.len());
         for (vector, mut meta) in vectors.iter().zip(metadata) {
-            let id = format!("{}_{}", collection, coll.len());
+            // Random rather than positional, so ids are not reused after a delete
+            let id = format!("{}_{}", collection, uuid::Uuid::new_v4());
             // Store the generated ID in metadata for deletion
             meta.insert("generated_id".to_string(), serde_json::json!(&id));
             coll.push((vector.clone(), meta));
@@ -115,6 +126,41 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
         Ok(ids)
     }
 
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Ids, vectors and metadata length mismatch",
+            ));
+        }
+        let mut coll = self
+            .collections
+            .get_mut(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        let mut positions: HashMap<String, usize> = coll
+            .iter()
+            .enumerate()
+            .map(|(i, (_embedding, metadata))| (metadata.string_or("generated_id", ""), i))
+            .collect();
+        for ((id, vector), mut meta) in ids.iter().zip(vectors).zip(metadata) {
+            meta.insert("generated_id".to_string(), serde_json::json!(id));
+            match positions.get(id) {
+                Some(&i) => coll[i] = (vector.clone(), meta),
+                None => {
+                    positions.insert(id.clone(), coll.len());
+                    coll.push((vector.clone(), meta));
+                }
+            }
+        }
+        Ok(())
+    }
+
     async fn search_similar(
         &self,
         collection: &str,
@@ -184,6 +230,32 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
         Ok(())
     }
 
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        filter.ensure_not_empty()?;
+        let mut coll = self
+            .collections
+            .get_mut(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        let before = coll.len();
+        coll.retain(|(_embedding, metadata)| !filter.matches(metadata));
+        Ok((before - coll.len()) as u64)
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        let coll = self
+            .collections
+            .get(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        let filter = MetadataFilter::file_path(file_path);
+        Ok(coll
+            .iter()
+            .filter(|(_embedding, metadata)| filter.matches(metadata))
+            .map(|(_embedding, metadata)| metadata.string_or("generated_id", ""))
+            .collect())
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
@@ -220,6 +292,40 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
 
         Ok(results)
     }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        let coll = self
+            .collections
+            .get(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        Ok(coll.iter().map(entry_to_stored_vector).collect())
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        let coll = self
+            .collections
+            .get(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        // Only the page's entries are cloned
+        let mut page: Vec<(&str, &CollectionEntry)> = coll
+            .iter()
+            .map(|entry| (entry.1.str_or("generated_id", ""), entry))
+            .filter(|(id, _)| after.is_none_or(|after| *id > after))
+            .collect();
+        page.sort_by(|a, b| a.0.cmp(b.0));
+        page.truncate(limit);
+        Ok(page
+            .into_iter()
+            .map(|(_, entry)| entry_to_stored_vector(entry))
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -340,6 +446,19 @@ fn compute_norm(v: &[f32]) -> f32 {
 }
 
 /// Convert metadata to SearchResult
+fn entry_to_stored_vector((embedding, metadata): &CollectionEntry) -> StoredVector {
+    let mut metadata = metadata.clone();
+    let id = metadata
+        .remove("generated_id")
+        .and_then(|id| id.as_str().map(str::to_string))
+        .unwrap_or_default();
+    StoredVector {
+        id,
+        vector: embedding.vector.clone(),
+        metadata,
+    }
+}
+
 fn metadata_to_search_result(metadata: &HashMap<String, Value>, score: f64) -> SearchResult {
     let id = metadata.string_or("generated_id", "");
     let start_line = metadata
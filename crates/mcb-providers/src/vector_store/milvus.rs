@@ -4,13 +4,17 @@
 //! Supports production-scale vector storage with automatic indexing and distributed search.
 
 use crate::constants::{
-    MILVUS_FIELD_VARCHAR_MAX_LENGTH, MILVUS_IVFFLAT_NLIST, MILVUS_METADATA_VARCHAR_MAX_LENGTH,
+    MILVUS_FIELD_VARCHAR_MAX_LENGTH, MILVUS_ID_VARCHAR_MAX_LENGTH, MILVUS_IVFFLAT_NLIST,
+    MILVUS_METADATA_VARCHAR_MAX_LENGTH,
 };
 use crate::utils::JsonExt;
 use async_trait::async_trait;
+use dashmap::DashSet;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
-use mcb_domain::value_objects::{CollectionInfo, Embedding, FileInfo, SearchResult};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
 use milvus::client::Client;
 use milvus::data::FieldColumn;
 use milvus::proto::schema::DataType;
@@ -22,18 +26,121 @@ use std::collections::HashMap;
 /// Milvus vector store provider implementation
 pub struct MilvusVectorStoreProvider {
     client: Client,
+    /// Collections whose schema was checked against the current layout
+    checked_schemas: DashSet<String>,
 }
 
 /// Default connection timeout in seconds
 const DEFAULT_TIMEOUT_SECS: u64 = 10;
 
+/// Expression matching every row
+const ALL_ROWS_EXPR: &str = "id != \"\"";
+
+/// Rows read per query when exporting, keeping responses with their
+/// vectors under the 4MB gRPC message limit
+const EXPORT_BATCH_SIZE: usize = 100;
+
+/// Quote a string literal for a Milvus boolean expression
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Milvus expression for a metadata filter
+///
+/// Only the scalar fields of the collection schema can be matched.
+fn filter_expr(filter: &MetadataFilter) -> Result<String> {
+    filter.ensure_not_empty()?;
+    filter
+        .conditions()
+        .iter()
+        .map(|(key, value)| match (key.as_str(), value) {
+            ("id" | "file_path" | "content", serde_json::Value::String(s)) => {
+                Ok(format!("{} == {}", key, quote(s)))
+            }
+            ("start_line", serde_json::Value::Number(n)) if n.is_i64() || n.is_u64() => {
+                Ok(format!("start_line == {}", n))
+            }
+            _ => Err(Error::invalid_argument(format!(
+                "Milvus cannot filter on metadata field '{}' = {}",
+                key, value
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(|conditions| conditions.join(" && "))
+}
+
+/// Rows of a query for `id`, `vector` and the scalar fields as stored vectors
+///
+/// The scalar fields are the only metadata a Milvus collection keeps.
+fn stored_vectors(columns: &[FieldColumn]) -> Vec<StoredVector> {
+    let column = |name: &str| columns.iter().find(|column| column.name == name);
+    let (Some(ids), Some(vectors)) = (column("id"), column("vector")) else {
+        return Vec::new();
+    };
+    (0..ids.len())
+        .filter_map(|i| {
+            let id = match ids.get(i)? {
+                Value::String(id) => id.into_owned(),
+                Value::Long(id) => id.to_string(),
+                _ => return None,
+            };
+            let Value::FloatArray(vector) = vectors.get(i)? else {
+                return None;
+            };
+            let mut metadata = HashMap::new();
+            for name in ["file_path", "content"] {
+                if let Some(Value::String(value)) = column(name).and_then(|c| c.get(i)) {
+                    metadata.insert(name.to_string(), serde_json::json!(value.into_owned()));
+                }
+            }
+            if let Some(Value::Long(line)) = column("start_line").and_then(|c| c.get(i)) {
+                metadata.insert("start_line".to_string(), serde_json::json!(line));
+            }
+            Some(StoredVector {
+                id,
+                vector: vector.into_owned(),
+                metadata,
+            })
+        })
+        .collect()
+}
+
+/// Convert a Milvus client error into a domain error
+///
+/// Transport failures and the gRPC statuses a retry can fix (`UNAVAILABLE`,
+/// `RESOURCE_EXHAUSTED`, `ABORTED`, `DEADLINE_EXCEEDED`) become
+/// [`Error::Unavailable`] or [`Error::Network`] so the resilience layer
+/// retries them and counts them against the breaker; everything else is a
+/// permanent [`Error::vector_db`].
+fn milvus_error(operation: &str, error: milvus::error::Error) -> Error {
+    use std::error::Error as _;
+    use tonic::Code;
+
+    let message = format!("Failed to {}: {}", operation, error);
+    let mut sources = std::iter::successors(error.source(), |e| e.source());
+    let code = sources.find_map(|e| {
+        // A failed connection is reported like an unreachable server
+        if e.is::<tonic::transport::Error>() {
+            return Some(Code::Unavailable);
+        }
+        e.downcast_ref::<tonic::Status>().map(tonic::Status::code)
+    });
+    match code {
+        Some(Code::Unavailable | Code::ResourceExhausted | Code::Aborted) => {
+            Error::unavailable(message, None)
+        }
+        Some(Code::DeadlineExceeded) => Error::network(message),
+        _ => Error::vector_db(message),
+    }
+}
+
 impl MilvusVectorStoreProvider {
     /// Helper method to convert Milvus errors to domain errors
-    fn map_milvus_error<T, E: std::fmt::Display>(
-        result: std::result::Result<T, E>,
+    fn map_milvus_error<T>(
+        result: std::result::Result<T, milvus::error::Error>,
         operation: &str,
     ) -> Result<T> {
-        result.map_err(|e| Error::vector_db(format!("Failed to {}: {}", operation, e)))
+        result.map_err(|e| milvus_error(operation, e))
     }
 
     /// Create a new Milvus vector store provider
@@ -72,7 +179,140 @@ impl MilvusVectorStoreProvider {
                 ))
             })?;
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            checked_schemas: DashSet::new(),
+        })
+    }
+
+    /// Refuse collections created with the legacy int64 auto-id primary key
+    ///
+    /// Chunk ids are strings chosen by the caller, so writes and deletes by
+    /// id cannot work against the old layout. Such a collection has to be
+    /// dropped and re-indexed. Collections that cannot be described are left
+    /// to the operation itself to report.
+    async fn ensure_current_schema(&self, collection: &str) -> Result<()> {
+        if self.checked_schemas.contains(collection) {
+            return Ok(());
+        }
+        let Ok(description) = self.client.describe_collection(collection).await else {
+            return Ok(());
+        };
+        let legacy = description
+            .fields
+            .iter()
+            .any(|field| field.is_primary_key && field.dtype != DataType::VarChar);
+        if legacy {
+            return Err(Error::vector_db(format!(
+                "Milvus collection '{}' uses the legacy int64 auto-id primary key, \
+                 which cannot hold chunk ids; drop the collection and re-index it",
+                collection
+            )));
+        }
+        self.checked_schemas.insert(collection.to_string());
+        Ok(())
+    }
+
+    /// Columns for one write of `vectors` under `ids`
+    fn row_columns(
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: &[HashMap<String, serde_json::Value>],
+    ) -> Result<Vec<FieldColumn>> {
+        if vectors.is_empty() {
+            return Err(Error::vector_db(
+                "No vectors provided for insertion".to_string(),
+            ));
+        }
+
+        if vectors.len() != metadata.len() {
+            return Err(Error::vector_db(format!(
+                "Vectors ({}) and metadata ({}) arrays must have the same length",
+                vectors.len(),
+                metadata.len()
+            )));
+        }
+
+        // Validate all vectors have the same dimensions
+        let expected_dims = vectors[0].dimensions;
+        for (i, vector) in vectors.iter().enumerate() {
+            if vector.dimensions != expected_dims {
+                return Err(Error::vector_db(format!(
+                    "Vector at index {} has dimensions {} but expected {}",
+                    i, vector.dimensions, expected_dims
+                )));
+            }
+        }
+
+        // Prepare data for insertion
+        let mut vectors_flat = Vec::new();
+        let mut file_paths = Vec::new();
+        let mut start_lines = Vec::new();
+        let mut contents = Vec::new();
+
+        for (embedding, meta) in vectors.iter().zip(metadata.iter()) {
+            vectors_flat.extend_from_slice(&embedding.vector);
+
+            let file_path = meta.string_or("file_path", "unknown");
+            let start_line = meta
+                .opt_i64("start_line")
+                .or_else(|| meta.opt_i64("line_number"))
+                .unwrap_or(0);
+            let content = meta.string_or("content", "");
+
+            file_paths.push(file_path);
+            start_lines.push(start_line);
+            contents.push(content);
+        }
+
+        let id_column = FieldColumn {
+            name: "id".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(ids.to_vec()),
+            dim: 1,
+            max_length: MILVUS_ID_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+        let vector_column = FieldColumn {
+            name: "vector".to_string(),
+            dtype: DataType::FloatVector,
+            value: ValueVec::Float(vectors_flat),
+            dim: expected_dims as i64,
+            max_length: 0,
+            is_dynamic: false,
+        };
+        let file_path_column = FieldColumn {
+            name: "file_path".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(file_paths),
+            dim: 1,
+            max_length: MILVUS_FIELD_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+        let start_line_column = FieldColumn {
+            name: "start_line".to_string(),
+            dtype: DataType::Int64,
+            value: ValueVec::Long(start_lines),
+            dim: 1,
+            max_length: 0,
+            is_dynamic: false,
+        };
+        let content_column = FieldColumn {
+            name: "content".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(contents),
+            dim: 1,
+            max_length: MILVUS_METADATA_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+
+        Ok(vec![
+            id_column,
+            vector_column,
+            file_path_column,
+            start_line_column,
+            content_column,
+        ])
     }
 }
 
@@ -87,12 +327,7 @@ impl VectorStoreAdmin for MilvusVectorStoreProvider {
             .client
             .get_collection_stats(collection)
             .await
-            .map_err(|e| {
-                Error::vector_db(format!(
-                    "Failed to get stats for collection '{}': {}",
-                    collection, e
-                ))
-            })?;
+            .map_err(|e| milvus_error(&format!("get stats for collection '{}'", collection), e))?;
 
         let mut result = HashMap::new();
         result.insert("collection".to_string(), serde_json::json!(collection));
@@ -125,24 +360,27 @@ impl VectorStoreAdmin for MilvusVectorStoreProvider {
                         .await;
                         continue;
                     }
-                    return Err(Error::vector_db(format!(
-                        "Failed to flush collection: {}",
-                        e
-                    )));
+                    return Err(milvus_error("flush collection", e));
                 }
             }
         }
 
         if let Some(e) = last_error {
-            return Err(Error::vector_db(format!(
-                "Failed to flush collection after retries: {}",
-                e
-            )));
+            // Still rate limited: let the caller back off and retry
+            return Err(Error::unavailable(
+                format!("Failed to flush collection after retries: {}", e),
+                None,
+            ));
         }
 
         Ok(())
     }
 
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        let collections = VectorStoreBrowser::list_collections(self).await?;
+        Ok(collections.into_iter().map(|c| c.name).collect())
+    }
+
     fn provider_name(&self) -> &str {
         "milvus"
     }
@@ -151,11 +389,16 @@ impl VectorStoreAdmin for MilvusVectorStoreProvider {
 #[async_trait]
 impl VectorStoreProvider for MilvusVectorStoreProvider {
     async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
+        if self.collection_exists(name).await? {
+            self.ensure_current_schema(name).await?;
+        }
+
         let schema = CollectionSchemaBuilder::new(name, &format!("Collection for {}", name))
-            .add_field(FieldSchema::new_primary_int64(
+            .add_field(FieldSchema::new_primary_varchar(
                 "id",
                 "primary key field",
-                true, // Use auto_id
+                false, // Ids are chosen by the caller or minted on insert
+                MILVUS_ID_VARCHAR_MAX_LENGTH,
             ))
             .add_field(FieldSchema::new_float_vector(
                 "vector",
@@ -222,7 +465,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
                         .await;
                         continue;
                     }
-                    return Err(Error::vector_db(format!("Failed to create index: {}", e)));
+                    return Err(milvus_error("create index", e));
                 }
             }
         }
@@ -239,6 +482,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
 
     async fn delete_collection(&self, name: &str) -> Result<()> {
         Self::map_milvus_error(self.client.drop_collection(name).await, "delete collection")?;
+        self.checked_schemas.remove(name);
         Ok(())
     }
 
@@ -248,111 +492,43 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         vectors: &[Embedding],
         metadata: Vec<HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>> {
-        if vectors.is_empty() {
-            return Err(Error::vector_db(
-                "No vectors provided for insertion".to_string(),
-            ));
-        }
-
-        if vectors.len() != metadata.len() {
-            return Err(Error::vector_db(format!(
-                "Vectors ({}) and metadata ({}) arrays must have the same length",
-                vectors.len(),
-                metadata.len()
-            )));
-        }
-
-        // Validate all vectors have the same dimensions
-        let expected_dims = vectors[0].dimensions;
-        for (i, vector) in vectors.iter().enumerate() {
-            if vector.dimensions != expected_dims {
-                return Err(Error::vector_db(format!(
-                    "Vector at index {} has dimensions {} but expected {}",
-                    i, vector.dimensions, expected_dims
-                )));
-            }
-        }
-
-        // Prepare data for insertion
-        let mut vectors_flat = Vec::new();
-        let mut file_paths = Vec::new();
-        let mut start_lines = Vec::new();
-        let mut contents = Vec::new();
+        let ids: Vec<String> = (0..vectors.len())
+            .map(|_| uuid::Uuid::new_v4().to_string())
+            .collect();
+        let columns = Self::row_columns(&ids, vectors, &metadata)?;
+        self.ensure_current_schema(collection).await?;
 
-        for (embedding, meta) in vectors.iter().zip(metadata.iter()) {
-            vectors_flat.extend_from_slice(&embedding.vector);
+        Self::map_milvus_error(
+            self.client.insert(collection, columns, None).await,
+            "insert vectors",
+        )?;
 
-            let file_path = meta.string_or("file_path", "unknown");
-            let start_line = meta
-                .opt_i64("start_line")
-                .or_else(|| meta.opt_i64("line_number"))
-                .unwrap_or(0);
-            let content = meta.string_or("content", "");
+        Ok(ids)
+    }
 
-            file_paths.push(file_path);
-            start_lines.push(start_line);
-            contents.push(content);
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() {
+            return Err(Error::vector_db(format!(
+                "Ids ({}) and vectors ({}) arrays must have the same length",
+                ids.len(),
+                vectors.len()
+            )));
         }
+        let columns = Self::row_columns(ids, vectors, &metadata)?;
+        self.ensure_current_schema(collection).await?;
 
-        // With auto_id: true, we don't provide the "id" column
-        let vector_column = FieldColumn {
-            name: "vector".to_string(),
-            dtype: DataType::FloatVector,
-            value: ValueVec::Float(vectors_flat),
-            dim: expected_dims as i64,
-            max_length: 0,
-            is_dynamic: false,
-        };
-        let file_path_column = FieldColumn {
-            name: "file_path".to_string(),
-            dtype: DataType::VarChar,
-            value: ValueVec::String(file_paths),
-            dim: 1,
-            max_length: MILVUS_FIELD_VARCHAR_MAX_LENGTH,
-            is_dynamic: false,
-        };
-        let start_line_column = FieldColumn {
-            name: "start_line".to_string(),
-            dtype: DataType::Int64,
-            value: ValueVec::Long(start_lines),
-            dim: 1,
-            max_length: 0,
-            is_dynamic: false,
-        };
-        let content_column = FieldColumn {
-            name: "content".to_string(),
-            dtype: DataType::VarChar,
-            value: ValueVec::String(contents),
-            dim: 1,
-            max_length: MILVUS_METADATA_VARCHAR_MAX_LENGTH,
-            is_dynamic: false,
-        };
-
-        let columns = vec![
-            vector_column,
-            file_path_column,
-            start_line_column,
-            content_column,
-        ];
-
-        let res = Self::map_milvus_error(
-            self.client.insert(collection, columns, None).await,
-            "insert vectors",
+        Self::map_milvus_error(
+            self.client.upsert(collection, columns, None).await,
+            "upsert vectors",
         )?;
 
-        // Return IDs as strings from the result
-        let ids = match res.i_ds {
-            Some(ids) => match ids.id_field {
-                Some(milvus::proto::schema::i_ds::IdField::IntId(int_ids)) => {
-                    int_ids.data.iter().map(|id| id.to_string()).collect()
-                }
-                Some(milvus::proto::schema::i_ds::IdField::StrId(str_ids)) => str_ids.data.clone(),
-                None => Vec::new(),
-            },
-            None => Vec::new(),
-        };
-
-        Ok(ids)
+        Ok(())
     }
 
     async fn search_similar(
@@ -383,10 +559,10 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
                 );
                 return Ok(Vec::new());
             }
-            return Err(Error::vector_db(format!(
-                "Failed to load collection '{}': {}",
-                collection, e
-            )));
+            return Err(milvus_error(
+                &format!("load collection '{}'", collection),
+                e,
+            ));
         }
 
         use milvus::query::SearchOptions;
@@ -416,7 +592,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
                 if err_str.contains("no IDs") || err_str.contains("empty") {
                     return Ok(Vec::new());
                 }
-                return Err(Error::vector_db(format!("Failed to search: {}", e)));
+                return Err(milvus_error("search", e));
             }
         };
 
@@ -492,16 +668,14 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
 
     async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
         use milvus::mutate::DeleteOptions;
-        use milvus::value::ValueVec;
 
-        // Convert string IDs to i64 for Milvus
-        let id_numbers: Vec<i64> = ids.iter().filter_map(|id| id.parse::<i64>().ok()).collect();
-
-        if id_numbers.is_empty() {
+        if ids.is_empty() {
             return Ok(()); // Nothing to delete
         }
 
-        let options = DeleteOptions::with_ids(ValueVec::Long(id_numbers));
+        self.ensure_current_schema(collection).await?;
+        let ids: Vec<String> = ids.iter().map(|id| quote(id)).collect();
+        let options = DeleteOptions::with_filter(format!("id in [{}]", ids.join(",")));
 
         Self::map_milvus_error(
             self.client.delete(collection, &options).await,
@@ -511,6 +685,48 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         Ok(())
     }
 
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        use milvus::mutate::DeleteOptions;
+
+        let options = DeleteOptions::with_filter(filter_expr(filter)?);
+        self.ensure_current_schema(collection).await?;
+        let res = Self::map_milvus_error(
+            self.client.delete(collection, &options).await,
+            "delete vectors by filter",
+        )?;
+
+        Ok(res.delete_cnt.max(0) as u64)
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        use milvus::query::QueryOptions;
+
+        self.client
+            .load_collection(collection, None)
+            .await
+            .map_err(|e| milvus_error(&format!("load collection '{}'", collection), e))?;
+
+        let expr = filter_expr(&MetadataFilter::file_path(file_path))?;
+        let query_options = QueryOptions::new()
+            .limit(crate::constants::MILVUS_DEFAULT_QUERY_LIMIT)
+            .output_fields(vec!["id".to_string()]);
+        let query_results = Self::map_milvus_error(
+            self.client.query(collection, &expr, &query_options).await,
+            "query ids by file",
+        )?;
+
+        let Some(ids) = query_results.iter().find(|column| column.name == "id") else {
+            return Ok(Vec::new());
+        };
+        Ok((0..ids.len())
+            .filter_map(|i| match ids.get(i) {
+                Some(Value::String(id)) => Some(id.to_string()),
+                Some(Value::Long(id)) => Some(id.to_string()),
+                _ => None,
+            })
+            .collect())
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
@@ -524,12 +740,11 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         self.client
             .load_collection(collection, None)
             .await
-            .map_err(|e| {
-                Error::vector_db(format!("Failed to load collection '{}': {}", collection, e))
-            })?;
+            .map_err(|e| milvus_error(&format!("load collection '{}'", collection), e))?;
 
         // Construct expression for query
-        let expr = format!("id in [{}]", ids.join(","));
+        let quoted: Vec<String> = ids.iter().map(|id| quote(id)).collect();
+        let expr = format!("id in [{}]", quoted.join(","));
 
         use milvus::query::QueryOptions;
         let mut query_options = QueryOptions::new();
@@ -621,9 +836,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         self.client
             .load_collection(collection, None)
             .await
-            .map_err(|e| {
-                Error::vector_db(format!("Failed to load collection '{}': {}", collection, e))
-            })?;
+            .map_err(|e| milvus_error(&format!("load collection '{}'", collection), e))?;
 
         // Use pagination to avoid gRPC message size limits (4MB default)
         // Batch size of 100 keeps responses well under the limit
@@ -631,7 +844,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         let mut all_results = Vec::new();
         let mut offset = 0i64;
 
-        let expr = "id >= 0".to_string();
+        let expr = ALL_ROWS_EXPR.to_string();
         use milvus::query::QueryOptions;
 
         loop {
@@ -664,7 +877,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
                         );
                         break;
                     }
-                    return Err(Error::vector_db(format!("Failed to list vectors: {}", e)));
+                    return Err(milvus_error("list vectors", e));
                 }
             };
 
@@ -744,6 +957,53 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
 
         Ok(all_results)
     }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        self.export_vectors_page(collection, None, usize::MAX).await
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        use milvus::query::QueryOptions;
+
+        self.client
+            .load_collection(collection, None)
+            .await
+            .map_err(|e| milvus_error(&format!("load collection '{}'", collection), e))?;
+
+        // A query with a limit returns rows in primary key order, so the id
+        // of the last row read is the cursor for the next batch
+        let mut vectors: Vec<StoredVector> = Vec::new();
+        while vectors.len() < limit {
+            let batch = (limit - vectors.len()).min(EXPORT_BATCH_SIZE);
+            let expr = match vectors.last().map(|v| v.id.as_str()).or(after) {
+                Some(cursor) => format!("id > {}", quote(cursor)),
+                None => ALL_ROWS_EXPR.to_string(),
+            };
+            let query_options = QueryOptions::new().limit(batch as i64).output_fields(vec![
+                "id".to_string(),
+                "vector".to_string(),
+                "file_path".to_string(),
+                "start_line".to_string(),
+                "content".to_string(),
+            ]);
+            let columns = Self::map_milvus_error(
+                self.client.query(collection, &expr, &query_options).await,
+                "export vectors",
+            )?;
+            let rows = stored_vectors(&columns);
+            let exhausted = rows.len() < batch;
+            vectors.extend(rows);
+            if exhausted {
+                break;
+            }
+        }
+        Ok(vectors)
+    }
 }
 
 #[async_trait]
@@ -790,16 +1050,16 @@ impl VectorStoreBrowser for MilvusVectorStoreProvider {
             {
                 return Ok(Vec::new());
             }
-            return Err(Error::vector_db(format!(
-                "Failed to load collection '{}': {}",
-                collection, e
-            )));
+            return Err(milvus_error(
+                &format!("load collection '{}'", collection),
+                e,
+            ));
         }
 
         // Query all file_path values and aggregate
         use milvus::query::QueryOptions;
 
-        let expr = "id >= 0".to_string();
+        let expr = ALL_ROWS_EXPR.to_string();
         let query_options = QueryOptions::new()
             .limit(crate::constants::MILVUS_DEFAULT_QUERY_LIMIT)
             .output_fields(vec!["file_path".to_string()]);
@@ -848,10 +1108,10 @@ impl VectorStoreBrowser for MilvusVectorStoreProvider {
             {
                 return Ok(Vec::new());
             }
-            return Err(Error::vector_db(format!(
-                "Failed to load collection '{}': {}",
-                collection, e
-            )));
+            return Err(milvus_error(
+                &format!("load collection '{}'", collection),
+                e,
+            ));
         }
 
         use milvus::query::QueryOptions;
@@ -12,6 +12,8 @@
 //! | FilesystemVectorStore | Local | Persistent filesystem-based storage |
 //! | EdgeVecVectorStoreProvider | Embedded | High-performance HNSW vector store |
 //! | MilvusVectorStoreProvider | Cloud | Production-scale cloud vector database |
+//! | QdrantVectorStoreProvider | Remote | Qdrant server over HTTP |
+//! | SqliteVectorStoreProvider | Local | Single-file SQLite database |
 //!
 //! ## Provider Selection Guide
 //!
@@ -19,8 +21,10 @@
 //! - **Development with data**: Use `InMemoryVectorStoreProvider`
 //! - **Production with encryption**: Use `EncryptedVectorStoreProvider` wrapper
 //! - **Production local storage**: Use `FilesystemVectorStore` for persistent local storage
+//! - **Laptops and small teams**: Use `SqliteVectorStoreProvider` for one database file
 //! - **High-performance embedded**: Use `EdgeVecVectorStoreProvider` for sub-ms search
 //! - **Cloud production**: Use `MilvusVectorStoreProvider` for distributed cloud deployments
+//! - **Existing Qdrant deployments**: Use `QdrantVectorStoreProvider`
 
 #[cfg(feature = "vectorstore-edgevec")]
 pub mod edgevec;
@@ -32,6 +36,10 @@ pub mod in_memory;
 #[cfg(feature = "vectorstore-milvus")]
 pub mod milvus;
 pub mod null;
+#[cfg(feature = "vectorstore-qdrant")]
+pub mod qdrant;
+#[cfg(feature = "vectorstore-sqlite")]
+pub mod sqlite;
 
 // Re-export for convenience
 #[cfg(feature = "vectorstore-edgevec")]
@@ -41,8 +49,15 @@ pub use edgevec::{
 #[cfg(feature = "vectorstore-encrypted")]
 pub use encrypted::EncryptedVectorStoreProvider;
 #[cfg(feature = "vectorstore-filesystem")]
-pub use filesystem::{FilesystemVectorStore, FilesystemVectorStoreConfig};
+pub use filesystem::{
+    FilesystemSearchConfig, FilesystemStorageConfig, FilesystemSyncPolicy, FilesystemVectorStore,
+    FilesystemVectorStoreConfig,
+};
 pub use in_memory::InMemoryVectorStoreProvider;
 #[cfg(feature = "vectorstore-milvus")]
 pub use milvus::MilvusVectorStoreProvider;
 pub use null::NullVectorStoreProvider;
+#[cfg(feature = "vectorstore-qdrant")]
+pub use qdrant::{QdrantDistance, QdrantVectorStoreProvider};
+#[cfg(feature = "vectorstore-sqlite")]
+pub use sqlite::SqliteVectorStoreProvider;
@@ -7,7 +7,9 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
-use mcb_domain::value_objects::{CollectionInfo, Embedding, FileInfo, SearchResult};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -65,6 +67,14 @@ impl VectorStoreAdmin for NullVectorStoreProvider {
         Ok(())
     }
 
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        Ok(self
+            .collections
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect())
+    }
+
     fn provider_name(&self) -> &str {
         "null"
     }
@@ -98,6 +108,16 @@ impl VectorStoreProvider for NullVectorStoreProvider {
         Ok(vec!["".to_string(); vectors.len()])
     }
 
+    async fn upsert_vectors(
+        &self,
+        _collection: &str,
+        _ids: &[String],
+        _vectors: &[Embedding],
+        _metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     async fn search_similar(
         &self,
         _collection: &str,
@@ -113,6 +133,14 @@ impl VectorStoreProvider for NullVectorStoreProvider {
         Ok(())
     }
 
+    async fn delete_by_filter(&self, _collection: &str, _filter: &MetadataFilter) -> Result<u64> {
+        Ok(0)
+    }
+
+    async fn file_vector_ids(&self, _collection: &str, _file_path: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
     async fn get_vectors_by_ids(
         &self,
         _collection: &str,
@@ -124,6 +152,10 @@ impl VectorStoreProvider for NullVectorStoreProvider {
     async fn list_vectors(&self, _collection: &str, _limit: usize) -> Result<Vec<SearchResult>> {
         Ok(Vec::new())
     }
+
+    async fn export_vectors(&self, _collection: &str) -> Result<Vec<StoredVector>> {
+        Ok(Vec::new())
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,758 @@
+//! Qdrant vector store provider implementation
+//!
+//! Talks to a Qdrant server over its HTTP API. Chunk metadata is stored as
+//! the point payload, with keyword indexes on `file_path` and `language`
+//! so browsing and deletes by file are served from the index.
+
+use crate::constants::QDRANT_SCROLL_PAGE_SIZE;
+use crate::utils::JsonExt;
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Method, StatusCode};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Payload key holding the caller's chunk id
+const CHUNK_ID_KEY: &str = "chunk_id";
+
+/// Payload fields with a keyword index
+const INDEXED_FIELDS: [&str; 2] = ["file_path", "language"];
+
+/// Distance metric of new collections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QdrantDistance {
+    /// Cosine similarity; scores are in `[-1, 1]`, higher is closer
+    #[default]
+    Cosine,
+    /// Dot product; higher is closer
+    Dot,
+    /// Euclidean distance; reported as the similarity `1 / (1 + distance)`
+    Euclid,
+    /// Manhattan distance; reported as the similarity `1 / (1 + distance)`
+    Manhattan,
+}
+
+impl QdrantDistance {
+    /// Name used by the Qdrant API
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cosine => "Cosine",
+            Self::Dot => "Dot",
+            Self::Euclid => "Euclid",
+            Self::Manhattan => "Manhattan",
+        }
+    }
+
+    /// Similarity for a Qdrant search score, higher is closer
+    pub fn similarity(self, score: f64) -> f64 {
+        match self {
+            Self::Cosine | Self::Dot => score,
+            Self::Euclid | Self::Manhattan => 1.0 / (1.0 + score.max(0.0)),
+        }
+    }
+}
+
+impl FromStr for QdrantDistance {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(Self::Cosine),
+            "dot" => Ok(Self::Dot),
+            "euclid" | "euclidean" => Ok(Self::Euclid),
+            "manhattan" => Ok(Self::Manhattan),
+            other => Err(format!(
+                "Unknown Qdrant distance '{other}', expected 'cosine', 'dot', 'euclid' or 'manhattan'"
+            )),
+        }
+    }
+}
+
+/// Qdrant vector store provider
+///
+/// Point ids must be UUIDs or integers in Qdrant, so each chunk id is
+/// mapped to a name-based UUID and kept in the payload as `chunk_id`.
+/// Writes wait for the server to apply them.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use mcb_providers::vector_store::{QdrantDistance, QdrantVectorStoreProvider};
+/// use reqwest::Client;
+///
+/// let provider = QdrantVectorStoreProvider::new("http://localhost:6333", Client::new())
+///     .with_api_key("secret")
+///     .with_distance(QdrantDistance::Dot);
+/// ```
+pub struct QdrantVectorStoreProvider {
+    base_url: String,
+    api_key: Option<String>,
+    distance: QdrantDistance,
+    http_client: Client,
+}
+
+impl QdrantVectorStoreProvider {
+    /// Create a provider for the server at `base_url`
+    pub fn new(base_url: impl Into<String>, http_client: Client) -> Self {
+        let base_url = base_url.into();
+        let base_url = base_url.trim().trim_end_matches('/');
+        let base_url = if base_url.starts_with("http://") || base_url.starts_with("https://") {
+            base_url.to_string()
+        } else {
+            format!("http://{}", base_url)
+        };
+        Self {
+            base_url,
+            api_key: None,
+            distance: QdrantDistance::default(),
+            http_client,
+        }
+    }
+
+    /// Send `api_key` in the `api-key` header of every request
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set the distance metric of collections created from now on
+    pub fn with_distance(mut self, distance: QdrantDistance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Point id for a chunk id
+    fn point_id(id: &str) -> String {
+        Uuid::new_v5(&Uuid::NAMESPACE_OID, id.as_bytes()).to_string()
+    }
+
+    /// Send a request and return the `result` field of the response
+    ///
+    /// Returns `Ok(None)` when the server answers 404.
+    async fn call(&self, method: Method, path: &str, body: Option<Value>) -> Result<Option<Value>> {
+        let operation = format!("{} {}", method, path);
+        let mut request = self
+            .http_client
+            .request(method, format!("{}{}", self.base_url, path));
+        if let Some(api_key) = &self.api_key {
+            request = request.header("api-key", api_key);
+        }
+        if let Some(body) = &body {
+            request = request.json(body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("Qdrant {} failed: {}", operation, e)))?;
+        let status = response.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let text = response.text().await.map_err(|e| {
+            Error::vector_db(format!("Qdrant {} response unreadable: {}", operation, e))
+        })?;
+        if !status.is_success() {
+            let message = format!(
+                "Qdrant {} failed ({}): {}",
+                operation,
+                status.as_u16(),
+                text
+            );
+            return Err(
+                if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+                    Error::unavailable(message, retry_after)
+                } else {
+                    Error::vector_db(message)
+                },
+            );
+        }
+        let mut response: Value = serde_json::from_str(&text).map_err(|e| {
+            Error::vector_db(format!("Qdrant {} returned invalid JSON: {}", operation, e))
+        })?;
+        Ok(Some(take_field(&mut response, "result")))
+    }
+
+    /// Like `call`, but a 404 means the collection is missing
+    async fn call_collection(
+        &self,
+        method: Method,
+        collection: &str,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<Value> {
+        self.call(
+            method,
+            &format!("/collections/{}{}", collection, path),
+            body,
+        )
+        .await?
+        .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))
+    }
+
+    /// Scroll through up to `limit` points, a page at a time
+    async fn scroll(
+        &self,
+        collection: &str,
+        filter: Option<Value>,
+        with_payload: Value,
+        with_vector: bool,
+        limit: usize,
+    ) -> Result<Vec<Value>> {
+        self.scroll_from(
+            collection,
+            filter,
+            with_payload,
+            with_vector,
+            Value::Null,
+            limit,
+        )
+        .await
+    }
+
+    /// Scroll through up to `limit` points starting at point id `offset`
+    /// (inclusive), or at the first point when `offset` is `null`
+    async fn scroll_from(
+        &self,
+        collection: &str,
+        filter: Option<Value>,
+        with_payload: Value,
+        with_vector: bool,
+        mut offset: Value,
+        limit: usize,
+    ) -> Result<Vec<Value>> {
+        let mut points = Vec::new();
+        while points.len() < limit {
+            let mut body = json!({
+                "limit": (limit - points.len()).min(QDRANT_SCROLL_PAGE_SIZE),
+                "with_payload": with_payload,
+                "with_vector": with_vector,
+            });
+            if let Some(filter) = &filter {
+                body["filter"] = filter.clone();
+            }
+            if !offset.is_null() {
+                body["offset"] = offset;
+            }
+            let mut page = self
+                .call_collection(Method::POST, collection, "/points/scroll", Some(body))
+                .await?;
+            if let Value::Array(batch) = take_field(&mut page, "points") {
+                points.extend(batch);
+            }
+            offset = take_field(&mut page, "next_page_offset");
+            if offset.is_null() {
+                break;
+            }
+        }
+        Ok(points)
+    }
+
+    /// Exact number of points matching `filter`
+    async fn count(&self, collection: &str, filter: Option<Value>) -> Result<u64> {
+        let mut body = json!({ "exact": true });
+        if let Some(filter) = filter {
+            body["filter"] = filter;
+        }
+        let result = self
+            .call_collection(Method::POST, collection, "/points/count", Some(body))
+            .await?;
+        Ok(result.u64_or("count", 0))
+    }
+}
+
+/// Move `key` out of a JSON object, or `null` when it is missing
+fn take_field(value: &mut Value, key: &str) -> Value {
+    value.get_mut(key).map(Value::take).unwrap_or_default()
+}
+
+/// Qdrant filter requiring every condition of `filter`
+///
+/// `id` matches the chunk id. A `null` value matches points where the key
+/// is missing or null.
+fn filter_json(filter: &MetadataFilter) -> Result<Value> {
+    filter.ensure_not_empty()?;
+    let must = filter
+        .conditions()
+        .iter()
+        .map(|(key, value)| {
+            let key = if key == "id" {
+                CHUNK_ID_KEY
+            } else {
+                key.as_str()
+            };
+            match value {
+                Value::Null => Ok(json!({ "is_null": { "key": key } })),
+                Value::Number(n) if n.is_f64() => {
+                    Ok(json!({ "key": key, "range": { "gte": n, "lte": n } }))
+                }
+                Value::String(_) | Value::Bool(_) | Value::Number(_) => {
+                    Ok(json!({ "key": key, "match": { "value": value } }))
+                }
+                Value::Array(_) | Value::Object(_) => Err(Error::invalid_argument(format!(
+                    "Cannot filter on nested metadata value for '{}'",
+                    key
+                ))),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({ "must": must }))
+}
+
+/// Stored vector from a point scrolled with its payload and vector
+fn point_to_stored(mut point: Value) -> Result<StoredVector> {
+    let Value::Object(mut metadata) = take_field(&mut point, "payload") else {
+        return Err(Error::vector_db("Qdrant returned a point without payload"));
+    };
+    let id = match metadata.remove(CHUNK_ID_KEY) {
+        Some(Value::String(id)) => id,
+        _ => return Err(Error::vector_db("Qdrant point has no chunk id")),
+    };
+    let vector = serde_json::from_value(take_field(&mut point, "vector"))
+        .map_err(|e| Error::vector_db(format!("Invalid vector for '{}': {}", id, e)))?;
+    Ok(StoredVector {
+        id,
+        vector,
+        metadata: metadata.into_iter().collect(),
+    })
+}
+
+/// Search result from a point returned by search, retrieve or scroll
+fn point_to_result(point: &Value, score: f64) -> SearchResult {
+    let payload = &point["payload"];
+    let start_line = payload
+        .opt_u64("start_line")
+        .or_else(|| payload.opt_u64("line_number"))
+        .unwrap_or(0) as u32;
+    SearchResult {
+        id: payload.string_or(CHUNK_ID_KEY, ""),
+        file_path: payload.string_or("file_path", ""),
+        start_line,
+        content: payload.string_or("content", ""),
+        score,
+        language: payload.string_or("language", "unknown"),
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for QdrantVectorStoreProvider {
+    async fn collection_exists(&self, name: &str) -> Result<bool> {
+        let result = self
+            .call_collection(Method::GET, name, "/exists", None)
+            .await?;
+        Ok(result.bool_or("exists", false))
+    }
+
+    async fn get_stats(&self, collection: &str) -> Result<HashMap<String, Value>> {
+        let info = self
+            .call_collection(Method::GET, collection, "", None)
+            .await?;
+        let vectors = &info["config"]["params"]["vectors"];
+
+        let mut stats = HashMap::new();
+        stats.insert("collection".to_string(), json!(collection));
+        stats.insert("status".to_string(), info["status"].clone());
+        stats.insert("vectors_count".to_string(), info["points_count"].clone());
+        stats.insert(
+            "indexed_vectors_count".to_string(),
+            info["indexed_vectors_count"].clone(),
+        );
+        stats.insert("dimensions".to_string(), vectors["size"].clone());
+        stats.insert("distance".to_string(), vectors["distance"].clone());
+        stats.insert("provider".to_string(), json!(self.provider_name()));
+        Ok(stats)
+    }
+
+    async fn flush(&self, _collection: &str) -> Result<()> {
+        // Writes already wait until Qdrant has applied them
+        Ok(())
+    }
+
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        let collections = VectorStoreBrowser::list_collections(self).await?;
+        Ok(collections.into_iter().map(|c| c.name).collect())
+    }
+
+    fn provider_name(&self) -> &str {
+        "qdrant"
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for QdrantVectorStoreProvider {
+    async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
+        let body = json!({
+            "vectors": { "size": dimensions, "distance": self.distance.as_str() },
+        });
+        self.call_collection(Method::PUT, name, "", Some(body))
+            .await?;
+        for field in INDEXED_FIELDS {
+            let body = json!({ "field_name": field, "field_schema": "keyword" });
+            self.call_collection(Method::PUT, name, "/index?wait=true", Some(body))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn delete_collection(&self, name: &str) -> Result<()> {
+        // A missing collection is already deleted
+        self.call(Method::DELETE, &format!("/collections/{}", name), None)
+            .await?;
+        Ok(())
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &str,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        if vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Vectors and metadata length mismatch",
+            ));
+        }
+        let ids: Vec<String> = vectors
+            .iter()
+            .map(|_| format!("{}_{}", collection, Uuid::new_v4()))
+            .collect();
+        self.upsert_vectors(collection, &ids, vectors, metadata)
+            .await?;
+        Ok(ids)
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Ids, vectors and metadata length mismatch",
+            ));
+        }
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let points: Vec<Value> = ids
+            .iter()
+            .zip(vectors)
+            .zip(metadata)
+            .map(|((id, vector), metadata)| {
+                let mut payload: serde_json::Map<String, Value> = metadata.into_iter().collect();
+                payload.insert(CHUNK_ID_KEY.to_string(), json!(id));
+                json!({
+                    "id": Self::point_id(id),
+                    "vector": vector.vector,
+                    "payload": payload,
+                })
+            })
+            .collect();
+        self.call_collection(
+            Method::PUT,
+            collection,
+            "/points?wait=true",
+            Some(json!({ "points": points })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+    ) -> Result<Vec<SearchResult>> {
+        let mut body = json!({
+            "vector": query_vector,
+            "limit": limit,
+            "with_payload": true,
+        });
+        if let Some(filter) = filter.map(MetadataFilter::parse).transpose()?
+            && !filter.conditions().is_empty()
+        {
+            body["filter"] = filter_json(&filter)?;
+        }
+        let path = format!("/collections/{}/points/search", collection);
+        // Return empty results for non-existent collections (graceful degradation)
+        let Some(points) = self.call(Method::POST, &path, Some(body)).await? else {
+            return Ok(Vec::new());
+        };
+
+        Ok(points
+            .as_array()
+            .map(|points| {
+                points
+                    .iter()
+                    .map(|point| {
+                        let score = self.distance.similarity(point.f64_or("score", 0.0));
+                        point_to_result(point, score)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let points: Vec<String> = ids.iter().map(|id| Self::point_id(id)).collect();
+        self.call_collection(
+            Method::POST,
+            collection,
+            "/points/delete?wait=true",
+            Some(json!({ "points": points })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        let filter = filter_json(filter)?;
+        // Qdrant does not report how many points a delete removed
+        let matching = self.count(collection, Some(filter.clone())).await?;
+        if matching > 0 {
+            self.call_collection(
+                Method::POST,
+                collection,
+                "/points/delete?wait=true",
+                Some(json!({ "filter": filter })),
+            )
+            .await?;
+        }
+        Ok(matching)
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        let filter = filter_json(&MetadataFilter::file_path(file_path))?;
+        let points = self
+            .scroll(
+                collection,
+                Some(filter),
+                json!([CHUNK_ID_KEY]),
+                false,
+                usize::MAX,
+            )
+            .await?;
+        Ok(points
+            .iter()
+            .map(|point| point["payload"].string_or(CHUNK_ID_KEY, ""))
+            .collect())
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &str,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let points: Vec<String> = ids.iter().map(|id| Self::point_id(id)).collect();
+        let body = json!({ "ids": points, "with_payload": true, "with_vector": false });
+        let result = self
+            .call_collection(Method::POST, collection, "/points", Some(body))
+            .await?;
+
+        Ok(result
+            .as_array()
+            .map(|points| {
+                points
+                    .iter()
+                    .map(|point| point_to_result(point, 1.0))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let points = self
+            .scroll(collection, None, json!(true), false, limit)
+            .await?;
+        Ok(points
+            .iter()
+            .map(|point| point_to_result(point, 1.0))
+            .collect())
+    }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        let points = self
+            .scroll(collection, None, json!(true), true, usize::MAX)
+            .await?;
+
+        points.into_iter().map(point_to_stored).collect()
+    }
+
+    /// Pages follow Qdrant's point order, resuming at the point of `after`
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        // The scroll offset is inclusive, so fetch one extra point to skip `after`
+        let (offset, fetch) = match after {
+            Some(after) => (json!(Self::point_id(after)), limit.saturating_add(1)),
+            None => (Value::Null, limit),
+        };
+        let points = self
+            .scroll_from(collection, None, json!(true), true, offset, fetch)
+            .await?;
+        let mut vectors = points
+            .into_iter()
+            .map(point_to_stored)
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(after) = after
+            && vectors.first().is_some_and(|vector| vector.id == after)
+        {
+            vectors.remove(0);
+        }
+        vectors.truncate(limit);
+        Ok(vectors)
+    }
+}
+
+#[async_trait]
+impl VectorStoreBrowser for QdrantVectorStoreProvider {
+    async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        let result = self
+            .call(Method::GET, "/collections", None)
+            .await?
+            .unwrap_or_default();
+        let names: Vec<String> = result["collections"]
+            .as_array()
+            .map(|collections| {
+                collections
+                    .iter()
+                    .filter_map(|c| c.opt_str("name").map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut collections = Vec::with_capacity(names.len());
+        for name in names {
+            let vector_count = self.count(&name, None).await?;
+            let file_count = self.list_file_paths(&name, usize::MAX).await?.len() as u64;
+            collections.push(CollectionInfo::new(
+                name,
+                vector_count,
+                file_count,
+                None,
+                self.provider_name(),
+            ));
+        }
+        Ok(collections)
+    }
+
+    async fn list_file_paths(&self, collection: &str, limit: usize) -> Result<Vec<FileInfo>> {
+        let points = self
+            .scroll(
+                collection,
+                None,
+                json!(["file_path", "language"]),
+                false,
+                usize::MAX,
+            )
+            .await?;
+
+        // Aggregate file info from chunks, ordered by path
+        let mut file_map: BTreeMap<String, (u32, String)> = BTreeMap::new();
+        for point in &points {
+            let payload = &point["payload"];
+            if let Some(file_path) = payload.opt_str("file_path") {
+                let entry = file_map
+                    .entry(file_path.to_string())
+                    .or_insert((0, payload.string_or("language", "unknown")));
+                entry.0 += 1;
+            }
+        }
+
+        Ok(file_map
+            .into_iter()
+            .take(limit)
+            .map(|(path, (chunk_count, language))| FileInfo::new(path, chunk_count, language, None))
+            .collect())
+    }
+
+    async fn get_chunks_by_file(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let filter = filter_json(&MetadataFilter::file_path(file_path))?;
+        let points = self
+            .scroll(collection, Some(filter), json!(true), false, usize::MAX)
+            .await?;
+
+        let mut results: Vec<SearchResult> = points
+            .iter()
+            .map(|point| point_to_result(point, 1.0))
+            .collect();
+        results.sort_by_key(|r| r.start_line);
+        Ok(results)
+    }
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+
+use mcb_application::ports::registry::{
+    VECTOR_STORE_PROVIDERS, VectorStoreProviderConfig, VectorStoreProviderEntry,
+};
+
+/// Factory function for creating Qdrant vector store provider instances.
+fn qdrant_factory(
+    config: &VectorStoreProviderConfig,
+) -> std::result::Result<Arc<dyn VectorStoreProvider>, String> {
+    let uri = config.uri.clone().ok_or_else(|| {
+        format!(
+            "Qdrant requires 'uri' configuration (e.g., http://localhost:{})",
+            crate::constants::QDRANT_DEFAULT_PORT
+        )
+    })?;
+    let http_client = Client::builder()
+        .timeout(Duration::from_secs(
+            crate::constants::QDRANT_REQUEST_TIMEOUT_SECS,
+        ))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let mut provider = QdrantVectorStoreProvider::new(uri, http_client);
+    if let Some(api_key) = &config.api_key {
+        provider = provider.with_api_key(api_key);
+    }
+    if let Some(distance) = config.extra.get("distance") {
+        provider = provider.with_distance(distance.parse()?);
+    }
+    Ok(Arc::new(provider))
+}
+
+#[linkme::distributed_slice(VECTOR_STORE_PROVIDERS)]
+static QDRANT_PROVIDER: VectorStoreProviderEntry = VectorStoreProviderEntry {
+    name: "qdrant",
+    description: "Qdrant vector database (HTTP API)",
+    factory: qdrant_factory,
+};
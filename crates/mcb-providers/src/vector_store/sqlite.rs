@@ -0,0 +1,816 @@
+//! SQLite vector store provider implementation
+//!
+//! Keeps every collection in a single SQLite database file. Chunk metadata
+//! lives in real columns, so browsing and deletes by filter are SQL queries.
+//! Vectors are stored as little-endian `f32` blobs next to their norm and
+//! scored by a full scan of the collection.
+
+use crate::constants::{SQLITE_BUSY_TIMEOUT_MS, SQLITE_STATEMENT_CACHE_CAPACITY};
+use crate::utils::JsonExt;
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
+use mcb_domain::value_objects::{
+    CollectionInfo, Embedding, FileInfo, MetadataFilter, SearchResult, StoredVector,
+};
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{Connection, OptionalExtension, Row, params, params_from_iter};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Tables and indexes, created on open
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS collections (
+    name TEXT PRIMARY KEY,
+    dimensions INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS chunks (
+    collection TEXT NOT NULL,
+    id TEXT NOT NULL,
+    file_path TEXT,
+    language TEXT,
+    start_line INTEGER,
+    end_line INTEGER,
+    node_type TEXT,
+    content TEXT,
+    metadata TEXT NOT NULL,
+    norm REAL NOT NULL,
+    vector BLOB NOT NULL,
+    PRIMARY KEY (collection, id)
+);
+CREATE INDEX IF NOT EXISTS chunks_by_file ON chunks (collection, file_path, start_line);
+";
+
+/// Metadata keys whose text column holds the same string as the JSON
+///
+/// `start_line` and `end_line` have columns too, but `start_line` falls
+/// back to `line_number`, so filters read both from the JSON.
+const TEXT_COLUMNS: [&str; 5] = ["id", "file_path", "language", "node_type", "content"];
+
+/// SQLite vector store provider
+///
+/// One connection is shared behind a mutex and used from blocking tasks,
+/// so operations on the store run one at a time. The database runs in WAL
+/// mode, which lets other processes read the file while it is written.
+pub struct SqliteVectorStoreProvider {
+    conn: Arc<Mutex<Connection>>,
+    path: PathBuf,
+}
+
+impl SqliteVectorStoreProvider {
+    /// Open or create the database at `path`, creating its directory
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                Error::io(format!(
+                    "Failed to create directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+        let conn = Connection::open(&path).map_err(db_err)?;
+        Self::init(conn, path)
+    }
+
+    /// Open a private database that lives in memory
+    pub fn open_in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory().map_err(db_err)?;
+        Self::init(conn, PathBuf::from(":memory:"))
+    }
+
+    fn init(conn: Connection, path: PathBuf) -> Result<Self> {
+        conn.busy_timeout(Duration::from_millis(SQLITE_BUSY_TIMEOUT_MS))
+            .map_err(db_err)?;
+        conn.set_prepared_statement_cache_capacity(SQLITE_STATEMENT_CACHE_CAPACITY);
+        conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL;")
+            .map_err(db_err)?;
+        conn.execute_batch(SCHEMA).map_err(db_err)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+            path,
+        })
+    }
+
+    /// Run `f` on the connection from a blocking task
+    async fn with_conn<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Connection) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        tokio::task::spawn_blocking(move || {
+            let mut conn = conn
+                .lock()
+                .map_err(|_| Error::internal("SQLite connection lock poisoned"))?;
+            f(&mut conn)
+        })
+        .await
+        .map_err(|e| Error::internal(format!("SQLite task failed: {}", e)))?
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for SqliteVectorStoreProvider {
+    async fn collection_exists(&self, name: &str) -> Result<bool> {
+        let name = name.to_string();
+        self.with_conn(move |conn| Ok(dimensions(conn, &name)?.is_some()))
+            .await
+    }
+
+    async fn get_stats(&self, collection: &str) -> Result<HashMap<String, Value>> {
+        let name = collection.to_string();
+        let (dimensions, vectors_count, file_count) = self
+            .with_conn(move |conn| {
+                let dimensions = dimensions(conn, &name)?;
+                let (vectors, files): (i64, i64) = conn
+                    .prepare_cached(
+                        "SELECT COUNT(*), COUNT(DISTINCT file_path) FROM chunks WHERE collection = ?1",
+                    )
+                    .and_then(|mut stmt| {
+                        stmt.query_row(params![name], |row| Ok((row.get(0)?, row.get(1)?)))
+                    })
+                    .map_err(db_err)?;
+                Ok((dimensions, vectors, files))
+            })
+            .await?;
+
+        let mut stats = HashMap::new();
+        stats.insert("collection".to_string(), serde_json::json!(collection));
+        stats.insert("status".to_string(), serde_json::json!("active"));
+        stats.insert(
+            "vectors_count".to_string(),
+            serde_json::json!(vectors_count),
+        );
+        stats.insert("file_count".to_string(), serde_json::json!(file_count));
+        stats.insert("dimensions".to_string(), serde_json::json!(dimensions));
+        stats.insert(
+            "database".to_string(),
+            serde_json::json!(self.path.display().to_string()),
+        );
+        stats.insert(
+            "provider".to_string(),
+            serde_json::json!(self.provider_name()),
+        );
+        Ok(stats)
+    }
+
+    async fn flush(&self, _collection: &str) -> Result<()> {
+        // Commits are already durable; this moves the WAL into the main file
+        self.with_conn(|conn| {
+            conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()))
+                .map_err(db_err)
+        })
+        .await
+    }
+
+    async fn list_collection_names(&self) -> Result<Vec<String>> {
+        let collections = VectorStoreBrowser::list_collections(self).await?;
+        Ok(collections.into_iter().map(|c| c.name).collect())
+    }
+
+    fn provider_name(&self) -> &str {
+        "sqlite"
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for SqliteVectorStoreProvider {
+    async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
+        let name = name.to_string();
+        self.with_conn(move |conn| {
+            let created = conn
+                .execute(
+                    "INSERT OR IGNORE INTO collections (name, dimensions) VALUES (?1, ?2)",
+                    params![name, dimensions as i64],
+                )
+                .map_err(db_err)?;
+            if created == 0 {
+                return Err(Error::vector_db(format!(
+                    "Collection '{}' already exists",
+                    name
+                )));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn delete_collection(&self, name: &str) -> Result<()> {
+        let name = name.to_string();
+        self.with_conn(move |conn| {
+            let tx = conn.transaction().map_err(db_err)?;
+            tx.execute("DELETE FROM chunks WHERE collection = ?1", params![name])
+                .map_err(db_err)?;
+            tx.execute("DELETE FROM collections WHERE name = ?1", params![name])
+                .map_err(db_err)?;
+            tx.commit().map_err(db_err)
+        })
+        .await
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &str,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        if vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Vectors and metadata length mismatch",
+            ));
+        }
+        let ids: Vec<String> = vectors
+            .iter()
+            .map(|_| format!("{}_{}", collection, uuid::Uuid::new_v4()))
+            .collect();
+        self.upsert_vectors(collection, &ids, vectors, metadata)
+            .await?;
+        Ok(ids)
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Ids, vectors and metadata length mismatch",
+            ));
+        }
+        let collection = collection.to_string();
+        let ids = ids.to_vec();
+        let vectors: Vec<Vec<f32>> = vectors.iter().map(|v| v.vector.clone()).collect();
+        self.with_conn(move |conn| write_rows(conn, &collection, &ids, &vectors, &metadata))
+            .await
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+    ) -> Result<Vec<SearchResult>> {
+        let (condition, values) = match filter.map(MetadataFilter::parse).transpose()? {
+            Some(filter) if !filter.conditions().is_empty() => {
+                let (condition, values) = filter_condition(&filter)?;
+                (format!(" AND {}", condition), values)
+            }
+            _ => (String::new(), Vec::new()),
+        };
+        let collection = collection.to_string();
+        let query = query_vector.to_vec();
+        self.with_conn(move |conn| {
+            // Return empty results for non-existent collections (graceful degradation)
+            let Some(dimensions) = dimensions(conn, &collection)? else {
+                return Ok(Vec::new());
+            };
+            check_dimensions(&collection, dimensions, query.len())?;
+            let query_norm = norm(&query);
+
+            let mut heap: BinaryHeap<ScoredRow> = BinaryHeap::with_capacity(limit + 1);
+            let mut vector = Vec::with_capacity(dimensions);
+            {
+                let sql = format!(
+                    "SELECT rowid, norm, vector FROM chunks WHERE collection = ?1{}",
+                    condition
+                );
+                let mut stmt = conn.prepare_cached(&sql).map_err(db_err)?;
+                let params = std::iter::once(SqlValue::Text(collection)).chain(values);
+                let mut rows = stmt.query(params_from_iter(params)).map_err(db_err)?;
+                while let Some(row) = rows.next().map_err(db_err)? {
+                    let ValueRef::Blob(blob) = row.get_ref(2).map_err(db_err)? else {
+                        continue;
+                    };
+                    decode_into(blob, &mut vector);
+                    let row_norm: f64 = row.get(1).map_err(db_err)?;
+                    let scored = ScoredRow {
+                        score: similarity(&query, query_norm, &vector, row_norm as f32),
+                        rowid: row.get(0).map_err(db_err)?,
+                    };
+                    if heap.len() < limit {
+                        heap.push(scored);
+                    } else if heap.peek().is_some_and(|min| scored.score > min.score) {
+                        heap.pop();
+                        heap.push(scored);
+                    }
+                }
+            }
+
+            let mut top = heap.into_vec();
+            top.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT id, file_path, start_line, content, language FROM chunks WHERE rowid = ?1",
+                )
+                .map_err(db_err)?;
+            top.into_iter()
+                .map(|scored| {
+                    stmt.query_row(params![scored.rowid], |row| {
+                        search_result(row, scored.score as f64)
+                    })
+                })
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .map_err(db_err)
+        })
+        .await
+    }
+
+    async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
+        let collection = collection.to_string();
+        let ids = ids.to_vec();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let tx = conn.transaction().map_err(db_err)?;
+            {
+                let mut stmt = tx
+                    .prepare_cached("DELETE FROM chunks WHERE collection = ?1 AND id = ?2")
+                    .map_err(db_err)?;
+                for id in &ids {
+                    stmt.execute(params![collection, id]).map_err(db_err)?;
+                }
+            }
+            tx.commit().map_err(db_err)
+        })
+        .await
+    }
+
+    async fn delete_by_filter(&self, collection: &str, filter: &MetadataFilter) -> Result<u64> {
+        filter.ensure_not_empty()?;
+        let (condition, values) = filter_condition(filter)?;
+        let collection = collection.to_string();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let sql = format!("DELETE FROM chunks WHERE collection = ?1 AND {}", condition);
+            let params = std::iter::once(SqlValue::Text(collection)).chain(values);
+            let deleted = conn
+                .execute(&sql, params_from_iter(params))
+                .map_err(db_err)?;
+            Ok(deleted as u64)
+        })
+        .await
+    }
+
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        let collection = collection.to_string();
+        let file_path = file_path.to_string();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let mut stmt = conn
+                .prepare_cached("SELECT id FROM chunks WHERE collection = ?1 AND file_path = ?2")
+                .map_err(db_err)?;
+            stmt.query_map(params![collection, file_path], |row| row.get(0))
+                .and_then(|rows| rows.collect())
+                .map_err(db_err)
+        })
+        .await
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &str,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        let collection = collection.to_string();
+        let ids = ids.to_vec();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT id, file_path, start_line, content, language FROM chunks
+                     WHERE collection = ?1 AND id = ?2",
+                )
+                .map_err(db_err)?;
+            let mut results = Vec::with_capacity(ids.len());
+            for id in &ids {
+                let found = stmt
+                    .query_row(params![collection, id], |row| search_result(row, 1.0))
+                    .optional()
+                    .map_err(db_err)?;
+                results.extend(found);
+            }
+            Ok(results)
+        })
+        .await
+    }
+
+    async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let collection = collection.to_string();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT id, file_path, start_line, content, language FROM chunks
+                     WHERE collection = ?1 ORDER BY rowid LIMIT ?2",
+                )
+                .map_err(db_err)?;
+            stmt.query_map(params![collection, sql_limit(limit)], |row| {
+                search_result(row, 1.0)
+            })
+            .and_then(|rows| rows.collect())
+            .map_err(db_err)
+        })
+        .await
+    }
+
+    async fn export_vectors(&self, collection: &str) -> Result<Vec<StoredVector>> {
+        let collection = collection.to_string();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT id, metadata, vector FROM chunks WHERE collection = ?1 ORDER BY rowid",
+                )
+                .map_err(db_err)?;
+            let rows: Vec<(String, String, Vec<u8>)> = stmt
+                .query_map(params![collection], |row| {
+                    Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+                })
+                .and_then(|rows| rows.collect())
+                .map_err(db_err)?;
+            rows.into_iter().map(stored_vector).collect()
+        })
+        .await
+    }
+
+    async fn export_vectors_page(
+        &self,
+        collection: &str,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredVector>> {
+        let collection = collection.to_string();
+        let after = after.map(str::to_string);
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT id, metadata, vector FROM chunks \
+                     WHERE collection = ?1 AND (?2 IS NULL OR id > ?2) ORDER BY id LIMIT ?3",
+                )
+                .map_err(db_err)?;
+            let rows: Vec<(String, String, Vec<u8>)> = stmt
+                .query_map(params![collection, after, sql_limit(limit)], |row| {
+                    Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+                })
+                .and_then(|rows| rows.collect())
+                .map_err(db_err)?;
+            rows.into_iter().map(stored_vector).collect()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl VectorStoreBrowser for SqliteVectorStoreProvider {
+    async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        let rows: Vec<(String, i64, i64)> = self
+            .with_conn(|conn| {
+                let mut stmt = conn
+                    .prepare_cached(
+                        "SELECT c.name, COUNT(k.id), COUNT(DISTINCT k.file_path)
+                         FROM collections c LEFT JOIN chunks k ON k.collection = c.name
+                         GROUP BY c.name ORDER BY c.name",
+                    )
+                    .map_err(db_err)?;
+                stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+                    .and_then(|rows| rows.collect())
+                    .map_err(db_err)
+            })
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(name, vectors, files)| {
+                CollectionInfo::new(
+                    name,
+                    vectors as u64,
+                    files as u64,
+                    None,
+                    self.provider_name(),
+                )
+            })
+            .collect())
+    }
+
+    async fn list_file_paths(&self, collection: &str, limit: usize) -> Result<Vec<FileInfo>> {
+        let collection = collection.to_string();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT file_path, COUNT(*), MAX(language) FROM chunks
+                     WHERE collection = ?1 AND file_path IS NOT NULL
+                     GROUP BY file_path ORDER BY file_path LIMIT ?2",
+                )
+                .map_err(db_err)?;
+            stmt.query_map(params![collection, sql_limit(limit)], |row| {
+                let language: Option<String> = row.get(2)?;
+                Ok(FileInfo::new(
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)? as u32,
+                    language.unwrap_or_else(|| "unknown".to_string()),
+                    None,
+                ))
+            })
+            .and_then(|rows| rows.collect())
+            .map_err(db_err)
+        })
+        .await
+    }
+
+    async fn get_chunks_by_file(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let collection = collection.to_string();
+        let file_path = file_path.to_string();
+        self.with_conn(move |conn| {
+            require_collection(conn, &collection)?;
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT id, file_path, start_line, content, language FROM chunks
+                     WHERE collection = ?1 AND file_path = ?2 ORDER BY start_line",
+                )
+                .map_err(db_err)?;
+            stmt.query_map(params![collection, file_path], |row| {
+                search_result(row, 1.0)
+            })
+            .and_then(|rows| rows.collect())
+            .map_err(db_err)
+        })
+        .await
+    }
+}
+
+/// Insert or replace rows, keyed by collection and id, in one transaction
+fn write_rows(
+    conn: &mut Connection,
+    collection: &str,
+    ids: &[String],
+    vectors: &[Vec<f32>],
+    metadata: &[HashMap<String, Value>],
+) -> Result<()> {
+    let dimensions = require_collection(conn, collection)?;
+    for vector in vectors {
+        check_dimensions(collection, dimensions, vector.len())?;
+    }
+
+    let tx = conn.transaction().map_err(db_err)?;
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT OR REPLACE INTO chunks (collection, id, file_path, language, start_line,
+                     end_line, node_type, content, metadata, norm, vector)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            )
+            .map_err(db_err)?;
+        for ((id, vector), meta) in ids.iter().zip(vectors).zip(metadata) {
+            let json = serde_json::to_string(meta)
+                .map_err(|e| Error::internal(format!("Failed to serialize metadata: {}", e)))?;
+            let start_line = meta
+                .opt_i64("start_line")
+                .or_else(|| meta.opt_i64("line_number"));
+            stmt.execute(params![
+                collection,
+                id,
+                meta.opt_str("file_path"),
+                meta.opt_str("language"),
+                start_line,
+                meta.opt_i64("end_line"),
+                meta.opt_str("node_type"),
+                meta.opt_str("content"),
+                json,
+                norm(vector) as f64,
+                encode(vector),
+            ])
+            .map_err(db_err)?;
+        }
+    }
+    tx.commit().map_err(db_err)
+}
+
+/// Dimensions of `collection`, or `None` when it does not exist
+fn dimensions(conn: &Connection, collection: &str) -> Result<Option<usize>> {
+    conn.prepare_cached("SELECT dimensions FROM collections WHERE name = ?1")
+        .and_then(|mut stmt| {
+            stmt.query_row(params![collection], |row| row.get::<_, i64>(0))
+                .optional()
+        })
+        .map(|dimensions| dimensions.map(|d| d as usize))
+        .map_err(db_err)
+}
+
+fn require_collection(conn: &Connection, collection: &str) -> Result<usize> {
+    dimensions(conn, collection)?
+        .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))
+}
+
+fn check_dimensions(collection: &str, expected: usize, actual: usize) -> Result<()> {
+    if expected == actual {
+        return Ok(());
+    }
+    Err(Error::invalid_argument(format!(
+        "Collection '{}' has {} dimensions, got a vector with {}",
+        collection, expected, actual
+    )))
+}
+
+/// SQL condition for `filter` and its parameters, numbered from `?2`
+///
+/// Matches exactly what [`MetadataFilter::matches`] accepts: a string on a
+/// key in `TEXT_COLUMNS` compares against its column; every other
+/// condition reads the metadata JSON and requires the JSON type to match,
+/// so `1` does not match `1.0` or `true`, and `null` does not match a
+/// missing key.
+fn filter_condition(filter: &MetadataFilter) -> Result<(String, Vec<SqlValue>)> {
+    let mut terms = Vec::new();
+    let mut values = Vec::new();
+    for (key, value) in filter.conditions() {
+        if let (true, Value::String(s)) = (TEXT_COLUMNS.contains(&key.as_str()), value) {
+            values.push(SqlValue::Text(s.clone()));
+            terms.push(format!("{} = ?{}", key, values.len() + 1));
+            continue;
+        }
+
+        values.push(SqlValue::Text(format!(
+            "$.\"{}\"",
+            key.replace('\\', "\\\\").replace('"', "\\\"")
+        )));
+        let path = values.len() + 1;
+        let json_type = format!("json_type(metadata, ?{})", path);
+        let (expected_type, operand) = match value {
+            Value::Null => ("null", None),
+            Value::Bool(true) => ("true", None),
+            Value::Bool(false) => ("false", None),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => ("integer", Some(SqlValue::Integer(i))),
+                None => ("real", Some(SqlValue::Real(n.as_f64().unwrap_or_default()))),
+            },
+            Value::String(s) => ("text", Some(SqlValue::Text(s.clone()))),
+            Value::Array(_) | Value::Object(_) => {
+                return Err(Error::invalid_argument(format!(
+                    "Cannot filter on nested metadata value for '{}'",
+                    key
+                )));
+            }
+        };
+        let mut term = format!("{} = '{}'", json_type, expected_type);
+        if let Some(operand) = operand {
+            values.push(operand);
+            term.push_str(&format!(
+                " AND json_extract(metadata, ?{}) = ?{}",
+                path,
+                values.len() + 1
+            ));
+        }
+        terms.push(term);
+    }
+    Ok((terms.join(" AND "), values))
+}
+
+fn search_result(row: &Row<'_>, score: f64) -> rusqlite::Result<SearchResult> {
+    Ok(SearchResult {
+        id: row.get(0)?,
+        file_path: row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+        start_line: row.get::<_, Option<i64>>(2)?.unwrap_or(0) as u32,
+        content: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+        score,
+        language: row
+            .get::<_, Option<String>>(4)?
+            .unwrap_or_else(|| "unknown".to_string()),
+    })
+}
+
+/// Exported vector from an `(id, metadata, vector)` row
+fn stored_vector((id, metadata, blob): (String, String, Vec<u8>)) -> Result<StoredVector> {
+    let metadata = serde_json::from_str(&metadata)
+        .map_err(|e| Error::vector_db(format!("Invalid metadata for chunk '{}': {}", id, e)))?;
+    let mut vector = Vec::new();
+    decode_into(&blob, &mut vector);
+    Ok(StoredVector {
+        id,
+        vector,
+        metadata,
+    })
+}
+
+/// SQLite `LIMIT` value; `usize::MAX` means no limit
+fn sql_limit(limit: usize) -> i64 {
+    i64::try_from(limit).unwrap_or(i64::MAX)
+}
+
+fn db_err(e: rusqlite::Error) -> Error {
+    Error::vector_db(format!("SQLite error: {}", e))
+}
+
+fn encode(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+fn decode_into(blob: &[u8], vector: &mut Vec<f32>) {
+    vector.clear();
+    vector.extend(
+        blob.chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+    );
+}
+
+/// Dot product accumulated in eight independent lanes
+///
+/// Without a dependency between iterations the compiler emits SIMD
+/// multiply-adds for the main loop.
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    let a_chunks = a.chunks_exact(8);
+    let b_chunks = b.chunks_exact(8);
+    let tail: f32 = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| x * y)
+        .sum();
+
+    let mut lanes = [0.0f32; 8];
+    for (x, y) in a_chunks.zip(b_chunks) {
+        for ((lane, x), y) in lanes.iter_mut().zip(x).zip(y) {
+            *lane += x * y;
+        }
+    }
+    lanes.iter().sum::<f32>() + tail
+}
+
+fn norm(vector: &[f32]) -> f32 {
+    dot(vector, vector).sqrt()
+}
+
+/// Cosine similarity normalized to `[0, 1]`, from precomputed norms
+fn similarity(query: &[f32], query_norm: f32, vector: &[f32], vector_norm: f32) -> f32 {
+    if query_norm == 0.0 || vector_norm == 0.0 {
+        return 0.0;
+    }
+    (dot(query, vector) / (query_norm * vector_norm) + 1.0) / 2.0
+}
+
+/// Scored row for heap-based top-k selection
+///
+/// Uses reverse ordering so BinaryHeap acts as a min-heap (smallest scores at top).
+#[derive(PartialEq)]
+struct ScoredRow {
+    score: f32,
+    rowid: i64,
+}
+
+impl Eq for ScoredRow {}
+
+impl Ord for ScoredRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .partial_cmp(&self.score)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for ScoredRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use mcb_application::ports::registry::{
+    VECTOR_STORE_PROVIDERS, VectorStoreProviderConfig, VectorStoreProviderEntry,
+};
+
+/// Factory function for creating SQLite vector store provider instances.
+fn sqlite_factory(
+    config: &VectorStoreProviderConfig,
+) -> std::result::Result<Arc<dyn VectorStoreProvider>, String> {
+    let path = config.uri.clone().ok_or_else(|| {
+        "SQLite store requires 'uri' configuration (path to database file)".to_string()
+    })?;
+    let store = SqliteVectorStoreProvider::open(path)
+        .map_err(|e| format!("Failed to open SQLite store: {e}"))?;
+    Ok(Arc::new(store))
+}
+
+#[linkme::distributed_slice(VECTOR_STORE_PROVIDERS)]
+static SQLITE_PROVIDER: VectorStoreProviderEntry = VectorStoreProviderEntry {
+    name: "sqlite",
+    description: "SQLite vector store (single file, persistent)",
+    factory: sqlite_factory,
+};
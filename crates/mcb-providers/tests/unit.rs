@@ -2,6 +2,54 @@
 //!
 //! Run with: `cargo test -p mcb-providers --test unit --features hybrid-search`
 
+#[cfg(feature = "cache-moka")]
+#[path = "unit/cache_namespace_tests.rs"]
+mod cache_namespace_tests;
+
+#[cfg(feature = "vectorstore-edgevec")]
+#[path = "unit/edgevec_index_tests.rs"]
+mod edgevec_index_tests;
+
+#[cfg(feature = "vectorstore-edgevec")]
+#[path = "unit/edgevec_persistence_tests.rs"]
+mod edgevec_persistence_tests;
+
+#[cfg(feature = "vectorstore-filesystem")]
+#[path = "unit/filesystem_ann_tests.rs"]
+mod filesystem_ann_tests;
+
+#[cfg(feature = "vectorstore-filesystem")]
+#[path = "unit/filesystem_storage_tests.rs"]
+mod filesystem_storage_tests;
+
+#[path = "unit/hashing_embedding_tests.rs"]
+mod hashing_embedding_tests;
+
 #[cfg(feature = "hybrid-search")]
 #[path = "unit/hybrid_search_tests.rs"]
 mod hybrid_search_tests;
+
+#[cfg(feature = "embedding-fastembed")]
+#[path = "unit/local_model_tests.rs"]
+mod local_model_tests;
+
+#[path = "unit/mock_http.rs"]
+mod mock_http;
+
+#[path = "unit/openai_compatible_tests.rs"]
+mod openai_compatible_tests;
+
+#[cfg(feature = "vectorstore-qdrant")]
+#[path = "unit/qdrant_store_tests.rs"]
+mod qdrant_store_tests;
+
+#[cfg(feature = "vectorstore-sqlite")]
+#[path = "unit/sqlite_store_tests.rs"]
+mod sqlite_store_tests;
+
+#[cfg(feature = "cache-moka")]
+#[path = "unit/tiered_cache_tests.rs"]
+mod tiered_cache_tests;
+
+#[path = "unit/vector_store_conformance_tests.rs"]
+mod vector_store_conformance_tests;
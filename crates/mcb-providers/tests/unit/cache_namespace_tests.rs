@@ -0,0 +1,158 @@
+//! Cache Namespace Tests
+//!
+//! Checks per-entry TTLs and the namespace operations of the cache
+//! providers. `test_redis_namespaces_against_real_redis` runs only when
+//! `REDIS_URL` points at a Redis server, e.g. `REDIS_URL=redis://localhost:6379`.
+
+use std::time::Duration;
+
+use mcb_domain::ports::providers::{CacheEntryConfig, CacheProvider, NamespaceStats};
+use mcb_providers::cache::{MokaCacheProvider, NullCacheProvider};
+
+/// Store two search results and one embedding, and check every namespace
+/// operation against them
+async fn check_namespaces(cache: &dyn CacheProvider) {
+    for key in ["search:code:b", "search:code:a"] {
+        cache
+            .set_json(key, "[]", CacheEntryConfig::default())
+            .await
+            .unwrap();
+    }
+    cache
+        .set_json(
+            "abc",
+            "[0.5]",
+            CacheEntryConfig::default().with_namespace("embeddings"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        cache.list_keys("search").await.unwrap(),
+        vec!["search:code:a", "search:code:b"]
+    );
+    assert_eq!(cache.list_keys("embeddings").await.unwrap(), vec!["abc"]);
+    assert_eq!(
+        cache.stats_by_namespace().await.unwrap(),
+        vec![
+            NamespaceStats {
+                namespace: "embeddings".to_string(),
+                entries: 1,
+                bytes_used: 5,
+            },
+            NamespaceStats {
+                namespace: "search".to_string(),
+                entries: 2,
+                bytes_used: 4,
+            },
+        ]
+    );
+
+    assert_eq!(cache.clear_namespace("search").await.unwrap(), 2);
+    assert!(cache.list_keys("search").await.unwrap().is_empty());
+    assert!(!cache.exists("search:code:a").await.unwrap());
+    assert_eq!(
+        cache.get_json("abc").await.unwrap().as_deref(),
+        Some("[0.5]")
+    );
+    assert_eq!(cache.clear_namespace("search").await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_moka_namespaces() {
+    check_namespaces(&MokaCacheProvider::new()).await;
+}
+
+#[tokio::test]
+async fn test_moka_honours_entry_ttl() {
+    let cache = MokaCacheProvider::new();
+    cache
+        .set_json(
+            "search:short",
+            "1",
+            CacheEntryConfig::default().with_ttl(Duration::from_millis(50)),
+        )
+        .await
+        .unwrap();
+    cache
+        .set_json("search:long", "2", CacheEntryConfig::default())
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    assert_eq!(cache.get_json("search:short").await.unwrap(), None);
+    assert_eq!(
+        cache.get_json("search:long").await.unwrap().as_deref(),
+        Some("2")
+    );
+    assert_eq!(
+        cache.list_keys("search").await.unwrap(),
+        vec!["search:long"]
+    );
+}
+
+#[tokio::test]
+async fn test_moka_zero_ttl_never_expires() {
+    let cache = MokaCacheProvider::new();
+    cache
+        .set_json("key", "1", CacheEntryConfig::default().with_ttl_secs(0))
+        .await
+        .unwrap();
+
+    assert_eq!(cache.get_json("key").await.unwrap().as_deref(), Some("1"));
+}
+
+#[tokio::test]
+async fn test_null_has_no_namespaces() {
+    let cache = NullCacheProvider::new();
+    cache
+        .set_json("search:a", "[]", CacheEntryConfig::default())
+        .await
+        .unwrap();
+
+    assert_eq!(cache.clear_namespace("search").await.unwrap(), 0);
+    assert!(cache.list_keys("search").await.unwrap().is_empty());
+    assert!(cache.stats_by_namespace().await.unwrap().is_empty());
+}
+
+#[cfg(feature = "cache-redis")]
+#[tokio::test]
+async fn test_redis_namespaces_against_real_redis() {
+    let Ok(url) = std::env::var("REDIS_URL") else {
+        return;
+    };
+    let cache = mcb_providers::cache::RedisCacheProvider::new(&url).unwrap();
+    cache.clear().await.unwrap();
+
+    check_namespaces(&cache).await;
+
+    cache
+        .set_json(
+            "search:short",
+            "1",
+            CacheEntryConfig::default().with_ttl_secs(1),
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    assert!(cache.list_keys("search").await.unwrap().is_empty());
+
+    // The index of a namespace expires with its last entry
+    cache
+        .set_json(
+            "expiring:key",
+            "1",
+            CacheEntryConfig::default().with_ttl(Duration::from_millis(500)),
+        )
+        .await
+        .unwrap();
+    let index = format!(
+        "{}expiring",
+        mcb_providers::constants::REDIS_NAMESPACE_INDEX_PREFIX
+    );
+    assert!(cache.exists(&index).await.unwrap());
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+    assert!(!cache.exists(&index).await.unwrap());
+    cache.clear().await.unwrap();
+}
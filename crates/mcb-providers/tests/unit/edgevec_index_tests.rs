@@ -0,0 +1,123 @@
+//! EdgeVec Index Tests
+//!
+//! Covers exclusion of deleted vectors from search results and the
+//! background rebuild once enough of a collection is deleted.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
+use mcb_domain::value_objects::Embedding;
+use mcb_providers::vector_store::{EdgeVecConfig, EdgeVecVectorStoreProvider};
+
+const DIMENSIONS: usize = 8;
+
+fn provider(compaction_threshold: f64) -> EdgeVecVectorStoreProvider {
+    EdgeVecVectorStoreProvider::new(EdgeVecConfig {
+        dimensions: DIMENSIONS,
+        compaction_threshold,
+        compaction_min_tombstones: 1,
+        ..Default::default()
+    })
+    .expect("provider")
+}
+
+/// Unit vector pointing mostly along axis `i % DIMENSIONS`
+fn embedding(i: usize) -> Embedding {
+    let mut vector = vec![0.05; DIMENSIONS];
+    vector[i % DIMENSIONS] = 1.0;
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    Embedding {
+        vector: vector.into_iter().map(|v| v / norm).collect(),
+        model: "test".to_string(),
+        dimensions: DIMENSIONS,
+    }
+}
+
+async fn insert(store: &EdgeVecVectorStoreProvider, count: usize) -> Vec<String> {
+    let vectors: Vec<Embedding> = (0..count).map(embedding).collect();
+    let metadata = (0..count)
+        .map(|i| {
+            HashMap::from([
+                (
+                    "file_path".to_string(),
+                    serde_json::json!(format!("f{i}.rs")),
+                ),
+                (
+                    "content".to_string(),
+                    serde_json::json!(format!("chunk {i}")),
+                ),
+            ])
+        })
+        .collect();
+    store
+        .insert_vectors("code", &vectors, metadata)
+        .await
+        .unwrap()
+}
+
+async fn stat(store: &EdgeVecVectorStoreProvider, key: &str) -> serde_json::Value {
+    store.get_stats("code").await.unwrap()[key].clone()
+}
+
+#[tokio::test]
+async fn test_deleted_vectors_are_excluded_from_search() {
+    let store = provider(0.0);
+    let ids = insert(&store, 16).await;
+    // Delete every vector near axis 0 but one
+    store
+        .delete_vectors("code", &[ids[0].clone(), ids[8].clone()])
+        .await
+        .unwrap();
+
+    let results = store
+        .search_similar("code", &embedding(0).vector, 5, None)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 5);
+    assert!(results.iter().all(|r| r.id != ids[0] && r.id != ids[8]));
+    assert_eq!(stat(&store, "tombstones").await, 2);
+    assert_eq!(stat(&store, "vector_count").await, 14);
+}
+
+#[tokio::test]
+async fn test_search_returns_only_live_vectors_when_most_are_deleted() {
+    let store = provider(0.0);
+    let ids = insert(&store, 32).await;
+    store.delete_vectors("code", &ids[..30]).await.unwrap();
+
+    let results = store
+        .search_similar("code", &embedding(3).vector, 10, None)
+        .await
+        .unwrap();
+    let mut found: Vec<_> = results.iter().map(|r| r.id.clone()).collect();
+    found.sort();
+    let mut live = ids[30..].to_vec();
+    live.sort();
+    assert_eq!(found, live);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_index_is_rebuilt_past_the_tombstone_threshold() {
+    let store = provider(0.5);
+    let ids = insert(&store, 20).await;
+    store.delete_vectors("code", &ids[..12]).await.unwrap();
+
+    let mut compactions = serde_json::json!(0);
+    for _ in 0..100 {
+        compactions = stat(&store, "compactions").await;
+        if compactions != 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(compactions, 1);
+    assert_eq!(stat(&store, "tombstones").await, 0);
+    assert_eq!(stat(&store, "vector_count").await, 8);
+
+    let results = store
+        .search_similar("code", &embedding(12).vector, 1, None)
+        .await
+        .unwrap();
+    assert_eq!(results[0].id, ids[12]);
+}
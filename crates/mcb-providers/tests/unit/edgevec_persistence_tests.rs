@@ -0,0 +1,179 @@
+//! EdgeVec Persistence Tests
+//!
+//! Covers flushing collections to the data directory, lazy reload by a new
+//! provider and snapshot removal on delete.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
+use mcb_domain::value_objects::Embedding;
+use mcb_providers::vector_store::{EdgeVecConfig, EdgeVecVectorStoreProvider};
+
+const DIMENSIONS: usize = 4;
+
+fn provider(dir: &Path) -> EdgeVecVectorStoreProvider {
+    EdgeVecVectorStoreProvider::new(EdgeVecConfig {
+        dimensions: DIMENSIONS,
+        data_dir: Some(dir.to_path_buf()),
+        flush_interval_secs: 0,
+        ..Default::default()
+    })
+    .expect("provider")
+}
+
+fn embedding(vector: [f32; DIMENSIONS]) -> Embedding {
+    Embedding {
+        vector: vector.to_vec(),
+        model: "test".to_string(),
+        dimensions: DIMENSIONS,
+    }
+}
+
+fn chunk(file_path: &str, content: &str) -> HashMap<String, serde_json::Value> {
+    HashMap::from([
+        ("file_path".to_string(), serde_json::json!(file_path)),
+        ("content".to_string(), serde_json::json!(content)),
+        ("start_line".to_string(), serde_json::json!(1)),
+    ])
+}
+
+#[tokio::test]
+async fn test_flushed_collection_reloads_in_new_provider() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = provider(dir.path());
+    store.create_collection("code", DIMENSIONS).await.unwrap();
+    let ids = store
+        .insert_vectors(
+            "code",
+            &[
+                embedding([1.0, 0.0, 0.0, 0.0]),
+                embedding([0.0, 1.0, 0.0, 0.0]),
+                embedding([0.0, 0.0, 1.0, 0.0]),
+            ],
+            vec![
+                chunk("src/a.rs", "alpha"),
+                chunk("src/b.rs", "beta"),
+                chunk("src/c.rs", "gamma"),
+            ],
+        )
+        .await
+        .unwrap();
+    store.delete_vectors("code", &ids[2..]).await.unwrap();
+    store.flush("code").await.unwrap();
+    assert!(dir.path().join("code.edgevec").is_file());
+    drop(store);
+
+    let reopened = provider(dir.path());
+    assert!(reopened.collection_exists("code").await.unwrap());
+
+    let results = reopened
+        .search_similar("code", &[0.0, 1.0, 0.0, 0.0], 1, None)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, ids[1]);
+    assert_eq!(results[0].content, "beta");
+
+    let stored = reopened.get_vectors_by_ids("code", &ids).await.unwrap();
+    assert_eq!(stored.len(), 2, "deleted vector must stay deleted");
+
+    let collections = reopened.list_collections().await.unwrap();
+    assert_eq!(collections.len(), 1);
+    assert_eq!(collections[0].vector_count, 2);
+}
+
+#[tokio::test]
+async fn test_unflushed_changes_are_not_visible_on_disk() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = provider(dir.path());
+    store
+        .insert_vectors(
+            "code",
+            &[embedding([1.0, 0.0, 0.0, 0.0])],
+            vec![chunk("src/a.rs", "alpha")],
+        )
+        .await
+        .unwrap();
+
+    assert!(!dir.path().join("code.edgevec").exists());
+    assert!(
+        !provider(dir.path())
+            .collection_exists("code")
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_delete_collection_removes_snapshot() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = provider(dir.path());
+    store.create_collection("code", DIMENSIONS).await.unwrap();
+    store.flush("code").await.unwrap();
+    assert!(dir.path().join("code.edgevec").is_file());
+
+    store.delete_collection("code").await.unwrap();
+    assert!(!dir.path().join("code.edgevec").exists());
+    assert!(
+        !provider(dir.path())
+            .collection_exists("code")
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_corrupt_snapshot_is_reported_on_first_use() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(dir.path().join("code.edgevec"), b"not a snapshot").unwrap();
+
+    let store = provider(dir.path());
+    assert!(store.collection_exists("code").await.unwrap());
+    assert!(store.list_vectors("code", 10).await.is_err());
+}
+
+#[tokio::test]
+async fn test_reload_keeps_graph_tombstones() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = provider(dir.path());
+    let ids = store
+        .insert_vectors(
+            "code",
+            &[
+                embedding([1.0, 0.0, 0.0, 0.0]),
+                embedding([0.0, 1.0, 0.0, 0.0]),
+            ],
+            vec![chunk("src/a.rs", "alpha"), chunk("src/b.rs", "beta")],
+        )
+        .await
+        .unwrap();
+    store.delete_vectors("code", &ids[..1]).await.unwrap();
+    store.flush("code").await.unwrap();
+    drop(store);
+
+    let stats = provider(dir.path()).get_stats("code").await.unwrap();
+    assert_eq!(stats["tombstones"], 1, "graph restored, not rebuilt");
+}
+
+#[tokio::test]
+async fn test_failed_load_does_not_affect_other_collections() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(dir.path().join("broken.edgevec"), b"not a snapshot").unwrap();
+    let store = provider(dir.path());
+    store
+        .insert_vectors(
+            "code",
+            &[embedding([1.0, 0.0, 0.0, 0.0])],
+            vec![chunk("src/a.rs", "alpha")],
+        )
+        .await
+        .unwrap();
+
+    let (broken, code) = tokio::join!(
+        store.list_vectors("broken", 10),
+        store.list_vectors("code", 10)
+    );
+    assert!(broken.is_err());
+    assert_eq!(code.unwrap().len(), 1);
+}
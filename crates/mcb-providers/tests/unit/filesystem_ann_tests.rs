@@ -0,0 +1,150 @@
+//! Filesystem ANN Tests
+//!
+//! Covers approximate search against the exact scan, rebuilding a missing
+//! or corrupt index from the shards and the exact mode of small collections.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
+use mcb_domain::value_objects::Embedding;
+use mcb_providers::vector_store::{
+    FilesystemSearchConfig, FilesystemVectorStore, FilesystemVectorStoreConfig,
+};
+
+const DIMENSIONS: usize = 8;
+const VECTORS: usize = 64;
+
+async fn store(dir: &Path, exact_below: usize) -> FilesystemVectorStore {
+    FilesystemVectorStore::new(FilesystemVectorStoreConfig {
+        base_path: dir.to_path_buf(),
+        dimensions: DIMENSIONS,
+        search: FilesystemSearchConfig {
+            exact_below,
+            nprobe: 2,
+            nlist: 4,
+        },
+        ..Default::default()
+    })
+    .await
+    .expect("store")
+}
+
+/// Vector in one of four clusters, offset from its axis by its index
+fn embedding(i: usize) -> Embedding {
+    let mut vector = vec![0.0; DIMENSIONS];
+    vector[i % 4] = 1.0;
+    vector[i % 4 + 4] = (i / 4) as f32 * 0.02;
+    Embedding {
+        vector,
+        model: "test".to_string(),
+        dimensions: DIMENSIONS,
+    }
+}
+
+async fn populate(store: &FilesystemVectorStore) -> Vec<String> {
+    let vectors: Vec<Embedding> = (0..VECTORS).map(embedding).collect();
+    let metadata = (0..VECTORS)
+        .map(|i| {
+            HashMap::from([
+                (
+                    "file_path".to_string(),
+                    serde_json::json!(format!("f{i}.rs")),
+                ),
+                (
+                    "content".to_string(),
+                    serde_json::json!(format!("chunk {i}")),
+                ),
+            ])
+        })
+        .collect();
+    store
+        .insert_vectors("code", &vectors, metadata)
+        .await
+        .unwrap()
+}
+
+async fn top_ids(store: &FilesystemVectorStore, query: usize, limit: usize) -> Vec<String> {
+    store
+        .search_similar("code", &embedding(query).vector, limit, None)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect()
+}
+
+async fn stat(store: &FilesystemVectorStore, key: &str) -> serde_json::Value {
+    store.get_stats("code").await.unwrap()[key].clone()
+}
+
+#[tokio::test]
+async fn test_ann_search_matches_exact_search_on_clustered_data() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let ann = store(dir.path(), 0).await;
+    populate(&ann).await;
+
+    let exact = store(dir.path(), usize::MAX).await;
+    for query in [3, 12, 45] {
+        assert_eq!(
+            top_ids(&ann, query, 5).await,
+            top_ids(&exact, query, 5).await
+        );
+    }
+    assert_eq!(stat(&ann, "search_mode").await, "ann");
+    assert_eq!(stat(&ann, "ann_lists").await, 4);
+    assert!(dir.path().join("code_ann.json").is_file());
+}
+
+#[tokio::test]
+async fn test_ann_index_follows_inserts_and_deletes() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = store(dir.path(), 0).await;
+    let ids = populate(&store).await;
+    assert_eq!(top_ids(&store, 5, 1).await, vec![ids[5].clone()]);
+
+    store.delete_vectors("code", &ids[5..6]).await.unwrap();
+    assert!(!top_ids(&store, 5, 10).await.contains(&ids[5]));
+
+    let added = store
+        .insert_vectors(
+            "code",
+            &[embedding(5)],
+            vec![HashMap::from([(
+                "file_path".to_string(),
+                serde_json::json!("new.rs"),
+            )])],
+        )
+        .await
+        .unwrap();
+    assert_eq!(top_ids(&store, 5, 1).await, added);
+}
+
+#[tokio::test]
+async fn test_missing_or_corrupt_ann_index_is_rebuilt_from_shards() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let ids = populate(&store(dir.path(), 0).await).await;
+    let ann_path = dir.path().join("code_ann.json");
+
+    std::fs::write(&ann_path, b"not an index").unwrap();
+    let reopened = store(dir.path(), 0).await;
+    assert_eq!(top_ids(&reopened, 20, 1).await, vec![ids[20].clone()]);
+    assert_eq!(stat(&reopened, "ann_lists").await, 4);
+
+    std::fs::remove_file(&ann_path).unwrap();
+    let reopened = store(dir.path(), 0).await;
+    assert_eq!(top_ids(&reopened, 33, 1).await, vec![ids[33].clone()]);
+    assert!(ann_path.is_file());
+}
+
+#[tokio::test]
+async fn test_small_collections_use_exact_search() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = store(dir.path(), VECTORS + 1).await;
+    let ids = populate(&store).await;
+
+    assert_eq!(top_ids(&store, 7, 1).await, vec![ids[7].clone()]);
+    assert_eq!(stat(&store, "search_mode").await, "exact");
+    assert_eq!(stat(&store, "ann_lists").await, 0);
+    assert!(!dir.path().join("code_ann.json").exists());
+}
@@ -0,0 +1,218 @@
+//! Filesystem Storage Tests
+//!
+//! Covers write-ahead log replay after an unclean stop, torn log and shard
+//! tails, compaction of deleted vectors and the consistency check on load.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
+use mcb_domain::value_objects::Embedding;
+use mcb_providers::vector_store::{
+    FilesystemStorageConfig, FilesystemSyncPolicy, FilesystemVectorStore,
+    FilesystemVectorStoreConfig,
+};
+
+const DIMENSIONS: usize = 4;
+
+async fn open_store_with(
+    dir: &Path,
+    memory_mapping_enabled: bool,
+    sync: FilesystemSyncPolicy,
+) -> FilesystemVectorStore {
+    FilesystemVectorStore::new(FilesystemVectorStoreConfig {
+        base_path: dir.to_path_buf(),
+        dimensions: DIMENSIONS,
+        memory_mapping_enabled,
+        storage: FilesystemStorageConfig {
+            sync,
+            // Compaction is triggered explicitly
+            compaction_threshold: 0.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await
+    .expect("store")
+}
+
+async fn open_store(dir: &Path) -> FilesystemVectorStore {
+    open_store_with(dir, true, FilesystemSyncPolicy::Batch).await
+}
+
+fn embedding(i: usize) -> Embedding {
+    let mut vector = vec![0.1; DIMENSIONS];
+    vector[i % DIMENSIONS] = 1.0 + i as f32;
+    Embedding {
+        vector,
+        model: "test".to_string(),
+        dimensions: DIMENSIONS,
+    }
+}
+
+async fn insert(store: &FilesystemVectorStore, range: std::ops::Range<usize>) -> Vec<String> {
+    let vectors: Vec<Embedding> = range.clone().map(embedding).collect();
+    let metadata = range
+        .map(|i| {
+            HashMap::from([
+                (
+                    "file_path".to_string(),
+                    serde_json::json!(format!("src/f{i}.rs")),
+                ),
+                (
+                    "content".to_string(),
+                    serde_json::json!(format!("chunk {i}")),
+                ),
+            ])
+        })
+        .collect();
+    store
+        .insert_vectors("code", &vectors, metadata)
+        .await
+        .unwrap()
+}
+
+async fn stored(store: &FilesystemVectorStore, ids: &[String]) -> Vec<String> {
+    store
+        .get_vectors_by_ids("code", ids)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.content)
+        .collect()
+}
+
+async fn stat(store: &FilesystemVectorStore, key: &str) -> serde_json::Value {
+    store.get_stats("code").await.unwrap()[key].clone()
+}
+
+fn shard_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir.join("code_shards"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "dat"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[tokio::test]
+async fn test_unflushed_changes_are_replayed_from_the_log() {
+    for (memory_mapping, sync) in [
+        (true, FilesystemSyncPolicy::Batch),
+        (false, FilesystemSyncPolicy::Always),
+        (true, FilesystemSyncPolicy::Never),
+    ] {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = open_store_with(dir.path(), memory_mapping, sync).await;
+        let ids = insert(&store, 0..3).await;
+        store.delete_vectors("code", &ids[1..2]).await.unwrap();
+        assert!(stat(&store, "wal_bytes").await.as_u64().unwrap() > 0);
+        drop(store);
+
+        let reopened = open_store_with(dir.path(), memory_mapping, sync).await;
+        assert_eq!(
+            stored(&reopened, &ids).await,
+            vec!["chunk 0".to_string(), "chunk 2".to_string()]
+        );
+        let results = reopened
+            .search_similar("code", &embedding(2).vector, 1, None)
+            .await
+            .unwrap();
+        assert_eq!(results[0].id, ids[2]);
+    }
+}
+
+#[tokio::test]
+async fn test_flush_checkpoints_and_empties_the_log() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open_store(dir.path()).await;
+    let ids = insert(&store, 0..2).await;
+    store.flush("code").await.unwrap();
+    assert_eq!(stat(&store, "wal_bytes").await, 0);
+    drop(store);
+
+    let reopened = open_store(dir.path()).await;
+    assert_eq!(stored(&reopened, &ids).await.len(), 2);
+}
+
+#[tokio::test]
+async fn test_torn_log_record_is_discarded() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open_store(dir.path()).await;
+    let ids = insert(&store, 0..2).await;
+    drop(store);
+
+    // A record cut short by a crash: length and checksum, no payload
+    let wal_path = dir.path().join("code_wal.log");
+    let intact = std::fs::metadata(&wal_path).unwrap().len();
+    let mut log = std::fs::read(&wal_path).unwrap();
+    log.extend_from_slice(&[200, 0, 0, 0, 1, 2, 3, 4, b'{']);
+    std::fs::write(&wal_path, log).unwrap();
+
+    let reopened = open_store(dir.path()).await;
+    assert_eq!(stored(&reopened, &ids).await.len(), 2);
+    assert!(std::fs::metadata(&wal_path).unwrap().len() <= intact);
+}
+
+#[tokio::test]
+async fn test_entries_past_a_truncated_shard_are_dropped_on_load() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open_store(dir.path()).await;
+    let ids = insert(&store, 0..4).await;
+    store.flush("code").await.unwrap();
+    drop(store);
+
+    // Cut the last record in half
+    let shard = &shard_files(dir.path())[0];
+    let len = std::fs::metadata(shard).unwrap().len();
+    let file = std::fs::OpenOptions::new().write(true).open(shard).unwrap();
+    file.set_len(len - 10).unwrap();
+    drop(file);
+
+    let reopened = open_store(dir.path()).await;
+    assert_eq!(stat(&reopened, "total_vectors").await, 3);
+    assert_eq!(stored(&reopened, &ids).await.len(), 3);
+    assert!(stored(&reopened, &ids[3..]).await.is_empty());
+
+    // The repaired shard accepts new vectors after the last complete record
+    let added = insert(&reopened, 4..5).await;
+    assert_eq!(stored(&reopened, &added).await, vec!["chunk 4".to_string()]);
+}
+
+#[tokio::test]
+async fn test_compaction_reclaims_deleted_vectors() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open_store(dir.path()).await;
+    let ids = insert(&store, 0..10).await;
+    store.delete_vectors("code", &ids[..6]).await.unwrap();
+    assert_eq!(stat(&store, "dead_vectors").await, 6);
+
+    let before = std::fs::metadata(&shard_files(dir.path())[0])
+        .unwrap()
+        .len();
+    let reclaimed = store.compact("code").await.unwrap();
+    let shards = shard_files(dir.path());
+    assert_eq!(shards.len(), 1);
+    assert_eq!(
+        std::fs::metadata(&shards[0]).unwrap().len(),
+        before - reclaimed
+    );
+    assert!(reclaimed > 0);
+    assert_eq!(stat(&store, "dead_vectors").await, 0);
+
+    let expected: Vec<String> = (6..10).map(|i| format!("chunk {i}")).collect();
+    assert_eq!(stored(&store, &ids).await.len(), 4);
+    let results = store
+        .search_similar("code", &embedding(7).vector, 1, None)
+        .await
+        .unwrap();
+    assert_eq!(results[0].id, ids[7]);
+    drop(store);
+
+    let reopened = open_store(dir.path()).await;
+    let mut contents = stored(&reopened, &ids).await;
+    contents.sort();
+    assert_eq!(contents, expected);
+    assert_eq!(reopened.compact("code").await.unwrap(), 0);
+}
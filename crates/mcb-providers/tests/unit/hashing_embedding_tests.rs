@@ -0,0 +1,84 @@
+//! Hashing Embedding Provider Tests
+//!
+//! Covers determinism, configured dimensions, normalization and the
+//! lexical similarity ordering of `HashingEmbeddingProvider`.
+
+use mcb_application::ports::registry::{EmbeddingProviderConfig, resolve_embedding_provider};
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_providers::embedding::HashingEmbeddingProvider;
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[tokio::test]
+async fn test_embeddings_are_deterministic_and_normalized() {
+    let provider = HashingEmbeddingProvider::default();
+    let texts = vec![
+        "fn parse_config(path: &Path) -> Result<Config>".to_string(),
+        String::new(),
+    ];
+
+    let first = provider.embed_batch(&texts).await.expect("embed");
+    let second = HashingEmbeddingProvider::default()
+        .embed_batch(&texts)
+        .await
+        .expect("embed");
+    assert_eq!(first[0].vector, second[0].vector);
+    assert_eq!(first[0].model, provider.model());
+
+    for embedding in &first {
+        assert_eq!(embedding.vector.len(), 384);
+        let norm: f32 = embedding.vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-4, "norm was {norm}");
+    }
+}
+
+#[tokio::test]
+async fn test_registry_uses_configured_dimensions() {
+    let provider =
+        resolve_embedding_provider(&EmbeddingProviderConfig::new("hashing").with_dimensions(128))
+            .expect("resolve hashing provider");
+    assert_eq!(provider.provider_name(), "hashing");
+    assert_eq!(provider.dimensions(), 128);
+
+    let embedding = provider.embed("cache eviction").await.expect("embed");
+    assert_eq!(embedding.vector.len(), 128);
+    assert_eq!(embedding.dimensions, 128);
+
+    assert!(
+        resolve_embedding_provider(&EmbeddingProviderConfig::new("hashing").with_dimensions(0))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_identifier_styles_share_features() {
+    let provider = HashingEmbeddingProvider::default();
+    let snake = provider.embed_text("http_client_timeout");
+    let camel = provider.embed_text("HttpClientTimeout");
+    let unrelated = provider.embed_text("render_markdown_table");
+
+    assert!(cosine(&snake, &camel) > 0.9);
+    assert!(cosine(&snake, &unrelated) < 0.3);
+}
+
+#[test]
+fn test_query_ranks_related_code_first() {
+    let provider = HashingEmbeddingProvider::default();
+    let documents = [
+        "fn evict_expired(cache: &mut Cache) { cache.entries.retain(|e| !e.is_expired()) }",
+        "fn parse_toml_config(path: &Path) -> Result<AppConfig> { toml::from_str(&read(path)?) }",
+        "async fn send_request(client: &HttpClient, url: &str) -> Response { client.get(url).await }",
+    ];
+    let query = provider.embed_text("load configuration from a toml file");
+
+    let scores: Vec<f32> = documents
+        .iter()
+        .map(|doc| cosine(&query, &provider.embed_text(doc)))
+        .collect();
+    assert!(
+        scores[1] > scores[0] && scores[1] > scores[2],
+        "scores were {scores:?}"
+    );
+}
@@ -223,6 +223,28 @@ async fn test_clear_collection() {
     assert_eq!(stats.get("collection_count"), Some(&serde_json::json!(0)));
 }
 
+#[tokio::test]
+async fn test_indexed_chunks_restore_an_index() {
+    let engine = HybridSearchEngine::new();
+    let chunks = vec![
+        create_test_chunk("fn login() {}", "auth.rs", 1),
+        create_test_chunk("fn render() {}", "view.rs", 1),
+    ];
+    engine.index_chunks("source", &chunks).await.unwrap();
+
+    let indexed = engine.indexed_chunks("source").await.unwrap();
+    assert_eq!(indexed, chunks);
+    assert!(engine.indexed_chunks("missing").await.unwrap().is_empty());
+
+    let restored = HybridSearchEngine::new();
+    restored.index_chunks("copy", &indexed).await.unwrap();
+    let stats = restored.get_stats().await;
+    assert_eq!(
+        stats["collections"]["copy"]["total_documents"],
+        serde_json::json!(2)
+    );
+}
+
 #[tokio::test]
 async fn test_search_without_index() {
     let engine = HybridSearchEngine::new();
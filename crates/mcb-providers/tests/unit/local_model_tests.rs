@@ -0,0 +1,76 @@
+//! Local FastEmbed Model Tests
+//!
+//! Covers checksum manifest verification of `LocalModelOptions`. Loading
+//! the model itself needs real ONNX weights and is not exercised here.
+
+use mcb_providers::embedding::{LocalModelOptions, LocalModelPooling};
+use std::path::Path;
+
+/// SHA-256 of an empty file
+const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+const MODEL_FILES: [&str; 5] = [
+    "model.onnx",
+    "tokenizer.json",
+    "config.json",
+    "special_tokens_map.json",
+    "tokenizer_config.json",
+];
+
+/// Model directory of empty files with a manifest listing `listed`
+fn model_dir(listed: &[&str]) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().expect("tempdir");
+    for file in MODEL_FILES {
+        std::fs::write(dir.path().join(file), b"").expect("write model file");
+    }
+    let manifest: String = listed
+        .iter()
+        .map(|file| format!("{EMPTY_SHA256}  {file}\n"))
+        .collect();
+    std::fs::write(dir.path().join("SHA256SUMS"), manifest).expect("write manifest");
+    dir
+}
+
+fn options(dir: &Path) -> LocalModelOptions {
+    LocalModelOptions::new(dir, 384).with_checksums("SHA256SUMS")
+}
+
+#[test]
+fn test_matching_manifest_verifies() {
+    let dir = model_dir(&MODEL_FILES);
+    options(dir.path()).verify_checksums().expect("verified");
+}
+
+#[test]
+fn test_modified_file_is_rejected() {
+    let dir = model_dir(&MODEL_FILES);
+    std::fs::write(dir.path().join("tokenizer.json"), b"{}").expect("write");
+
+    let error = options(dir.path())
+        .verify_checksums()
+        .expect_err("checksum mismatch");
+    assert!(error.to_string().contains("tokenizer.json"));
+}
+
+#[test]
+fn test_unlisted_file_is_rejected() {
+    let dir = model_dir(&MODEL_FILES[1..]);
+
+    let error = options(dir.path())
+        .verify_checksums()
+        .expect_err("model.onnx not listed");
+    assert!(error.to_string().contains("model.onnx"));
+}
+
+#[test]
+fn test_options_defaults() {
+    let dir = model_dir(&[]);
+    let options = LocalModelOptions::new(dir.path().join("minilm"), 384);
+    assert_eq!(options.model_name, "minilm");
+    assert_eq!(options.pooling, LocalModelPooling::Mean);
+    assert_eq!(options.max_length, 512);
+    options.verify_checksums().expect("no manifest configured");
+
+    assert_eq!("CLS".parse(), Ok(LocalModelPooling::Cls));
+    assert!("max".parse::<LocalModelPooling>().is_err());
+}
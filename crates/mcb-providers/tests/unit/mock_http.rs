@@ -0,0 +1,103 @@
+//! Mock HTTP Server
+//!
+//! A local HTTP/1.1 server for provider tests. Every request is recorded
+//! and answered with the status and JSON body chosen by a handler.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A request received by the mock server
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// Method and path, e.g. `PUT /collections/code`
+    pub target: String,
+    /// Headers keyed by lowercase name
+    pub headers: HashMap<String, String>,
+    pub body: Value,
+}
+
+type Handler = dyn Fn(&RecordedRequest) -> (u16, Value) + Send + Sync;
+
+/// HTTP server answering each request with the response chosen by a handler
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    pub async fn start(
+        handler: impl Fn(&RecordedRequest) -> (u16, Value) + Send + Sync + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let url = format!("http://{}", listener.local_addr().expect("addr"));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let request = read_request(&mut socket).await;
+                let (status, response) = handler(&request);
+                recorded.lock().expect("lock").push(request);
+                let response = response.to_string();
+                let reply = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                );
+                let _ = socket.write_all(reply.as_bytes()).await;
+            }
+        });
+        Self { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().expect("lock").clone()
+    }
+}
+
+async fn read_request(socket: &mut TcpStream) -> RecordedRequest {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.expect("read");
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        assert!(read > 0, "connection closed before headers");
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.lines();
+    let request_line = lines.next().unwrap_or_default();
+    let target = request_line
+        .rsplit_once(' ')
+        .map_or(request_line, |(target, _)| target)
+        .to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let length: usize = headers
+        .get("content-length")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    while buffer.len() < header_end + length {
+        let read = socket.read(&mut chunk).await.expect("read body");
+        assert!(read > 0, "connection closed before body");
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+    let body =
+        serde_json::from_slice(&buffer[header_end..header_end + length]).unwrap_or(Value::Null);
+
+    RecordedRequest {
+        target,
+        headers,
+        body,
+    }
+}
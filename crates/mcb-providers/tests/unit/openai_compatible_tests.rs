@@ -0,0 +1,154 @@
+//! Azure OpenAI and OpenAI-Compatible Provider Tests
+//!
+//! Runs both providers against a local mock HTTP server and checks the
+//! request URL, headers and body, and how vectors are read from responses.
+
+use mcb_application::ports::registry::{EmbeddingProviderConfig, resolve_embedding_provider};
+use mcb_domain::ports::providers::EmbeddingProvider;
+use serde_json::{Value, json};
+
+use crate::mock_http::MockServer;
+
+/// Mock server answering every request with one JSON response
+async fn respond_with(status: u16, response: Value) -> MockServer {
+    MockServer::start(move |_| (status, response.clone())).await
+}
+
+fn openai_response(vectors: &[Vec<f32>]) -> Value {
+    json!({
+        "object": "list",
+        "data": vectors
+            .iter()
+            .enumerate()
+            .map(|(index, embedding)| json!({"object": "embedding", "index": index, "embedding": embedding}))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn texts() -> Vec<String> {
+    vec!["fn a() {}".to_string(), "fn b() {}".to_string()]
+}
+
+#[tokio::test]
+async fn test_azure_uses_deployment_url_and_api_key_header() {
+    let server = respond_with(200, openai_response(&[vec![0.1, 0.2], vec![0.3, 0.4]])).await;
+    let config = EmbeddingProviderConfig::new("azure-openai")
+        .with_base_url(&server.url)
+        .with_api_key("azure-secret")
+        .with_model("text-embedding-3-small")
+        .with_dimensions(2)
+        .with_extra("deployment", "code-embeddings")
+        .with_extra("api_version", "2024-06-01");
+    let provider = resolve_embedding_provider(&config).expect("resolve azure-openai");
+
+    let embeddings = provider.embed_batch(&texts()).await.expect("embed");
+    assert_eq!(embeddings.len(), 2);
+    assert_eq!(embeddings[1].vector, vec![0.3, 0.4]);
+    assert_eq!(embeddings[0].model, "text-embedding-3-small");
+    assert_eq!(provider.provider_name(), "azure-openai");
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "POST /openai/deployments/code-embeddings/embeddings?api-version=2024-06-01"
+    );
+    assert_eq!(requests[0].headers["api-key"], "azure-secret");
+    assert!(!requests[0].headers.contains_key("authorization"));
+    assert_eq!(requests[0].body["input"], json!(texts()));
+    assert_eq!(requests[0].body["dimensions"], 2);
+}
+
+#[tokio::test]
+async fn test_azure_reports_server_errors() {
+    let server = respond_with(429, json!({"error": {"message": "slow down"}})).await;
+    let config = EmbeddingProviderConfig::new("azure-openai")
+        .with_base_url(&server.url)
+        .with_api_key("azure-secret");
+    let provider = resolve_embedding_provider(&config).expect("resolve azure-openai");
+
+    let error = provider
+        .embed_batch(&texts())
+        .await
+        .expect_err("rate limited");
+    assert!(error.to_string().contains("rate limit"), "{error}");
+}
+
+#[tokio::test]
+async fn test_compatible_sends_custom_path_and_headers() {
+    let server = respond_with(
+        200,
+        openai_response(&[vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]),
+    )
+    .await;
+    let config = EmbeddingProviderConfig::new("openai-compatible")
+        .with_base_url(format!("{}/gateway/", server.url))
+        .with_model("nomic-embed-text")
+        .with_api_key("gateway-token")
+        .with_dimensions(3)
+        .with_extra("path", "/v1/models/{model}/embeddings")
+        .with_extra("header.X-Team", "search");
+    let provider = resolve_embedding_provider(&config).expect("resolve openai-compatible");
+
+    let embeddings = provider.embed_batch(&texts()).await.expect("embed");
+    assert_eq!(embeddings[0].vector, vec![1.0, 0.0, 0.0]);
+    assert_eq!(embeddings[0].model, "nomic-embed-text");
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.target,
+        "POST /gateway/v1/models/nomic-embed-text/embeddings"
+    );
+    assert_eq!(request.headers["authorization"], "Bearer gateway-token");
+    assert_eq!(request.headers["x-team"], "search");
+    assert_eq!(request.body["model"], "nomic-embed-text");
+}
+
+#[tokio::test]
+async fn test_compatible_reads_custom_response_path() {
+    let server = respond_with(
+        200,
+        json!({"result": {"vectors": [[0.5, 0.5], [0.25, 0.75]]}}),
+    )
+    .await;
+    let config = EmbeddingProviderConfig::new("openai-compatible")
+        .with_base_url(&server.url)
+        .with_api_key("raw-key")
+        .with_dimensions(2)
+        .with_extra("response_path", "result.vectors")
+        .with_extra("api_key_header", "X-Api-Key");
+    let provider = resolve_embedding_provider(&config).expect("resolve openai-compatible");
+
+    let embeddings = provider.embed_batch(&texts()).await.expect("embed");
+    assert_eq!(embeddings[1].vector, vec![0.25, 0.75]);
+    let request = &server.requests()[0];
+    assert_eq!(request.target, "POST /embeddings");
+    assert_eq!(request.headers["x-api-key"], "raw-key");
+}
+
+#[tokio::test]
+async fn test_compatible_rejects_unexpected_dimensions() {
+    let server = respond_with(200, openai_response(&[vec![0.1; 4], vec![0.1; 4]])).await;
+    let config = EmbeddingProviderConfig::new("openai-compatible")
+        .with_base_url(&server.url)
+        .with_dimensions(8);
+    let provider = resolve_embedding_provider(&config).expect("resolve openai-compatible");
+
+    let error = provider.embed_batch(&texts()).await.expect_err("4 != 8");
+    assert!(error.to_string().contains("dimensions"), "{error}");
+}
+
+#[test]
+fn test_compatible_requires_base_url_and_dimensions() {
+    assert!(
+        resolve_embedding_provider(
+            &EmbeddingProviderConfig::new("openai-compatible").with_dimensions(8)
+        )
+        .is_err()
+    );
+    assert!(
+        resolve_embedding_provider(
+            &EmbeddingProviderConfig::new("openai-compatible").with_base_url("http://localhost:1")
+        )
+        .is_err()
+    );
+}
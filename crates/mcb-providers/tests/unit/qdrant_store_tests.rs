@@ -0,0 +1,343 @@
+//! Qdrant Store Tests
+//!
+//! Runs the provider against a local mock HTTP server and checks the
+//! requests sent for collections, points, filters and scroll pagination.
+//! `test_round_trip_against_real_qdrant` runs only when `QDRANT_URL` points
+//! at a Qdrant server, e.g. `QDRANT_URL=http://localhost:6333`.
+
+use std::collections::HashMap;
+
+use mcb_domain::ports::providers::{VectorStoreBrowser, VectorStoreProvider};
+use mcb_domain::value_objects::{Embedding, MetadataFilter};
+use mcb_providers::vector_store::{QdrantDistance, QdrantVectorStoreProvider};
+use serde_json::{Value, json};
+
+use crate::mock_http::{MockServer, RecordedRequest};
+
+/// Mock Qdrant server wrapping each handler result in a Qdrant response
+async fn start(
+    handler: impl Fn(&RecordedRequest) -> (u16, Value) + Send + Sync + 'static,
+) -> MockServer {
+    MockServer::start(move |request| {
+        let (status, result) = handler(request);
+        (
+            status,
+            json!({"result": result, "status": "ok", "time": 0.001}),
+        )
+    })
+    .await
+}
+
+fn qdrant_provider(server: &MockServer) -> QdrantVectorStoreProvider {
+    QdrantVectorStoreProvider::new(&server.url, reqwest::Client::new())
+}
+
+fn embedding(values: &[f32]) -> Embedding {
+    Embedding {
+        vector: values.to_vec(),
+        model: "test".to_string(),
+        dimensions: values.len(),
+    }
+}
+
+fn chunk(file_path: &str, start_line: u32) -> HashMap<String, Value> {
+    HashMap::from([
+        ("file_path".to_string(), json!(file_path)),
+        ("start_line".to_string(), json!(start_line)),
+        ("content".to_string(), json!(format!("line {start_line}"))),
+        ("language".to_string(), json!("rust")),
+    ])
+}
+
+fn point(chunk_id: &str, file_path: &str, start_line: u32) -> Value {
+    json!({
+        "id": "00000000-0000-0000-0000-000000000000",
+        "payload": {
+            "chunk_id": chunk_id,
+            "file_path": file_path,
+            "start_line": start_line,
+            "content": format!("line {start_line}"),
+            "language": "rust",
+        },
+    })
+}
+
+#[tokio::test]
+async fn test_create_collection_sets_distance_and_payload_indexes() {
+    let server = start(|_| (200, json!(true))).await;
+    let provider = qdrant_provider(&server)
+        .with_api_key("secret")
+        .with_distance(QdrantDistance::Dot);
+
+    provider.create_collection("code", 4).await.expect("create");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "PUT /collections/code");
+    assert_eq!(
+        requests[0].body["vectors"],
+        json!({"size": 4, "distance": "Dot"})
+    );
+    assert_eq!(requests[0].headers["api-key"], "secret");
+    let indexed: Vec<&Value> = requests[1..]
+        .iter()
+        .inspect(|r| assert_eq!(r.target, "PUT /collections/code/index?wait=true"))
+        .map(|r| &r.body["field_name"])
+        .collect();
+    assert_eq!(indexed, vec!["file_path", "language"]);
+}
+
+#[tokio::test]
+async fn test_upsert_maps_chunk_ids_to_stable_point_ids() {
+    let server = start(|_| (200, json!({"status": "completed"}))).await;
+    let provider = qdrant_provider(&server);
+    let ids = vec!["chunk-a".to_string(), "chunk-b".to_string()];
+    let vectors = [embedding(&[1.0, 0.0]), embedding(&[0.0, 1.0])];
+
+    for _ in 0..2 {
+        provider
+            .upsert_vectors(
+                "code",
+                &ids,
+                &vectors,
+                vec![chunk("src/a.rs", 1), chunk("src/a.rs", 9)],
+            )
+            .await
+            .expect("upsert");
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "PUT /collections/code/points?wait=true");
+    let points = requests[0].body["points"].as_array().expect("points");
+    assert_eq!(points[0]["payload"]["chunk_id"], "chunk-a");
+    assert_eq!(points[0]["payload"]["file_path"], "src/a.rs");
+    assert_eq!(points[1]["vector"], json!([0.0, 1.0]));
+    let point_id = points[0]["id"].as_str().expect("uuid id");
+    assert!(uuid::Uuid::parse_str(point_id).is_ok(), "{point_id}");
+    assert_ne!(points[0]["id"], points[1]["id"]);
+    // The same chunk id maps to the same point, so the second upsert replaces it
+    assert_eq!(requests[1].body["points"][0]["id"], points[0]["id"]);
+}
+
+#[tokio::test]
+async fn test_search_reads_chunk_ids_and_scores_from_points() {
+    let server = start(|request| match request.target.as_str() {
+        "POST /collections/code/points/search" => {
+            let mut hit = point("chunk-b", "src/b.rs", 7);
+            hit["score"] = json!(0.75);
+            (200, json!([hit]))
+        }
+        _ => (404, Value::Null),
+    })
+    .await;
+    let provider = qdrant_provider(&server);
+
+    let results = provider
+        .search_similar("code", &[0.5, 0.5], 3, None)
+        .await
+        .expect("search");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, "chunk-b");
+    assert_eq!(results[0].file_path, "src/b.rs");
+    assert_eq!(results[0].start_line, 7);
+    assert_eq!(results[0].score, 0.75);
+    assert_eq!(server.requests()[0].body["limit"], 3);
+
+    let missing = provider
+        .search_similar("other", &[0.5, 0.5], 3, None)
+        .await
+        .expect("missing collection");
+    assert!(missing.is_empty());
+}
+
+#[tokio::test]
+async fn test_search_sends_metadata_filter() {
+    let server = start(|_| (200, json!([]))).await;
+    let provider = qdrant_provider(&server);
+
+    provider
+        .search_similar("code", &[0.5, 0.5], 3, Some(r#"{"file_path": "src/a.rs"}"#))
+        .await
+        .expect("filtered search");
+    assert_eq!(
+        server.requests()[0].body["filter"],
+        json!({"must": [{"key": "file_path", "match": {"value": "src/a.rs"}}]})
+    );
+
+    provider
+        .search_similar("code", &[0.5, 0.5], 3, Some("{}"))
+        .await
+        .expect("empty filter");
+    assert!(server.requests()[1].body.get("filter").is_none());
+
+    assert!(
+        provider
+            .search_similar("code", &[0.5, 0.5], 3, Some("src/a.rs"))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_search_reports_distances_as_similarities() {
+    let server = start(|_| {
+        let mut exact = point("chunk-a", "src/a.rs", 1);
+        exact["score"] = json!(0.0);
+        let mut near = point("chunk-b", "src/b.rs", 1);
+        near["score"] = json!(1.0);
+        (200, json!([exact, near]))
+    })
+    .await;
+    let provider = qdrant_provider(&server).with_distance(QdrantDistance::Euclid);
+
+    let results = provider
+        .search_similar("code", &[0.5, 0.5], 2, None)
+        .await
+        .expect("search");
+    let scores: Vec<f64> = results.iter().map(|r| r.score).collect();
+    assert_eq!(scores, vec![1.0, 0.5]);
+}
+
+#[tokio::test]
+async fn test_delete_by_filter_counts_then_deletes_matching_points() {
+    let server = start(|request| match request.target.as_str() {
+        "POST /collections/code/points/count" => (200, json!({"count": 2})),
+        _ => (200, json!({"status": "completed"})),
+    })
+    .await;
+    let provider = qdrant_provider(&server);
+
+    let filter = MetadataFilter::file_path("src/a.rs").with("start_line", 3);
+    let deleted = provider
+        .delete_by_filter("code", &filter)
+        .await
+        .expect("delete by filter");
+    assert_eq!(deleted, 2);
+
+    let expected = json!({"must": [
+        {"key": "file_path", "match": {"value": "src/a.rs"}},
+        {"key": "start_line", "match": {"value": 3}},
+    ]});
+    let requests = server.requests();
+    assert_eq!(requests[0].body["filter"], expected);
+    assert_eq!(requests[0].body["exact"], true);
+    assert_eq!(
+        requests[1].target,
+        "POST /collections/code/points/delete?wait=true"
+    );
+    assert_eq!(requests[1].body["filter"], expected);
+
+    let id_filter = MetadataFilter::default().with("id", "chunk-a");
+    provider
+        .delete_by_filter("code", &id_filter)
+        .await
+        .expect("delete by id");
+    assert_eq!(
+        server.requests()[2].body["filter"]["must"][0]["key"],
+        "chunk_id"
+    );
+    assert!(
+        provider
+            .delete_by_filter("code", &MetadataFilter::default())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_list_file_paths_follows_scroll_pages() {
+    let server = start(|request| {
+        if request.body["offset"].is_null() {
+            (
+                200,
+                json!({
+                    "points": [point("a1", "src/a.rs", 1), point("b1", "src/b.rs", 1)],
+                    "next_page_offset": "page-2",
+                }),
+            )
+        } else {
+            (
+                200,
+                json!({
+                    "points": [point("a2", "src/a.rs", 9)],
+                    "next_page_offset": null,
+                }),
+            )
+        }
+    })
+    .await;
+    let provider = qdrant_provider(&server);
+
+    let files = provider.list_file_paths("code", 10).await.expect("files");
+    let counts: Vec<(&str, u32)> = files
+        .iter()
+        .map(|f| (f.path.as_str(), f.chunk_count))
+        .collect();
+    assert_eq!(counts, vec![("src/a.rs", 2), ("src/b.rs", 1)]);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].target, "POST /collections/code/points/scroll");
+    assert_eq!(requests[0].body["with_vector"], false);
+    assert_eq!(requests[1].body["offset"], "page-2");
+}
+
+#[test]
+fn test_distance_names_parse() {
+    assert_eq!(
+        "cosine".parse::<QdrantDistance>(),
+        Ok(QdrantDistance::Cosine)
+    );
+    assert_eq!(
+        "Euclidean".parse::<QdrantDistance>(),
+        Ok(QdrantDistance::Euclid)
+    );
+    assert_eq!(QdrantDistance::Manhattan.as_str(), "Manhattan");
+    assert!("hamming".parse::<QdrantDistance>().is_err());
+}
+
+#[tokio::test]
+async fn test_round_trip_against_real_qdrant() {
+    let Ok(url) = std::env::var("QDRANT_URL") else {
+        println!("⊘ SKIPPED: QDRANT_URL not set (skipping test)");
+        return;
+    };
+    let provider = QdrantVectorStoreProvider::new(url, reqwest::Client::new());
+    let collection = format!("mcb_test_{}", uuid::Uuid::new_v4().simple());
+    provider
+        .create_collection(&collection, 2)
+        .await
+        .expect("create");
+
+    let ids = vec!["a".to_string(), "b".to_string()];
+    provider
+        .upsert_vectors(
+            &collection,
+            &ids,
+            &[embedding(&[1.0, 0.0]), embedding(&[0.0, 1.0])],
+            vec![chunk("src/a.rs", 1), chunk("src/b.rs", 1)],
+        )
+        .await
+        .expect("upsert");
+    let results = provider
+        .search_similar(&collection, &[0.0, 1.0], 1, None)
+        .await
+        .expect("search");
+    assert_eq!(results[0].id, "b");
+
+    let deleted = provider
+        .delete_by_file(&collection, "src/a.rs")
+        .await
+        .expect("delete by file");
+    assert_eq!(deleted, 1);
+    let files = provider
+        .list_file_paths(&collection, 10)
+        .await
+        .expect("files");
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, "src/b.rs");
+
+    provider
+        .delete_collection(&collection)
+        .await
+        .expect("delete collection");
+}
@@ -0,0 +1,233 @@
+//! SQLite Store Tests
+//!
+//! Covers persistence across reopening the database file, the browser
+//! queries, filters on keys without a column, filters agreeing with
+//! `MetadataFilter::matches`, search filters and dimension checks.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
+use mcb_domain::value_objects::{Embedding, MetadataFilter};
+use mcb_providers::vector_store::SqliteVectorStoreProvider;
+
+const COLLECTION: &str = "code";
+const DIMENSIONS: usize = 4;
+
+fn open(path: &Path) -> SqliteVectorStoreProvider {
+    SqliteVectorStoreProvider::open(path).expect("sqlite store")
+}
+
+fn embedding(i: usize) -> Embedding {
+    let mut vector = vec![0.1; DIMENSIONS];
+    vector[i % DIMENSIONS] = 1.0 + i as f32;
+    Embedding {
+        vector,
+        model: "test".to_string(),
+        dimensions: DIMENSIONS,
+    }
+}
+
+fn chunk(file_path: &str, start_line: u32, kind: &str) -> HashMap<String, serde_json::Value> {
+    HashMap::from([
+        ("file_path".to_string(), serde_json::json!(file_path)),
+        ("start_line".to_string(), serde_json::json!(start_line)),
+        ("end_line".to_string(), serde_json::json!(start_line + 5)),
+        (
+            "content".to_string(),
+            serde_json::json!(format!("{kind} at {start_line}")),
+        ),
+        ("language".to_string(), serde_json::json!("rust")),
+        ("kind".to_string(), serde_json::json!(kind)),
+    ])
+}
+
+async fn seed(store: &SqliteVectorStoreProvider) {
+    store
+        .create_collection(COLLECTION, DIMENSIONS)
+        .await
+        .expect("create collection");
+    let ids: Vec<String> = ["a20", "a1", "b1"].iter().map(|s| s.to_string()).collect();
+    store
+        .upsert_vectors(
+            COLLECTION,
+            &ids,
+            &[embedding(0), embedding(1), embedding(2)],
+            vec![
+                chunk("src/a.rs", 20, "struct"),
+                chunk("src/a.rs", 1, "fn"),
+                chunk("src/b.rs", 1, "fn"),
+            ],
+        )
+        .await
+        .expect("upsert");
+}
+
+#[tokio::test]
+async fn test_vectors_survive_reopening_the_database() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("nested").join("vectors.db");
+    seed(&open(&path)).await;
+
+    let store = open(&path);
+    assert!(store.collection_exists(COLLECTION).await.expect("exists"));
+    let results = store
+        .search_similar(COLLECTION, &embedding(2).vector, 1, None)
+        .await
+        .expect("search");
+    assert_eq!(results[0].id, "b1");
+    assert_eq!(results[0].file_path, "src/b.rs");
+    assert_eq!(results[0].content, "fn at 1");
+}
+
+#[tokio::test]
+async fn test_browser_queries_group_chunks_by_file() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open(&dir.path().join("vectors.db"));
+    seed(&store).await;
+
+    let collections = store.list_collections().await.expect("collections");
+    assert_eq!(collections.len(), 1);
+    assert_eq!(collections[0].vector_count, 3);
+    assert_eq!(collections[0].file_count, 2);
+
+    let files = store.list_file_paths(COLLECTION, 10).await.expect("files");
+    let counts: Vec<(&str, u32)> = files
+        .iter()
+        .map(|f| (f.path.as_str(), f.chunk_count))
+        .collect();
+    assert_eq!(counts, vec![("src/a.rs", 2), ("src/b.rs", 1)]);
+
+    let chunks = store
+        .get_chunks_by_file(COLLECTION, "src/a.rs")
+        .await
+        .expect("chunks");
+    let lines: Vec<u32> = chunks.iter().map(|c| c.start_line).collect();
+    assert_eq!(lines, vec![1, 20]);
+}
+
+#[tokio::test]
+async fn test_filter_on_key_without_column_reads_metadata_json() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open(&dir.path().join("vectors.db"));
+    seed(&store).await;
+
+    let deleted = store
+        .delete_by_filter(COLLECTION, &MetadataFilter::default().with("kind", "fn"))
+        .await
+        .expect("delete by filter");
+    assert_eq!(deleted, 2);
+
+    let remaining = store.list_vectors(COLLECTION, 10).await.expect("list");
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, "a20");
+}
+
+#[tokio::test]
+async fn test_filters_match_like_metadata_filter() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open(&dir.path().join("vectors.db"));
+    seed(&store).await;
+    // Stored with `line_number` only; its `start_line` column falls back to it
+    let legacy = HashMap::from([
+        ("file_path".to_string(), serde_json::json!("src/c.rs")),
+        ("line_number".to_string(), serde_json::json!(1)),
+        ("kind".to_string(), serde_json::json!(null)),
+    ]);
+    store
+        .upsert_vectors(
+            COLLECTION,
+            &["c1".to_string()],
+            &[embedding(3)],
+            vec![legacy.clone()],
+        )
+        .await
+        .expect("upsert");
+
+    let filters = [
+        MetadataFilter::default().with("start_line", 1),
+        MetadataFilter::default().with("start_line", 1.0),
+        MetadataFilter::default().with("start_line", "1"),
+        MetadataFilter::default().with("line_number", 1),
+        MetadataFilter::default().with("kind", serde_json::Value::Null),
+        MetadataFilter::default().with("end_line", 6),
+    ];
+    let rows = [
+        ("a20", chunk("src/a.rs", 20, "struct")),
+        ("a1", chunk("src/a.rs", 1, "fn")),
+        ("b1", chunk("src/b.rs", 1, "fn")),
+        ("c1", legacy),
+    ];
+    for filter in filters {
+        let expected: Vec<&str> = rows
+            .iter()
+            .filter(|(_, metadata)| filter.matches(metadata))
+            .map(|(id, _)| *id)
+            .collect();
+        let results = store
+            .search_similar(
+                COLLECTION,
+                &embedding(0).vector,
+                10,
+                Some(&serde_json::to_string(filter.conditions()).expect("filter json")),
+            )
+            .await
+            .expect("filtered search");
+        let mut ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        ids.sort();
+        let mut expected = expected;
+        expected.sort();
+        assert_eq!(ids, expected, "{:?}", filter);
+    }
+}
+
+#[tokio::test]
+async fn test_search_filter_restricts_scored_rows() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open(&dir.path().join("vectors.db"));
+    seed(&store).await;
+
+    let results = store
+        .search_similar(
+            COLLECTION,
+            &embedding(2).vector,
+            10,
+            Some(r#"{"file_path": "src/a.rs", "kind": "fn"}"#),
+        )
+        .await
+        .expect("filtered search");
+    let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, vec!["a1"]);
+
+    assert!(
+        store
+            .search_similar(COLLECTION, &embedding(2).vector, 10, Some("file_path"))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_vectors_with_other_dimensions_are_rejected() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = open(&dir.path().join("vectors.db"));
+    seed(&store).await;
+
+    let wrong = Embedding {
+        vector: vec![1.0; DIMENSIONS + 1],
+        model: "test".to_string(),
+        dimensions: DIMENSIONS + 1,
+    };
+    let result = store
+        .insert_vectors(COLLECTION, &[wrong], vec![chunk("src/c.rs", 1, "fn")])
+        .await;
+    assert!(result.is_err());
+    assert_eq!(
+        store
+            .list_vectors(COLLECTION, 10)
+            .await
+            .expect("list")
+            .len(),
+        3
+    );
+}
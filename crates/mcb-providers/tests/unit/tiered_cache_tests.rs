@@ -0,0 +1,228 @@
+//! Tiered Cache Tests
+//!
+//! Two tiered caches share a Moka cache standing in for Redis and an
+//! in-process event bus standing in for a distributed one, as two server
+//! instances would.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use mcb_domain::ports::providers::{CacheEntryConfig, CacheProvider};
+use mcb_providers::cache::{MokaCacheProvider, TieredCacheProvider};
+use mcb_providers::events::TokioEventBusProvider;
+
+fn l1() -> MokaCacheProvider {
+    MokaCacheProvider::with_config(1024 * 1024, Duration::from_secs(60))
+}
+
+fn shared_l2() -> Arc<dyn CacheProvider> {
+    Arc::new(MokaCacheProvider::new())
+}
+
+/// Two instances over the same L2 and event bus, both listening
+async fn instances() -> (Arc<TieredCacheProvider>, Arc<TieredCacheProvider>) {
+    let l2 = shared_l2();
+    let bus = Arc::new(TokioEventBusProvider::new());
+    let instance = || {
+        let cache =
+            Arc::new(TieredCacheProvider::new(l1(), Arc::clone(&l2)).with_event_bus(bus.clone()));
+        cache.listen_for_invalidations().expect("listener");
+        cache
+    };
+    let (a, b) = (instance(), instance());
+    for _ in 0..100 {
+        if a.is_listening() && b.is_listening() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(bus.subscriber_count(), 2);
+    (a, b)
+}
+
+#[tokio::test]
+async fn test_reads_through_l2_into_l1() {
+    let l2 = shared_l2();
+    l2.set_json("mcb:key", "\"value\"", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    let cache = TieredCacheProvider::new(l1(), Arc::clone(&l2));
+
+    assert_eq!(
+        cache.get_json("mcb:key").await.unwrap().as_deref(),
+        Some("\"value\"")
+    );
+    // Served from L1 even once L2 has dropped it
+    l2.delete("mcb:key").await.unwrap();
+    assert_eq!(
+        cache.get_json("mcb:key").await.unwrap().as_deref(),
+        Some("\"value\"")
+    );
+    assert_eq!(cache.get_json("mcb:missing").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_writes_through_to_l2() {
+    let l2 = shared_l2();
+    let cache = TieredCacheProvider::new(l1(), Arc::clone(&l2));
+
+    cache
+        .set_json("mcb:key", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(l2.get_json("mcb:key").await.unwrap().as_deref(), Some("1"));
+
+    assert!(cache.delete("mcb:key").await.unwrap());
+    assert!(!l2.exists("mcb:key").await.unwrap());
+    assert!(!cache.exists("mcb:key").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_stats_report_each_tier() {
+    let cache = TieredCacheProvider::new(l1(), shared_l2());
+    cache
+        .set_json("mcb:key", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+
+    cache.get_json("mcb:key").await.unwrap();
+    cache.get_json("mcb:key").await.unwrap();
+    cache.get_json("mcb:missing").await.unwrap();
+
+    let stats = cache.stats().await.unwrap();
+    assert_eq!((stats.hits, stats.misses), (2, 1));
+    assert_eq!(stats.tiers.len(), 2);
+    let (l1, l2) = (&stats.tiers[0], &stats.tiers[1]);
+    assert_eq!((l1.tier.as_str(), l1.provider.as_str()), ("l1", "moka"));
+    assert_eq!((l1.hits, l1.misses), (2, 1));
+    assert_eq!((l2.tier.as_str(), l2.hits, l2.misses), ("l2", 0, 1));
+    assert_eq!(l2.entries, 1);
+    assert!((l1.hit_rate - 2.0 / 3.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_write_invalidates_l1_of_other_instances() {
+    let (a, b) = instances().await;
+    a.set_json("mcb:key", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(b.get_json("mcb:key").await.unwrap().as_deref(), Some("1"));
+
+    a.set_json("mcb:key", "2", CacheEntryConfig::default())
+        .await
+        .unwrap();
+
+    let mut value = b.get_json("mcb:key").await.unwrap();
+    for _ in 0..100 {
+        if value.as_deref() == Some("2") {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        value = b.get_json("mcb:key").await.unwrap();
+    }
+    assert_eq!(value.as_deref(), Some("2"));
+    assert_eq!(a.get_json("mcb:key").await.unwrap().as_deref(), Some("2"));
+}
+
+#[tokio::test]
+async fn test_invalidation_is_scoped_to_namespace() {
+    let (a, b) = instances().await;
+    // Written by `b` itself, so its L1 holds the value without a round trip
+    b.set_json("other:key", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    a.set_json("mcb:key", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    b.get_json("mcb:key").await.unwrap();
+
+    a.set_json("mcb:key", "2", CacheEntryConfig::default())
+        .await
+        .unwrap();
+
+    let mut value = b.get_json("mcb:key").await.unwrap();
+    for _ in 0..100 {
+        if value.as_deref() == Some("2") {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        value = b.get_json("mcb:key").await.unwrap();
+    }
+    assert_eq!(value.as_deref(), Some("2"));
+    let stats = b.stats().await.unwrap();
+    // `other:key` was never invalidated, so reading it again is an L1 hit
+    b.get_json("other:key").await.unwrap();
+    assert_eq!(
+        b.stats().await.unwrap().tiers[0].hits,
+        stats.tiers[0].hits + 1
+    );
+}
+
+#[tokio::test]
+async fn test_l1_copy_expires_with_l2_entry() {
+    let l2 = shared_l2();
+    l2.set_json(
+        "mcb:key",
+        "1",
+        CacheEntryConfig::default().with_ttl(Duration::from_millis(100)),
+    )
+    .await
+    .unwrap();
+    let cache = TieredCacheProvider::new(l1(), Arc::clone(&l2));
+
+    assert_eq!(
+        cache.get_json("mcb:key").await.unwrap().as_deref(),
+        Some("1")
+    );
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    // The L1 copy kept the L2 expiry rather than the 60s L1 TTL
+    assert_eq!(cache.get_json("mcb:key").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_write_invalidates_only_the_written_key() {
+    let (a, b) = instances().await;
+    // Written by `b` itself, so its L1 holds the value without a round trip
+    b.set_json("mcb:other", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    a.set_json("mcb:key", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    b.get_json("mcb:key").await.unwrap();
+
+    a.set_json("mcb:key", "2", CacheEntryConfig::default())
+        .await
+        .unwrap();
+
+    let mut value = b.get_json("mcb:key").await.unwrap();
+    for _ in 0..100 {
+        if value.as_deref() == Some("2") {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        value = b.get_json("mcb:key").await.unwrap();
+    }
+    assert_eq!(value.as_deref(), Some("2"));
+    // `mcb:other` shares the namespace but was not written
+    let stats = b.stats().await.unwrap();
+    b.get_json("mcb:other").await.unwrap();
+    assert_eq!(
+        b.stats().await.unwrap().tiers[0].hits,
+        stats.tiers[0].hits + 1
+    );
+}
+
+#[tokio::test]
+async fn test_own_invalidations_keep_l1() {
+    let (a, _b) = instances().await;
+    a.set_json("mcb:key", "1", CacheEntryConfig::default())
+        .await
+        .unwrap();
+    // Give the echo of the invalidation time to arrive
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    a.get_json("mcb:key").await.unwrap();
+    let l1 = &a.stats().await.unwrap().tiers[0];
+    assert_eq!((l1.hits, l1.misses), (1, 0));
+}
@@ -0,0 +1,652 @@
+//! Vector Store Conformance Tests
+//!
+//! The same checks run against every vector store: caller-chosen ids on
+//! upsert, replacement of an existing id, unique generated ids, listing and
+//! deleting a file's chunks, deletes by filter, and exporting a collection
+//! whole or in pages.
+//! Qdrant runs against an in-process fake of its HTTP API. Milvus runs only
+//! when `MILVUS_ADDRESS` points at a Milvus server, e.g.
+//! `MILVUS_ADDRESS=http://localhost:19530`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use mcb_domain::ports::providers::VectorStoreProvider;
+use mcb_domain::value_objects::{Embedding, MetadataFilter};
+use mcb_providers::vector_store::InMemoryVectorStoreProvider;
+
+const COLLECTION: &str = "conformance";
+const DIMENSIONS: usize = 4;
+
+/// A store with an empty collection, and the directory backing it
+struct Fixture {
+    store: Arc<dyn VectorStoreProvider>,
+    collection: String,
+    _dir: Option<tempfile::TempDir>,
+}
+
+impl Fixture {
+    async fn new(store: Arc<dyn VectorStoreProvider>, dir: Option<tempfile::TempDir>) -> Self {
+        Self::with_collection(store, COLLECTION, dir).await
+    }
+
+    async fn with_collection(
+        store: Arc<dyn VectorStoreProvider>,
+        collection: &str,
+        dir: Option<tempfile::TempDir>,
+    ) -> Self {
+        store
+            .create_collection(collection, DIMENSIONS)
+            .await
+            .expect("create collection");
+        Self {
+            store,
+            collection: collection.to_string(),
+            _dir: dir,
+        }
+    }
+
+    /// Delete the collection, so stores outliving the test stay clean
+    async fn finish(self) {
+        self.store
+            .delete_collection(&self.collection)
+            .await
+            .expect("delete collection");
+    }
+}
+
+async fn in_memory_store() -> Option<Fixture> {
+    Some(Fixture::new(Arc::new(InMemoryVectorStoreProvider::new()), None).await)
+}
+
+#[cfg(feature = "vectorstore-filesystem")]
+async fn filesystem_store() -> Option<Fixture> {
+    use mcb_providers::vector_store::{FilesystemVectorStore, FilesystemVectorStoreConfig};
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = FilesystemVectorStore::new(FilesystemVectorStoreConfig {
+        base_path: dir.path().to_path_buf(),
+        dimensions: DIMENSIONS,
+        ..Default::default()
+    })
+    .await
+    .expect("filesystem store");
+    Some(Fixture::new(Arc::new(store), Some(dir)).await)
+}
+
+#[cfg(feature = "vectorstore-edgevec")]
+async fn edgevec_store() -> Option<Fixture> {
+    use mcb_providers::vector_store::{EdgeVecConfig, EdgeVecVectorStoreProvider};
+
+    let store = EdgeVecVectorStoreProvider::new(EdgeVecConfig {
+        dimensions: DIMENSIONS,
+        ..Default::default()
+    })
+    .expect("edgevec store");
+    Some(Fixture::new(Arc::new(store), None).await)
+}
+
+#[cfg(feature = "vectorstore-encrypted")]
+async fn encrypted_store() -> Option<Fixture> {
+    use mcb_domain::error::Result;
+    use mcb_domain::ports::providers::{CryptoProvider, EncryptedData};
+    use mcb_providers::vector_store::EncryptedVectorStoreProvider;
+
+    /// Stores plaintext as is; the wrapper's field handling is under test
+    struct PlainCrypto;
+
+    impl CryptoProvider for PlainCrypto {
+        fn encrypt(&self, plaintext: &[u8]) -> Result<EncryptedData> {
+            Ok(EncryptedData::new(plaintext.to_vec(), Vec::new()))
+        }
+
+        fn decrypt(&self, encrypted_data: &EncryptedData) -> Result<Vec<u8>> {
+            Ok(encrypted_data.ciphertext.clone())
+        }
+
+        fn provider_name(&self) -> &str {
+            "plain"
+        }
+    }
+
+    let store = EncryptedVectorStoreProvider::new(
+        InMemoryVectorStoreProvider::new(),
+        Arc::new(PlainCrypto),
+    );
+    Some(Fixture::new(Arc::new(store), None).await)
+}
+
+#[cfg(feature = "vectorstore-sqlite")]
+async fn sqlite_store() -> Option<Fixture> {
+    use mcb_providers::vector_store::SqliteVectorStoreProvider;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store =
+        SqliteVectorStoreProvider::open(dir.path().join("vectors.db")).expect("sqlite store");
+    Some(Fixture::new(Arc::new(store), Some(dir)).await)
+}
+
+#[cfg(feature = "vectorstore-qdrant")]
+async fn qdrant_store() -> Option<Fixture> {
+    use mcb_providers::vector_store::QdrantVectorStoreProvider;
+    use std::sync::Mutex;
+
+    let points = Mutex::new(fake_qdrant::Points::new());
+    let server = crate::mock_http::MockServer::start(move |request| {
+        fake_qdrant::handle(&mut points.lock().expect("lock"), request)
+    })
+    .await;
+    let store = QdrantVectorStoreProvider::new(&server.url, reqwest::Client::new());
+    Some(Fixture::new(Arc::new(store), None).await)
+}
+
+#[cfg(feature = "vectorstore-milvus")]
+async fn milvus_store() -> Option<Fixture> {
+    use mcb_providers::vector_store::MilvusVectorStoreProvider;
+
+    let Ok(address) = std::env::var("MILVUS_ADDRESS") else {
+        println!("⊘ SKIPPED: MILVUS_ADDRESS not set (skipping test)");
+        return None;
+    };
+    let store = MilvusVectorStoreProvider::new(address, None, None)
+        .await
+        .expect("milvus store");
+    let collection = format!("mcb_conformance_{}", uuid::Uuid::new_v4().simple());
+    Some(Fixture::with_collection(Arc::new(store), &collection, None).await)
+}
+
+/// In-process stand-in for the parts of the Qdrant HTTP API the provider uses
+///
+/// Holds the points of a single collection, ordered by point id like Qdrant
+/// scrolls them, and answers with cosine similarity scores.
+#[cfg(feature = "vectorstore-qdrant")]
+mod fake_qdrant {
+    use std::collections::BTreeMap;
+
+    use serde_json::{Value, json};
+
+    use crate::mock_http::RecordedRequest;
+
+    /// Points keyed by point id
+    pub type Points = BTreeMap<String, Value>;
+
+    pub fn handle(points: &mut Points, request: &RecordedRequest) -> (u16, Value) {
+        let (method, target) = request.target.split_once(' ').unwrap_or_default();
+        let path = target.split_once('?').map_or(target, |(path, _)| path);
+        let Some(rest) = path.strip_prefix("/collections/") else {
+            return (404, Value::Null);
+        };
+        let route = rest.split_once('/').map_or("", |(_, route)| route);
+        let body = &request.body;
+        let result = match (method, route) {
+            ("PUT", "") | ("DELETE", "") => json!(true),
+            ("PUT", "index") => json!({"status": "completed"}),
+            ("PUT", "points") => {
+                for point in body["points"].as_array().into_iter().flatten() {
+                    let id = point["id"].as_str().unwrap_or_default().to_string();
+                    points.insert(id, point.clone());
+                }
+                json!({"status": "completed"})
+            }
+            ("POST", "points") => {
+                let ids = body["ids"].as_array().cloned().unwrap_or_default();
+                let found: Vec<Value> = ids
+                    .iter()
+                    .filter_map(|id| points.get(id.as_str().unwrap_or_default()))
+                    .map(|point| shape(point, &json!(true), false))
+                    .collect();
+                json!(found)
+            }
+            ("POST", "points/search") => {
+                let query = vector(&body["vector"]);
+                let mut hits: Vec<Value> = points
+                    .values()
+                    .filter(|point| matches(&body["filter"], point))
+                    .map(|point| {
+                        let mut hit = shape(point, &body["with_payload"], false);
+                        hit["score"] = json!(cosine(&query, &vector(&point["vector"])));
+                        hit
+                    })
+                    .collect();
+                hits.sort_by(|a, b| {
+                    b["score"]
+                        .as_f64()
+                        .partial_cmp(&a["score"].as_f64())
+                        .unwrap()
+                });
+                hits.truncate(body["limit"].as_u64().unwrap_or(10) as usize);
+                json!(hits)
+            }
+            ("POST", "points/scroll") => {
+                let limit = body["limit"].as_u64().unwrap_or(10) as usize;
+                let mut page = points
+                    .iter()
+                    .filter(|(id, _)| {
+                        body["offset"]
+                            .as_str()
+                            .is_none_or(|offset| id.as_str() >= offset)
+                    })
+                    .filter(|(_, point)| matches(&body["filter"], point));
+                let batch: Vec<Value> = page
+                    .by_ref()
+                    .take(limit)
+                    .map(|(_, point)| {
+                        shape(
+                            point,
+                            &body["with_payload"],
+                            body["with_vector"] == json!(true),
+                        )
+                    })
+                    .collect();
+                let next = page.next().map_or(Value::Null, |(id, _)| json!(id));
+                json!({"points": batch, "next_page_offset": next})
+            }
+            ("POST", "points/count") => {
+                let count = points
+                    .values()
+                    .filter(|point| matches(&body["filter"], point))
+                    .count();
+                json!({"count": count})
+            }
+            ("POST", "points/delete") => {
+                if let Some(ids) = body["points"].as_array() {
+                    for id in ids {
+                        points.remove(id.as_str().unwrap_or_default());
+                    }
+                } else {
+                    points.retain(|_, point| !matches(&body["filter"], point));
+                }
+                json!({"status": "completed"})
+            }
+            _ => return (404, Value::Null),
+        };
+        (
+            200,
+            json!({"result": result, "status": "ok", "time": 0.001}),
+        )
+    }
+
+    /// Point as returned to the client, with the payload and vector asked for
+    fn shape(point: &Value, with_payload: &Value, with_vector: bool) -> Value {
+        let payload = match with_payload {
+            Value::Array(keys) => {
+                let keys: Vec<&str> = keys.iter().filter_map(Value::as_str).collect();
+                let selected: serde_json::Map<String, Value> = point["payload"]
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .filter(|(key, _)| keys.contains(&key.as_str()))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                Value::Object(selected)
+            }
+            Value::Bool(false) => Value::Null,
+            _ => point["payload"].clone(),
+        };
+        let mut shaped = json!({"id": point["id"], "payload": payload});
+        if with_vector {
+            shaped["vector"] = point["vector"].clone();
+        }
+        shaped
+    }
+
+    /// Whether the point's payload meets every `must` condition of `filter`
+    fn matches(filter: &Value, point: &Value) -> bool {
+        let payload = &point["payload"];
+        filter["must"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .all(|condition| {
+                if let Some(key) = condition["is_null"]["key"].as_str() {
+                    return payload[key].is_null();
+                }
+                let value = &payload[condition["key"].as_str().unwrap_or_default()];
+                if let Some(expected) = condition.get("match") {
+                    return *value == expected["value"];
+                }
+                let range = &condition["range"];
+                value.as_f64().is_some_and(|v| {
+                    range["gte"].as_f64().is_none_or(|gte| v >= gte)
+                        && range["lte"].as_f64().is_none_or(|lte| v <= lte)
+                })
+            })
+    }
+
+    fn vector(value: &Value) -> Vec<f64> {
+        value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_f64)
+            .collect()
+    }
+
+    fn cosine(a: &[f64], b: &[f64]) -> f64 {
+        let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        dot / (norm(a) * norm(b))
+    }
+}
+
+fn embedding(i: usize) -> Embedding {
+    let mut vector = vec![0.1; DIMENSIONS];
+    vector[i % DIMENSIONS] = 1.0 + i as f32;
+    Embedding {
+        vector,
+        model: "test".to_string(),
+        dimensions: DIMENSIONS,
+    }
+}
+
+fn chunk(file_path: &str, start_line: u32, content: &str) -> HashMap<String, serde_json::Value> {
+    HashMap::from([
+        ("file_path".to_string(), serde_json::json!(file_path)),
+        ("start_line".to_string(), serde_json::json!(start_line)),
+        ("content".to_string(), serde_json::json!(content)),
+        ("language".to_string(), serde_json::json!("rust")),
+    ])
+}
+
+async fn upsert(fixture: &Fixture, rows: &[(&str, usize, &str, &str)]) {
+    let ids: Vec<String> = rows.iter().map(|(id, ..)| id.to_string()).collect();
+    let vectors: Vec<Embedding> = rows.iter().map(|(_, i, ..)| embedding(*i)).collect();
+    let metadata = rows
+        .iter()
+        .map(|(_, i, file, content)| chunk(file, *i as u32, content))
+        .collect();
+    fixture
+        .store
+        .upsert_vectors(&fixture.collection, &ids, &vectors, metadata)
+        .await
+        .expect("upsert");
+}
+
+async fn stored_ids(fixture: &Fixture) -> Vec<String> {
+    let mut ids: Vec<String> = fixture
+        .store
+        .list_vectors(&fixture.collection, usize::MAX)
+        .await
+        .expect("list")
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+async fn check_upsert_keeps_caller_ids(fixture: &Fixture) {
+    upsert(
+        fixture,
+        &[
+            ("a", 0, "src/a.rs", "fn a() {}"),
+            ("b", 1, "src/b.rs", "fn b() {}"),
+        ],
+    )
+    .await;
+
+    assert_eq!(stored_ids(fixture).await, vec!["a", "b"]);
+    let found = fixture
+        .store
+        .get_vectors_by_ids(&fixture.collection, &["b".to_string()])
+        .await
+        .expect("get");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, "b");
+    assert_eq!(found[0].content, "fn b() {}");
+
+    let results = fixture
+        .store
+        .search_similar(&fixture.collection, &embedding(1).vector, 1, None)
+        .await
+        .expect("search");
+    assert_eq!(results[0].id, "b");
+}
+
+async fn check_upsert_replaces_existing_id(fixture: &Fixture) {
+    upsert(fixture, &[("a", 0, "src/a.rs", "fn old() {}")]).await;
+    upsert(fixture, &[("a", 2, "src/a.rs", "fn new() {}")]).await;
+
+    assert_eq!(stored_ids(fixture).await, vec!["a"]);
+    let found = fixture
+        .store
+        .get_vectors_by_ids(&fixture.collection, &["a".to_string()])
+        .await
+        .expect("get");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].content, "fn new() {}");
+
+    let results = fixture
+        .store
+        .search_similar(&fixture.collection, &embedding(2).vector, 5, None)
+        .await
+        .expect("search");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, "a");
+}
+
+async fn check_generated_ids_stay_unique_after_delete(fixture: &Fixture) {
+    let first = fixture
+        .store
+        .insert_vectors(
+            &fixture.collection,
+            &[embedding(0), embedding(1)],
+            vec![chunk("src/a.rs", 0, "a"), chunk("src/a.rs", 1, "b")],
+        )
+        .await
+        .expect("insert");
+    fixture
+        .store
+        .delete_vectors(&fixture.collection, &first[..1])
+        .await
+        .expect("delete");
+    let second = fixture
+        .store
+        .insert_vectors(
+            &fixture.collection,
+            &[embedding(2)],
+            vec![chunk("src/a.rs", 2, "c")],
+        )
+        .await
+        .expect("insert");
+
+    assert_ne!(second[0], first[1]);
+    let mut expected = vec![first[1].clone(), second[0].clone()];
+    expected.sort();
+    assert_eq!(stored_ids(fixture).await, expected);
+}
+
+async fn check_delete_by_file_removes_only_that_file(fixture: &Fixture) {
+    upsert(
+        fixture,
+        &[
+            ("a1", 0, "src/a.rs", "fn a1() {}"),
+            ("a2", 1, "src/a.rs", "fn a2() {}"),
+            ("b1", 2, "src/b.rs", "fn b1() {}"),
+        ],
+    )
+    .await;
+
+    let deleted = fixture
+        .store
+        .delete_by_file(&fixture.collection, "src/a.rs")
+        .await
+        .expect("delete by file");
+    assert_eq!(deleted, 2);
+    assert_eq!(stored_ids(fixture).await, vec!["b1"]);
+
+    let deleted = fixture
+        .store
+        .delete_by_file(&fixture.collection, "src/missing.rs")
+        .await
+        .expect("delete by file");
+    assert_eq!(deleted, 0);
+}
+
+async fn check_delete_by_filter_requires_every_condition(fixture: &Fixture) {
+    upsert(
+        fixture,
+        &[
+            ("a0", 0, "src/a.rs", "fn a0() {}"),
+            ("a1", 1, "src/a.rs", "fn a1() {}"),
+        ],
+    )
+    .await;
+
+    let filter = MetadataFilter::file_path("src/a.rs").with("start_line", 1);
+    let deleted = fixture
+        .store
+        .delete_by_filter(&fixture.collection, &filter)
+        .await
+        .expect("delete by filter");
+    assert_eq!(deleted, 1);
+    assert_eq!(stored_ids(fixture).await, vec!["a0"]);
+
+    assert!(
+        fixture
+            .store
+            .delete_by_filter(&fixture.collection, &MetadataFilter::default())
+            .await
+            .is_err()
+    );
+    assert_eq!(stored_ids(fixture).await, vec!["a0"]);
+}
+
+async fn check_file_vector_ids_lists_only_that_file(fixture: &Fixture) {
+    upsert(
+        fixture,
+        &[
+            ("a1", 0, "src/a.rs", "fn a1() {}"),
+            ("a2", 1, "src/a.rs", "fn a2() {}"),
+            ("b1", 2, "src/b.rs", "fn b1() {}"),
+        ],
+    )
+    .await;
+
+    let mut ids = fixture
+        .store
+        .file_vector_ids(&fixture.collection, "src/a.rs")
+        .await
+        .expect("file ids");
+    ids.sort();
+    assert_eq!(ids, vec!["a1", "a2"]);
+
+    let missing = fixture
+        .store
+        .file_vector_ids(&fixture.collection, "src/missing.rs")
+        .await
+        .expect("file ids");
+    assert!(missing.is_empty());
+}
+
+async fn check_export_returns_ids_vectors_and_metadata(fixture: &Fixture) {
+    upsert(
+        fixture,
+        &[
+            ("a", 0, "src/a.rs", "fn a() {}"),
+            ("b", 1, "src/b.rs", "fn b() {}"),
+        ],
+    )
+    .await;
+
+    let mut exported = fixture
+        .store
+        .export_vectors(&fixture.collection)
+        .await
+        .expect("export");
+    exported.sort_by(|x, y| x.id.cmp(&y.id));
+    assert_eq!(exported.len(), 2);
+    assert_eq!(exported[0].id, "a");
+    assert_eq!(exported[1].id, "b");
+    assert_eq!(exported[1].vector, embedding(1).vector);
+    assert_eq!(
+        exported[1].metadata.get("file_path"),
+        Some(&serde_json::json!("src/b.rs"))
+    );
+    assert_eq!(
+        exported[1].metadata.get("content"),
+        Some(&serde_json::json!("fn b() {}"))
+    );
+}
+
+async fn check_export_pages_cover_every_vector_once(fixture: &Fixture) {
+    upsert(
+        fixture,
+        &[
+            ("a", 0, "src/a.rs", "fn a() {}"),
+            ("b", 1, "src/a.rs", "fn b() {}"),
+            ("c", 2, "src/b.rs", "fn c() {}"),
+            ("d", 3, "src/b.rs", "fn d() {}"),
+            ("e", 4, "src/c.rs", "fn e() {}"),
+        ],
+    )
+    .await;
+
+    let mut ids = Vec::new();
+    let mut after: Option<String> = None;
+    loop {
+        let page = fixture
+            .store
+            .export_vectors_page(&fixture.collection, after.as_deref(), 2)
+            .await
+            .expect("export page");
+        assert!(page.len() <= 2);
+        ids.extend(page.iter().map(|v| v.id.clone()));
+        match page.last() {
+            Some(last) if page.len() == 2 => after = Some(last.id.clone()),
+            _ => break,
+        }
+    }
+    ids.sort();
+    assert_eq!(ids, vec!["a", "b", "c", "d", "e"]);
+}
+
+/// One test per check for a store created by `$fixture`
+///
+/// A fixture returning `None` skips the tests.
+macro_rules! conformance_suite {
+    ($provider:ident, $fixture:ident) => {
+        mod $provider {
+            use super::*;
+
+            conformance_suite!(@test $fixture, upsert_keeps_caller_ids, check_upsert_keeps_caller_ids);
+            conformance_suite!(@test $fixture, upsert_replaces_existing_id, check_upsert_replaces_existing_id);
+            conformance_suite!(@test $fixture, generated_ids_stay_unique_after_delete, check_generated_ids_stay_unique_after_delete);
+            conformance_suite!(@test $fixture, delete_by_file_removes_only_that_file, check_delete_by_file_removes_only_that_file);
+            conformance_suite!(@test $fixture, delete_by_filter_requires_every_condition, check_delete_by_filter_requires_every_condition);
+            conformance_suite!(@test $fixture, file_vector_ids_lists_only_that_file, check_file_vector_ids_lists_only_that_file);
+            conformance_suite!(@test $fixture, export_returns_ids_vectors_and_metadata, check_export_returns_ids_vectors_and_metadata);
+            conformance_suite!(@test $fixture, export_pages_cover_every_vector_once, check_export_pages_cover_every_vector_once);
+        }
+    };
+    (@test $fixture:ident, $name:ident, $check:ident) => {
+        #[tokio::test]
+        async fn $name() {
+            let Some(fixture) = $fixture().await else {
+                return;
+            };
+            $check(&fixture).await;
+            fixture.finish().await;
+        }
+    };
+}
+
+conformance_suite!(in_memory, in_memory_store);
+
+#[cfg(feature = "vectorstore-filesystem")]
+conformance_suite!(filesystem, filesystem_store);
+
+#[cfg(feature = "vectorstore-edgevec")]
+conformance_suite!(edgevec, edgevec_store);
+
+#[cfg(feature = "vectorstore-encrypted")]
+conformance_suite!(encrypted, encrypted_store);
+
+#[cfg(feature = "vectorstore-sqlite")]
+conformance_suite!(sqlite, sqlite_store);
+
+#[cfg(feature = "vectorstore-qdrant")]
+conformance_suite!(qdrant, qdrant_store);
+
+#[cfg(feature = "vectorstore-milvus")]
+conformance_suite!(milvus, milvus_store);
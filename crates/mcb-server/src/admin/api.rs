@@ -5,17 +5,36 @@
 //! Migrated from Axum to Rocket in v0.1.2 (ADR-026).
 
 use mcb_application::ports::admin::{IndexingOperationsInterface, PerformanceMetricsInterface};
-use mcb_application::ports::infrastructure::EventBusProvider;
+use mcb_application::ports::infrastructure::{
+    AuditLogInterface, EventBusProvider, EventStoreInterface,
+};
 use mcb_infrastructure::config::watcher::ConfigWatcher;
+use mcb_infrastructure::ratelimit::RateLimiter;
 use rocket::config::{Config as RocketConfig, LogLevel};
 use std::net::IpAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use super::audit::AuditState;
 use super::auth::AdminAuthConfig;
+use super::backups::BackupAdminState;
 use super::browse_handlers::BrowseState;
+use super::bundles::BundleAdminState;
+use super::embedding_routing::EmbeddingRoutingState;
+use super::embedding_usage::EmbeddingUsageState;
+use super::encryption::EncryptionAdminState;
+use super::events::EventStoreState;
 use super::handlers::AdminState;
-use super::routes::admin_rocket;
+use super::provider_health::ProviderHealthState;
+use super::providers::ProviderAdminState;
+use super::routes::{
+    admin_rocket, with_audit_routes, with_backup_routes, with_bundle_routes,
+    with_collection_authorizer, with_embedding_routing, with_embedding_usage,
+    with_encryption_routes, with_event_routes, with_provider_admin, with_provider_health,
+    with_rate_limiter, with_user_routes,
+};
+use super::user_handlers::UserAuthState;
+use crate::auth::CollectionAuthorizer;
 
 /// Admin API server configuration
 #[derive(Debug, Clone)]
@@ -71,6 +90,18 @@ pub struct AdminApi {
     state: AdminState,
     auth_config: Arc<AdminAuthConfig>,
     browse_state: Option<BrowseState>,
+    user_state: Option<UserAuthState>,
+    authorizer: Option<Arc<CollectionAuthorizer>>,
+    audit_state: Option<AuditState>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    provider_health: Option<ProviderHealthState>,
+    embedding_routing: Option<EmbeddingRoutingState>,
+    embedding_usage: Option<EmbeddingUsageState>,
+    provider_admin: Option<ProviderAdminState>,
+    bundles: Option<BundleAdminState>,
+    backups: Option<BackupAdminState>,
+    encryption: Option<EncryptionAdminState>,
+    event_store: Option<EventStoreState>,
 }
 
 impl AdminApi {
@@ -96,6 +127,18 @@ impl AdminApi {
             },
             auth_config: Arc::new(AdminAuthConfig::default()),
             browse_state: None,
+            user_state: None,
+            authorizer: None,
+            audit_state: None,
+            rate_limiter: None,
+            provider_health: None,
+            embedding_routing: None,
+            embedding_usage: None,
+            provider_admin: None,
+            bundles: None,
+            backups: None,
+            encryption: None,
+            event_store: None,
         }
     }
 
@@ -122,6 +165,18 @@ impl AdminApi {
             },
             auth_config: Arc::new(auth_config),
             browse_state: None,
+            user_state: None,
+            authorizer: None,
+            audit_state: None,
+            rate_limiter: None,
+            provider_health: None,
+            embedding_routing: None,
+            embedding_usage: None,
+            provider_admin: None,
+            bundles: None,
+            backups: None,
+            encryption: None,
+            event_store: None,
         }
     }
 
@@ -150,6 +205,18 @@ impl AdminApi {
             },
             auth_config: Arc::new(auth_config),
             browse_state: None,
+            user_state: None,
+            authorizer: None,
+            audit_state: None,
+            rate_limiter: None,
+            provider_health: None,
+            embedding_routing: None,
+            embedding_usage: None,
+            provider_admin: None,
+            bundles: None,
+            backups: None,
+            encryption: None,
+            event_store: None,
         }
     }
 
@@ -162,6 +229,149 @@ impl AdminApi {
         self
     }
 
+    /// Set the user authentication state
+    ///
+    /// When set, enables `/auth/login` and `/users` endpoints and lets admin
+    /// users authenticate with bearer tokens.
+    pub fn with_user_auth(mut self, user_state: UserAuthState) -> Self {
+        self.user_state = Some(user_state);
+        self
+    }
+
+    /// Set the collection authorizer
+    ///
+    /// When set, scoped API keys and user tokens are resolved against
+    /// their `collection:<glob>:<level>` scopes.
+    pub fn with_authorizer(mut self, authorizer: Arc<CollectionAuthorizer>) -> Self {
+        self.authorizer = Some(authorizer);
+        self
+    }
+
+    /// Set the audit log
+    ///
+    /// When set, admin mutations are recorded and `/audit` is mounted.
+    pub fn with_audit_log(mut self, log: Arc<dyn AuditLogInterface>) -> Self {
+        self.audit_state = Some(AuditState { log });
+        self
+    }
+
+    /// Set the rate limiter
+    ///
+    /// When set, guarded endpoints are rate limited per caller and
+    /// `/metrics/rate-limits` is mounted.
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Set the provider router and resilience layer
+    ///
+    /// When set, `/health/providers` is mounted and circuit breakers are
+    /// reported by `/health/extended`.
+    pub fn with_provider_health(mut self, health: ProviderHealthState) -> Self {
+        self.provider_health = Some(health);
+        self
+    }
+
+    /// Set the embedding failover chain
+    ///
+    /// When set, `/embedding/routing` is mounted.
+    pub fn with_embedding_routing(mut self, routing: EmbeddingRoutingState) -> Self {
+        self.embedding_routing = Some(routing);
+        self
+    }
+
+    /// Set the embedding usage totals
+    ///
+    /// When set, `/embedding/usage` is mounted.
+    pub fn with_embedding_usage(mut self, usage: EmbeddingUsageState) -> Self {
+        self.embedding_usage = Some(usage);
+        self
+    }
+
+    /// Set the collection guard for provider switching
+    ///
+    /// When set, the `/providers` endpoints are mounted.
+    pub fn with_provider_admin(mut self, providers: ProviderAdminState) -> Self {
+        self.provider_admin = Some(providers);
+        self
+    }
+
+    /// Set the collection bundler
+    ///
+    /// When set, the `/bundles` endpoints are mounted.
+    pub fn with_bundles(mut self, bundles: BundleAdminState) -> Self {
+        self.bundles = Some(bundles);
+        self
+    }
+
+    /// Set the backup service
+    ///
+    /// When set, the `/backups` endpoints are mounted.
+    pub fn with_backups(mut self, backups: BackupAdminState) -> Self {
+        self.backups = Some(backups);
+        self
+    }
+
+    /// Set the re-encryption jobs
+    ///
+    /// When set, the `/encryption` endpoints are mounted.
+    pub fn with_encryption(mut self, encryption: EncryptionAdminState) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// Set the domain event store
+    ///
+    /// When set, `/events/history` is mounted and `/events` clients can
+    /// resume with `Last-Event-ID`.
+    pub fn with_event_store(mut self, store: Arc<dyn EventStoreInterface>) -> Self {
+        self.event_store = Some(EventStoreState { store });
+        self
+    }
+
+    /// Build the Rocket instance with all configured route groups
+    fn build_rocket(self) -> rocket::Rocket<rocket::Build> {
+        let mut rocket = admin_rocket(self.state, self.auth_config, self.browse_state);
+        if let Some(users) = self.user_state {
+            rocket = with_user_routes(rocket, users);
+        }
+        if let Some(authorizer) = self.authorizer {
+            rocket = with_collection_authorizer(rocket, authorizer);
+        }
+        if let Some(audit) = self.audit_state {
+            rocket = with_audit_routes(rocket, audit);
+        }
+        if let Some(limiter) = self.rate_limiter {
+            rocket = with_rate_limiter(rocket, limiter);
+        }
+        if let Some(health) = self.provider_health {
+            rocket = with_provider_health(rocket, health);
+        }
+        if let Some(routing) = self.embedding_routing {
+            rocket = with_embedding_routing(rocket, routing);
+        }
+        if let Some(usage) = self.embedding_usage {
+            rocket = with_embedding_usage(rocket, usage);
+        }
+        if let Some(providers) = self.provider_admin {
+            rocket = with_provider_admin(rocket, providers);
+        }
+        if let Some(bundles) = self.bundles {
+            rocket = with_bundle_routes(rocket, bundles);
+        }
+        if let Some(backups) = self.backups {
+            rocket = with_backup_routes(rocket, backups);
+        }
+        if let Some(encryption) = self.encryption {
+            rocket = with_encryption_routes(rocket, encryption);
+        }
+        if let Some(events) = self.event_store {
+            rocket = with_event_routes(rocket, events);
+        }
+        rocket
+    }
+
     /// Start the admin API server
     ///
     /// Returns a handle that can be used to gracefully shutdown the server.
@@ -174,8 +384,7 @@ impl AdminApi {
             rocket_config.port
         );
 
-        let rocket =
-            admin_rocket(self.state, self.auth_config, self.browse_state).configure(rocket_config);
+        let rocket = self.build_rocket().configure(rocket_config);
 
         rocket.launch().await.map_err(|e| {
             Box::new(std::io::Error::new(
@@ -204,7 +413,8 @@ impl AdminApi {
             rocket_config.port
         );
 
-        let rocket = admin_rocket(self.state, self.auth_config, self.browse_state)
+        let rocket = self
+            .build_rocket()
             .configure(rocket_config)
             .ignite()
             .await
@@ -0,0 +1,234 @@
+//! Admin audit trail
+//!
+//! Records admin mutations (every non-`GET` request: config PATCH/reload,
+//! shutdown, service start/stop/restart, user management and logins) and
+//! serves the audit log for inspection.
+//!
+//! Recording is done by [`AuditFairing`] once the response is known, so
+//! requests rejected by authentication are captured as `denied` too.
+//! Handlers attach their arguments through the [`AuditTrail`] guard.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/audit` | GET | Query audit events (protected) |
+//!
+//! Query parameters: `principal`, `action`, `source` (`tool`/`admin`),
+//! `outcome` (`success`/`denied`/`failure`), `since`/`until` (RFC 3339)
+//! and `limit`.
+
+use chrono::{DateTime, Utc};
+use mcb_domain::ports::infrastructure::{
+    AuditEvent, AuditLogInterface, AuditOutcome, AuditQuery, AuditSource,
+};
+use mcb_infrastructure::audit::redact_arguments;
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::{Method, Status};
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket::serde::json::Json;
+use rocket::{Data, FromForm, Response, State, get};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::warn;
+
+use super::auth::{AdminAuth, cached_principal};
+use crate::constants::SESSION_ID_HEADER;
+
+/// Audit state containing the audit log
+#[derive(Clone)]
+pub struct AuditState {
+    /// Destination and source of audit events
+    pub log: Arc<dyn AuditLogInterface>,
+}
+
+/// Request start time, stored in the request-local cache
+struct RequestStart(Instant);
+
+/// Arguments a handler attached to the current request
+#[derive(Default)]
+struct RequestArguments(Mutex<Option<serde_json::Value>>);
+
+/// Request guard through which handlers attach audit arguments
+///
+/// Never fails. Arguments are redacted before they are written.
+pub struct AuditTrail<'r>(&'r RequestArguments);
+
+impl AuditTrail<'_> {
+    /// Attach the arguments of the current admin action
+    pub fn arguments(&self, arguments: serde_json::Value) {
+        if let Ok(mut slot) = self.0.0.lock() {
+            *slot = Some(arguments);
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AuditTrail<'r> {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(AuditTrail(request.local_cache(RequestArguments::default)))
+    }
+}
+
+/// Fairing that writes one audit event per admin mutation
+pub struct AuditFairing {
+    log: Arc<dyn AuditLogInterface>,
+}
+
+impl AuditFairing {
+    /// Create a fairing writing to `log`
+    pub fn new(log: Arc<dyn AuditLogInterface>) -> Self {
+        Self { log }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for AuditFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Admin Audit Trail",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _data: &mut Data<'_>) {
+        request.local_cache(|| RequestStart(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        if matches!(
+            request.method(),
+            Method::Get | Method::Head | Method::Options
+        ) {
+            return;
+        }
+
+        let started = request.local_cache(|| RequestStart(Instant::now())).0;
+        let arguments = request
+            .local_cache(RequestArguments::default)
+            .0
+            .lock()
+            .ok()
+            .and_then(|mut slot| slot.take())
+            .unwrap_or(serde_json::Value::Null);
+        let principal = cached_principal(request)
+            .await
+            .as_ref()
+            .ok()
+            .map(|p| p.subject().to_string());
+
+        let status = response.status();
+        let (outcome, error) = match status.code {
+            200..=399 => (AuditOutcome::Success, None),
+            401 | 403 | 429 => (AuditOutcome::Denied, Some(status.to_string())),
+            _ => (AuditOutcome::Failure, Some(status.to_string())),
+        };
+
+        let event = AuditEvent {
+            timestamp: Utc::now(),
+            principal,
+            session: request
+                .headers()
+                .get_one(SESSION_ID_HEADER)
+                .map(str::to_string),
+            source: AuditSource::Admin,
+            action: format!("{} {}", request.method(), request.uri().path()),
+            arguments: redact_arguments(&arguments),
+            outcome,
+            error,
+            duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        };
+
+        if let Err(e) = self.log.record(event).await {
+            warn!(error = %e, path = %request.uri().path(), "Failed to write audit event");
+        }
+    }
+}
+
+/// Query parameters for `/audit`
+#[derive(Debug, FromForm)]
+pub struct AuditQueryParams {
+    /// Exact principal name
+    pub principal: Option<String>,
+    /// Exact tool name or `METHOD /path`
+    pub action: Option<String>,
+    /// `tool` or `admin`
+    pub source: Option<String>,
+    /// `success`, `denied` or `failure`
+    pub outcome: Option<String>,
+    /// RFC 3339 lower bound (inclusive)
+    pub since: Option<String>,
+    /// RFC 3339 upper bound (exclusive)
+    pub until: Option<String>,
+    /// Maximum number of events
+    pub limit: Option<usize>,
+}
+
+impl AuditQueryParams {
+    fn into_query(self) -> Result<AuditQuery, String> {
+        Ok(AuditQuery {
+            principal: self.principal,
+            action: self.action,
+            source: self.source.as_deref().map(parse_enum).transpose()?,
+            outcome: self.outcome.as_deref().map(parse_enum).transpose()?,
+            since: self.since.as_deref().map(parse_time).transpose()?,
+            until: self.until.as_deref().map(parse_time).transpose()?,
+            limit: self.limit,
+        })
+    }
+}
+
+fn parse_enum<T: serde::de::DeserializeOwned>(value: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|_| format!("Invalid filter value '{}'", value))
+}
+
+/// Parse an RFC 3339 timestamp query parameter
+pub(super) fn parse_time(value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid timestamp '{}': {}", value, e))
+}
+
+/// Response for audit queries
+#[derive(Serialize)]
+pub struct AuditListResponse {
+    /// Matching events, newest first
+    pub events: Vec<AuditEvent>,
+    /// Number of events returned
+    pub total: usize,
+}
+
+/// Error response for audit queries
+#[derive(Serialize)]
+pub struct AuditErrorResponse {
+    /// Error message
+    pub error: String,
+}
+
+/// Query the audit log (protected)
+#[get("/audit?<params..>")]
+pub async fn query_audit(
+    _auth: AdminAuth,
+    state: &State<AuditState>,
+    params: AuditQueryParams,
+) -> Result<Json<AuditListResponse>, (Status, Json<AuditErrorResponse>)> {
+    let query = params
+        .into_query()
+        .map_err(|error| (Status::BadRequest, Json(AuditErrorResponse { error })))?;
+
+    let events = state.log.query(&query).await.map_err(|e| {
+        (
+            Status::InternalServerError,
+            Json(AuditErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let total = events.len();
+    Ok(Json(AuditListResponse { events, total }))
+}
@@ -3,6 +3,16 @@
 //! Provides API key-based authentication for admin endpoints.
 //! Uses the `X-Admin-Key` header by default (configurable).
 //!
+//! When the user database is configured (`auth.user_db_path`), users may
+//! authenticate with `Authorization: Bearer <access token>` instead of the
+//! shared key; scoped API keys from `auth.api_key.keys` are accepted the same
+//! way when a [`CollectionAuthorizer`] is managed. Admin endpoints require a
+//! `collection:*:admin` scope and reject other callers with `403`; the browse
+//! API only requires `read` on the collection being viewed. User management
+//! (`/users*`) additionally requires a user with the `admin` role or the
+//! shared admin key, so scopes granted to an editor never let it create or
+//! promote accounts.
+//!
 //! # Configuration
 //!
 //! Authentication can be configured via:
@@ -14,15 +24,27 @@
 //! The following routes are exempt from authentication:
 //! - `/live` - Kubernetes liveness probe
 //! - `/ready` - Kubernetes readiness probe
+//! - `/auth/login`, `/auth/refresh` - User login and token refresh
+//!
+//! Guarded requests are charged to the caller's rate limit first when a
+//! [`RateLimiter`](mcb_infrastructure::ratelimit::RateLimiter) is managed.
 //!
 //! Migrated from Axum to Rocket in v0.1.2 (ADR-026).
 
+use mcb_infrastructure::auth::Principal;
+use mcb_infrastructure::constants::BEARER_PREFIX;
 use rocket::http::Status;
 use rocket::outcome::Outcome;
 use rocket::request::{self, FromRequest, Request};
 use rocket::serde::json::Json;
 use serde::Serialize;
 use std::sync::Arc;
+use tracing::warn;
+
+use super::rate_limit::check_rate_limit;
+use super::user_handlers::UserAuthState;
+use crate::auth::CollectionAuthorizer;
+use crate::constants::ERROR_CODE_ADMIN_ACCESS_DENIED;
 
 /// Admin authentication configuration for the middleware
 #[derive(Clone)]
@@ -136,7 +158,7 @@ impl AuthErrorResponse {
 pub struct AdminAuth;
 
 /// Error type for admin authentication failures
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum AdminAuthError {
     /// Authentication not configured
     NotConfigured,
@@ -144,6 +166,35 @@ pub enum AdminAuthError {
     InvalidKey,
     /// Missing API key
     MissingKey(String),
+    /// Invalid, expired or revoked bearer token
+    InvalidToken,
+    /// Authenticated caller lacks the global admin scope
+    Forbidden,
+    /// Caller exceeded its rate limit; seconds until it may retry
+    RateLimited(u64),
+}
+
+/// Request guard resolving the caller for collection-scoped endpoints
+///
+/// Accepts the same credentials as [`AdminAuth`] but does not require the
+/// admin scope; handlers check the wrapped [`Principal`] against the
+/// collection they serve. The shared admin key and disabled authentication
+/// both yield an unrestricted principal.
+pub struct CollectionAccess(pub Principal);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for CollectionAccess {
+    type Error = AdminAuthError;
+
+    async fn from_request(request: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        if let Err(e) = check_rate_limit(request).await {
+            return Outcome::Error(e);
+        }
+        match cached_principal(request).await {
+            Ok(principal) => Outcome::Success(CollectionAccess(principal.clone())),
+            Err(e) => Outcome::Error(e.clone()),
+        }
+    }
 }
 
 #[rocket::async_trait]
@@ -151,42 +202,135 @@ impl<'r> FromRequest<'r> for AdminAuth {
     type Error = AdminAuthError;
 
     async fn from_request(request: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
-        // Get auth config from managed state
-        let auth_config = match request.rocket().state::<Arc<AdminAuthConfig>>() {
-            Some(config) => config,
-            None => {
-                // No auth config means auth is disabled
-                return Outcome::Success(AdminAuth);
+        if let Err(e) = check_rate_limit(request).await {
+            return Outcome::Error(e);
+        }
+        match cached_principal(request).await {
+            Ok(principal) if principal.is_admin() => Outcome::Success(AdminAuth),
+            Ok(principal) => {
+                warn!(
+                    target: "mcb::audit",
+                    principal = principal.subject(),
+                    path = %request.uri().path(),
+                    code = ERROR_CODE_ADMIN_ACCESS_DENIED,
+                    "Admin request denied: missing collection:*:admin scope"
+                );
+                Outcome::Error((Status::Forbidden, AdminAuthError::Forbidden))
             }
-        };
-
-        // If authentication is disabled, allow all requests
-        if !auth_config.enabled {
-            return Outcome::Success(AdminAuth);
+            Err(e) => Outcome::Error(e.clone()),
         }
+    }
+}
 
-        // Check if auth is properly configured
-        if !auth_config.is_configured() {
-            return Outcome::Error((Status::ServiceUnavailable, AdminAuthError::NotConfigured));
+/// Request guard for user management endpoints
+///
+/// Stricter than [`AdminAuth`]: a global admin scope is not enough, the caller
+/// must be a user with the [`UserRole::Admin`] role or hold the shared key.
+///
+/// [`UserRole::Admin`]: mcb_infrastructure::auth::UserRole::Admin
+pub struct UserAdminAuth;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for UserAdminAuth {
+    type Error = AdminAuthError;
+
+    async fn from_request(request: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        if let Err(e) = check_rate_limit(request).await {
+            return Outcome::Error(e);
+        }
+        match cached_principal(request).await {
+            Ok(principal) if principal.is_user_admin() => Outcome::Success(UserAdminAuth),
+            Ok(principal) => {
+                warn!(
+                    target: "mcb::audit",
+                    principal = principal.subject(),
+                    path = %request.uri().path(),
+                    code = ERROR_CODE_ADMIN_ACCESS_DENIED,
+                    "User management request denied: admin role required"
+                );
+                Outcome::Error((Status::Forbidden, AdminAuthError::Forbidden))
+            }
+            Err(e) => Outcome::Error(e.clone()),
         }
+    }
+}
+
+/// Outcome of authenticating a request, computed once and cached
+type CallerResult = Result<Principal, (Status, AdminAuthError)>;
+
+/// Resolve the caller once per request
+///
+/// Shared by [`AdminAuth`], [`UserAdminAuth`], [`CollectionAccess`] and the audit fairing so
+/// tokens are validated a single time.
+pub(crate) async fn cached_principal<'r>(request: &'r Request<'_>) -> &'r CallerResult {
+    request
+        .local_cache_async(async { resolve_principal(request).await })
+        .await
+}
+
+/// Authenticate the request and resolve the calling principal
+async fn resolve_principal(request: &Request<'_>) -> CallerResult {
+    // No auth config means auth is disabled
+    let Some(auth_config) = request.rocket().state::<Arc<AdminAuthConfig>>() else {
+        return Ok(Principal::unrestricted("anonymous"));
+    };
+
+    // If authentication is disabled, allow all requests
+    if !auth_config.enabled {
+        return Ok(Principal::unrestricted("anonymous"));
+    }
 
-        // Get the API key from headers
-        let api_key = request.headers().get_one(&auth_config.header_name);
+    let authorizer = request.rocket().state::<Arc<CollectionAuthorizer>>();
+    let users = request.rocket().state::<UserAuthState>();
 
-        match api_key {
-            Some(key) if auth_config.validate_key(key) => Outcome::Success(AdminAuth),
-            Some(_) => Outcome::Error((Status::Unauthorized, AdminAuthError::InvalidKey)),
-            None => Outcome::Error((
-                Status::Unauthorized,
-                AdminAuthError::MissingKey(auth_config.header_name.clone()),
-            )),
+    // Bearer tokens (scoped API keys, user tokens) take precedence over the shared key
+    if authorizer.is_some() || users.is_some() {
+        if let Some(token) = bearer_token(request) {
+            let principal = match authorizer {
+                Some(authorizer) => authorizer.resolve_token(token).await,
+                None => None,
+            };
+            let principal = match (principal, users) {
+                (Some(principal), _) => Some(principal),
+                (None, Some(users)) => users.service.principal_for_token(token).await.ok(),
+                (None, None) => None,
+            };
+            return match principal {
+                Some(principal) => Ok(principal),
+                None => Err((Status::Unauthorized, AdminAuthError::InvalidToken)),
+            };
         }
+    } else if !auth_config.is_configured() {
+        // Check if auth is properly configured
+        return Err((Status::ServiceUnavailable, AdminAuthError::NotConfigured));
     }
+
+    // Get the API key from headers
+    let api_key = request.headers().get_one(&auth_config.header_name);
+
+    match api_key {
+        Some(key) if auth_config.validate_key(key) => Ok(Principal::unrestricted("admin-key")),
+        Some(_) => Err((Status::Unauthorized, AdminAuthError::InvalidKey)),
+        None => Err((
+            Status::Unauthorized,
+            AdminAuthError::MissingKey(auth_config.header_name.clone()),
+        )),
+    }
+}
+
+/// Extract the token from an `Authorization: Bearer <token>` header
+fn bearer_token<'r>(request: &'r Request<'_>) -> Option<&'r str> {
+    request
+        .headers()
+        .get_one("Authorization")
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
 }
 
 /// Check if a route should bypass authentication
 pub fn is_unauthenticated_route(path: &str) -> bool {
-    matches!(path, "/live" | "/ready")
+    matches!(path, "/live" | "/ready" | "/auth/login" | "/auth/refresh")
 }
 
 /// Wrapper function for backwards compatibility
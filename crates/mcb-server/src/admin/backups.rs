@@ -0,0 +1,118 @@
+//! Backups
+//!
+//! Lists, triggers and restores the scheduled backups written by the
+//! backup service. Backups are addressed by file name and only names found
+//! in the backup directory are accepted.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/backups` | GET | List backups, newest first |
+//! | `/backups` | POST | Write a backup now |
+//! | `/backups/restore` | POST | Restore a backup (409 on model mismatch) |
+
+use mcb_domain::error::Error;
+use mcb_infrastructure::backup::{BackupInfo, BackupService, RestoreReport};
+use rocket::State;
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::{get, post};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::audit::AuditTrail;
+use super::auth::AdminAuth;
+
+/// Backup service shared with the admin routes
+#[derive(Clone)]
+pub struct BackupAdminState {
+    /// Running backup service
+    pub service: Arc<BackupService>,
+}
+
+/// Request body for `POST /backups/restore`
+#[derive(Debug, Deserialize)]
+pub struct BackupRestoreRequest {
+    /// Backup file name
+    pub name: String,
+    /// Replace collections that already exist instead of skipping them
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+/// Error response for backup operations
+#[derive(Debug, Serialize)]
+pub struct BackupErrorResponse {
+    /// Error message
+    pub error: String,
+    /// Error code for programmatic handling
+    pub code: &'static str,
+}
+
+type BackupError = (Status, Json<BackupErrorResponse>);
+
+fn map_error(e: Error) -> BackupError {
+    let (status, code) = match &e {
+        Error::NotFound { .. } => (Status::NotFound, "BACKUP_NOT_FOUND"),
+        Error::InvalidArgument { .. } | Error::Conflict { .. } => {
+            (Status::Conflict, "RESTORE_REFUSED")
+        }
+        Error::Configuration { .. } => (Status::BadRequest, "ENCRYPTION_UNAVAILABLE"),
+        _ => (Status::InternalServerError, "INTERNAL_ERROR"),
+    };
+    (
+        status,
+        Json(BackupErrorResponse {
+            error: e.to_string(),
+            code,
+        }),
+    )
+}
+
+/// List backups, newest first
+#[get("/backups")]
+pub async fn list_backups(
+    _auth: AdminAuth,
+    state: &State<BackupAdminState>,
+) -> Result<Json<Vec<BackupInfo>>, BackupError> {
+    state.service.list().await.map(Json).map_err(map_error)
+}
+
+/// Write a backup now
+#[post("/backups")]
+pub async fn trigger_backup(
+    _auth: AdminAuth,
+    state: &State<BackupAdminState>,
+) -> Result<Json<BackupInfo>, BackupError> {
+    state
+        .service
+        .run_backup()
+        .await
+        .map(Json)
+        .map_err(map_error)
+}
+
+/// Restore a backup
+///
+/// Refused with 409 when a collection in the backup was embedded with
+/// another model or dimensionality than the active embedding provider.
+#[post("/backups/restore", format = "json", data = "<request>")]
+pub async fn restore_backup(
+    _auth: AdminAuth,
+    state: &State<BackupAdminState>,
+    audit: AuditTrail<'_>,
+    request: Json<BackupRestoreRequest>,
+) -> Result<Json<RestoreReport>, BackupError> {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({
+        "name": request.name,
+        "overwrite": request.overwrite,
+    }));
+    state
+        .service
+        .restore(&request.name, request.overwrite)
+        .await
+        .map(Json)
+        .map_err(map_error)
+}
@@ -10,18 +10,23 @@
 //! | `/collections` | GET | List all indexed collections |
 //! | `/collections/:name/files` | GET | List files in a collection |
 //! | `/collections/:name/files/*path/chunks` | GET | Get chunks for a file |
+//!
+//! Callers only see collections their `collection:<glob>:read` scopes cover.
 
 use mcb_domain::ports::providers::VectorStoreBrowser;
+use mcb_infrastructure::auth::AccessLevel;
 use rocket::http::Status;
 use rocket::serde::json::Json;
 use rocket::{State, get};
 use std::sync::Arc;
+use tracing::warn;
 
-use super::auth::AdminAuth;
+use super::auth::CollectionAccess;
 use super::models::{
     ChunkDetailResponse, ChunkListResponse, CollectionInfoResponse, CollectionListResponse,
     FileInfoResponse, FileListResponse,
 };
+use crate::constants::ERROR_CODE_COLLECTION_ACCESS_DENIED;
 
 /// Browse handler state containing the vector store browser
 #[derive(Clone)]
@@ -56,6 +61,37 @@ impl BrowseErrorResponse {
     pub fn internal(message: impl Into<String>) -> Self {
         Self::new(message, "INTERNAL_ERROR")
     }
+
+    /// Creates an access denied response for a collection
+    pub fn access_denied(collection: &str) -> Self {
+        Self::new(
+            format!("Read access to collection '{}' is not granted", collection),
+            ERROR_CODE_COLLECTION_ACCESS_DENIED,
+        )
+    }
+}
+
+/// Reject callers without read access to `collection`, auditing the denial
+fn require_read(
+    access: &CollectionAccess,
+    collection: &str,
+) -> Result<(), (Status, Json<BrowseErrorResponse>)> {
+    let principal = &access.0;
+    if principal.can(collection, AccessLevel::Read) {
+        return Ok(());
+    }
+    warn!(
+        target: "mcb::audit",
+        principal = principal.subject(),
+        collection,
+        required = %AccessLevel::Read,
+        code = ERROR_CODE_COLLECTION_ACCESS_DENIED,
+        "Browse request denied: insufficient collection scope"
+    );
+    Err((
+        Status::Forbidden,
+        Json(BrowseErrorResponse::access_denied(collection)),
+    ))
 }
 
 /// List all indexed collections
@@ -65,10 +101,11 @@ impl BrowseErrorResponse {
 ///
 /// # Authentication
 ///
-/// Requires valid admin API key via `X-Admin-Key` header.
+/// Requires the admin API key or a bearer token; only collections the
+/// caller may read are returned.
 #[get("/collections")]
 pub async fn list_collections(
-    _auth: AdminAuth,
+    access: CollectionAccess,
     state: &State<BrowseState>,
 ) -> Result<Json<CollectionListResponse>, (Status, Json<BrowseErrorResponse>)> {
     let collections = state.browser.list_collections().await.map_err(|e| {
@@ -78,7 +115,9 @@ pub async fn list_collections(
         )
     })?;
 
-    let collection_responses: Vec<CollectionInfoResponse> = collections
+    let collection_responses: Vec<CollectionInfoResponse> = access
+        .0
+        .filter_readable(collections, |c| c.name.as_str())
         .into_iter()
         .map(|c| CollectionInfoResponse {
             name: c.name,
@@ -108,14 +147,15 @@ pub async fn list_collections(
 ///
 /// # Authentication
 ///
-/// Requires valid admin API key via `X-Admin-Key` header.
+/// Requires `read` scope on the collection (or the admin API key).
 #[get("/collections/<name>/files?<limit>")]
 pub async fn list_collection_files(
-    _auth: AdminAuth,
+    access: CollectionAccess,
     state: &State<BrowseState>,
     name: &str,
     limit: Option<usize>,
 ) -> Result<Json<FileListResponse>, (Status, Json<BrowseErrorResponse>)> {
+    require_read(&access, name)?;
     let limit = limit.unwrap_or(100);
 
     let files = state
@@ -169,14 +209,15 @@ pub async fn list_collection_files(
 ///
 /// # Authentication
 ///
-/// Requires valid admin API key via `X-Admin-Key` header.
+/// Requires `read` scope on the collection (or the admin API key).
 #[get("/collections/<name>/chunks/<path..>")]
 pub async fn get_file_chunks(
-    _auth: AdminAuth,
+    access: CollectionAccess,
     state: &State<BrowseState>,
     name: &str,
     path: std::path::PathBuf,
 ) -> Result<Json<ChunkListResponse>, (Status, Json<BrowseErrorResponse>)> {
+    require_read(&access, name)?;
     let file_path = path.to_string_lossy().to_string();
 
     let chunks = state
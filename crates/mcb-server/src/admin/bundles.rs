@@ -0,0 +1,169 @@
+//! Collection bundles
+//!
+//! Exports collections to portable bundles and imports bundles into the
+//! active vector store. Bundles live in one server-side directory and are
+//! addressed by file name only.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/bundles/export` | POST | Write a collection to a bundle (404 for a missing collection) |
+//! | `/bundles/import` | POST | Import a bundle (404 for a missing bundle, 400 for a damaged one, 409 on model mismatch or existing collection) |
+
+use mcb_domain::error::Error;
+use mcb_infrastructure::collections::{
+    BundleManifest, CollectionBundler, ExportOptions, ImportedBundle,
+};
+use rocket::State;
+use rocket::http::Status;
+use rocket::post;
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::audit::AuditTrail;
+use super::auth::AdminAuth;
+
+/// Bundler and the directory bundles are read from and written to
+#[derive(Clone)]
+pub struct BundleAdminState {
+    /// Bundler over the collection guard
+    pub bundler: Arc<CollectionBundler>,
+    /// Directory holding bundle files
+    pub directory: PathBuf,
+}
+
+/// Request body for `POST /bundles/export`
+#[derive(Debug, Deserialize)]
+pub struct BundleExportRequest {
+    /// Collection to export
+    pub collection: String,
+    /// Bundle file name inside the bundle directory
+    pub file: String,
+    /// gzip the bundle contents
+    #[serde(default)]
+    pub compress: bool,
+    /// Encrypt the bundle contents with the server key
+    #[serde(default)]
+    pub encrypt: bool,
+}
+
+/// Request body for `POST /bundles/import`
+#[derive(Debug, Deserialize)]
+pub struct BundleImportRequest {
+    /// Bundle file name inside the bundle directory
+    pub file: String,
+    /// Collection to import into; defaults to the exported collection
+    pub collection: Option<String>,
+}
+
+/// Error response for bundle operations
+#[derive(Debug, Serialize)]
+pub struct BundleErrorResponse {
+    /// Error message
+    pub error: String,
+    /// Error code for programmatic handling
+    pub code: &'static str,
+}
+
+type BundleError = (Status, Json<BundleErrorResponse>);
+
+fn error(status: Status, code: &'static str, message: String) -> BundleError {
+    (
+        status,
+        Json(BundleErrorResponse {
+            error: message,
+            code,
+        }),
+    )
+}
+
+fn map_error(e: Error) -> BundleError {
+    let (status, code) = match &e {
+        Error::NotFound { .. } => (Status::NotFound, "NOT_FOUND"),
+        Error::Conflict { .. } => (Status::Conflict, "BUNDLE_REFUSED"),
+        Error::InvalidArgument { .. } => (Status::BadRequest, "INVALID_BUNDLE"),
+        Error::Configuration { .. } => (Status::BadRequest, "ENCRYPTION_UNAVAILABLE"),
+        _ => (Status::InternalServerError, "INTERNAL_ERROR"),
+    };
+    error(status, code, e.to_string())
+}
+
+/// Path of `file` inside the bundle directory
+///
+/// Only plain file names are accepted, so requests cannot reach outside
+/// the directory.
+fn bundle_path(state: &BundleAdminState, file: &str) -> Result<PathBuf, BundleError> {
+    if file.is_empty() || Path::new(file).file_name().and_then(|n| n.to_str()) != Some(file) {
+        return Err(error(
+            Status::BadRequest,
+            "INVALID_FILE_NAME",
+            format!("'{file}' is not a plain file name"),
+        ));
+    }
+    Ok(state.directory.join(file))
+}
+
+/// Write a collection to a bundle
+#[post("/bundles/export", format = "json", data = "<request>")]
+pub async fn export_bundle(
+    _auth: AdminAuth,
+    state: &State<BundleAdminState>,
+    audit: AuditTrail<'_>,
+    request: Json<BundleExportRequest>,
+) -> Result<Json<BundleManifest>, BundleError> {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({
+        "collection": request.collection,
+        "file": request.file,
+        "compress": request.compress,
+        "encrypt": request.encrypt,
+    }));
+    let path = bundle_path(state, &request.file)?;
+    tokio::fs::create_dir_all(&state.directory)
+        .await
+        .map_err(|e| {
+            error(
+                Status::InternalServerError,
+                "INTERNAL_ERROR",
+                format!("Failed to create bundle directory: {e}"),
+            )
+        })?;
+    let options = ExportOptions {
+        compress: request.compress,
+        encrypt: request.encrypt,
+    };
+    state
+        .bundler
+        .export(&request.collection, &path, options)
+        .await
+        .map(Json)
+        .map_err(map_error)
+}
+
+/// Import a bundle into the active vector store
+///
+/// Refused with 409 when the active embedding provider uses another model
+/// or dimensionality than the bundle, or the target collection exists.
+#[post("/bundles/import", format = "json", data = "<request>")]
+pub async fn import_bundle(
+    _auth: AdminAuth,
+    state: &State<BundleAdminState>,
+    audit: AuditTrail<'_>,
+    request: Json<BundleImportRequest>,
+) -> Result<Json<ImportedBundle>, BundleError> {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({
+        "file": request.file,
+        "collection": request.collection,
+    }));
+    let path = bundle_path(state, &request.file)?;
+    state
+        .bundler
+        .import(&path, request.collection.as_deref())
+        .await
+        .map(Json)
+        .map_err(map_error)
+}
@@ -13,6 +13,7 @@ use rocket::{State, get, patch, post};
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use super::audit::AuditTrail;
 use super::auth::AdminAuth;
 use super::config::{
     ConfigReloadResponse, ConfigResponse, ConfigSectionUpdateRequest, ConfigSectionUpdateResponse,
@@ -127,9 +128,11 @@ pub async fn update_config_section(
     _auth: AdminAuth,
     state: &State<AdminState>,
     section: &str,
+    audit: AuditTrail<'_>,
     request: Json<ConfigSectionUpdateRequest>,
 ) -> (Status, Json<ConfigSectionUpdateResponse>) {
     let request = request.into_inner();
+    audit.arguments(serde_json::json!({ "section": section, "values": request.values }));
 
     // Validate and get required resources
     let (watcher, config_path) = match validate_update_prerequisites(state, section) {
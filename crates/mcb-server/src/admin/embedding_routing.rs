@@ -0,0 +1,41 @@
+//! Embedding failover status
+//!
+//! Shows the members of the embedding failover chain with their health and,
+//! for every collection the chain has served, the model and dimensions the
+//! collection is pinned to and the provider that handled its last call.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/embedding/routing` | GET | Failover members and active provider per collection |
+
+use mcb_infrastructure::routing::{FailoverEmbeddingProvider, FailoverSnapshot};
+use rocket::serde::json::Json;
+use rocket::{State, get};
+use std::sync::Arc;
+
+use super::auth::CollectionAccess;
+
+/// Failover chain shared with the context service
+#[derive(Clone)]
+pub struct EmbeddingRoutingState {
+    /// The chain resolved from `providers.embedding.failover`
+    pub failover: Arc<FailoverEmbeddingProvider>,
+}
+
+/// Failover members and collection routes
+///
+/// Requires the admin API key or a bearer token; only collections the
+/// caller may read are listed.
+#[get("/embedding/routing")]
+pub async fn get_embedding_routing(
+    access: CollectionAccess,
+    state: &State<EmbeddingRoutingState>,
+) -> Json<FailoverSnapshot> {
+    let mut snapshot = state.failover.snapshot().await;
+    snapshot.collections = access
+        .0
+        .filter_readable(snapshot.collections, |route| route.collection.as_str());
+    Json(snapshot)
+}
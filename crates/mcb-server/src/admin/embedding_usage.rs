@@ -0,0 +1,47 @@
+//! Embedding usage
+//!
+//! Estimated tokens, requests and oversized inputs per embedding provider
+//! and model, as counted by the batching front-end.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/embedding/usage` | GET | Estimated token totals per provider and model |
+
+use mcb_infrastructure::embedding::{EmbeddingUsage, EmbeddingUsageEntry};
+use rocket::serde::json::Json;
+use rocket::{State, get};
+use serde::Serialize;
+use std::sync::Arc;
+
+use super::auth::AdminAuth;
+
+/// Usage totals shared with the embedding resolver
+#[derive(Clone)]
+pub struct EmbeddingUsageState {
+    /// Totals recorded by every batching decorator
+    pub usage: Arc<EmbeddingUsage>,
+}
+
+/// Response for `GET /embedding/usage`
+#[derive(Debug, Serialize)]
+pub struct EmbeddingUsageResponse {
+    /// Estimated tokens over all providers
+    pub total_tokens: u64,
+    /// Totals per provider and model
+    pub providers: Vec<EmbeddingUsageEntry>,
+}
+
+/// Estimated token totals per provider and model
+#[get("/embedding/usage")]
+pub fn get_embedding_usage(
+    _auth: AdminAuth,
+    state: &State<EmbeddingUsageState>,
+) -> Json<EmbeddingUsageResponse> {
+    let providers = state.usage.snapshot();
+    Json(EmbeddingUsageResponse {
+        total_tokens: providers.iter().map(|entry| entry.tokens).sum(),
+        providers,
+    })
+}
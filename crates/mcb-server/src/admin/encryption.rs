@@ -0,0 +1,94 @@
+//! Vector store encryption
+//!
+//! Starts and reports background re-encryption of collections after the
+//! active key of the vector store keyring has been rotated.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/encryption/reencrypt` | GET | Latest re-encryption status per collection |
+//! | `/encryption/reencrypt` | POST | Re-encrypt a collection with the active key |
+
+use mcb_domain::error::Error;
+use mcb_infrastructure::collections::{ReencryptionJobs, ReencryptionStatus};
+use rocket::State;
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::{get, post};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::audit::AuditTrail;
+use super::auth::AdminAuth;
+
+/// Re-encryption jobs shared with the admin routes
+#[derive(Clone)]
+pub struct EncryptionAdminState {
+    /// Re-encryption jobs over the encrypted vector store
+    pub jobs: Arc<ReencryptionJobs>,
+}
+
+/// Request body for `POST /encryption/reencrypt`
+#[derive(Debug, Deserialize)]
+pub struct ReencryptRequest {
+    /// Collection to re-encrypt
+    pub collection: String,
+}
+
+/// Error response for encryption operations
+#[derive(Debug, Serialize)]
+pub struct EncryptionErrorResponse {
+    /// Error message
+    pub error: String,
+    /// Error code for programmatic handling
+    pub code: &'static str,
+}
+
+type EncryptionError = (Status, Json<EncryptionErrorResponse>);
+
+fn map_error(e: Error) -> EncryptionError {
+    let (status, code) = match &e {
+        Error::NotFound { .. } => (Status::NotFound, "COLLECTION_NOT_FOUND"),
+        Error::InvalidArgument { .. } => (Status::Conflict, "REENCRYPTION_RUNNING"),
+        _ => (Status::InternalServerError, "INTERNAL_ERROR"),
+    };
+    (
+        status,
+        Json(EncryptionErrorResponse {
+            error: e.to_string(),
+            code,
+        }),
+    )
+}
+
+/// Latest re-encryption status per collection
+#[get("/encryption/reencrypt")]
+pub fn list_reencryptions(
+    _auth: AdminAuth,
+    state: &State<EncryptionAdminState>,
+) -> Json<Vec<ReencryptionStatus>> {
+    Json(state.jobs.statuses())
+}
+
+/// Re-encrypt a collection with the active key
+///
+/// Returns 202 with the initial status; poll `GET /encryption/reencrypt`
+/// for progress. Refused with 409 while the collection is already being
+/// re-encrypted.
+#[post("/encryption/reencrypt", format = "json", data = "<request>")]
+pub async fn start_reencryption(
+    _auth: AdminAuth,
+    state: &State<EncryptionAdminState>,
+    audit: AuditTrail<'_>,
+    request: Json<ReencryptRequest>,
+) -> Result<(Status, Json<ReencryptionStatus>), EncryptionError> {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({ "collection": request.collection }));
+    state
+        .jobs
+        .start(&request.collection)
+        .await
+        .map(|status| (Status::Accepted, Json(status)))
+        .map_err(map_error)
+}
@@ -0,0 +1,109 @@
+//! Domain event history
+//!
+//! Serves the event store for inspection. The same store lets `/events`
+//! clients resume with `Last-Event-ID` after a reconnect.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/events/history` | GET | Query past domain events (protected) |
+//!
+//! Query parameters: `type` (e.g. `IndexingProgress`), `collection`,
+//! `since`/`until` (RFC 3339), `after` (sequence number) and `limit`.
+
+use mcb_domain::ports::infrastructure::{EventQuery, EventStoreInterface, StoredEvent};
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::{FromForm, State, get};
+use serde::Serialize;
+use std::sync::Arc;
+
+use super::audit::parse_time;
+use super::auth::AdminAuth;
+
+/// Event store state for replay and history queries
+#[derive(Clone)]
+pub struct EventStoreState {
+    /// Log of published domain events
+    pub store: Arc<dyn EventStoreInterface>,
+}
+
+/// Query parameters for `/events/history`
+#[derive(Debug, FromForm)]
+pub struct EventHistoryParams {
+    /// Event type, e.g. `IndexingProgress`
+    #[field(name = "type")]
+    pub event_type: Option<String>,
+    /// Collection the event concerns
+    pub collection: Option<String>,
+    /// RFC 3339 lower bound (inclusive)
+    pub since: Option<String>,
+    /// RFC 3339 upper bound (exclusive)
+    pub until: Option<String>,
+    /// Only events after this sequence number
+    pub after: Option<u64>,
+    /// Maximum number of events
+    pub limit: Option<usize>,
+}
+
+impl EventHistoryParams {
+    fn into_query(self) -> Result<EventQuery, String> {
+        Ok(EventQuery {
+            event_type: self.event_type,
+            collection: self.collection,
+            since: self.since.as_deref().map(parse_time).transpose()?,
+            until: self.until.as_deref().map(parse_time).transpose()?,
+            after_sequence: self.after,
+            limit: self.limit,
+        })
+    }
+}
+
+/// Response for event history queries
+#[derive(Serialize)]
+pub struct EventHistoryResponse {
+    /// Matching events, newest first
+    pub events: Vec<StoredEvent>,
+    /// Number of events returned
+    pub total: usize,
+    /// Sequence number of the newest event in the store
+    pub last_sequence: u64,
+}
+
+/// Error response for event history queries
+#[derive(Serialize)]
+pub struct EventHistoryErrorResponse {
+    /// Error message
+    pub error: String,
+}
+
+/// Query past domain events (protected)
+#[get("/events/history?<params..>")]
+pub async fn query_event_history(
+    _auth: AdminAuth,
+    state: &State<EventStoreState>,
+    params: EventHistoryParams,
+) -> Result<Json<EventHistoryResponse>, (Status, Json<EventHistoryErrorResponse>)> {
+    let query = params.into_query().map_err(|error| {
+        (
+            Status::BadRequest,
+            Json(EventHistoryErrorResponse { error }),
+        )
+    })?;
+
+    let events = state.store.query(&query).await.map_err(|e| {
+        (
+            Status::InternalServerError,
+            Json(EventHistoryErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    Ok(Json(EventHistoryResponse {
+        total: events.len(),
+        events,
+        last_sequence: state.store.last_sequence(),
+    }))
+}
@@ -12,19 +12,21 @@ use mcb_application::ports::admin::{
     ShutdownCoordinator,
 };
 use mcb_application::ports::infrastructure::EventBusProvider;
-use mcb_application::ports::providers::CacheProvider;
+use mcb_application::ports::providers::{CacheProvider, NamespaceStats};
 use mcb_infrastructure::config::watcher::ConfigWatcher;
 use mcb_infrastructure::infrastructure::ServiceManager;
 use rocket::http::Status;
 use rocket::serde::json::Json;
-use rocket::{State, get, post};
+use rocket::{State, delete, get, post};
 use serde::Serialize;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::info;
 
-use super::auth::AdminAuth;
+use super::audit::AuditTrail;
+use super::auth::{AdminAuth, CollectionAccess};
+use super::provider_health::ProviderHealthState;
 
 /// Admin handler state containing shared service references
 #[derive(Clone)]
@@ -109,12 +111,22 @@ pub struct IndexingOperationStatus {
 }
 
 /// Get indexing status endpoint
+///
+/// Only operations on collections the caller may read are reported.
 #[get("/indexing")]
-pub fn get_indexing_status(state: &State<AdminState>) -> Json<IndexingStatusResponse> {
+pub fn get_indexing_status(
+    access: CollectionAccess,
+    state: &State<AdminState>,
+) -> Json<IndexingStatusResponse> {
     let operations = state.indexing.get_operations();
+    let operations = access
+        .0
+        .filter_readable(operations.into_values().collect(), |op| {
+            op.collection.as_str()
+        });
 
     let operation_statuses: Vec<IndexingOperationStatus> = operations
-        .values()
+        .iter()
         .map(|op| {
             let progress = if op.total_files > 0 {
                 (op.processed_files as f32 / op.total_files as f32) * 100.0
@@ -235,9 +247,14 @@ impl ShutdownResponse {
 pub fn shutdown(
     _auth: AdminAuth,
     state: &State<AdminState>,
+    audit: AuditTrail<'_>,
     request: Json<ShutdownRequest>,
 ) -> (Status, Json<ShutdownResponse>) {
     let request = request.into_inner();
+    audit.arguments(serde_json::json!({
+        "timeout_secs": request.timeout_secs,
+        "immediate": request.immediate,
+    }));
 
     let Some(coordinator) = &state.shutdown_coordinator else {
         return (
@@ -293,7 +310,9 @@ fn spawn_graceful_shutdown(coord: Arc<dyn ShutdownCoordinator>, timeout: u64) {
 /// Extended health check with dependency status (protected)
 ///
 /// Returns detailed health information including the status of
-/// all service dependencies (embedding provider, vector store, cache).
+/// all service dependencies (embedding provider, vector store, cache),
+/// plus one entry per provider circuit breaker when provider health is
+/// mounted.
 ///
 /// # Authentication
 ///
@@ -302,12 +321,16 @@ fn spawn_graceful_shutdown(coord: Arc<dyn ShutdownCoordinator>, timeout: u64) {
 pub fn extended_health_check(
     _auth: AdminAuth,
     state: &State<AdminState>,
+    provider_health: Option<&State<ProviderHealthState>>,
 ) -> Json<ExtendedHealthResponse> {
     let metrics = state.metrics.get_performance_metrics();
     let operations = state.indexing.get_operations();
     let now = current_timestamp();
 
-    let dependencies = build_dependency_checks(&metrics, &operations, now);
+    let mut dependencies = build_dependency_checks(&metrics, &operations, now);
+    if let Some(provider_health) = provider_health {
+        dependencies.extend(provider_health.dependency_checks(now));
+    }
     let dependencies_status = calculate_overall_health(&dependencies);
 
     let response = ExtendedHealthResponse {
@@ -421,7 +444,7 @@ fn calculate_overall_health(dependencies: &[DependencyHealthCheck]) -> Dependenc
 }
 
 // ============================================================================
-// Cache Stats Endpoint
+// Cache Endpoints
 // ============================================================================
 
 /// Cache error response
@@ -430,6 +453,29 @@ pub struct CacheErrorResponse {
     pub error: String,
 }
 
+type CacheError = (Status, Json<CacheErrorResponse>);
+
+/// The cache provider, or 503 when none is configured
+fn require_cache(state: &AdminState) -> Result<&Arc<dyn CacheProvider>, CacheError> {
+    state.cache.as_ref().ok_or_else(|| {
+        (
+            Status::ServiceUnavailable,
+            Json(CacheErrorResponse {
+                error: "Cache provider not available".to_string(),
+            }),
+        )
+    })
+}
+
+fn cache_error(e: mcb_domain::error::Error) -> CacheError {
+    (
+        Status::InternalServerError,
+        Json(CacheErrorResponse {
+            error: e.to_string(),
+        }),
+    )
+}
+
 /// Get cache statistics (protected)
 ///
 /// Returns cache hit/miss rates, entry counts, and other metrics.
@@ -441,26 +487,63 @@ pub struct CacheErrorResponse {
 pub async fn get_cache_stats(
     _auth: AdminAuth,
     state: &State<AdminState>,
-) -> Result<
-    Json<mcb_application::ports::providers::cache::CacheStats>,
-    (Status, Json<CacheErrorResponse>),
-> {
-    let Some(cache) = &state.cache else {
-        return Err((
-            Status::ServiceUnavailable,
-            Json(CacheErrorResponse {
-                error: "Cache provider not available".to_string(),
-            }),
-        ));
-    };
+) -> Result<Json<mcb_application::ports::providers::cache::CacheStats>, CacheError> {
+    let stats = require_cache(state)?.stats().await.map_err(cache_error)?;
+    Ok(Json(stats))
+}
 
-    match cache.stats().await {
-        Ok(stats) => Ok(Json(stats)),
-        Err(e) => Err((
-            Status::InternalServerError,
-            Json(CacheErrorResponse {
-                error: e.to_string(),
-            }),
-        )),
-    }
+/// Response for `DELETE /cache/namespaces/<namespace>`
+#[derive(Serialize)]
+pub struct ClearNamespaceResponse {
+    /// Cleared namespace
+    pub namespace: String,
+    /// Number of entries removed
+    pub removed: u64,
+}
+
+/// Get entry counts per cache namespace (protected)
+#[get("/cache/namespaces")]
+pub async fn list_cache_namespaces(
+    _auth: AdminAuth,
+    state: &State<AdminState>,
+) -> Result<Json<Vec<NamespaceStats>>, CacheError> {
+    let stats = require_cache(state)?
+        .stats_by_namespace()
+        .await
+        .map_err(cache_error)?;
+    Ok(Json(stats))
+}
+
+/// List the keys in a cache namespace (protected)
+#[get("/cache/namespaces/<namespace>/keys")]
+pub async fn list_cache_keys(
+    _auth: AdminAuth,
+    state: &State<AdminState>,
+    namespace: &str,
+) -> Result<Json<Vec<String>>, CacheError> {
+    let keys = require_cache(state)?
+        .list_keys(namespace)
+        .await
+        .map_err(cache_error)?;
+    Ok(Json(keys))
+}
+
+/// Remove every entry in a cache namespace (protected)
+///
+/// Other namespaces, such as the embedding cache, are left untouched.
+#[delete("/cache/namespaces/<namespace>")]
+pub async fn clear_cache_namespace(
+    _auth: AdminAuth,
+    state: &State<AdminState>,
+    namespace: &str,
+) -> Result<Json<ClearNamespaceResponse>, CacheError> {
+    let removed = require_cache(state)?
+        .clear_namespace(namespace)
+        .await
+        .map_err(cache_error)?;
+    info!(namespace = %namespace, removed, "Cache namespace cleared");
+    Ok(Json(ClearNamespaceResponse {
+        namespace: namespace.to_string(),
+        removed,
+    }))
 }
@@ -19,37 +19,84 @@
 //! | `/health` | GET | Health check with uptime |
 //! | `/health/extended` | GET | Extended health with dependency status |
 //! | `/metrics` | GET | Performance metrics |
-//! | `/indexing` | GET | Indexing operations status |
+//! | `/indexing` | GET | Indexing operations on readable collections |
 //! | `/ready` | GET | Kubernetes readiness probe |
 //! | `/live` | GET | Kubernetes liveness probe |
 //! | `/shutdown` | POST | Initiate graceful server shutdown |
 //! | `/config` | GET | Current configuration (sanitized) |
 //! | `/config/reload` | POST | Reload configuration from file |
 //! | `/config/:section` | PATCH | Update a configuration section |
+//! | `/auth/login` | POST | User login returning JWTs (user database only) |
+//! | `/users` | GET/POST | List and create users (user database only) |
+//! | `/users/:username` | GET/PATCH/DELETE | Manage a user (user database only) |
+//! | `/audit` | GET | Query the audit log (audit enabled only) |
+//! | `/metrics/rate-limits` | GET | Rate limiter state (rate limiting only) |
+//! | `/health/providers` | GET | Provider health and circuit breakers |
+//! | `/embedding/routing` | GET | Embedding failover members and active provider per collection |
+//! | `/embedding/usage` | GET | Estimated embedding tokens per provider and model |
+//! | `/providers` | GET | Active and available providers |
+//! | `/providers/collections` | GET | Provider, model and dimensions per collection |
+//! | `/providers/embedding/switch` | POST | Switch embedding provider, optionally migrating collections |
+//! | `/providers/embedding/migration` | GET | Latest collection migration status |
+//! | `/providers/vector-store/switch` | POST | Switch vector store provider |
+//! | `/bundles/export` | POST | Export a collection to a bundle file |
+//! | `/bundles/import` | POST | Import a bundle file into the active vector store |
+//! | `/backups` | GET/POST | List backups or write one now |
+//! | `/backups/restore` | POST | Restore a backup |
+//! | `/encryption/reencrypt` | GET/POST | Re-encryption status, or re-encrypt a collection with the active key |
+//! | `/events` | GET | SSE event stream, resumable with `Last-Event-ID` when the event store is set |
+//! | `/events/history` | GET | Query past domain events (event store only) |
 
 pub mod api;
+pub mod audit;
 pub mod auth;
+pub mod backups;
 pub mod browse_handlers;
+pub mod bundles;
 pub mod config;
 pub mod config_handlers;
+pub mod embedding_routing;
+pub mod embedding_usage;
+pub mod encryption;
+pub mod events;
 pub mod handlers;
 pub mod lifecycle_handlers;
 pub mod models;
 pub mod propagation;
+pub mod provider_health;
+pub mod providers;
+pub mod rate_limit;
 pub mod routes;
 pub mod sse;
+pub mod user_handlers;
 pub mod web;
 
 // Re-export main types
 pub use api::{AdminApi, AdminApiConfig};
-pub use auth::{AdminAuthConfig, AuthErrorResponse, with_admin_auth};
+pub use audit::{AuditFairing, AuditState, AuditTrail};
+pub use auth::{AdminAuthConfig, AuthErrorResponse, CollectionAccess, with_admin_auth};
+pub use backups::BackupAdminState;
 pub use browse_handlers::BrowseState;
+pub use bundles::BundleAdminState;
 pub use config::{
     ConfigReloadResponse, ConfigResponse, ConfigSectionUpdateRequest, ConfigSectionUpdateResponse,
     SanitizedConfig,
 };
+pub use embedding_routing::EmbeddingRoutingState;
+pub use embedding_usage::EmbeddingUsageState;
+pub use encryption::EncryptionAdminState;
+pub use events::EventStoreState;
 pub use handlers::AdminState;
 pub use models::{AdminActionResponse, CollectionStats, ServerInfo};
 pub use propagation::{ConfigPropagator, PropagatorHandle};
-pub use routes::admin_rocket;
+pub use provider_health::ProviderHealthState;
+pub use providers::ProviderAdminState;
+pub use rate_limit::RateLimit;
+pub use routes::{
+    admin_rocket, with_audit_routes, with_backup_routes, with_bundle_routes,
+    with_collection_authorizer, with_embedding_routing, with_embedding_usage,
+    with_encryption_routes, with_event_routes, with_provider_admin, with_provider_health,
+    with_rate_limiter, with_user_routes,
+};
+pub use user_handlers::UserAuthState;
 pub use web::{web_rocket, web_routes};
@@ -0,0 +1,86 @@
+//! Provider health reporting
+//!
+//! Exposes router health and circuit breaker state for embedding and
+//! vector store providers. Breakers also appear as dependencies in
+//! `/health/extended` when this state is mounted.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/health/providers` | GET | Router health and circuit breakers (protected) |
+
+use mcb_application::ports::admin::{DependencyHealth, DependencyHealthCheck};
+use mcb_domain::ports::infrastructure::{ProviderHealthStatus, ProviderRouter};
+use mcb_infrastructure::resilience::{CircuitBreakerSnapshot, CircuitState, ProviderResilience};
+use rocket::serde::json::Json;
+use rocket::{State, get};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::auth::AdminAuth;
+
+/// Provider router and resilience layer shared with the providers
+#[derive(Clone)]
+pub struct ProviderHealthState {
+    /// Router receiving provider call outcomes
+    pub router: Arc<dyn ProviderRouter>,
+    /// Retry/circuit breaker layer (None when disabled)
+    pub resilience: Option<Arc<ProviderResilience>>,
+}
+
+impl ProviderHealthState {
+    /// Current breaker snapshots
+    pub fn circuit_breakers(&self) -> Vec<CircuitBreakerSnapshot> {
+        self.resilience
+            .as_ref()
+            .map(|resilience| resilience.snapshot())
+            .unwrap_or_default()
+    }
+
+    /// One dependency check per breaker, for `/health/extended`
+    pub fn dependency_checks(&self, now: u64) -> Vec<DependencyHealthCheck> {
+        self.circuit_breakers()
+            .into_iter()
+            .map(|breaker| DependencyHealthCheck {
+                name: format!("{}:{}", breaker.kind, breaker.provider),
+                status: match breaker.state {
+                    CircuitState::Open => DependencyHealth::Unhealthy,
+                    CircuitState::HalfOpen => DependencyHealth::Degraded,
+                    CircuitState::Closed if breaker.consecutive_failures > 0 => {
+                        DependencyHealth::Degraded
+                    }
+                    CircuitState::Closed => DependencyHealth::Healthy,
+                },
+                message: Some(format!(
+                    "Circuit {}, consecutive failures: {}, trips: {}",
+                    breaker.state, breaker.consecutive_failures, breaker.trips
+                )),
+                latency_ms: None,
+                last_check: now,
+            })
+            .collect()
+    }
+}
+
+/// Response for `/health/providers`
+#[derive(Serialize)]
+pub struct ProviderHealthResponse {
+    /// Router health per provider name
+    pub providers: HashMap<String, ProviderHealthStatus>,
+    /// Circuit breaker per provider
+    pub circuit_breakers: Vec<CircuitBreakerSnapshot>,
+}
+
+/// Router health and circuit breaker state (protected)
+#[get("/health/providers")]
+pub async fn get_provider_health(
+    _auth: AdminAuth,
+    state: &State<ProviderHealthState>,
+) -> Json<ProviderHealthResponse> {
+    Json(ProviderHealthResponse {
+        providers: state.router.get_all_health().await.unwrap_or_default(),
+        circuit_breakers: state.circuit_breakers(),
+    })
+}
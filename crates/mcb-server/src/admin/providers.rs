@@ -0,0 +1,268 @@
+//! Provider switching
+//!
+//! Lists and switches the embedding and vector store providers at runtime.
+//! Every collection records the embedding provider, model and dimensions it
+//! was built with; a switch that would leave collections in another
+//! embedding space is refused unless a migration is requested.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/providers` | GET | Active and available providers |
+//! | `/providers/collections` | GET | Provider, model and dimensions per collection |
+//! | `/providers/embedding/switch` | POST | Switch embedding provider (200, 202 migrating, 409 refused) |
+//! | `/providers/embedding/migration` | GET | Status of the latest migration |
+//! | `/providers/vector-store/switch` | POST | Switch vector store provider (200, 409 refused) |
+
+use mcb_application::ports::registry::{EmbeddingProviderConfig, VectorStoreProviderConfig};
+use mcb_domain::error::Error;
+use mcb_infrastructure::collections::{
+    CollectionGuard, CollectionRecord, MigrationStatus, SwitchOutcome,
+};
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::{State, get, post};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+
+use super::audit::AuditTrail;
+use super::auth::AdminAuth;
+
+/// Collection guard shared with the context service
+#[derive(Clone)]
+pub struct ProviderAdminState {
+    /// Guard owning the provider handles and collection registry
+    pub guard: Arc<CollectionGuard>,
+}
+
+/// A registered provider
+#[derive(Debug, Serialize)]
+pub struct ProviderEntry {
+    /// Provider name
+    pub name: String,
+    /// Provider description
+    pub description: String,
+}
+
+/// Active and available providers of one kind
+#[derive(Debug, Serialize)]
+pub struct ProviderKindInfo {
+    /// Provider currently in use
+    pub current: String,
+    /// Providers that can be switched to
+    pub available: Vec<ProviderEntry>,
+}
+
+/// Response for `GET /providers`
+#[derive(Debug, Serialize)]
+pub struct ProvidersResponse {
+    /// Embedding providers
+    pub embedding: ProviderKindInfo,
+    /// Vector store providers
+    pub vector_store: ProviderKindInfo,
+}
+
+/// Request body for `POST /providers/embedding/switch`
+#[derive(Debug, Deserialize)]
+pub struct EmbeddingSwitchRequest {
+    /// Provider name
+    pub provider: String,
+    /// Model name
+    pub model: Option<String>,
+    /// Custom endpoint URL
+    pub base_url: Option<String>,
+    /// API key
+    pub api_key: Option<String>,
+    /// Embedding dimensions
+    pub dimensions: Option<usize>,
+    /// Re-embed incompatible collections instead of refusing the switch
+    #[serde(default)]
+    pub migrate: bool,
+}
+
+impl EmbeddingSwitchRequest {
+    fn to_config(&self) -> EmbeddingProviderConfig {
+        let mut config = EmbeddingProviderConfig::new(&self.provider);
+        if let Some(model) = &self.model {
+            config = config.with_model(model);
+        }
+        if let Some(base_url) = &self.base_url {
+            config = config.with_base_url(base_url);
+        }
+        if let Some(api_key) = &self.api_key {
+            config = config.with_api_key(api_key);
+        }
+        if let Some(dimensions) = self.dimensions {
+            config = config.with_dimensions(dimensions);
+        }
+        config
+    }
+}
+
+/// Request body for `POST /providers/vector-store/switch`
+#[derive(Debug, Deserialize)]
+pub struct VectorStoreSwitchRequest {
+    /// Provider name
+    pub provider: String,
+    /// Connection URI
+    pub uri: Option<String>,
+    /// Default collection
+    pub collection: Option<String>,
+    /// Vector dimensions
+    pub dimensions: Option<usize>,
+}
+
+impl VectorStoreSwitchRequest {
+    fn to_config(&self) -> VectorStoreProviderConfig {
+        let mut config = VectorStoreProviderConfig::new(&self.provider);
+        if let Some(uri) = &self.uri {
+            config = config.with_uri(uri);
+        }
+        if let Some(collection) = &self.collection {
+            config = config.with_collection(collection);
+        }
+        if let Some(dimensions) = self.dimensions {
+            config = config.with_dimensions(dimensions);
+        }
+        config
+    }
+}
+
+/// Error response for provider switching
+#[derive(Debug, Serialize)]
+pub struct ProviderErrorResponse {
+    /// Error message
+    pub error: String,
+    /// Error code for programmatic handling
+    pub code: &'static str,
+}
+
+type SwitchResult = Result<(Status, Json<SwitchOutcome>), (Status, Json<ProviderErrorResponse>)>;
+
+fn map_error(e: Error) -> (Status, Json<ProviderErrorResponse>) {
+    let (status, code) = match &e {
+        Error::Configuration { .. } => (Status::BadRequest, "INVALID_PROVIDER"),
+        Error::InvalidArgument { .. } => (Status::Conflict, "MIGRATION_RUNNING"),
+        _ => (Status::InternalServerError, "INTERNAL_ERROR"),
+    };
+    (
+        status,
+        Json(ProviderErrorResponse {
+            error: e.to_string(),
+            code,
+        }),
+    )
+}
+
+fn switch_status(outcome: &SwitchOutcome) -> Status {
+    match outcome {
+        SwitchOutcome::Switched { .. } => Status::Ok,
+        SwitchOutcome::Refused { .. } => Status::Conflict,
+        SwitchOutcome::Migrating { .. } => Status::Accepted,
+    }
+}
+
+fn entries(providers: Vec<(&'static str, &'static str)>) -> Vec<ProviderEntry> {
+    providers
+        .into_iter()
+        .map(|(name, description)| ProviderEntry {
+            name: name.to_string(),
+            description: description.to_string(),
+        })
+        .collect()
+}
+
+/// Active and available providers
+#[get("/providers")]
+pub fn list_providers(
+    _auth: AdminAuth,
+    state: &State<ProviderAdminState>,
+) -> Json<ProvidersResponse> {
+    let guard = &state.guard;
+    Json(ProvidersResponse {
+        embedding: ProviderKindInfo {
+            current: guard.current_embedding_provider(),
+            available: entries(guard.available_embedding_providers()),
+        },
+        vector_store: ProviderKindInfo {
+            current: guard.current_vector_store_provider(),
+            available: entries(guard.available_vector_store_providers()),
+        },
+    })
+}
+
+/// Provider, model and dimensions recorded for every collection
+#[get("/providers/collections")]
+pub async fn list_collection_records(
+    _auth: AdminAuth,
+    state: &State<ProviderAdminState>,
+) -> Json<Vec<CollectionRecord>> {
+    Json(state.guard.registry().list().await)
+}
+
+/// Switch the embedding provider
+///
+/// Responds 200 when switched, 409 with the conflicting collections when
+/// refused, and 202 when a migration was started.
+#[post("/providers/embedding/switch", format = "json", data = "<request>")]
+pub async fn switch_embedding_provider(
+    _auth: AdminAuth,
+    state: &State<ProviderAdminState>,
+    audit: AuditTrail<'_>,
+    request: Json<EmbeddingSwitchRequest>,
+) -> SwitchResult {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({
+        "provider": request.provider,
+        "model": request.model,
+        "dimensions": request.dimensions,
+        "migrate": request.migrate,
+    }));
+    let outcome = state
+        .guard
+        .switch_embedding(&request.to_config(), request.migrate)
+        .await
+        .map_err(map_error)?;
+    info!(provider = %request.provider, outcome = ?outcome, "Embedding provider switch requested");
+    Ok((switch_status(&outcome), Json(outcome)))
+}
+
+/// Status of the latest embedding migration
+#[get("/providers/embedding/migration")]
+pub fn get_migration_status(
+    _auth: AdminAuth,
+    state: &State<ProviderAdminState>,
+) -> Result<Json<MigrationStatus>, Status> {
+    state
+        .guard
+        .migration_status()
+        .map(Json)
+        .ok_or(Status::NotFound)
+}
+
+/// Switch the vector store provider
+///
+/// Refused with 409 while any collection exists in the current store.
+#[post("/providers/vector-store/switch", format = "json", data = "<request>")]
+pub async fn switch_vector_store_provider(
+    _auth: AdminAuth,
+    state: &State<ProviderAdminState>,
+    audit: AuditTrail<'_>,
+    request: Json<VectorStoreSwitchRequest>,
+) -> SwitchResult {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({
+        "provider": request.provider,
+        "collection": request.collection,
+        "dimensions": request.dimensions,
+    }));
+    let outcome = state
+        .guard
+        .switch_vector_store(&request.to_config())
+        .await
+        .map_err(map_error)?;
+    info!(provider = %request.provider, outcome = ?outcome, "Vector store provider switch requested");
+    Ok((switch_status(&outcome), Json(outcome)))
+}
@@ -0,0 +1,125 @@
+//! Admin API rate limiting
+//!
+//! Charges admin requests to the caller's token buckets. Authenticated
+//! routes are limited through [`AdminAuth`](super::auth::AdminAuth) and
+//! [`CollectionAccess`](super::auth::CollectionAccess); the public login
+//! routes use the [`RateLimit`] guard. Health probes are not limited.
+//!
+//! Rejected requests get `429 Too Many Requests` with a `Retry-After`
+//! header.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/metrics/rate-limits` | GET | Limiter counters and busiest buckets (protected) |
+
+use mcb_infrastructure::ratelimit::{RateLimitClass, RateLimited, RateLimiter, RateLimiterStats};
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::{Header, Status};
+use rocket::outcome::Outcome;
+use rocket::request::{self, FromRequest, Request};
+use rocket::serde::json::Json;
+use rocket::{Response, State, get};
+use std::sync::Arc;
+use tracing::warn;
+
+use super::auth::{AdminAuth, AdminAuthError, cached_principal};
+use crate::constants::{RETRY_AFTER_HEADER, SESSION_ID_HEADER};
+
+/// Rate limit decision, computed once per request
+struct RateLimitDecision(Result<(), RateLimited>);
+
+/// Charge the request once and return the decision
+pub(crate) async fn check_rate_limit(
+    request: &Request<'_>,
+) -> Result<(), (Status, AdminAuthError)> {
+    let decision = request
+        .local_cache_async(async { RateLimitDecision(charge(request).await) })
+        .await;
+    match &decision.0 {
+        Ok(()) => Ok(()),
+        Err(limited) => Err((
+            Status::TooManyRequests,
+            AdminAuthError::RateLimited(limited.retry_after_secs()),
+        )),
+    }
+}
+
+async fn charge(request: &Request<'_>) -> Result<(), RateLimited> {
+    let Some(limiter) = request.rocket().state::<Arc<RateLimiter>>() else {
+        return Ok(());
+    };
+
+    let principal = cached_principal(request)
+        .await
+        .as_ref()
+        .ok()
+        .map(|p| p.subject().to_string());
+    let client = request.client_ip().map(|ip| ip.to_string());
+    let keys = RateLimiter::caller_keys(
+        principal.as_deref(),
+        request.headers().get_one(SESSION_ID_HEADER),
+        client.as_deref(),
+    );
+
+    limiter
+        .check(RateLimitClass::Standard, &keys)
+        .inspect_err(|limited| {
+            warn!(
+                key = %limited.key,
+                path = %request.uri().path(),
+                "Admin request rate limited"
+            );
+        })
+}
+
+/// Request guard for routes that are public but still rate limited
+pub struct RateLimit;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RateLimit {
+    type Error = AdminAuthError;
+
+    async fn from_request(request: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        match check_rate_limit(request).await {
+            Ok(()) => Outcome::Success(RateLimit),
+            Err(e) => Outcome::Error(e),
+        }
+    }
+}
+
+/// Fairing adding `Retry-After` to rate-limited responses
+pub struct RetryAfterFairing;
+
+#[rocket::async_trait]
+impl Fairing for RetryAfterFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Rate Limit Retry-After",
+            kind: Kind::Response,
+        }
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        if response.status() != Status::TooManyRequests {
+            return;
+        }
+        let decision = request.local_cache(|| RateLimitDecision(Ok(())));
+        if let Err(limited) = &decision.0 {
+            response.set_header(Header::new(
+                RETRY_AFTER_HEADER,
+                limited.retry_after_secs().to_string(),
+            ));
+        }
+    }
+}
+
+/// Rate limiter counters and busiest buckets (protected)
+#[get("/metrics/rate-limits")]
+pub fn get_rate_limit_stats(
+    _auth: AdminAuth,
+    limiter: &State<Arc<RateLimiter>>,
+) -> Json<RateLimiterStats> {
+    Json(limiter.stats())
+}
@@ -5,23 +5,53 @@
 //! Migrated from Axum to Rocket in v0.1.2 (ADR-026).
 //! Authentication integration added in v0.1.2.
 //! Browse API added in v0.1.2 for code navigation.
+//! User login and management routes mounted via [`with_user_routes`].
+//! Audit trail and `/audit` query mounted via [`with_audit_routes`].
+//! Rate limiting enabled via [`with_rate_limiter`].
+//! Provider health and circuit breakers mounted via [`with_provider_health`].
+//! Embedding failover status mounted via [`with_embedding_routing`].
+//! Embedding token usage mounted via [`with_embedding_usage`].
+//! Provider switching and collection migration mounted via [`with_provider_admin`].
+//! Collection bundle export and import mounted via [`with_bundle_routes`].
+//! Backup listing, triggering and restore mounted via [`with_backup_routes`].
+//! Vector store re-encryption mounted via [`with_encryption_routes`].
+//! Event history and SSE replay mounted via [`with_event_routes`].
 
+use mcb_infrastructure::ratelimit::RateLimiter;
 use rocket::{Build, Rocket, routes};
 use std::sync::Arc;
 
+use super::audit::{AuditFairing, AuditState, query_audit};
 use super::auth::AdminAuthConfig;
+use super::backups::{BackupAdminState, list_backups, restore_backup, trigger_backup};
 use super::browse_handlers::{
     BrowseState, get_file_chunks, list_collection_files, list_collections,
 };
+use super::bundles::{BundleAdminState, export_bundle, import_bundle};
 use super::config_handlers::{get_config, reload_config, update_config_section};
+use super::embedding_routing::{EmbeddingRoutingState, get_embedding_routing};
+use super::embedding_usage::{EmbeddingUsageState, get_embedding_usage};
+use super::encryption::{EncryptionAdminState, list_reencryptions, start_reencryption};
+use super::events::{EventStoreState, query_event_history};
 use super::handlers::{
-    AdminState, extended_health_check, get_cache_stats, get_indexing_status, get_metrics,
-    health_check, liveness_check, readiness_check, shutdown,
+    AdminState, clear_cache_namespace, extended_health_check, get_cache_stats, get_indexing_status,
+    get_metrics, health_check, list_cache_keys, list_cache_namespaces, liveness_check,
+    readiness_check, shutdown,
 };
 use super::lifecycle_handlers::{
     list_services, restart_service, services_health, start_service, stop_service,
 };
+use super::provider_health::{ProviderHealthState, get_provider_health};
+use super::providers::{
+    ProviderAdminState, get_migration_status, list_collection_records, list_providers,
+    switch_embedding_provider, switch_vector_store_provider,
+};
+use super::rate_limit::{RetryAfterFairing, get_rate_limit_stats};
 use super::sse::events_stream;
+use super::user_handlers::{
+    UserAuthState, create_user, delete_user, get_user, list_users, login, refresh, update_user,
+};
+use crate::auth::CollectionAuthorizer;
 
 /// Create the admin API rocket instance
 ///
@@ -29,28 +59,32 @@ use super::sse::events_stream;
 /// - GET /health - Health check with uptime and status
 /// - GET /health/extended - Extended health check with dependency status
 /// - GET /metrics - Performance metrics
-/// - GET /indexing - Indexing operations status
+/// - GET /indexing - Indexing operations status (scoped)
 /// - GET /ready - Kubernetes readiness probe (public)
 /// - GET /live - Kubernetes liveness probe (public)
 /// - POST /shutdown - Initiate graceful server shutdown (protected)
 /// - GET /config - View current configuration (protected)
 /// - POST /config/reload - Trigger configuration reload (protected)
 /// - PATCH /config/:section - Update configuration section (protected)
-/// - GET /events - SSE event stream for real-time updates
+/// - GET /events - SSE event stream for real-time updates (`?last_event_id=` to resume)
 /// - GET /services - List registered services (protected)
 /// - GET /services/health - Health check all services (protected)
 /// - POST /services/:name/start - Start a service (protected)
 /// - POST /services/:name/stop - Stop a service (protected)
 /// - POST /services/:name/restart - Restart a service (protected)
 /// - GET /cache/stats - Cache statistics (protected)
-/// - GET /collections - List indexed collections (protected)
-/// - GET /collections/:name/files - List files in collection (protected)
-/// - GET /collections/:name/files/*path/chunks - Get file chunks (protected)
+/// - GET /cache/namespaces - Entry counts per cache namespace (protected)
+/// - GET /cache/namespaces/:namespace/keys - Keys in a cache namespace (protected)
+/// - DELETE /cache/namespaces/:namespace - Clear a cache namespace (protected)
+/// - GET /collections - List readable collections (scoped)
+/// - GET /collections/:name/files - List files in collection (scoped)
+/// - GET /collections/:name/files/*path/chunks - Get file chunks (scoped)
 ///
 /// # Authentication
 ///
 /// Protected endpoints require the `X-Admin-Key` header (or configured header name)
 /// with a valid API key. Public endpoints (health probes) are exempt.
+/// Scoped endpoints also accept bearer tokens with `read` on the collection.
 pub fn admin_rocket(
     state: AdminState,
     auth_config: Arc<AdminAuthConfig>,
@@ -85,6 +119,9 @@ pub fn admin_rocket(
             restart_service,
             // Cache management
             get_cache_stats,
+            list_cache_namespaces,
+            list_cache_keys,
+            clear_cache_namespace,
         ],
     );
 
@@ -98,3 +135,177 @@ pub fn admin_rocket(
 
     rocket
 }
+
+/// Mount user login and management routes
+///
+/// Routes:
+/// - POST /auth/login - Log in with username/password (public)
+/// - POST /auth/refresh - Refresh an access token (public)
+/// - GET /users - List users (admin role)
+/// - POST /users - Create a user (admin role)
+/// - GET /users/:username - Get a user (admin role)
+/// - PATCH /users/:username - Update a user (admin role)
+/// - DELETE /users/:username - Delete a user (admin role)
+///
+/// Managing [`UserAuthState`] also lets admin users authenticate every
+/// protected endpoint with a bearer token.
+pub fn with_user_routes(rocket: Rocket<Build>, users: UserAuthState) -> Rocket<Build> {
+    rocket.manage(users).mount(
+        "/",
+        routes![
+            login,
+            refresh,
+            list_users,
+            create_user,
+            get_user,
+            update_user,
+            delete_user,
+        ],
+    )
+}
+
+/// Manage the collection authorizer used to resolve bearer tokens
+///
+/// Lets scoped API keys and user tokens reach the browse API and, when
+/// they carry `collection:*:admin`, the protected admin endpoints.
+pub fn with_collection_authorizer(
+    rocket: Rocket<Build>,
+    authorizer: Arc<CollectionAuthorizer>,
+) -> Rocket<Build> {
+    rocket.manage(authorizer)
+}
+
+/// Record admin mutations to the audit log and mount the query route
+///
+/// Routes:
+/// - GET /audit - Query audit events (protected)
+///
+/// Every non-`GET` request handled by this instance is recorded, including
+/// requests rejected by authentication.
+pub fn with_audit_routes(rocket: Rocket<Build>, audit: AuditState) -> Rocket<Build> {
+    let fairing = AuditFairing::new(Arc::clone(&audit.log));
+    rocket
+        .manage(audit)
+        .attach(fairing)
+        .mount("/", routes![query_audit])
+}
+
+/// Rate limit admin requests and mount the limiter metrics route
+///
+/// Routes:
+/// - GET /metrics/rate-limits - Limiter counters and busiest buckets (protected)
+///
+/// Pass the MCP server's limiter to share buckets with the HTTP transport.
+pub fn with_rate_limiter(rocket: Rocket<Build>, limiter: Arc<RateLimiter>) -> Rocket<Build> {
+    rocket
+        .manage(limiter)
+        .attach(RetryAfterFairing)
+        .mount("/", routes![get_rate_limit_stats])
+}
+
+/// Expose provider router health and circuit breakers
+///
+/// Routes:
+/// - GET /health/providers - Router health and circuit breakers (protected)
+///
+/// Breakers are also listed as dependencies in `/health/extended`.
+pub fn with_provider_health(rocket: Rocket<Build>, health: ProviderHealthState) -> Rocket<Build> {
+    rocket
+        .manage(health)
+        .mount("/", routes![get_provider_health])
+}
+
+/// Expose the embedding failover chain
+///
+/// Routes:
+/// - GET /embedding/routing - Failover members and active provider per collection
+pub fn with_embedding_routing(
+    rocket: Rocket<Build>,
+    routing: EmbeddingRoutingState,
+) -> Rocket<Build> {
+    rocket
+        .manage(routing)
+        .mount("/", routes![get_embedding_routing])
+}
+
+/// Expose estimated embedding token usage
+///
+/// Routes:
+/// - GET /embedding/usage - Estimated token totals per provider and model
+pub fn with_embedding_usage(rocket: Rocket<Build>, usage: EmbeddingUsageState) -> Rocket<Build> {
+    rocket
+        .manage(usage)
+        .mount("/", routes![get_embedding_usage])
+}
+
+/// Expose provider switching through the collection guard
+///
+/// Routes:
+/// - GET /providers - Active and available providers
+/// - GET /providers/collections - Provider, model and dimensions per collection
+/// - POST /providers/embedding/switch - Switch (or migrate to) an embedding provider
+/// - GET /providers/embedding/migration - Latest migration status
+/// - POST /providers/vector-store/switch - Switch the vector store provider
+pub fn with_provider_admin(rocket: Rocket<Build>, providers: ProviderAdminState) -> Rocket<Build> {
+    rocket.manage(providers).mount(
+        "/",
+        routes![
+            list_providers,
+            list_collection_records,
+            switch_embedding_provider,
+            get_migration_status,
+            switch_vector_store_provider
+        ],
+    )
+}
+
+/// Expose collection bundle export and import
+///
+/// Routes:
+/// - POST /bundles/export - Write a collection to a bundle file
+/// - POST /bundles/import - Import a bundle file into the active vector store
+pub fn with_bundle_routes(rocket: Rocket<Build>, bundles: BundleAdminState) -> Rocket<Build> {
+    rocket
+        .manage(bundles)
+        .mount("/", routes![export_bundle, import_bundle])
+}
+
+/// Expose backup listing, triggering and restore
+///
+/// Routes:
+/// - GET /backups - List backups, newest first
+/// - POST /backups - Write a backup now
+/// - POST /backups/restore - Restore a backup
+pub fn with_backup_routes(rocket: Rocket<Build>, backups: BackupAdminState) -> Rocket<Build> {
+    rocket
+        .manage(backups)
+        .mount("/", routes![list_backups, trigger_backup, restore_backup])
+}
+
+/// Expose vector store re-encryption
+///
+/// Routes:
+/// - GET /encryption/reencrypt - Latest re-encryption status per collection
+/// - POST /encryption/reencrypt - Re-encrypt a collection with the active key
+pub fn with_encryption_routes(
+    rocket: Rocket<Build>,
+    encryption: EncryptionAdminState,
+) -> Rocket<Build> {
+    rocket
+        .manage(encryption)
+        .mount("/", routes![list_reencryptions, start_reencryption])
+}
+
+/// Expose the domain event store
+///
+/// Routes:
+/// - GET /events/history - Query past domain events (protected)
+///
+/// Managing [`EventStoreState`] also makes `/events` tag every event with
+/// its sequence number and replay missed events to clients that resume
+/// with `Last-Event-ID`.
+pub fn with_event_routes(rocket: Rocket<Build>, events: EventStoreState) -> Rocket<Build> {
+    rocket
+        .manage(events)
+        .mount("/", routes![query_event_history])
+}
@@ -4,6 +4,16 @@
 //! Events are received from the TokioBroadcastEventBus and forwarded
 //! to connected SSE clients.
 //!
+//! ## Replay
+//!
+//! When the event store is mounted (see [`with_event_routes`]), events are
+//! read from the store instead and carry their sequence number as the SSE
+//! `id`. A reconnecting client sends it back in the `Last-Event-ID` header
+//! (or the `last_event_id` query parameter) and receives every retained
+//! event it missed before the live stream continues.
+//!
+//! [`with_event_routes`]: super::routes::with_event_routes
+//!
 //! ## Supported Events
 //!
 //! | Event Type | Description |
@@ -30,21 +40,84 @@
 
 use futures::StreamExt;
 use mcb_domain::events::DomainEvent;
+use rocket::request::{FromRequest, Outcome, Request};
 use rocket::response::stream::{Event, EventStream};
 use rocket::{State, get};
+use std::sync::Arc;
 use tracing::{debug, warn};
 
+use super::events::EventStoreState;
 use super::handlers::AdminState;
 
+/// Header sent by reconnecting `EventSource` clients
+const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+/// Sequence number from the `Last-Event-ID` header, if present and numeric
+pub struct LastEventId(pub Option<u64>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for LastEventId {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let id = request
+            .headers()
+            .get_one(LAST_EVENT_ID_HEADER)
+            .and_then(|value| value.trim().parse().ok());
+        Outcome::Success(LastEventId(id))
+    }
+}
+
 /// SSE event stream handler
 ///
 /// Streams domain events to connected clients in real-time.
-/// Uses the EventBusProvider's subscribe_events() method to receive events.
-#[get("/events")]
-pub async fn events_stream(state: &State<AdminState>) -> EventStream![] {
+/// Uses the EventBusProvider's subscribe_events() method to receive events,
+/// or the event store when one is mounted so clients can resume.
+#[get("/events?<last_event_id>")]
+pub async fn events_stream(
+    state: &State<AdminState>,
+    event_store: Option<&State<EventStoreState>>,
+    header_id: LastEventId,
+    last_event_id: Option<u64>,
+) -> EventStream![] {
     let event_bus = state.event_bus.clone();
+    let event_store = event_store.map(|s| Arc::clone(&s.store));
+    let resume_after = header_id.0.or(last_event_id);
 
     EventStream! {
+        if let Some(store) = event_store {
+            let mut stored_stream = match store.subscribe_after(resume_after).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!("Failed to subscribe to event store: {}", e);
+                    yield Event::data(format!("Failed to subscribe: {}", e))
+                        .event("error");
+                    return;
+                }
+            };
+
+            debug!(
+                resume_after = ?resume_after,
+                "SSE client connected, streaming stored events"
+            );
+
+            while let Some(stored) = stored_stream.next().await {
+                let event_data = match serde_json::to_string(&stored.event) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        warn!("Failed to serialize event: {}", e);
+                        continue;
+                    }
+                };
+                yield Event::data(event_data)
+                    .event(stored.event.name())
+                    .id(stored.sequence.to_string());
+            }
+
+            debug!("SSE event stream closed");
+            return;
+        }
+
         // Subscribe to domain events
         let mut event_stream = match event_bus.subscribe_events().await {
             Ok(stream) => stream,
@@ -80,29 +153,5 @@ pub async fn events_stream(state: &State<AdminState>) -> EventStream![] {
 
 /// Get the event name string for SSE event type header
 pub fn get_event_name(event: &DomainEvent) -> &'static str {
-    match event {
-        // Indexing events
-        DomainEvent::IndexRebuild { .. } => "IndexRebuild",
-        DomainEvent::IndexingStarted { .. } => "IndexingStarted",
-        DomainEvent::IndexingProgress { .. } => "IndexingProgress",
-        DomainEvent::IndexingCompleted { .. } => "IndexingCompleted",
-        // Sync events
-        DomainEvent::SyncCompleted { .. } => "SyncCompleted",
-        // Cache events
-        DomainEvent::CacheInvalidate { .. } => "CacheInvalidate",
-        // Snapshot events
-        DomainEvent::SnapshotCreated { .. } => "SnapshotCreated",
-        // File watcher events
-        DomainEvent::FileChangesDetected { .. } => "FileChangesDetected",
-        // Service lifecycle events
-        DomainEvent::ServiceStateChanged { .. } => "ServiceStateChanged",
-        // Configuration events
-        DomainEvent::ConfigReloaded { .. } => "ConfigReloaded",
-        // Health events
-        DomainEvent::HealthCheckCompleted { .. } => "HealthCheckCompleted",
-        // Metrics events
-        DomainEvent::MetricsSnapshot { .. } => "MetricsSnapshot",
-        // Search events
-        DomainEvent::SearchExecuted { .. } => "SearchExecuted",
-    }
+    event.name()
 }
@@ -0,0 +1,228 @@
+//! User management and login handlers
+//!
+//! Endpoints backed by the local user database (`auth.user_db_path`).
+//! Mounted only when a [`UserAuthState`] is available.
+//!
+//! ## Endpoints
+//!
+//! | Path | Method | Description |
+//! |------|--------|-------------|
+//! | `/auth/login` | POST | Exchange username/password for tokens (public) |
+//! | `/auth/refresh` | POST | Exchange a refresh token for new tokens (public) |
+//! | `/users` | GET | List users |
+//! | `/users` | POST | Create a user |
+//! | `/users/:username` | GET | Get a user |
+//! | `/users/:username` | PATCH | Update password, role, scopes or disabled flag |
+//! | `/users/:username` | DELETE | Delete a user |
+
+use mcb_domain::error::Error;
+use mcb_infrastructure::auth::{TokenPair, UserAuthService, UserInfo, UserRole, UserUpdate};
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::{State, delete, get, patch, post};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+
+use super::audit::AuditTrail;
+use super::auth::UserAdminAuth;
+use super::rate_limit::RateLimit;
+
+/// User handler state containing the user authentication service
+#[derive(Clone)]
+pub struct UserAuthState {
+    /// Login and user management service
+    pub service: Arc<UserAuthService>,
+}
+
+/// Error response for login and user management
+#[derive(Debug, Serialize)]
+pub struct UserErrorResponse {
+    /// Error message
+    pub error: String,
+    /// Error code for programmatic handling
+    pub code: &'static str,
+}
+
+type UserResult<T> = Result<Json<T>, (Status, Json<UserErrorResponse>)>;
+
+fn map_error(e: Error) -> (Status, Json<UserErrorResponse>) {
+    let (status, code) = match &e {
+        Error::Authentication { .. } => (Status::Unauthorized, "UNAUTHORIZED"),
+        Error::NotFound { .. } => (Status::NotFound, "NOT_FOUND"),
+        Error::InvalidArgument { .. } => (Status::BadRequest, "INVALID_ARGUMENT"),
+        _ => (Status::InternalServerError, "INTERNAL_ERROR"),
+    };
+    (
+        status,
+        Json(UserErrorResponse {
+            error: e.to_string(),
+            code,
+        }),
+    )
+}
+
+/// Login request body
+#[derive(Deserialize)]
+pub struct LoginRequest {
+    /// Username
+    pub username: String,
+    /// Password
+    pub password: String,
+}
+
+/// Refresh request body
+#[derive(Deserialize)]
+pub struct RefreshRequest {
+    /// Refresh token from a previous login or refresh
+    pub refresh_token: String,
+}
+
+/// Create user request body
+#[derive(Deserialize)]
+pub struct CreateUserRequest {
+    /// Username
+    pub username: String,
+    /// Initial password
+    pub password: String,
+    /// Role (defaults to viewer)
+    #[serde(default)]
+    pub role: Option<UserRole>,
+    /// Collection scopes (defaults to the role's scopes)
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// Response for listing users
+#[derive(Serialize)]
+pub struct UserListResponse {
+    /// Users without password hashes
+    pub users: Vec<UserInfo>,
+    /// Total number of users
+    pub total: usize,
+}
+
+/// Log in with username and password
+///
+/// Returns an access token for the `Authorization: Bearer` header and a
+/// refresh token. This endpoint is public.
+#[post("/auth/login", format = "json", data = "<request>")]
+pub async fn login(
+    _limit: RateLimit,
+    state: &State<UserAuthState>,
+    audit: AuditTrail<'_>,
+    request: Json<LoginRequest>,
+) -> UserResult<TokenPair> {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({ "username": request.username }));
+    let tokens = state
+        .service
+        .login(&request.username, &request.password)
+        .await
+        .map_err(map_error)?;
+    info!(username = %request.username, "User logged in");
+    Ok(Json(tokens))
+}
+
+/// Exchange a refresh token for a new token pair
+#[post("/auth/refresh", format = "json", data = "<request>")]
+pub async fn refresh(
+    _limit: RateLimit,
+    state: &State<UserAuthState>,
+    request: Json<RefreshRequest>,
+) -> UserResult<TokenPair> {
+    state
+        .service
+        .refresh(&request.refresh_token)
+        .await
+        .map(Json)
+        .map_err(map_error)
+}
+
+/// List all users (protected)
+#[get("/users")]
+pub async fn list_users(
+    _auth: UserAdminAuth,
+    state: &State<UserAuthState>,
+) -> Json<UserListResponse> {
+    let users = state.service.list_users().await;
+    let total = users.len();
+    Json(UserListResponse { users, total })
+}
+
+/// Get a single user (protected)
+#[get("/users/<username>")]
+pub async fn get_user(
+    _auth: UserAdminAuth,
+    state: &State<UserAuthState>,
+    username: &str,
+) -> UserResult<UserInfo> {
+    state
+        .service
+        .get_user(username)
+        .await
+        .map(Json)
+        .map_err(map_error)
+}
+
+/// Create a user (protected)
+#[post("/users", format = "json", data = "<request>")]
+pub async fn create_user(
+    _auth: UserAdminAuth,
+    state: &State<UserAuthState>,
+    audit: AuditTrail<'_>,
+    request: Json<CreateUserRequest>,
+) -> Result<(Status, Json<UserInfo>), (Status, Json<UserErrorResponse>)> {
+    let request = request.into_inner();
+    audit.arguments(serde_json::json!({
+        "username": request.username,
+        "role": request.role,
+        "scopes": request.scopes,
+    }));
+    let role = request.role.unwrap_or(UserRole::Viewer);
+    let user = state
+        .service
+        .create_user_with_scopes(&request.username, &request.password, role, request.scopes)
+        .await
+        .map_err(map_error)?;
+    info!(username = %user.username, role = role.as_str(), "User created");
+    Ok((Status::Created, Json(user)))
+}
+
+/// Update a user's password, role, scopes or disabled flag (protected)
+#[patch("/users/<username>", format = "json", data = "<request>")]
+pub async fn update_user(
+    _auth: UserAdminAuth,
+    state: &State<UserAuthState>,
+    username: &str,
+    audit: AuditTrail<'_>,
+    request: Json<UserUpdate>,
+) -> UserResult<UserInfo> {
+    let update = request.into_inner();
+    audit.arguments(serde_json::json!({ "username": username, "update": update }));
+    let user = state
+        .service
+        .update_user(username, update)
+        .await
+        .map_err(map_error)?;
+    info!(username = %username, "User updated");
+    Ok(Json(user))
+}
+
+/// Delete a user (protected)
+#[delete("/users/<username>")]
+pub async fn delete_user(
+    _auth: UserAdminAuth,
+    state: &State<UserAuthState>,
+    username: &str,
+    audit: AuditTrail<'_>,
+) -> Result<Status, (Status, Json<UserErrorResponse>)> {
+    audit.arguments(serde_json::json!({ "username": username }));
+    state
+        .service
+        .delete_user(username)
+        .await
+        .map_err(map_error)?;
+    info!(username = %username, "User deleted");
+    Ok(Status::NoContent)
+}
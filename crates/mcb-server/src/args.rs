@@ -131,6 +131,9 @@ pub struct ClearIndexArgs {
     #[schemars(description = "Name of the collection to clear")]
     #[serde(default = "default_collection")]
     pub collection: String,
+    /// Optional JWT token for authentication
+    #[schemars(description = "JWT token for authenticated requests")]
+    pub token: Option<String>,
 }
 
 fn default_limit() -> usize {
@@ -0,0 +1,88 @@
+//! Tool Call Auditing
+//!
+//! Records every MCP tool call to the audit log with the resolved principal,
+//! redacted arguments, outcome and duration. Recording failures are logged
+//! and never fail the tool call itself.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use mcb_domain::ports::infrastructure::{AuditEvent, AuditLogInterface, AuditOutcome, AuditSource};
+use mcb_infrastructure::audit::redact_arguments;
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolRequestParams, CallToolResult};
+use tracing::warn;
+
+use crate::auth::CollectionAuthorizer;
+use crate::constants::{JSONRPC_FORBIDDEN, JSONRPC_UNAUTHENTICATED};
+
+/// Writes tool call records to an audit log
+pub struct ToolCallAuditor {
+    log: Arc<dyn AuditLogInterface>,
+    authorizer: Arc<CollectionAuthorizer>,
+}
+
+impl ToolCallAuditor {
+    /// Create an auditor resolving principals through `authorizer`
+    pub fn new(log: Arc<dyn AuditLogInterface>, authorizer: Arc<CollectionAuthorizer>) -> Self {
+        Self { log, authorizer }
+    }
+
+    /// Record the outcome of a tool call
+    pub async fn record(
+        &self,
+        request: &CallToolRequestParams,
+        session: Option<&str>,
+        result: &Result<CallToolResult, McpError>,
+        elapsed: Duration,
+    ) {
+        let arguments = request
+            .arguments
+            .clone()
+            .map(serde_json::Value::Object)
+            .unwrap_or_default();
+        let token = arguments.get("token").and_then(|t| t.as_str());
+        let principal = self
+            .authorizer
+            .resolve(token)
+            .await
+            .map(|p| p.subject().to_string());
+
+        let (outcome, error) = match result {
+            Ok(result) if result.is_error.unwrap_or(false) => {
+                (AuditOutcome::Failure, Some(first_text(result)))
+            }
+            Ok(_) => (AuditOutcome::Success, None),
+            Err(e) if matches!(e.code.0, JSONRPC_UNAUTHENTICATED | JSONRPC_FORBIDDEN) => {
+                (AuditOutcome::Denied, Some(e.message.to_string()))
+            }
+            Err(e) => (AuditOutcome::Failure, Some(e.message.to_string())),
+        };
+
+        let event = AuditEvent {
+            timestamp: chrono::Utc::now(),
+            principal,
+            session: session.map(str::to_string),
+            source: AuditSource::Tool,
+            action: request.name.to_string(),
+            arguments: redact_arguments(&arguments),
+            outcome,
+            error,
+            duration_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+        };
+
+        if let Err(e) = self.log.record(event).await {
+            warn!(error = %e, tool = %request.name, "Failed to write audit event");
+        }
+    }
+}
+
+/// Text of the first content item of an error result
+fn first_text(result: &CallToolResult) -> String {
+    result
+        .content
+        .first()
+        .and_then(|content| serde_json::to_value(content).ok())
+        .and_then(|value| value.get("text")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| "Tool returned an error result".to_string())
+}
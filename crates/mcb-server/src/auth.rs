@@ -1,22 +1,174 @@
 //! Authentication and Authorization
 //!
-//! Handles user authentication and authorization for admin interfaces.
+//! Resolves the caller of an MCP tool call to a [`Principal`] and checks its
+//! `collection:<glob>:<level>` scopes before a handler touches a collection.
 //! Uses infrastructure auth services through dependency injection.
+//!
+//! Tokens are accepted from the tool's `token` argument; the HTTP transport
+//! also forwards `Authorization: Bearer` headers into that argument. A token
+//! is either a scoped API key from `auth.api_key.keys` or a user access token
+//! issued by the user database.
+//!
+//! When `auth.enabled` is false every caller is unrestricted.
+
+use std::sync::Arc;
+
+use mcb_infrastructure::auth::{AccessLevel, Principal, UserAuthService, parse_scopes};
+use mcb_infrastructure::config::AppConfig;
+use mcb_infrastructure::crypto::HashUtils;
+use rmcp::ErrorData as McpError;
+use rmcp::model::ErrorCode;
+use tracing::warn;
+
+use crate::constants::{
+    ERROR_CODE_COLLECTION_ACCESS_DENIED, ERROR_CODE_UNAUTHENTICATED, JSONRPC_FORBIDDEN,
+    JSONRPC_UNAUTHENTICATED,
+};
+
+/// A configured API key with its parsed principal
+struct ApiKeyPrincipal {
+    key: String,
+    principal: Principal,
+}
 
-/// Authentication handler for admin interfaces
-pub struct AuthHandler {
-    // Will be implemented with infrastructure auth integration
+/// Resolves tool-call tokens and enforces collection scopes
+pub struct CollectionAuthorizer {
+    enabled: bool,
+    api_keys: Vec<ApiKeyPrincipal>,
+    users: Option<Arc<UserAuthService>>,
 }
 
-impl Default for AuthHandler {
+impl Default for CollectionAuthorizer {
     fn default() -> Self {
-        Self::new()
+        Self::disabled()
     }
 }
 
-impl AuthHandler {
-    /// Create a new auth handler
-    pub fn new() -> Self {
-        Self {}
+impl CollectionAuthorizer {
+    /// An authorizer that lets every caller through
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            api_keys: Vec::new(),
+            users: None,
+        }
+    }
+
+    /// Build from configuration and the optional user database
+    ///
+    /// Fails if a configured API key carries an invalid scope.
+    pub fn from_config(
+        config: &AppConfig,
+        users: Option<Arc<UserAuthService>>,
+    ) -> mcb_domain::error::Result<Self> {
+        let api_keys = config
+            .auth
+            .api_key
+            .keys
+            .iter()
+            .map(|entry| {
+                Ok(ApiKeyPrincipal {
+                    key: entry.key.clone(),
+                    principal: Principal::new(entry.name.clone(), parse_scopes(&entry.scopes)?),
+                })
+            })
+            .collect::<mcb_domain::error::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            enabled: config.auth.enabled,
+            api_keys,
+            users,
+        })
+    }
+
+    /// Whether scopes are enforced
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
     }
+
+    /// Resolve a token to a principal
+    ///
+    /// Returns an unrestricted anonymous principal when authorization is
+    /// disabled. Returns `None` for missing, unknown or expired tokens.
+    pub async fn resolve(&self, token: Option<&str>) -> Option<Principal> {
+        if !self.enabled {
+            return Some(Principal::unrestricted("anonymous"));
+        }
+        self.resolve_token(token?).await
+    }
+
+    /// Resolve a token without considering whether enforcement is enabled
+    pub async fn resolve_token(&self, token: &str) -> Option<Principal> {
+        if let Some(entry) = self
+            .api_keys
+            .iter()
+            .find(|k| HashUtils::constant_time_eq(k.key.as_bytes(), token.as_bytes()))
+        {
+            return Some(entry.principal.clone());
+        }
+
+        match &self.users {
+            Some(users) => users.principal_for_token(token).await.ok(),
+            None => None,
+        }
+    }
+
+    /// Resolve the caller and require `level` on `collection`
+    ///
+    /// Denials are logged to the audit target with a stable error code and
+    /// returned as JSON-RPC errors carrying the same code in `data.code`.
+    pub async fn authorize(
+        &self,
+        token: Option<&str>,
+        tool: &str,
+        collection: &str,
+        level: AccessLevel,
+    ) -> Result<Principal, McpError> {
+        let Some(principal) = self.resolve(token).await else {
+            warn!(
+                target: "mcb::audit",
+                tool,
+                collection,
+                required = %level,
+                code = ERROR_CODE_UNAUTHENTICATED,
+                "Tool call denied: missing or invalid token"
+            );
+            return Err(McpError::new(
+                ErrorCode(JSONRPC_UNAUTHENTICATED),
+                "Authentication required: provide a valid token",
+                Some(serde_json::json!({ "code": ERROR_CODE_UNAUTHENTICATED })),
+            ));
+        };
+
+        if principal.can(collection, level) {
+            return Ok(principal);
+        }
+
+        warn!(
+            target: "mcb::audit",
+            principal = principal.subject(),
+            tool,
+            collection,
+            required = %level,
+            code = ERROR_CODE_COLLECTION_ACCESS_DENIED,
+            "Tool call denied: insufficient collection scope"
+        );
+        Err(collection_access_denied(collection, level))
+    }
+}
+
+/// JSON-RPC error for a caller lacking `level` on `collection`
+pub fn collection_access_denied(collection: &str, level: AccessLevel) -> McpError {
+    McpError::new(
+        ErrorCode(JSONRPC_FORBIDDEN),
+        format!(
+            "Access denied: '{}' access to collection '{}' is not granted",
+            level, collection
+        ),
+        Some(serde_json::json!({
+            "code": ERROR_CODE_COLLECTION_ACCESS_DENIED,
+            "collection": collection,
+            "required": level.as_str(),
+        })),
+    )
 }
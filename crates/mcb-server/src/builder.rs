@@ -4,7 +4,12 @@
 //! Ensures all required dependencies are provided before server construction.
 
 use crate::McpServer;
+use crate::auth::CollectionAuthorizer;
 use mcb_application::{ContextServiceInterface, IndexingServiceInterface, SearchServiceInterface};
+use mcb_domain::ports::infrastructure::AuditLogInterface;
+use mcb_infrastructure::backup::BackupService;
+use mcb_infrastructure::infrastructure::ServiceManager;
+use mcb_infrastructure::ratelimit::{ConnectionLimiter, RateLimiter};
 use std::sync::Arc;
 
 /// Builder for MCP Server with dependency injection
@@ -16,6 +21,12 @@ pub struct McpServerBuilder {
     indexing_service: Option<Arc<dyn IndexingServiceInterface>>,
     context_service: Option<Arc<dyn ContextServiceInterface>>,
     search_service: Option<Arc<dyn SearchServiceInterface>>,
+    authorizer: Option<Arc<CollectionAuthorizer>>,
+    audit_log: Option<Arc<dyn AuditLogInterface>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    connection_limiter: Option<Arc<ConnectionLimiter>>,
+    service_manager: Option<Arc<ServiceManager>>,
+    backup_service: Option<Arc<BackupService>>,
 }
 
 impl McpServerBuilder {
@@ -51,6 +62,72 @@ impl McpServerBuilder {
         self
     }
 
+    /// Set the collection authorizer
+    ///
+    /// Optional; without it collection scopes are not enforced.
+    ///
+    /// # Arguments
+    /// * `authorizer` - Resolves tool-call tokens and checks collection scopes
+    pub fn with_authorizer(mut self, authorizer: Arc<CollectionAuthorizer>) -> Self {
+        self.authorizer = Some(authorizer);
+        self
+    }
+
+    /// Set the audit log
+    ///
+    /// Optional; without it tool calls are not audited.
+    ///
+    /// # Arguments
+    /// * `log` - Destination for tool call audit records
+    pub fn with_audit_log(mut self, log: Arc<dyn AuditLogInterface>) -> Self {
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Set the rate limiter
+    ///
+    /// Optional; without it the HTTP transport does not limit requests.
+    ///
+    /// # Arguments
+    /// * `limiter` - Token buckets shared with the admin API
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Set the connection limiter
+    ///
+    /// Optional; without it the HTTP transport does not cap concurrency.
+    ///
+    /// # Arguments
+    /// * `limiter` - Concurrent connection and per-client request quotas
+    pub fn with_connection_limiter(mut self, limiter: Arc<ConnectionLimiter>) -> Self {
+        self.connection_limiter = Some(limiter);
+        self
+    }
+
+    /// Set the service manager
+    ///
+    /// Optional; without it background services are not tracked.
+    ///
+    /// # Arguments
+    /// * `manager` - Lifecycle of background services, shared with the admin API
+    pub fn with_service_manager(mut self, manager: Arc<ServiceManager>) -> Self {
+        self.service_manager = Some(manager);
+        self
+    }
+
+    /// Set the backup service
+    ///
+    /// Optional; without it the admin API cannot list or restore backups.
+    ///
+    /// # Arguments
+    /// * `service` - Scheduled backups, already registered with the service manager
+    pub fn with_backup_service(mut self, service: Arc<BackupService>) -> Self {
+        self.backup_service = Some(service);
+        self
+    }
+
     /// Build the MCP server
     ///
     /// # Returns
@@ -79,11 +156,36 @@ impl McpServerBuilder {
             .search_service
             .ok_or(BuilderError::MissingDependency("search service"))?;
 
-        Ok(McpServer::new(
+        let authorizer = self
+            .authorizer
+            .unwrap_or_else(|| Arc::new(CollectionAuthorizer::disabled()));
+
+        let server = McpServer::new_with_authorizer(
             indexing_service,
             context_service,
             search_service,
-        ))
+            authorizer,
+        );
+        let server = match self.audit_log {
+            Some(log) => server.with_audit_log(log),
+            None => server,
+        };
+        let server = match self.rate_limiter {
+            Some(limiter) => server.with_rate_limiter(limiter),
+            None => server,
+        };
+        let server = match self.connection_limiter {
+            Some(limiter) => server.with_connection_limiter(limiter),
+            None => server,
+        };
+        let server = match self.service_manager {
+            Some(manager) => server.with_service_manager(manager),
+            None => server,
+        };
+        Ok(match self.backup_service {
+            Some(service) => server.with_backup_service(service),
+            None => server,
+        })
     }
 }
 
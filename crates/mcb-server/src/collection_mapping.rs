@@ -27,7 +27,9 @@ const MAPPING_FILENAME: &str = "collection_mapping.json";
 const LOCK_FILENAME: &str = "collection_mapping.lock";
 
 /// Gets the default mapping file path (~/.config/mcb/collection_mapping.json)
-fn get_mapping_file_path() -> Result<PathBuf> {
+///
+/// Public so the backup service can include the mapping in backups.
+pub fn get_mapping_file_path() -> Result<PathBuf> {
     let config_dir =
         dirs::config_dir().ok_or_else(|| Error::io("Unable to determine config directory"))?;
 
@@ -21,3 +21,45 @@ pub const JSONRPC_INVALID_PARAMS: i32 = -32602;
 
 /// JSON-RPC Internal error code
 pub const JSONRPC_INTERNAL_ERROR: i32 = -32603;
+
+// ============================================================================
+// JSON-RPC ERROR CODES (Server-defined, -32000 to -32099)
+// ============================================================================
+
+/// Caller did not present a valid token
+pub const JSONRPC_UNAUTHENTICATED: i32 = -32001;
+
+/// Caller is authenticated but lacks the required scope
+pub const JSONRPC_FORBIDDEN: i32 = -32003;
+
+/// Caller exceeded its rate limit
+pub const JSONRPC_RATE_LIMITED: i32 = -32029;
+
+// ============================================================================
+// AUTHORIZATION ERROR CODES (machine-readable `code` in error payloads)
+// ============================================================================
+
+/// Missing, unknown or expired token
+pub const ERROR_CODE_UNAUTHENTICATED: &str = "UNAUTHENTICATED";
+
+/// Token lacks the `collection:<glob>:<level>` scope for the operation
+pub const ERROR_CODE_COLLECTION_ACCESS_DENIED: &str = "COLLECTION_ACCESS_DENIED";
+
+/// Caller reached an instance-wide admin endpoint without `collection:*:admin`
+pub const ERROR_CODE_ADMIN_ACCESS_DENIED: &str = "ADMIN_ACCESS_DENIED";
+
+/// Caller ran out of tokens in its rate limit bucket
+pub const ERROR_CODE_RATE_LIMITED: &str = "RATE_LIMITED";
+
+/// Caller exceeded the concurrent connection or request quota
+pub const ERROR_CODE_CONNECTION_LIMITED: &str = "CONNECTION_LIMITED";
+
+// ============================================================================
+// HTTP HEADERS
+// ============================================================================
+
+/// Header carrying the client session identifier
+pub const SESSION_ID_HEADER: &str = "X-Session-Id";
+
+/// Header telling rate-limited clients how many seconds to wait
+pub const RETRY_AFTER_HEADER: &str = "Retry-After";
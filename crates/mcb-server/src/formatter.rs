@@ -22,6 +22,17 @@ impl ResponseFormatter {
         results: &[SearchResult],
         duration: Duration,
         limit: usize,
+    ) -> Result<CallToolResult, McpError> {
+        Self::format_search_response_with_cache_hit(query, results, false, duration, limit)
+    }
+
+    /// Format search response, noting results served from the result cache
+    pub fn format_search_response_with_cache_hit(
+        query: &str,
+        results: &[SearchResult],
+        cache_hit: bool,
+        duration: Duration,
+        limit: usize,
     ) -> Result<CallToolResult, McpError> {
         let mut message = "🔍 **Semantic Code Search Results**\n\n".to_string();
         message.push_str(&format!("**Query:** \"{}\" \n", query));
@@ -29,6 +40,9 @@ impl ResponseFormatter {
             "**Search completed in:** {:.2}s\n",
             duration.as_secs_f64()
         ));
+        if cache_hit {
+            message.push_str("**Served from cache:** yes\n");
+        }
         message.push_str(&format!("**Results found:** {}\n\n", results.len()));
 
         if results.is_empty() {
@@ -38,6 +52,7 @@ impl ResponseFormatter {
         }
 
         tracing::info!(
+            cache_hit,
             "Search completed: found {} results in {:?}",
             results.len(),
             duration
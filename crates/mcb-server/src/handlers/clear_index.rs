@@ -9,20 +9,32 @@ use std::sync::Arc;
 use validator::Validate;
 
 use mcb_application::domain_services::search::IndexingServiceInterface;
+use mcb_infrastructure::auth::AccessLevel;
 
 use crate::args::ClearIndexArgs;
+use crate::auth::CollectionAuthorizer;
 use crate::collection_mapping::map_collection_name;
 use crate::formatter::ResponseFormatter;
 
 /// Handler for index clearing operations
 pub struct ClearIndexHandler {
     indexing_service: Arc<dyn IndexingServiceInterface>,
+    authorizer: Arc<CollectionAuthorizer>,
 }
 
 impl ClearIndexHandler {
     /// Create a new clear_index handler
     pub fn new(indexing_service: Arc<dyn IndexingServiceInterface>) -> Self {
-        Self { indexing_service }
+        Self {
+            indexing_service,
+            authorizer: Arc::new(CollectionAuthorizer::disabled()),
+        }
+    }
+
+    /// Require `admin` scope on the target collection
+    pub fn with_authorizer(mut self, authorizer: Arc<CollectionAuthorizer>) -> Self {
+        self.authorizer = authorizer;
+        self
     }
 
     /// Handle the clear_index tool request
@@ -37,6 +49,15 @@ impl ClearIndexHandler {
             ));
         }
 
+        self.authorizer
+            .authorize(
+                args.token.as_deref(),
+                "clear_index",
+                &args.collection,
+                AccessLevel::Admin,
+            )
+            .await?;
+
         // Map user-friendly name to Milvus-compatible name
         let milvus_collection = match map_collection_name(&args.collection) {
             Ok(name) => name,
@@ -11,8 +11,10 @@ use std::time::Instant;
 use validator::Validate;
 
 use mcb_application::domain_services::search::IndexingServiceInterface;
+use mcb_infrastructure::auth::AccessLevel;
 
 use crate::args::IndexCodebaseArgs;
+use crate::auth::CollectionAuthorizer;
 use crate::collection_mapping::map_collection_name;
 use crate::formatter::ResponseFormatter;
 
@@ -25,12 +27,22 @@ struct ValidatedRequest {
 /// Handler for codebase indexing operations
 pub struct IndexCodebaseHandler {
     indexing_service: Arc<dyn IndexingServiceInterface>,
+    authorizer: Arc<CollectionAuthorizer>,
 }
 
 impl IndexCodebaseHandler {
     /// Create a new index_codebase handler
     pub fn new(indexing_service: Arc<dyn IndexingServiceInterface>) -> Self {
-        Self { indexing_service }
+        Self {
+            indexing_service,
+            authorizer: Arc::new(CollectionAuthorizer::disabled()),
+        }
+    }
+
+    /// Require `write` scope on the target collection
+    pub fn with_authorizer(mut self, authorizer: Arc<CollectionAuthorizer>) -> Self {
+        self.authorizer = authorizer;
+        self
     }
 
     /// Validate arguments and resolve collection name
@@ -76,6 +88,15 @@ impl IndexCodebaseHandler {
             ));
         }
 
+        self.authorizer
+            .authorize(
+                args.token.as_deref(),
+                "index_codebase",
+                args.collection.as_deref().unwrap_or("default"),
+                AccessLevel::Write,
+            )
+            .await?;
+
         let request = match Self::validate_request(&args) {
             Ok(req) => req,
             Err(error_result) => return Ok(error_result),
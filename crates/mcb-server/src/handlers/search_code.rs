@@ -9,21 +9,33 @@ use std::sync::Arc;
 use std::time::Instant;
 use validator::Validate;
 
-use mcb_application::domain_services::search::SearchServiceInterface;
+use mcb_application::domain_services::search::{SearchFilters, SearchServiceInterface};
+use mcb_infrastructure::auth::AccessLevel;
 
 use crate::args::SearchCodeArgs;
+use crate::auth::CollectionAuthorizer;
 use crate::collection_mapping::map_collection_name;
 use crate::formatter::ResponseFormatter;
 
 /// Handler for code search operations
 pub struct SearchCodeHandler {
     search_service: Arc<dyn SearchServiceInterface>,
+    authorizer: Arc<CollectionAuthorizer>,
 }
 
 impl SearchCodeHandler {
     /// Create a new search_code handler
     pub fn new(search_service: Arc<dyn SearchServiceInterface>) -> Self {
-        Self { search_service }
+        Self {
+            search_service,
+            authorizer: Arc::new(CollectionAuthorizer::disabled()),
+        }
+    }
+
+    /// Require `read` scope on the searched collection
+    pub fn with_authorizer(mut self, authorizer: Arc<CollectionAuthorizer>) -> Self {
+        self.authorizer = authorizer;
+        self
     }
 
     /// Handle the search_code tool request
@@ -40,6 +52,14 @@ impl SearchCodeHandler {
         }
 
         let collection_name = args.collection.as_deref().unwrap_or("default");
+        self.authorizer
+            .authorize(
+                args.token.as_deref(),
+                "search_code",
+                collection_name,
+                AccessLevel::Read,
+            )
+            .await?;
 
         // Map user-friendly name to Milvus-compatible name
         let milvus_collection = match map_collection_name(collection_name) {
@@ -54,17 +74,44 @@ impl SearchCodeHandler {
 
         let timer = Instant::now();
 
-        let results = self
+        let response = self
             .search_service
-            .search(&milvus_collection, &args.query, args.limit)
+            .search_filtered(
+                &milvus_collection,
+                &args.query,
+                &search_filters(&args),
+                args.limit,
+            )
             .await
             .map_err(|e| McpError::internal_error(format!("Search failed: {}", e), None))?;
 
-        ResponseFormatter::format_search_response(
+        ResponseFormatter::format_search_response_with_cache_hit(
             &args.query,
-            &results,
+            &response.results,
+            response.cache_hit,
             timer.elapsed(),
             args.limit,
         )
     }
 }
+
+/// Result filters from `extensions` and `filters`
+///
+/// `exclude_patterns` is not applied.
+fn search_filters(args: &SearchCodeArgs) -> SearchFilters {
+    let filters = args.filters.as_ref();
+    let file_extensions = args
+        .extensions
+        .iter()
+        .chain(filters.and_then(|f| f.file_extensions.as_ref()))
+        .flatten()
+        .cloned()
+        .collect();
+    SearchFilters {
+        file_extensions,
+        languages: filters
+            .and_then(|f| f.languages.clone())
+            .unwrap_or_default(),
+        min_score: filters.and_then(|f| f.min_score).map(f64::from),
+    }
+}
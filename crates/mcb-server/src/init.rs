@@ -35,13 +35,24 @@
 use std::path::Path;
 use std::sync::Arc;
 
+use mcb_application::ports::infrastructure::AuditLogInterface;
+use mcb_domain::ports::admin::LifecycleManaged;
+use mcb_infrastructure::audit::JsonlAuditLog;
+use mcb_infrastructure::auth::{UserAuthService, UserInfo};
+use mcb_infrastructure::backup::BackupService;
 use mcb_infrastructure::cache::provider::SharedCacheProvider;
+use mcb_infrastructure::collections::{
+    BundleManifest, CollectionBundler, CollectionGuard, ExportOptions, ImportedBundle,
+};
 use mcb_infrastructure::config::{AppConfig, OperatingMode, TransportMode};
-use mcb_infrastructure::crypto::CryptoService;
+use mcb_infrastructure::crypto::{CryptoService, load_key};
+use mcb_infrastructure::infrastructure::ServiceManager;
+use mcb_infrastructure::ratelimit::{ConnectionLimiter, RateLimiter};
 use tracing::{error, info, warn};
 
 use crate::McpServer;
 use crate::McpServerBuilder;
+use crate::auth::CollectionAuthorizer;
 use crate::transport::http::{HttpTransport, HttpTransportConfig};
 use crate::transport::stdio::StdioServerExt;
 
@@ -89,6 +100,69 @@ pub async fn run_server(config_path: Option<&Path>) -> Result<(), Box<dyn std::e
     run(config_path, false).await
 }
 
+/// Create the first admin user in the configured user database
+///
+/// Loads configuration the same way as [`run`] and requires
+/// `auth.user_db_path` to be set. Fails if an enabled admin already exists,
+/// so provisioning scripts can run it unconditionally.
+pub async fn bootstrap_admin(
+    config_path: Option<&Path>,
+    username: &str,
+    password: &str,
+) -> Result<UserInfo, Box<dyn std::error::Error>> {
+    let config = load_config(config_path)?;
+    let service = UserAuthService::from_config(&config.auth)
+        .await?
+        .ok_or("auth.user_db_path is not configured; set it to enable the user database")?;
+
+    Ok(service.bootstrap_admin(username, password).await?)
+}
+
+/// Export a collection to a bundle file
+///
+/// Loads configuration the same way as [`run`] and reads the collection
+/// from the configured vector store. Encryption uses
+/// `system.data.collections.bundle_key`; the importing server needs the
+/// same key.
+pub async fn export_collection(
+    config_path: Option<&Path>,
+    collection: &str,
+    output: &Path,
+    options: ExportOptions,
+) -> Result<BundleManifest, Box<dyn std::error::Error>> {
+    let config = load_config(config_path)?;
+    let bundler = create_bundler(&config).await?;
+    Ok(bundler.export(collection, output, options).await?)
+}
+
+/// Import a bundle file into the configured vector store
+///
+/// Imports into `collection`, or into the collection the bundle was
+/// exported from. Refused when the configured embedding provider uses
+/// another model or dimensionality than the bundle.
+pub async fn import_collection(
+    config_path: Option<&Path>,
+    input: &Path,
+    collection: Option<&str>,
+) -> Result<ImportedBundle, Box<dyn std::error::Error>> {
+    let config = load_config(config_path)?;
+    let bundler = create_bundler(&config).await?;
+    Ok(bundler.import(input, collection).await?)
+}
+
+/// Bundler over freshly resolved providers
+async fn create_bundler(
+    config: &AppConfig,
+) -> Result<CollectionBundler, Box<dyn std::error::Error>> {
+    let crypto = bundle_crypto_service(config)?;
+    let app_context = mcb_infrastructure::di::bootstrap::init_app(config.clone()).await?;
+    let mut bundler = CollectionBundler::new(app_context.collection_guard());
+    if let Some(crypto) = crypto {
+        bundler = bundler.with_crypto(Arc::new(crypto));
+    }
+    Ok(bundler)
+}
+
 // =============================================================================
 // Operating Modes
 // =============================================================================
@@ -209,16 +283,46 @@ async fn create_mcp_server(config: AppConfig) -> Result<McpServer, Box<dyn std::
     // Create AppContext with resolved providers
     let app_context = mcb_infrastructure::di::bootstrap::init_app(config.clone()).await?;
 
-    // Get all providers from handles (runtime-swappable via admin API)
-    let embedding_provider = app_context.embedding_handle().get();
-    let vector_store_provider = app_context.vector_store_handle().get();
+    // Embedding and vector store calls go through the collection guard so
+    // provider switches take effect without mixing embedding spaces
+    let collection_guard = app_context.collection_guard();
+    let embedding_provider = collection_guard.embedding_provider();
+    let vector_store_provider = collection_guard.vector_store_provider();
     let cache_provider = app_context.cache_handle().get();
     let language_chunker = app_context.language_handle().get();
 
+    // Background services are started through the service manager so the
+    // admin API sees and controls the same instances
+    let service_manager = Arc::new(ServiceManager::new(app_context.event_bus()));
+
+    // Scheduled backups (disabled unless configured)
+    let backup_service = if config.system.data.backup.enabled {
+        Some(start_backup_service(&config, collection_guard.clone(), &service_manager).await?)
+    } else {
+        None
+    };
+
     // Create shared cache provider (conversion for domain services factory)
     let shared_cache = SharedCacheProvider::from_arc(cache_provider);
     let crypto = create_crypto_service(&config).await?;
 
+    // Collection scopes for tool calls (API keys and user database tokens)
+    let users = UserAuthService::from_config(&config.auth)
+        .await?
+        .map(Arc::new);
+    let authorizer = Arc::new(CollectionAuthorizer::from_config(&config, users)?);
+
+    // Audit trail for tool calls (disabled unless configured)
+    let audit_log = JsonlAuditLog::from_config(&config.system.infrastructure.audit)
+        .map(|log| Arc::new(log) as Arc<dyn AuditLogInterface>);
+
+    // Per-caller rate limits and concurrency quotas for the HTTP transport
+    let rate_limiter =
+        RateLimiter::from_config(&config.system.infrastructure.resilience).map(Arc::new);
+    let connection_limiter = Arc::new(ConnectionLimiter::from_config(
+        &config.system.infrastructure.limits,
+    ));
+
     // Create domain services with providers
     let deps = mcb_infrastructure::di::modules::domain_services::ServiceDependencies {
         cache: shared_cache,
@@ -229,15 +333,29 @@ async fn create_mcp_server(config: AppConfig) -> Result<McpServer, Box<dyn std::
         language_chunker,
     };
     let services =
-        mcb_infrastructure::di::modules::domain_services::DomainServicesFactory::create_services(
+        mcb_infrastructure::di::modules::domain_services::DomainServicesFactory::create_services_for(
+            &app_context,
             deps,
         )
         .await?;
 
-    McpServerBuilder::new()
+    let mut builder = McpServerBuilder::new()
         .with_indexing_service(services.indexing_service)
         .with_context_service(services.context_service)
         .with_search_service(services.search_service)
+        .with_authorizer(authorizer)
+        .with_connection_limiter(connection_limiter)
+        .with_service_manager(service_manager);
+    if let Some(service) = backup_service {
+        builder = builder.with_backup_service(service);
+    }
+    if let Some(log) = audit_log {
+        builder = builder.with_audit_log(log);
+    }
+    if let Some(limiter) = rate_limiter {
+        builder = builder.with_rate_limiter(limiter);
+    }
+    builder
         .try_build()
         .map_err(|e| -> Box<dyn std::error::Error> { Box::new(e) })
 }
@@ -383,3 +501,39 @@ async fn create_crypto_service(
 
     CryptoService::new(master_key).map_err(|e| -> Box<dyn std::error::Error> { Box::new(e) })
 }
+
+/// Start scheduled backups of collections, snapshots and the collection mapping
+///
+/// The service is registered with `service_manager` and started through it;
+/// the returned handle is the one the admin API lists and restores from.
+async fn start_backup_service(
+    config: &AppConfig,
+    guard: Arc<CollectionGuard>,
+    service_manager: &ServiceManager,
+) -> Result<Arc<BackupService>, Box<dyn std::error::Error>> {
+    let mut service = BackupService::new(&config.system.data.backup, guard)?
+        .with_snapshot_directory(&config.system.data.snapshot.directory);
+    match crate::collection_mapping::get_mapping_file_path() {
+        Ok(path) => service = service.with_file("collection_mapping.json", path),
+        Err(e) => warn!(error = %e, "Collection mapping not included in backups"),
+    }
+    let service = Arc::new(service);
+    service_manager.register(service.clone());
+    service_manager.start(service.name()).await?;
+    Ok(service)
+}
+
+/// Crypto service for collection bundles
+///
+/// Uses the dedicated `system.data.collections.bundle_key`, so bundles stay
+/// importable when the JWT secret is rotated and leaking one key does not
+/// expose the other. Never falls back to a random key: a bundle encrypted
+/// with one could not be imported anywhere.
+fn bundle_crypto_service(
+    config: &AppConfig,
+) -> Result<Option<CryptoService>, Box<dyn std::error::Error>> {
+    let Some(source) = &config.system.data.collections.bundle_key else {
+        return Ok(None);
+    };
+    Ok(Some(CryptoService::new(load_key(source)?)?))
+}
@@ -61,6 +61,7 @@
 
 pub mod admin;
 pub mod args;
+pub mod audit;
 pub mod auth;
 pub mod builder;
 pub mod collection_mapping;
@@ -79,7 +80,7 @@ pub mod transport;
 
 // Re-export core types for public API
 pub use builder::McpServerBuilder;
-pub use init::run;
 #[allow(deprecated)]
 pub use init::run_server;
+pub use init::{bootstrap_admin, export_collection, import_collection, run};
 pub use mcp_server::McpServer;
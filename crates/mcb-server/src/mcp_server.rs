@@ -4,16 +4,23 @@
 //! Follows Clean Architecture principles with dependency injection.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use rmcp::ErrorData as McpError;
 use rmcp::ServerHandler;
 use rmcp::model::{
-    CallToolResult, Implementation, ListToolsResult, PaginatedRequestParams, ProtocolVersion,
-    ServerCapabilities, ServerInfo,
+    CallToolRequestParams, CallToolResult, Implementation, ListToolsResult, PaginatedRequestParams,
+    ProtocolVersion, ServerCapabilities, ServerInfo,
 };
 
 use mcb_application::{ContextServiceInterface, IndexingServiceInterface, SearchServiceInterface};
+use mcb_domain::ports::infrastructure::AuditLogInterface;
+use mcb_infrastructure::backup::BackupService;
+use mcb_infrastructure::infrastructure::ServiceManager;
+use mcb_infrastructure::ratelimit::{ConnectionLimiter, RateLimiter};
 
+use crate::audit::ToolCallAuditor;
+use crate::auth::CollectionAuthorizer;
 use crate::handlers::{
     ClearIndexHandler, GetIndexingStatusHandler, IndexCodebaseHandler, SearchCodeHandler,
 };
@@ -32,6 +39,18 @@ pub struct McpServer {
     context_service: Arc<dyn ContextServiceInterface>,
     /// Service for semantic code search
     search_service: Arc<dyn SearchServiceInterface>,
+    /// Collection scope enforcement shared by the tool handlers
+    authorizer: Arc<CollectionAuthorizer>,
+    /// Records tool calls when an audit log is configured
+    auditor: Option<Arc<ToolCallAuditor>>,
+    /// Rate limits applied by the HTTP transport
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Concurrency quotas applied by the HTTP transport
+    connection_limiter: Option<Arc<ConnectionLimiter>>,
+    /// Lifecycle of background services such as scheduled backups
+    service_manager: Option<Arc<ServiceManager>>,
+    /// Scheduled backups, when enabled
+    backup_service: Option<Arc<BackupService>>,
     /// Handler for indexing operations
     index_codebase_handler: Arc<IndexCodebaseHandler>,
     /// Handler for search operations
@@ -44,21 +63,53 @@ pub struct McpServer {
 
 impl McpServer {
     /// Create a new MCP server with injected dependencies
+    ///
+    /// Collection scopes are not enforced; use
+    /// [`McpServer::new_with_authorizer`] to enable them.
     pub fn new(
         indexing_service: Arc<dyn IndexingServiceInterface>,
         context_service: Arc<dyn ContextServiceInterface>,
         search_service: Arc<dyn SearchServiceInterface>,
     ) -> Self {
-        let index_codebase_handler = Arc::new(IndexCodebaseHandler::new(indexing_service.clone()));
-        let search_code_handler = Arc::new(SearchCodeHandler::new(search_service.clone()));
+        Self::new_with_authorizer(
+            indexing_service,
+            context_service,
+            search_service,
+            Arc::new(CollectionAuthorizer::disabled()),
+        )
+    }
+
+    /// Create a new MCP server whose tool handlers enforce collection scopes
+    pub fn new_with_authorizer(
+        indexing_service: Arc<dyn IndexingServiceInterface>,
+        context_service: Arc<dyn ContextServiceInterface>,
+        search_service: Arc<dyn SearchServiceInterface>,
+        authorizer: Arc<CollectionAuthorizer>,
+    ) -> Self {
+        let index_codebase_handler = Arc::new(
+            IndexCodebaseHandler::new(indexing_service.clone())
+                .with_authorizer(Arc::clone(&authorizer)),
+        );
+        let search_code_handler = Arc::new(
+            SearchCodeHandler::new(search_service.clone()).with_authorizer(Arc::clone(&authorizer)),
+        );
         let get_indexing_status_handler =
             Arc::new(GetIndexingStatusHandler::new(indexing_service.clone()));
-        let clear_index_handler = Arc::new(ClearIndexHandler::new(indexing_service.clone()));
+        let clear_index_handler = Arc::new(
+            ClearIndexHandler::new(indexing_service.clone())
+                .with_authorizer(Arc::clone(&authorizer)),
+        );
 
         Self {
             indexing_service,
             context_service,
             search_service,
+            authorizer,
+            auditor: None,
+            rate_limiter: None,
+            connection_limiter: None,
+            service_manager: None,
+            backup_service: None,
             index_codebase_handler,
             search_code_handler,
             get_indexing_status_handler,
@@ -66,6 +117,67 @@ impl McpServer {
         }
     }
 
+    /// Record every tool call to `log`
+    pub fn with_audit_log(mut self, log: Arc<dyn AuditLogInterface>) -> Self {
+        self.auditor = Some(Arc::new(ToolCallAuditor::new(
+            log,
+            Arc::clone(&self.authorizer),
+        )));
+        self
+    }
+
+    /// Rate limit requests arriving over HTTP with `limiter`
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Cap concurrent HTTP connections and requests per client with `limiter`
+    pub fn with_connection_limiter(mut self, limiter: Arc<ConnectionLimiter>) -> Self {
+        self.connection_limiter = Some(limiter);
+        self
+    }
+
+    /// Track background services with `manager`
+    pub fn with_service_manager(mut self, manager: Arc<ServiceManager>) -> Self {
+        self.service_manager = Some(manager);
+        self
+    }
+
+    /// Run scheduled backups with `service`
+    pub fn with_backup_service(mut self, service: Arc<BackupService>) -> Self {
+        self.backup_service = Some(service);
+        self
+    }
+
+    /// Route a tool call to its handler, auditing it if configured
+    ///
+    /// `session` identifies the client session (e.g. the HTTP
+    /// `X-Session-Id` header) in audit records.
+    pub async fn call_tool_with_session(
+        &self,
+        request: CallToolRequestParams,
+        session: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let handlers = ToolHandlers {
+            index_codebase: Arc::clone(&self.index_codebase_handler),
+            search_code: Arc::clone(&self.search_code_handler),
+            get_indexing_status: Arc::clone(&self.get_indexing_status_handler),
+            clear_index: Arc::clone(&self.clear_index_handler),
+        };
+
+        let Some(auditor) = &self.auditor else {
+            return route_tool_call(request, &handlers).await;
+        };
+
+        let started = Instant::now();
+        let result = route_tool_call(request.clone(), &handlers).await;
+        auditor
+            .record(&request, session, &result, started.elapsed())
+            .await;
+        result
+    }
+
     /// Access to indexing service
     pub fn indexing_service(&self) -> Arc<dyn IndexingServiceInterface> {
         Arc::clone(&self.indexing_service)
@@ -81,6 +193,31 @@ impl McpServer {
         Arc::clone(&self.search_service)
     }
 
+    /// Access to the rate limiter, if configured (shared with the admin API)
+    pub fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    /// Access to the connection limiter, if configured
+    pub fn connection_limiter(&self) -> Option<Arc<ConnectionLimiter>> {
+        self.connection_limiter.clone()
+    }
+
+    /// Access to the service manager, if configured (shared with the admin API)
+    pub fn service_manager(&self) -> Option<Arc<ServiceManager>> {
+        self.service_manager.clone()
+    }
+
+    /// Access to the backup service, if enabled (shared with the admin API)
+    pub fn backup_service(&self) -> Option<Arc<BackupService>> {
+        self.backup_service.clone()
+    }
+
+    /// Access to the collection authorizer (shared with the admin API)
+    pub fn authorizer(&self) -> Arc<CollectionAuthorizer> {
+        Arc::clone(&self.authorizer)
+    }
+
     /// Access to index codebase handler (for HTTP transport)
     pub fn index_codebase_handler(&self) -> Arc<IndexCodebaseHandler> {
         Arc::clone(&self.index_codebase_handler)
@@ -143,15 +280,9 @@ impl ServerHandler for McpServer {
     /// Call a tool
     async fn call_tool(
         &self,
-        request: rmcp::model::CallToolRequestParams,
+        request: CallToolRequestParams,
         _context: rmcp::service::RequestContext<rmcp::RoleServer>,
     ) -> Result<CallToolResult, McpError> {
-        let handlers = ToolHandlers {
-            index_codebase: Arc::clone(&self.index_codebase_handler),
-            search_code: Arc::clone(&self.search_code_handler),
-            get_indexing_status: Arc::clone(&self.get_indexing_status_handler),
-            clear_index: Arc::clone(&self.clear_index_handler),
-        };
-        route_tool_call(request, &handlers).await
+        self.call_tool_with_session(request, None).await
     }
 }
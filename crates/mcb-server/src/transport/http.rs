@@ -12,6 +12,19 @@
 //! | `tools/call` | Call a tool with arguments |
 //! | `ping` | Health check |
 //!
+//! # Rate Limiting
+//!
+//! When the server has a rate limiter, every request is charged to the
+//! caller's principal and `X-Session-Id` buckets, or to its IP address when
+//! anonymous; expensive tools use a separate budget. Rejected requests get
+//! HTTP `429` with `Retry-After` and a JSON-RPC error (`-32029`,
+//! `data.code = "RATE_LIMITED"`).
+//!
+//! When the server has a connection limiter, each client IP may hold at most
+//! `max_requests_per_client` requests and `/events` streams at once, and
+//! at most `max_connections` clients are served concurrently. Excess
+//! requests get the same `429` with `data.code = "CONNECTION_LIMITED"`.
+//!
 //! # Example
 //!
 //! ```text
@@ -31,19 +44,29 @@
 
 use super::types::{McpRequest, McpResponse};
 use crate::McpServer;
-use crate::constants::{JSONRPC_INTERNAL_ERROR, JSONRPC_INVALID_PARAMS, JSONRPC_METHOD_NOT_FOUND};
-use crate::tools::{ToolHandlers, create_tool_list, route_tool_call};
+use crate::constants::{
+    ERROR_CODE_CONNECTION_LIMITED, ERROR_CODE_RATE_LIMITED, JSONRPC_FORBIDDEN,
+    JSONRPC_INTERNAL_ERROR, JSONRPC_INVALID_PARAMS, JSONRPC_METHOD_NOT_FOUND, JSONRPC_RATE_LIMITED,
+    JSONRPC_UNAUTHENTICATED, RETRY_AFTER_HEADER, SESSION_ID_HEADER,
+};
+use crate::tools::create_tool_list;
+use mcb_infrastructure::constants::{AUTHORIZATION_HEADER, BEARER_PREFIX};
+use mcb_infrastructure::ratelimit::{
+    ConnectionLimited, ConnectionPermit, RateLimitClass, RateLimited, RateLimiter,
+};
 use rmcp::ServerHandler;
 use rmcp::model::CallToolRequestParams;
 use rocket::fairing::{Fairing, Info, Kind};
-use rocket::http::Header;
+use rocket::http::{Header, Status};
+use rocket::request::{FromRequest, Outcome};
 use rocket::response::stream::{Event, EventStream};
+use rocket::response::{self, Responder};
 use rocket::serde::json::Json;
 use rocket::{Build, Request, Response, Rocket, State, get, post, routes};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::sync::broadcast;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// HTTP transport configuration
 #[derive(Debug, Clone)]
@@ -179,6 +202,42 @@ impl Fairing for Cors {
     }
 }
 
+/// Caller identification taken from request headers
+///
+/// Never fails; tools decide whether a token is required.
+pub struct CallerHeaders {
+    /// Token from `Authorization: Bearer <token>`
+    pub bearer: Option<String>,
+    /// Client session from `X-Session-Id`
+    pub session: Option<String>,
+    /// Client IP address
+    pub client: Option<String>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for CallerHeaders {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let bearer = request
+            .headers()
+            .get_one(AUTHORIZATION_HEADER)
+            .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty());
+        let session = request
+            .headers()
+            .get_one(SESSION_ID_HEADER)
+            .map(str::to_string);
+        let client = request.client_ip().map(|ip| ip.to_string());
+        Outcome::Success(CallerHeaders {
+            bearer,
+            session,
+            client,
+        })
+    }
+}
+
 /// Handle MCP request via HTTP POST
 ///
 /// Routes MCP JSON-RPC requests to the appropriate handlers based on method name.
@@ -192,13 +251,43 @@ impl Fairing for Cors {
 #[post("/mcp", format = "json", data = "<request>")]
 async fn handle_mcp_request(
     state: &State<HttpTransportState>,
+    caller: CallerHeaders,
     request: Json<McpRequest>,
-) -> Json<McpResponse> {
+) -> McpHttpResponse {
     let request = request.into_inner();
+
+    let _permit = match acquire_connection(state, &caller) {
+        Ok(permit) => permit,
+        Err(limited) => {
+            warn!(
+                error = %limited,
+                method = %request.method,
+                "MCP request over connection limits"
+            );
+            return McpHttpResponse {
+                retry_after: Some(1),
+                response: connection_limited_response(request.id.clone(), &limited),
+            };
+        }
+    };
+
+    if let Err(limited) = check_rate_limit(state, &request, &caller).await {
+        warn!(
+            key = %limited.key,
+            class = %limited.class,
+            method = %request.method,
+            "MCP request rate limited"
+        );
+        return McpHttpResponse {
+            retry_after: Some(limited.retry_after_secs()),
+            response: rate_limited_response(request.id.clone(), &limited),
+        };
+    }
+
     let response = match request.method.as_str() {
         "initialize" => handle_initialize(state, &request).await,
         "tools/list" => handle_tools_list(state, &request).await,
-        "tools/call" => handle_tools_call(state, &request).await,
+        "tools/call" => handle_tools_call(state, &request, &caller).await,
         "ping" => McpResponse::success(request.id.clone(), serde_json::json!({})),
         _ => McpResponse::error(
             request.id.clone(),
@@ -207,7 +296,113 @@ async fn handle_mcp_request(
         ),
     };
 
-    Json(response)
+    McpHttpResponse {
+        response,
+        retry_after: None,
+    }
+}
+
+/// JSON-RPC response, sent as `429` with `Retry-After` when rate limited
+struct McpHttpResponse {
+    response: McpResponse,
+    retry_after: Option<u64>,
+}
+
+impl<'r> Responder<'r, 'static> for McpHttpResponse {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        let mut response = Json(self.response).respond_to(request)?;
+        if let Some(secs) = self.retry_after {
+            response.set_status(Status::TooManyRequests);
+            response.set_header(Header::new(RETRY_AFTER_HEADER, secs.to_string()));
+        }
+        Ok(response)
+    }
+}
+
+/// Charge the request to the caller's rate limit buckets
+///
+/// The caller is identified by the principal behind its token (bearer
+/// header or the tool's `token` argument, when authorization is enabled),
+/// its session, and otherwise its IP address.
+async fn check_rate_limit(
+    state: &HttpTransportState,
+    request: &McpRequest,
+    caller: &CallerHeaders,
+) -> Result<(), RateLimited> {
+    let Some(limiter) = state.server.rate_limiter() else {
+        return Ok(());
+    };
+
+    let params = request.params.as_ref();
+    let tool = params
+        .filter(|_| request.method == "tools/call")
+        .and_then(|p| p.get("name"))
+        .and_then(|name| name.as_str());
+    let token = caller.bearer.as_deref().or_else(|| {
+        params
+            .and_then(|p| p.get("arguments"))
+            .and_then(|args| args.get("token"))
+            .and_then(|token| token.as_str())
+    });
+
+    let authorizer = state.server.authorizer();
+    let principal = match token {
+        Some(token) if authorizer.is_enabled() => authorizer.resolve_token(token).await,
+        _ => None,
+    };
+
+    let keys = RateLimiter::caller_keys(
+        principal.as_ref().map(|p| p.subject()),
+        caller.session.as_deref(),
+        caller.client.as_deref(),
+    );
+    let class = tool.map_or(RateLimitClass::Standard, |tool| {
+        limiter.class_for_tool(tool)
+    });
+    limiter.check(class, &keys)
+}
+
+/// Reserve a concurrency slot for the caller, if quotas are enforced
+fn acquire_connection(
+    state: &HttpTransportState,
+    caller: &CallerHeaders,
+) -> Result<Option<ConnectionPermit>, ConnectionLimited> {
+    let Some(limiter) = state.server.connection_limiter() else {
+        return Ok(None);
+    };
+    limiter
+        .acquire(caller.client.as_deref().unwrap_or("anonymous"))
+        .map(Some)
+}
+
+/// JSON-RPC error for a request over the connection quotas
+fn connection_limited_response(
+    id: Option<serde_json::Value>,
+    limited: &ConnectionLimited,
+) -> McpResponse {
+    McpResponse::error_with_data(
+        id,
+        JSONRPC_RATE_LIMITED,
+        limited.to_string(),
+        serde_json::json!({
+            "code": ERROR_CODE_CONNECTION_LIMITED,
+            "retry_after_secs": 1,
+        }),
+    )
+}
+
+/// JSON-RPC error for a rate-limited request
+fn rate_limited_response(id: Option<serde_json::Value>, limited: &RateLimited) -> McpResponse {
+    McpResponse::error_with_data(
+        id,
+        JSONRPC_RATE_LIMITED,
+        limited.to_string(),
+        serde_json::json!({
+            "code": ERROR_CODE_RATE_LIMITED,
+            "class": limited.class.as_str(),
+            "retry_after_secs": limited.retry_after_secs(),
+        }),
+    )
 }
 
 /// Handle the `initialize` method
@@ -265,8 +460,12 @@ async fn handle_tools_list(_state: &HttpTransportState, request: &McpRequest) ->
 }
 
 /// Parse tool call parameters from the request
+///
+/// A bearer token from the HTTP request is injected as the `token` argument
+/// unless the caller already passed one explicitly.
 fn parse_tool_call_params(
     params: &serde_json::Value,
+    bearer: Option<&str>,
 ) -> Result<CallToolRequestParams, (i32, &'static str)> {
     let tool_name = params
         .get("name")
@@ -277,7 +476,13 @@ fn parse_tool_call_params(
         ))?
         .to_string();
 
-    let arguments = params.get("arguments").and_then(|v| v.as_object().cloned());
+    let mut arguments = params.get("arguments").and_then(|v| v.as_object().cloned());
+    if let Some(token) = bearer {
+        arguments
+            .get_or_insert_with(serde_json::Map::new)
+            .entry("token")
+            .or_insert_with(|| serde_json::Value::String(token.to_string()));
+    }
 
     Ok(CallToolRequestParams {
         name: tool_name.into(),
@@ -309,7 +514,13 @@ fn tool_result_to_json(result: rmcp::model::CallToolResult) -> serde_json::Value
 /// Handle the `tools/call` method
 ///
 /// Executes the specified tool with the provided arguments.
-async fn handle_tools_call(state: &HttpTransportState, request: &McpRequest) -> McpResponse {
+async fn handle_tools_call(
+    state: &HttpTransportState,
+    request: &McpRequest,
+    caller: &CallerHeaders,
+) -> McpResponse {
+    let bearer = caller.bearer.as_deref();
+    let session = caller.session.as_deref();
     let params = match &request.params {
         Some(params) => params,
         None => {
@@ -321,20 +532,21 @@ async fn handle_tools_call(state: &HttpTransportState, request: &McpRequest) ->
         }
     };
 
-    let call_request = match parse_tool_call_params(params) {
+    let call_request = match parse_tool_call_params(params, bearer) {
         Ok(req) => req,
         Err((code, msg)) => return McpResponse::error(request.id.clone(), code, msg),
     };
 
-    let handlers = ToolHandlers {
-        index_codebase: state.server.index_codebase_handler(),
-        search_code: state.server.search_code_handler(),
-        get_indexing_status: state.server.get_indexing_status_handler(),
-        clear_index: state.server.clear_index_handler(),
-    };
-
-    match route_tool_call(call_request, &handlers).await {
+    match state
+        .server
+        .call_tool_with_session(call_request, session)
+        .await
+    {
         Ok(result) => McpResponse::success(request.id.clone(), tool_result_to_json(result)),
+        // Authorization failures keep their codes so clients can react to them
+        Err(e) if matches!(e.code.0, JSONRPC_UNAUTHENTICATED | JSONRPC_FORBIDDEN) => {
+            McpResponse::error(request.id.clone(), e.code.0, e.message)
+        }
         Err(e) => {
             error!(error = ?e, "Tool call failed");
             McpResponse::error(
@@ -347,16 +559,26 @@ async fn handle_tools_call(state: &HttpTransportState, request: &McpRequest) ->
 }
 
 /// Handle SSE connection for server-to-client events
+///
+/// The stream holds a connection slot until the client disconnects.
 #[get("/events")]
-fn handle_sse(state: &State<HttpTransportState>) -> EventStream![] {
+fn handle_sse(
+    state: &State<HttpTransportState>,
+    caller: CallerHeaders,
+) -> Result<EventStream![], Status> {
+    let permit = acquire_connection(state, &caller).map_err(|limited| {
+        warn!(error = %limited, "SSE connection over connection limits");
+        Status::TooManyRequests
+    })?;
     let mut rx = state.event_tx.subscribe();
 
-    EventStream! {
+    Ok(EventStream! {
+        let _permit = permit;
         loop {
             match rx.recv().await {
                 Ok(data) => yield Event::data(data),
                 Err(_) => break,
             }
         }
-    }
+    })
 }
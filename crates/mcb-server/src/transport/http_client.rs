@@ -14,6 +14,7 @@ use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use super::types::{McpRequest, McpResponse};
+use crate::constants::SESSION_ID_HEADER;
 
 /// JSON-RPC 2.0 error codes
 const JSONRPC_PARSE_ERROR: i32 = -32700;
@@ -153,7 +154,7 @@ impl HttpClientTransport {
             .client
             .post(&url)
             .header("Content-Type", "application/json")
-            .header("X-Session-Id", &self.config.session_id)
+            .header(SESSION_ID_HEADER, &self.config.session_id)
             .json(request)
             .send()
             .await?;
@@ -197,6 +198,7 @@ impl HttpClientTransport {
             error: Some(super::types::McpError {
                 code: JSONRPC_PARSE_ERROR,
                 message: format!("Parse error: {}", e),
+                data: None,
             }),
             id: None,
         }
@@ -210,6 +212,7 @@ impl HttpClientTransport {
             error: Some(super::types::McpError {
                 code: JSONRPC_INTERNAL_ERROR,
                 message: format!("Server communication error: {}", e),
+                data: None,
             }),
             id,
         }
@@ -42,6 +42,9 @@ pub struct McpError {
     pub code: i32,
     /// Error message
     pub message: String,
+    /// Additional machine-readable details
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
 }
 
 impl McpResponse {
@@ -63,8 +66,23 @@ impl McpResponse {
             error: Some(McpError {
                 code,
                 message: message.into(),
+                data: None,
             }),
             id,
         }
     }
+
+    /// Create an error response carrying `data`
+    pub fn error_with_data(
+        id: Option<serde_json::Value>,
+        code: i32,
+        message: impl Into<String>,
+        data: serde_json::Value,
+    ) -> Self {
+        let mut response = Self::error(id, code, message);
+        if let Some(error) = response.error.as_mut() {
+            error.data = Some(data);
+        }
+        response
+    }
 }
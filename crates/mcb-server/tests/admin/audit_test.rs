@@ -0,0 +1,196 @@
+//! Admin Audit Trail Tests
+//!
+//! Verifies that admin mutations are recorded and that `/audit` serves
+//! filtered events to authenticated callers only.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{
+    AuditLogInterface, AuditOutcome, AuditQuery, AuditSource, DomainEventStream, EventBusProvider,
+};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_infrastructure::audit::JsonlAuditLog;
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_server::admin::{
+    audit::AuditState,
+    auth::AdminAuthConfig,
+    handlers::AdminState,
+    routes::{admin_rocket, with_audit_routes},
+};
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+const ADMIN_KEY: &str = "audit-test-key";
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state() -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    }
+}
+
+async fn create_client(log: Arc<JsonlAuditLog>) -> Client {
+    let auth_config = Arc::new(AdminAuthConfig::new(
+        true,
+        "X-Admin-Key".to_string(),
+        Some(ADMIN_KEY.to_string()),
+    ));
+    let rocket = with_audit_routes(
+        admin_rocket(create_test_state(), auth_config, None),
+        AuditState { log },
+    );
+    Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance")
+}
+
+fn admin_key() -> Header<'static> {
+    Header::new("X-Admin-Key", ADMIN_KEY)
+}
+
+#[rocket::async_test]
+async fn test_admin_mutation_is_recorded() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let client = create_client(log.clone()).await;
+
+    let response = client
+        .post("/shutdown")
+        .header(admin_key())
+        .header(Header::new("X-Session-Id", "session-7"))
+        .header(ContentType::JSON)
+        .body(r#"{"timeout_secs": 5}"#)
+        .dispatch()
+        .await;
+    // No shutdown coordinator is configured in tests
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+
+    let events = log.query(&AuditQuery::default()).await.unwrap();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.source, AuditSource::Admin);
+    assert_eq!(event.action, "POST /shutdown");
+    assert_eq!(event.session.as_deref(), Some("session-7"));
+    assert_eq!(event.outcome, AuditOutcome::Failure);
+    assert_eq!(event.arguments["timeout_secs"], 5);
+}
+
+#[rocket::async_test]
+async fn test_unauthenticated_mutation_is_recorded_as_denied() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let client = create_client(log.clone()).await;
+
+    let response = client.post("/config/reload").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+
+    let events = log.query(&AuditQuery::default()).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].outcome, AuditOutcome::Denied);
+    assert!(events[0].principal.is_none());
+}
+
+#[rocket::async_test]
+async fn test_reads_are_not_recorded() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let client = create_client(log.clone()).await;
+
+    let response = client.get("/health").dispatch().await;
+    assert_eq!(response.status(), Status::Ok);
+
+    assert!(log.query(&AuditQuery::default()).await.unwrap().is_empty());
+}
+
+#[rocket::async_test]
+async fn test_query_audit_filters_events() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let client = create_client(log.clone()).await;
+
+    client.post("/config/reload").dispatch().await;
+    client
+        .post("/config/reload")
+        .header(admin_key())
+        .dispatch()
+        .await;
+
+    let response = client
+        .get("/audit?outcome=denied")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.unwrap()).unwrap();
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["events"][0]["action"], "POST /config/reload");
+    assert_eq!(body["events"][0]["outcome"], "denied");
+}
+
+#[rocket::async_test]
+async fn test_query_audit_rejects_invalid_filters() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let client = create_client(log).await;
+
+    let response = client
+        .get("/audit?since=yesterday")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let response = client
+        .get("/audit?outcome=maybe")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[rocket::async_test]
+async fn test_query_audit_requires_auth() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let client = create_client(log).await;
+
+    let response = client.get("/audit").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+}
@@ -14,8 +14,14 @@ use async_trait::async_trait;
 use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
 use mcb_domain::error::Result;
 use mcb_domain::events::DomainEvent;
+use mcb_infrastructure::config::{AppConfig, ScopedApiKeyConfig};
 use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
-use mcb_server::admin::{auth::AdminAuthConfig, handlers::AdminState, routes::admin_rocket};
+use mcb_server::admin::{
+    auth::AdminAuthConfig,
+    handlers::AdminState,
+    routes::{admin_rocket, with_collection_authorizer},
+};
+use mcb_server::auth::CollectionAuthorizer;
 use rocket::http::{Header, Status};
 use rocket::local::asynchronous::Client;
 use std::sync::Arc;
@@ -429,9 +435,9 @@ async fn test_ready_public_no_auth_required() {
     );
 }
 
-/// Test: /indexing should work WITHOUT API key and return valid status
+/// Test: /indexing requires credentials and returns valid status with the key
 #[rocket::async_test]
-async fn test_indexing_public_no_auth_required() {
+async fn test_indexing_requires_auth_and_returns_status() {
     let indexing = Arc::new(DefaultIndexingOperations::new());
 
     // Start an indexing operation to verify we get real data
@@ -455,11 +461,22 @@ async fn test_indexing_public_no_auth_required() {
         .expect("valid rocket instance");
 
     let response = client.get("/indexing").dispatch().await;
+    assert_eq!(
+        response.status(),
+        Status::Unauthorized,
+        "GET /indexing without API key should return 401"
+    );
+
+    let response = client
+        .get("/indexing")
+        .header(Header::new(TEST_HEADER, TEST_API_KEY))
+        .dispatch()
+        .await;
 
     assert_eq!(
         response.status(),
         Status::Ok,
-        "GET /indexing should work without API key (public endpoint)"
+        "GET /indexing with correct API key should return 200"
     );
 
     // Validate response body contains real indexing data
@@ -492,6 +509,54 @@ async fn test_indexing_public_no_auth_required() {
     assert_eq!(op["progress_percent"], 25.0, "Progress should be 25%");
 }
 
+/// Test: /indexing only reports operations on collections the caller may read
+#[rocket::async_test]
+async fn test_indexing_filtered_by_scope() {
+    let indexing = Arc::new(DefaultIndexingOperations::new());
+    indexing.start_operation("team-a-api", 10);
+    indexing.start_operation("team-b-api", 10);
+
+    let state = AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: indexing.clone(),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    };
+
+    let mut config = AppConfig::default();
+    config.auth.enabled = true;
+    config.auth.api_key.keys = vec![ScopedApiKeyConfig {
+        name: "team-a-reader".to_string(),
+        key: "team-a-key".to_string(),
+        scopes: vec!["collection:team-a-*:read".to_string()],
+    }];
+    let authorizer = CollectionAuthorizer::from_config(&config, None).expect("valid scopes");
+    let rocket = with_collection_authorizer(
+        admin_rocket(state, Arc::new(create_auth_config()), None),
+        Arc::new(authorizer),
+    );
+    let client = Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance");
+
+    let response = client
+        .get("/indexing")
+        .header(Header::new("Authorization", "Bearer team-a-key"))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_string().await.expect("response body");
+    let json: serde_json::Value = serde_json::from_str(&body).expect("valid JSON");
+    assert_eq!(json["active_operations"], 1);
+    assert_eq!(json["operations"][0]["collection"], "team-a-api");
+}
+
 // =============================================================================
 // EDGE CASES
 // =============================================================================
@@ -0,0 +1,100 @@
+//! Backup Tests
+//!
+//! Verifies the `/backups` endpoints list, trigger and restore backups.
+
+use super::{create_admin_client, create_guard, seed_collection};
+use mcb_infrastructure::backup::BackupService;
+use mcb_infrastructure::collections::CollectionGuard;
+use mcb_infrastructure::config::BackupConfig;
+use mcb_providers::vector_store::InMemoryVectorStoreProvider;
+use mcb_server::admin::{BackupAdminState, routes::with_backup_routes};
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+
+const ADMIN_KEY: &str = "backups-test-key";
+
+/// Client over an in-memory store, with backups kept in `dir`
+async fn create_client(dir: &tempfile::TempDir) -> (Client, Arc<CollectionGuard>) {
+    let guard = create_guard(Arc::new(InMemoryVectorStoreProvider::new()));
+    let backup_config = BackupConfig {
+        enabled: true,
+        directory: dir.path().to_path_buf(),
+        ..BackupConfig::default()
+    };
+    let service = BackupService::new(&backup_config, guard.clone()).expect("backup service");
+    let state = BackupAdminState {
+        service: Arc::new(service),
+    };
+    let client = create_admin_client(ADMIN_KEY, |rocket| with_backup_routes(rocket, state)).await;
+    (client, guard)
+}
+
+#[rocket::async_test]
+async fn test_trigger_list_and_restore_backup() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (client, guard) = create_client(&dir).await;
+    seed_collection(&guard, "code").await;
+
+    let response = client
+        .post("/backups")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let backup: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    let name = backup["name"].as_str().expect("backup name").to_string();
+
+    let response = client
+        .get("/backups")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let backups: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    assert_eq!(backups[0]["name"], name.as_str());
+
+    guard
+        .vector_store_provider()
+        .delete_collection("code")
+        .await
+        .expect("delete");
+    let response = client
+        .post("/backups/restore")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(serde_json::json!({ "name": name }).to_string())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let report: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    assert_eq!(report["restored"], serde_json::json!(["code"]));
+    assert!(guard.registry().get("code").await.is_some());
+}
+
+#[rocket::async_test]
+async fn test_restore_unknown_backup_is_not_found() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (client, _) = create_client(&dir).await;
+
+    let response = client
+        .post("/backups/restore")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"name": "../mcb-backup-20260101T000000000Z.tar"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[rocket::async_test]
+async fn test_backups_require_auth() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (client, _) = create_client(&dir).await;
+
+    let response = client.post("/backups").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+}
@@ -0,0 +1,131 @@
+//! Collection Bundle Tests
+//!
+//! Verifies the `/bundles` endpoints export and import collections, only
+//! accept plain file names, and report failures with matching statuses.
+
+use super::{create_admin_client, create_guard, seed_collection};
+use mcb_infrastructure::collections::{CollectionBundler, CollectionGuard};
+use mcb_providers::vector_store::InMemoryVectorStoreProvider;
+use mcb_server::admin::{BundleAdminState, routes::with_bundle_routes};
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+
+const ADMIN_KEY: &str = "bundles-test-key";
+
+/// Client over an in-memory store, with bundles kept in `dir`
+async fn create_client(dir: &tempfile::TempDir) -> (Client, Arc<CollectionGuard>) {
+    let guard = create_guard(Arc::new(InMemoryVectorStoreProvider::new()));
+    let state = BundleAdminState {
+        bundler: Arc::new(CollectionBundler::new(guard.clone())),
+        directory: dir.path().to_path_buf(),
+    };
+    let client = create_admin_client(ADMIN_KEY, |rocket| with_bundle_routes(rocket, state)).await;
+    (client, guard)
+}
+
+#[rocket::async_test]
+async fn test_export_then_import_into_new_collection() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (client, guard) = create_client(&dir).await;
+    seed_collection(&guard, "code").await;
+
+    let response = client
+        .post("/bundles/export")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"collection": "code", "file": "code.bundle", "compress": true}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    assert_eq!(body["vector_count"], 1);
+    assert_eq!(body["compression"], "gzip");
+    assert!(dir.path().join("code.bundle").exists());
+
+    let response = client
+        .post("/bundles/import")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"file": "code.bundle", "collection": "restored"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    assert!(guard.registry().get("restored").await.is_some());
+
+    let response = client
+        .post("/bundles/import")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"file": "code.bundle"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Conflict);
+}
+
+#[rocket::async_test]
+async fn test_bundle_file_must_be_a_plain_name() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (client, _) = create_client(&dir).await;
+
+    for file in ["../escape.bundle", "/tmp/escape.bundle", ""] {
+        let response = client
+            .post("/bundles/import")
+            .header(Header::new("X-Admin-Key", ADMIN_KEY))
+            .header(ContentType::JSON)
+            .body(serde_json::json!({ "file": file }).to_string())
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::BadRequest, "{file}");
+    }
+}
+
+#[rocket::async_test]
+async fn test_bundles_require_auth() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (client, _) = create_client(&dir).await;
+
+    let response = client
+        .post("/bundles/export")
+        .header(ContentType::JSON)
+        .body(r#"{"collection": "code", "file": "code.bundle"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Unauthorized);
+}
+
+#[rocket::async_test]
+async fn test_bundle_errors_map_to_statuses() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (client, _) = create_client(&dir).await;
+    std::fs::write(dir.path().join("damaged.bundle"), b"not a bundle").expect("write");
+
+    let cases = [
+        (
+            "/bundles/export",
+            r#"{"collection": "missing", "file": "missing.bundle"}"#,
+            Status::NotFound,
+        ),
+        (
+            "/bundles/import",
+            r#"{"file": "missing.bundle"}"#,
+            Status::NotFound,
+        ),
+        (
+            "/bundles/import",
+            r#"{"file": "damaged.bundle"}"#,
+            Status::BadRequest,
+        ),
+    ];
+    for (path, body, status) in cases {
+        let response = client
+            .post(path)
+            .header(Header::new("X-Admin-Key", ADMIN_KEY))
+            .header(ContentType::JSON)
+            .body(body)
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), status, "{path} {body}");
+    }
+}
@@ -0,0 +1,158 @@
+//! Cache Namespace Tests
+//!
+//! Verifies the `/cache/namespaces` endpoints list, count and clear
+//! namespaces of the configured cache provider.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::providers::{CacheEntryConfig, CacheProvider};
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_providers::cache::MokaCacheProvider;
+use mcb_server::admin::{auth::AdminAuthConfig, handlers::AdminState, routes::admin_rocket};
+use rocket::http::{Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+
+const ADMIN_KEY: &str = "cache-test-key";
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state(cache: Option<Arc<dyn CacheProvider>>) -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache,
+    }
+}
+
+async fn create_client(cache: Option<Arc<dyn CacheProvider>>) -> Client {
+    let auth_config = Arc::new(AdminAuthConfig::new(
+        true,
+        "X-Admin-Key".to_string(),
+        Some(ADMIN_KEY.to_string()),
+    ));
+    Client::tracked(admin_rocket(create_test_state(cache), auth_config, None))
+        .await
+        .expect("valid rocket instance")
+}
+
+/// Moka cache holding two search results and one embedding
+async fn populated_cache() -> Arc<dyn CacheProvider> {
+    let cache = Arc::new(MokaCacheProvider::new());
+    for key in ["search:code:a", "search:code:b"] {
+        cache
+            .set_json(key, "[]", CacheEntryConfig::default())
+            .await
+            .unwrap();
+    }
+    cache
+        .set_json(
+            "abc",
+            "[0.5]",
+            CacheEntryConfig::default().with_namespace("embeddings"),
+        )
+        .await
+        .unwrap();
+    cache
+}
+
+fn admin_key() -> Header<'static> {
+    Header::new("X-Admin-Key", ADMIN_KEY)
+}
+
+#[rocket::async_test]
+async fn test_list_namespaces_and_keys() {
+    let client = create_client(Some(populated_cache().await)).await;
+
+    let response = client
+        .get("/cache/namespaces")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let body: serde_json::Value = response.into_json().await.expect("json");
+    assert_eq!(
+        body,
+        serde_json::json!([
+            { "namespace": "embeddings", "entries": 1, "bytes_used": 5 },
+            { "namespace": "search", "entries": 2, "bytes_used": 4 },
+        ])
+    );
+
+    let response = client
+        .get("/cache/namespaces/search/keys")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let keys: Vec<String> = response.into_json().await.expect("json");
+    assert_eq!(keys, vec!["search:code:a", "search:code:b"]);
+}
+
+#[rocket::async_test]
+async fn test_clear_namespace_keeps_other_namespaces() {
+    let cache = populated_cache().await;
+    let client = create_client(Some(cache.clone())).await;
+
+    let response = client
+        .delete("/cache/namespaces/search")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let body: serde_json::Value = response.into_json().await.expect("json");
+    assert_eq!(
+        body,
+        serde_json::json!({ "namespace": "search", "removed": 2 })
+    );
+
+    assert!(!cache.exists("search:code:a").await.unwrap());
+    assert!(cache.exists("abc").await.unwrap());
+}
+
+#[rocket::async_test]
+async fn test_namespace_endpoints_require_cache_and_key() {
+    let client = create_client(None).await;
+
+    let response = client.get("/cache/namespaces").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+
+    let response = client
+        .delete("/cache/namespaces/search")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+}
@@ -0,0 +1,129 @@
+//! Embedding Routing Tests
+//!
+//! Verifies `/embedding/routing` lists failover members and the active
+//! provider per collection.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::infrastructure::ProviderRouter;
+use mcb_domain::ports::providers::EmbeddingProvider;
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_infrastructure::routing::{FailoverEmbeddingProvider, FailoverMember, NullProviderRouter};
+use mcb_providers::embedding::NullEmbeddingProvider;
+use mcb_server::admin::{
+    EmbeddingRoutingState,
+    auth::AdminAuthConfig,
+    handlers::AdminState,
+    routes::{admin_rocket, with_embedding_routing},
+};
+use rocket::http::{Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+
+const ADMIN_KEY: &str = "embedding-routing-test-key";
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state() -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    }
+}
+
+/// Client with a two-member chain that has served the `code` collection
+async fn create_client() -> Client {
+    let auth_config = Arc::new(AdminAuthConfig::new(
+        true,
+        "X-Admin-Key".to_string(),
+        Some(ADMIN_KEY.to_string()),
+    ));
+    let router: Arc<dyn ProviderRouter> = Arc::new(NullProviderRouter::new());
+    let failover = Arc::new(
+        FailoverEmbeddingProvider::new(
+            vec![
+                FailoverMember::new("primary", Arc::new(NullEmbeddingProvider::new()), "null"),
+                FailoverMember::new("backup", Arc::new(NullEmbeddingProvider::new()), "null"),
+            ],
+            router,
+        )
+        .expect("chain"),
+    );
+    failover
+        .embed_batch_for("code", &["fn main() {}".to_string()])
+        .await
+        .expect("embed");
+
+    let rocket = with_embedding_routing(
+        admin_rocket(create_test_state(), auth_config, None),
+        EmbeddingRoutingState { failover },
+    );
+    Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance")
+}
+
+#[rocket::async_test]
+async fn test_embedding_routing_lists_members_and_collections() {
+    let client = create_client().await;
+
+    let response = client
+        .get("/embedding/routing")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    let members = body["members"].as_array().expect("members");
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0]["id"], "primary");
+    assert_eq!(members[0]["health"], "Healthy");
+
+    let collections = body["collections"].as_array().expect("collections");
+    assert_eq!(collections.len(), 1);
+    assert_eq!(collections[0]["collection"], "code");
+    assert_eq!(collections[0]["active_provider"], "primary");
+}
+
+#[rocket::async_test]
+async fn test_embedding_routing_requires_auth() {
+    let client = create_client().await;
+
+    let response = client.get("/embedding/routing").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+}
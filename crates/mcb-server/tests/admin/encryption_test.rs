@@ -0,0 +1,99 @@
+//! Encryption Tests
+//!
+//! Verifies the `/encryption/reencrypt` endpoints start re-encryption jobs
+//! and report their progress.
+
+use super::{create_admin_client, create_guard, seed_collection};
+use mcb_domain::ports::providers::CryptoProvider;
+use mcb_infrastructure::collections::{CollectionGuard, ReencryptionJobs};
+use mcb_infrastructure::crypto::Keyring;
+use mcb_providers::vector_store::{EncryptedVectorStoreProvider, InMemoryVectorStoreProvider};
+use mcb_server::admin::{EncryptionAdminState, routes::with_encryption_routes};
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+
+const ADMIN_KEY: &str = "encryption-test-key";
+
+/// Client over an in-memory store encrypted with a rotated keyring
+async fn create_client() -> (Client, Arc<CollectionGuard>) {
+    let keyring = Arc::new(
+        Keyring::new(
+            "v2",
+            [
+                ("v1".to_string(), vec![1u8; 32]),
+                ("v2".to_string(), vec![2u8; 32]),
+            ],
+        )
+        .expect("keyring"),
+    );
+    let guard = create_guard(Arc::new(EncryptedVectorStoreProvider::new(
+        InMemoryVectorStoreProvider::new(),
+        keyring.clone() as Arc<dyn CryptoProvider>,
+    )));
+    let state = EncryptionAdminState {
+        jobs: Arc::new(ReencryptionJobs::new(guard.clone(), keyring)),
+    };
+    let client =
+        create_admin_client(ADMIN_KEY, |rocket| with_encryption_routes(rocket, state)).await;
+    (client, guard)
+}
+
+#[rocket::async_test]
+async fn test_reencrypt_collection_reports_progress() {
+    let (client, guard) = create_client().await;
+    seed_collection(&guard, "code").await;
+
+    let response = client
+        .post("/encryption/reencrypt")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"collection": "code"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Accepted);
+    let status: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    assert_eq!(status["key_id"], "v2");
+
+    let mut statuses = serde_json::Value::Null;
+    for _ in 0..100 {
+        let response = client
+            .get("/encryption/reencrypt")
+            .header(Header::new("X-Admin-Key", ADMIN_KEY))
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::Ok);
+        statuses =
+            serde_json::from_str(&response.into_string().await.expect("body")).expect("json");
+        if statuses[0]["state"] != "running" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert_eq!(statuses[0]["collection"], "code");
+    assert_eq!(statuses[0]["state"], "completed");
+    assert_eq!(statuses[0]["reencrypted"], 1);
+}
+
+#[rocket::async_test]
+async fn test_reencrypt_unknown_collection_is_not_found() {
+    let (client, _) = create_client().await;
+
+    let response = client
+        .post("/encryption/reencrypt")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"collection": "missing"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[rocket::async_test]
+async fn test_encryption_routes_require_auth() {
+    let (client, _) = create_client().await;
+
+    let response = client.get("/encryption/reencrypt").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+}
@@ -0,0 +1,208 @@
+//! Event History Tests
+//!
+//! Verifies that `/events/history` serves filtered stored events and that
+//! `/events` replays missed events to clients resuming with `Last-Event-ID`.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{
+    DomainEventStream, EventBusProvider, EventStoreInterface,
+};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_infrastructure::event_store::JsonlEventStore;
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_server::admin::{
+    EventStoreState,
+    auth::AdminAuthConfig,
+    handlers::AdminState,
+    routes::{admin_rocket, with_event_routes},
+};
+use rocket::http::{Header, Status};
+use rocket::local::asynchronous::{Client, LocalResponse};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+
+const ADMIN_KEY: &str = "events-test-key";
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state() -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    }
+}
+
+/// Client with a store holding three events: alpha started, alpha
+/// progress and beta progress
+async fn create_client() -> (Client, Arc<JsonlEventStore>) {
+    let store = Arc::new(JsonlEventStore::in_memory(100, Duration::from_secs(3600)));
+    for event in [
+        DomainEvent::IndexingStarted {
+            collection: "alpha".to_string(),
+            total_files: 2,
+        },
+        progress("alpha"),
+        progress("beta"),
+    ] {
+        store.append(event).await.unwrap();
+    }
+
+    let auth_config = Arc::new(AdminAuthConfig::new(
+        true,
+        "X-Admin-Key".to_string(),
+        Some(ADMIN_KEY.to_string()),
+    ));
+    let rocket = with_event_routes(
+        admin_rocket(create_test_state(), auth_config, None),
+        EventStoreState {
+            store: store.clone(),
+        },
+    );
+    let client = Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance");
+    (client, store)
+}
+
+fn progress(collection: &str) -> DomainEvent {
+    DomainEvent::IndexingProgress {
+        collection: collection.to_string(),
+        processed: 1,
+        total: 2,
+        current_file: None,
+    }
+}
+
+fn admin_key() -> Header<'static> {
+    Header::new("X-Admin-Key", ADMIN_KEY)
+}
+
+/// Read the SSE body until `count` event ids arrived
+async fn read_event_ids(mut response: LocalResponse<'_>, count: usize) -> Vec<u64> {
+    let ids = |body: &[u8]| -> Vec<u64> {
+        String::from_utf8_lossy(body)
+            .lines()
+            .filter_map(|line| line.strip_prefix("id:"))
+            .filter_map(|id| id.trim().parse().ok())
+            .collect()
+    };
+
+    let mut body = Vec::new();
+    let mut chunk = [0u8; 1024];
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let seen = ids(&body);
+            if seen.len() >= count {
+                return seen;
+            }
+            let n = response.read(&mut chunk).await.expect("read SSE body");
+            if n == 0 {
+                return ids(&body);
+            }
+            body.extend_from_slice(&chunk[..n]);
+        }
+    })
+    .await
+    .expect("events not streamed")
+}
+
+#[rocket::async_test]
+async fn test_history_filters_by_type_and_collection() {
+    let (client, _store) = create_client().await;
+
+    let response = client
+        .get("/events/history?type=IndexingProgress&collection=alpha")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.unwrap()).unwrap();
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["last_sequence"], 3);
+    assert_eq!(body["events"][0]["sequence"], 2);
+    assert_eq!(
+        body["events"][0]["event"]["IndexingProgress"]["collection"],
+        "alpha"
+    );
+}
+
+#[rocket::async_test]
+async fn test_history_rejects_invalid_timestamps() {
+    let (client, _store) = create_client().await;
+
+    let response = client
+        .get("/events/history?since=yesterday")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[rocket::async_test]
+async fn test_history_requires_auth() {
+    let (client, _store) = create_client().await;
+
+    let response = client.get("/events/history").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+}
+
+#[rocket::async_test]
+async fn test_stream_replays_events_after_last_event_id() {
+    let (client, store) = create_client().await;
+
+    let response = client
+        .get("/events")
+        .header(Header::new("Last-Event-ID", "1"))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    store.append(progress("alpha")).await.unwrap();
+
+    assert_eq!(read_event_ids(response, 3).await, [2, 3, 4]);
+}
+
+#[rocket::async_test]
+async fn test_stream_accepts_last_event_id_query_parameter() {
+    let (client, _store) = create_client().await;
+
+    let response = client.get("/events?last_event_id=2").dispatch().await;
+    assert_eq!(response.status(), Status::Ok);
+
+    assert_eq!(read_event_ids(response, 1).await, [3]);
+}
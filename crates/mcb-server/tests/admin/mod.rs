@@ -3,10 +3,140 @@
 //! Integration tests for the admin HTTP API endpoints.
 
 mod api_test;
+mod audit_test;
 mod auth_integration_test;
 mod auth_test;
+mod backups_test;
+mod bundles_test;
+mod cache_test;
+mod embedding_routing_test;
+mod encryption_test;
+mod events_test;
 mod integration_test;
 mod lifecycle_handlers_test;
 mod propagation_test;
+mod provider_health_test;
+mod providers_test;
+mod rate_limit_test;
 mod sse_test;
+mod user_handlers_test;
 mod web_test;
+
+// Fixtures shared by the collection admin tests (backups, bundles,
+// encryption, providers)
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::providers::VectorStoreProvider;
+use mcb_infrastructure::collections::{CollectionGuard, CollectionRegistry};
+use mcb_infrastructure::config::AppConfig;
+use mcb_infrastructure::di::{
+    EmbeddingProviderHandle, EmbeddingProviderResolver, VectorStoreProviderHandle,
+    VectorStoreProviderResolver,
+};
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_providers::embedding::NullEmbeddingProvider;
+use mcb_server::admin::{auth::AdminAuthConfig, handlers::AdminState, routes::admin_rocket};
+use rocket::local::asynchronous::Client;
+use rocket::{Build, Rocket};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state() -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    }
+}
+
+/// Guard using the null embedding provider over `store`, with an in-memory
+/// collection registry
+fn create_guard(store: Arc<dyn VectorStoreProvider>) -> Arc<CollectionGuard> {
+    let config = Arc::new(AppConfig::default());
+    Arc::new(CollectionGuard::new(
+        Arc::new(EmbeddingProviderResolver::new(config.clone())),
+        Arc::new(EmbeddingProviderHandle::new(Arc::new(
+            NullEmbeddingProvider::new(),
+        ))),
+        Arc::new(VectorStoreProviderResolver::new(config)),
+        Arc::new(VectorStoreProviderHandle::new(store)),
+        Arc::new(CollectionRegistry::in_memory()),
+    ))
+}
+
+/// Client for the admin API guarded by `admin_key`, with the routes under
+/// test added by `mount`
+async fn create_admin_client(
+    admin_key: &str,
+    mount: impl FnOnce(Rocket<Build>) -> Rocket<Build>,
+) -> Client {
+    let auth_config = Arc::new(AdminAuthConfig::new(
+        true,
+        "X-Admin-Key".to_string(),
+        Some(admin_key.to_string()),
+    ));
+    let rocket = mount(admin_rocket(create_test_state(), auth_config, None));
+    Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance")
+}
+
+/// Index one chunk into `collection` with the active embedding provider
+async fn seed_collection(guard: &Arc<CollectionGuard>, collection: &str) {
+    let embedding = guard
+        .embedding_provider()
+        .embed("fn main() {}")
+        .await
+        .expect("embed");
+    let store = guard.vector_store_provider();
+    store
+        .create_collection(collection, embedding.dimensions)
+        .await
+        .expect("create");
+    store
+        .insert_vectors(
+            collection,
+            &[embedding],
+            vec![HashMap::from([(
+                "content".to_string(),
+                serde_json::json!("fn main() {}"),
+            )])],
+        )
+        .await
+        .expect("insert");
+}
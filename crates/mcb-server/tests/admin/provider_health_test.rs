@@ -0,0 +1,161 @@
+//! Provider Health Tests
+//!
+//! Verifies `/health/providers` and circuit breaker entries in
+//! `/health/extended`.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::infrastructure::ProviderRouter;
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_infrastructure::resilience::{ProviderKind, ProviderResilience, RetryPolicy};
+use mcb_infrastructure::routing::{DefaultProviderRouter, InMemoryHealthMonitor};
+use mcb_server::admin::{
+    ProviderHealthState,
+    auth::AdminAuthConfig,
+    handlers::AdminState,
+    routes::{admin_rocket, with_provider_health},
+};
+use rocket::http::{Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+use std::time::Duration;
+
+const ADMIN_KEY: &str = "provider-health-test-key";
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state() -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    }
+}
+
+/// Client with an open breaker for `ollama` and a healthy one for `milvus`
+async fn create_client() -> Client {
+    let auth_config = Arc::new(AdminAuthConfig::new(
+        true,
+        "X-Admin-Key".to_string(),
+        Some(ADMIN_KEY.to_string()),
+    ));
+    let router: Arc<dyn ProviderRouter> = Arc::new(DefaultProviderRouter::new(
+        Arc::new(InMemoryHealthMonitor::new()),
+        vec!["ollama".to_string()],
+        vec!["milvus".to_string()],
+    ));
+    router
+        .report_failure("ollama", "server error (503)")
+        .await
+        .expect("report");
+
+    let resilience = Arc::new(ProviderResilience::new(
+        RetryPolicy::none(),
+        1,
+        1,
+        Duration::from_secs(60),
+    ));
+    let breaker = resilience.breaker(ProviderKind::Embedding, "ollama");
+    breaker.try_acquire().expect("closed").record_failure();
+    resilience.breaker(ProviderKind::VectorStore, "milvus");
+
+    let rocket = with_provider_health(
+        admin_rocket(create_test_state(), auth_config, None),
+        ProviderHealthState {
+            router,
+            resilience: Some(resilience),
+        },
+    );
+    Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance")
+}
+
+fn admin_key() -> Header<'static> {
+    Header::new("X-Admin-Key", ADMIN_KEY)
+}
+
+#[rocket::async_test]
+async fn test_provider_health_lists_breakers() {
+    let client = create_client().await;
+
+    let response = client
+        .get("/health/providers")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    let breakers = body["circuit_breakers"].as_array().expect("breakers");
+    assert_eq!(breakers.len(), 2);
+    assert_eq!(breakers[0]["kind"], "embedding");
+    assert_eq!(breakers[0]["provider"], "ollama");
+    assert_eq!(breakers[0]["state"], "open");
+    assert_eq!(breakers[1]["provider"], "milvus");
+    assert_eq!(breakers[1]["state"], "closed");
+    assert!(body["providers"]["ollama"].is_string());
+}
+
+#[rocket::async_test]
+async fn test_provider_health_requires_auth() {
+    let client = create_client().await;
+
+    let response = client.get("/health/providers").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+}
+
+#[rocket::async_test]
+async fn test_extended_health_reports_open_breaker() {
+    let client = create_client().await;
+
+    let response = client
+        .get("/health/extended")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    let dependencies = body["dependencies"].as_array().expect("dependencies");
+    let ollama = dependencies
+        .iter()
+        .find(|dep| dep["name"] == "embedding:ollama")
+        .expect("breaker listed");
+    assert_eq!(ollama["status"], "Unhealthy");
+    assert_eq!(body["status"], "degraded");
+}
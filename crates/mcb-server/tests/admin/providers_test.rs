@@ -0,0 +1,133 @@
+//! Provider Switching Tests
+//!
+//! Verifies the `/providers` endpoints list providers and refuse switches
+//! that would leave collections in another embedding space.
+
+use super::{create_admin_client, create_guard};
+use mcb_domain::value_objects::Embedding;
+use mcb_infrastructure::collections::CollectionGuard;
+use mcb_providers::vector_store::InMemoryVectorStoreProvider;
+use mcb_server::admin::{ProviderAdminState, routes::with_provider_admin};
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::asynchronous::Client;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const ADMIN_KEY: &str = "providers-test-key";
+
+/// Client over an in-memory store
+async fn create_client() -> (Client, Arc<CollectionGuard>) {
+    let guard = create_guard(Arc::new(InMemoryVectorStoreProvider::new()));
+    let state = ProviderAdminState {
+        guard: guard.clone(),
+    };
+    let client = create_admin_client(ADMIN_KEY, |rocket| with_provider_admin(rocket, state)).await;
+    (client, guard)
+}
+
+/// Store one vector in `collection` with a foreign provider's embedding
+async fn seed_collection(guard: &Arc<CollectionGuard>, collection: &str, dimensions: usize) {
+    let store = guard.vector_store_provider();
+    store
+        .create_collection(collection, dimensions)
+        .await
+        .expect("create");
+    store
+        .insert_vectors(
+            collection,
+            &[Embedding {
+                vector: vec![0.0; dimensions],
+                model: "legacy-model".to_string(),
+                dimensions,
+            }],
+            vec![HashMap::from([(
+                "content".to_string(),
+                serde_json::json!("fn main() {}"),
+            )])],
+        )
+        .await
+        .expect("insert");
+}
+
+#[rocket::async_test]
+async fn test_list_providers() {
+    let (client, _) = create_client().await;
+
+    let response = client
+        .get("/providers")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    assert_eq!(body["embedding"]["current"], "null");
+    assert!(
+        body["embedding"]["available"]
+            .as_array()
+            .expect("available")
+            .iter()
+            .any(|p| p["name"] == "null")
+    );
+    assert_eq!(body["vector_store"]["current"], "in_memory");
+}
+
+#[rocket::async_test]
+async fn test_switch_refused_for_incompatible_collection() {
+    let (client, guard) = create_client().await;
+    seed_collection(&guard, "code", 3).await;
+
+    let response = client
+        .post("/providers/embedding/switch")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"provider": "null"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Conflict);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    assert_eq!(body["status"], "refused");
+    assert_eq!(body["conflicts"][0], "code");
+
+    let response = client
+        .get("/providers/collections")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .dispatch()
+        .await;
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.expect("body")).expect("json body");
+    assert_eq!(body[0]["collection"], "code");
+    assert_eq!(body[0]["model"], "legacy-model");
+}
+
+#[rocket::async_test]
+async fn test_unknown_provider_is_rejected() {
+    let (client, _) = create_client().await;
+
+    let response = client
+        .post("/providers/embedding/switch")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .header(ContentType::JSON)
+        .body(r#"{"provider": "does-not-exist"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[rocket::async_test]
+async fn test_providers_require_auth() {
+    let (client, _) = create_client().await;
+
+    let response = client.get("/providers").dispatch().await;
+    assert_eq!(response.status(), Status::Unauthorized);
+
+    let response = client
+        .get("/providers/embedding/migration")
+        .header(Header::new("X-Admin-Key", ADMIN_KEY))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::NotFound);
+}
@@ -0,0 +1,148 @@
+//! Admin Rate Limiting Tests
+//!
+//! Verifies `429` responses with `Retry-After` on guarded admin endpoints
+//! and the `/metrics/rate-limits` view.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_infrastructure::ratelimit::RateLimiter;
+use mcb_server::admin::{
+    auth::AdminAuthConfig,
+    handlers::AdminState,
+    routes::{admin_rocket, with_rate_limiter},
+};
+use rocket::http::{Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+
+const ADMIN_KEY: &str = "rate-limit-test-key";
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state() -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    }
+}
+
+/// Client whose callers get `burst` standard requests
+async fn create_client(burst: u32) -> Client {
+    let auth_config = Arc::new(AdminAuthConfig::new(
+        true,
+        "X-Admin-Key".to_string(),
+        Some(ADMIN_KEY.to_string()),
+    ));
+    let limiter = Arc::new(RateLimiter::new(1, burst, 1, 1, Vec::new()));
+    let rocket = with_rate_limiter(
+        admin_rocket(create_test_state(), auth_config, None),
+        limiter,
+    );
+    Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance")
+}
+
+fn admin_key() -> Header<'static> {
+    Header::new("X-Admin-Key", ADMIN_KEY)
+}
+
+#[rocket::async_test]
+async fn test_guarded_endpoint_returns_429_with_retry_after() {
+    let client = create_client(2).await;
+
+    for _ in 0..2 {
+        let response = client.get("/metrics").header(admin_key()).dispatch().await;
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    let response = client.get("/metrics").header(admin_key()).dispatch().await;
+    assert_eq!(response.status(), Status::TooManyRequests);
+    assert_eq!(response.headers().get_one("Retry-After"), Some("1"));
+}
+
+#[rocket::async_test]
+async fn test_sessions_have_separate_buckets() {
+    let client = create_client(1).await;
+
+    let response = client
+        .get("/metrics")
+        .header(admin_key())
+        .header(Header::new("X-Session-Id", "a"))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    // Same principal (`admin-key`) shares its bucket across sessions
+    let response = client
+        .get("/metrics")
+        .header(admin_key())
+        .header(Header::new("X-Session-Id", "b"))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::TooManyRequests);
+}
+
+#[rocket::async_test]
+async fn test_probes_are_not_limited() {
+    let client = create_client(1).await;
+
+    for _ in 0..3 {
+        let response = client.get("/live").dispatch().await;
+        assert_eq!(response.status(), Status::Ok);
+    }
+}
+
+#[rocket::async_test]
+async fn test_rate_limit_stats_endpoint() {
+    let client = create_client(3).await;
+    client.get("/metrics").header(admin_key()).dispatch().await;
+
+    let response = client
+        .get("/metrics/rate-limits")
+        .header(admin_key())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.unwrap()).unwrap();
+    assert_eq!(body["standard_burst"], 3);
+    assert_eq!(body["allowed_total"], 2);
+    assert_eq!(body["limited_total"], 0);
+    assert_eq!(body["buckets"][0]["key"], "principal:admin-key");
+}
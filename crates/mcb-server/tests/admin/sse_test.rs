@@ -87,6 +87,8 @@ fn test_get_event_name_search_executed() {
 fn test_get_event_name_cache_invalidate() {
     let event = DomainEvent::CacheInvalidate {
         namespace: Some("embeddings".to_string()),
+        key: None,
+        origin: None,
     };
     assert_eq!(get_event_name(&event), "CacheInvalidate");
 }
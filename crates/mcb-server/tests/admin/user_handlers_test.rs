@@ -0,0 +1,277 @@
+//! User Login and Management Endpoint Tests
+//!
+//! Verifies `/auth/login`, `/users` CRUD and bearer-token access to
+//! protected admin endpoints when the user database is configured.
+
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_infrastructure::auth::{UserAuthService, UserRole};
+use mcb_infrastructure::config::AuthConfig;
+use mcb_infrastructure::infrastructure::{AtomicPerformanceMetrics, DefaultIndexingOperations};
+use mcb_server::admin::{
+    auth::AdminAuthConfig,
+    handlers::AdminState,
+    routes::{admin_rocket, with_user_routes},
+    user_handlers::UserAuthState,
+};
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::asynchronous::Client;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+/// Null EventBus for testing
+struct TestEventBus;
+
+#[async_trait]
+impl EventBusProvider for TestEventBus {
+    async fn publish_event(&self, _event: DomainEvent) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: &str) -> Result<String> {
+        Ok("test-subscription".to_string())
+    }
+}
+
+fn create_test_state() -> AdminState {
+    AdminState {
+        metrics: Arc::new(AtomicPerformanceMetrics::new()),
+        indexing: Arc::new(DefaultIndexingOperations::new()),
+        config_watcher: None,
+        config_path: None,
+        shutdown_coordinator: None,
+        shutdown_timeout_secs: 30,
+        event_bus: Arc::new(TestEventBus),
+        service_manager: None,
+        cache: None,
+    }
+}
+
+/// Build a client with admin auth enabled, no shared key, and a user
+/// database containing one admin (`root`) and one viewer (`alice`)
+async fn create_client(dir: &TempDir) -> Client {
+    let mut config = AuthConfig::default();
+    config.jwt.secret = "0123456789abcdef0123456789abcdef".to_string();
+    config.user_db_path = Some(dir.path().join("users.json"));
+
+    let service = UserAuthService::from_config(&config)
+        .await
+        .expect("valid config")
+        .expect("user database configured");
+    service
+        .bootstrap_admin("root", "root-password")
+        .await
+        .expect("bootstrap admin");
+    service
+        .create_user("alice", "alice-password", UserRole::Viewer)
+        .await
+        .expect("create viewer");
+
+    let auth_config = Arc::new(AdminAuthConfig::new(true, "X-Admin-Key".to_string(), None));
+    let rocket = with_user_routes(
+        admin_rocket(create_test_state(), auth_config, None),
+        UserAuthState {
+            service: Arc::new(service),
+        },
+    );
+    Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance")
+}
+
+async fn login(client: &Client, username: &str, password: &str) -> (Status, serde_json::Value) {
+    let response = client
+        .post("/auth/login")
+        .header(ContentType::JSON)
+        .body(serde_json::json!({ "username": username, "password": password }).to_string())
+        .dispatch()
+        .await;
+    let status = response.status();
+    let body = response.into_string().await.unwrap_or_default();
+    (status, serde_json::from_str(&body).unwrap_or_default())
+}
+
+fn bearer(token: &serde_json::Value) -> Header<'static> {
+    Header::new(
+        "Authorization",
+        format!("Bearer {}", token.as_str().expect("token string")),
+    )
+}
+
+#[rocket::async_test]
+async fn test_login_returns_tokens() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+
+    let (status, body) = login(&client, "root", "root-password").await;
+
+    assert_eq!(status, Status::Ok);
+    assert_eq!(body["token_type"], "Bearer");
+    assert!(body["access_token"].is_string());
+    assert!(body["refresh_token"].is_string());
+}
+
+#[rocket::async_test]
+async fn test_login_with_wrong_password_returns_401() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+
+    let (status, body) = login(&client, "root", "not-the-password").await;
+
+    assert_eq!(status, Status::Unauthorized);
+    assert_eq!(body["code"], "UNAUTHORIZED");
+}
+
+#[rocket::async_test]
+async fn test_admin_token_grants_access_to_protected_endpoints() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+    let (_, tokens) = login(&client, "root", "root-password").await;
+
+    let response = client
+        .get("/metrics")
+        .header(bearer(&tokens["access_token"]))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client
+        .get("/users")
+        .header(bearer(&tokens["access_token"]))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.unwrap()).unwrap();
+    assert_eq!(body["total"], 2);
+    assert!(body["users"][0].get("password_hash").is_none());
+}
+
+#[rocket::async_test]
+async fn test_viewer_token_is_forbidden_on_admin_endpoints() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+    let (_, tokens) = login(&client, "alice", "alice-password").await;
+
+    let response = client
+        .get("/users")
+        .header(bearer(&tokens["access_token"]))
+        .dispatch()
+        .await;
+
+    assert_eq!(response.status(), Status::Forbidden);
+}
+
+#[rocket::async_test]
+async fn test_users_without_credentials_returns_401() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+
+    let response = client.get("/users").dispatch().await;
+
+    assert_eq!(response.status(), Status::Unauthorized);
+}
+
+#[rocket::async_test]
+async fn test_user_crud_lifecycle() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+    let (_, tokens) = login(&client, "root", "root-password").await;
+    let auth = bearer(&tokens["access_token"]);
+
+    let response = client
+        .post("/users")
+        .header(ContentType::JSON)
+        .header(auth.clone())
+        .body(r#"{"username":"bob","password":"bob-password","role":"editor"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Created);
+
+    let response = client
+        .patch("/users/bob")
+        .header(ContentType::JSON)
+        .header(auth.clone())
+        .body(r#"{"role":"viewer"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let body: serde_json::Value =
+        serde_json::from_str(&response.into_string().await.unwrap()).unwrap();
+    assert_eq!(body["role"], "viewer");
+
+    let response = client
+        .delete("/users/bob")
+        .header(auth.clone())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::NoContent);
+
+    let response = client.get("/users/bob").header(auth).dispatch().await;
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[rocket::async_test]
+async fn test_cannot_delete_last_admin() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+    let (_, tokens) = login(&client, "root", "root-password").await;
+
+    let response = client
+        .delete("/users/root")
+        .header(bearer(&tokens["access_token"]))
+        .dispatch()
+        .await;
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[rocket::async_test]
+async fn test_admin_scope_without_admin_role_cannot_manage_users() {
+    let dir = TempDir::new().unwrap();
+    let client = create_client(&dir).await;
+    let (_, tokens) = login(&client, "root", "root-password").await;
+
+    let response = client
+        .post("/users")
+        .header(ContentType::JSON)
+        .header(bearer(&tokens["access_token"]))
+        .body(
+            r#"{"username":"carol","password":"carol-password","role":"editor","scopes":["collection:*:admin"]}"#,
+        )
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Created);
+
+    let (_, tokens) = login(&client, "carol", "carol-password").await;
+    let auth = bearer(&tokens["access_token"]);
+
+    let response = client.get("/metrics").header(auth.clone()).dispatch().await;
+    assert_eq!(response.status(), Status::Ok);
+
+    let response = client
+        .patch("/users/carol")
+        .header(ContentType::JSON)
+        .header(auth.clone())
+        .body(r#"{"role":"admin"}"#)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Forbidden);
+
+    let response = client.get("/users").header(auth).dispatch().await;
+    assert_eq!(response.status(), Status::Forbidden);
+}
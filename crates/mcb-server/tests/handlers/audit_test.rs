@@ -0,0 +1,75 @@
+//! Tests for tool call auditing
+
+use mcb_domain::ports::infrastructure::{AuditLogInterface, AuditOutcome, AuditQuery, AuditSource};
+use mcb_infrastructure::audit::JsonlAuditLog;
+use mcb_server::McpServerBuilder;
+use mcb_server::mcp_server::McpServer;
+use rmcp::model::CallToolRequestParams;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+use crate::test_utils::mock_services::{
+    MockContextService, MockIndexingService, MockSearchService,
+};
+
+fn create_server(log: Arc<dyn AuditLogInterface>) -> McpServer {
+    McpServerBuilder::new()
+        .with_indexing_service(Arc::new(MockIndexingService::new()))
+        .with_context_service(Arc::new(MockContextService::new()))
+        .with_search_service(Arc::new(MockSearchService::new()))
+        .with_audit_log(log)
+        .build()
+        .expect("Failed to build MCP server")
+}
+
+fn tool_call(name: &str, arguments: serde_json::Value) -> CallToolRequestParams {
+    CallToolRequestParams {
+        name: name.to_string().into(),
+        arguments: arguments.as_object().cloned(),
+        task: None,
+        meta: None,
+    }
+}
+
+#[tokio::test]
+async fn test_tool_call_is_recorded_with_redacted_arguments() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let server = create_server(log.clone());
+
+    let request = tool_call(
+        "search_code",
+        serde_json::json!({ "query": "auth", "collection": "default", "token": "secret" }),
+    );
+    server
+        .call_tool_with_session(request, Some("session-1"))
+        .await
+        .expect("tool call succeeds");
+
+    let events = log.query(&AuditQuery::default()).await.unwrap();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.source, AuditSource::Tool);
+    assert_eq!(event.action, "search_code");
+    assert_eq!(event.session.as_deref(), Some("session-1"));
+    assert_eq!(event.principal.as_deref(), Some("anonymous"));
+    assert_eq!(event.arguments["query"], "auth");
+    assert_eq!(event.arguments["token"], "[REDACTED]");
+}
+
+#[tokio::test]
+async fn test_failed_tool_call_is_recorded_as_failure() {
+    let dir = TempDir::new().unwrap();
+    let log = Arc::new(JsonlAuditLog::new(dir.path(), 1024 * 1024, 1));
+    let server = create_server(log.clone());
+
+    let result = server
+        .call_tool_with_session(tool_call("no_such_tool", serde_json::json!({})), None)
+        .await;
+    assert!(result.is_err());
+
+    let events = log.query(&AuditQuery::default()).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].outcome, AuditOutcome::Failure);
+    assert!(events[0].error.is_some());
+}
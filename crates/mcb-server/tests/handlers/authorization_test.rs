@@ -0,0 +1,119 @@
+//! Tests for collection scope enforcement in tool handlers
+
+use mcb_infrastructure::config::{AppConfig, ScopedApiKeyConfig};
+use mcb_server::args::{ClearIndexArgs, SearchCodeArgs};
+use mcb_server::auth::CollectionAuthorizer;
+use mcb_server::constants::{
+    ERROR_CODE_COLLECTION_ACCESS_DENIED, JSONRPC_FORBIDDEN, JSONRPC_UNAUTHENTICATED,
+};
+use mcb_server::handlers::{ClearIndexHandler, SearchCodeHandler};
+use rmcp::handler::server::wrapper::Parameters;
+use std::sync::Arc;
+
+use crate::test_utils::mock_services::{MockIndexingService, MockSearchService};
+
+/// Authorizer with a reader of `team-a-*` and a global admin key
+fn create_authorizer() -> Arc<CollectionAuthorizer> {
+    let mut config = AppConfig::default();
+    config.auth.enabled = true;
+    config.auth.api_key.keys = vec![
+        ScopedApiKeyConfig {
+            name: "team-a-reader".to_string(),
+            key: "reader-key".to_string(),
+            scopes: vec!["collection:team-a-*:read".to_string()],
+        },
+        ScopedApiKeyConfig {
+            name: "ops".to_string(),
+            key: "admin-key".to_string(),
+            scopes: vec!["collection:*:admin".to_string()],
+        },
+    ];
+    Arc::new(CollectionAuthorizer::from_config(&config, None).expect("valid scopes"))
+}
+
+fn search_args(collection: &str, token: Option<&str>) -> SearchCodeArgs {
+    SearchCodeArgs {
+        query: "authentication".to_string(),
+        limit: 10,
+        collection: Some(collection.to_string()),
+        extensions: None,
+        filters: None,
+        token: token.map(str::to_string),
+    }
+}
+
+fn clear_args(collection: &str, token: Option<&str>) -> ClearIndexArgs {
+    ClearIndexArgs {
+        collection: collection.to_string(),
+        token: token.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn test_search_allowed_within_scope() {
+    let handler = SearchCodeHandler::new(Arc::new(MockSearchService::new()))
+        .with_authorizer(create_authorizer());
+
+    let result = handler
+        .handle(Parameters(search_args("team-a-api", Some("reader-key"))))
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_search_denied_outside_scope() {
+    let handler = SearchCodeHandler::new(Arc::new(MockSearchService::new()))
+        .with_authorizer(create_authorizer());
+
+    let err = handler
+        .handle(Parameters(search_args("team-b-api", Some("reader-key"))))
+        .await
+        .expect_err("collection outside scope");
+
+    assert_eq!(err.code.0, JSONRPC_FORBIDDEN);
+    let data = err.data.expect("error data");
+    assert_eq!(data["code"], ERROR_CODE_COLLECTION_ACCESS_DENIED);
+    assert_eq!(data["collection"], "team-b-api");
+}
+
+#[tokio::test]
+async fn test_search_without_token_is_unauthenticated() {
+    let handler = SearchCodeHandler::new(Arc::new(MockSearchService::new()))
+        .with_authorizer(create_authorizer());
+
+    let err = handler
+        .handle(Parameters(search_args("team-a-api", None)))
+        .await
+        .expect_err("token required");
+
+    assert_eq!(err.code.0, JSONRPC_UNAUTHENTICATED);
+}
+
+#[tokio::test]
+async fn test_clear_index_requires_admin_scope() {
+    let handler = ClearIndexHandler::new(Arc::new(MockIndexingService::new()))
+        .with_authorizer(create_authorizer());
+
+    let denied = handler
+        .handle(Parameters(clear_args("team-a-api", Some("reader-key"))))
+        .await
+        .expect_err("read scope cannot clear");
+    assert_eq!(denied.code.0, JSONRPC_FORBIDDEN);
+
+    let allowed = handler
+        .handle(Parameters(clear_args("team-a-api", Some("admin-key"))))
+        .await;
+    assert!(allowed.is_ok());
+}
+
+#[tokio::test]
+async fn test_disabled_authorizer_allows_anonymous_calls() {
+    let handler = ClearIndexHandler::new(Arc::new(MockIndexingService::new()));
+
+    let result = handler
+        .handle(Parameters(clear_args("anything", None)))
+        .await;
+
+    assert!(result.is_ok());
+}
@@ -14,6 +14,7 @@ async fn test_clear_index_valid_collection() {
 
     let args = ClearIndexArgs {
         collection: "test-collection".to_string(),
+        token: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -30,6 +31,7 @@ async fn test_clear_index_default_collection() {
 
     let args = ClearIndexArgs {
         collection: "default".to_string(),
+        token: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -44,6 +46,7 @@ async fn test_clear_index_empty_collection() {
 
     let args = ClearIndexArgs {
         collection: "".to_string(),
+        token: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -59,6 +62,7 @@ async fn test_clear_index_service_error() {
 
     let args = ClearIndexArgs {
         collection: "test-collection".to_string(),
+        token: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -74,6 +78,7 @@ async fn test_clear_index_with_special_characters() {
 
     let args = ClearIndexArgs {
         collection: "my-project_v2".to_string(),
+        token: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -89,6 +94,7 @@ async fn test_clear_index_invalid_characters() {
 
     let args = ClearIndexArgs {
         collection: "my/project".to_string(),
+        token: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -104,6 +110,7 @@ async fn test_clear_index_very_long_name() {
 
     let args = ClearIndexArgs {
         collection: "a".repeat(101), // Exceeds 100 character limit
+        token: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -2,6 +2,8 @@
 //!
 //! Tests for MCP tool handlers.
 
+mod audit_test;
+mod authorization_test;
 mod clear_index_test;
 mod get_indexing_status_test;
 mod index_codebase_test;
@@ -1,12 +1,12 @@
 //! Tests for SearchCodeHandler
 
-use mcb_server::args::SearchCodeArgs;
+use mcb_server::args::{SearchCodeArgs, SearchFilters};
 use mcb_server::handlers::SearchCodeHandler;
 use rmcp::handler::server::wrapper::Parameters;
 use std::sync::Arc;
 
 use crate::test_utils::mock_services::MockSearchService;
-use crate::test_utils::test_fixtures::create_test_search_results;
+use crate::test_utils::test_fixtures::{create_test_search_result, create_test_search_results};
 
 #[tokio::test]
 async fn test_search_code_valid_query() {
@@ -169,3 +169,43 @@ async fn test_search_code_with_collection() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_search_code_applies_extension_and_language_filters() {
+    let mut results = create_test_search_results(2);
+    results.push(create_test_search_result(
+        "scripts/build.py",
+        "def build(): pass",
+        0.9,
+        1,
+    ));
+    let mock_service = MockSearchService::new().with_results(results);
+    let handler = SearchCodeHandler::new(Arc::new(mock_service));
+
+    let args = SearchCodeArgs {
+        query: "build".to_string(),
+        limit: 10,
+        collection: None,
+        extensions: Some(vec!["py".to_string()]),
+        filters: Some(SearchFilters {
+            file_extensions: None,
+            languages: Some(vec!["rust".to_string()]),
+            exclude_patterns: None,
+            min_score: None,
+        }),
+        token: None,
+    };
+
+    let response = handler
+        .handle(Parameters(args))
+        .await
+        .expect("Expected successful response");
+    let text = serde_json::to_value(&response.content).expect("json")[0]["text"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+
+    // The fixture labels every result `rust`, including the Python file
+    assert!(text.contains("scripts/build.py"));
+    assert!(!text.contains("src/file_0.rs"));
+}
@@ -12,10 +12,12 @@ use mcb_domain::error::Result;
 use mcb_domain::events::DomainEvent;
 use mcb_domain::ports::providers::VectorStoreBrowser;
 use mcb_domain::value_objects::{CollectionInfo, FileInfo, SearchResult};
+use mcb_infrastructure::config::{AppConfig, ScopedApiKeyConfig};
 use mcb_server::admin::auth::AdminAuthConfig;
 use mcb_server::admin::browse_handlers::BrowseState;
 use mcb_server::admin::handlers::AdminState;
-use mcb_server::admin::routes::admin_rocket;
+use mcb_server::admin::routes::{admin_rocket, with_collection_authorizer};
+use mcb_server::auth::CollectionAuthorizer;
 use rocket::http::{Header, Status};
 use rocket::local::asynchronous::Client;
 use std::collections::HashMap;
@@ -327,6 +329,92 @@ async fn test_browse_invalid_auth() {
     );
 }
 
+/// Create a client that also accepts a scoped key reading `team-a-*`
+async fn create_scoped_client(browse_state: BrowseState) -> Client {
+    let mut config = AppConfig::default();
+    config.auth.enabled = true;
+    config.auth.api_key.keys = vec![ScopedApiKeyConfig {
+        name: "team-a-reader".to_string(),
+        key: "team-a-key".to_string(),
+        scopes: vec!["collection:team-a-*:read".to_string()],
+    }];
+    let authorizer = CollectionAuthorizer::from_config(&config, None).expect("valid scopes");
+
+    let auth_config = Arc::new(AdminAuthConfig {
+        enabled: true,
+        header_name: "X-Admin-Key".to_string(),
+        api_key: Some("test-key".to_string()),
+    });
+    let rocket = with_collection_authorizer(
+        admin_rocket(create_test_admin_state(), auth_config, Some(browse_state)),
+        Arc::new(authorizer),
+    );
+    Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance")
+}
+
+#[tokio::test]
+async fn test_list_collections_filtered_by_scope() {
+    let collections = vec![
+        CollectionInfo::new("team-a-api".to_string(), 100, 10, None, "memory"),
+        CollectionInfo::new("team-b-api".to_string(), 50, 5, None, "memory"),
+    ];
+    let browse_state = BrowseState {
+        browser: Arc::new(MockVectorStoreBrowser::new().with_collections(collections)),
+    };
+
+    let client = create_scoped_client(browse_state).await;
+
+    let response = client
+        .get("/collections")
+        .header(Header::new("Authorization", "Bearer team-a-key"))
+        .dispatch()
+        .await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let body = response.into_string().await.expect("response body");
+    assert!(body.contains("team-a-api"));
+    assert!(!body.contains("team-b-api"));
+    assert!(body.contains("\"total\":1"));
+}
+
+#[tokio::test]
+async fn test_browse_collection_outside_scope_is_forbidden() {
+    let browse_state = BrowseState {
+        browser: Arc::new(MockVectorStoreBrowser::new()),
+    };
+
+    let client = create_scoped_client(browse_state).await;
+
+    let response = client
+        .get("/collections/team-b-api/files")
+        .header(Header::new("Authorization", "Bearer team-a-key"))
+        .dispatch()
+        .await;
+
+    assert_eq!(response.status(), Status::Forbidden);
+    let body = response.into_string().await.expect("response body");
+    assert!(body.contains("COLLECTION_ACCESS_DENIED"));
+}
+
+#[tokio::test]
+async fn test_scoped_key_cannot_reach_admin_endpoints() {
+    let browse_state = BrowseState {
+        browser: Arc::new(MockVectorStoreBrowser::new()),
+    };
+
+    let client = create_scoped_client(browse_state).await;
+
+    let response = client
+        .get("/config")
+        .header(Header::new("Authorization", "Bearer team-a-key"))
+        .dispatch()
+        .await;
+
+    assert_eq!(response.status(), Status::Forbidden);
+}
+
 // ============================================================================
 // Real End-to-End Tests with InMemoryVectorStore
 // ============================================================================
@@ -20,7 +20,9 @@ use mcb_infrastructure::di::bootstrap::init_app;
 use mcb_infrastructure::di::modules::domain_services::{
     DomainServicesFactory, ServiceDependencies,
 };
+use mcb_infrastructure::ratelimit::{ConnectionLimiter, RateLimiter};
 use mcb_server::McpServerBuilder;
+use mcb_server::constants::JSONRPC_RATE_LIMITED;
 use mcb_server::mcp_server::McpServer;
 use mcb_server::session::SessionManager;
 use mcb_server::transport::http::{HttpTransport, HttpTransportConfig};
@@ -681,6 +683,102 @@ async fn test_http_server_ping() {
     );
 }
 
+#[tokio::test]
+async fn test_http_server_rate_limits_anonymous_callers_by_address() {
+    let port = get_free_port();
+    let limiter = Arc::new(RateLimiter::new(1, 1, 1, 1, Vec::new()));
+    let server = Arc::new(create_test_mcp_server().await.with_rate_limiter(limiter));
+
+    let http_config = HttpTransportConfig::localhost(port);
+    let transport = HttpTransport::new(http_config, server);
+
+    let rocket = transport.rocket();
+    let client = rocket::local::asynchronous::Client::tracked(rocket)
+        .await
+        .expect("Failed to create test client");
+
+    let request = McpRequest {
+        method: "ping".to_string(),
+        params: None,
+        id: Some(serde_json::json!(7)),
+    };
+    let send = |session: &'static str| {
+        client
+            .post("/mcp")
+            .header(rocket::http::ContentType::JSON)
+            .header(rocket::http::Header::new("X-Session-Id", session))
+            .body(serde_json::to_string(&request).unwrap())
+            .dispatch()
+    };
+
+    assert_eq!(send("s1").await.status(), rocket::http::Status::Ok);
+
+    let response = send("s1").await;
+    assert_eq!(response.status(), rocket::http::Status::TooManyRequests);
+    assert_eq!(response.headers().get_one("Retry-After"), Some("1"));
+
+    let body = response.into_string().await.expect("Response body");
+    let mcp_response: McpResponse = serde_json::from_str(&body).expect("Parse response");
+    let error = mcp_response.error.expect("Should be rate limited");
+    assert_eq!(error.code, JSONRPC_RATE_LIMITED);
+    assert_eq!(error.data.expect("error data")["code"], "RATE_LIMITED");
+
+    // A new session id does not get a fresh bucket
+    assert_eq!(
+        send("s2").await.status(),
+        rocket::http::Status::TooManyRequests
+    );
+}
+
+#[tokio::test]
+async fn test_http_server_enforces_requests_per_connection() {
+    let port = get_free_port();
+    let limiter = Arc::new(ConnectionLimiter::new(10, 1));
+    let server = Arc::new(
+        create_test_mcp_server()
+            .await
+            .with_connection_limiter(limiter),
+    );
+
+    let http_config = HttpTransportConfig::localhost(port);
+    let transport = HttpTransport::new(http_config, server);
+
+    let rocket = transport.rocket();
+    let client = rocket::local::asynchronous::Client::tracked(rocket)
+        .await
+        .expect("Failed to create test client");
+
+    let request = McpRequest {
+        method: "ping".to_string(),
+        params: None,
+        id: Some(serde_json::json!(8)),
+    };
+    let ping = || {
+        client
+            .post("/mcp")
+            .header(rocket::http::ContentType::JSON)
+            .body(serde_json::to_string(&request).unwrap())
+            .dispatch()
+    };
+
+    // An open event stream holds the client's only slot
+    let stream = client.get("/events").dispatch().await;
+    assert_eq!(stream.status(), rocket::http::Status::Ok);
+
+    let response = ping().await;
+    assert_eq!(response.status(), rocket::http::Status::TooManyRequests);
+    let body = response.into_string().await.expect("Response body");
+    let mcp_response: McpResponse = serde_json::from_str(&body).expect("Parse response");
+    let error = mcp_response.error.expect("Should be connection limited");
+    assert_eq!(
+        error.data.expect("error data")["code"],
+        "CONNECTION_LIMITED"
+    );
+
+    drop(stream);
+    assert_eq!(ping().await.status(), rocket::http::Status::Ok);
+}
+
 #[tokio::test]
 async fn test_http_server_initialize() {
     let port = get_free_port();
@@ -154,6 +154,14 @@ impl IndexingServiceInterface for MockIndexingService {
         self.status.lock().expect("Lock poisoned").clone()
     }
 
+    async fn delete_file_chunks(&self, _collection: &str, _file_path: &str) -> Result<u64> {
+        if self.should_fail.load(Ordering::SeqCst) {
+            let msg = self.error_message.lock().expect("Lock poisoned").clone();
+            return Err(mcb_domain::error::Error::internal(msg));
+        }
+        Ok(0)
+    }
+
     async fn clear_collection(&self, _collection: &str) -> Result<()> {
         if self.should_fail.load(Ordering::SeqCst) {
             let msg = self.error_message.lock().expect("Lock poisoned").clone();
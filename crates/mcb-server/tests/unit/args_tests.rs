@@ -136,6 +136,7 @@ fn test_index_args_path_traversal() {
 fn test_clear_args_valid() {
     let args = ClearIndexArgs {
         collection: "my-collection".to_string(),
+        token: None,
     };
 
     assert!(args.validate().is_ok());
@@ -145,6 +146,7 @@ fn test_clear_args_valid() {
 fn test_clear_args_empty_collection() {
     let args = ClearIndexArgs {
         collection: "".to_string(),
+        token: None,
     };
 
     assert!(args.validate().is_err());
@@ -154,6 +156,7 @@ fn test_clear_args_empty_collection() {
 fn test_clear_args_invalid_characters() {
     let args = ClearIndexArgs {
         collection: "my/collection".to_string(),
+        token: None,
     };
 
     assert!(args.validate().is_err());
@@ -163,6 +166,7 @@ fn test_clear_args_invalid_characters() {
 fn test_clear_args_collection_too_long() {
     let args = ClearIndexArgs {
         collection: "a".repeat(101),
+        token: None,
     };
 
     assert!(args.validate().is_err());
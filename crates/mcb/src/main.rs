@@ -11,12 +11,24 @@
 //! | **Standalone** | `mcb` (config: `mode.type = "standalone"`) | Local providers, stdio transport |
 //! | **Server** | `mcb --server` | HTTP daemon, accepts client connections |
 //! | **Client** | `mcb` (config: `mode.type = "client"`) | Connects to server via HTTP |
+//!
+//! ## Commands
+//!
+//! | Command | Description |
+//! |---------|-------------|
+//! | `mcb bootstrap-admin --username <name>` | Create the first admin in `auth.user_db_path` |
+//! | `mcb export --collection <name> --output <file>` | Write a collection to a portable bundle |
+//! | `mcb import --input <file>` | Import a bundle into the configured vector store |
 
 // Force-link mcb-providers to ensure linkme inventory registrations are included
 extern crate mcb_providers;
 
-use clap::Parser;
-use mcb_server::run;
+use clap::{Parser, Subcommand};
+use mcb_infrastructure::collections::ExportOptions;
+use mcb_server::{bootstrap_admin, export_collection, import_collection, run};
+
+/// Environment variable holding the password for `bootstrap-admin`
+const BOOTSTRAP_PASSWORD_ENV: &str = "MCB_ADMIN_PASSWORD";
 
 /// Command line interface for MCP Context Browser
 #[derive(Parser, Debug)]
@@ -35,6 +47,54 @@ pub struct Cli {
     /// config file to determine if it should run in standalone or client mode.
     #[arg(long, help = "Run as server daemon")]
     pub server: bool,
+
+    /// One-off administrative command (runs instead of the server)
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Administrative commands
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Create the first admin user in the configured user database
+    ///
+    /// The password is read from the `MCB_ADMIN_PASSWORD` environment
+    /// variable, or from the first line of stdin when it is not set.
+    BootstrapAdmin {
+        /// Username of the admin to create
+        #[arg(long, default_value = "admin")]
+        username: String,
+    },
+    /// Export a collection to a portable bundle file
+    ///
+    /// Encryption uses the key derived from `auth.jwt.secret`, which must
+    /// be at least 32 bytes.
+    Export {
+        /// Collection to export
+        #[arg(long)]
+        collection: String,
+        /// Bundle file to write
+        #[arg(long)]
+        output: std::path::PathBuf,
+        /// gzip the bundle contents
+        #[arg(long)]
+        compress: bool,
+        /// Encrypt the bundle contents
+        #[arg(long)]
+        encrypt: bool,
+    },
+    /// Import a bundle file into the configured vector store
+    ///
+    /// Refused when the configured embedding provider uses another model
+    /// or dimensionality than the bundle.
+    Import {
+        /// Bundle file to read
+        #[arg(long)]
+        input: std::path::PathBuf,
+        /// Collection to import into (defaults to the exported collection)
+        #[arg(long)]
+        collection: Option<String>,
+    },
 }
 
 /// Main entry point for the MCP Context Browser
@@ -46,5 +106,54 @@ pub struct Cli {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
-    run(cli.config.as_deref(), cli.server).await
+    match cli.command {
+        Some(Command::BootstrapAdmin { username }) => {
+            let password = read_bootstrap_password()?;
+            let user = bootstrap_admin(cli.config.as_deref(), &username, &password).await?;
+            println!("Created admin user '{}'", user.username);
+            Ok(())
+        }
+        Some(Command::Export {
+            collection,
+            output,
+            compress,
+            encrypt,
+        }) => {
+            let options = ExportOptions { compress, encrypt };
+            let manifest =
+                export_collection(cli.config.as_deref(), &collection, &output, options).await?;
+            println!(
+                "Exported {} vectors from '{}' to {}",
+                manifest.vector_count,
+                collection,
+                output.display()
+            );
+            Ok(())
+        }
+        Some(Command::Import { input, collection }) => {
+            let imported =
+                import_collection(cli.config.as_deref(), &input, collection.as_deref()).await?;
+            println!(
+                "Imported {} vectors into '{}'",
+                imported.manifest.vector_count, imported.collection
+            );
+            Ok(())
+        }
+        None => run(cli.config.as_deref(), cli.server).await,
+    }
+}
+
+/// Read the bootstrap password from the environment or stdin
+fn read_bootstrap_password() -> Result<String, Box<dyn std::error::Error>> {
+    if let Ok(password) = std::env::var(BOOTSTRAP_PASSWORD_ENV) {
+        return Ok(password);
+    }
+
+    eprintln!(
+        "Enter password for the admin user ({} not set):",
+        BOOTSTRAP_PASSWORD_ENV
+    );
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
 }